@@ -57,6 +57,13 @@ impl bencher_endpoint::Registrar for Api {
             }
             api_description.register(oauth::google::auth_google_get)?;
             api_description.register(oauth::google::auth_google_post)?;
+
+            // OpenID Connect
+            if http_options {
+                api_description.register(oauth::oidc::auth_oidc_options)?;
+            }
+            api_description.register(oauth::oidc::auth_oidc_get)?;
+            api_description.register(oauth::oidc::auth_oidc_post)?;
         }
 
         Ok(())
@@ -19,6 +19,7 @@ use crate::CLIENT_TOKEN_TTL;
 pub mod github;
 pub mod google;
 mod oauth_state;
+pub mod oidc;
 
 use oauth_state::OAuthState;
 
@@ -26,6 +27,7 @@ use oauth_state::OAuthState;
 enum OAuthProvider {
     GitHub,
     Google,
+    Oidc,
 }
 
 impl AsRef<str> for OAuthProvider {
@@ -33,6 +35,7 @@ impl AsRef<str> for OAuthProvider {
         match self {
             Self::GitHub => "GitHub OAuth2",
             Self::Google => "Google OAuth2",
+            Self::Oidc => "OpenID Connect",
         }
     }
 }
@@ -193,6 +196,7 @@ impl From<OAuthProvider> for bencher_otel::OAuthProvider {
         match provider {
             OAuthProvider::GitHub => bencher_otel::OAuthProvider::GitHub,
             OAuthProvider::Google => bencher_otel::OAuthProvider::Google,
+            OAuthProvider::Oidc => bencher_otel::OAuthProvider::Oidc,
         }
     }
 }
@@ -0,0 +1,130 @@
+use bencher_endpoint::{CorsResponse, Endpoint, Get, Post, ResponseAccepted, ResponseOk};
+use bencher_json::{JsonOAuthUrl, JsonOAuthUser, system::auth::JsonOAuth};
+use bencher_schema::{
+    HeaderMap, conn_lock,
+    context::ApiContext,
+    error::{bad_gateway_error, forbidden_error, payment_required_error, unauthorized_error},
+    model::organization::sso::QuerySso,
+};
+use dropshot::{HttpError, Query, RequestContext, TypedBody, endpoint};
+use slog::Logger;
+
+use crate::oauth::{OAuthProvider, oauth_state::OAuthState};
+
+use super::{handle_oauth_user, is_allowed_oauth};
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/auth/oidc",
+    tags = ["auth"]
+}]
+pub async fn auth_oidc_options(
+    _rqctx: RequestContext<ApiContext>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// Get the OIDC login URL
+///
+/// ➕ Bencher Plus: Get the authorization URL for the OIDC provider configured for the server.
+/// The authorization request uses PKCE.
+#[endpoint {
+    method = GET,
+    path =  "/v0/auth/oidc",
+    tags = ["auth"]
+}]
+pub async fn auth_oidc_get(
+    rqctx: RequestContext<ApiContext>,
+    query_params: Query<OAuthState>,
+) -> Result<ResponseOk<JsonOAuthUrl>, HttpError> {
+    let json = get_inner(
+        &rqctx.log,
+        rqctx.context(),
+        rqctx.request.headers(),
+        query_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::pub_response_ok(json))
+}
+
+async fn get_inner(
+    log: &Logger,
+    context: &ApiContext,
+    headers: &HeaderMap,
+    oauth_state: OAuthState,
+) -> Result<JsonOAuthUrl, HttpError> {
+    let Some(oidc_client) = &context.oidc_client else {
+        let err = "OpenID Connect is not configured";
+        slog::warn!(log, "{err}");
+        return Err(payment_required_error(err));
+    };
+    is_allowed_oauth(context).await?;
+
+    if let Some(remote_ip) = bencher_schema::RateLimiting::remote_ip(headers) {
+        context.rate_limiting.public_auth_attempt(remote_ip)?;
+    }
+
+    let state = oauth_state.encode(log, &context.token_key)?;
+    let url = oidc_client.auth_url(state).await.map_err(|e| {
+        slog::warn!(
+            log,
+            "Failed to create OpenID Connect authorization URL: {e}"
+        );
+        bad_gateway_error(e)
+    })?;
+    Ok(JsonOAuthUrl { url })
+}
+
+/// Log in with OIDC
+///
+/// ➕ Bencher Plus: Exchange an OIDC authorization code for a user token.
+/// The email domain of the user must be an SSO domain for an organization with OIDC enabled.
+/// If the user does not exist, then they are signed up.
+#[endpoint {
+    method = POST,
+    path = "/v0/auth/oidc",
+    tags = ["auth"]
+}]
+pub async fn auth_oidc_post(
+    rqctx: RequestContext<ApiContext>,
+    body: TypedBody<JsonOAuth>,
+) -> Result<ResponseAccepted<JsonOAuthUser>, HttpError> {
+    let json = post_inner(&rqctx.log, rqctx.context(), body.into_inner()).await?;
+    Ok(Post::pub_response_accepted(json))
+}
+
+async fn post_inner(
+    log: &Logger,
+    context: &ApiContext,
+    json_oauth: JsonOAuth,
+) -> Result<JsonOAuthUser, HttpError> {
+    let Some(oidc_client) = &context.oidc_client else {
+        let err = "OpenID Connect is not configured";
+        slog::warn!(log, "{err}");
+        return Err(payment_required_error(err));
+    };
+    is_allowed_oauth(context).await?;
+
+    let oauth_state = OAuthState::decode(log, &context.token_key, &json_oauth.state)?;
+
+    let (name, email) = oidc_client
+        .oauth_user(&json_oauth.state, json_oauth.code.clone())
+        .await
+        .map_err(|e| {
+            if e.is_unavailable() {
+                slog::warn!(log, "Failed to reach OpenID Connect provider: {e}");
+                bad_gateway_error(e)
+            } else {
+                unauthorized_error(e)
+            }
+        })?;
+
+    if !QuerySso::is_oidc(conn_lock!(context), &email)? {
+        return Err(forbidden_error(format!(
+            "OpenID Connect is not enabled for the email domain: {domain}",
+            domain = email.domain()
+        )));
+    }
+
+    handle_oauth_user(log, context, oauth_state, name, email, OAuthProvider::Oidc).await
+}
@@ -0,0 +1,89 @@
+use bencher_endpoint::{CorsResponse, Endpoint, Get, ResponseOk, TotalCount};
+use bencher_json::{JsonAuditEvents, JsonAuditQuery, OrganizationResourceId};
+use bencher_rbac::organization::Permission;
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    model::{
+        audit::{AuditEventsPagination, AuditScope, QueryAuditEvent},
+        organization::QueryOrganization,
+        user::auth::{AuthUser, BearerToken},
+    },
+};
+use dropshot::{HttpError, Path, Query, RequestContext, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OrgAuditParams {
+    /// The slug or UUID for an organization.
+    pub organization: OrganizationResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/audit",
+    tags = ["organizations", "audit"]
+}]
+pub async fn org_audit_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OrgAuditParams>,
+    _pagination_params: Query<AuditEventsPagination>,
+    _query_params: Query<JsonAuditQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List audit events for an organization
+///
+/// List all audit events for an organization, including the events for all of its projects.
+/// The user must have `manage` permissions for the organization to use this route.
+/// By default, the audit events are sorted in reverse chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of audit events.
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/audit",
+    tags = ["organizations", "audit"]
+}]
+pub async fn org_audit_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgAuditParams>,
+    pagination_params: Query<AuditEventsPagination>,
+    query_params: Query<JsonAuditQuery>,
+) -> Result<ResponseOk<JsonAuditEvents>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+        query_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: OrgAuditParams,
+    pagination_params: AuditEventsPagination,
+    query_params: JsonAuditQuery,
+) -> Result<(JsonAuditEvents, TotalCount), HttpError> {
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let (json_audit_events, total_count) = QueryAuditEvent::list(
+        conn_lock!(context),
+        AuditScope::Organization(query_organization.uuid),
+        &pagination_params,
+        &query_params,
+    )?;
+    Ok((json_audit_events, total_count.try_into()?))
+}
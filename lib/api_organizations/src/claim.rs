@@ -1,9 +1,12 @@
 use bencher_endpoint::{CorsResponse, Endpoint, Post, ResponseCreated};
-use bencher_json::{JsonNewClaim, JsonOrganization, OrganizationResourceId};
+use bencher_json::{
+    AuditAction, AuditResource, JsonNewClaim, JsonOrganization, OrganizationResourceId,
+};
 use bencher_schema::{
     conn_lock,
     context::ApiContext,
     model::{
+        audit::InsertAuditEvent,
         organization::QueryOrganization,
         user::auth::{AuthUser, BearerToken},
     },
@@ -66,5 +69,17 @@ async fn post_inner(
         QueryOrganization::from_resource_id(conn_lock!(context), &path_params.organization)?;
     query_organization.claim(context, &auth_user.user).await?;
 
-    Ok(query_organization.into_json(conn_lock!(context)))
+    let audit_event = InsertAuditEvent::organization(
+        &auth_user,
+        &query_organization,
+        AuditAction::Update,
+        AuditResource::Organization,
+        query_organization.uuid,
+    );
+    let json_organization = query_organization.into_json(conn_lock!(context));
+    audit_event
+        .after(&json_organization)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_organization)
 }
@@ -0,0 +1,154 @@
+use bencher_endpoint::{CorsResponse, Endpoint, Post, ResponseCreated};
+use bencher_json::{
+    AuditAction, AuditResource, JsonProject, JsonProjectExport, OrganizationResourceId,
+    ProjectResourceId,
+};
+use bencher_rbac::project::Permission;
+#[cfg(feature = "plus")]
+use bencher_schema::model::organization::plan::PlanKind;
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    model::{
+        audit::InsertAuditEvent,
+        organization::QueryOrganization,
+        project::{InsertProject, QueryProject},
+        user::auth::{AuthUser, BearerToken},
+    },
+};
+use dropshot::{HttpError, Path, RequestContext, TypedBody, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use slog::Logger;
+
+// A project archive contains every report for the project,
+// so it is allowed to be much larger than the default request body.
+const IMPORT_MAX_BODY_SIZE: usize = 1 << 30;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OrgProjectImportParams {
+    /// The slug or UUID for an organization.
+    pub organization: OrganizationResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/projects/import",
+    tags = ["organizations", "projects"]
+}]
+pub async fn org_project_import_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OrgProjectImportParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Post.into()]))
+}
+
+/// Import a project into an organization
+///
+/// Import a project archive created by exporting a project, possibly from another Bencher server.
+/// If the project does not exist yet, it is created and the user must have `create` permissions for the organization.
+/// Otherwise, the user must have `edit` permissions for the project.
+/// UUIDs from the archive are preserved, unless they are already used by another organization.
+/// Importing the same archive more than once is idempotent.
+/// ➕ Bencher Plus: The project visibility must be `public` unless the organization has a valid Bencher Plus subscription.
+#[endpoint {
+    method = POST,
+    path =  "/v0/organizations/{organization}/projects/import",
+    tags = ["organizations", "projects"],
+    request_body_max_bytes = IMPORT_MAX_BODY_SIZE,
+}]
+pub async fn org_project_import_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgProjectImportParams>,
+    body: TypedBody<JsonProjectExport>,
+) -> Result<ResponseCreated<JsonProject>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: OrgProjectImportParams,
+    json_export: JsonProjectExport,
+    auth_user: &AuthUser,
+) -> Result<JsonProject, HttpError> {
+    let query_organization =
+        QueryOrganization::from_resource_id(conn_lock!(context), &path_params.organization)?;
+
+    let project_uuid = QueryProject::import_uuid(
+        conn_lock!(context),
+        &query_organization,
+        &json_export.project,
+    )?;
+    let (query_project, action) = if QueryProject::from_uuid(
+        conn_lock!(context),
+        query_organization.id,
+        project_uuid,
+    )
+    .is_ok()
+    {
+        let query_project = QueryProject::is_allowed(
+            conn_lock!(context),
+            &context.rbac,
+            &ProjectResourceId::Uuid(project_uuid),
+            auth_user,
+            Permission::Edit,
+        )?;
+        (query_project, AuditAction::Update)
+    } else {
+        #[cfg(feature = "plus")]
+        InsertProject::rate_limit(context, &query_organization).await?;
+
+        // Check project visibility
+        #[cfg(not(feature = "plus"))]
+        QueryProject::is_visibility_public(json_export.project.visibility)?;
+        #[cfg(feature = "plus")]
+        PlanKind::check_for_organization(
+            context,
+            context.biller.as_ref(),
+            &context.licensor,
+            &query_organization,
+            json_export.project.visibility,
+        )
+        .await?;
+
+        let insert_project = InsertProject::from_export(
+            query_organization.id,
+            project_uuid,
+            json_export.project.clone(),
+        );
+        let query_project =
+            QueryProject::create(log, context, auth_user, &query_organization, insert_project)
+                .await?;
+        (query_project, AuditAction::Create)
+    };
+
+    query_project.import(conn_lock!(context), &json_export)?;
+    slog::debug!(log, "Imported project: {query_project:?}");
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        action,
+        AuditResource::Project,
+        query_project.uuid,
+    )?;
+    let json_project =
+        query_project.into_json_for_organization(conn_lock!(context), &query_organization);
+    audit_event
+        .after(&json_project)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_project)
+}
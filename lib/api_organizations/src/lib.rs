@@ -1,9 +1,13 @@
 mod allowed;
+mod audit;
 mod claim;
+mod import;
 mod members;
 mod organizations;
 mod plan;
 mod projects;
+mod roles;
+mod scim;
 mod sso;
 mod usage;
 
@@ -49,6 +53,17 @@ impl bencher_endpoint::Registrar for Api {
         api_description.register(members::org_member_patch)?;
         api_description.register(members::org_member_delete)?;
 
+        // Organization Custom Roles
+        if http_options {
+            api_description.register(roles::org_roles_options)?;
+            api_description.register(roles::org_role_options)?;
+        }
+        api_description.register(roles::org_roles_get)?;
+        api_description.register(roles::org_role_post)?;
+        api_description.register(roles::org_role_get)?;
+        api_description.register(roles::org_role_patch)?;
+        api_description.register(roles::org_role_delete)?;
+
         // Organization Projects
         if http_options {
             api_description.register(projects::org_projects_options)?;
@@ -56,6 +71,18 @@ impl bencher_endpoint::Registrar for Api {
         api_description.register(projects::org_projects_get)?;
         api_description.register(projects::org_project_post)?;
 
+        // Organization Project Import
+        if http_options {
+            api_description.register(import::org_project_import_options)?;
+        }
+        api_description.register(import::org_project_import_post)?;
+
+        // Organization Audit Log
+        if http_options {
+            api_description.register(audit::org_audit_options)?;
+        }
+        api_description.register(audit::org_audit_get)?;
+
         #[cfg(feature = "plus")]
         {
             // Organization Plan
@@ -84,6 +111,22 @@ impl bencher_endpoint::Registrar for Api {
             api_description.register(sso::org_sso_post)?;
             api_description.register(sso::org_sso_get)?;
             api_description.register(sso::org_sso_delete)?;
+
+            // Organization SCIM
+            if http_options {
+                api_description.register(scim::org_scim_users_options)?;
+                api_description.register(scim::org_scim_user_options)?;
+                api_description.register(scim::org_scim_groups_options)?;
+                api_description.register(scim::org_scim_group_options)?;
+            }
+            api_description.register(scim::org_scim_users_get)?;
+            api_description.register(scim::org_scim_user_post)?;
+            api_description.register(scim::org_scim_user_get)?;
+            api_description.register(scim::org_scim_user_patch)?;
+            api_description.register(scim::org_scim_user_delete)?;
+            api_description.register(scim::org_scim_groups_get)?;
+            api_description.register(scim::org_scim_group_get)?;
+            api_description.register(scim::org_scim_group_patch)?;
         }
 
         Ok(())
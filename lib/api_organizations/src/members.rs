@@ -3,8 +3,8 @@ use bencher_endpoint::{
     ResponseOk, TotalCount,
 };
 use bencher_json::{
-    JsonAuthAck, JsonDirection, JsonMember, JsonMembers, JsonPagination, OrganizationResourceId,
-    Search, UserName, UserResourceId,
+    AuditAction, AuditResource, JsonAuthAck, JsonDirection, JsonMember, JsonMembers,
+    JsonPagination, OrganizationResourceId, Search, UserName, UserResourceId,
     organization::member::{JsonNewMember, JsonUpdateMember},
 };
 use bencher_rbac::organization::Permission;
@@ -13,7 +13,9 @@ use bencher_schema::{
     context::{ApiContext, Body, ButtonBody, DbConnection, Message},
     error::{forbidden_error, issue_error, resource_conflict_err, resource_not_found_err},
     model::{
+        audit::InsertAuditEvent,
         organization::{OrganizationId, QueryOrganization, member::QueryMember},
+        project::project_member::QueryProjectMember,
         user::{
             QueryUser, UserId,
             auth::{AuthUser, BearerToken},
@@ -112,19 +114,22 @@ async fn get_ls_inner(
         Permission::ViewRole,
     )?;
 
-    let members = get_ls_query(&query_organization, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryMember>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            OrganizationRole,
-            (&query_organization, &pagination_params, &query_params)
-        ))?;
+    let members = order_ls_query(
+        get_ls_query(&query_organization, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryMember>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        OrganizationRole,
+        (&query_organization, &pagination_params, &query_params)
+    ))?;
 
     // Drop connection lock before iterating
     let json_members = members.into_iter().map(QueryMember::into_json).collect();
 
-    let total_count = get_ls_query(&query_organization, &pagination_params, &query_params)
+    let total_count = get_ls_query(&query_organization, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -138,7 +143,6 @@ async fn get_ls_inner(
 
 fn get_ls_query<'q>(
     query_organization: &QueryOrganization,
-    pagination_params: &OrgMembersPagination,
     query_params: &'q OrgMembersQuery,
 ) -> BoxedQuery<'q> {
     let mut query = schema::user::table
@@ -167,6 +171,15 @@ fn get_ls_query<'q>(
         );
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: BoxedQuery<'q>,
+    pagination_params: &OrgMembersPagination,
+) -> BoxedQuery<'q> {
     match pagination_params.order() {
         OrgMembersSort::Name => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => {
@@ -197,7 +210,7 @@ type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
             schema::organization_role::table,
         >,
     >,
-    diesel::sqlite::Sqlite,
+    bencher_schema::context::DbBackend,
 >;
 
 /// Invite a user to an organization
@@ -249,6 +262,17 @@ async fn post_inner(
     #[cfg(feature = "plus")]
     auth_user.rate_limit_invites(context)?;
 
+    // The invitee may not have a user yet, so the invite is recorded against the organization
+    InsertAuditEvent::organization(
+        auth_user,
+        &query_org,
+        AuditAction::Invite,
+        AuditResource::Member,
+        query_org.uuid,
+    )
+    .after(&json_new_member)
+    .insert(conn_lock!(context))?;
+
     let email = json_new_member.email.clone();
     // If a user already exists for the email then direct them to login.
     // Otherwise, direct them to signup.
@@ -429,6 +453,7 @@ async fn patch_inner(
             auth_user,
             Permission::EditRole,
         )?;
+        let before_member = json_member(conn_lock!(context), query_user.id, query_organization.id)?;
         diesel::update(
             schema::organization_role::table
                 .filter(schema::organization_role::user_id.eq(query_user.id))
@@ -440,6 +465,19 @@ async fn patch_inner(
             OrganizationRole,
             (&query_user, &query_organization, role)
         ))?;
+
+        let json_member = json_member(conn_lock!(context), query_user.id, query_organization.id)?;
+        InsertAuditEvent::organization(
+            auth_user,
+            &query_organization,
+            AuditAction::Update,
+            AuditResource::Member,
+            query_user.uuid,
+        )
+        .before(&before_member)
+        .after(&json_member)
+        .insert(conn_lock!(context))?;
+        return Ok(json_member);
     }
 
     json_member(conn_lock!(context), query_user.id, query_organization.id)
@@ -477,7 +515,13 @@ async fn delete_inner(
         Permission::DeleteRole,
     )?;
     let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
+    let before_member = json_member(conn_lock!(context), query_user.id, query_organization.id)?;
 
+    QueryProjectMember::delete_for_organization(
+        conn_lock!(context),
+        query_user.id,
+        query_organization.id,
+    )?;
     diesel::delete(
         schema::organization_role::table
             .filter(schema::organization_role::user_id.eq(query_user.id))
@@ -486,9 +530,19 @@ async fn delete_inner(
     .execute(conn_lock!(context))
     .map_err(resource_conflict_err!(
         OrganizationRole,
-        (&query_user, query_organization)
+        (&query_user, &query_organization)
     ))?;
 
+    InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Delete,
+        AuditResource::Member,
+        query_user.uuid,
+    )
+    .before(&before_member)
+    .insert(conn_lock!(context))?;
+
     Ok(())
 }
 
@@ -3,8 +3,9 @@ use bencher_endpoint::{
     TotalCount,
 };
 use bencher_json::{
-    JsonDirection, JsonNewOrganization, JsonOrganization, JsonOrganizations, JsonPagination,
-    OrganizationResourceId, ResourceName, Search, organization::JsonUpdateOrganization,
+    AuditAction, AuditResource, JsonDirection, JsonNewOrganization, JsonOrganization,
+    JsonOrganizations, JsonPagination, OrganizationResourceId, ResourceName, Search,
+    organization::JsonUpdateOrganization,
 };
 use bencher_rbac::organization::Permission;
 use bencher_schema::{
@@ -12,6 +13,7 @@ use bencher_schema::{
     context::ApiContext,
     error::{resource_conflict_err, resource_not_found_err},
     model::{
+        audit::InsertAuditEvent,
         organization::{InsertOrganization, QueryOrganization, UpdateOrganization},
         user::auth::{AuthUser, BearerToken},
     },
@@ -90,14 +92,17 @@ async fn get_ls_inner(
     pagination_params: OrganizationsPagination,
     query_params: OrganizationsQuery,
 ) -> Result<(JsonOrganizations, TotalCount), HttpError> {
-    let organizations = get_ls_query(context, auth_user, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryOrganization>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Organization,
-            (auth_user, &pagination_params, &query_params)
-        ))?;
+    let organizations = order_ls_query(
+        get_ls_query(context, auth_user, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryOrganization>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Organization,
+        (auth_user, &pagination_params, &query_params)
+    ))?;
 
     // Drop connection lock before iterating
     let json_organizations = conn_lock!(context, |conn| organizations
@@ -105,7 +110,7 @@ async fn get_ls_inner(
         .map(|org| org.into_json(conn))
         .collect());
 
-    let total_count = get_ls_query(context, auth_user, &pagination_params, &query_params)
+    let total_count = get_ls_query(context, auth_user, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -120,9 +125,8 @@ async fn get_ls_inner(
 fn get_ls_query<'q>(
     context: &ApiContext,
     auth_user: &AuthUser,
-    pagination_params: &OrganizationsPagination,
     query_params: &'q OrganizationsQuery,
-) -> schema::organization::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+) -> schema::organization::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     let mut query = schema::organization::table.into_boxed();
 
     if !auth_user.is_admin(&context.rbac) {
@@ -142,6 +146,15 @@ fn get_ls_query<'q>(
         );
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::organization::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &OrganizationsPagination,
+) -> schema::organization::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         OrganizationsSort::Name => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order((
@@ -180,10 +193,23 @@ async fn post_inner(
     json_organization: JsonNewOrganization,
     auth_user: &AuthUser,
 ) -> Result<JsonOrganization, HttpError> {
+    auth_user.check_is_unscoped()?;
     let insert_organization = InsertOrganization::from_json(conn_lock!(context), json_organization);
     let query_organization =
         QueryOrganization::create(context, auth_user, insert_organization).await?;
-    Ok(query_organization.into_json(conn_lock!(context)))
+    let audit_event = InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Create,
+        AuditResource::Organization,
+        query_organization.uuid,
+    );
+    let json_organization = query_organization.into_json(conn_lock!(context));
+    audit_event
+        .after(&json_organization)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_organization)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -311,6 +337,7 @@ async fn patch_inner(
             ))?;
     }
 
+    let before_organization = query_organization.clone().into_json(conn_lock!(context));
     let organization_query =
         schema::organization::table.filter(schema::organization::id.eq(query_organization.id));
     let update_organization = UpdateOrganization::from(json_organization);
@@ -319,11 +346,24 @@ async fn patch_inner(
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Organization, update_organization))?;
 
-    conn_lock!(context, |conn| QueryOrganization::get(
+    let json_organization = conn_lock!(context, |conn| QueryOrganization::get(
         conn,
         query_organization.id
     )?
-    .into_json_full(conn))
+    .into_json_full(conn))?;
+
+    InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Update,
+        AuditResource::Organization,
+        query_organization.uuid,
+    )
+    .before(&before_organization)
+    .after(&json_organization)
+    .insert(conn_lock!(context))?;
+
+    Ok(json_organization)
 }
 
 /// Delete an organization
@@ -358,12 +398,21 @@ async fn delete_inner(
         auth_user,
         Permission::Delete,
     )?;
+    let audit_event = InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Delete,
+        AuditResource::Organization,
+        query_organization.uuid,
+    )
+    .before(&query_organization.clone().into_json(conn_lock!(context)));
 
     diesel::delete(
         schema::organization::table.filter(schema::organization::id.eq(query_organization.id)),
     )
     .execute(conn_lock!(context))
     .map_err(resource_conflict_err!(Organization, query_organization))?;
+    audit_event.insert(conn_lock!(context))?;
 
     #[cfg(feature = "otel")]
     bencher_otel::ApiMeter::increment(bencher_otel::ApiCounter::OrganizationDelete);
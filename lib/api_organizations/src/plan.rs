@@ -5,7 +5,7 @@ use bencher_endpoint::{
     CorsResponse, Delete, Endpoint, Get, Post, ResponseCreated, ResponseDeleted, ResponseOk,
 };
 use bencher_json::{
-    DateTime, OrganizationResourceId,
+    AuditAction, AuditResource, DateTime, OrganizationResourceId,
     organization::plan::{JsonNewPlan, JsonPlan},
 };
 use bencher_rbac::organization::Permission;
@@ -16,14 +16,15 @@ use bencher_schema::{
         BencherResource, forbidden_error, issue_error, resource_conflict_err,
         resource_conflict_error, resource_not_found_err,
     },
-    model::{organization::QueryOrganization, user::auth::BearerToken},
     model::{
+        audit::InsertAuditEvent,
         organization::{
             UpdateOrganization,
             plan::{InsertPlan, QueryPlan},
         },
         user::auth::AuthUser,
     },
+    model::{organization::QueryOrganization, user::auth::BearerToken},
     schema,
 };
 use diesel::{BelongingToDsl as _, ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
@@ -186,7 +187,7 @@ async fn post_inner(
         })?
     };
 
-    if let Some(entitlements) = entitlements {
+    let json_plan = if let Some(entitlements) = entitlements {
         let licensed_plan_id = subscription_id
             .as_ref()
             .parse()
@@ -227,7 +228,19 @@ async fn post_inner(
             &format!("Failed to find metered plan for organization ({query_organization:?}) after creating it even though plan exists."),
           "Failed to find metered plan after creating it"
             )})
-    }
+    }?;
+
+    // The plan contains billing details, so only the event itself is recorded
+    InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Create,
+        AuditResource::Plan,
+        query_organization.uuid,
+    )
+    .insert(conn_lock!(context))?;
+
+    Ok(json_plan)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -299,6 +312,15 @@ async fn delete_inner(
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Plan, query_plan))?;
 
+    InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Delete,
+        AuditResource::Plan,
+        query_organization.uuid,
+    )
+    .insert(conn_lock!(context))?;
+
     delete_plan_result
 }
 
@@ -2,8 +2,8 @@ use bencher_endpoint::{
     CorsResponse, Endpoint, Get, Post, ResponseCreated, ResponseOk, TotalCount,
 };
 use bencher_json::{
-    JsonDirection, JsonNewProject, JsonPagination, JsonProject, JsonProjects,
-    OrganizationResourceId, ResourceName, Search,
+    AuditAction, AuditResource, JsonDirection, JsonNewProject, JsonPagination, JsonProject,
+    JsonProjects, OrganizationResourceId, ResourceName, Search,
 };
 use bencher_rbac::organization::Permission;
 #[cfg(feature = "plus")]
@@ -13,6 +13,7 @@ use bencher_schema::{
     context::ApiContext,
     error::resource_not_found_err,
     model::{
+        audit::InsertAuditEvent,
         organization::QueryOrganization,
         project::{InsertProject, QueryProject},
         user::auth::{AuthUser, BearerToken},
@@ -110,14 +111,17 @@ async fn get_ls_inner(
         Permission::View,
     )?;
 
-    let projects = get_ls_query(&query_organization, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryProject>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Project,
-            (&query_organization, &pagination_params, &query_params)
-        ))?;
+    let projects = order_ls_query(
+        get_ls_query(&query_organization, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryProject>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Project,
+        (&query_organization, &pagination_params, &query_params)
+    ))?;
 
     // Drop connection lock before iterating
     let json_projects = conn_lock!(context, |conn| projects
@@ -125,7 +129,7 @@ async fn get_ls_inner(
         .map(|project| project.into_json_for_organization(conn, &query_organization))
         .collect());
 
-    let total_count = get_ls_query(&query_organization, &pagination_params, &query_params)
+    let total_count = get_ls_query(&query_organization, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -139,9 +143,8 @@ async fn get_ls_inner(
 
 fn get_ls_query<'q>(
     query_organization: &'q QueryOrganization,
-    pagination_params: &OrgProjectsPagination,
     query_params: &'q OrgProjectsQuery,
-) -> schema::project::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+) -> schema::project::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     let mut query = QueryProject::belonging_to(query_organization).into_boxed();
 
     if let Some(name) = query_params.name.as_ref() {
@@ -156,6 +159,15 @@ fn get_ls_query<'q>(
         );
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::project::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &OrgProjectsPagination,
+) -> schema::project::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         OrgProjectsSort::Name => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order(schema::project::name.asc()),
@@ -223,7 +235,20 @@ async fn post_inner(
     let insert_project =
         InsertProject::from_json(conn_lock!(context), &query_organization, json_project);
 
-    QueryProject::create(log, context, auth_user, &query_organization, insert_project)
-        .await?
-        .into_json(conn_lock!(context))
+    let query_project =
+        QueryProject::create(log, context, auth_user, &query_organization, insert_project).await?;
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Create,
+        AuditResource::Project,
+        query_project.uuid,
+    )?;
+    let json_project = query_project.into_json(conn_lock!(context))?;
+    audit_event
+        .after(&json_project)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_project)
 }
@@ -0,0 +1,392 @@
+use bencher_endpoint::{
+    CorsResponse, Delete, Endpoint, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+    TotalCount,
+};
+use bencher_json::{
+    AuditAction, AuditResource, JsonDirection, JsonNewRole, JsonPagination, JsonRole, JsonRoles,
+    JsonUpdateRole, OrganizationResourceId, RoleUuid,
+};
+use bencher_rbac::organization::Permission;
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        audit::InsertAuditEvent,
+        organization::{
+            QueryOrganization,
+            custom_role::{InsertCustomRole, QueryCustomRole, UpdateCustomRole},
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+};
+use diesel::{BelongingToDsl as _, ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::{HttpError, Path, Query, RequestContext, TypedBody, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OrgRolesParams {
+    /// The slug or UUID for an organization.
+    pub organization: OrganizationResourceId,
+}
+
+pub type OrgRolesPagination = JsonPagination<OrgRolesSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OrgRolesSort {
+    /// Sort by role name.
+    #[default]
+    Name,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/roles",
+    tags = ["organizations", "roles"]
+}]
+pub async fn org_roles_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OrgRolesParams>,
+    _pagination_params: Query<OrgRolesPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List custom roles for an organization
+///
+/// List all custom roles for an organization.
+/// A custom role is a set of project permissions that can be assigned to organization members for a project.
+/// The user must have `view_role` permissions for the organization.
+/// By default, the roles are sorted in alphabetical order by name.
+/// The HTTP response header `X-Total-Count` contains the total number of roles.
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/roles",
+    tags = ["organizations", "roles"]
+}]
+pub async fn org_roles_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgRolesParams>,
+    pagination_params: Query<OrgRolesPagination>,
+) -> Result<ResponseOk<JsonRoles>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: OrgRolesParams,
+    pagination_params: OrgRolesPagination,
+) -> Result<(JsonRoles, TotalCount), HttpError> {
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::ViewRole,
+    )?;
+
+    let roles = order_ls_query(get_ls_query(&query_organization), &pagination_params)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryCustomRole>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            CustomRole,
+            (&query_organization, &pagination_params)
+        ))?;
+
+    let json_roles = roles
+        .into_iter()
+        .map(|role| role.into_json_for_organization(&query_organization))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let total_count = get_ls_query(&query_organization)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            CustomRole,
+            (&query_organization, &pagination_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_roles.into(), total_count))
+}
+
+fn get_ls_query(
+    query_organization: &QueryOrganization,
+) -> schema::custom_role::BoxedQuery<'_, bencher_schema::context::DbBackend> {
+    QueryCustomRole::belonging_to(query_organization).into_boxed()
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::custom_role::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &OrgRolesPagination,
+) -> schema::custom_role::BoxedQuery<'q, bencher_schema::context::DbBackend> {
+    match pagination_params.order() {
+        OrgRolesSort::Name => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order(schema::custom_role::name.asc()),
+            Some(JsonDirection::Desc) => query.order(schema::custom_role::name.desc()),
+        },
+    }
+}
+
+/// Create a custom role
+///
+/// Create a custom role for an organization.
+/// The user must have `create_role` permissions for the organization.
+#[endpoint {
+    method = POST,
+    path =  "/v0/organizations/{organization}/roles",
+    tags = ["organizations", "roles"]
+}]
+pub async fn org_role_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgRolesParams>,
+    body: TypedBody<JsonNewRole>,
+) -> Result<ResponseCreated<JsonRole>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: OrgRolesParams,
+    json_role: JsonNewRole,
+    auth_user: &AuthUser,
+) -> Result<JsonRole, HttpError> {
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::CreateRole,
+    )?;
+
+    let insert_role = InsertCustomRole::from_json(query_organization.id, json_role)?;
+    diesel::insert_into(schema::custom_role::table)
+        .values(&insert_role)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(CustomRole, insert_role))?;
+
+    let json_role =
+        QueryCustomRole::get_with_uuid(conn_lock!(context), &query_organization, insert_role.uuid)?
+            .into_json_for_organization(&query_organization)?;
+
+    InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Create,
+        AuditResource::Role,
+        json_role.uuid,
+    )
+    .after(&json_role)
+    .insert(conn_lock!(context))?;
+
+    Ok(json_role)
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OrgRoleParams {
+    /// The slug or UUID for an organization.
+    pub organization: OrganizationResourceId,
+    /// The UUID for a custom role.
+    pub role: RoleUuid,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/roles/{role}",
+    tags = ["organizations", "roles"]
+}]
+pub async fn org_role_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OrgRoleParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+}
+
+/// View a custom role
+///
+/// View a custom role for an organization.
+/// The user must have `view_role` permissions for the organization.
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/roles/{role}",
+    tags = ["organizations", "roles"]
+}]
+pub async fn org_role_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgRoleParams>,
+) -> Result<ResponseOk<JsonRole>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: OrgRoleParams,
+    auth_user: &AuthUser,
+) -> Result<JsonRole, HttpError> {
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::ViewRole,
+    )?;
+
+    QueryCustomRole::get_with_uuid(conn_lock!(context), &query_organization, path_params.role)?
+        .into_json_for_organization(&query_organization)
+}
+
+/// Update a custom role
+///
+/// Update a custom role for an organization.
+/// The user must have `edit_role` permissions for the organization.
+/// The changes apply to all of the project members that have been assigned the role.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/organizations/{organization}/roles/{role}",
+    tags = ["organizations", "roles"]
+}]
+pub async fn org_role_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgRoleParams>,
+    body: TypedBody<JsonUpdateRole>,
+) -> Result<ResponseOk<JsonRole>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: OrgRoleParams,
+    json_role: JsonUpdateRole,
+    auth_user: &AuthUser,
+) -> Result<JsonRole, HttpError> {
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::EditRole,
+    )?;
+    let query_role =
+        QueryCustomRole::get_with_uuid(conn_lock!(context), &query_organization, path_params.role)?;
+    let before_role = query_role
+        .clone()
+        .into_json_for_organization(&query_organization)?;
+
+    let update_role = UpdateCustomRole::try_from(json_role)?;
+    diesel::update(schema::custom_role::table.filter(schema::custom_role::id.eq(query_role.id)))
+        .set(&update_role)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            CustomRole,
+            (&query_role, &update_role)
+        ))?;
+
+    let json_role = QueryCustomRole::get(conn_lock!(context), query_role.id)?
+        .into_json_for_organization(&query_organization)?;
+
+    InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Update,
+        AuditResource::Role,
+        json_role.uuid,
+    )
+    .before(&before_role)
+    .after(&json_role)
+    .insert(conn_lock!(context))?;
+
+    Ok(json_role)
+}
+
+/// Delete a custom role
+///
+/// Delete a custom role for an organization.
+/// The user must have `delete_role` permissions for the organization.
+/// All project members that have been assigned the role lose the permissions it granted.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/organizations/{organization}/roles/{role}",
+    tags = ["organizations", "roles"]
+}]
+pub async fn org_role_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgRoleParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: OrgRoleParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::DeleteRole,
+    )?;
+    let query_role =
+        QueryCustomRole::get_with_uuid(conn_lock!(context), &query_organization, path_params.role)?;
+    let audit_event = InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Delete,
+        AuditResource::Role,
+        query_role.uuid,
+    )
+    .before(
+        &query_role
+            .clone()
+            .into_json_for_organization(&query_organization)?,
+    );
+
+    // This will cascade and delete all of the project member assignments for the role
+    diesel::delete(schema::custom_role::table.filter(schema::custom_role::id.eq(query_role.id)))
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(CustomRole, query_role))?;
+    audit_event.insert(conn_lock!(context))?;
+
+    Ok(())
+}
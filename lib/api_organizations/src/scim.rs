@@ -0,0 +1,687 @@
+#![cfg(feature = "plus")]
+
+use bencher_endpoint::{
+    CorsResponse, Delete, Endpoint, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+};
+use bencher_json::{
+    AuditAction, AuditResource, JsonScimGroup, JsonScimGroups, JsonScimNewUser, JsonScimPatch,
+    JsonScimUser, JsonScimUsers, OrganizationResourceId, ScimGroupId, UserUuid,
+    organization::scim::{
+        JsonScimPatchOperation, SCIM_LIST_RESPONSE_SCHEMA, ScimFilter, ScimPatchOp,
+    },
+};
+use bencher_rbac::organization::Permission;
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    error::{bad_request_error, payment_required_error},
+    model::{
+        audit::InsertAuditEvent,
+        organization::{QueryOrganization, plan::LicenseUsage, scim::ScimOrganization},
+        user::{
+            QueryUser,
+            auth::{AuthUser, BearerToken},
+        },
+    },
+};
+use dropshot::{HttpError, Path, Query, RequestContext, TypedBody, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use slog::Logger;
+
+const DEFAULT_SCIM_COUNT: u32 = 100;
+const MAX_SCIM_COUNT: u32 = 255;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OrgScimParams {
+    /// The slug or UUID for an organization.
+    pub organization: OrganizationResourceId,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct OrgScimQuery {
+    /// A SCIM filter with a single equality expression.
+    /// For example: `userName eq "bjorn@example.com"`
+    pub filter: Option<String>,
+    /// The 1-based index of the first result.
+    #[serde(rename = "startIndex")]
+    pub start_index: Option<u32>,
+    /// The maximum number of results per page.
+    pub count: Option<u32>,
+}
+
+impl OrgScimQuery {
+    fn filter(&self) -> Result<Option<ScimFilter>, HttpError> {
+        self.filter
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(bad_request_error)
+    }
+
+    fn start_index(&self) -> u32 {
+        self.start_index.unwrap_or(1).max(1)
+    }
+
+    fn count(&self) -> u32 {
+        self.count.unwrap_or(DEFAULT_SCIM_COUNT).min(MAX_SCIM_COUNT)
+    }
+
+    fn page<T>(&self, resources: Vec<T>) -> (u32, Vec<T>) {
+        let total_results = u32::try_from(resources.len()).unwrap_or(u32::MAX);
+        let skip = usize::try_from(self.start_index().saturating_sub(1)).unwrap_or(usize::MAX);
+        let take = usize::try_from(self.count()).unwrap_or(usize::MAX);
+        let page = resources.into_iter().skip(skip).take(take).collect();
+        (total_results, page)
+    }
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/scim/v2/Users",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_users_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OrgScimParams>,
+    _query_params: Query<OrgScimQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List SCIM users for an organization
+///
+/// ➕ Bencher Plus: List the members of an organization as SCIM 2.0 users.
+/// The API token must be scoped to only the organization,
+/// and the user must have `manage` permissions for the organization.
+/// Only the `userName` and `emails.value` attributes can be used to filter users.
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/scim/v2/Users",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_users_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgScimParams>,
+    query_params: Query<OrgScimQuery>,
+) -> Result<ResponseOk<JsonScimUsers>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_users_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        query_params.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_users_inner(
+    context: &ApiContext,
+    path_params: OrgScimParams,
+    query_params: OrgScimQuery,
+    auth_user: &AuthUser,
+) -> Result<JsonScimUsers, HttpError> {
+    let query_organization = scim_organization(context, &path_params, auth_user).await?;
+    let scim = ScimOrganization::new(&query_organization);
+
+    let filter = query_params.filter()?;
+    if let Some(filter) = &filter
+        && !(filter.attribute.eq_ignore_ascii_case("userName")
+            || filter.attribute.eq_ignore_ascii_case("emails.value"))
+    {
+        return Err(bad_request_error(format!(
+            "Unsupported SCIM user filter attribute ({attribute})",
+            attribute = filter.attribute
+        )));
+    }
+
+    let member_ids = scim.member_ids(conn_lock!(context))?;
+    let mut users = Vec::with_capacity(member_ids.len());
+    for member_id in member_ids {
+        let query_user = QueryUser::get(conn_lock!(context), member_id)?;
+        if filter
+            .as_ref()
+            .is_some_and(|filter| !filter.value.eq_ignore_ascii_case(query_user.email.as_ref()))
+        {
+            continue;
+        }
+        users.push(query_user);
+    }
+
+    let (total_results, users) = query_params.page(users);
+    let mut resources = Vec::with_capacity(users.len());
+    for query_user in users {
+        resources.push(scim.user(conn_lock!(context), query_user)?);
+    }
+
+    Ok(JsonScimUsers {
+        schemas: vec![SCIM_LIST_RESPONSE_SCHEMA.into()],
+        total_results,
+        start_index: query_params.start_index(),
+        items_per_page: u32::try_from(resources.len()).unwrap_or(u32::MAX),
+        resources,
+    })
+}
+
+/// Provision a SCIM user for an organization
+///
+/// ➕ Bencher Plus: Provision a SCIM 2.0 user as a member of an organization.
+/// The API token must be scoped to only the organization,
+/// and the user must have `manage` permissions for the organization.
+/// The email for the user must be at one of the SSO domains for the organization.
+/// If there is no Bencher user with the email, then a new user is created.
+#[endpoint {
+    method = POST,
+    path =  "/v0/organizations/{organization}/scim/v2/Users",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_user_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgScimParams>,
+    body: TypedBody<JsonScimNewUser>,
+) -> Result<ResponseCreated<JsonScimUser>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_user_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_user_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: OrgScimParams,
+    json_user: JsonScimNewUser,
+    auth_user: &AuthUser,
+) -> Result<JsonScimUser, HttpError> {
+    let query_organization = scim_organization(context, &path_params, auth_user).await?;
+    let scim = ScimOrganization::new(&query_organization);
+
+    let (query_user, insert_user) = scim.provision(conn_lock!(context), &json_user)?;
+    if let Some(insert_user) = insert_user {
+        insert_user.notify(
+            log,
+            conn_lock!(context),
+            &context.messenger,
+            &context.console_url,
+            true,
+            "SCIM",
+        )?;
+    }
+
+    let user_uuid = query_user.uuid;
+    let json_scim_user = scim.user(conn_lock!(context), query_user)?;
+    InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Provision,
+        AuditResource::Member,
+        user_uuid,
+    )
+    .after(&json_scim_user)
+    .insert(conn_lock!(context))?;
+
+    Ok(json_scim_user)
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OrgScimUserParams {
+    /// The slug or UUID for an organization.
+    pub organization: OrganizationResourceId,
+    /// The UUID for a user.
+    pub user: UserUuid,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/scim/v2/Users/{user}",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_user_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OrgScimUserParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+}
+
+/// View a SCIM user for an organization
+///
+/// ➕ Bencher Plus: View a member of an organization as a SCIM 2.0 user.
+/// The API token must be scoped to only the organization,
+/// and the user must have `manage` permissions for the organization.
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/scim/v2/Users/{user}",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_user_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgScimUserParams>,
+) -> Result<ResponseOk<JsonScimUser>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_user_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_user_inner(
+    context: &ApiContext,
+    path_params: OrgScimUserParams,
+    auth_user: &AuthUser,
+) -> Result<JsonScimUser, HttpError> {
+    let query_organization = scim_organization(
+        context,
+        &OrgScimParams {
+            organization: path_params.organization,
+        },
+        auth_user,
+    )
+    .await?;
+    let scim = ScimOrganization::new(&query_organization);
+
+    let query_user = scim.member(conn_lock!(context), path_params.user)?;
+    scim.user(conn_lock!(context), query_user)
+}
+
+/// Update a SCIM user for an organization
+///
+/// ➕ Bencher Plus: Update a SCIM 2.0 user for an organization.
+/// The API token must be scoped to only the organization,
+/// and the user must have `manage` permissions for the organization.
+/// Only the `active` attribute can be updated.
+/// Setting `active` to `false` deprovisions the user, the same as deleting them.
+/// Setting `active` to `true` adds the user back to the organization.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/organizations/{organization}/scim/v2/Users/{user}",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_user_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgScimUserParams>,
+    body: TypedBody<JsonScimPatch>,
+) -> Result<ResponseOk<JsonScimUser>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_user_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_user_inner(
+    context: &ApiContext,
+    path_params: OrgScimUserParams,
+    json_patch: JsonScimPatch,
+    auth_user: &AuthUser,
+) -> Result<JsonScimUser, HttpError> {
+    let query_organization = scim_organization(
+        context,
+        &OrgScimParams {
+            organization: path_params.organization,
+        },
+        auth_user,
+    )
+    .await?;
+    let scim = ScimOrganization::new(&query_organization);
+
+    let active = json_patch
+        .operations
+        .iter()
+        .filter(|operation| operation.op != ScimPatchOp::Remove)
+        .filter_map(JsonScimPatchOperation::active)
+        .next_back();
+    let (query_user, action) = match active {
+        Some(true) => {
+            let query_user = QueryUser::from_uuid(conn_lock!(context), path_params.user)?;
+            scim.activate(conn_lock!(context), &query_user)?;
+            (query_user, Some(AuditAction::Provision))
+        },
+        Some(false) => {
+            let query_user = scim.member(conn_lock!(context), path_params.user)?;
+            scim.deprovision(conn_lock!(context), &query_user)?;
+            (query_user, Some(AuditAction::Deprovision))
+        },
+        None => (scim.member(conn_lock!(context), path_params.user)?, None),
+    };
+
+    let user_uuid = query_user.uuid;
+    let json_scim_user = scim.user(conn_lock!(context), query_user)?;
+    if let Some(action) = action {
+        InsertAuditEvent::organization(
+            auth_user,
+            &query_organization,
+            action,
+            AuditResource::Member,
+            user_uuid,
+        )
+        .after(&json_scim_user)
+        .insert(conn_lock!(context))?;
+    }
+
+    Ok(json_scim_user)
+}
+
+/// Deprovision a SCIM user for an organization
+///
+/// ➕ Bencher Plus: Deprovision a SCIM 2.0 user for an organization.
+/// The API token must be scoped to only the organization,
+/// and the user must have `manage` permissions for the organization.
+/// The user is removed from the organization and all of its projects.
+/// If the email for the user is at one of the SSO domains for the organization,
+/// then all of their API tokens are also revoked.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/organizations/{organization}/scim/v2/Users/{user}",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_user_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgScimUserParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_user_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_user_inner(
+    context: &ApiContext,
+    path_params: OrgScimUserParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    let query_organization = scim_organization(
+        context,
+        &OrgScimParams {
+            organization: path_params.organization,
+        },
+        auth_user,
+    )
+    .await?;
+    let scim = ScimOrganization::new(&query_organization);
+
+    let query_user = scim.member(conn_lock!(context), path_params.user)?;
+    scim.deprovision(conn_lock!(context), &query_user)?;
+
+    InsertAuditEvent::organization(
+        auth_user,
+        &query_organization,
+        AuditAction::Deprovision,
+        AuditResource::Member,
+        query_user.uuid,
+    )
+    .insert(conn_lock!(context))
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/scim/v2/Groups",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_groups_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OrgScimParams>,
+    _query_params: Query<OrgScimQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List SCIM groups for an organization
+///
+/// ➕ Bencher Plus: List the roles of an organization as SCIM 2.0 groups.
+/// The API token must be scoped to only the organization,
+/// and the user must have `manage` permissions for the organization.
+/// There is one group for each organization role
+/// and one group for each project role of each project in the organization.
+/// Only the `displayName` attribute can be used to filter groups.
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/scim/v2/Groups",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_groups_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgScimParams>,
+    query_params: Query<OrgScimQuery>,
+) -> Result<ResponseOk<JsonScimGroups>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_groups_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        query_params.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_groups_inner(
+    context: &ApiContext,
+    path_params: OrgScimParams,
+    query_params: OrgScimQuery,
+    auth_user: &AuthUser,
+) -> Result<JsonScimGroups, HttpError> {
+    let query_organization = scim_organization(context, &path_params, auth_user).await?;
+    let scim = ScimOrganization::new(&query_organization);
+
+    let filter = query_params.filter()?;
+    if let Some(filter) = &filter
+        && !filter.attribute.eq_ignore_ascii_case("displayName")
+    {
+        return Err(bad_request_error(format!(
+            "Unsupported SCIM group filter attribute ({attribute})",
+            attribute = filter.attribute
+        )));
+    }
+
+    let group_ids = scim.group_ids(conn_lock!(context))?;
+    let mut groups = Vec::with_capacity(group_ids.len());
+    for group_id in group_ids {
+        let group = scim.group(conn_lock!(context), group_id)?;
+        if filter
+            .as_ref()
+            .is_some_and(|filter| filter.value != group.display_name)
+        {
+            continue;
+        }
+        groups.push(group);
+    }
+
+    let (total_results, resources) = query_params.page(groups);
+    Ok(JsonScimGroups {
+        schemas: vec![SCIM_LIST_RESPONSE_SCHEMA.into()],
+        total_results,
+        start_index: query_params.start_index(),
+        items_per_page: u32::try_from(resources.len()).unwrap_or(u32::MAX),
+        resources,
+    })
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OrgScimGroupParams {
+    /// The slug or UUID for an organization.
+    pub organization: OrganizationResourceId,
+    /// The ID for a SCIM group.
+    pub group: ScimGroupId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/organizations/{organization}/scim/v2/Groups/{group}",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_group_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<OrgScimGroupParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into()]))
+}
+
+/// View a SCIM group for an organization
+///
+/// ➕ Bencher Plus: View an organization or project role as a SCIM 2.0 group.
+/// The API token must be scoped to only the organization,
+/// and the user must have `manage` permissions for the organization.
+#[endpoint {
+    method = GET,
+    path =  "/v0/organizations/{organization}/scim/v2/Groups/{group}",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_group_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgScimGroupParams>,
+) -> Result<ResponseOk<JsonScimGroup>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_group_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_group_inner(
+    context: &ApiContext,
+    path_params: OrgScimGroupParams,
+    auth_user: &AuthUser,
+) -> Result<JsonScimGroup, HttpError> {
+    let query_organization = scim_organization(
+        context,
+        &OrgScimParams {
+            organization: path_params.organization,
+        },
+        auth_user,
+    )
+    .await?;
+    let scim = ScimOrganization::new(&query_organization);
+
+    scim.group(conn_lock!(context), path_params.group)
+}
+
+/// Update a SCIM group for an organization
+///
+/// ➕ Bencher Plus: Add or remove members for an organization or project role SCIM 2.0 group.
+/// The API token must be scoped to only the organization,
+/// and the user must have `manage` permissions for the organization.
+/// Adding a user to an organization role group adds them to the organization with that role.
+/// Removing a user from an organization role group removes them from the organization,
+/// but it does not revoke their API tokens.
+/// Users must already be members of the organization to be added to a project role group.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/organizations/{organization}/scim/v2/Groups/{group}",
+    tags = ["organizations", "scim"]
+}]
+pub async fn org_scim_group_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<OrgScimGroupParams>,
+    body: TypedBody<JsonScimPatch>,
+) -> Result<ResponseOk<JsonScimGroup>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_group_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_group_inner(
+    context: &ApiContext,
+    path_params: OrgScimGroupParams,
+    json_patch: JsonScimPatch,
+    auth_user: &AuthUser,
+) -> Result<JsonScimGroup, HttpError> {
+    let query_organization = scim_organization(
+        context,
+        &OrgScimParams {
+            organization: path_params.organization,
+        },
+        auth_user,
+    )
+    .await?;
+    let scim = ScimOrganization::new(&query_organization);
+    let group_id = path_params.group;
+    let before_group = scim.group(conn_lock!(context), group_id)?;
+
+    let mut user_uuids = Vec::new();
+    for operation in &json_patch.operations {
+        for member in operation.members() {
+            let uuid = member.parse::<UserUuid>().map_err(|e| {
+                bad_request_error(format!("Invalid SCIM group member ({member}): {e}"))
+            })?;
+            match operation.op {
+                ScimPatchOp::Add | ScimPatchOp::Replace => {
+                    scim.add_to_group(conn_lock!(context), group_id, uuid)?;
+                },
+                ScimPatchOp::Remove => {
+                    scim.remove_from_group(conn_lock!(context), group_id, uuid)?;
+                },
+            }
+            user_uuids.push(uuid);
+        }
+    }
+
+    let json_group = scim.group(conn_lock!(context), group_id)?;
+    for user_uuid in user_uuids {
+        InsertAuditEvent::organization(
+            auth_user,
+            &query_organization,
+            AuditAction::Update,
+            AuditResource::Member,
+            user_uuid,
+        )
+        .before(&before_group)
+        .after(&json_group)
+        .insert(conn_lock!(context))?;
+    }
+
+    Ok(json_group)
+}
+
+/// Get the organization for a SCIM request.
+/// The API token must be scoped to only the organization,
+/// and the organization must be on Bencher Cloud or have a valid Bencher Plus license.
+async fn scim_organization(
+    context: &ApiContext,
+    path_params: &OrgScimParams,
+    auth_user: &AuthUser,
+) -> Result<QueryOrganization, HttpError> {
+    let query_organization = QueryOrganization::is_allowed_resource_id(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.organization,
+        auth_user,
+        Permission::Manage,
+    )?;
+    auth_user.check_is_organization_scoped(query_organization.id)?;
+
+    let is_allowed = context.is_bencher_cloud
+        || LicenseUsage::get(
+            &context.database.connection,
+            &context.licensor,
+            &query_organization,
+        )
+        .await?
+        .is_some();
+    if !is_allowed {
+        return Err(payment_required_error(
+            "You must have a valid Bencher Plus Enterprise license for the organization to use SCIM",
+        ));
+    }
+
+    Ok(query_organization)
+}
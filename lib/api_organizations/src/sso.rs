@@ -5,7 +5,8 @@ use bencher_endpoint::{
     TotalCount,
 };
 use bencher_json::{
-    JsonDirection, JsonNewSso, JsonPagination, JsonSso, JsonSsos, OrganizationResourceId, SsoUuid,
+    AuditAction, AuditResource, JsonDirection, JsonNewSso, JsonPagination, JsonSso, JsonSsos,
+    OrganizationResourceId, SsoUuid,
 };
 use bencher_rbac::organization::Permission;
 use bencher_schema::{
@@ -13,6 +14,7 @@ use bencher_schema::{
     context::ApiContext,
     error::{payment_required_error, resource_conflict_err},
     model::{
+        audit::InsertAuditEvent,
         organization::{
             QueryOrganization,
             plan::LicenseUsage,
@@ -98,7 +100,7 @@ async fn get_ls_inner(
         Permission::View,
     )?;
 
-    let ssos = get_ls_query(&query_organization, &pagination_params)
+    let ssos = order_ls_query(get_ls_query(&query_organization), &pagination_params)
         .offset(pagination_params.offset())
         .limit(pagination_params.limit())
         .load::<QuerySso>(conn_lock!(context))
@@ -110,7 +112,7 @@ async fn get_ls_inner(
     // Drop connection lock before iterating
     let json_ssos = ssos.into_iter().map(QuerySso::into_json).collect();
 
-    let total_count = get_ls_query(&query_organization, &pagination_params)
+    let total_count = get_ls_query(&query_organization)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -122,12 +124,18 @@ async fn get_ls_inner(
     Ok((json_ssos, total_count))
 }
 
-fn get_ls_query<'q>(
-    query_organization: &'q QueryOrganization,
-    pagination_params: &OrgSsoPagination,
-) -> schema::sso::BoxedQuery<'q, diesel::sqlite::Sqlite> {
-    let query = QuerySso::belonging_to(query_organization).into_boxed();
+fn get_ls_query(
+    query_organization: &QueryOrganization,
+) -> schema::sso::BoxedQuery<'_, bencher_schema::context::DbBackend> {
+    QuerySso::belonging_to(query_organization).into_boxed()
+}
 
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::sso::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &OrgSsoPagination,
+) -> schema::sso::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         OrgSsoSort::Domain => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order(schema::sso::domain.asc()),
@@ -145,6 +153,8 @@ fn get_ls_query<'q>(
 /// If an SSO domain matches, they will be added as a Member to that Organization.
 /// SSO domains must be globally unique on the server.
 /// That is, two Organizations cannot have the same SSO domain.
+/// If `oidc` is enabled, users with an email at the SSO domain
+/// can also log in with the OIDC provider configured for the server.
 #[endpoint {
     method = POST,
     path =  "/v0/organizations/{organization}/sso",
@@ -156,8 +166,14 @@ pub async fn org_sso_post(
     path_params: Path<OrgSsosParams>,
     body: TypedBody<JsonNewSso>,
 ) -> Result<ResponseAccepted<JsonSso>, HttpError> {
-    let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
-    let json = post_inner(rqctx.context(), path_params.into_inner(), body.into_inner()).await?;
+    let admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &admin_user,
+    )
+    .await?;
     Ok(Post::auth_response_accepted(json))
 }
 
@@ -165,6 +181,7 @@ async fn post_inner(
     context: &ApiContext,
     path_params: OrgSsosParams,
     json_new_sso: JsonNewSso,
+    admin_user: &AdminUser,
 ) -> Result<JsonSso, HttpError> {
     // Get the organization
     let query_organization =
@@ -197,8 +214,19 @@ async fn post_inner(
     let query_sso = QuerySso::from_uuid(conn_lock!(context), insert_sso.uuid).map_err(
         resource_not_found_err!(Sso, (&query_organization, &insert_sso)),
     )?;
+    let json_sso = query_sso.into_json();
+
+    InsertAuditEvent::organization(
+        admin_user.user(),
+        &query_organization,
+        AuditAction::Create,
+        AuditResource::Sso,
+        json_sso.uuid,
+    )
+    .after(&json_sso)
+    .insert(conn_lock!(context))?;
 
-    Ok(query_sso.into_json())
+    Ok(json_sso)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -277,26 +305,43 @@ pub async fn org_sso_delete(
     bearer_token: BearerToken,
     path_params: Path<OrgSsoParams>,
 ) -> Result<ResponseDeleted, HttpError> {
-    let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
-    delete_inner(rqctx.context(), path_params.into_inner()).await?;
+    let admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &admin_user).await?;
     Ok(Delete::auth_response_deleted())
 }
 
-async fn delete_inner(context: &ApiContext, path_params: OrgSsoParams) -> Result<(), HttpError> {
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: OrgSsoParams,
+    admin_user: &AdminUser,
+) -> Result<(), HttpError> {
     // Get the organization
     let query_organization =
         QueryOrganization::from_resource_id(conn_lock!(context), &path_params.organization)?;
+    let query_sso = QuerySso::belonging_to(&query_organization)
+        .filter(schema::sso::uuid.eq(path_params.sso))
+        .first::<QuerySso>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Sso,
+            (&query_organization, path_params.sso)
+        ))?;
+
+    diesel::delete(schema::sso::table.filter(schema::sso::id.eq(query_sso.id)))
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            Sso,
+            (&query_organization, path_params.sso)
+        ))?;
 
-    diesel::delete(
-        schema::sso::table
-            .filter(schema::sso::organization_id.eq(query_organization.id))
-            .filter(schema::sso::uuid.eq(path_params.sso)),
+    InsertAuditEvent::organization(
+        admin_user.user(),
+        &query_organization,
+        AuditAction::Delete,
+        AuditResource::Sso,
+        path_params.sso,
     )
-    .execute(conn_lock!(context))
-    .map_err(resource_conflict_err!(
-        Sso,
-        (&query_organization, path_params.sso)
-    ))?;
+    .before(&query_sso.into_json())
+    .insert(conn_lock!(context))?;
 
     Ok(())
 }
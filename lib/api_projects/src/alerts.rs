@@ -1,7 +1,11 @@
 use bencher_endpoint::{CorsResponse, Endpoint, Get, Patch, ResponseOk, TotalCount};
 use bencher_json::{
-    AlertUuid, JsonAlert, JsonAlerts, JsonDirection, JsonPagination, ProjectResourceId,
-    project::alert::{AlertStatus, JsonUpdateAlert},
+    AlertUuid, AuditAction, AuditResource, JsonAlert, JsonAlerts, JsonDirection, JsonPagination,
+    ProjectResourceId,
+    project::{
+        alert::{AlertStatus, JsonUpdateAlert},
+        webhook::WebhookEvent,
+    },
 };
 use bencher_rbac::project::Permission;
 use bencher_schema::{
@@ -9,9 +13,11 @@ use bencher_schema::{
     context::ApiContext,
     error::{resource_conflict_err, resource_not_found_err},
     model::{
+        audit::InsertAuditEvent,
         project::{
             QueryProject,
             threshold::alert::{QueryAlert, UpdateAlert},
+            webhook::QueryWebhook,
         },
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
@@ -24,6 +30,7 @@ use diesel::{
 use dropshot::{HttpError, Path, Query, RequestContext, TypedBody, endpoint};
 use schemars::JsonSchema;
 use serde::Deserialize;
+use slog::Logger;
 
 #[derive(Deserialize, JsonSchema)]
 pub struct ProjAlertsParams {
@@ -115,14 +122,17 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
-    let alerts = get_ls_query(&query_project, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Alert,
-            (&query_project, &pagination_params, &query_params)
-        ))?;
+    let alerts = order_ls_query(
+        get_ls_query(&query_project, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Alert,
+        (&query_project, &pagination_params, &query_params)
+    ))?;
 
     // Separate out these queries to prevent a deadlock when getting the conn_lock
     let mut json_alerts = Vec::with_capacity(alerts.len());
@@ -137,7 +147,7 @@ async fn get_ls_inner(
         }
     }
 
-    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
+    let total_count = get_ls_query(&query_project, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -151,7 +161,6 @@ async fn get_ls_inner(
 
 fn get_ls_query<'q>(
     query_project: &'q QueryProject,
-    pagination_params: &ProjAlertsPagination,
     query_params: &'q ProjAlertsQuery,
 ) -> BoxedQuery<'q> {
     let mut query = schema::alert::table
@@ -194,6 +203,15 @@ fn get_ls_query<'q>(
         );
     }
 
+    query.select(QueryAlert::as_select())
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: BoxedQuery<'q>,
+    pagination_params: &ProjAlertsPagination,
+) -> BoxedQuery<'q> {
     match pagination_params.order() {
         ProjAlertsSort::Created => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order((
@@ -224,13 +242,12 @@ fn get_ls_query<'q>(
             )),
         },
     }
-    .select(QueryAlert::as_select())
 }
 
 // TODO refactor out internal types
 type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
     'q,
-    diesel::helper_types::AsSelect<QueryAlert, diesel::sqlite::Sqlite>,
+    diesel::helper_types::AsSelect<QueryAlert, bencher_schema::context::DbBackend>,
     diesel::internal::table_macro::FromClause<
         diesel::helper_types::InnerJoinQuerySource<
             schema::alert::table,
@@ -277,7 +294,7 @@ type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
             >,
         >,
     >,
-    diesel::sqlite::Sqlite,
+    bencher_schema::context::DbBackend,
 >;
 
 #[derive(Deserialize, JsonSchema)]
@@ -366,6 +383,7 @@ pub async fn proj_alert_patch(
 ) -> Result<ResponseOk<JsonAlert>, HttpError> {
     let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
     let json = patch_inner(
+        &rqctx.log,
         rqctx.context(),
         path_params.into_inner(),
         body.into_inner(),
@@ -376,6 +394,7 @@ pub async fn proj_alert_patch(
 }
 
 async fn patch_inner(
+    log: &Logger,
     context: &ApiContext,
     path_params: ProjAlertParams,
     json_alert: JsonUpdateAlert,
@@ -392,14 +411,38 @@ async fn patch_inner(
 
     let query_alert =
         QueryAlert::from_uuid(conn_lock!(context), query_project.id, path_params.alert)?;
+    let before_alert = query_alert.clone().into_json(context).await?;
     let update_alert = UpdateAlert::from(json_alert.clone());
     diesel::update(schema::alert::table.filter(schema::alert::id.eq(query_alert.id)))
         .set(&update_alert)
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Alert, (&query_alert, &json_alert)))?;
 
+    // Only notify the webhooks if the status of the alert actually changed
+    if update_alert
+        .status
+        .is_some_and(|status| status != query_alert.status)
+    {
+        QueryWebhook::notify_alert(log, context, query_alert.id, WebhookEvent::AlertUpdated).await;
+    }
+
     let alert = QueryAlert::get(conn_lock!(context), query_alert.id)?;
 
     // Separate out this query to prevent a deadlock when getting the conn_lock
-    alert.into_json(context).await
+    let json_alert = alert.into_json(context).await?;
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Update,
+        AuditResource::Alert,
+        json_alert.uuid,
+    )?;
+    audit_event
+        .before(&before_alert)
+        .after(&json_alert)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_alert)
 }
@@ -0,0 +1,89 @@
+use bencher_endpoint::{CorsResponse, Endpoint, Get, ResponseOk, TotalCount};
+use bencher_json::{JsonAuditEvents, JsonAuditQuery, ProjectResourceId};
+use bencher_rbac::project::Permission;
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    model::{
+        audit::{AuditEventsPagination, AuditScope, QueryAuditEvent},
+        project::QueryProject,
+        user::auth::{AuthUser, BearerToken},
+    },
+};
+use dropshot::{HttpError, Path, Query, RequestContext, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjAuditParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/audit",
+    tags = ["projects", "audit"]
+}]
+pub async fn proj_audit_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjAuditParams>,
+    _pagination_params: Query<AuditEventsPagination>,
+    _query_params: Query<JsonAuditQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List audit events for a project
+///
+/// List all audit events for a project.
+/// The user must have `manage` permissions for the project to use this route.
+/// By default, the audit events are sorted in reverse chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of audit events.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/audit",
+    tags = ["projects", "audit"]
+}]
+pub async fn proj_audit_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjAuditParams>,
+    pagination_params: Query<AuditEventsPagination>,
+    query_params: Query<JsonAuditQuery>,
+) -> Result<ResponseOk<JsonAuditEvents>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+        query_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: ProjAuditParams,
+    pagination_params: AuditEventsPagination,
+    query_params: JsonAuditQuery,
+) -> Result<(JsonAuditEvents, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Manage,
+    )?;
+
+    let (json_audit_events, total_count) = QueryAuditEvent::list(
+        conn_lock!(context),
+        AuditScope::Project(query_project.uuid),
+        &pagination_params,
+        &query_params,
+    )?;
+    Ok((json_audit_events, total_count.try_into()?))
+}
@@ -3,8 +3,8 @@ use bencher_endpoint::{
     TotalCount,
 };
 use bencher_json::{
-    BenchmarkName, BenchmarkResourceId, JsonBenchmark, JsonBenchmarks, JsonDirection,
-    JsonPagination, ProjectResourceId, Search,
+    AuditAction, AuditResource, BenchmarkName, BenchmarkResourceId, JsonBenchmark, JsonBenchmarks,
+    JsonDirection, JsonPagination, ProjectResourceId, Search, TagSelectors,
     project::benchmark::{JsonNewBenchmark, JsonUpdateBenchmark},
 };
 use bencher_rbac::project::Permission;
@@ -13,9 +13,13 @@ use bencher_schema::{
     context::ApiContext,
     error::{resource_conflict_err, resource_not_found_err},
     model::{
+        audit::InsertAuditEvent,
         project::{
             QueryProject,
-            benchmark::{QueryBenchmark, UpdateBenchmark},
+            benchmark::{
+                QueryBenchmark, UpdateBenchmark,
+                tag::{InsertBenchmarkTag, QueryBenchmarkTag},
+            },
         },
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
@@ -51,6 +55,10 @@ pub struct ProjBenchmarksQuery {
     pub name: Option<BenchmarkName>,
     /// Search by benchmark name, slug, or UUID.
     pub search: Option<Search>,
+    /// Filter by benchmark tags.
+    /// A comma separated list of `key=value` tag selectors.
+    /// Only benchmarks that match every selector are returned.
+    pub tags: Option<TagSelectors>,
     /// If set to `true`, only returns archived benchmarks.
     /// If not set or set to `false`, only returns non-archived benchmarks.
     pub archived: Option<bool>,
@@ -118,22 +126,36 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
-    let benchmarks = get_ls_query(&query_project, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryBenchmark>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Benchmark,
-            (&query_project, &pagination_params, &query_params)
-        ))?;
+    let benchmarks = order_ls_query(
+        get_ls_query(&query_project, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryBenchmark>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Benchmark,
+        (&query_project, &pagination_params, &query_params)
+    ))?;
+
+    let benchmark_ids = benchmarks
+        .iter()
+        .map(|benchmark| benchmark.id)
+        .collect::<Vec<_>>();
+    let mut benchmark_tags = QueryBenchmarkTag::get_tags_for(conn_lock!(context), &benchmark_ids)?;
 
     // Drop connection lock before iterating
     let json_benchmarks = benchmarks
         .into_iter()
-        .map(|benchmark| benchmark.into_json_for_project(&query_project))
-        .collect();
+        .map(|benchmark| {
+            let tags = benchmark_tags.remove(&benchmark.id);
+            let mut json_benchmark = benchmark.into_json_for_project(&query_project);
+            json_benchmark.tags = tags;
+            json_benchmark
+        })
+        .collect::<Vec<_>>();
 
-    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
+    let total_count = get_ls_query(&query_project, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -142,14 +164,13 @@ async fn get_ls_inner(
         ))?
         .try_into()?;
 
-    Ok((json_benchmarks, total_count))
+    Ok((json_benchmarks.into(), total_count))
 }
 
 fn get_ls_query<'q>(
     query_project: &'q QueryProject,
-    pagination_params: &ProjBenchmarksPagination,
     query_params: &'q ProjBenchmarksQuery,
-) -> schema::benchmark::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+) -> schema::benchmark::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     let mut query = QueryBenchmark::belonging_to(&query_project).into_boxed();
 
     if let Some(name) = query_params.name.as_ref() {
@@ -163,6 +184,11 @@ fn get_ls_query<'q>(
                 .or(schema::benchmark::uuid.like(search)),
         );
     }
+    if let Some(tags) = query_params.tags.as_ref() {
+        for selector in tags.iter() {
+            query = query.filter(QueryBenchmarkTag::eq_selector(selector));
+        }
+    }
 
     if let Some(true) = query_params.archived {
         query = query.filter(schema::benchmark::archived.is_not_null());
@@ -170,6 +196,15 @@ fn get_ls_query<'q>(
         query = query.filter(schema::benchmark::archived.is_null());
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::benchmark::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &ProjBenchmarksPagination,
+) -> schema::benchmark::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         ProjBenchmarksSort::Name => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order(schema::benchmark::name.asc()),
@@ -219,9 +254,23 @@ async fn post_inner(
         Permission::Create,
     )?;
 
-    QueryBenchmark::create(context, query_project.id, json_benchmark)
-        .await
-        .map(|benchmark| benchmark.into_json_for_project(&query_project))
+    let json_benchmark = QueryBenchmark::create(context, query_project.id, json_benchmark)
+        .await?
+        .into_json_with_tags(conn_lock!(context), &query_project)?;
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Create,
+        AuditResource::Benchmark,
+        json_benchmark.uuid,
+    )?;
+    audit_event
+        .after(&json_benchmark)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_benchmark)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -287,14 +336,14 @@ async fn get_one_inner(
         auth_user,
     )?;
 
-    QueryBenchmark::belonging_to(&query_project)
+    let query_benchmark = QueryBenchmark::belonging_to(&query_project)
         .filter(QueryBenchmark::eq_resource_id(&path_params.benchmark))
         .first::<QueryBenchmark>(conn_lock!(context))
-        .map(|benchmark| benchmark.into_json_for_project(&query_project))
         .map_err(resource_not_found_err!(
             Benchmark,
             (&query_project, path_params.benchmark)
-        ))
+        ))?;
+    query_benchmark.into_json_with_tags(conn_lock!(context), &query_project)
 }
 
 /// Update a benchmark
@@ -343,6 +392,19 @@ async fn patch_inner(
         query_project.id,
         &path_params.benchmark,
     )?;
+    let audit_event = conn_lock!(context, |conn| InsertAuditEvent::project(
+        conn,
+        auth_user,
+        &query_project,
+        AuditAction::Update,
+        AuditResource::Benchmark,
+        query_benchmark.uuid,
+    )?
+    .before(
+        &query_benchmark
+            .clone()
+            .into_json_with_tags(conn, &query_project)?
+    ));
     let update_benchmark = UpdateBenchmark::from(json_benchmark.clone());
     diesel::update(schema::benchmark::table.filter(schema::benchmark::id.eq(query_benchmark.id)))
         .set(&update_benchmark)
@@ -351,10 +413,20 @@ async fn patch_inner(
             Benchmark,
             (&query_benchmark, &json_benchmark)
         ))?;
+    if let Some(tags) = json_benchmark.tags {
+        InsertBenchmarkTag::replace(conn_lock!(context), query_benchmark.id, tags)?;
+    }
+
+    let query_benchmark = QueryBenchmark::get(conn_lock!(context), query_benchmark.id)
+        .map_err(resource_not_found_err!(Benchmark, query_benchmark))?;
+    let json_benchmark =
+        query_benchmark.into_json_with_tags(conn_lock!(context), &query_project)?;
 
-    QueryBenchmark::get(conn_lock!(context), query_benchmark.id)
-        .map(|benchmark| benchmark.into_json_for_project(&query_project))
-        .map_err(resource_not_found_err!(Benchmark, query_benchmark))
+    audit_event
+        .after(&json_benchmark)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_benchmark)
 }
 
 /// Delete a benchmark
@@ -396,9 +468,23 @@ async fn delete_inner(
         query_project.id,
         &path_params.benchmark,
     )?;
+    let audit_event = conn_lock!(context, |conn| InsertAuditEvent::project(
+        conn,
+        auth_user,
+        &query_project,
+        AuditAction::Delete,
+        AuditResource::Benchmark,
+        query_benchmark.uuid,
+    )?
+    .before(
+        &query_benchmark
+            .clone()
+            .into_json_with_tags(conn, &query_project)?
+    ));
     diesel::delete(schema::benchmark::table.filter(schema::benchmark::id.eq(query_benchmark.id)))
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Benchmark, query_benchmark))?;
+    audit_event.insert(conn_lock!(context))?;
 
     Ok(())
 }
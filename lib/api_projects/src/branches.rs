@@ -3,8 +3,9 @@ use bencher_endpoint::{
     TotalCount,
 };
 use bencher_json::{
-    BranchName, BranchResourceId, HeadUuid, JsonBranch, JsonBranches, JsonDirection, JsonNewBranch,
-    JsonPagination, ProjectResourceId, Search, project::branch::JsonUpdateBranch,
+    AuditAction, AuditResource, BranchName, BranchResourceId, HeadUuid, JsonBranch, JsonBranches,
+    JsonDirection, JsonNewBranch, JsonPagination, ProjectResourceId, Search,
+    project::branch::JsonUpdateBranch,
 };
 use bencher_rbac::project::Permission;
 use bencher_schema::{
@@ -14,6 +15,7 @@ use bencher_schema::{
         BencherResource, resource_conflict_err, resource_not_found_err, resource_not_found_error,
     },
     model::{
+        audit::InsertAuditEvent,
         project::{
             QueryProject,
             branch::{QueryBranch, UpdateBranch, head::QueryHead},
@@ -120,14 +122,17 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
-    let branches = get_ls_query(&query_project, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryBranch>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Branch,
-            (&query_project, &pagination_params, &query_params)
-        ))?;
+    let branches = order_ls_query(
+        get_ls_query(&query_project, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryBranch>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Branch,
+        (&query_project, &pagination_params, &query_params)
+    ))?;
 
     // Separate out these queries to prevent a deadlock when getting the conn_lock
     let mut json_branches = Vec::with_capacity(branches.len());
@@ -142,7 +147,7 @@ async fn get_ls_inner(
         }
     }
 
-    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
+    let total_count = get_ls_query(&query_project, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -156,9 +161,8 @@ async fn get_ls_inner(
 
 fn get_ls_query<'q>(
     query_project: &'q QueryProject,
-    pagination_params: &ProjBranchesPagination,
     query_params: &'q ProjBranchesQuery,
-) -> schema::branch::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+) -> schema::branch::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     let mut query = QueryBranch::belonging_to(query_project).into_boxed();
 
     if let Some(name) = query_params.name.as_ref() {
@@ -179,6 +183,15 @@ fn get_ls_query<'q>(
         query = query.filter(schema::branch::archived.is_null());
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::branch::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &ProjBranchesPagination,
+) -> schema::branch::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         ProjBranchesSort::Name => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order(schema::branch::name.asc()),
@@ -230,9 +243,23 @@ async fn post_inner(
         Permission::Create,
     )?;
 
-    QueryBranch::create(log, context, query_project.id, json_branch)
+    let json_branch = QueryBranch::create(log, context, query_project.id, json_branch)
         .await?
-        .into_json_for_project(conn_lock!(context), &query_project)
+        .into_json_for_project(conn_lock!(context), &query_project)?;
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Create,
+        AuditResource::Branch,
+        json_branch.uuid,
+    )?;
+    audit_event
+        .after(&json_branch)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_branch)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -382,6 +409,19 @@ async fn patch_inner(
 
     let query_branch =
         QueryBranch::from_resource_id(conn_lock!(context), query_project.id, &path_params.branch)?;
+    let audit_event = conn_lock!(context, |conn| InsertAuditEvent::project(
+        conn,
+        auth_user,
+        &query_project,
+        AuditAction::Update,
+        AuditResource::Branch,
+        query_branch.uuid,
+    )?
+    .before(
+        &query_branch
+            .clone()
+            .into_json_for_project(conn, &query_project)?
+    ));
 
     let (query_branch, _query_head) = query_branch
         .update_start_point_if_changed(
@@ -401,11 +441,15 @@ async fn patch_inner(
             (&query_branch, &json_branch)
         ))?;
 
-    conn_lock!(context, |conn| QueryBranch::get(conn, query_branch.id)
+    let json_branch = conn_lock!(context, |conn| QueryBranch::get(conn, query_branch.id)
         .map_err(resource_not_found_err!(Branch, query_branch))
-        .and_then(
-            |branch| branch.into_json_for_project(conn, &query_project)
-        ))
+        .and_then(|branch| branch.into_json_for_project(conn, &query_project)))?;
+
+    audit_event
+        .after(&json_branch)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_branch)
 }
 
 /// Delete a branch
@@ -444,10 +488,24 @@ async fn delete_inner(
 
     let query_branch =
         QueryBranch::from_resource_id(conn_lock!(context), query_project.id, &path_params.branch)?;
+    let audit_event = conn_lock!(context, |conn| InsertAuditEvent::project(
+        conn,
+        auth_user,
+        &query_project,
+        AuditAction::Delete,
+        AuditResource::Branch,
+        query_branch.uuid,
+    )?
+    .before(
+        &query_branch
+            .clone()
+            .into_json_for_project(conn, &query_project)?
+    ));
 
     diesel::delete(schema::branch::table.filter(schema::branch::id.eq(query_branch.id)))
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Branch, query_branch))?;
+    audit_event.insert(conn_lock!(context))?;
 
     Ok(())
 }
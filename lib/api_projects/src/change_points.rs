@@ -0,0 +1,260 @@
+use bencher_endpoint::{CorsResponse, Endpoint, Get, ResponseOk, TotalCount};
+use bencher_json::{
+    ChangePointUuid, JsonChangePoint, JsonChangePoints, JsonDirection, JsonPagination,
+    ProjectResourceId, ThresholdUuid,
+};
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    error::resource_not_found_err,
+    model::{
+        project::{QueryProject, threshold::change_point::QueryChangePoint},
+        user::auth::{AuthUser, PubBearerToken},
+    },
+    schema,
+};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::{HttpError, Path, Query, RequestContext, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjChangePointsParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+pub type ProjChangePointsPagination = JsonPagination<ProjChangePointsSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjChangePointsSort {
+    /// Sort by change point creation date time.
+    #[default]
+    Created,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjChangePointsQuery {
+    /// Filter change points by the threshold that detected them.
+    /// If not set, returns change points for all thresholds.
+    pub threshold: Option<ThresholdUuid>,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/change-points",
+    tags = ["projects", "change_points"]
+}]
+pub async fn proj_change_points_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjChangePointsParams>,
+    _pagination_params: Query<ProjChangePointsPagination>,
+    _query_params: Query<ProjChangePointsQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List change points for a project
+///
+/// List all change points for a project.
+/// A change point is recorded when a threshold detects a lasting shift in performance.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+/// By default, the change points are sorted by creation date time in reverse chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of change points.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/change-points",
+    tags = ["projects", "change_points"]
+}]
+pub async fn proj_change_points_get(
+    rqctx: RequestContext<ApiContext>,
+    path_params: Path<ProjChangePointsParams>,
+    pagination_params: Query<ProjChangePointsPagination>,
+    query_params: Query<ProjChangePointsQuery>,
+) -> Result<ResponseOk<JsonChangePoints>, HttpError> {
+    let auth_user = AuthUser::new_pub(&rqctx).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        auth_user.as_ref(),
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+        query_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::response_ok_with_total_count(
+        json,
+        auth_user.is_some(),
+        total_count,
+    ))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: Option<&AuthUser>,
+    path_params: ProjChangePointsParams,
+    pagination_params: ProjChangePointsPagination,
+    query_params: ProjChangePointsQuery,
+) -> Result<(JsonChangePoints, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let change_points = order_ls_query(
+        get_ls_query(&query_project, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryChangePoint>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        ChangePoint,
+        (&query_project, &pagination_params, &query_params)
+    ))?;
+
+    // Separate out these queries to prevent a deadlock when getting the conn_lock
+    let mut json_change_points = Vec::with_capacity(change_points.len());
+    for change_point in change_points {
+        match change_point.into_json(context).await {
+            Ok(change_point) => json_change_points.push(change_point),
+            Err(err) => {
+                debug_assert!(false, "{err}");
+                #[cfg(feature = "sentry")]
+                sentry::capture_error(&err);
+            },
+        }
+    }
+
+    let total_count = get_ls_query(&query_project, &query_params)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            ChangePoint,
+            (&query_project, &pagination_params, &query_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_change_points.into(), total_count))
+}
+
+fn get_ls_query<'q>(
+    query_project: &'q QueryProject,
+    query_params: &'q ProjChangePointsQuery,
+) -> schema::change_point::BoxedQuery<'q, bencher_schema::context::DbBackend> {
+    let mut query = schema::change_point::table
+        .filter(
+            schema::change_point::threshold_id.eq_any(
+                schema::threshold::table
+                    .filter(schema::threshold::project_id.eq(query_project.id))
+                    .select(schema::threshold::id),
+            ),
+        )
+        .into_boxed();
+    if let Some(threshold) = query_params.threshold {
+        query = query.filter(
+            schema::change_point::threshold_id.eq_any(
+                schema::threshold::table
+                    .filter(schema::threshold::uuid.eq(threshold))
+                    .select(schema::threshold::id),
+            ),
+        );
+    }
+
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::change_point::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &ProjChangePointsPagination,
+) -> schema::change_point::BoxedQuery<'q, bencher_schema::context::DbBackend> {
+    match pagination_params.order() {
+        ProjChangePointsSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) => query.order((
+                schema::change_point::created.asc(),
+                schema::change_point::id.asc(),
+            )),
+            Some(JsonDirection::Desc) | None => query.order((
+                schema::change_point::created.desc(),
+                schema::change_point::id.desc(),
+            )),
+        },
+    }
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjChangePointParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+    /// The UUID for a change point.
+    pub change_point: ChangePointUuid,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/change-points/{change_point}",
+    tags = ["projects", "change_points"]
+}]
+pub async fn proj_change_point_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjChangePointParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// View a change point
+///
+/// View a change point for a project.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/change-points/{change_point}",
+    tags = ["projects", "change_points"]
+}]
+pub async fn proj_change_point_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjChangePointParams>,
+) -> Result<ResponseOk<JsonChangePoint>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(
+        rqctx.context(),
+        #[cfg(feature = "plus")]
+        rqctx.request.headers(),
+        bearer_token,
+    )
+    .await?;
+    let json = get_one_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjChangePointParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonChangePoint, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let change_point = QueryChangePoint::from_uuid(
+        conn_lock!(context),
+        query_project.id,
+        path_params.change_point,
+    )?;
+
+    change_point.into_json(context).await
+}
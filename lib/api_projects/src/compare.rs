@@ -0,0 +1,346 @@
+use std::collections::HashMap;
+
+use bencher_boundary::{MetricsComparison, MetricsData};
+use bencher_endpoint::{CorsResponse, Endpoint, Get, ResponseOk};
+use bencher_json::{
+    GitHash, JsonCompare, JsonCompareQuery, Model, ProjectResourceId,
+    project::compare::{
+        CompareVerdict, JsonCompareQueryParams, JsonCompareReport, JsonCompareResult,
+        JsonCompareSide, JsonCompareSummary,
+    },
+};
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    error::{bad_request_error, resource_not_found_err},
+    model::{
+        project::{
+            QueryProject,
+            benchmark::{BenchmarkId, QueryBenchmark},
+            branch::{
+                QueryBranch,
+                head::{HeadId, QueryHead},
+            },
+            measure::{MeasureId, QueryMeasure},
+            report::QueryReport,
+            testbed::{QueryTestbed, TestbedId},
+        },
+        user::auth::{AuthUser, PubBearerToken},
+    },
+    schema,
+};
+use diesel::{
+    BelongingToDsl as _, ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _,
+    SelectableHelper as _,
+};
+use dropshot::{HttpError, Path, Query, RequestContext, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use slog::Logger;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjCompareParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/compare",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_compare_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjCompareParams>,
+    _query_params: Query<JsonCompareQueryParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Compare two reports
+///
+/// Compare the performance metrics of a candidate report to a baseline report.
+/// Each side of the comparison is either a report, the most recent report for a branch head,
+/// or the most recent report for the current head of a branch.
+/// Every benchmark and measure in either report is compared,
+/// using all of the iterations in each report.
+/// The test and boundaries determine if a change is significant.
+/// The configured boundary side is a regression.
+/// Unless both boundaries are set, a significant change in the opposite direction is an improvement.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/compare",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_compare_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjCompareParams>,
+    query_params: Query<JsonCompareQueryParams>,
+) -> Result<ResponseOk<JsonCompare>, HttpError> {
+    // Second round of marshaling
+    let json_compare_query = query_params
+        .into_inner()
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let auth_user = AuthUser::from_pub_token(
+        rqctx.context(),
+        #[cfg(feature = "plus")]
+        rqctx.request.headers(),
+        bearer_token,
+    )
+    .await?;
+    let json = get_inner(
+        &rqctx.log,
+        rqctx.context(),
+        path_params.into_inner(),
+        json_compare_query,
+        auth_user.as_ref(),
+    )
+    .await?;
+    Ok(Get::response_ok(json, auth_user.is_some()))
+}
+
+async fn get_inner(
+    log: &Logger,
+    context: &ApiContext,
+    path_params: ProjCompareParams,
+    json_compare_query: JsonCompareQuery,
+    auth_user: Option<&AuthUser>,
+) -> Result<JsonCompare, HttpError> {
+    let project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+
+    let JsonCompareQuery {
+        baseline,
+        candidate,
+        testbed,
+        model,
+    } = json_compare_query;
+
+    let testbed_id = if let Some(testbed) = &testbed {
+        Some(QueryTestbed::from_name_id(conn_lock!(context), project.id, testbed)?.id)
+    } else {
+        None
+    };
+
+    let baseline_report = get_report(context, &project, baseline, testbed_id).await?;
+    let candidate_report = get_report(context, &project, candidate, testbed_id).await?;
+
+    let baseline_metrics = get_metrics(context, &baseline_report).await?;
+    let candidate_metrics = get_metrics(context, &candidate_report).await?;
+    let results = compare_results(log, &project, baseline_metrics, candidate_metrics, model);
+    let summary = JsonCompareSummary::new(results.iter().map(|result| result.verdict));
+
+    let Model {
+        test,
+        lower_boundary,
+        upper_boundary,
+        ..
+    } = model;
+    Ok(JsonCompare {
+        baseline: into_compare_report(context, &project, baseline_report).await?,
+        candidate: into_compare_report(context, &project, candidate_report).await?,
+        test,
+        lower_boundary,
+        upper_boundary,
+        results,
+        summary,
+    })
+}
+
+async fn get_report(
+    context: &ApiContext,
+    project: &QueryProject,
+    side: JsonCompareSide,
+    testbed_id: Option<TestbedId>,
+) -> Result<QueryReport, HttpError> {
+    match side {
+        JsonCompareSide::Report(report_uuid) => QueryReport::belonging_to(project)
+            .filter(schema::report::uuid.eq(report_uuid))
+            .first::<QueryReport>(conn_lock!(context))
+            .map_err(resource_not_found_err!(Report, (project, report_uuid))),
+        JsonCompareSide::Head { head, hash } => {
+            let query_head = QueryHead::from_uuid(conn_lock!(context), project.id, head)?;
+            get_latest_report(context, project, query_head.id, hash.as_ref(), testbed_id).await
+        },
+        JsonCompareSide::Branch { branch, hash } => {
+            let query_branch = QueryBranch::from_name_id(conn_lock!(context), project.id, &branch)?;
+            get_latest_report(
+                context,
+                project,
+                query_branch.head_id()?,
+                hash.as_ref(),
+                testbed_id,
+            )
+            .await
+        },
+    }
+}
+
+// Get the most recent report for a branch head.
+// Like the perf query, the `head_version` table is used
+// so that the reports from a cloned start point are included.
+async fn get_latest_report(
+    context: &ApiContext,
+    project: &QueryProject,
+    head_id: HeadId,
+    hash: Option<&GitHash>,
+    testbed_id: Option<TestbedId>,
+) -> Result<QueryReport, HttpError> {
+    let mut query = schema::report::table
+        .inner_join(schema::version::table.inner_join(schema::head_version::table))
+        .filter(schema::report::project_id.eq(project.id))
+        .filter(schema::head_version::head_id.eq(head_id))
+        .into_boxed();
+    if let Some(hash) = hash {
+        query = query.filter(schema::version::hash.eq(hash));
+    }
+    if let Some(testbed_id) = testbed_id {
+        query = query.filter(schema::report::testbed_id.eq(testbed_id));
+    }
+    query
+        .order((
+            schema::version::number.desc(),
+            schema::report::start_time.desc(),
+        ))
+        .select(QueryReport::as_select())
+        .first::<QueryReport>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Report,
+            (project, head_id, hash, testbed_id)
+        ))
+}
+
+type CompareMetrics = HashMap<(BenchmarkId, MeasureId), (QueryBenchmark, QueryMeasure, Vec<f64>)>;
+
+async fn get_metrics(
+    context: &ApiContext,
+    report: &QueryReport,
+) -> Result<CompareMetrics, HttpError> {
+    let metrics = schema::metric::table
+        .inner_join(schema::report_benchmark::table.inner_join(schema::benchmark::table))
+        .inner_join(schema::measure::table)
+        .filter(schema::report_benchmark::report_id.eq(report.id))
+        .order(schema::report_benchmark::iteration)
+        .select((
+            QueryBenchmark::as_select(),
+            QueryMeasure::as_select(),
+            schema::metric::value,
+        ))
+        .load::<(QueryBenchmark, QueryMeasure, f64)>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Metric, report.uuid))?;
+
+    let mut compare_metrics = CompareMetrics::new();
+    for (benchmark, measure, value) in metrics {
+        compare_metrics
+            .entry((benchmark.id, measure.id))
+            .or_insert_with(|| (benchmark, measure, Vec::new()))
+            .2
+            .push(value);
+    }
+    Ok(compare_metrics)
+}
+
+fn compare_results(
+    log: &Logger,
+    project: &QueryProject,
+    mut baseline_metrics: CompareMetrics,
+    candidate_metrics: CompareMetrics,
+    model: Model,
+) -> Vec<JsonCompareResult> {
+    let mut permutations = Vec::with_capacity(baseline_metrics.len() + candidate_metrics.len());
+    for (key, (benchmark, measure, candidate)) in candidate_metrics {
+        let baseline = baseline_metrics
+            .remove(&key)
+            .map(|(_, _, baseline)| baseline)
+            .unwrap_or_default();
+        permutations.push((benchmark, measure, baseline, candidate));
+    }
+    for (benchmark, measure, baseline) in baseline_metrics.into_values() {
+        permutations.push((benchmark, measure, baseline, Vec::new()));
+    }
+    permutations.sort_by(
+        |(lhs_benchmark, lhs_measure, ..), (rhs_benchmark, rhs_measure, ..)| {
+            (lhs_benchmark.name.as_ref(), lhs_measure.name.as_ref())
+                .cmp(&(rhs_benchmark.name.as_ref(), rhs_measure.name.as_ref()))
+        },
+    );
+
+    permutations
+        .into_iter()
+        .map(|(benchmark, measure, baseline, candidate)| {
+            let baseline = MetricsData { data: baseline };
+            let candidate = MetricsData { data: candidate };
+            // If the comparison can not be made, such as there being too little data
+            // for the test, then the change is not considered significant.
+            let verdict = MetricsComparison::new(log, &baseline, &candidate, model).map_or_else(
+                |e| {
+                    slog::warn!(
+                        log,
+                        "Failed to compare {} {}: {e}",
+                        benchmark.name,
+                        measure.name
+                    );
+                    CompareVerdict::Unchanged
+                },
+                |comparison| comparison.verdict,
+            );
+            let baseline = mean(&baseline.data);
+            let candidate = mean(&candidate.data);
+            let delta = baseline
+                .zip(candidate)
+                .map(|(baseline, candidate)| candidate - baseline);
+            let percent = baseline.zip(delta).and_then(|(baseline, delta)| {
+                (baseline != 0.0).then(|| delta / baseline.abs() * 100.0)
+            });
+            JsonCompareResult {
+                benchmark: benchmark.into_json_for_project(project),
+                measure: measure.into_json_for_project(project),
+                baseline,
+                candidate,
+                delta,
+                percent,
+                verdict,
+            }
+        })
+        .collect()
+}
+
+fn mean(data: &[f64]) -> Option<f64> {
+    #[expect(clippy::cast_precision_loss)]
+    (!data.is_empty()).then(|| data.iter().sum::<f64>() / data.len() as f64)
+}
+
+async fn into_compare_report(
+    context: &ApiContext,
+    project: &QueryProject,
+    report: QueryReport,
+) -> Result<JsonCompareReport, HttpError> {
+    let QueryReport {
+        uuid,
+        head_id,
+        version_id,
+        testbed_id,
+        start_time,
+        end_time,
+        ..
+    } = report;
+    let branch = QueryBranch::get_json_for_report(context, project, head_id, version_id).await?;
+    let testbed =
+        QueryTestbed::get(conn_lock!(context), testbed_id)?.into_json_for_project(project);
+    Ok(JsonCompareReport {
+        uuid,
+        branch,
+        testbed,
+        start_time,
+        end_time,
+    })
+}
@@ -0,0 +1,67 @@
+use bencher_endpoint::{CorsResponse, Endpoint, Get, ResponseOk};
+use bencher_json::{JsonProjectExport, ProjectResourceId};
+use bencher_rbac::project::Permission;
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    model::{
+        project::QueryProject,
+        user::auth::{AuthUser, BearerToken},
+    },
+};
+use dropshot::{HttpError, Path, RequestContext, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjExportParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/export",
+    tags = ["projects"]
+}]
+pub async fn proj_export_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjExportParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Export a project
+///
+/// Export all of the data for a project as a versioned archive.
+/// The archive can be imported into an organization on another Bencher server.
+/// The user must have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path = "/v0/projects/{project}/export",
+    tags = ["projects"]
+}]
+pub async fn proj_export_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjExportParams>,
+) -> Result<ResponseOk<JsonProjectExport>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjExportParams,
+    auth_user: &AuthUser,
+) -> Result<JsonProjectExport, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+    query_project.export(conn_lock!(context))
+}
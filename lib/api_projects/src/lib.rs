@@ -1,21 +1,30 @@
 mod alerts;
 mod allowed;
+mod audit;
 mod benchmarks;
 mod branches;
+mod change_points;
+mod compare;
+mod export;
 mod measures;
+mod members;
 mod metrics;
+mod openmetrics;
 mod perf;
 mod plots;
 mod projects;
 mod reports;
+mod retention;
 mod testbeds;
 mod thresholds;
+mod webhooks;
 
 mod macros;
 
 pub struct Api;
 
 impl bencher_endpoint::Registrar for Api {
+    #[expect(clippy::too_many_lines)]
     fn register(
         api_description: &mut dropshot::ApiDescription<bencher_schema::ApiContext>,
         http_options: bool,
@@ -38,6 +47,12 @@ impl bencher_endpoint::Registrar for Api {
         }
         api_description.register(allowed::proj_allowed_get)?;
 
+        // Project Export
+        if http_options {
+            api_description.register(export::proj_export_options)?;
+        }
+        api_description.register(export::proj_export_get)?;
+
         // Reports
         if http_options {
             api_description.register(reports::proj_reports_options)?;
@@ -60,6 +75,18 @@ impl bencher_endpoint::Registrar for Api {
         }
         api_description.register(perf::img::proj_perf_img_get)?;
 
+        // Perf Export
+        if http_options {
+            api_description.register(perf::export::proj_perf_export_options)?;
+        }
+        api_description.register(perf::export::proj_perf_export_get)?;
+
+        // Compare
+        if http_options {
+            api_description.register(compare::proj_compare_options)?;
+        }
+        api_description.register(compare::proj_compare_get)?;
+
         // Plots
         if http_options {
             api_description.register(plots::proj_plots_options)?;
@@ -121,6 +148,12 @@ impl bencher_endpoint::Registrar for Api {
         }
         api_description.register(metrics::proj_metric_get)?;
 
+        // OpenMetrics
+        if http_options {
+            api_description.register(openmetrics::proj_openmetrics_options)?;
+        }
+        api_description.register(openmetrics::proj_openmetrics_get)?;
+
         // Thresholds
         if http_options {
             api_description.register(thresholds::proj_thresholds_options)?;
@@ -141,6 +174,50 @@ impl bencher_endpoint::Registrar for Api {
         api_description.register(alerts::proj_alert_get)?;
         api_description.register(alerts::proj_alert_patch)?;
 
+        // Threshold Change Points
+        if http_options {
+            api_description.register(change_points::proj_change_points_options)?;
+            api_description.register(change_points::proj_change_point_options)?;
+        }
+        api_description.register(change_points::proj_change_points_get)?;
+        api_description.register(change_points::proj_change_point_get)?;
+
+        // Retention
+        if http_options {
+            api_description.register(retention::proj_retention_options)?;
+            api_description.register(retention::proj_retention_dry_run_options)?;
+        }
+        api_description.register(retention::proj_retention_get)?;
+        api_description.register(retention::proj_retention_put)?;
+        api_description.register(retention::proj_retention_dry_run_get)?;
+
+        // Audit Log
+        if http_options {
+            api_description.register(audit::proj_audit_options)?;
+        }
+        api_description.register(audit::proj_audit_get)?;
+
+        // Custom Role Members
+        if http_options {
+            api_description.register(members::proj_members_options)?;
+            api_description.register(members::proj_member_options)?;
+        }
+        api_description.register(members::proj_members_get)?;
+        api_description.register(members::proj_member_get)?;
+        api_description.register(members::proj_member_put)?;
+        api_description.register(members::proj_member_delete)?;
+
+        // Webhooks
+        if http_options {
+            api_description.register(webhooks::proj_webhooks_options)?;
+            api_description.register(webhooks::proj_webhook_options)?;
+        }
+        api_description.register(webhooks::proj_webhooks_get)?;
+        api_description.register(webhooks::proj_webhook_post)?;
+        api_description.register(webhooks::proj_webhook_get)?;
+        api_description.register(webhooks::proj_webhook_patch)?;
+        api_description.register(webhooks::proj_webhook_delete)?;
+
         Ok(())
     }
 }
@@ -3,8 +3,9 @@ use bencher_endpoint::{
     TotalCount,
 };
 use bencher_json::{
-    JsonDirection, JsonMeasure, JsonMeasures, JsonNewMeasure, JsonPagination, MeasureResourceId,
-    ProjectResourceId, ResourceName, Search, project::measure::JsonUpdateMeasure,
+    AuditAction, AuditResource, JsonDirection, JsonMeasure, JsonMeasures, JsonNewMeasure,
+    JsonPagination, MeasureResourceId, ProjectResourceId, ResourceName, Search,
+    project::measure::JsonUpdateMeasure,
 };
 use bencher_rbac::project::Permission;
 use bencher_schema::{
@@ -12,6 +13,7 @@ use bencher_schema::{
     context::ApiContext,
     error::{resource_conflict_err, resource_not_found_err},
     model::{
+        audit::InsertAuditEvent,
         project::{
             QueryProject,
             measure::{QueryMeasure, UpdateMeasure},
@@ -117,14 +119,17 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
-    let measures = get_ls_query(&query_project, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryMeasure>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Measure,
-            (&query_project, &pagination_params, &query_params)
-        ))?;
+    let measures = order_ls_query(
+        get_ls_query(&query_project, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryMeasure>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Measure,
+        (&query_project, &pagination_params, &query_params)
+    ))?;
 
     // Drop connection lock before iterating
     let json_measures = measures
@@ -132,7 +137,7 @@ async fn get_ls_inner(
         .map(|measure| measure.into_json_for_project(&query_project))
         .collect();
 
-    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
+    let total_count = get_ls_query(&query_project, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -146,9 +151,8 @@ async fn get_ls_inner(
 
 fn get_ls_query<'q>(
     query_project: &'q QueryProject,
-    pagination_params: &ProjMeasuresPagination,
     query_params: &'q ProjMeasuresQuery,
-) -> schema::measure::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+) -> schema::measure::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     let mut query = QueryMeasure::belonging_to(&query_project).into_boxed();
 
     if let Some(name) = query_params.name.as_ref() {
@@ -169,6 +173,15 @@ fn get_ls_query<'q>(
         query = query.filter(schema::measure::archived.is_null());
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::measure::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &ProjMeasuresPagination,
+) -> schema::measure::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         ProjMeasuresSort::Name => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order(schema::measure::name.asc()),
@@ -218,9 +231,23 @@ async fn post_inner(
         Permission::Create,
     )?;
 
-    QueryMeasure::create(context, query_project.id, json_measure)
-        .await
-        .map(|measure| measure.into_json_for_project(&query_project))
+    let json_measure = QueryMeasure::create(context, query_project.id, json_measure)
+        .await?
+        .into_json_for_project(&query_project);
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Create,
+        AuditResource::Measure,
+        json_measure.uuid,
+    )?;
+    audit_event
+        .after(&json_measure)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_measure)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -342,6 +369,15 @@ async fn patch_inner(
         query_project.id,
         &path_params.measure,
     )?;
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Update,
+        AuditResource::Measure,
+        query_measure.uuid,
+    )?
+    .before(&query_measure.clone().into_json_for_project(&query_project));
     let update_measure = UpdateMeasure::from(json_measure.clone());
     diesel::update(schema::measure::table.filter(schema::measure::id.eq(query_measure.id)))
         .set(&update_measure)
@@ -351,9 +387,15 @@ async fn patch_inner(
             (&query_measure, &json_measure)
         ))?;
 
-    QueryMeasure::get(conn_lock!(context), query_measure.id)
+    let json_measure = QueryMeasure::get(conn_lock!(context), query_measure.id)
         .map(|measure| measure.into_json_for_project(&query_project))
-        .map_err(resource_not_found_err!(Measure, query_measure))
+        .map_err(resource_not_found_err!(Measure, query_measure))?;
+
+    audit_event
+        .after(&json_measure)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_measure)
 }
 
 /// Delete a measure
@@ -395,10 +437,20 @@ async fn delete_inner(
         query_project.id,
         &path_params.measure,
     )?;
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Delete,
+        AuditResource::Measure,
+        query_measure.uuid,
+    )?
+    .before(&query_measure.clone().into_json_for_project(&query_project));
 
     diesel::delete(schema::measure::table.filter(schema::measure::id.eq(query_measure.id)))
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Measure, query_measure))?;
+    audit_event.insert(conn_lock!(context))?;
 
     Ok(())
 }
@@ -0,0 +1,381 @@
+use bencher_endpoint::{
+    CorsResponse, Delete, Endpoint, Get, Put, ResponseDeleted, ResponseOk, TotalCount,
+};
+use bencher_json::{
+    AuditAction, AuditResource, JsonDirection, JsonPagination, JsonProjectMember,
+    JsonProjectMembers, JsonUpdateProjectMember, ProjectResourceId, UserResourceId,
+};
+use bencher_rbac::project::Permission;
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        audit::InsertAuditEvent,
+        organization::{QueryOrganization, custom_role::QueryCustomRole},
+        project::{
+            QueryProject,
+            project_member::{InsertProjectMember, QueryProjectMember},
+        },
+        user::{
+            QueryUser,
+            auth::{AuthUser, BearerToken},
+        },
+    },
+    schema,
+};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::{HttpError, Path, Query, RequestContext, TypedBody, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
+    'q,
+    (
+        diesel::sql_types::Text,
+        diesel::sql_types::Text,
+        diesel::sql_types::Text,
+        diesel::sql_types::Text,
+        diesel::sql_types::Text,
+        diesel::sql_types::BigInt,
+        diesel::sql_types::BigInt,
+    ),
+    diesel::internal::table_macro::FromClause<
+        diesel::helper_types::InnerJoinQuerySource<
+            diesel::helper_types::InnerJoinQuerySource<
+                schema::project_member::table,
+                schema::user::table,
+            >,
+            schema::custom_role::table,
+        >,
+    >,
+    bencher_schema::context::DbBackend,
+>;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjMembersParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+pub type ProjMembersPagination = JsonPagination<ProjMembersSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjMembersSort {
+    /// Sort by user name.
+    #[default]
+    Name,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/members",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_members_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjMembersParams>,
+    _pagination_params: Query<ProjMembersPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List custom role members for a project
+///
+/// List all of the users that have been assigned a custom role for a project.
+/// The user must have `view_role` permissions for the project.
+/// By default, the members are sorted in alphabetical order by name.
+/// The HTTP response header `X-Total-Count` contains the total number of members.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/members",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_members_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjMembersParams>,
+    pagination_params: Query<ProjMembersPagination>,
+) -> Result<ResponseOk<JsonProjectMembers>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: ProjMembersParams,
+    pagination_params: ProjMembersPagination,
+) -> Result<(JsonProjectMembers, TotalCount), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::ViewRole,
+    )?;
+
+    let members = order_ls_query(get_ls_query(&query_project), &pagination_params)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryProjectMember>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            ProjectMember,
+            (&query_project, &pagination_params)
+        ))?;
+
+    let json_members = members
+        .into_iter()
+        .map(QueryProjectMember::into_json)
+        .collect::<Vec<_>>();
+
+    let total_count = get_ls_query(&query_project)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            ProjectMember,
+            (&query_project, &pagination_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_members.into(), total_count))
+}
+
+fn get_ls_query<'q>(query_project: &QueryProject) -> BoxedQuery<'q> {
+    schema::project_member::table
+        .inner_join(schema::user::table)
+        .inner_join(schema::custom_role::table)
+        .filter(schema::project_member::project_id.eq(query_project.id))
+        .select((
+            schema::user::uuid,
+            schema::user::name,
+            schema::user::slug,
+            schema::user::email,
+            schema::custom_role::uuid,
+            schema::project_member::created,
+            schema::project_member::modified,
+        ))
+        .into_boxed()
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: BoxedQuery<'q>,
+    pagination_params: &ProjMembersPagination,
+) -> BoxedQuery<'q> {
+    match pagination_params.order() {
+        ProjMembersSort::Name => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => query.order(schema::user::name.asc()),
+            Some(JsonDirection::Desc) => query.order(schema::user::name.desc()),
+        },
+    }
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjMemberParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+    /// The slug or UUID for a user.
+    pub user: UserResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/members/{user}",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_member_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjMemberParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Put.into(), Delete.into()]))
+}
+
+/// View a custom role member for a project
+///
+/// View the custom role assigned to a user for a project.
+/// The user must have `view_role` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/members/{user}",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_member_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjMemberParams>,
+) -> Result<ResponseOk<JsonProjectMember>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjMemberParams,
+    auth_user: &AuthUser,
+) -> Result<JsonProjectMember, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::ViewRole,
+    )?;
+    let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
+
+    QueryProjectMember::get(conn_lock!(context), &query_project, query_user.id)
+        .map(QueryProjectMember::into_json)
+}
+
+/// Assign a custom role to a project member
+///
+/// Assign a custom role to a user for a project,
+/// replacing any custom role they already have for the project.
+/// The custom role must belong to the organization that owns the project.
+/// The user must have `edit_role` permissions for the project.
+#[endpoint {
+    method = PUT,
+    path =  "/v0/projects/{project}/members/{user}",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_member_put(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjMemberParams>,
+    body: TypedBody<JsonUpdateProjectMember>,
+) -> Result<ResponseOk<JsonProjectMember>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = put_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Put::auth_response_ok(json))
+}
+
+async fn put_inner(
+    context: &ApiContext,
+    path_params: ProjMemberParams,
+    json_member: JsonUpdateProjectMember,
+    auth_user: &AuthUser,
+) -> Result<JsonProjectMember, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::EditRole,
+    )?;
+    let query_organization =
+        QueryOrganization::get(conn_lock!(context), query_project.organization_id)?;
+    let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
+
+    let query_role =
+        QueryCustomRole::get_with_uuid(conn_lock!(context), &query_organization, json_member.role)?;
+
+    let before_member = QueryProjectMember::get(conn_lock!(context), &query_project, query_user.id)
+        .ok()
+        .map(QueryProjectMember::into_json);
+
+    InsertProjectMember::new(query_user.id, query_project.id, query_role.id)
+        .upsert(conn_lock!(context))?;
+
+    let json_member =
+        QueryProjectMember::get(conn_lock!(context), &query_project, query_user.id)?.into_json();
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        if before_member.is_some() {
+            AuditAction::Update
+        } else {
+            AuditAction::Create
+        },
+        AuditResource::ProjectMember,
+        query_user.uuid,
+    )?;
+    let audit_event = if let Some(before_member) = &before_member {
+        audit_event.before(before_member)
+    } else {
+        audit_event
+    };
+    audit_event
+        .after(&json_member)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_member)
+}
+
+/// Remove a custom role from a project member
+///
+/// Remove the custom role assigned to a user for a project.
+/// The user keeps any permissions granted by their organization or project role.
+/// The user must have `delete_role` permissions for the project.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/members/{user}",
+    tags = ["projects", "members"]
+}]
+pub async fn proj_member_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjMemberParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjMemberParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::DeleteRole,
+    )?;
+    let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
+    let before_member =
+        QueryProjectMember::get(conn_lock!(context), &query_project, query_user.id)?.into_json();
+
+    diesel::delete(
+        schema::project_member::table
+            .filter(schema::project_member::project_id.eq(query_project.id))
+            .filter(schema::project_member::user_id.eq(query_user.id)),
+    )
+    .execute(conn_lock!(context))
+    .map_err(resource_conflict_err!(
+        ProjectMember,
+        (&query_project, &query_user)
+    ))?;
+
+    InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Delete,
+        AuditResource::ProjectMember,
+        query_user.uuid,
+    )?
+    .before(&before_member)
+    .insert(conn_lock!(context))?;
+
+    Ok(())
+}
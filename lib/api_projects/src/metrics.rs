@@ -144,6 +144,7 @@ async fn get_one_inner(
                     schema::threshold::model_id,
                     schema::threshold::created,
                     schema::threshold::modified,
+                    schema::threshold::benchmark_tags,
                 ),
                 (
                     schema::model::id,
@@ -0,0 +1,365 @@
+use std::{collections::HashSet, fmt};
+
+use bencher_endpoint::{CorsResponse, Endpoint, Get};
+use bencher_json::{DateTime, ProjectResourceId, project::alert::AlertStatus};
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    error::{forbidden_error, resource_not_found_err},
+    model::{
+        project::{
+            QueryProject, benchmark::QueryBenchmark, branch::QueryBranch, measure::QueryMeasure,
+            metric_boundary::QueryMetricBoundary, testbed::QueryTestbed,
+        },
+        user::auth::{AuthUser, PubBearerToken},
+    },
+    schema, view,
+};
+use diesel::{
+    ExpressionMethods as _, JoinOnDsl as _, NullableExpressionMethods as _, QueryDsl as _,
+    RunQueryDsl as _, SelectableHelper as _,
+};
+use dropshot::{Body, HttpError, Path, RequestContext, endpoint};
+use http::Response;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+const OPENMETRICS_CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjOpenMetricsParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/openmetrics",
+    tags = ["projects", "metrics"]
+}]
+pub async fn proj_openmetrics_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjOpenMetricsParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Scrape the latest project metrics
+///
+/// Scrape the latest metrics for a project in the `OpenMetrics` text format.
+/// There is a gauge for the latest metric value, boundary limits, and alert state
+/// for every branch, testbed, benchmark, and measure on the current branch heads.
+/// Archived branches, testbeds, benchmarks, and measures are excluded.
+/// The `OpenMetrics` endpoint must be enabled for the project.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/openmetrics",
+    tags = ["projects", "metrics"]
+}]
+pub async fn proj_openmetrics_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjOpenMetricsParams>,
+) -> Result<Response<Body>, HttpError> {
+    let auth_user = AuthUser::from_pub_token(
+        rqctx.context(),
+        #[cfg(feature = "plus")]
+        rqctx.request.headers(),
+        bearer_token,
+    )
+    .await?;
+    let open_metrics = get_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        auth_user.as_ref(),
+    )
+    .await?;
+
+    Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, OPENMETRICS_CONTENT_TYPE)
+        .header(http::header::CACHE_CONTROL, "private, max-age=0, no-cache")
+        .body(open_metrics.to_string().into())
+        .map_err(Into::into)
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjOpenMetricsParams,
+    auth_user: Option<&AuthUser>,
+) -> Result<OpenMetrics, HttpError> {
+    let query_project = QueryProject::is_allowed_public(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+    )?;
+    if !query_project.openmetrics {
+        return Err(forbidden_error(format!(
+            "The OpenMetrics endpoint is not enabled for this project ({}). Enable it with `bencher project update --openmetrics true`.",
+            query_project.slug
+        )));
+    }
+
+    let rows = view::metric_boundary::table
+        .inner_join(
+            schema::report_benchmark::table.inner_join(
+                schema::report::table
+                    .inner_join(schema::version::table
+                        .inner_join(schema::head_version::table
+                            .inner_join(schema::head::table
+                                .on(schema::head_version::head_id.eq(schema::head::id)),
+                            )
+                            .inner_join(schema::branch::table.on(schema::head::branch_id.eq(schema::branch::id))),
+                        ),
+                    )
+                    .inner_join(schema::testbed::table)
+            )
+            .inner_join(schema::benchmark::table)
+        )
+        .inner_join(schema::measure::table)
+        // Only use the current, non-replaced head for each branch
+        .filter(schema::branch::head_id.eq(schema::head::id.nullable()))
+        // Make sure that the project is the same for all dimensions
+        .filter(schema::branch::project_id.eq(query_project.id))
+        .filter(schema::testbed::project_id.eq(query_project.id))
+        .filter(schema::benchmark::project_id.eq(query_project.id))
+        .filter(schema::measure::project_id.eq(query_project.id))
+        .filter(schema::branch::archived.is_null())
+        .filter(schema::testbed::archived.is_null())
+        .filter(schema::benchmark::archived.is_null())
+        .filter(schema::measure::archived.is_null())
+        // There may or may not be an alert for any given boundary
+        .left_join(schema::alert::table.on(view::metric_boundary::boundary_id.eq(schema::alert::boundary_id.nullable())))
+        // Order by the newest version first, so the first metric for each dimension is the latest
+        .order((
+            schema::version::number.desc(),
+            schema::report::start_time.desc(),
+            schema::report_benchmark::iteration.desc(),
+        ))
+        .select((
+            QueryBranch::as_select(),
+            QueryTestbed::as_select(),
+            QueryBenchmark::as_select(),
+            QueryMeasure::as_select(),
+            schema::report::start_time,
+            QueryMetricBoundary::as_select(),
+            schema::alert::status.nullable(),
+        ))
+        .load::<OpenMetricsQuery>(conn_lock!(context))
+        .map_err(resource_not_found_err!(Metric, query_project))?;
+
+    let mut dimensions = HashSet::new();
+    let mut open_metrics = OpenMetrics::default();
+    for row in rows {
+        let (branch, testbed, benchmark, measure, ..) = &row;
+        if dimensions.insert((branch.id, testbed.id, benchmark.id, measure.id)) {
+            open_metrics.push(&query_project, row);
+        }
+    }
+    Ok(open_metrics)
+}
+
+type OpenMetricsQuery = (
+    QueryBranch,
+    QueryTestbed,
+    QueryBenchmark,
+    QueryMeasure,
+    DateTime,
+    QueryMetricBoundary,
+    Option<AlertStatus>,
+);
+
+type Sample = (String, f64);
+
+#[derive(Default)]
+struct OpenMetrics {
+    value: Vec<Sample>,
+    lower_value: Vec<Sample>,
+    upper_value: Vec<Sample>,
+    baseline: Vec<Sample>,
+    lower_limit: Vec<Sample>,
+    upper_limit: Vec<Sample>,
+    alert: Vec<Sample>,
+    timestamp: Vec<Sample>,
+}
+
+impl OpenMetrics {
+    fn push(
+        &mut self,
+        project: &QueryProject,
+        (branch, testbed, benchmark, measure, start_time, metric_boundary, alert_status): OpenMetricsQuery,
+    ) {
+        let labels = format!(
+            "project=\"{project}\",branch=\"{branch}\",testbed=\"{testbed}\",benchmark=\"{benchmark}\",measure=\"{measure}\"",
+            project = escape_label(project.slug.as_ref()),
+            branch = escape_label(branch.name.as_ref()),
+            testbed = escape_label(testbed.name.as_ref()),
+            benchmark = escape_label(benchmark.name.as_ref()),
+            measure = escape_label(measure.name.as_ref()),
+        );
+
+        #[expect(clippy::cast_precision_loss)]
+        self.timestamp
+            .push((labels.clone(), start_time.timestamp() as f64));
+
+        let (metric, boundary) = QueryMetricBoundary::split(metric_boundary);
+        self.value.push((labels.clone(), metric.value));
+        if let Some(lower_value) = metric.lower_value {
+            self.lower_value.push((labels.clone(), lower_value));
+        }
+        if let Some(upper_value) = metric.upper_value {
+            self.upper_value.push((labels.clone(), upper_value));
+        }
+
+        if let Some(boundary) = boundary {
+            if let Some(baseline) = boundary.baseline {
+                self.baseline.push((labels.clone(), baseline));
+            }
+            if let Some(lower_limit) = boundary.lower_limit {
+                self.lower_limit.push((labels.clone(), lower_limit));
+            }
+            if let Some(upper_limit) = boundary.upper_limit {
+                self.upper_limit.push((labels.clone(), upper_limit));
+            }
+            let is_active = matches!(alert_status, Some(AlertStatus::Active));
+            self.alert.push((labels, if is_active { 1.0 } else { 0.0 }));
+        }
+    }
+}
+
+impl fmt::Display for OpenMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let families = [
+            (
+                "bencher_metric_value",
+                "The latest metric value.",
+                &self.value,
+            ),
+            (
+                "bencher_metric_lower_value",
+                "The lower value of the latest metric.",
+                &self.lower_value,
+            ),
+            (
+                "bencher_metric_upper_value",
+                "The upper value of the latest metric.",
+                &self.upper_value,
+            ),
+            (
+                "bencher_boundary_baseline",
+                "The threshold boundary baseline for the latest metric.",
+                &self.baseline,
+            ),
+            (
+                "bencher_boundary_lower_limit",
+                "The threshold boundary lower limit for the latest metric.",
+                &self.lower_limit,
+            ),
+            (
+                "bencher_boundary_upper_limit",
+                "The threshold boundary upper limit for the latest metric.",
+                &self.upper_limit,
+            ),
+            (
+                "bencher_alert_active",
+                "Whether the latest metric has an active alert (1) or not (0).",
+                &self.alert,
+            ),
+            (
+                "bencher_metric_timestamp_seconds",
+                "The start time of the report for the latest metric, in seconds since the Unix epoch.",
+                &self.timestamp,
+            ),
+        ];
+        for (name, help, samples) in families {
+            if samples.is_empty() {
+                continue;
+            }
+            writeln!(f, "# TYPE {name} gauge")?;
+            writeln!(f, "# HELP {name} {help}")?;
+            for (labels, value) in samples {
+                writeln!(f, "{name}{{{labels}}} {value}", value = SampleValue(*value))?;
+            }
+        }
+        f.write_str("# EOF\n")
+    }
+}
+
+struct SampleValue(f64);
+
+impl fmt::Display for SampleValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(value) = *self;
+        if value.is_nan() {
+            f.write_str("NaN")
+        } else if value.is_infinite() {
+            f.write_str(if value.is_sign_positive() {
+                "+Inf"
+            } else {
+                "-Inf"
+            })
+        } else {
+            write!(f, "{value}")
+        }
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OpenMetrics, SampleValue, escape_label};
+
+    #[test]
+    fn openmetrics_escape_label() {
+        assert_eq!(escape_label("plain"), "plain");
+        assert_eq!(
+            escape_label("a \"quoted\" \\path\nname"),
+            "a \\\"quoted\\\" \\\\path\\nname"
+        );
+    }
+
+    #[test]
+    fn openmetrics_sample_value() {
+        assert_eq!(SampleValue(1.5).to_string(), "1.5");
+        assert_eq!(SampleValue(f64::NAN).to_string(), "NaN");
+        assert_eq!(SampleValue(f64::INFINITY).to_string(), "+Inf");
+        assert_eq!(SampleValue(f64::NEG_INFINITY).to_string(), "-Inf");
+    }
+
+    #[test]
+    fn openmetrics_encode() {
+        assert_eq!(OpenMetrics::default().to_string(), "# EOF\n");
+
+        let labels = "project=\"p\",branch=\"main\"".to_owned();
+        let open_metrics = OpenMetrics {
+            value: vec![(labels.clone(), 11.3)],
+            alert: vec![(labels, 0.0)],
+            ..Default::default()
+        };
+        assert_eq!(
+            open_metrics.to_string(),
+            "# TYPE bencher_metric_value gauge\n\
+            # HELP bencher_metric_value The latest metric value.\n\
+            bencher_metric_value{project=\"p\",branch=\"main\"} 11.3\n\
+            # TYPE bencher_alert_active gauge\n\
+            # HELP bencher_alert_active Whether the latest metric has an active alert (1) or not (0).\n\
+            bencher_alert_active{project=\"p\",branch=\"main\"} 0\n\
+            # EOF\n"
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::{cmp, collections::BTreeMap};
+
+use bencher_json::{
+    DateTime,
+    project::perf::{JsonPerfBucket, JsonPerfMetric, PerfAggregate, PerfBucket},
+};
+use ordered_float::OrderedFloat;
+
+const HOUR_SECONDS: i64 = 60 * 60;
+const DAY_SECONDS: i64 = 24 * HOUR_SECONDS;
+const WEEK_SECONDS: i64 = 7 * DAY_SECONDS;
+// The Unix epoch was on a Thursday, so shift weeks to start on Monday.
+const WEEK_OFFSET_SECONDS: i64 = 3 * DAY_SECONDS;
+
+/// Downsample the metrics into one aggregated metric per bucket.
+/// The metrics are returned in bucket order.
+pub fn downsample(
+    metrics: Vec<JsonPerfMetric>,
+    bucket: PerfBucket,
+    aggregate: PerfAggregate,
+) -> Vec<JsonPerfMetric> {
+    let mut buckets: BTreeMap<i64, Vec<JsonPerfMetric>> = BTreeMap::new();
+    for metric in metrics {
+        buckets
+            .entry(bucket_key(bucket, &metric))
+            .or_default()
+            .push(metric);
+    }
+    buckets
+        .into_values()
+        .filter_map(|metrics| aggregate_bucket(metrics, aggregate))
+        .collect()
+}
+
+fn bucket_key(bucket: PerfBucket, metric: &JsonPerfMetric) -> i64 {
+    let timestamp = metric.start_time.timestamp();
+    match bucket {
+        PerfBucket::Hour => timestamp.div_euclid(HOUR_SECONDS),
+        PerfBucket::Day => timestamp.div_euclid(DAY_SECONDS),
+        PerfBucket::Week => (timestamp + WEEK_OFFSET_SECONDS).div_euclid(WEEK_SECONDS),
+        PerfBucket::Version => metric.version.number.0.into(),
+    }
+}
+
+fn aggregate_bucket(
+    mut metrics: Vec<JsonPerfMetric>,
+    aggregate: PerfAggregate,
+) -> Option<JsonPerfMetric> {
+    let start_time = metrics
+        .iter()
+        .map(|metric| metric.start_time)
+        .min_by_key(DateTime::timestamp)?;
+    let values = metrics
+        .iter()
+        .map(|metric| metric.metric.value)
+        .collect::<Vec<_>>();
+    let min = values.iter().min().copied()?;
+    let max = values.iter().max().copied()?;
+    let lower_values = metrics
+        .iter()
+        .filter_map(|metric| metric.metric.lower_value)
+        .collect::<Vec<_>>();
+    let upper_values = metrics
+        .iter()
+        .filter_map(|metric| metric.metric.upper_value)
+        .collect::<Vec<_>>();
+    // Use the most recent alert in the bucket, if there is one.
+    let alert = metrics.iter().rev().find_map(|metric| metric.alert.clone());
+    let count = u32::try_from(metrics.len()).unwrap_or(u32::MAX);
+
+    // The latest metric in the bucket is used for everything else.
+    let mut perf_metric = metrics.pop()?;
+    perf_metric.metric.value = aggregate_values(values, aggregate)?;
+    perf_metric.metric.lower_value = aggregate_values(lower_values, aggregate);
+    perf_metric.metric.upper_value = aggregate_values(upper_values, aggregate);
+    perf_metric.alert = alert;
+    perf_metric.bucket = Some(JsonPerfBucket {
+        count,
+        start_time,
+        min,
+        max,
+    });
+    Some(perf_metric)
+}
+
+fn aggregate_values(
+    mut values: Vec<OrderedFloat<f64>>,
+    aggregate: PerfAggregate,
+) -> Option<OrderedFloat<f64>> {
+    values.sort_unstable();
+    match aggregate {
+        PerfAggregate::Mean => mean(&values),
+        PerfAggregate::Median => percentile_of_sorted(&values, 0.5),
+        PerfAggregate::Min => values.first().copied(),
+        PerfAggregate::Max => values.last().copied(),
+        PerfAggregate::P90 => percentile_of_sorted(&values, 0.9),
+    }
+}
+
+fn mean(values: &[OrderedFloat<f64>]) -> Option<OrderedFloat<f64>> {
+    if values.is_empty() {
+        return None;
+    }
+    #[expect(clippy::cast_precision_loss)]
+    let mean = values.iter().map(|value| value.0).sum::<f64>() / values.len() as f64;
+    Some(mean.into())
+}
+
+// Linear interpolation between the closest ranks
+fn percentile_of_sorted(
+    values: &[OrderedFloat<f64>],
+    percentile: f64,
+) -> Option<OrderedFloat<f64>> {
+    let last_index = values.len().checked_sub(1)?;
+    #[expect(clippy::cast_precision_loss)]
+    let rank = percentile * last_index as f64;
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower_index, upper_index) = (rank.floor() as usize, rank.ceil() as usize);
+    let lower = values.get(lower_index)?.0;
+    let upper = values.get(cmp::min(upper_index, last_index))?.0;
+    Some((lower + (upper - lower) * rank.fract()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::{
+        DateTime,
+        project::perf::{JsonPerfMetric, PerfAggregate, PerfBucket},
+    };
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use super::{aggregate_values, downsample};
+
+    fn values(values: &[f64]) -> Vec<OrderedFloat<f64>> {
+        values.iter().copied().map(OrderedFloat).collect()
+    }
+
+    fn perf_metric(start_time: i64, version: u32, value: f64) -> JsonPerfMetric {
+        let mut perf_metric: JsonPerfMetric = serde_json::from_value(serde_json::json!({
+            "report": "e1d4c9aa-8d2a-4cbc-b0c2-4bdc5b0c1f8b",
+            "iteration": 0,
+            "start_time": "1970-01-01T00:00:00Z",
+            "end_time": "1970-01-01T00:00:00Z",
+            "version": { "number": version },
+            "metric": {
+                "uuid": "b8a0b0ce-b7b4-4f93-8a1e-5e6b0c7a3a4a",
+                "value": value,
+            },
+        }))
+        .unwrap();
+        perf_metric.start_time = DateTime::try_from(start_time).unwrap();
+        perf_metric.end_time = perf_metric.start_time;
+        perf_metric
+    }
+
+    #[test]
+    fn aggregate_values_all() {
+        let data = values(&[4.0, 1.0, 3.0, 2.0, 10.0]);
+        assert_eq!(
+            aggregate_values(data.clone(), PerfAggregate::Mean),
+            Some(OrderedFloat(4.0))
+        );
+        assert_eq!(
+            aggregate_values(data.clone(), PerfAggregate::Median),
+            Some(OrderedFloat(3.0))
+        );
+        assert_eq!(
+            aggregate_values(data.clone(), PerfAggregate::Min),
+            Some(OrderedFloat(1.0))
+        );
+        assert_eq!(
+            aggregate_values(data.clone(), PerfAggregate::Max),
+            Some(OrderedFloat(10.0))
+        );
+        assert_eq!(
+            aggregate_values(data, PerfAggregate::P90),
+            Some(OrderedFloat(7.600_000_000_000_000_5))
+        );
+        assert_eq!(
+            aggregate_values(
+                values(&[11.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]),
+                PerfAggregate::P90
+            ),
+            Some(OrderedFloat(10.0))
+        );
+        assert_eq!(
+            aggregate_values(values(&[1.0, 2.0]), PerfAggregate::Median),
+            Some(OrderedFloat(1.5))
+        );
+        assert_eq!(aggregate_values(Vec::new(), PerfAggregate::Mean), None);
+        assert_eq!(aggregate_values(Vec::new(), PerfAggregate::P90), None);
+    }
+
+    #[test]
+    fn downsample_day() {
+        const DAY: i64 = 24 * 60 * 60;
+        let metrics = vec![
+            perf_metric(DAY, 1, 1.0),
+            perf_metric(DAY + 60, 2, 3.0),
+            perf_metric(3 * DAY, 3, 10.0),
+            perf_metric(DAY + 120, 4, 5.0),
+        ];
+        let downsampled = downsample(metrics, PerfBucket::Day, PerfAggregate::Mean);
+        assert_eq!(downsampled.len(), 2);
+        let mut downsampled = downsampled.iter();
+
+        let first = downsampled.next().unwrap();
+        assert_eq!(first.metric.value, OrderedFloat(3.0));
+        assert_eq!(first.version.number.0, 4);
+        let bucket = first.bucket.as_ref().unwrap();
+        assert_eq!(bucket.count, 3);
+        assert_eq!(bucket.start_time.timestamp(), DAY);
+        assert_eq!(bucket.min, OrderedFloat(1.0));
+        assert_eq!(bucket.max, OrderedFloat(5.0));
+
+        let second = downsampled.next().unwrap();
+        assert_eq!(second.metric.value, OrderedFloat(10.0));
+        assert_eq!(second.bucket.as_ref().unwrap().count, 1);
+    }
+
+    #[test]
+    fn downsample_version() {
+        let metrics = vec![
+            perf_metric(1, 1, 1.0),
+            perf_metric(2, 1, 2.0),
+            perf_metric(3, 2, 3.0),
+        ];
+        let downsampled = downsample(metrics, PerfBucket::Version, PerfAggregate::Max);
+        let values = downsampled
+            .iter()
+            .map(|metric| metric.metric.value)
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![OrderedFloat(2.0), OrderedFloat(3.0)]);
+    }
+}
@@ -0,0 +1,492 @@
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use arrow_array::{
+    ArrayRef, Float64Array, RecordBatch, StringArray, TimestampMillisecondArray, UInt32Array,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use bencher_endpoint::{CorsResponse, Endpoint, Get};
+use bencher_json::{
+    DateTime, JsonPerfQuery,
+    project::{
+        alert::AlertStatus,
+        perf::{JsonPerfExportQueryParams, JsonPerfMetrics, JsonPerfQueryParams, PerfExportFormat},
+    },
+};
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    error::bad_request_error,
+    model::{
+        project::QueryProject,
+        user::auth::{AuthUser, PubBearerToken},
+    },
+};
+use bytes::Bytes;
+use dropshot::{Body, HttpError, Path, Query, RequestContext, endpoint};
+use http::Response;
+use http_body::Frame;
+use parquet::arrow::ArrowWriter;
+use serde::Serialize;
+use slog::Logger;
+use tokio::sync::mpsc;
+
+use super::{PerfPermutations, ProjPerfParams};
+
+// The number of encoded chunks that can be waiting to be sent to the client.
+// Each chunk holds the metrics for a single permutation.
+const EXPORT_CHANNEL_SIZE: usize = 4;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/perf/export",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_export_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjPerfParams>,
+    _query_params: Query<JsonPerfExportQueryParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Export project performance metrics
+///
+/// Export the performance metrics for a project as a flat table with one row per metric.
+/// The query parameters are the same as for querying the project performance metrics.
+/// The table can be exported as either CSV or Apache Parquet.
+/// The export is streamed one permutation at a time,
+/// so the response body is not buffered in memory.
+/// If the project is public, then the user does not need to be authenticated.
+/// If the project is private, then the user must be authenticated and have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/perf/export",
+    tags = ["projects", "perf"]
+}]
+pub async fn proj_perf_export_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: PubBearerToken,
+    path_params: Path<ProjPerfParams>,
+    query_params: Query<JsonPerfExportQueryParams>,
+) -> Result<Response<Body>, HttpError> {
+    let mut json_perf_export_query_params = query_params.into_inner();
+    let format = json_perf_export_query_params
+        .format
+        .take()
+        .unwrap_or_default();
+    let json_perf_query_params: JsonPerfQueryParams = json_perf_export_query_params.into();
+    // Second round of marshaling
+    let json_perf_query: JsonPerfQuery = json_perf_query_params
+        .try_into()
+        .map_err(bad_request_error)?;
+
+    let auth_user = AuthUser::from_pub_token(
+        rqctx.context(),
+        #[cfg(feature = "plus")]
+        rqctx.request.headers(),
+        bearer_token,
+    )
+    .await?;
+    let project = QueryProject::is_allowed_public(
+        conn_lock!(rqctx.context()),
+        &rqctx.context().rbac,
+        &path_params.into_inner().project,
+        auth_user.as_ref(),
+    )?;
+    let permutations = PerfPermutations::new(rqctx.context(), &project, json_perf_query).await?;
+
+    let content_disposition = format!(
+        "attachment; filename=\"{slug}-perf.{extension}\"",
+        slug = project.slug,
+        extension = format.extension()
+    );
+    let (tx, rx) = mpsc::channel(EXPORT_CHANNEL_SIZE);
+    let server = Arc::clone(&rqctx.server);
+    let log = rqctx.log.clone();
+    tokio::spawn(async move {
+        export(&log, &server.private, &project, &permutations, format, tx).await;
+    });
+
+    Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, format.content_type())
+        .header(http::header::CONTENT_DISPOSITION, content_disposition)
+        .header(http::header::CACHE_CONTROL, "private, max-age=0, no-cache")
+        .body(Body::wrap(ExportBody(rx)))
+        .map_err(Into::into)
+}
+
+async fn export(
+    log: &Logger,
+    context: &ApiContext,
+    project: &QueryProject,
+    permutations: &PerfPermutations,
+    format: PerfExportFormat,
+    tx: mpsc::Sender<Result<Bytes, BoxError>>,
+) {
+    if let Err(e) = export_inner(context, project, permutations, format, &tx).await {
+        slog::error!(log, "Failed to export perf metrics for {project:?}: {e}");
+        // Abort the response, so the client does not mistake a partial export for a complete one.
+        if tx.send(Err(e)).await.is_err() {
+            slog::debug!(log, "Perf export client disconnected");
+        }
+    }
+}
+
+async fn export_inner(
+    context: &ApiContext,
+    project: &QueryProject,
+    permutations: &PerfPermutations,
+    format: PerfExportFormat,
+    tx: &mpsc::Sender<Result<Bytes, BoxError>>,
+) -> Result<(), BoxError> {
+    let mut writer = PerfWriter::new(format)?;
+    for permutation in &permutations.permutations {
+        let Some(perf_metrics) = permutations
+            .perf_metrics(context, project, permutation)
+            .await?
+        else {
+            continue;
+        };
+        let rows = PerfRow::from_perf_metrics(perf_metrics);
+        let chunk = writer.write(&rows)?;
+        // Stop early if the client has disconnected.
+        if !chunk.is_empty() && tx.send(Ok(chunk)).await.is_err() {
+            return Ok(());
+        }
+    }
+    let chunk = writer.finish()?;
+    if !chunk.is_empty() {
+        // The client may have already disconnected, and there is nothing left to do either way.
+        let _disconnected = tx.send(Ok(chunk)).await.is_err();
+    }
+    Ok(())
+}
+
+struct ExportBody(mpsc::Receiver<Result<Bytes, BoxError>>);
+
+impl http_body::Body for ExportBody {
+    type Data = Bytes;
+    type Error = BoxError;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        self.0
+            .poll_recv(cx)
+            .map(|chunk| chunk.map(|chunk| chunk.map(Frame::data)))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct PerfRow {
+    branch: String,
+    head: String,
+    version_number: u32,
+    version_hash: Option<String>,
+    testbed: String,
+    benchmark: String,
+    measure: String,
+    units: String,
+    report: String,
+    iteration: u32,
+    start_time: DateTime,
+    end_time: DateTime,
+    value: f64,
+    lower_value: Option<f64>,
+    upper_value: Option<f64>,
+    baseline: Option<f64>,
+    lower_limit: Option<f64>,
+    upper_limit: Option<f64>,
+    alert_status: Option<&'static str>,
+    bucket_count: Option<u32>,
+    bucket_min: Option<f64>,
+    bucket_max: Option<f64>,
+}
+
+impl PerfRow {
+    fn from_perf_metrics(perf_metrics: JsonPerfMetrics) -> Vec<Self> {
+        let JsonPerfMetrics {
+            branch,
+            testbed,
+            benchmark,
+            measure,
+            metrics,
+        } = perf_metrics;
+        metrics
+            .into_iter()
+            .map(|perf_metric| {
+                let (baseline, lower_limit, upper_limit) =
+                    perf_metric.boundary.map_or((None, None, None), |boundary| {
+                        (
+                            boundary.baseline.map(Into::into),
+                            boundary.lower_limit.map(Into::into),
+                            boundary.upper_limit.map(Into::into),
+                        )
+                    });
+                Self {
+                    branch: branch.name.to_string(),
+                    head: branch.head.uuid.to_string(),
+                    version_number: perf_metric.version.number.0,
+                    version_hash: perf_metric.version.hash.map(|hash| hash.to_string()),
+                    testbed: testbed.name.to_string(),
+                    benchmark: benchmark.name.to_string(),
+                    measure: measure.name.to_string(),
+                    units: measure.units.to_string(),
+                    report: perf_metric.report.to_string(),
+                    iteration: perf_metric.iteration.0,
+                    start_time: perf_metric.start_time,
+                    end_time: perf_metric.end_time,
+                    value: perf_metric.metric.value.into(),
+                    lower_value: perf_metric.metric.lower_value.map(Into::into),
+                    upper_value: perf_metric.metric.upper_value.map(Into::into),
+                    baseline,
+                    lower_limit,
+                    upper_limit,
+                    alert_status: perf_metric.alert.map(|alert| match alert.status {
+                        AlertStatus::Active => "active",
+                        AlertStatus::Dismissed => "dismissed",
+                        AlertStatus::Silenced => "silenced",
+                    }),
+                    bucket_count: perf_metric.bucket.as_ref().map(|bucket| bucket.count),
+                    bucket_min: perf_metric.bucket.as_ref().map(|bucket| bucket.min.into()),
+                    bucket_max: perf_metric.bucket.as_ref().map(|bucket| bucket.max.into()),
+                }
+            })
+            .collect()
+    }
+
+    fn schema() -> Schema {
+        let timestamp = DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()));
+        Schema::new(vec![
+            Field::new("branch", DataType::Utf8, false),
+            Field::new("head", DataType::Utf8, false),
+            Field::new("version_number", DataType::UInt32, false),
+            Field::new("version_hash", DataType::Utf8, true),
+            Field::new("testbed", DataType::Utf8, false),
+            Field::new("benchmark", DataType::Utf8, false),
+            Field::new("measure", DataType::Utf8, false),
+            Field::new("units", DataType::Utf8, false),
+            Field::new("report", DataType::Utf8, false),
+            Field::new("iteration", DataType::UInt32, false),
+            Field::new("start_time", timestamp.clone(), false),
+            Field::new("end_time", timestamp, false),
+            Field::new("value", DataType::Float64, false),
+            Field::new("lower_value", DataType::Float64, true),
+            Field::new("upper_value", DataType::Float64, true),
+            Field::new("baseline", DataType::Float64, true),
+            Field::new("lower_limit", DataType::Float64, true),
+            Field::new("upper_limit", DataType::Float64, true),
+            Field::new("alert_status", DataType::Utf8, true),
+            Field::new("bucket_count", DataType::UInt32, true),
+            Field::new("bucket_min", DataType::Float64, true),
+            Field::new("bucket_max", DataType::Float64, true),
+        ])
+    }
+
+    fn record_batch(schema: &SchemaRef, rows: &[Self]) -> Result<RecordBatch, BoxError> {
+        fn strings<'a>(
+            rows: &'a [PerfRow],
+            f: impl Fn(&'a PerfRow) -> Option<&'a str>,
+        ) -> ArrayRef {
+            Arc::new(rows.iter().map(f).collect::<StringArray>())
+        }
+        fn floats(rows: &[PerfRow], f: impl Fn(&PerfRow) -> Option<f64>) -> ArrayRef {
+            Arc::new(rows.iter().map(f).collect::<Float64Array>())
+        }
+        fn integers(rows: &[PerfRow], f: impl Fn(&PerfRow) -> Option<u32>) -> ArrayRef {
+            Arc::new(rows.iter().map(f).collect::<UInt32Array>())
+        }
+        fn timestamps(rows: &[PerfRow], f: impl Fn(&PerfRow) -> DateTime) -> ArrayRef {
+            Arc::new(
+                rows.iter()
+                    .map(|row| Some(f(row).into_inner().timestamp_millis()))
+                    .collect::<TimestampMillisecondArray>()
+                    .with_timezone("UTC"),
+            )
+        }
+
+        let columns = vec![
+            strings(rows, |row| Some(&row.branch)),
+            strings(rows, |row| Some(&row.head)),
+            integers(rows, |row| Some(row.version_number)),
+            strings(rows, |row| row.version_hash.as_deref()),
+            strings(rows, |row| Some(&row.testbed)),
+            strings(rows, |row| Some(&row.benchmark)),
+            strings(rows, |row| Some(&row.measure)),
+            strings(rows, |row| Some(&row.units)),
+            strings(rows, |row| Some(&row.report)),
+            integers(rows, |row| Some(row.iteration)),
+            timestamps(rows, |row| row.start_time),
+            timestamps(rows, |row| row.end_time),
+            floats(rows, |row| Some(row.value)),
+            floats(rows, |row| row.lower_value),
+            floats(rows, |row| row.upper_value),
+            floats(rows, |row| row.baseline),
+            floats(rows, |row| row.lower_limit),
+            floats(rows, |row| row.upper_limit),
+            strings(rows, |row| row.alert_status),
+            integers(rows, |row| row.bucket_count),
+            floats(rows, |row| row.bucket_min),
+            floats(rows, |row| row.bucket_max),
+        ];
+        RecordBatch::try_new(Arc::clone(schema), columns).map_err(Into::into)
+    }
+}
+
+enum PerfWriter {
+    // Any encoded CSV that has not yet been returned
+    Csv(Vec<u8>),
+    Parquet {
+        schema: SchemaRef,
+        writer: Box<ArrowWriter<Vec<u8>>>,
+    },
+}
+
+impl PerfWriter {
+    fn new(format: PerfExportFormat) -> Result<Self, BoxError> {
+        let schema = Arc::new(PerfRow::schema());
+        Ok(match format {
+            PerfExportFormat::Csv => {
+                // Always write the header, even if there are no rows.
+                let mut writer = csv_writer(Vec::new());
+                writer.write_record(schema.fields().iter().map(|field| field.name()))?;
+                Self::Csv(
+                    writer
+                        .into_inner()
+                        .map_err(csv::IntoInnerError::into_error)?,
+                )
+            },
+            PerfExportFormat::Parquet => {
+                let writer = ArrowWriter::try_new(Vec::new(), Arc::clone(&schema), None)?;
+                Self::Parquet {
+                    schema,
+                    writer: Box::new(writer),
+                }
+            },
+        })
+    }
+
+    /// Encode the rows and return all of the bytes that are ready to be sent.
+    fn write(&mut self, rows: &[PerfRow]) -> Result<Bytes, BoxError> {
+        match self {
+            Self::Csv(buffer) => {
+                let mut writer = csv_writer(std::mem::take(buffer));
+                for row in rows {
+                    writer.serialize(row)?;
+                }
+                Ok(writer
+                    .into_inner()
+                    .map_err(csv::IntoInnerError::into_error)?
+                    .into())
+            },
+            Self::Parquet { schema, writer } => {
+                if !rows.is_empty() {
+                    writer.write(&PerfRow::record_batch(schema, rows)?)?;
+                    // Write each permutation as its own row group.
+                    writer.flush()?;
+                }
+                // The Parquet writer only appends to its underlying buffer,
+                // so it is safe to drain what has been written so far.
+                Ok(std::mem::take(writer.inner_mut()).into())
+            },
+        }
+    }
+
+    /// Finish encoding and return the remaining bytes.
+    fn finish(self) -> Result<Bytes, BoxError> {
+        match self {
+            Self::Csv(buffer) => Ok(buffer.into()),
+            Self::Parquet { writer, .. } => writer.into_inner().map(Into::into).map_err(Into::into),
+        }
+    }
+}
+
+// The header is written once up front, so it must not be repeated for each chunk.
+fn csv_writer(buffer: Vec<u8>) -> csv::Writer<Vec<u8>> {
+    csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::{DateTime, project::perf::PerfExportFormat};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use pretty_assertions::assert_eq;
+
+    use super::{PerfRow, PerfWriter};
+
+    fn perf_row(benchmark: &str, value: f64) -> PerfRow {
+        PerfRow {
+            branch: "main".to_owned(),
+            head: "7b3ba3ba-0cf0-4c1e-b9c0-4c2cf1a0a0a0".to_owned(),
+            version_number: 1,
+            version_hash: None,
+            testbed: "localhost".to_owned(),
+            benchmark: benchmark.to_owned(),
+            measure: "Latency".to_owned(),
+            units: "nanoseconds (ns)".to_owned(),
+            report: "9c3b8e7e-6d3a-4d0e-8a52-5bb4c9b5e0e1".to_owned(),
+            iteration: 0,
+            start_time: DateTime::try_from(1_700_000_000).unwrap(),
+            end_time: DateTime::try_from(1_700_000_060).unwrap(),
+            value,
+            lower_value: None,
+            upper_value: None,
+            baseline: Some(1.5),
+            lower_limit: None,
+            upper_limit: Some(2.5),
+            alert_status: None,
+            bucket_count: None,
+            bucket_min: None,
+            bucket_max: None,
+        }
+    }
+
+    #[test]
+    fn export_csv() {
+        let mut writer = PerfWriter::new(PerfExportFormat::Csv).unwrap();
+        let header = String::from_utf8(writer.write(&[]).unwrap().to_vec()).unwrap();
+        assert_eq!(
+            header,
+            "branch,head,version_number,version_hash,testbed,benchmark,measure,units,report,iteration,start_time,end_time,value,lower_value,upper_value,baseline,lower_limit,upper_limit,alert_status,bucket_count,bucket_min,bucket_max\n"
+        );
+        let rows = writer.write(&[perf_row("bench, \"quoted\"", 2.0)]).unwrap();
+        assert_eq!(
+            String::from_utf8(rows.to_vec()).unwrap(),
+            "main,7b3ba3ba-0cf0-4c1e-b9c0-4c2cf1a0a0a0,1,,localhost,\"bench, \"\"quoted\"\"\",Latency,nanoseconds (ns),9c3b8e7e-6d3a-4d0e-8a52-5bb4c9b5e0e1,0,2023-11-14T22:13:20Z,2023-11-14T22:14:20Z,2.0,,,1.5,,2.5,,,,\n"
+        );
+        assert!(writer.finish().unwrap().is_empty());
+    }
+
+    #[test]
+    fn export_parquet() {
+        let mut writer = PerfWriter::new(PerfExportFormat::Parquet).unwrap();
+        let mut parquet = Vec::new();
+        parquet.extend(writer.write(&[perf_row("a", 1.0)]).unwrap());
+        parquet.extend(writer.write(&[]).unwrap());
+        parquet.extend(
+            writer
+                .write(&[perf_row("b", 2.0), perf_row("c", 3.0)])
+                .unwrap(),
+        );
+        parquet.extend(writer.finish().unwrap());
+
+        // Parquet files start and end with the magic bytes.
+        assert_eq!(parquet.get(..4), Some(b"PAR1".as_slice()));
+        assert_eq!(parquet.get(parquet.len() - 4..), Some(b"PAR1".as_slice()));
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(parquet)).unwrap();
+        assert_eq!(reader.metadata().num_row_groups(), 2);
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 3);
+        assert_eq!(reader.schema().as_ref(), &PerfRow::schema());
+    }
+}
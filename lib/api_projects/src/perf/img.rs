@@ -1,9 +1,9 @@
 use bencher_endpoint::{CorsResponse, Endpoint, Get};
 use bencher_json::{
     JsonPerfQuery,
-    project::perf::{JsonPerfImgQueryParams, JsonPerfQueryParams},
+    project::perf::{JsonPerfImgQueryParams, JsonPerfQueryParams, PerfBucket},
 };
-use bencher_plot::LinePlot;
+use bencher_plot::{LinePlot, PlotError};
 use bencher_schema::{
     context::ApiContext,
     error::{bad_request_error, issue_error},
@@ -33,6 +33,9 @@ pub async fn proj_perf_img_options(
 /// The query results are every permutation of each branch, testbed, benchmark, and measure.
 /// There is a limit of 8 permutations for a single image.
 /// Therefore, only the first 8 permutations are plotted.
+/// The image is generated as a PNG by default, or as a JPEG or SVG, with either a light or dark theme.
+/// If no `bucket` size is provided, then time windows longer than 90 days are downsampled by day
+/// and time windows longer than two years are downsampled by week.
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
 #[endpoint {
@@ -48,11 +51,18 @@ pub async fn proj_perf_img_get(
 ) -> Result<Response<Body>, HttpError> {
     let mut json_perf_img_query_params = query_params.into_inner();
     let title = json_perf_img_query_params.title.take();
+    let format = json_perf_img_query_params.format.unwrap_or_default();
+    let line_plot = line_plot(&json_perf_img_query_params).map_err(bad_request_error)?;
     let json_perf_query_params: JsonPerfQueryParams = json_perf_img_query_params.into();
     // Second round of marshaling
-    let json_perf_query = json_perf_query_params
+    let mut json_perf_query: JsonPerfQuery = json_perf_query_params
         .try_into()
         .map_err(bad_request_error)?;
+    // Downsample long time windows, so the plot stays readable.
+    if json_perf_query.bucket.is_none() {
+        json_perf_query.bucket =
+            PerfBucket::auto(json_perf_query.start_time, json_perf_query.end_time);
+    }
 
     let auth_user = AuthUser::from_pub_token(
         rqctx.context(),
@@ -61,9 +71,10 @@ pub async fn proj_perf_img_get(
         bearer_token,
     )
     .await?;
-    let jpeg = get_inner(
+    let img = get_inner(
         rqctx.context(),
         path_params.into_inner(),
+        &line_plot,
         title.as_deref(),
         json_perf_query,
         auth_user.as_ref(),
@@ -72,21 +83,44 @@ pub async fn proj_perf_img_get(
 
     Response::builder()
         .status(http::StatusCode::OK)
-        .header(http::header::CONTENT_TYPE, "image/jpeg")
+        .header(http::header::CONTENT_TYPE, format.content_type())
         .header(http::header::CACHE_CONTROL, "private, max-age=0, no-cache")
-        .body(jpeg.into())
+        .body(img.into())
         .map_err(Into::into)
 }
 
+fn line_plot(json_perf_img_query_params: &JsonPerfImgQueryParams) -> Result<LinePlot, PlotError> {
+    let JsonPerfImgQueryParams {
+        format,
+        width,
+        height,
+        theme,
+        log_scale,
+        ..
+    } = *json_perf_img_query_params;
+    let mut line_plot = LinePlot::new()
+        .format(format.unwrap_or_default())
+        .theme(theme.unwrap_or_default())
+        .log_scale(log_scale);
+    if let Some(width) = width {
+        line_plot = line_plot.width(width)?;
+    }
+    if let Some(height) = height {
+        line_plot = line_plot.height(height)?;
+    }
+    Ok(line_plot)
+}
+
 async fn get_inner(
     context: &ApiContext,
     path_params: ProjPerfParams,
+    line_plot: &LinePlot,
     title: Option<&str>,
     json_perf_query: JsonPerfQuery,
     auth_user: Option<&AuthUser>,
 ) -> Result<Vec<u8>, HttpError> {
     let json_perf = super::get_inner(context, path_params, json_perf_query, auth_user).await?;
-    LinePlot::new().draw(title, &json_perf).map_err(|e| {
+    line_plot.draw(title, &json_perf).map_err(|e| {
         issue_error(
             "Failed to draw perf plot",
             &format!("Failed draw perf plot: {json_perf:?}"),
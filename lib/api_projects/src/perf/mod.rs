@@ -1,3 +1,5 @@
+use std::mem;
+
 use bencher_endpoint::{CorsResponse, Endpoint, Get, ResponseOk};
 use bencher_json::{
     BenchmarkUuid, BranchUuid, DateTime, GitHash, HeadUuid, JsonPerf, JsonPerfQuery, MeasureUuid,
@@ -5,7 +7,7 @@ use bencher_json::{
     project::{
         alert::JsonPerfAlert,
         head::{JsonVersion, VersionNumber},
-        perf::{JsonPerfMetric, JsonPerfMetrics, JsonPerfQueryParams},
+        perf::{JsonPerfMetric, JsonPerfMetrics, JsonPerfQueryParams, PerfAggregate, PerfBucket},
         report::Iteration,
         threshold::JsonThresholdModel,
     },
@@ -17,7 +19,7 @@ use bencher_schema::{
     model::{
         project::{
             QueryProject,
-            benchmark::QueryBenchmark,
+            benchmark::{QueryBenchmark, tag::QueryBenchmarkTag},
             branch::{QueryBranch, head::QueryHead},
             measure::QueryMeasure,
             metric_boundary::QueryMetricBoundary,
@@ -38,6 +40,8 @@ use dropshot::{HttpError, Path, Query, RequestContext, endpoint};
 use schemars::JsonSchema;
 use serde::Deserialize;
 
+mod bucket;
+pub mod export;
 pub mod img;
 
 const MAX_PERMUTATIONS: usize = 255;
@@ -65,8 +69,11 @@ pub async fn proj_perf_options(
 ///
 /// Query the performance metrics for a project.
 /// The query results are every permutation of each branch, testbed, benchmark, and measure.
+/// Any non-archived benchmarks that match the `tags` selectors are added to the benchmarks.
 /// There is a limit of 255 permutations for a single request.
 /// Therefore, only the first 255 permutations are returned.
+/// If a `bucket` size is provided, then the metrics are downsampled to one metric per bucket using the `aggregate`.
+/// Each downsampled metric includes the count and the minimum and maximum values for its bucket.
 /// If the project is public, then the user does not need to be authenticated.
 /// If the project is private, then the user must be authenticated and have `view` permissions for the project.
 #[endpoint {
@@ -116,37 +123,28 @@ async fn get_inner(
         auth_user,
     )?;
 
-    let JsonPerfQuery {
-        branches,
-        heads,
-        testbeds,
-        benchmarks,
-        measures,
-        start_time,
-        end_time,
-    } = json_perf_query;
+    let permutations = PerfPermutations::new(context, &project, json_perf_query).await?;
+    let mut results = Vec::with_capacity(permutations.permutations.len());
+    for permutation in &permutations.permutations {
+        if let Some(perf_metrics) = permutations
+            .perf_metrics(context, &project, permutation)
+            .await?
+        {
+            results.push(perf_metrics);
+        }
+    }
 
-    let times = Times {
+    let Times {
         start_time,
         end_time,
-    };
-
-    let results = perf_results(
-        context,
-        &project,
-        &branches,
-        &heads,
-        &testbeds,
-        &benchmarks,
-        &measures,
-        times,
-    )
-    .await?;
-
+    } = permutations.times;
+    let (bucket, aggregate) = permutations.bucket.unzip();
     Ok(JsonPerf {
         project: project.into_json(conn_lock!(context))?,
         start_time,
         end_time,
+        bucket,
+        aggregate,
         results,
     })
 }
@@ -157,71 +155,134 @@ struct Times {
     end_time: Option<DateTime>,
 }
 
-#[expect(clippy::too_many_arguments)]
-async fn perf_results(
-    context: &ApiContext,
-    project: &QueryProject,
-    branches: &[BranchUuid],
-    heads: &[Option<HeadUuid>],
-    testbeds: &[TestbedUuid],
-    benchmarks: &[BenchmarkUuid],
-    measures: &[MeasureUuid],
+#[derive(Clone, Copy)]
+struct Permutation {
+    branch: BranchUuid,
+    head: Option<HeadUuid>,
+    testbed: TestbedUuid,
+    benchmark: BenchmarkUuid,
+    measure: MeasureUuid,
+}
+
+struct PerfPermutations {
+    permutations: Vec<Permutation>,
     times: Times,
-) -> Result<Vec<JsonPerfMetrics>, HttpError> {
-    let permutations = branches.len() * testbeds.len() * benchmarks.len() * measures.len();
-    let gt_max_permutations = permutations > MAX_PERMUTATIONS;
-    let mut results = Vec::with_capacity(permutations.min(MAX_PERMUTATIONS));
-    // It is okay to use `zip` because `JsonPerfQuery` guarantees that the lengths are the same.
-    for (branch_index, (branch_uuid, head_uuid)) in branches.iter().zip(heads.iter()).enumerate() {
-        for (testbed_index, testbed_uuid) in testbeds.iter().enumerate() {
-            for (benchmark_index, benchmark_uuid) in benchmarks.iter().enumerate() {
-                for (measure_index, measure_uuid) in measures.iter().enumerate() {
-                    if gt_max_permutations
-                        && (branch_index + 1)
-                            * (testbed_index + 1)
-                            * (benchmark_index + 1)
-                            * (measure_index + 1)
-                            > MAX_PERMUTATIONS
-                    {
-                        return Ok(results);
-                    }
+    bucket: Option<(PerfBucket, PerfAggregate)>,
+}
 
-                    let pq = perf_query(
-                        context,
-                        project,
-                        *branch_uuid,
-                        *head_uuid,
-                        *testbed_uuid,
-                        *benchmark_uuid,
-                        *measure_uuid,
-                        times,
-                    )
-                    .await?;
-
-                    let mut perf_metrics: Option<JsonPerfMetrics> = None;
-                    for (query_dimensions, perf_metric) in
-                        pq.into_iter().map(|pq| split_perf_query(project, pq))
-                    {
-                        if let Some(perf_metrics) = &mut perf_metrics {
-                            perf_metrics.metrics.push(perf_metric);
-                        } else {
-                            perf_metrics = new_perf_metrics(
-                                conn_lock!(context),
-                                project,
-                                query_dimensions,
-                                perf_metric,
-                            )
-                            .ok();
+impl PerfPermutations {
+    async fn new(
+        context: &ApiContext,
+        project: &QueryProject,
+        json_perf_query: JsonPerfQuery,
+    ) -> Result<Self, HttpError> {
+        let JsonPerfQuery {
+            branches,
+            heads,
+            testbeds,
+            mut benchmarks,
+            tags,
+            measures,
+            start_time,
+            end_time,
+            bucket,
+            aggregate,
+        } = json_perf_query;
+
+        // Add any benchmarks that match the tag selectors.
+        if let Some(tags) = &tags {
+            let tagged_benchmarks =
+                QueryBenchmarkTag::get_benchmark_uuids(conn_lock!(context), project.id, tags)?;
+            for benchmark_uuid in tagged_benchmarks {
+                if !benchmarks.contains(&benchmark_uuid) {
+                    benchmarks.push(benchmark_uuid);
+                }
+            }
+        }
+
+        let permutations = branches.len() * testbeds.len() * benchmarks.len() * measures.len();
+        let gt_max_permutations = permutations > MAX_PERMUTATIONS;
+        let mut permutations = Vec::with_capacity(permutations.min(MAX_PERMUTATIONS));
+        // It is okay to use `zip` because `JsonPerfQuery` guarantees that the lengths are the same.
+        'permutations: for (branch_index, (branch, head)) in
+            branches.into_iter().zip(heads).enumerate()
+        {
+            for (testbed_index, testbed) in testbeds.iter().copied().enumerate() {
+                for (benchmark_index, benchmark) in benchmarks.iter().copied().enumerate() {
+                    for (measure_index, measure) in measures.iter().copied().enumerate() {
+                        if gt_max_permutations
+                            && (branch_index + 1)
+                                * (testbed_index + 1)
+                                * (benchmark_index + 1)
+                                * (measure_index + 1)
+                                > MAX_PERMUTATIONS
+                        {
+                            break 'permutations;
                         }
-                    }
-                    if let Some(perf_metrics) = perf_metrics.take() {
-                        results.push(perf_metrics);
+                        permutations.push(Permutation {
+                            branch,
+                            head,
+                            testbed,
+                            benchmark,
+                            measure,
+                        });
                     }
                 }
             }
         }
+
+        Ok(Self {
+            permutations,
+            times: Times {
+                start_time,
+                end_time,
+            },
+            // The aggregate is only used when downsampling into buckets.
+            bucket: bucket.map(|bucket| (bucket, aggregate.unwrap_or_default())),
+        })
+    }
+
+    async fn perf_metrics(
+        &self,
+        context: &ApiContext,
+        project: &QueryProject,
+        permutation: &Permutation,
+    ) -> Result<Option<JsonPerfMetrics>, HttpError> {
+        let pq = perf_query(
+            context,
+            project,
+            permutation.branch,
+            permutation.head,
+            permutation.testbed,
+            permutation.benchmark,
+            permutation.measure,
+            self.times,
+        )
+        .await?;
+
+        let mut perf_metrics: Option<JsonPerfMetrics> = None;
+        for (query_dimensions, perf_metric) in
+            pq.into_iter().map(|pq| split_perf_query(project, pq))
+        {
+            if let Some(perf_metrics) = &mut perf_metrics {
+                perf_metrics.metrics.push(perf_metric);
+            } else {
+                perf_metrics =
+                    new_perf_metrics(conn_lock!(context), project, query_dimensions, perf_metric)
+                        .ok();
+            }
+        }
+
+        // Downsample the metrics into buckets if requested.
+        if let Some(perf_metrics) = &mut perf_metrics
+            && let Some((bucket, aggregate)) = self.bucket
+        {
+            let metrics = mem::take(&mut perf_metrics.metrics);
+            perf_metrics.metrics = bucket::downsample(metrics, bucket, aggregate);
+        }
+
+        Ok(perf_metrics)
     }
-    Ok(results)
 }
 
 #[expect(clippy::too_many_arguments, clippy::too_many_lines)]
@@ -325,6 +386,7 @@ async fn perf_query(
                     schema::threshold::model_id,
                     schema::threshold::created,
                     schema::threshold::modified,
+                    schema::threshold::benchmark_tags,
                 ),
                 (
                     schema::model::id,
@@ -469,6 +531,7 @@ fn new_perf_metric(
         threshold,
         boundary,
         alert,
+        bucket: None,
     }
 }
 
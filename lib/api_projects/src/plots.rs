@@ -3,8 +3,8 @@ use bencher_endpoint::{
     TotalCount,
 };
 use bencher_json::{
-    JsonDirection, JsonNewPlot, JsonPagination, JsonPlot, JsonPlots, PlotUuid, ProjectResourceId,
-    ResourceName, Search, project::plot::JsonUpdatePlot,
+    AuditAction, AuditResource, JsonDirection, JsonNewPlot, JsonPagination, JsonPlot, JsonPlots,
+    PlotUuid, ProjectResourceId, ResourceName, Search, project::plot::JsonUpdatePlot,
 };
 use bencher_rbac::project::Permission;
 use bencher_schema::{
@@ -12,6 +12,7 @@ use bencher_schema::{
     context::ApiContext,
     error::{resource_conflict_err, resource_not_found_err},
     model::{
+        audit::InsertAuditEvent,
         project::{
             QueryProject,
             plot::{InsertPlot, QueryPlot, UpdatePlot},
@@ -116,14 +117,17 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
-    let plots = get_ls_query(&query_project, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryPlot>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Plot,
-            (&query_project, &pagination_params, &query_params)
-        ))?;
+    let plots = order_ls_query(
+        get_ls_query(&query_project, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryPlot>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Plot,
+        (&query_project, &pagination_params, &query_params)
+    ))?;
 
     // Separate out these queries to prevent a deadlock when getting the conn_lock
     let mut json_plots = Vec::with_capacity(plots.len());
@@ -138,7 +142,7 @@ async fn get_ls_inner(
         }
     }
 
-    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
+    let total_count = get_ls_query(&query_project, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -152,9 +156,8 @@ async fn get_ls_inner(
 
 fn get_ls_query<'q>(
     query_project: &'q QueryProject,
-    pagination_params: &ProjPlotsPagination,
     query_params: &'q ProjPlotsQuery,
-) -> schema::plot::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+) -> schema::plot::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     let mut query = QueryPlot::belonging_to(&query_project).into_boxed();
 
     if let Some(title) = query_params.title.as_ref() {
@@ -169,6 +172,15 @@ fn get_ls_query<'q>(
         );
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::plot::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &ProjPlotsPagination,
+) -> schema::plot::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         ProjPlotsSort::Index => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order(schema::plot::rank.asc()),
@@ -227,7 +239,19 @@ async fn post_inner(
     InsertPlot::rate_limit(context, query_project.id).await?;
     let query_plot = InsertPlot::from_json(context, &query_project, json_plot).await?;
 
-    query_plot.into_json_for_project(conn_lock!(context), &query_project)
+    let json_plot = query_plot.into_json_for_project(conn_lock!(context), &query_project)?;
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Create,
+        AuditResource::Plot,
+        json_plot.uuid,
+    )?;
+    audit_event.after(&json_plot).insert(conn_lock!(context))?;
+
+    Ok(json_plot)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -345,6 +369,19 @@ async fn patch_inner(
 
     let query_plot =
         QueryPlot::get_with_uuid(conn_lock!(context), &query_project, path_params.plot)?;
+    let audit_event = conn_lock!(context, |conn| InsertAuditEvent::project(
+        conn,
+        auth_user,
+        &query_project,
+        AuditAction::Update,
+        AuditResource::Plot,
+        query_plot.uuid,
+    )?
+    .before(
+        &query_plot
+            .clone()
+            .into_json_for_project(conn, &query_project)?
+    ));
 
     let update_plot =
         UpdatePlot::from_json(context, &query_project, &query_plot, json_plot.clone()).await?;
@@ -353,12 +390,16 @@ async fn patch_inner(
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Plot, (&query_plot, &json_plot)))?;
 
-    conn_lock!(context, |conn| QueryPlot::get_with_uuid(
+    let json_plot = conn_lock!(context, |conn| QueryPlot::get_with_uuid(
         conn,
         &query_project,
         path_params.plot
     )
-    .and_then(|plot| plot.into_json_for_project(conn, &query_project)))
+    .and_then(|plot| plot.into_json_for_project(conn, &query_project)))?;
+
+    audit_event.after(&json_plot).insert(conn_lock!(context))?;
+
+    Ok(json_plot)
 }
 
 /// Delete a plot
@@ -396,10 +437,24 @@ async fn delete_inner(
 
     let query_plot =
         QueryPlot::get_with_uuid(conn_lock!(context), &query_project, path_params.plot)?;
+    let audit_event = conn_lock!(context, |conn| InsertAuditEvent::project(
+        conn,
+        auth_user,
+        &query_project,
+        AuditAction::Delete,
+        AuditResource::Plot,
+        query_plot.uuid,
+    )?
+    .before(
+        &query_plot
+            .clone()
+            .into_json_for_project(conn, &query_project)?
+    ));
 
     diesel::delete(schema::plot::table.filter(schema::plot::id.eq(query_plot.id)))
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Plot, query_plot))?;
+    audit_event.insert(conn_lock!(context))?;
 
     Ok(())
 }
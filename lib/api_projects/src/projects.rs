@@ -2,8 +2,8 @@ use bencher_endpoint::{
     CorsResponse, Delete, Endpoint, Get, Patch, ResponseDeleted, ResponseOk, TotalCount,
 };
 use bencher_json::{
-    JsonDirection, JsonPagination, JsonProject, JsonProjects, ProjectResourceId, ResourceName,
-    Search,
+    AuditAction, AuditResource, JsonDirection, JsonPagination, JsonProject, JsonProjects,
+    ProjectResourceId, ResourceName, Search,
     project::{JsonUpdateProject, Visibility},
 };
 use bencher_rbac::project::Permission;
@@ -14,6 +14,7 @@ use bencher_schema::{
     context::ApiContext,
     error::{resource_conflict_err, resource_not_found_err},
     model::{
+        audit::InsertAuditEvent,
         project::{QueryProject, UpdateProject},
         user::auth::{AuthUser, BearerToken, PubBearerToken},
     },
@@ -106,14 +107,17 @@ async fn get_ls_inner(
     pagination_params: ProjectsPagination,
     query_params: ProjectsQuery,
 ) -> Result<(JsonProjects, TotalCount), HttpError> {
-    let projects = get_ls_query(context, auth_user, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryProject>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Project,
-            (auth_user, &pagination_params, &query_params)
-        ))?;
+    let projects = order_ls_query(
+        get_ls_query(context, auth_user, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryProject>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Project,
+        (auth_user, &pagination_params, &query_params)
+    ))?;
 
     // Separate out these queries to prevent a deadlock when getting the conn_lock
     let mut json_projects = Vec::with_capacity(projects.len());
@@ -128,7 +132,7 @@ async fn get_ls_inner(
         }
     }
 
-    let total_count = get_ls_query(context, auth_user, &pagination_params, &query_params)
+    let total_count = get_ls_query(context, auth_user, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -143,9 +147,8 @@ async fn get_ls_inner(
 fn get_ls_query<'q>(
     context: &ApiContext,
     auth_user: Option<&AuthUser>,
-    pagination_params: &ProjectsPagination,
     query_params: &'q ProjectsQuery,
-) -> schema::project::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+) -> schema::project::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     let mut query = schema::project::table.into_boxed();
 
     // All users should just see the public projects if the query is for public projects
@@ -174,6 +177,15 @@ fn get_ls_query<'q>(
         );
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::project::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &ProjectsPagination,
+) -> schema::project::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         ProjectsSort::Name => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order(schema::project::name.asc()),
@@ -306,6 +318,8 @@ async fn patch_inner(
         .await?;
     }
 
+    let before_project = query_project.clone().into_json(conn_lock!(context))?;
+
     let update_project = UpdateProject::from(json_project.clone());
     diesel::update(schema::project::table.filter(schema::project::id.eq(query_project.id)))
         .set(&update_project)
@@ -326,7 +340,22 @@ async fn patch_inner(
         context.update_index(log, &new_query_project).await;
     }
 
-    new_query_project.into_json(conn_lock!(context))
+    let json_project = new_query_project.into_json(conn_lock!(context))?;
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Update,
+        AuditResource::Project,
+        query_project.uuid,
+    )?;
+    audit_event
+        .before(&before_project)
+        .after(&json_project)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_project)
 }
 
 /// Delete a project
@@ -369,10 +398,21 @@ async fn delete_inner(
         auth_user,
         Permission::Delete,
     )?;
+    let before_project = query_project.clone().into_json(conn_lock!(context))?;
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Delete,
+        AuditResource::Project,
+        query_project.uuid,
+    )?
+    .before(&before_project);
 
     diesel::delete(schema::project::table.filter(schema::project::id.eq(query_project.id)))
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Project, query_project))?;
+    audit_event.insert(conn_lock!(context))?;
 
     #[cfg(feature = "plus")]
     context.delete_index(log, &query_project).await;
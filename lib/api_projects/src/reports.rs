@@ -5,8 +5,8 @@ use bencher_endpoint::{
     TotalCount,
 };
 use bencher_json::{
-    JsonDirection, JsonNewReport, JsonPagination, JsonReport, JsonReports, ProjectResourceId,
-    ReportUuid,
+    AuditAction, AuditResource, JsonDirection, JsonNewReport, JsonPagination, JsonReport,
+    JsonReports, ProjectResourceId, ReportUuid,
     project::{
         head::VersionNumber,
         report::{JsonReportQuery, JsonReportQueryParams},
@@ -18,6 +18,7 @@ use bencher_schema::{
     context::ApiContext,
     error::{bad_request_error, resource_conflict_err, resource_not_found_err},
     model::{
+        audit::InsertAuditEvent,
         project::{
             QueryProject,
             branch::{
@@ -127,14 +128,17 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
-    let reports = get_ls_query(&query_project, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Report,
-            (&query_project, &pagination_params, &query_params)
-        ))?;
+    let reports = order_ls_query(
+        get_ls_query(&query_project, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Report,
+        (&query_project, &pagination_params, &query_params)
+    ))?;
 
     // Separate out these queries to prevent a deadlock when getting the conn_lock
     let mut json_reports = Vec::with_capacity(reports.len());
@@ -149,7 +153,7 @@ async fn get_ls_inner(
         }
     }
 
-    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
+    let total_count = get_ls_query(&query_project, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -163,7 +167,6 @@ async fn get_ls_inner(
 
 fn get_ls_query<'q>(
     query_project: &'q QueryProject,
-    pagination_params: &ProjReportsPagination,
     query_params: &'q JsonReportQuery,
 ) -> BoxedQuery<'q> {
     let mut query =
@@ -187,6 +190,9 @@ fn get_ls_query<'q>(
     if let Some(end_time) = query_params.end_time {
         query = query.filter(schema::report::end_time.le(end_time));
     }
+    if let Some(group) = query_params.group {
+        query = query.filter(schema::report::group_uuid.eq(group));
+    }
 
     if let Some(true) = query_params.archived {
         query = query.filter(
@@ -202,6 +208,15 @@ fn get_ls_query<'q>(
         );
     }
 
+    query.select(QueryReport::as_select())
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: BoxedQuery<'q>,
+    pagination_params: &ProjReportsPagination,
+) -> BoxedQuery<'q> {
     match pagination_params.order() {
         ProjReportsSort::DateTime => match pagination_params.direction {
             Some(JsonDirection::Asc) => query.order((
@@ -216,13 +231,12 @@ fn get_ls_query<'q>(
             )),
         },
     }
-    .select(QueryReport::as_select())
 }
 
 // TODO refactor out internal types
 type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
     'q,
-    diesel::helper_types::AsSelect<QueryReport, diesel::sqlite::Sqlite>,
+    diesel::helper_types::AsSelect<QueryReport, bencher_schema::context::DbBackend>,
     diesel::internal::table_macro::FromClause<
         diesel::helper_types::InnerJoinQuerySource<
             diesel::helper_types::InnerJoinQuerySource<
@@ -243,7 +257,7 @@ type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
             schema::testbed::table,
         >,
     >,
-    diesel::sqlite::Sqlite,
+    bencher_schema::context::DbBackend,
 >;
 
 /// Create a report
@@ -292,7 +306,7 @@ async fn post_inner(
         &context.rbac,
         &path_params.project,
         auth_user,
-        Permission::Create,
+        Permission::CreateReport,
     )?;
     QueryReport::create(log, context, &query_project, json_report, Some(auth_user)).await
 }
@@ -420,6 +434,16 @@ async fn delete_inner(
     diesel::delete(schema::report::table.filter(schema::report::id.eq(report_id)))
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Report, report_id))?;
+    // Reports can be quite large, so only record that the report was deleted
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Delete,
+        AuditResource::Report,
+        path_params.report,
+    )?;
+    audit_event.insert(conn_lock!(context))?;
 
     // If there are no more reports for this version, delete the version
     // This is necessary because multiple reports can use the same version via a git hash
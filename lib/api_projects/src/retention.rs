@@ -0,0 +1,191 @@
+use bencher_endpoint::{CorsResponse, Endpoint, Get, Put, ResponseOk};
+use bencher_json::{
+    AuditAction, AuditResource, DateTime, JsonRetention, JsonRetentionPrune, JsonUpdateRetention,
+    ProjectResourceId,
+};
+use bencher_rbac::project::Permission;
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    model::{
+        audit::InsertAuditEvent,
+        project::{QueryProject, retention::RetentionPolicy},
+        user::auth::{AuthUser, BearerToken},
+    },
+};
+use dropshot::{HttpError, Path, RequestContext, TypedBody, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjRetentionParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/retention",
+    tags = ["projects"]
+}]
+pub async fn proj_retention_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjRetentionParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Put.into()]))
+}
+
+/// View the retention policy for a project
+///
+/// View the data retention policy for a project.
+/// The user must have `view` permissions for the project.
+/// If a retention period is not set, then that data is kept forever.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/retention",
+    tags = ["projects"]
+}]
+pub async fn proj_retention_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+) -> Result<ResponseOk<JsonRetention>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_inner(
+    context: &ApiContext,
+    path_params: ProjRetentionParams,
+    auth_user: &AuthUser,
+) -> Result<JsonRetention, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+    Ok(
+        RetentionPolicy::get_for_project(conn_lock!(context), &query_project)?
+            .into_json_for_project(&query_project),
+    )
+}
+
+/// Update the retention policy for a project
+///
+/// Replace the data retention policy for a project.
+/// The user must have `delete` permissions for the project.
+/// The policy is enforced by the API server in the background about once an hour.
+/// Use the retention dry run endpoint to see what would be deleted before updating the policy.
+#[endpoint {
+    method = PUT,
+    path =  "/v0/projects/{project}/retention",
+    tags = ["projects"]
+}]
+pub async fn proj_retention_put(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+    body: TypedBody<JsonUpdateRetention>,
+) -> Result<ResponseOk<JsonRetention>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = put_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Put::auth_response_ok(json))
+}
+
+async fn put_inner(
+    context: &ApiContext,
+    path_params: ProjRetentionParams,
+    json_retention: JsonUpdateRetention,
+    auth_user: &AuthUser,
+) -> Result<JsonRetention, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+    let before_retention = RetentionPolicy::get_for_project(conn_lock!(context), &query_project)?
+        .into_json_for_project(&query_project);
+    let policy = RetentionPolicy::from(json_retention);
+    policy.update(conn_lock!(context), &query_project)?;
+    let json_retention = RetentionPolicy::get_for_project(conn_lock!(context), &query_project)?
+        .into_json_for_project(&query_project);
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Update,
+        AuditResource::Retention,
+        query_project.uuid,
+    )?;
+    audit_event
+        .before(&before_retention)
+        .after(&json_retention)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_retention)
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/retention/dry-run",
+    tags = ["projects"]
+}]
+pub async fn proj_retention_dry_run_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjRetentionParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// Dry run the retention policy for a project
+///
+/// Count all of the data that would be deleted by the current retention policy for a project,
+/// without deleting anything.
+/// The user must have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/retention/dry-run",
+    tags = ["projects"]
+}]
+pub async fn proj_retention_dry_run_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjRetentionParams>,
+) -> Result<ResponseOk<JsonRetentionPrune>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = dry_run_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn dry_run_inner(
+    context: &ApiContext,
+    path_params: ProjRetentionParams,
+    auth_user: &AuthUser,
+) -> Result<JsonRetentionPrune, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+    let policy = RetentionPolicy::get_for_project(conn_lock!(context), &query_project)?;
+    let db_connection = &context.database.connection;
+    policy
+        .plan(db_connection, query_project.id, DateTime::now())
+        .await?
+        .into_json(db_connection)
+        .await
+}
@@ -3,8 +3,9 @@ use bencher_endpoint::{
     TotalCount,
 };
 use bencher_json::{
-    JsonDirection, JsonNewTestbed, JsonPagination, JsonTestbed, JsonTestbeds, ProjectResourceId,
-    ResourceName, Search, TestbedResourceId, project::testbed::JsonUpdateTestbed,
+    AuditAction, AuditResource, JsonDirection, JsonNewTestbed, JsonPagination, JsonTestbed,
+    JsonTestbeds, ProjectResourceId, ResourceName, Search, TestbedResourceId,
+    project::testbed::JsonUpdateTestbed,
 };
 use bencher_rbac::project::Permission;
 use bencher_schema::{
@@ -12,6 +13,7 @@ use bencher_schema::{
     context::ApiContext,
     error::{resource_conflict_err, resource_not_found_err},
     model::{
+        audit::InsertAuditEvent,
         project::{
             QueryProject,
             testbed::{QueryTestbed, UpdateTestbed},
@@ -117,14 +119,17 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
-    let testbeds = get_ls_query(&query_project, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryTestbed>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Testbed,
-            (&query_project, &pagination_params, &query_params)
-        ))?;
+    let testbeds = order_ls_query(
+        get_ls_query(&query_project, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryTestbed>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Testbed,
+        (&query_project, &pagination_params, &query_params)
+    ))?;
 
     // Drop connection lock before iterating
     let json_testbeds = testbeds
@@ -132,7 +137,7 @@ async fn get_ls_inner(
         .map(|testbed| testbed.into_json_for_project(&query_project))
         .collect();
 
-    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
+    let total_count = get_ls_query(&query_project, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -146,9 +151,8 @@ async fn get_ls_inner(
 
 fn get_ls_query<'q>(
     query_project: &'q QueryProject,
-    pagination_params: &ProjTestbedsPagination,
     query_params: &'q ProjTestbedsQuery,
-) -> schema::testbed::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+) -> schema::testbed::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     let mut query = QueryTestbed::belonging_to(query_project).into_boxed();
 
     if let Some(name) = query_params.name.as_ref() {
@@ -169,6 +173,15 @@ fn get_ls_query<'q>(
         query = query.filter(schema::testbed::archived.is_null());
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::testbed::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &ProjTestbedsPagination,
+) -> schema::testbed::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         ProjTestbedsSort::Name => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order(schema::testbed::name.asc()),
@@ -218,9 +231,23 @@ async fn post_inner(
         Permission::Create,
     )?;
 
-    QueryTestbed::create(context, query_project.id, json_testbed)
-        .await
-        .map(|testbed| testbed.into_json_for_project(&query_project))
+    let json_testbed = QueryTestbed::create(context, query_project.id, json_testbed)
+        .await?
+        .into_json_for_project(&query_project);
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Create,
+        AuditResource::Testbed,
+        json_testbed.uuid,
+    )?;
+    audit_event
+        .after(&json_testbed)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_testbed)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -343,6 +370,15 @@ async fn patch_inner(
         query_project.id,
         &path_params.testbed,
     )?;
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Update,
+        AuditResource::Testbed,
+        query_testbed.uuid,
+    )?
+    .before(&query_testbed.clone().into_json_for_project(&query_project));
     let update_testbed = UpdateTestbed::from(json_testbed.clone());
     diesel::update(schema::testbed::table.filter(schema::testbed::id.eq(query_testbed.id)))
         .set(&update_testbed)
@@ -352,9 +388,15 @@ async fn patch_inner(
             (&query_testbed, &json_testbed)
         ))?;
 
-    QueryTestbed::get(conn_lock!(context), query_testbed.id)
+    let json_testbed = QueryTestbed::get(conn_lock!(context), query_testbed.id)
         .map(|testbed| testbed.into_json_for_project(&query_project))
-        .map_err(resource_not_found_err!(Testbed, query_testbed))
+        .map_err(resource_not_found_err!(Testbed, query_testbed))?;
+
+    audit_event
+        .after(&json_testbed)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_testbed)
 }
 
 /// Delete a testbed
@@ -396,10 +438,20 @@ async fn delete_inner(
         query_project.id,
         &path_params.testbed,
     )?;
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Delete,
+        AuditResource::Testbed,
+        query_testbed.uuid,
+    )?
+    .before(&query_testbed.clone().into_json_for_project(&query_project));
 
     diesel::delete(schema::testbed::table.filter(schema::testbed::id.eq(query_testbed.id)))
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Testbed, query_testbed))?;
+    audit_event.insert(conn_lock!(context))?;
 
     Ok(())
 }
@@ -3,7 +3,8 @@ use bencher_endpoint::{
     TotalCount,
 };
 use bencher_json::{
-    JsonDirection, JsonPagination, JsonThresholds, ModelUuid, ProjectResourceId, ThresholdUuid,
+    AuditAction, AuditResource, JsonDirection, JsonPagination, JsonThresholds, ModelUuid,
+    ProjectResourceId, ThresholdUuid,
     project::threshold::{
         JsonNewThreshold, JsonRemoveModel, JsonThreshold, JsonThresholdQuery,
         JsonThresholdQueryParams, JsonUpdateModel, JsonUpdateThreshold,
@@ -18,6 +19,7 @@ use bencher_schema::{
         resource_not_found_error,
     },
     model::{
+        audit::InsertAuditEvent,
         project::{
             QueryProject,
             branch::QueryBranch,
@@ -125,14 +127,17 @@ async fn get_ls_inner(
         auth_user,
     )?;
 
-    let thresholds = get_ls_query(&query_project, &pagination_params, &query_params)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryThreshold>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Threshold,
-            (&query_project, &pagination_params, &query_params)
-        ))?;
+    let thresholds = order_ls_query(
+        get_ls_query(&query_project, &query_params),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryThreshold>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Threshold,
+        (&query_project, &pagination_params, &query_params)
+    ))?;
 
     // Separate out these queries to prevent a deadlock when getting the conn_lock
     let mut json_thresholds = Vec::with_capacity(thresholds.len());
@@ -147,7 +152,7 @@ async fn get_ls_inner(
         }
     }
 
-    let total_count = get_ls_query(&query_project, &pagination_params, &query_params)
+    let total_count = get_ls_query(&query_project, &query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -161,7 +166,6 @@ async fn get_ls_inner(
 
 fn get_ls_query<'q>(
     query_project: &'q QueryProject,
-    pagination_params: &ProjThresholdsPagination,
     query_params: &'q JsonThresholdQuery,
 ) -> BoxedQuery<'q> {
     let mut query = QueryThreshold::belonging_to(query_project)
@@ -196,6 +200,15 @@ fn get_ls_query<'q>(
         );
     }
 
+    query.select(QueryThreshold::as_select())
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: BoxedQuery<'q>,
+    pagination_params: &ProjThresholdsPagination,
+) -> BoxedQuery<'q> {
     match pagination_params.order() {
         ProjThresholdsSort::Created => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => query.order(schema::threshold::created.asc()),
@@ -206,13 +219,12 @@ fn get_ls_query<'q>(
             Some(JsonDirection::Desc) | None => query.order(schema::threshold::modified.desc()),
         },
     }
-    .select(QueryThreshold::as_select())
 }
 
 // TODO refactor out internal types
 type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
     'q,
-    diesel::helper_types::AsSelect<QueryThreshold, diesel::sqlite::Sqlite>,
+    diesel::helper_types::AsSelect<QueryThreshold, bencher_schema::context::DbBackend>,
     diesel::internal::table_macro::FromClause<
         diesel::helper_types::InnerJoinQuerySource<
             diesel::helper_types::InnerJoinQuerySource<
@@ -225,14 +237,17 @@ type BoxedQuery<'q> = diesel::internal::table_macro::BoxedSelectStatement<
             schema::measure::table,
         >,
     >,
-    diesel::sqlite::Sqlite,
+    bencher_schema::context::DbBackend,
 >;
 
 /// Create a threshold
 ///
 /// Create a threshold for a project.
 /// The user must have `create` permissions for the project.
-/// There can only be one threshold for any unique combination of: branch, testbed, and measure.
+/// There can only be one threshold for any unique combination of: branch, testbed, measure, and benchmark tags.
+/// If `benchmark_tags` are set, the threshold only applies to benchmarks that match every tag selector.
+/// Each benchmark uses the matching threshold with the most tag selectors,
+/// falling back to the threshold without any benchmark tags.
 #[endpoint {
     method = POST,
     path =  "/v0/projects/{project}/thresholds",
@@ -289,6 +304,7 @@ async fn post_inner(
         branch_id,
         testbed_id,
         measure_id,
+        json_threshold.benchmark_tags.clone(),
         json_threshold.model,
     )
     .await?;
@@ -300,7 +316,21 @@ async fn post_inner(
         .map_err(resource_not_found_err!(Threshold, threshold_id))?;
 
     // Return the new threshold with the new model
-    query_threshold.into_json(context).await
+    let json_threshold = query_threshold.into_json(context).await?;
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Create,
+        AuditResource::Threshold,
+        json_threshold.uuid,
+    )?;
+    audit_event
+        .after(&json_threshold)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_threshold)
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -457,6 +487,7 @@ async fn put_inner(
     // Get the current threshold
     let query_threshold =
         QueryThreshold::get_with_uuid(conn_lock!(context), &query_project, path_params.threshold)?;
+    let before_threshold = query_threshold.clone().into_json(context).await?;
 
     // Update the current threshold with the new model, if changed
     query_threshold
@@ -467,7 +498,22 @@ async fn put_inner(
     let query_threshold = QueryThreshold::get(conn_lock!(context), query_threshold.id)?;
 
     // Return the updated threshold with the new model
-    query_threshold.into_json(context).await
+    let json_threshold = query_threshold.into_json(context).await?;
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Update,
+        AuditResource::Threshold,
+        json_threshold.uuid,
+    )?;
+    audit_event
+        .before(&before_threshold)
+        .after(&json_threshold)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_threshold)
 }
 
 /// Delete a threshold
@@ -506,10 +552,21 @@ async fn delete_inner(
 
     let query_threshold =
         QueryThreshold::get_with_uuid(conn_lock!(context), &query_project, path_params.threshold)?;
+    let before_threshold = query_threshold.clone().into_json(context).await?;
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Delete,
+        AuditResource::Threshold,
+        query_threshold.uuid,
+    )?
+    .before(&before_threshold);
 
     diesel::delete(schema::threshold::table.filter(schema::threshold::id.eq(query_threshold.id)))
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Threshold, query_threshold))?;
+    audit_event.insert(conn_lock!(context))?;
 
     Ok(())
 }
@@ -0,0 +1,399 @@
+use bencher_endpoint::{
+    CorsResponse, Delete, Endpoint, Get, Patch, Post, ResponseCreated, ResponseDeleted, ResponseOk,
+    TotalCount,
+};
+use bencher_json::{
+    AuditAction, AuditResource, JsonDirection, JsonNewWebhook, JsonPagination, JsonUpdateWebhook,
+    JsonWebhook, JsonWebhooks, ProjectResourceId, WebhookUuid,
+};
+use bencher_rbac::project::Permission;
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        audit::InsertAuditEvent,
+        project::{
+            QueryProject,
+            webhook::{InsertWebhook, QueryWebhook, UpdateWebhook},
+        },
+        user::auth::{AuthUser, BearerToken},
+    },
+    schema,
+};
+use diesel::{BelongingToDsl as _, ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::{HttpError, Path, Query, RequestContext, TypedBody, endpoint};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhooksParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+}
+
+pub type ProjWebhooksPagination = JsonPagination<ProjWebhooksSort>;
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjWebhooksSort {
+    /// Sort by webhook creation date time.
+    #[default]
+    Created,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhooksParams>,
+    _pagination_params: Query<ProjWebhooksPagination>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Post.into()]))
+}
+
+/// List webhooks for a project
+///
+/// List all webhooks for a project.
+/// The user must have `view` permissions for the project.
+/// By default, the webhooks are sorted by creation date time in chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of webhooks.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhooks_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    pagination_params: Query<ProjWebhooksPagination>,
+) -> Result<ResponseOk<JsonWebhooks>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        &auth_user,
+        path_params.into_inner(),
+        pagination_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    auth_user: &AuthUser,
+    path_params: ProjWebhooksParams,
+    pagination_params: ProjWebhooksPagination,
+) -> Result<(JsonWebhooks, TotalCount), HttpError> {
+    // Webhook URLs are not public, even for public projects.
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    let webhooks = order_ls_query(get_ls_query(&query_project), &pagination_params)
+        .offset(pagination_params.offset())
+        .limit(pagination_params.limit())
+        .load::<QueryWebhook>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Webhook,
+            (&query_project, &pagination_params)
+        ))?;
+
+    let json_webhooks = webhooks
+        .into_iter()
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+        .collect::<Vec<_>>();
+
+    let total_count = get_ls_query(&query_project)
+        .count()
+        .get_result::<i64>(conn_lock!(context))
+        .map_err(resource_not_found_err!(
+            Webhook,
+            (&query_project, &pagination_params)
+        ))?
+        .try_into()?;
+
+    Ok((json_webhooks.into(), total_count))
+}
+
+fn get_ls_query(
+    query_project: &QueryProject,
+) -> schema::webhook::BoxedQuery<'_, bencher_schema::context::DbBackend> {
+    QueryWebhook::belonging_to(query_project).into_boxed()
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::webhook::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &ProjWebhooksPagination,
+) -> schema::webhook::BoxedQuery<'q, bencher_schema::context::DbBackend> {
+    match pagination_params.order() {
+        ProjWebhooksSort::Created => match pagination_params.direction {
+            Some(JsonDirection::Asc) | None => {
+                query.order((schema::webhook::created.asc(), schema::webhook::id.asc()))
+            },
+            Some(JsonDirection::Desc) => {
+                query.order((schema::webhook::created.desc(), schema::webhook::id.desc()))
+            },
+        },
+    }
+}
+
+/// Create a webhook
+///
+/// Create a webhook for a project.
+/// The user must have `create` permissions for the project.
+/// An HTTP POST request is sent to the webhook URL whenever an alert is created or updated for the project.
+/// The request body is signed with the webhook secret using HMAC-SHA256,
+/// and the signature is sent in the `X-Bencher-Signature` header.
+#[endpoint {
+    method = POST,
+    path =  "/v0/projects/{project}/webhooks",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_post(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhooksParams>,
+    body: TypedBody<JsonNewWebhook>,
+) -> Result<ResponseCreated<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Post::auth_response_created(json))
+}
+
+async fn post_inner(
+    context: &ApiContext,
+    path_params: ProjWebhooksParams,
+    json_webhook: JsonNewWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Create,
+    )?;
+
+    let insert_webhook = InsertWebhook::from_json(query_project.id, json_webhook);
+    diesel::insert_into(schema::webhook::table)
+        .values(&insert_webhook)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(Webhook, insert_webhook))?;
+
+    let json_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, insert_webhook.uuid)
+            .map(|webhook| webhook.into_json_for_project(&query_project))?;
+
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Create,
+        AuditResource::Webhook,
+        json_webhook.uuid,
+    )?;
+    audit_event
+        .after(&json_webhook)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_webhook)
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ProjWebhookParams {
+    /// The slug or UUID for a project.
+    pub project: ProjectResourceId,
+    /// The UUID for a webhook.
+    pub webhook: WebhookUuid,
+}
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_options(
+    _rqctx: RequestContext<ApiContext>,
+    _path_params: Path<ProjWebhookParams>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into(), Patch.into(), Delete.into()]))
+}
+
+/// View a webhook
+///
+/// View a webhook for a project.
+/// The user must have `view` permissions for the project.
+#[endpoint {
+    method = GET,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = get_one_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Get::auth_response_ok(json))
+}
+
+async fn get_one_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::View,
+    )?;
+
+    QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+}
+
+/// Update a webhook
+///
+/// Update a webhook for a project.
+/// The user must have `edit` permissions for the project.
+#[endpoint {
+    method = PATCH,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_patch(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+    body: TypedBody<JsonUpdateWebhook>,
+) -> Result<ResponseOk<JsonWebhook>, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = patch_inner(
+        rqctx.context(),
+        path_params.into_inner(),
+        body.into_inner(),
+        &auth_user,
+    )
+    .await?;
+    Ok(Patch::auth_response_ok(json))
+}
+
+async fn patch_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    json_webhook: JsonUpdateWebhook,
+    auth_user: &AuthUser,
+) -> Result<JsonWebhook, HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Edit,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)?;
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Update,
+        AuditResource::Webhook,
+        query_webhook.uuid,
+    )?
+    .before(&query_webhook.clone().into_json_for_project(&query_project));
+    let update_webhook = UpdateWebhook::from(json_webhook.clone());
+    diesel::update(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+        .set(&update_webhook)
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(
+            Webhook,
+            (&query_webhook, &json_webhook)
+        ))?;
+
+    let json_webhook = QueryWebhook::get(conn_lock!(context), query_webhook.id)
+        .map(|webhook| webhook.into_json_for_project(&query_project))
+        .map_err(resource_not_found_err!(Webhook, query_webhook))?;
+
+    audit_event
+        .after(&json_webhook)
+        .insert(conn_lock!(context))?;
+
+    Ok(json_webhook)
+}
+
+/// Delete a webhook
+///
+/// Delete a webhook for a project.
+/// The user must have `delete` permissions for the project.
+#[endpoint {
+    method = DELETE,
+    path =  "/v0/projects/{project}/webhooks/{webhook}",
+    tags = ["projects", "webhooks"]
+}]
+pub async fn proj_webhook_delete(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    path_params: Path<ProjWebhookParams>,
+) -> Result<ResponseDeleted, HttpError> {
+    let auth_user = AuthUser::from_token(rqctx.context(), bearer_token).await?;
+    delete_inner(rqctx.context(), path_params.into_inner(), &auth_user).await?;
+    Ok(Delete::auth_response_deleted())
+}
+
+async fn delete_inner(
+    context: &ApiContext,
+    path_params: ProjWebhookParams,
+    auth_user: &AuthUser,
+) -> Result<(), HttpError> {
+    // Verify that the user is allowed
+    let query_project = QueryProject::is_allowed(
+        conn_lock!(context),
+        &context.rbac,
+        &path_params.project,
+        auth_user,
+        Permission::Delete,
+    )?;
+
+    let query_webhook =
+        QueryWebhook::get_with_uuid(conn_lock!(context), &query_project, path_params.webhook)?;
+    let audit_event = InsertAuditEvent::project(
+        conn_lock!(context),
+        auth_user,
+        &query_project,
+        AuditAction::Delete,
+        AuditResource::Webhook,
+        query_webhook.uuid,
+    )?
+    .before(&query_webhook.clone().into_json_for_project(&query_project));
+
+    diesel::delete(schema::webhook::table.filter(schema::webhook::id.eq(query_webhook.id)))
+        .execute(conn_lock!(context))
+        .map_err(resource_conflict_err!(Webhook, query_webhook))?;
+    audit_event.insert(conn_lock!(context))?;
+
+    Ok(())
+}
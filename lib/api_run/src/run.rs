@@ -103,7 +103,7 @@ async fn post_inner(
             // If the user is authenticated, then we may have created a new role for them.
             // If so then we need to reload the permissions.
             let auth_user = auth_user.reload(conn_lock!(context))?;
-            query_project.try_allowed(&context.rbac, &auth_user, Permission::Create)?;
+            query_project.try_allowed(&context.rbac, &auth_user, Permission::CreateReport)?;
         } else {
             return Err(unauthorized_error(format!(
                 "This project ({}) has already been claimed. Provide a valid API token (`--token`) to authenticate.",
@@ -0,0 +1,65 @@
+use bencher_endpoint::{CorsResponse, Endpoint, Get, ResponseOk, TotalCount};
+use bencher_json::{JsonAuditEvents, JsonAuditQuery};
+use bencher_schema::{
+    conn_lock,
+    context::ApiContext,
+    model::{
+        audit::{AuditEventsPagination, AuditScope, QueryAuditEvent},
+        user::{admin::AdminUser, auth::BearerToken},
+    },
+};
+use dropshot::{HttpError, Query, RequestContext, endpoint};
+
+#[endpoint {
+    method = OPTIONS,
+    path =  "/v0/server/audit",
+    tags = ["server", "audit"]
+}]
+pub async fn server_audit_options(
+    _rqctx: RequestContext<ApiContext>,
+    _pagination_params: Query<AuditEventsPagination>,
+    _query_params: Query<JsonAuditQuery>,
+) -> Result<CorsResponse, HttpError> {
+    Ok(Endpoint::cors(&[Get.into()]))
+}
+
+/// List audit events for the server
+///
+/// List all audit events on the server, across all organizations and projects.
+/// The user must be an admin on the server to use this route.
+/// By default, the audit events are sorted in reverse chronological order.
+/// The HTTP response header `X-Total-Count` contains the total number of audit events.
+#[endpoint {
+    method = GET,
+    path =  "/v0/server/audit",
+    tags = ["server", "audit"]
+}]
+pub async fn server_audit_get(
+    rqctx: RequestContext<ApiContext>,
+    bearer_token: BearerToken,
+    pagination_params: Query<AuditEventsPagination>,
+    query_params: Query<JsonAuditQuery>,
+) -> Result<ResponseOk<JsonAuditEvents>, HttpError> {
+    let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    let (json, total_count) = get_ls_inner(
+        rqctx.context(),
+        pagination_params.into_inner(),
+        query_params.into_inner(),
+    )
+    .await?;
+    Ok(Get::auth_response_ok_with_total_count(json, total_count))
+}
+
+async fn get_ls_inner(
+    context: &ApiContext,
+    pagination_params: AuditEventsPagination,
+    query_params: JsonAuditQuery,
+) -> Result<(JsonAuditEvents, TotalCount), HttpError> {
+    let (json_audit_events, total_count) = QueryAuditEvent::list(
+        conn_lock!(context),
+        AuditScope::Server,
+        &pagination_params,
+        &query_params,
+    )?;
+    Ok((json_audit_events, total_count.try_into()?))
+}
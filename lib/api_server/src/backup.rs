@@ -1,9 +1,11 @@
 use bencher_endpoint::{CorsResponse, Endpoint, Post, ResponseCreated};
-use bencher_json::{JsonBackup, JsonBackupCreated, JsonRestart};
+use bencher_json::{AuditAction, JsonBackup, JsonBackupCreated, JsonRestart};
 use bencher_schema::{
+    conn_lock,
     context::ApiContext,
     error::bad_request_error,
     model::{
+        audit::InsertAuditEvent,
         server::ServerBackup,
         user::{admin::AdminUser, auth::BearerToken},
     },
@@ -36,17 +38,22 @@ pub async fn server_backup_post(
     bearer_token: BearerToken,
     body: TypedBody<JsonBackup>,
 ) -> Result<ResponseCreated<JsonBackupCreated>, HttpError> {
-    let _admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
-    let json = post_inner(rqctx.context(), body.into_inner()).await?;
+    let admin_user = AdminUser::from_token(rqctx.context(), bearer_token).await?;
+    let json = post_inner(rqctx.context(), body.into_inner(), &admin_user).await?;
     Ok(Post::auth_response_created(json))
 }
 
 async fn post_inner(
     context: &ApiContext,
     json_backup: JsonBackup,
+    admin_user: &AdminUser,
 ) -> Result<JsonBackupCreated, HttpError> {
+    InsertAuditEvent::server(admin_user.user(), AuditAction::Backup)
+        .after(&json_backup)
+        .insert(conn_lock!(context))?;
+
     ServerBackup::run(
-        context.database.path.clone(),
+        context.database.url.clone(),
         context.database.data_store.as_ref(),
         json_backup,
     )
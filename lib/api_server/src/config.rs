@@ -1,15 +1,19 @@
 use bencher_config::{BENCHER_CONFIG, Config};
 use bencher_endpoint::{CorsResponse, Endpoint, Get, Put, ResponseAccepted, ResponseOk};
 use bencher_json::{
-    JsonConfig,
+    AuditAction, JsonConfig, Sanitize as _,
     system::config::{JsonConsole, JsonUpdateConfig},
 };
 use bencher_schema::{
+    conn_lock,
     context::ApiContext,
     error::{bad_request_error, issue_error},
-    model::user::{
-        admin::AdminUser,
-        auth::{AuthUser, BearerToken, PubBearerToken},
+    model::{
+        audit::InsertAuditEvent,
+        user::{
+            admin::AdminUser,
+            auth::{AuthUser, BearerToken, PubBearerToken},
+        },
     },
 };
 use dropshot::{HttpError, RequestContext, TypedBody, endpoint};
@@ -87,6 +91,8 @@ async fn put_inner(
     admin_user: &AdminUser,
 ) -> Result<JsonConfig, HttpError> {
     let JsonUpdateConfig { config, delay } = json_config;
+    let mut before_config = get_one_inner(log).await?;
+    before_config.sanitize();
 
     // TODO add validation here
     let config_str = serde_json::to_string(&config).map_err(bad_request_error)?;
@@ -105,7 +111,15 @@ async fn put_inner(
                 e,
             )
         })?;
-    let json_config = serde_json::from_str(&config_str).map_err(bad_request_error)?;
+    let json_config: JsonConfig = serde_json::from_str(&config_str).map_err(bad_request_error)?;
+
+    // Never record any secrets in the audit log
+    let mut after_config = json_config.clone();
+    after_config.sanitize();
+    InsertAuditEvent::server(admin_user.user(), AuditAction::Update)
+        .before(&before_config)
+        .after(&after_config)
+        .insert(conn_lock!(context))?;
 
     countdown(log, context.restart_tx.clone(), delay, admin_user.user().id);
 
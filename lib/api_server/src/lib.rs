@@ -1,3 +1,4 @@
+mod audit;
 mod backup;
 mod config;
 mod restart;
@@ -30,6 +31,7 @@ impl bencher_endpoint::Registrar for Api {
             api_description.register(config::server_config_options)?;
             api_description.register(config::server_config_console_options)?;
             api_description.register(backup::server_backup_options)?;
+            api_description.register(audit::server_audit_options)?;
         }
         api_description.register(version::server_version_get)?;
         api_description.register(spec::server_spec_get)?;
@@ -38,6 +40,7 @@ impl bencher_endpoint::Registrar for Api {
         api_description.register(config::server_config_put)?;
         api_description.register(config::server_config_console_get)?;
         api_description.register(backup::server_backup_post)?;
+        api_description.register(audit::server_audit_get)?;
 
         #[cfg(feature = "plus")]
         {
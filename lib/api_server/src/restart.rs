@@ -1,8 +1,12 @@
 use bencher_endpoint::{CorsResponse, Endpoint, Post, ResponseAccepted};
-use bencher_json::JsonRestart;
+use bencher_json::{AuditAction, JsonRestart};
 use bencher_schema::{
+    conn_lock,
     context::ApiContext,
-    model::user::{UserId, admin::AdminUser, auth::BearerToken},
+    model::{
+        audit::InsertAuditEvent,
+        user::{UserId, admin::AdminUser, auth::BearerToken},
+    },
 };
 use dropshot::{HttpError, RequestContext, TypedBody, endpoint};
 use slog::{Logger, error, warn};
@@ -41,13 +45,16 @@ pub async fn server_restart_post(
     Ok(Post::auth_response_accepted(()))
 }
 
-#[expect(clippy::unused_async, reason = "For consistency with other endpoints")]
 async fn post_inner(
     log: &Logger,
     context: &ApiContext,
     json_restart: JsonRestart,
     admin_user: &AdminUser,
 ) -> Result<(), HttpError> {
+    InsertAuditEvent::server(admin_user.user(), AuditAction::Restart)
+        .after(&json_restart)
+        .insert(conn_lock!(context))?;
+
     countdown(
         log,
         context.restart_tx.clone(),
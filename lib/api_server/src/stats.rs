@@ -47,8 +47,8 @@ pub async fn server_stats_get(
 
 async fn get_one_inner(log: &Logger, context: &ApiContext) -> Result<JsonServerStats, HttpError> {
     let query_server = QueryServer::get_server(conn_lock!(context))?;
-    let db_path = context.database.path.clone();
-    query_server.get_stats(log.clone(), db_path).await
+    let database_url = context.database.url.clone();
+    query_server.get_stats(log.clone(), database_url).await
 }
 
 // TODO remove in due time
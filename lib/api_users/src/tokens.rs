@@ -2,18 +2,21 @@ use bencher_endpoint::{
     CorsResponse, Endpoint, Get, Patch, Post, ResponseCreated, ResponseOk, TotalCount,
 };
 use bencher_json::{
-    JsonDirection, JsonNewToken, JsonPagination, JsonToken, JsonTokens, ResourceName, Search,
-    UserResourceId, user::token::JsonUpdateToken,
+    AuditAction, AuditResource, JsonDirection, JsonNewToken, JsonPagination, JsonToken, JsonTokens,
+    ResourceName, Search, UserResourceId, user::token::JsonUpdateToken,
 };
 use bencher_schema::{
     conn_lock,
     context::ApiContext,
     error::{resource_conflict_err, resource_not_found_err},
-    model::user::{
-        QueryUser, UserId,
-        auth::{AuthUser, BearerToken},
-        same_user,
-        token::{InsertToken, QueryToken, UpdateToken},
+    model::{
+        audit::InsertAuditEvent,
+        user::{
+            QueryUser, UserId,
+            auth::{AuthUser, BearerToken},
+            same_user,
+            token::{InsertToken, QueryToken, UpdateToken},
+        },
     },
     schema,
 };
@@ -103,14 +106,17 @@ async fn get_ls_inner(
     let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
     same_user!(auth_user, context.rbac, query_user.uuid);
 
-    let tokens = get_ls_query(&pagination_params, &query_params, query_user.id)
-        .offset(pagination_params.offset())
-        .limit(pagination_params.limit())
-        .load::<QueryToken>(conn_lock!(context))
-        .map_err(resource_not_found_err!(
-            Token,
-            (&pagination_params, &query_params, auth_user)
-        ))?;
+    let tokens = order_ls_query(
+        get_ls_query(&query_params, query_user.id),
+        &pagination_params,
+    )
+    .offset(pagination_params.offset())
+    .limit(pagination_params.limit())
+    .load::<QueryToken>(conn_lock!(context))
+    .map_err(resource_not_found_err!(
+        Token,
+        (&pagination_params, &query_params, auth_user)
+    ))?;
 
     // Drop connection lock before iterating
     let json_tokens = tokens
@@ -118,7 +124,7 @@ async fn get_ls_inner(
         .map(|query_token| query_token.into_json_for_user(auth_user))
         .collect();
 
-    let total_count = get_ls_query(&pagination_params, &query_params, query_user.id)
+    let total_count = get_ls_query(&query_params, query_user.id)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(
@@ -130,11 +136,10 @@ async fn get_ls_inner(
     Ok((json_tokens, total_count))
 }
 
-fn get_ls_query<'q>(
-    pagination_params: &UserTokensPagination,
-    query_params: &'q UserTokensQuery,
+fn get_ls_query(
+    query_params: &UserTokensQuery,
     user_id: UserId,
-) -> schema::token::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+) -> schema::token::BoxedQuery<'_, bencher_schema::context::DbBackend> {
     let mut query = schema::token::table
         .filter(schema::token::user_id.eq(user_id))
         .into_boxed();
@@ -150,6 +155,15 @@ fn get_ls_query<'q>(
         );
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::token::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &UserTokensPagination,
+) -> schema::token::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         UserTokensSort::Name => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => {
@@ -166,6 +180,8 @@ fn get_ls_query<'q>(
 ///
 /// Create an API token for a user.
 /// Only the authenticated user themselves and server admins have access to this endpoint.
+/// The token can be restricted to specific organizations and projects with a maximum permission.
+/// A scoped API token cannot be used to create another token.
 #[endpoint {
     method = POST,
     path =  "/v0/users/{user}/tokens",
@@ -197,6 +213,8 @@ async fn post_inner(
     #[cfg(feature = "plus")]
     context.rate_limiting.create_token(auth_user.user.uuid)?;
 
+    // The token itself is a secret, so only the request is recorded in the audit log
+    let audit_json = json_token.clone();
     let insert_token = InsertToken::from_json(
         conn_lock!(context),
         &context.rbac,
@@ -211,6 +229,15 @@ async fn post_inner(
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Token, insert_token))?;
 
+    InsertAuditEvent::user(
+        auth_user,
+        AuditAction::Create,
+        AuditResource::Token,
+        insert_token.uuid,
+    )
+    .after(&audit_json)
+    .insert(conn_lock!(context))?;
+
     conn_lock!(context, |conn| schema::token::table
         .filter(schema::token::uuid.eq(&insert_token.uuid))
         .first::<QueryToken>(conn)
@@ -281,6 +308,7 @@ async fn get_one_inner(
 ///
 /// Update an API token for a user.
 /// Only the authenticated user themselves and server admins have access to this endpoint.
+/// A scoped API token cannot be used to update a token.
 #[endpoint {
     method = PATCH,
     path =  "/v0/users/{user}/tokens/{token}",
@@ -311,6 +339,7 @@ async fn patch_inner(
 ) -> Result<JsonToken, HttpError> {
     let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
     same_user!(auth_user, context.rbac, query_user.uuid);
+    auth_user.check_is_unscoped()?;
 
     let query_token = QueryToken::get_user_token(
         conn_lock!(context),
@@ -318,11 +347,19 @@ async fn patch_inner(
         &path_params.token.to_string(),
     )?;
 
+    let audit_event = InsertAuditEvent::user(
+        auth_user,
+        AuditAction::Update,
+        AuditResource::Token,
+        query_token.uuid,
+    )
+    .after(&json_token);
     let update_token = UpdateToken::from(json_token);
     diesel::update(schema::token::table.filter(schema::token::id.eq(query_token.id)))
         .set(&update_token)
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(Token, (&query_user, &query_token)))?;
+    audit_event.insert(conn_lock!(context))?;
 
     conn_lock!(context, |conn| QueryToken::get(conn, query_token.id)?
         .into_json(conn))
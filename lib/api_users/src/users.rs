@@ -1,17 +1,20 @@
 use bencher_endpoint::{CorsResponse, Endpoint, Get, Patch, ResponseOk, TotalCount};
 use bencher_json::{
-    JsonDirection, JsonPagination, JsonUpdateUser, JsonUser, Sanitize as _, Search, UserName,
-    UserResourceId, user::JsonUsers,
+    AuditAction, AuditResource, JsonDirection, JsonPagination, JsonUpdateUser, JsonUser,
+    Sanitize as _, Search, UserName, UserResourceId, user::JsonUsers,
 };
 use bencher_schema::{
     conn_lock,
     context::ApiContext,
     error::{forbidden_error, resource_conflict_err, resource_not_found_err},
-    model::user::{
-        QueryUser, UpdateUser,
-        admin::AdminUser,
-        auth::{AuthUser, BearerToken},
-        same_user,
+    model::{
+        audit::InsertAuditEvent,
+        user::{
+            QueryUser, UpdateUser,
+            admin::AdminUser,
+            auth::{AuthUser, BearerToken},
+            same_user,
+        },
     },
     schema,
 };
@@ -85,7 +88,7 @@ async fn get_ls_inner(
     pagination_params: UsersPagination,
     query_params: UsersQuery,
 ) -> Result<(JsonUsers, TotalCount), HttpError> {
-    let users = get_ls_query(&pagination_params, &query_params)
+    let users = order_ls_query(get_ls_query(&query_params), &pagination_params)
         .offset(pagination_params.offset())
         .limit(pagination_params.limit())
         .load::<QueryUser>(conn_lock!(context))
@@ -94,7 +97,7 @@ async fn get_ls_inner(
     // Drop connection lock before iterating
     let json_users = users.into_iter().map(QueryUser::into_json).collect();
 
-    let total_count = get_ls_query(&pagination_params, &query_params)
+    let total_count = get_ls_query(&query_params)
         .count()
         .get_result::<i64>(conn_lock!(context))
         .map_err(resource_not_found_err!(User))?
@@ -103,10 +106,9 @@ async fn get_ls_inner(
     Ok((json_users, total_count))
 }
 
-fn get_ls_query<'q>(
-    pagination_params: &UsersPagination,
-    query_params: &'q UsersQuery,
-) -> schema::user::BoxedQuery<'q, diesel::sqlite::Sqlite> {
+fn get_ls_query(
+    query_params: &UsersQuery,
+) -> schema::user::BoxedQuery<'_, bencher_schema::context::DbBackend> {
     let mut query = schema::user::table.into_boxed();
 
     if let Some(name) = query_params.name.as_ref() {
@@ -121,6 +123,15 @@ fn get_ls_query<'q>(
         );
     }
 
+    query
+}
+
+// The ordering is kept separate from the filters,
+// because PostgreSQL does not allow ordering the total count query.
+fn order_ls_query<'q>(
+    query: schema::user::BoxedQuery<'q, bencher_schema::context::DbBackend>,
+    pagination_params: &UsersPagination,
+) -> schema::user::BoxedQuery<'q, bencher_schema::context::DbBackend> {
     match pagination_params.order() {
         UsersSort::Name => match pagination_params.direction {
             Some(JsonDirection::Asc) | None => {
@@ -218,6 +229,7 @@ async fn patch_inner(
 ) -> Result<JsonUser, HttpError> {
     let query_user = QueryUser::from_resource_id(conn_lock!(context), &path_params.user)?;
     same_user!(auth_user, context.rbac, query_user.uuid);
+    auth_user.check_is_unscoped()?;
 
     let admin_only_error = |field: &str| {
         let mut auth_user = auth_user.clone();
@@ -239,5 +251,16 @@ async fn patch_inner(
         .execute(conn_lock!(context))
         .map_err(resource_conflict_err!(User, (&query_user, &json_user)))?;
 
-    Ok(QueryUser::get(conn_lock!(context), query_user.id)?.into_json())
+    let json_user = QueryUser::get(conn_lock!(context), query_user.id)?.into_json();
+    InsertAuditEvent::user(
+        auth_user,
+        AuditAction::Update,
+        AuditResource::User,
+        query_user.uuid,
+    )
+    .before(&query_user.into_json())
+    .after(&json_user)
+    .insert(conn_lock!(context))?;
+
+    Ok(json_user)
 }
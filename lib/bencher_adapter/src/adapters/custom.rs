@@ -0,0 +1,325 @@
+use std::{collections::HashMap, str::FromStr as _};
+
+use bencher_json::{
+    BenchmarkName, JsonNewMetric, MeasureNameId,
+    project::{
+        measure::built_in::{BuiltInMeasure as _, default::Latency},
+        report::JsonAdapterSpec,
+    },
+};
+use ordered_float::OrderedFloat;
+use regex::{Captures, Regex};
+
+use crate::{
+    Adaptable, AdapterError, Settings,
+    results::{adapter_metrics::AdapterMetrics, adapter_results::AdapterResults},
+};
+
+const NAME: &str = "name";
+const VALUE: &str = "value";
+const LOWER_VALUE: &str = "lower_value";
+const UPPER_VALUE: &str = "upper_value";
+const UNIT: &str = "unit";
+
+#[derive(Debug, Clone)]
+pub struct AdapterCustom {
+    regex: Regex,
+    measure: MeasureNameId,
+    units: HashMap<String, (MeasureNameId, f64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomLine<'i> {
+    /// The line matched and was converted into a metric.
+    Metric {
+        benchmark_name: BenchmarkName,
+        measure: MeasureNameId,
+        metric: JsonNewMetric,
+    },
+    /// The line matched, but it could not be converted into a metric.
+    Invalid(String),
+    /// The line did not match.
+    NoMatch(&'i str),
+}
+
+impl AdapterCustom {
+    pub fn new(spec: &JsonAdapterSpec) -> Result<Self, AdapterError> {
+        let JsonAdapterSpec {
+            regex,
+            measure,
+            units,
+        } = spec;
+
+        let regex = Regex::new(regex).map_err(|e| AdapterError::Regex(regex.clone(), e))?;
+        for group in [NAME, VALUE] {
+            if !regex.capture_names().flatten().any(|name| name == group) {
+                return Err(AdapterError::CaptureGroup(regex.to_string(), group));
+            }
+        }
+
+        let measure = if let Some(measure) = measure {
+            measure.clone()
+        } else {
+            MeasureNameId::from_str(Latency::SLUG_STR)?
+        };
+        let units = units
+            .iter()
+            .flatten()
+            .map(|(unit, spec_unit)| {
+                (
+                    unit.clone(),
+                    (spec_unit.measure.clone(), spec_unit.scale.unwrap_or(1.0)),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            regex,
+            measure,
+            units,
+        })
+    }
+
+    /// Parse each line of the input, keeping track of whether it matched.
+    /// This is useful for debugging a custom adapter spec.
+    pub fn lines<'i>(&self, input: &'i str) -> Vec<CustomLine<'i>> {
+        input
+            .lines()
+            .map(|line| match self.regex.captures(line) {
+                Some(captures) => self.parse_captures(&captures),
+                None => CustomLine::NoMatch(line),
+            })
+            .collect()
+    }
+
+    fn parse_captures(&self, captures: &Captures<'_>) -> CustomLine<'static> {
+        let capture = |group| captures.name(group).map(|m| m.as_str().trim());
+
+        let Some(name) = capture(NAME) else {
+            return CustomLine::Invalid("Missing benchmark name".to_owned());
+        };
+        let benchmark_name = match name.parse::<BenchmarkName>() {
+            Ok(benchmark_name) => benchmark_name,
+            Err(e) => return CustomLine::Invalid(format!("Invalid benchmark name ({name}): {e}")),
+        };
+
+        let (measure, scale) = match capture(UNIT) {
+            Some(unit) => {
+                let Some((measure, scale)) = self.units.get(unit) else {
+                    return CustomLine::Invalid(format!("Unknown unit ({unit})"));
+                };
+                (measure.clone(), *scale)
+            },
+            None => (self.measure.clone(), 1.0),
+        };
+
+        let parse_value = |group| -> Result<Option<OrderedFloat<f64>>, String> {
+            capture(group)
+                .filter(|value| !value.is_empty())
+                .map(|value| {
+                    parse_number(value)
+                        .map(|number| OrderedFloat::from(number * scale))
+                        .ok_or_else(|| format!("Invalid {group} ({value})"))
+                })
+                .transpose()
+        };
+        let metric = match (
+            parse_value(VALUE),
+            parse_value(LOWER_VALUE),
+            parse_value(UPPER_VALUE),
+        ) {
+            (Ok(Some(value)), Ok(lower_value), Ok(upper_value)) => JsonNewMetric {
+                value,
+                lower_value,
+                upper_value,
+            },
+            (Ok(None), _, _) => return CustomLine::Invalid(format!("Missing {VALUE}")),
+            (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => return CustomLine::Invalid(e),
+        };
+
+        CustomLine::Metric {
+            benchmark_name,
+            measure,
+            metric,
+        }
+    }
+}
+
+// Thousands separators are ignored.
+fn parse_number(value: &str) -> Option<f64> {
+    value
+        .replace([',', '_'], "")
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+}
+
+impl Adaptable for AdapterCustom {
+    fn convert(&self, input: &str, _settings: Settings) -> Option<AdapterResults> {
+        let mut results = HashMap::new();
+        for line in self.lines(input) {
+            if let CustomLine::Metric {
+                benchmark_name,
+                measure,
+                metric,
+            } = line
+            {
+                // If the same benchmark and measure is matched more than once, the last one is used.
+                results
+                    .entry(benchmark_name)
+                    .or_insert_with(AdapterMetrics::default)
+                    .inner
+                    .insert(measure, metric);
+            }
+        }
+
+        if results.is_empty() {
+            None
+        } else {
+            Some(results.into())
+        }
+    }
+
+    // A custom adapter requires a spec, so it can only be used through `convert`.
+    fn parse(_input: &str, _settings: Settings) -> Option<AdapterResults> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test_custom {
+    use std::collections::HashMap;
+
+    use bencher_json::project::report::{JsonAdapterSpec, JsonAdapterSpecUnit};
+    use pretty_assertions::assert_eq;
+
+    use super::{AdapterCustom, CustomLine};
+    use crate::{
+        Adaptable as _, AdapterError, Settings,
+        adapters::test_util::{validate_latency, validate_metric, validate_throughput},
+    };
+
+    const REGEX: &str = r"^bench (?<name>\S+)\s+(?<value>[\d.,]+)\s*(?<unit>\S+)(?: \[(?<lower_value>[\d.]+), (?<upper_value>[\d.]+)\])?$";
+
+    fn spec() -> JsonAdapterSpec {
+        JsonAdapterSpec {
+            regex: REGEX.to_owned(),
+            measure: None,
+            units: Some(HashMap::from([
+                (
+                    "ns".to_owned(),
+                    JsonAdapterSpecUnit {
+                        measure: "latency".parse().unwrap(),
+                        scale: None,
+                    },
+                ),
+                (
+                    "ms".to_owned(),
+                    JsonAdapterSpecUnit {
+                        measure: "latency".parse().unwrap(),
+                        scale: Some(1_000_000.0),
+                    },
+                ),
+                (
+                    "ops/s".to_owned(),
+                    JsonAdapterSpecUnit {
+                        measure: "throughput".parse().unwrap(),
+                        scale: None,
+                    },
+                ),
+            ])),
+        }
+    }
+
+    const OUTPUT: &str = "Running in-house harness v2
+bench parse_small 1,234 ns
+bench parse_large 1.5 ms [1.25, 1.75]
+bench throughput 42000 ops/s
+bench unknown_unit 7 furlongs
+done";
+
+    #[test]
+    fn adapter_custom() {
+        let adapter = AdapterCustom::new(&spec()).unwrap();
+        let results = adapter.convert(OUTPUT, Settings::default()).unwrap();
+        assert_eq!(results.inner.len(), 3);
+
+        let metrics = results.get("parse_small").unwrap();
+        validate_latency(metrics, 1234.0, None, None);
+
+        let metrics = results.get("parse_large").unwrap();
+        validate_latency(metrics, 1_500_000.0, Some(1_250_000.0), Some(1_750_000.0));
+
+        let metrics = results.get("throughput").unwrap();
+        validate_throughput(metrics, 42000.0, None, None);
+    }
+
+    #[test]
+    fn adapter_custom_measure() {
+        let spec = JsonAdapterSpec {
+            regex: r"^(?<name>\w+): (?<value>\d+)$".to_owned(),
+            measure: Some("file-size".parse().unwrap()),
+            units: None,
+        };
+        let adapter = AdapterCustom::new(&spec).unwrap();
+        let results = adapter
+            .convert("binary: 2048\nlibrary: 512", Settings::default())
+            .unwrap();
+        assert_eq!(results.inner.len(), 2);
+        let metrics = results.get("binary").unwrap();
+        validate_metric(metrics, "file-size", 2048.0, None, None);
+        let metrics = results.get("library").unwrap();
+        validate_metric(metrics, "file-size", 512.0, None, None);
+    }
+
+    #[test]
+    fn adapter_custom_lines() {
+        let adapter = AdapterCustom::new(&spec()).unwrap();
+        let lines = adapter.lines(OUTPUT);
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines.first(),
+            Some(&CustomLine::NoMatch("Running in-house harness v2"))
+        );
+        assert!(matches!(lines.get(1), Some(CustomLine::Metric { .. })));
+        assert!(matches!(lines.get(2), Some(CustomLine::Metric { .. })));
+        assert!(matches!(lines.get(3), Some(CustomLine::Metric { .. })));
+        assert_eq!(
+            lines.get(4),
+            Some(&CustomLine::Invalid("Unknown unit (furlongs)".to_owned()))
+        );
+        assert_eq!(lines.get(5), Some(&CustomLine::NoMatch("done")));
+    }
+
+    #[test]
+    fn adapter_custom_no_match() {
+        let adapter = AdapterCustom::new(&spec()).unwrap();
+        assert_eq!(
+            adapter.convert("nothing to see here", Settings::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn adapter_custom_invalid_spec() {
+        let spec = JsonAdapterSpec {
+            regex: r"^(?<name>\w+: (?<value>\d+)$".to_owned(),
+            measure: None,
+            units: None,
+        };
+        assert!(matches!(
+            AdapterCustom::new(&spec),
+            Err(AdapterError::Regex(..))
+        ));
+
+        let spec = JsonAdapterSpec {
+            regex: r"^(?<name>\w+): \d+$".to_owned(),
+            measure: None,
+            units: None,
+        };
+        assert!(matches!(
+            AdapterCustom::new(&spec),
+            Err(AdapterError::CaptureGroup(_, "value"))
+        ));
+    }
+}
@@ -1,20 +1,19 @@
 use bencher_json::{BenchmarkName, JsonNewMetric, project::report::JsonAverage};
 use nom::{
     IResult,
-    branch::alt,
-    bytes::complete::{tag, take_till1},
-    character::complete::{anychar, space1},
+    bytes::complete::take_till1,
+    character::complete::space1,
     combinator::{eof, map, map_res},
-    multi::many_till,
-    sequence::tuple,
+    multi::many1,
+    sequence::{preceded, tuple},
 };
 
 use crate::{
     Adaptable, Settings,
     adapters::util::{
-        NomError, latency_as_nanos, parse_benchmark_name, parse_f64, parse_u64, parse_units,
+        NomError, Units, latency_as_nanos, nom_error, parse_benchmark_name, parse_f64, parse_u64,
     },
-    results::adapter_results::AdapterResults,
+    results::adapter_results::{AdapterResults, GoMeasure},
 };
 
 pub struct AdapterGoBench;
@@ -36,55 +35,89 @@ impl Adaptable for AdapterGoBench {
             }
         }
 
-        AdapterResults::new_latency(benchmark_metrics)
+        AdapterResults::new_go(benchmark_metrics)
     }
 }
 
-fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, JsonNewMetric)> {
+fn parse_go(input: &str) -> IResult<&str, (BenchmarkName, Vec<GoMeasure>)> {
     map_res(
         tuple((
             take_till1(|c| c == ' ' || c == '\t'),
             space1,
             parse_u64,
-            space1,
-            parse_go_bench,
-            alt((
-                map(eof, |_| ()),
-                map(
-                    tuple((space1, parse_f64, space1, many_till(anychar, eof))),
-                    |_| (),
-                ),
-            )),
+            many1(preceded(space1, parse_go_bench)),
+            eof,
         )),
-        |(name, _, _iter, _, json_metric, ())| -> Result<(BenchmarkName, JsonNewMetric), NomError> {
+        |(name, _, _iter, measures, _)| -> Result<(BenchmarkName, Vec<GoMeasure>), NomError> {
+            // Every Go benchmark reports its latency in `ns/op`.
+            if !measures
+                .iter()
+                .any(|measure| matches!(measure, GoMeasure::Latency(_)))
+            {
+                return Err(nom_error(name));
+            }
             let benchmark_name = parse_benchmark_name(name)?;
-            Ok((benchmark_name, json_metric))
+            Ok((benchmark_name, measures))
         },
     )(input)
 }
 
-fn parse_go_bench(input: &str) -> IResult<&str, JsonNewMetric> {
-    map_res(
-        tuple((parse_f64, space1, parse_units, tag("/op"))),
-        |(duration, _, units, _)| -> Result<JsonNewMetric, NomError> {
-            let value = latency_as_nanos(duration, units);
-            Ok(JsonNewMetric {
+// Each `<value> <unit>` pair is its own measure.
+// Units other than the built-in ones are reported with `b.ReportMetric`.
+fn parse_go_bench(input: &str) -> IResult<&str, GoMeasure> {
+    map(
+        tuple((parse_f64, space1, take_till1(|c: char| c.is_whitespace()))),
+        |(value, _, unit)| {
+            let json_metric = |value| JsonNewMetric {
                 value,
                 lower_value: None,
                 upper_value: None,
-            })
+            };
+            match unit {
+                "B/op" => GoMeasure::BytesPerOp(json_metric(value.into())),
+                "allocs/op" => GoMeasure::AllocsPerOp(json_metric(value.into())),
+                "MB/s" => GoMeasure::MegabytesPerSecond(json_metric(value.into())),
+                _ => {
+                    if let Some(units) = unit
+                        .strip_suffix("/op")
+                        .and_then(|units| units.parse::<Units>().ok())
+                    {
+                        GoMeasure::Latency(json_metric(latency_as_nanos(value, units)))
+                    } else if let Ok(measure) = unit.parse() {
+                        GoMeasure::Custom(measure, json_metric(value.into()))
+                    } else {
+                        // Keep the value instead of dropping the entire line.
+                        GoMeasure::Unknown(unit.to_owned(), json_metric(value.into()))
+                    }
+                },
+            }
         },
     )(input)
 }
 
 #[cfg(test)]
 pub(crate) mod test_go_bench {
-    use bencher_json::{JsonNewMetric, project::report::JsonAverage};
+    use bencher_json::{
+        JsonNewMetric,
+        project::{
+            measure::{
+                DEFAULT_UNIT,
+                built_in::{
+                    BuiltInMeasure as _,
+                    default::Latency,
+                    go::{AllocsPerOp, BytesPerOp, MegabytesPerSecond},
+                },
+            },
+            report::JsonAverage,
+        },
+    };
+    use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
     use crate::{
-        AdapterResults, Settings,
+        Adaptable as _, AdapterResults, Settings,
         adapters::test_util::{convert_file_path, opt_convert_file_path, validate_latency},
+        results::{adapter_metrics::AdapterMetrics, adapter_results::GoMeasure},
     };
 
     use super::{AdapterGoBench, parse_go};
@@ -95,6 +128,7 @@ pub(crate) mod test_go_bench {
     }
 
     #[test]
+    #[expect(clippy::too_many_lines)]
     fn parse_go_line() {
         for (index, (expected, input)) in [
             (
@@ -102,11 +136,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib10-8".parse().unwrap(),
-                        JsonNewMetric {
+                        vec![GoMeasure::Latency(JsonNewMetric {
                             value: 325.0.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib10-8   		 					5000000		325 ns/op",
@@ -116,11 +150,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20".parse().unwrap(),
-                        JsonNewMetric {
+                        vec![GoMeasure::Latency(JsonNewMetric {
                             value: 40_537.123.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib20  	 	   					30000		40537.123 ns/op",
@@ -130,11 +164,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_10-8".parse().unwrap(),
-                        JsonNewMetric {
+                        vec![GoMeasure::Latency(JsonNewMetric {
                             value: 325.0.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_10-8    	5000000		325 ns/op",
@@ -144,11 +178,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my_tabled_benchmark_-_20".parse().unwrap(),
-                        JsonNewMetric {
+                        vec![GoMeasure::Latency(JsonNewMetric {
                             value: 40_537.123.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my_tabled_benchmark_-_20		30000		40537.123 ns/op",
@@ -158,11 +192,11 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib/my/tabled/benchmark_-_20".parse().unwrap(),
-                        JsonNewMetric {
+                        vec![GoMeasure::Latency(JsonNewMetric {
                             value: 40_537.456.into(),
                             lower_value: None,
                             upper_value: None,
-                        },
+                        })],
                     ),
                 )),
                 "BenchmarkFib/my/tabled/benchmark_-_20		30001		40537.456 ns/op",
@@ -172,11 +206,21 @@ pub(crate) mod test_go_bench {
                     "",
                     (
                         "BenchmarkFib20WithAuxMetric-8".parse().unwrap(),
-                        JsonNewMetric {
-                            value: 25_829.0.into(),
-                            lower_value: None,
-                            upper_value: None,
-                        },
+                        vec![
+                            GoMeasure::Latency(JsonNewMetric {
+                                value: 25_829.0.into(),
+                                lower_value: None,
+                                upper_value: None,
+                            }),
+                            GoMeasure::Custom(
+                                "auxMetricUnits".parse().unwrap(),
+                                JsonNewMetric {
+                                    value: 4.0.into(),
+                                    lower_value: None,
+                                    upper_value: None,
+                                },
+                            ),
+                        ],
                     ),
                 )),
                 "BenchmarkFib20WithAuxMetric-8              46714             25829 ns/op                 4.000 auxMetricUnits",
@@ -255,6 +299,77 @@ pub(crate) mod test_go_bench {
         validate_latency(metrics, 26264.0, None, None);
 
         let metrics = results.get("BenchmarkFib20WithAuxMetric-8").unwrap();
-        validate_latency(metrics, 25829.0, None, None);
+        validate_go_metrics(
+            metrics,
+            [(Latency::SLUG_STR, 25829.0), ("auxMetricUnits", 4.0)],
+        );
+    }
+
+    #[test]
+    fn adapter_go_bench_metrics() {
+        let results = convert_go_bench("metrics");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("BenchmarkEncode-16").unwrap();
+        validate_go_metrics(
+            metrics,
+            [
+                (Latency::SLUG_STR, 2345.0),
+                (MegabytesPerSecond::SLUG_STR, 436.72),
+                (BytesPerOp::SLUG_STR, 1024.0),
+                (AllocsPerOp::SLUG_STR, 12.0),
+            ],
+        );
+
+        let metrics = results.get("BenchmarkServe-16").unwrap();
+        validate_go_metrics(
+            metrics,
+            [
+                (Latency::SLUG_STR, 112_345.0),
+                ("req/s", 8901.0),
+                ("p99-ns", 250_000.0),
+                (BytesPerOp::SLUG_STR, 2048.0),
+                (AllocsPerOp::SLUG_STR, 24.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn adapter_go_bench_unknown_unit() {
+        let unit = "x".repeat(65);
+        let results = AdapterGoBench::parse(
+            &format!("BenchmarkFib10-8    5000000    325 ns/op    42 {unit}"),
+            Settings::default(),
+        )
+        .unwrap();
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("BenchmarkFib10-8").unwrap();
+        validate_go_metrics(
+            metrics,
+            [(Latency::SLUG_STR, 325.0), (DEFAULT_UNIT.as_ref(), 42.0)],
+        );
+        assert_eq!(results.warnings.len(), 1);
+        assert!(
+            results
+                .warnings
+                .first()
+                .is_some_and(|warning| warning.contains(&unit))
+        );
+    }
+
+    #[test]
+    fn parse_go_line_no_latency() {
+        assert!(parse_go("BenchmarkFib10-8    5000000    1024 B/op").is_err());
+    }
+
+    fn validate_go_metrics<const N: usize>(metrics: &AdapterMetrics, expected: [(&str, f64); N]) {
+        assert_eq!(metrics.inner.len(), N);
+        for (key, value) in expected {
+            let metric = metrics.get(key).unwrap();
+            assert_eq!(metric.value, OrderedFloat::from(value), "{key}");
+            assert_eq!(metric.lower_value, None, "{key}");
+            assert_eq!(metric.upper_value, None, "{key}");
+        }
     }
 }
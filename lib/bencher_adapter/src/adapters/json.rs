@@ -43,6 +43,26 @@ pub(crate) mod test_json {
         validate_latency(metrics, 3361.0, Some(1093.0), Some(1093.0));
     }
 
+    #[test]
+    fn adapter_json_tags() {
+        let results = convert_json("tags");
+        assert_eq!(results.inner.len(), 2);
+
+        let metrics = results.get("tests::benchmark_a").unwrap();
+        validate_latency(metrics, 3247.0, Some(1044.0), Some(1044.0));
+        let tags = metrics.tags.as_ref().unwrap();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(
+            tags.get(&"suite".parse().unwrap()).unwrap().as_ref(),
+            "parser"
+        );
+        assert_eq!(tags.get(&"os".parse().unwrap()).unwrap().as_ref(), "linux");
+
+        let metrics = results.get("tests::benchmark_b").unwrap();
+        validate_latency(metrics, 3443.0, Some(2275.0), Some(2275.0));
+        assert_eq!(metrics.tags, None);
+    }
+
     #[test]
     fn adapter_json_dhat() {
         let results = convert_json("dhat");
@@ -24,21 +24,24 @@ impl Adaptable for AdapterMagic {
 #[cfg(test)]
 mod test_magic {
     use super::AdapterMagic;
-    use crate::adapters::{
-        c_sharp::{AdapterCSharp, dot_net::test_c_sharp_dot_net},
-        cpp::{catch2::test_cpp_catch2, google::test_cpp_google},
-        go::bench::test_go_bench,
-        java::jmh::test_java_jmh,
-        js::{benchmark::test_js_benchmark, time::test_js_time},
-        json::test_json,
-        python::{asv::test_python_asv, pytest::test_python_pytest},
-        ruby::benchmark::test_ruby_benchmark,
-        rust::{
-            bench::test_rust_bench, criterion::test_rust_criterion, gungraun::test_rust_gungraun,
-            iai::test_rust_iai,
+    use crate::{
+        Settings,
+        adapters::{
+            c_sharp::{AdapterCSharp, dot_net::test_c_sharp_dot_net},
+            cpp::{catch2::test_cpp_catch2, google::test_cpp_google},
+            go::bench::test_go_bench,
+            java::jmh::test_java_jmh,
+            js::{benchmark::test_js_benchmark, time::test_js_time},
+            json::test_json,
+            python::{asv::test_python_asv, pytest::test_python_pytest},
+            ruby::benchmark::test_ruby_benchmark,
+            rust::{
+                bench::test_rust_bench, criterion::test_rust_criterion,
+                gungraun::test_rust_gungraun, iai::test_rust_iai,
+            },
+            shell::{hyperfine::test_shell_hyperfine, perf_stat::test_shell_perf_stat},
+            test_util::{convert_file_path, opt_convert_file_path},
         },
-        shell::hyperfine::test_shell_hyperfine,
-        test_util::convert_file_path,
     };
 
     #[test]
@@ -148,4 +151,19 @@ mod test_magic {
         let results = convert_file_path::<AdapterMagic>("./tool_output/shell/hyperfine/two.json");
         test_shell_hyperfine::validate_adapter_shell_hyperfine(&results);
     }
+
+    #[test]
+    fn adapter_magic_shell_perf_stat() {
+        let results = convert_file_path::<AdapterMagic>("./tool_output/shell/perf_stat/human.txt");
+        test_shell_perf_stat::validate_adapter_shell_perf_stat(&results);
+    }
+
+    #[test]
+    fn adapter_magic_shell_perf_stat_csv() {
+        let results = opt_convert_file_path::<AdapterMagic>(
+            "./tool_output/shell/perf_stat/csv.txt",
+            Settings::default(),
+        );
+        assert!(results.is_none());
+    }
 }
@@ -1,24 +1,48 @@
 pub mod hyperfine;
+pub mod perf_stat;
 
 use crate::{Adaptable, AdapterResults, Settings};
 use hyperfine::AdapterShellHyperfine;
+use perf_stat::AdapterShellPerfStat;
 
 pub struct AdapterShell;
 
 impl Adaptable for AdapterShell {
     fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
         AdapterShellHyperfine::parse(input, settings)
+            .or_else(|| AdapterShellPerfStat::parse_with_header(input, settings))
     }
 }
 
 #[cfg(test)]
 mod test_shell {
     use super::AdapterShell;
-    use crate::adapters::{shell::hyperfine::test_shell_hyperfine, test_util::convert_file_path};
+    use crate::{
+        Settings,
+        adapters::{
+            shell::{hyperfine::test_shell_hyperfine, perf_stat::test_shell_perf_stat},
+            test_util::{convert_file_path, opt_convert_file_path},
+        },
+    };
 
     #[test]
     fn adapter_shell_hyperfine() {
         let results = convert_file_path::<AdapterShell>("./tool_output/shell/hyperfine/two.json");
         test_shell_hyperfine::validate_adapter_shell_hyperfine(&results);
     }
+
+    #[test]
+    fn adapter_shell_perf_stat() {
+        let results = convert_file_path::<AdapterShell>("./tool_output/shell/perf_stat/human.txt");
+        test_shell_perf_stat::validate_adapter_shell_perf_stat(&results);
+    }
+
+    #[test]
+    fn adapter_shell_perf_stat_csv() {
+        let results = opt_convert_file_path::<AdapterShell>(
+            "./tool_output/shell/perf_stat/csv.txt",
+            Settings::default(),
+        );
+        assert!(results.is_none());
+    }
 }
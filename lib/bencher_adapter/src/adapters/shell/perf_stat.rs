@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+
+use bencher_json::{
+    BenchmarkName, JsonNewMetric, MeasureNameId,
+    project::{
+        measure::built_in::{BuiltInMeasure as _, default::Latency},
+        report::JsonAverage,
+    },
+};
+use ordered_float::OrderedFloat;
+
+use crate::{
+    Adaptable, Settings,
+    adapters::util::{Units, latency_as_nanos},
+    results::{adapter_metrics::AdapterMetrics, adapter_results::AdapterResults},
+};
+
+pub struct AdapterShellPerfStat;
+
+const HEADER_PREFIX: &str = "Performance counter stats for";
+const TIME_ELAPSED: &str = "seconds time elapsed";
+const SPREAD_PREFIX: &str = "+-";
+const CSV_SEPARATOR: char = ',';
+// The CSV output does not include the command that was run.
+const CSV_BENCHMARK_NAME: &str = "perf stat";
+
+impl Adaptable for AdapterShellPerfStat {
+    fn parse(input: &str, settings: Settings) -> Option<AdapterResults> {
+        parse_perf_stat(input, settings, true)
+    }
+}
+
+impl AdapterShellPerfStat {
+    // The CSV output does not have a header, so it is too generic to be detected.
+    // It is only parsed when the `shell_perf_stat` adapter is explicitly selected.
+    pub fn parse_with_header(input: &str, settings: Settings) -> Option<AdapterResults> {
+        parse_perf_stat(input, settings, false)
+    }
+}
+
+fn parse_perf_stat(input: &str, settings: Settings, csv: bool) -> Option<AdapterResults> {
+    // When run with `--repeat`, perf stat reports the mean and its relative standard deviation.
+    match settings.average {
+        None | Some(JsonAverage::Mean) => {},
+        Some(JsonAverage::Median) => return None,
+    }
+
+    let mut benchmark_name = None;
+    let mut results = HashMap::new();
+    for line in input.lines() {
+        if let Some(name) = parse_header(line) {
+            benchmark_name = Some(name);
+            continue;
+        }
+
+        // The human readable output is only parsed after its header,
+        // as its counter lines are too generic to be matched on their own.
+        let csv_counter = if csv { parse_csv(line) } else { None };
+        let (name, (measure, metric)) = if let Some(counter) = csv_counter {
+            let name = benchmark_name
+                .clone()
+                .or_else(|| CSV_BENCHMARK_NAME.parse().ok())?;
+            (name, counter)
+        } else if let Some(name) = &benchmark_name {
+            let Some(counter) = parse_human(line) else {
+                continue;
+            };
+            (name.clone(), counter)
+        } else {
+            continue;
+        };
+
+        // Each counter is its own measure.
+        // The counters with a matching `perf_stat` built-in measure are created automatically.
+        results
+            .entry(name)
+            .or_insert_with(AdapterMetrics::default)
+            .inner
+            .insert(measure, metric);
+    }
+
+    if results.is_empty() {
+        None
+    } else {
+        Some(results.into())
+    }
+}
+
+// ` Performance counter stats for './fibonacci 30' (10 runs):`
+fn parse_header(line: &str) -> Option<BenchmarkName> {
+    let command = line.trim().strip_prefix(HEADER_PREFIX)?;
+    let command = match (command.find('\''), command.rfind('\'')) {
+        (Some(start), Some(end)) if start < end => command.get(start + 1..end)?,
+        _ => command.trim().trim_end_matches(':'),
+    };
+    command.trim().parse().ok()
+}
+
+// `<value>,<unit>,<event>,[<variance>%,]<run time>,<percent running>,<metric value>,<metric unit>`
+fn parse_csv(line: &str) -> Option<(MeasureNameId, JsonNewMetric)> {
+    let fields = line.split(CSV_SEPARATOR).collect::<Vec<_>>();
+    let [value, _unit, event, rest @ ..] = fields.as_slice() else {
+        return None;
+    };
+    if rest.len() < 2 {
+        return None;
+    }
+    // Uncounted events have a value of `<not counted>` or `<not supported>`.
+    let value = parse_value(value)?;
+    let measure = parse_event(event)?;
+    let spread = rest
+        .first()
+        .and_then(|variance| variance.strip_suffix('%'))
+        .and_then(parse_value);
+    Some((measure, new_metric(value, spread)))
+}
+
+// `     4,923,781,556      instructions:u    #    2.98  insn per cycle    ( +-  0.01% )`
+// `          0.411000 +- 0.000822 seconds time elapsed  ( +-  0.20% )`
+fn parse_human(line: &str) -> Option<(MeasureNameId, JsonNewMetric)> {
+    let (line, spread) = split_spread(line);
+    if let Some(elapsed) = line.trim().strip_suffix(TIME_ELAPSED) {
+        let seconds = elapsed.split_whitespace().next().and_then(parse_value)?;
+        let nanos = latency_as_nanos(seconds, Units::Sec).into_inner();
+        return Some((Latency::name_id(), new_metric(nanos, spread)));
+    }
+
+    // Anything after the `#` is a derived metric, not the counter itself.
+    let counter = line.split_once('#').map_or(line, |(counter, _)| counter);
+    let tokens = counter.split_whitespace().collect::<Vec<_>>();
+    let (value, event) = match tokens.as_slice() {
+        [value, event] => (value, event),
+        // Only the clock events have units, such as `msec`.
+        // The `seconds user` and `seconds sys` summary lines are skipped.
+        [value, unit, event] if *unit != "seconds" => (value, event),
+        _ => return None,
+    };
+    let value = parse_value(&value.replace(',', ""))?;
+    let measure = parse_event(event)?;
+    Some((measure, new_metric(value, spread)))
+}
+
+// Remove any trailing parentheticals, keeping the relative standard deviation if present.
+// For example, `( +-  0.50% )` or the multiplexing percentage `(83.33%)`.
+fn split_spread(mut line: &str) -> (&str, Option<f64>) {
+    let mut spread = None;
+    while let Some((before, inner)) = line
+        .trim_end()
+        .strip_suffix(')')
+        .and_then(|line| line.rsplit_once('('))
+    {
+        if let Some(variance) = inner.trim().strip_prefix(SPREAD_PREFIX) {
+            spread = variance.trim().strip_suffix('%').and_then(parse_value);
+        }
+        line = before;
+    }
+    (line, spread)
+}
+
+fn parse_value(value: &str) -> Option<f64> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+// Event modifiers, such as `:u` for user space only, are removed.
+fn parse_event(event: &str) -> Option<MeasureNameId> {
+    if event.is_empty() || event.contains(char::is_whitespace) || parse_value(event).is_some() {
+        return None;
+    }
+    let event = event.split_once(':').map_or(event, |(event, _)| event);
+    event.parse().ok()
+}
+
+fn new_metric(value: f64, spread: Option<f64>) -> JsonNewMetric {
+    let (lower_value, upper_value) = spread.map_or((None, None), |percent| {
+        let delta = value * percent / 100.0;
+        (
+            Some(OrderedFloat::from(value - delta)),
+            Some(OrderedFloat::from(value + delta)),
+        )
+    });
+    JsonNewMetric {
+        value: value.into(),
+        lower_value,
+        upper_value,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_shell_perf_stat {
+    use bencher_json::project::{
+        measure::built_in::{
+            BuiltInMeasure as _,
+            default::Latency,
+            perf_stat::{
+                BranchMisses, Branches, ContextSwitches, CpuMigrations, Cycles, Instructions,
+                PageFaults, TaskClock,
+            },
+        },
+        report::JsonAverage,
+    };
+    use ordered_float::OrderedFloat;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        Adaptable as _, AdapterResults, Settings,
+        adapters::test_util::{convert_file_path, opt_convert_file_path},
+        results::adapter_metrics::AdapterMetrics,
+    };
+
+    use super::AdapterShellPerfStat;
+
+    fn convert_shell_perf_stat(suffix: &str) -> AdapterResults {
+        let file_path = file_path(suffix);
+        convert_file_path::<AdapterShellPerfStat>(&file_path)
+    }
+
+    fn file_path(suffix: &str) -> String {
+        format!("./tool_output/shell/perf_stat/{suffix}.txt")
+    }
+
+    fn validate_counters<const N: usize>(
+        metrics: &AdapterMetrics,
+        counters: [(&str, f64, Option<f64>); N],
+    ) {
+        assert_eq!(metrics.inner.len(), N);
+        for (key, value, spread) in counters {
+            let metric = metrics.get(key).unwrap();
+            assert_eq!(metric.value, OrderedFloat::from(value), "{key}");
+            let (lower_value, upper_value) = spread.map_or((None, None), |percent| {
+                let delta = value * percent / 100.0;
+                (Some(value - delta), Some(value + delta))
+            });
+            assert_eq!(
+                metric.lower_value,
+                lower_value.map(OrderedFloat::from),
+                "{key}"
+            );
+            assert_eq!(
+                metric.upper_value,
+                upper_value.map(OrderedFloat::from),
+                "{key}"
+            );
+        }
+    }
+
+    #[test]
+    fn adapter_shell_perf_stat_human() {
+        let results = convert_shell_perf_stat("human");
+        validate_adapter_shell_perf_stat(&results);
+    }
+
+    pub fn validate_adapter_shell_perf_stat(results: &AdapterResults) {
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("./fibonacci 30").unwrap();
+        validate_counters(
+            metrics,
+            [
+                (TaskClock::SLUG_STR, 412.35, None),
+                (ContextSwitches::SLUG_STR, 3.0, None),
+                (CpuMigrations::SLUG_STR, 0.0, None),
+                (PageFaults::SLUG_STR, 127.0, None),
+                (Cycles::SLUG_STR, 1_650_112_384.0, None),
+                (Instructions::SLUG_STR, 4_923_781_556.0, None),
+                (Branches::SLUG_STR, 985_512_447.0, None),
+                (BranchMisses::SLUG_STR, 1_204_331.0, None),
+                (Latency::SLUG_STR, 413_168_542.0, None),
+            ],
+        );
+    }
+
+    #[test]
+    fn adapter_shell_perf_stat_repeat() {
+        let results = convert_shell_perf_stat("repeat");
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("./fibonacci 30").unwrap();
+        validate_counters(
+            metrics,
+            [
+                (TaskClock::SLUG_STR, 410.12, Some(0.5)),
+                (ContextSwitches::SLUG_STR, 0.0, None),
+                (Cycles::SLUG_STR, 1_640_000_000.0, Some(0.25)),
+                (Instructions::SLUG_STR, 4_900_000_000.0, Some(0.01)),
+                (BranchMisses::SLUG_STR, 1_000_000.0, Some(2.0)),
+                ("L1-dcache-load-misses", 12_345.0, Some(1.0)),
+                (Latency::SLUG_STR, 411_000_000.0, Some(0.2)),
+            ],
+        );
+    }
+
+    #[test]
+    fn adapter_shell_perf_stat_csv() {
+        let results = convert_shell_perf_stat("csv");
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("perf stat").unwrap();
+        validate_counters(
+            metrics,
+            [
+                (TaskClock::SLUG_STR, 412.35, None),
+                (ContextSwitches::SLUG_STR, 3.0, None),
+                (CpuMigrations::SLUG_STR, 0.0, None),
+                (PageFaults::SLUG_STR, 127.0, None),
+                (Cycles::SLUG_STR, 1_650_112_384.0, None),
+                (Instructions::SLUG_STR, 4_923_781_556.0, None),
+                (Branches::SLUG_STR, 985_512_447.0, None),
+                (BranchMisses::SLUG_STR, 1_204_331.0, None),
+            ],
+        );
+    }
+
+    #[test]
+    fn adapter_shell_perf_stat_csv_repeat() {
+        let results = convert_shell_perf_stat("csv_repeat");
+        assert_eq!(results.inner.len(), 1);
+
+        let metrics = results.get("perf stat").unwrap();
+        validate_counters(
+            metrics,
+            [
+                (TaskClock::SLUG_STR, 410.12, Some(0.5)),
+                (Cycles::SLUG_STR, 1_640_000_000.0, Some(0.25)),
+                (Instructions::SLUG_STR, 4_900_000_000.0, Some(0.01)),
+            ],
+        );
+    }
+
+    #[test]
+    fn adapter_shell_perf_stat_median() {
+        let results = opt_convert_file_path::<AdapterShellPerfStat>(
+            &file_path("human"),
+            Settings {
+                average: Some(JsonAverage::Median),
+            },
+        );
+        assert!(results.is_none());
+    }
+
+    #[test]
+    fn adapter_shell_perf_stat_no_header() {
+        let results = AdapterShellPerfStat::parse(
+            "     1,650,112,384      cycles\n  127      page-faults",
+            Settings::default(),
+        );
+        assert!(results.is_none());
+    }
+}
@@ -8,4 +8,8 @@ pub enum AdapterError {
     BenchmarkUnits(String),
     #[error("Failed to convert results: {0}")]
     Convert(String),
+    #[error("Invalid custom adapter regex ({0}): {1}")]
+    Regex(String, regex::Error),
+    #[error("Custom adapter regex ({0}) is missing the `{1}` named capture group")]
+    CaptureGroup(String, &'static str),
 }
@@ -19,7 +19,7 @@ use adapters::{
         AdapterRust, bench::AdapterRustBench, criterion::AdapterRustCriterion,
         gungraun::AdapterRustGungraun, iai::AdapterRustIai,
     },
-    shell::{AdapterShell, hyperfine::AdapterShellHyperfine},
+    shell::{AdapterShell, hyperfine::AdapterShellHyperfine, perf_stat::AdapterShellPerfStat},
 };
 use bencher_json::project::report::{Adapter, JsonAverage};
 pub use bencher_json::{BenchmarkName, JsonNewMetric};
@@ -39,6 +39,8 @@ impl Adaptable for Adapter {
         match self {
             Adapter::Magic => AdapterMagic::parse(input, settings),
             Adapter::Json => AdapterJson::parse(input, settings),
+            // The custom adapter requires a spec, so it must be converted with `AdapterCustom`.
+            Adapter::Custom => None,
             Adapter::CSharp => AdapterCSharp::parse(input, settings),
             Adapter::CSharpDotNet => AdapterCSharpDotNet::parse(input, settings),
             Adapter::Cpp => AdapterCpp::parse(input, settings),
@@ -63,6 +65,7 @@ impl Adaptable for Adapter {
             Adapter::RustGungraun => AdapterRustGungraun::parse(input, settings),
             Adapter::Shell => AdapterShell::parse(input, settings),
             Adapter::ShellHyperfine => AdapterShellHyperfine::parse(input, settings),
+            Adapter::ShellPerfStat => AdapterShellPerfStat::parse(input, settings),
         }
     }
 
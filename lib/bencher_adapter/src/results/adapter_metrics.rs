@@ -1,6 +1,6 @@
 use std::{collections::HashMap, str::FromStr as _};
 
-use bencher_json::{JsonNewMetric, MeasureNameId};
+use bencher_json::{JsonBenchmarkTags, JsonNewMetric, MeasureNameId};
 use serde::{Deserialize, Serialize};
 
 use super::{CombinedKind, OrdKind};
@@ -9,13 +9,16 @@ use super::{CombinedKind, OrdKind};
 pub struct AdapterMetrics {
     #[serde(flatten)]
     pub inner: MetricsMap,
+    /// Key/value tags to add to the benchmark.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<JsonBenchmarkTags>,
 }
 
 pub type MetricsMap = HashMap<MeasureNameId, JsonNewMetric>;
 
 impl From<MetricsMap> for AdapterMetrics {
     fn from(inner: MetricsMap) -> Self {
-        Self { inner }
+        Self { inner, tags: None }
     }
 }
 
@@ -38,7 +41,10 @@ impl AdapterMetrics {
             metric_map.insert(measure, combined_metric);
         }
         metric_map.extend(other.inner);
-        metric_map.into()
+        Self {
+            inner: metric_map,
+            tags: merge_tags(self.tags, other.tags),
+        }
     }
 
     pub fn get(&self, key: &str) -> Option<&JsonNewMetric> {
@@ -54,6 +60,23 @@ impl std::ops::Div<usize> for AdapterMetrics {
         for (measure, metric) in self.inner {
             metric_map.insert(measure, metric / rhs);
         }
-        metric_map.into()
+        Self {
+            inner: metric_map,
+            tags: self.tags,
+        }
+    }
+}
+
+// If the same tag key is used more than once, the last value is used.
+pub(crate) fn merge_tags(
+    tags: Option<JsonBenchmarkTags>,
+    other: Option<JsonBenchmarkTags>,
+) -> Option<JsonBenchmarkTags> {
+    match (tags, other) {
+        (Some(mut tags), Some(other)) => {
+            tags.extend(other);
+            Some(tags)
+        },
+        (tags, other) => tags.or(other),
     }
 }
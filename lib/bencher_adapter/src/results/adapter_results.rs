@@ -1,9 +1,12 @@
 use std::{collections::HashMap, str::FromStr as _};
 
 use bencher_json::{
-    BenchmarkName, JsonNewMetric,
+    BenchmarkName, JsonNewMetric, MeasureNameId,
     project::{
-        measure::built_in::{self, BuiltInMeasure as _},
+        measure::{
+            DEFAULT_UNIT,
+            built_in::{self, BuiltInMeasure as _},
+        },
         metric::Mean,
     },
 };
@@ -16,13 +19,19 @@ use super::{CombinedKind, adapter_metrics::AdapterMetrics};
 pub struct AdapterResults {
     #[serde(flatten)]
     pub inner: ResultsMap,
+    /// Any parts of the results that could only be partially parsed.
+    #[serde(skip)]
+    pub warnings: Vec<String>,
 }
 
 pub type ResultsMap = HashMap<BenchmarkName, AdapterMetrics>;
 
 impl From<ResultsMap> for AdapterResults {
     fn from(inner: ResultsMap) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            warnings: Vec::new(),
+        }
     }
 }
 
@@ -32,6 +41,18 @@ pub enum AdapterMeasure {
     Throughput(JsonNewMetric),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GoMeasure {
+    Latency(JsonNewMetric),            // ns/op
+    BytesPerOp(JsonNewMetric),         // B/op
+    AllocsPerOp(JsonNewMetric),        // allocs/op
+    MegabytesPerSecond(JsonNewMetric), // MB/s
+    // Any other unit reported with `b.ReportMetric`
+    Custom(MeasureNameId, JsonNewMetric),
+    // A reported unit that is not a valid Measure name
+    Unknown(String, JsonNewMetric),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum IaiMeasure {
     Instructions(JsonNewMetric),
@@ -148,6 +169,7 @@ impl AdapterResults {
                         }
                     },
                 },
+                tags: None,
             };
             results_map.insert(benchmark_name, adapter_metrics);
         }
@@ -177,6 +199,50 @@ impl AdapterResults {
         )
     }
 
+    pub fn new_go(benchmark_metrics: Vec<(BenchmarkName, Vec<GoMeasure>)>) -> Option<Self> {
+        if benchmark_metrics.is_empty() {
+            return None;
+        }
+
+        let mut results_map = HashMap::new();
+        let mut warnings = Vec::new();
+        for (benchmark_name, metrics) in benchmark_metrics {
+            let metrics_value = results_map
+                .entry(benchmark_name.clone())
+                .or_insert_with(AdapterMetrics::default);
+            for metric in metrics {
+                let (resource_id, metric) = match metric {
+                    GoMeasure::Latency(json_metric) => {
+                        (built_in::default::Latency::name_id(), json_metric)
+                    },
+                    GoMeasure::BytesPerOp(json_metric) => {
+                        (built_in::go::BytesPerOp::name_id(), json_metric)
+                    },
+                    GoMeasure::AllocsPerOp(json_metric) => {
+                        (built_in::go::AllocsPerOp::name_id(), json_metric)
+                    },
+                    GoMeasure::MegabytesPerSecond(json_metric) => {
+                        (built_in::go::MegabytesPerSecond::name_id(), json_metric)
+                    },
+                    GoMeasure::Custom(measure, json_metric) => (measure, json_metric),
+                    GoMeasure::Unknown(unit, json_metric) => {
+                        warnings.push(format!(
+                            "Failed to parse the unit ({unit}) for benchmark ({benchmark_name}), using the default unit ({default_unit}) instead.",
+                            default_unit = *DEFAULT_UNIT
+                        ));
+                        (MeasureNameId::Name(DEFAULT_UNIT.clone()), json_metric)
+                    },
+                };
+                metrics_value.inner.insert(resource_id, metric);
+            }
+        }
+
+        Some(Self {
+            inner: results_map,
+            warnings,
+        })
+    }
+
     pub fn new_iai(benchmark_metrics: Vec<(BenchmarkName, Vec<IaiMeasure>)>) -> Option<Self> {
         if benchmark_metrics.is_empty() {
             return None;
@@ -436,7 +502,12 @@ impl AdapterResults {
             results_map.insert(benchmark_name, combined_metrics);
         }
         results_map.extend(other.inner);
-        results_map.into()
+        let mut warnings = self.warnings;
+        warnings.extend(other.warnings);
+        Self {
+            inner: results_map,
+            warnings,
+        }
     }
 
     pub fn get(&self, key: &str) -> Option<&AdapterMetrics> {
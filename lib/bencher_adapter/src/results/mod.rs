@@ -1,11 +1,8 @@
 use std::collections::HashMap;
 
-use bencher_json::project::{
-    metric::Mean as _,
-    report::{Adapter, JsonFold},
-};
+use bencher_json::project::{metric::Mean as _, report::JsonFold};
 
-use crate::{Adaptable as _, AdapterError, Settings};
+use crate::{Adaptable, AdapterError, Settings};
 
 pub mod adapter_metrics;
 pub mod adapter_results;
@@ -28,11 +25,14 @@ impl From<ResultsArray> for AdapterResultsArray {
 }
 
 impl AdapterResultsArray {
-    pub fn new(
+    pub fn new<A>(
         results_array: &[&str],
-        adapter: Adapter,
+        adapter: &A,
         settings: Settings,
-    ) -> Result<Self, AdapterError> {
+    ) -> Result<Self, AdapterError>
+    where
+        A: Adaptable,
+    {
         let mut parsed_results_array = Vec::new();
         for &results in results_array {
             let parsed_results = adapter
@@ -43,6 +43,12 @@ impl AdapterResultsArray {
         Ok(parsed_results_array.into())
     }
 
+    pub fn warnings(&self) -> impl Iterator<Item = &str> {
+        self.inner
+            .iter()
+            .flat_map(|results| results.warnings.iter().map(String::as_str))
+    }
+
     pub fn min(self) -> AdapterResults {
         self.ord(OrdKind::Min)
     }
@@ -1,9 +1,13 @@
 use std::collections::HashMap;
 
-use bencher_json::{BenchmarkName, JsonNewMetric, MeasureNameId, project::metric::Median as _};
+use bencher_json::{
+    BenchmarkName, JsonBenchmarkTags, JsonNewMetric, MeasureNameId, project::metric::Median as _,
+};
 
 use super::{
-    AdapterResultsArray, adapter_metrics::AdapterMetrics, adapter_results::AdapterResults,
+    AdapterResultsArray,
+    adapter_metrics::{AdapterMetrics, merge_tags},
+    adapter_results::AdapterResults,
 };
 
 #[derive(Debug, Clone, Default)]
@@ -25,6 +29,7 @@ impl ResultsReducer {
     fn reduce(&mut self, results: AdapterResults) {
         for (benchmark_name, metrics) in results.inner {
             if let Some(measures_map) = self.inner.get_mut(&benchmark_name) {
+                measures_map.tags = merge_tags(measures_map.tags.take(), metrics.tags);
                 for (measure, metric) in metrics.inner {
                     if let Some(list) = measures_map.inner.get_mut(&measure) {
                         list.push(metric);
@@ -41,6 +46,7 @@ impl ResultsReducer {
                     benchmark_name,
                     MeasuresMap {
                         inner: measures_map,
+                        tags: metrics.tags,
                     },
                 );
             }
@@ -51,6 +57,7 @@ impl ResultsReducer {
 #[derive(Debug, Clone)]
 pub struct MeasuresMap {
     pub inner: HashMap<MeasureNameId, Vec<JsonNewMetric>>,
+    pub tags: Option<JsonBenchmarkTags>,
 }
 
 impl MeasuresMap {
@@ -61,6 +68,9 @@ impl MeasuresMap {
                 metric_map.insert(measure, median);
             }
         }
-        metric_map.into()
+        AdapterMetrics {
+            inner: metric_map,
+            tags: self.tags,
+        }
     }
 }
@@ -3,8 +3,11 @@ use slog::Logger;
 
 use crate::limits::{MetricsLimits, NormalTestKind};
 use crate::ln::Ln;
-use crate::mean::Mean;
+use crate::mean::{Mean, mean};
 use crate::quartiles::Quartiles;
+use crate::rank_sum::RankSum;
+use crate::ranks::Ranks;
+use crate::welch::Welch;
 use crate::{BoundaryError, MetricsData};
 
 #[derive(Debug, Default)]
@@ -26,6 +29,7 @@ impl MetricsBoundary {
         Self::new_inner(
             log,
             datum,
+            &[datum],
             metrics_data,
             model_test,
             min_sample_size,
@@ -35,9 +39,39 @@ impl MetricsBoundary {
         .map(Option::unwrap_or_default)
     }
 
+    // Create a boundary for all of the iterations of a benchmark measure in the current report.
+    // The sample is compared as a whole, and the outlier is judged using the mean of the sample.
+    pub fn new_sample(
+        log: &Logger,
+        sample: &MetricsData,
+        metrics_data: &MetricsData,
+        model_test: ModelTest,
+        min_sample_size: Option<SampleSize>,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Self, BoundaryError> {
+        let Some(datum) = mean(&sample.data) else {
+            slog::debug!(log, "No sample for threshold model test {model_test:?}");
+            return Ok(Self::default());
+        };
+        Self::new_inner(
+            log,
+            datum,
+            &sample.data,
+            metrics_data,
+            model_test,
+            min_sample_size,
+            lower_boundary,
+            upper_boundary,
+        )
+        .map(Option::unwrap_or_default)
+    }
+
+    #[expect(clippy::too_many_arguments)]
     fn new_inner(
         log: &Logger,
         datum: f64,
+        sample: &[f64],
         metrics_data: &MetricsData,
         model_test: ModelTest,
         min_sample_size: Option<SampleSize>,
@@ -106,6 +140,12 @@ impl MetricsBoundary {
             ModelTest::DeltaIqr => {
                 Self::new_iqr(log, datum, data, true, lower_boundary, upper_boundary)
             },
+            ModelTest::MannWhitney => {
+                Self::new_mann_whitney(log, datum, data, sample, lower_boundary, upper_boundary)
+            },
+            ModelTest::WelchTTest => {
+                Self::new_welch(log, datum, data, sample, lower_boundary, upper_boundary)
+            },
         }
     }
 
@@ -253,4 +293,96 @@ impl MetricsBoundary {
 
         Ok(Some(Self { limits, outlier }))
     }
+
+    fn new_mann_whitney(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        sample: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(ranks) = Ranks::new(data) else {
+            return Ok(None);
+        };
+
+        let limits = MetricsLimits::new_mann_whitney(log, &ranks, lower_boundary, upper_boundary);
+        let outlier = if let [_, _, ..] = sample {
+            let Some(rank_sum) = RankSum::new(data, sample) else {
+                return Ok(Some(Self {
+                    limits,
+                    outlier: None,
+                }));
+            };
+            slog::debug!(
+                log,
+                "Mann-Whitney: U={u}, mean={mean}, std_dev={std_dev} for sample_len={len}",
+                u = rank_sum.u,
+                mean = rank_sum.mean,
+                std_dev = rank_sum.std_dev,
+                len = sample.len()
+            );
+            rank_sum.outlier(lower_boundary, upper_boundary)
+        } else {
+            // With a single datum, the exact distribution of its rank is uniform,
+            // so the exact test is the same as comparing against the order statistic limits.
+            limits.outlier(datum)
+        };
+
+        Ok(Some(Self { limits, outlier }))
+    }
+
+    fn new_welch(
+        log: &Logger,
+        datum: f64,
+        data: &[f64],
+        sample: &[f64],
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Result<Option<Self>, BoundaryError> {
+        let lower_boundary = lower_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+        let upper_boundary = upper_boundary
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(BoundaryError::Valid)?;
+
+        let Some(Welch {
+            mean,
+            std_err,
+            freedom,
+        }) = Welch::new(data, sample)
+        else {
+            return Ok(None);
+        };
+        slog::debug!(
+            log,
+            "Welch: sample_len={len}, sample_mean={datum}",
+            len = sample.len()
+        );
+
+        // The limits are for the mean of the sample, so the scale is the standard error of the difference in means.
+        let limits = MetricsLimits::new_normal(
+            log,
+            mean,
+            std_err,
+            NormalTestKind::T { freedom },
+            lower_boundary,
+            upper_boundary,
+        )?;
+        let outlier = limits.outlier(datum);
+
+        Ok(Some(Self { limits, outlier }))
+    }
 }
@@ -0,0 +1,276 @@
+use std::f64::consts::SQRT_2;
+
+use slog::{Logger, debug};
+
+use crate::mean::{mean, std_deviation};
+
+// The minimum number of data points on either side of a change point.
+const MIN_SEGMENT_LEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChangePoint {
+    // The index of the first data point after the change,
+    // for the series in chronological order.
+    pub index: usize,
+    // The mean of the segment before the change.
+    pub baseline: f64,
+    // The mean of the segment after the change.
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsChangePoints {
+    pub change_points: Vec<ChangePoint>,
+}
+
+impl MetricsChangePoints {
+    // Detect step changes in the mean of the series using
+    // Pruned Exact Linear Time (PELT) with a normal mean change cost.
+    // The series must be in chronological order, oldest first.
+    // https://arxiv.org/abs/1101.1438
+    pub fn new(log: &Logger, data: &[f64]) -> Self {
+        if data.len() < MIN_SEGMENT_LEN * 2 || data.iter().any(|datum| !datum.is_finite()) {
+            return Self::default();
+        }
+        let Some(penalty) = penalty(data) else {
+            return Self::default();
+        };
+        debug!(
+            log,
+            "Change point detection: len={len}, penalty={penalty}",
+            len = data.len()
+        );
+
+        let cost = SegmentCost::new(data);
+        let indices = pelt(&cost, data.len(), penalty);
+        let mut change_points = Vec::with_capacity(indices.len());
+        let mut start = 0;
+        for (position, &index) in indices.iter().enumerate() {
+            let end = indices.get(position + 1).copied().unwrap_or(data.len());
+            if let (Some(baseline), Some(value)) = (
+                data.get(start..index).and_then(mean),
+                data.get(index..end).and_then(mean),
+            ) {
+                change_points.push(ChangePoint {
+                    index,
+                    baseline,
+                    value,
+                });
+            }
+            start = index;
+        }
+
+        Self { change_points }
+    }
+
+    // Remove any change points that have already been recorded.
+    // The `recorded` indices are for change points that were recorded from an earlier window of the same series.
+    // As the window slides, the same step change may be found a few data points away from where it was first found.
+    // Two separate change points are always at least a full segment apart,
+    // so a change point within a segment of a recorded change point is the same change.
+    pub fn retain_new(&mut self, recorded: &[usize]) {
+        self.change_points.retain(|change_point| {
+            !recorded
+                .iter()
+                .any(|&index| index.abs_diff(change_point.index) < MIN_SEGMENT_LEN)
+        });
+    }
+}
+
+// A Bayesian information criterion (BIC) style penalty,
+// scaled by a robust estimate of the noise variance.
+// The noise is estimated from the median absolute difference between adjacent data points,
+// so that the step changes themselves do not inflate the estimate.
+#[expect(clippy::cast_precision_loss, clippy::integer_division)]
+fn penalty(data: &[f64]) -> Option<f64> {
+    // https://en.wikipedia.org/wiki/Median_absolute_deviation#Relation_to_standard_deviation
+    const MAD_SCALE: f64 = 1.4826;
+
+    let mut diffs = data
+        .windows(2)
+        .filter_map(|window| match window {
+            [prev, next] => Some((next - prev).abs()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    diffs.sort_unstable_by(f64::total_cmp);
+    let median_diff = diffs.get(diffs.len() / 2).copied()?;
+    // The difference of two independent samples has twice the variance.
+    let mut std_dev = MAD_SCALE * median_diff / SQRT_2;
+    if std_dev <= 0.0 {
+        // Fall back to the sample standard deviation for mostly constant data.
+        std_dev = std_deviation(mean(data)?, data)?;
+    }
+    let variance = std_dev * std_dev;
+    (variance > 0.0 && variance.is_finite()).then(|| 2.0 * variance * (data.len() as f64).ln())
+}
+
+// Prefix sums used to compute the cost of any segment in constant time.
+struct SegmentCost {
+    sum: Vec<f64>,
+    sum_sq: Vec<f64>,
+}
+
+impl SegmentCost {
+    fn new(data: &[f64]) -> Self {
+        let mut sum = Vec::with_capacity(data.len() + 1);
+        let mut sum_sq = Vec::with_capacity(data.len() + 1);
+        sum.push(0.0);
+        sum_sq.push(0.0);
+        let (mut running, mut running_sq) = (0.0, 0.0);
+        for datum in data {
+            running += datum;
+            running_sq += datum * datum;
+            sum.push(running);
+            sum_sq.push(running_sq);
+        }
+        Self { sum, sum_sq }
+    }
+
+    // The sum of squared errors from the segment mean, for the half open range `start..end`.
+    #[expect(clippy::cast_precision_loss, clippy::indexing_slicing)]
+    fn cost(&self, start: usize, end: usize) -> f64 {
+        let len = (end - start) as f64;
+        let sum = self.sum[end] - self.sum[start];
+        let sum_sq = self.sum_sq[end] - self.sum_sq[start];
+        sum_sq - sum * sum / len
+    }
+}
+
+#[expect(clippy::indexing_slicing)]
+fn pelt(cost: &SegmentCost, len: usize, penalty: f64) -> Vec<usize> {
+    // `best[t]` is the optimal cost of segmenting the first `t` data points.
+    let mut best = vec![f64::INFINITY; len + 1];
+    // `last[t]` is the last change point in the optimal segmentation of the first `t` data points.
+    let mut last = vec![0; len + 1];
+    best[0] = -penalty;
+    let mut candidates = vec![0];
+
+    for end in MIN_SEGMENT_LEN..=len {
+        // Only consider candidates that leave a full segment.
+        let admissible = end - MIN_SEGMENT_LEN;
+        if best[admissible].is_finite() && !candidates.contains(&admissible) && admissible > 0 {
+            candidates.push(admissible);
+        }
+
+        let mut min_cost = f64::INFINITY;
+        let mut min_start = 0;
+        for &start in &candidates {
+            if end - start < MIN_SEGMENT_LEN {
+                continue;
+            }
+            let total = best[start] + cost.cost(start, end) + penalty;
+            if total < min_cost {
+                min_cost = total;
+                min_start = start;
+            }
+        }
+        best[end] = min_cost;
+        last[end] = min_start;
+
+        // Prune candidates that can never be optimal.
+        candidates.retain(|&start| {
+            end - start < MIN_SEGMENT_LEN || best[start] + cost.cost(start, end) <= min_cost
+        });
+    }
+
+    let mut change_points = Vec::new();
+    let mut end = len;
+    while end > 0 {
+        let start = last[end];
+        if start > 0 {
+            change_points.push(start);
+        }
+        end = start;
+    }
+    change_points.reverse();
+    change_points
+}
+
+#[cfg(test)]
+#[expect(clippy::unreadable_literal)]
+mod tests {
+    use bencher_logger::bootstrap_logger;
+    use pretty_assertions::assert_eq;
+
+    use super::{ChangePoint, MetricsChangePoints};
+
+    const DATA_SHORT: &[f64] = &[1.0, 2.0, 3.0];
+    const DATA_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_NOISE: &[f64] = &[
+        10.1, 9.9, 10.0, 10.2, 9.8, 10.1, 9.9, 10.0, 10.2, 9.8, 10.1, 9.9,
+    ];
+    const DATA_STEP: &[f64] = &[
+        10.1, 9.9, 10.0, 10.2, 9.8, 10.1, 20.1, 19.9, 20.0, 20.2, 19.8, 20.1,
+    ];
+    const DATA_TWO_STEPS: &[f64] = &[
+        10.1, 9.9, 10.0, 10.2, 9.8, 20.1, 19.9, 20.0, 20.2, 19.8, 15.1, 14.9, 15.0, 15.2, 14.8,
+    ];
+
+    #[test]
+    fn change_points_short() {
+        let log = bootstrap_logger();
+        let change_points = MetricsChangePoints::new(&log, DATA_SHORT);
+        assert_eq!(change_points, MetricsChangePoints::default());
+    }
+
+    #[test]
+    fn change_points_const() {
+        let log = bootstrap_logger();
+        let change_points = MetricsChangePoints::new(&log, DATA_CONST);
+        assert_eq!(change_points, MetricsChangePoints::default());
+    }
+
+    #[test]
+    fn change_points_noise() {
+        let log = bootstrap_logger();
+        let change_points = MetricsChangePoints::new(&log, DATA_NOISE);
+        assert_eq!(change_points, MetricsChangePoints::default());
+    }
+
+    #[test]
+    fn change_points_step() {
+        let log = bootstrap_logger();
+        let change_points = MetricsChangePoints::new(&log, DATA_STEP);
+        assert_eq!(
+            change_points.change_points,
+            vec![ChangePoint {
+                index: 6,
+                baseline: 10.016666666666667,
+                value: 20.016666666666666,
+            }]
+        );
+    }
+
+    #[test]
+    fn change_points_two_steps() {
+        let log = bootstrap_logger();
+        let change_points = MetricsChangePoints::new(&log, DATA_TWO_STEPS);
+        let indices = change_points
+            .change_points
+            .iter()
+            .map(|change_point| change_point.index)
+            .collect::<Vec<_>>();
+        assert_eq!(indices, vec![5, 10]);
+    }
+
+    #[test]
+    fn change_points_retain_new() {
+        let log = bootstrap_logger();
+        let mut change_points = MetricsChangePoints::new(&log, DATA_TWO_STEPS);
+        change_points.retain_new(&[]);
+        assert_eq!(change_points.change_points.len(), 2);
+
+        // The first step was already recorded one data point later, in an earlier window.
+        change_points.retain_new(&[6]);
+        let indices = change_points
+            .change_points
+            .iter()
+            .map(|change_point| change_point.index)
+            .collect::<Vec<_>>();
+        assert_eq!(indices, vec![10]);
+
+        change_points.retain_new(&[12]);
+        assert_eq!(change_points, MetricsChangePoints::default());
+    }
+}
@@ -0,0 +1,173 @@
+use bencher_json::{
+    Model, ModelTest,
+    project::{boundary::BoundaryLimit, compare::CompareVerdict},
+};
+use slog::Logger;
+
+use crate::{BoundaryError, MetricsBoundary, MetricsData};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetricsComparison {
+    pub verdict: CompareVerdict,
+}
+
+impl MetricsComparison {
+    // Compare all of the candidate iterations of a benchmark measure against all of the baseline iterations.
+    // The configured boundary side is a regression.
+    // Unless both sides are configured, an outlier on the opposite side is an improvement.
+    pub fn new(
+        log: &Logger,
+        baseline: &MetricsData,
+        candidate: &MetricsData,
+        model: Model,
+    ) -> Result<Self, BoundaryError> {
+        let verdict = match (baseline.data.is_empty(), candidate.data.is_empty()) {
+            (false, false) => Self::compare(log, baseline, candidate, model)?,
+            (true, false) => CompareVerdict::Added,
+            (false, true) => CompareVerdict::Removed,
+            (true, true) => CompareVerdict::Unchanged,
+        };
+        Ok(Self { verdict })
+    }
+
+    fn compare(
+        log: &Logger,
+        baseline: &MetricsData,
+        candidate: &MetricsData,
+        model: Model,
+    ) -> Result<CompareVerdict, BoundaryError> {
+        let Model {
+            test,
+            min_sample_size,
+            lower_boundary,
+            upper_boundary,
+            ..
+        } = model;
+
+        // A static boundary is an absolute limit, so it can not be mirrored.
+        let (lower_boundary, upper_boundary) = match test {
+            ModelTest::Static => (lower_boundary, upper_boundary),
+            ModelTest::Percentage
+            | ModelTest::ZScore
+            | ModelTest::TTest
+            | ModelTest::WelchTTest
+            | ModelTest::LogNormal
+            | ModelTest::Iqr
+            | ModelTest::DeltaIqr
+            | ModelTest::MannWhitney => (
+                lower_boundary.or(upper_boundary),
+                upper_boundary.or(lower_boundary),
+            ),
+        };
+        let MetricsBoundary { outlier, .. } = MetricsBoundary::new_sample(
+            log,
+            candidate,
+            baseline,
+            test,
+            min_sample_size,
+            lower_boundary,
+            upper_boundary,
+        )?;
+
+        let verdict = match outlier {
+            None => CompareVerdict::Unchanged,
+            Some(BoundaryLimit::Lower) if model.lower_boundary.is_none() => {
+                CompareVerdict::Improved
+            },
+            Some(BoundaryLimit::Upper) if model.upper_boundary.is_none() => {
+                CompareVerdict::Improved
+            },
+            Some(BoundaryLimit::Lower | BoundaryLimit::Upper) => CompareVerdict::Regressed,
+        };
+        slog::debug!(log, "Comparison for model {model:?}: {verdict:?}");
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::{Boundary, Model, ModelTest, project::compare::CompareVerdict};
+    use bencher_logger::bootstrap_logger;
+    use pretty_assertions::assert_eq;
+
+    use super::MetricsComparison;
+    use crate::MetricsData;
+
+    const BASELINE: &[f64] = &[10.0, 10.0, 10.0];
+    const FASTER: &[f64] = &[8.0, 8.0, 8.0];
+    const SAME: &[f64] = &[10.0, 10.5, 9.5];
+    const SLOWER: &[f64] = &[12.0, 12.0, 12.0];
+
+    fn model(
+        test: ModelTest,
+        lower_boundary: Option<Boundary>,
+        upper_boundary: Option<Boundary>,
+    ) -> Model {
+        Model {
+            test,
+            min_sample_size: None,
+            max_sample_size: None,
+            window: None,
+            lower_boundary,
+            upper_boundary,
+        }
+    }
+
+    fn verdict(baseline: &[f64], candidate: &[f64], model: Model) -> CompareVerdict {
+        let log = bootstrap_logger();
+        MetricsComparison::new(
+            &log,
+            &MetricsData {
+                data: baseline.to_vec(),
+            },
+            &MetricsData {
+                data: candidate.to_vec(),
+            },
+            model,
+        )
+        .unwrap()
+        .verdict
+    }
+
+    #[test]
+    fn compare_percentage_upper() {
+        let model = model(ModelTest::Percentage, None, Some(Boundary::TEN));
+        assert_eq!(verdict(BASELINE, SLOWER, model), CompareVerdict::Regressed);
+        assert_eq!(verdict(BASELINE, SAME, model), CompareVerdict::Unchanged);
+        assert_eq!(verdict(BASELINE, FASTER, model), CompareVerdict::Improved);
+    }
+
+    #[test]
+    fn compare_percentage_lower() {
+        let model = model(ModelTest::Percentage, Some(Boundary::TEN), None);
+        assert_eq!(verdict(BASELINE, SLOWER, model), CompareVerdict::Improved);
+        assert_eq!(verdict(BASELINE, SAME, model), CompareVerdict::Unchanged);
+        assert_eq!(verdict(BASELINE, FASTER, model), CompareVerdict::Regressed);
+    }
+
+    #[test]
+    fn compare_percentage_both() {
+        let model = model(
+            ModelTest::Percentage,
+            Some(Boundary::TEN),
+            Some(Boundary::TEN),
+        );
+        assert_eq!(verdict(BASELINE, SLOWER, model), CompareVerdict::Regressed);
+        assert_eq!(verdict(BASELINE, SAME, model), CompareVerdict::Unchanged);
+        assert_eq!(verdict(BASELINE, FASTER, model), CompareVerdict::Regressed);
+    }
+
+    #[test]
+    fn compare_static() {
+        let model = model(ModelTest::Static, None, Some(11.0.try_into().unwrap()));
+        assert_eq!(verdict(BASELINE, SLOWER, model), CompareVerdict::Regressed);
+        assert_eq!(verdict(BASELINE, FASTER, model), CompareVerdict::Unchanged);
+    }
+
+    #[test]
+    fn compare_added_removed() {
+        let model = model(ModelTest::Percentage, None, Some(Boundary::TEN));
+        assert_eq!(verdict(&[], SLOWER, model), CompareVerdict::Added);
+        assert_eq!(verdict(BASELINE, &[], model), CompareVerdict::Removed);
+    }
+}
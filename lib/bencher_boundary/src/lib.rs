@@ -1,11 +1,18 @@
 pub mod boundary;
+mod change_point;
+mod compare;
 mod error;
 pub mod limits;
 mod ln;
 mod mean;
 mod quartiles;
+mod rank_sum;
+mod ranks;
+mod welch;
 
 pub use boundary::MetricsBoundary;
+pub use change_point::{ChangePoint, MetricsChangePoints};
+pub use compare::MetricsComparison;
 pub use error::BoundaryError;
 
 #[derive(Debug, Clone)]
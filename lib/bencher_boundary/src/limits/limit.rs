@@ -1,6 +1,6 @@
-use bencher_json::{Boundary, IqrBoundary, PercentageBoundary};
+use bencher_json::{Boundary, CdfBoundary, IqrBoundary, PercentageBoundary};
 
-use crate::quartiles::Quartiles;
+use crate::{quartiles::Quartiles, ranks::Ranks};
 
 #[derive(Debug, PartialEq)]
 pub struct MetricsLimit {
@@ -67,6 +67,18 @@ impl MetricsLimit {
         }
     }
 
+    pub fn rank_lower(ranks: &Ranks, boundary: CdfBoundary) -> Self {
+        Self {
+            value: ranks.lower(boundary),
+        }
+    }
+
+    pub fn rank_upper(ranks: &Ranks, boundary: CdfBoundary) -> Self {
+        Self {
+            value: ranks.upper(boundary),
+        }
+    }
+
     fn delta(median: f64, delta_quartiles: Quartiles, boundary: IqrBoundary) -> f64 {
         median * delta_quartiles.iqr(boundary)
     }
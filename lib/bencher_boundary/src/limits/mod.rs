@@ -4,7 +4,7 @@ use bencher_json::{
 use slog::{Logger, debug};
 use statrs::distribution::{ContinuousCDF as _, LogNormal, Normal, StudentsT};
 
-use crate::{BoundaryError, ln::Ln, quartiles::Quartiles};
+use crate::{BoundaryError, ln::Ln, quartiles::Quartiles, ranks::Ranks};
 
 mod limit;
 
@@ -197,6 +197,28 @@ impl MetricsLimits {
         }
     }
 
+    pub fn new_mann_whitney(
+        log: &Logger,
+        ranks: &Ranks,
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Self {
+        if lower_boundary.is_none() && upper_boundary.is_none() {
+            return Self::default();
+        }
+
+        let median = ranks.median();
+        debug!(log, "Mann-Whitney: median={median}");
+        let lower = lower_boundary.map(|boundary| MetricsLimit::rank_lower(ranks, boundary));
+        let upper = upper_boundary.map(|boundary| MetricsLimit::rank_upper(ranks, boundary));
+
+        Self {
+            baseline: Some(median),
+            lower,
+            upper,
+        }
+    }
+
     // An outlier occurs when the  datum exceeds a boundary limit.
     pub fn outlier(&self, datum: f64) -> Option<BoundaryLimit> {
         match (self.lower.as_ref(), self.upper.as_ref()) {
@@ -228,7 +250,7 @@ mod tests {
     use ordered_float::OrderedFloat;
     use pretty_assertions::assert_eq;
 
-    use crate::{ln::Ln, quartiles::Quartiles};
+    use crate::{ln::Ln, quartiles::Quartiles, ranks::Ranks};
 
     use super::{MetricsLimit, MetricsLimits, NormalTestKind};
 
@@ -299,6 +321,12 @@ mod tests {
     const LOG_NORMAL_POSITIVE_OUTLIER: f64 = 3.0;
     const IQR_POSITIVE_OUTLIER: f64 = 6.0;
 
+    const RANK_MEDIAN: f64 = 3.0;
+    const RANK_LOWER: f64 = 1.0;
+    const RANK_UPPER: f64 = 5.0;
+    const RANK_NEGATIVE_OUTLIER: f64 = 0.5;
+    const RANK_POSITIVE_OUTLIER: f64 = 5.5;
+
     #[test]
     fn limits_static_none() {
         let limits = MetricsLimits::new_static(None, None);
@@ -1354,4 +1382,90 @@ mod tests {
         let side = limits.outlier(150.0);
         assert_eq!(side, Some(BoundaryLimit::Upper));
     }
+
+    #[test]
+    fn limits_mann_whitney_none() {
+        let log = bootstrap_logger();
+        let ranks = Ranks::new(LOG_DATA).unwrap();
+        let limits = MetricsLimits::new_mann_whitney(&log, &ranks, None, None);
+        assert_eq!(limits.baseline, None);
+        assert_eq!(limits.lower, None);
+        assert_eq!(limits.upper, None);
+
+        let side = limits.outlier(RANK_NEGATIVE_OUTLIER);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(RANK_MEDIAN);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(RANK_POSITIVE_OUTLIER);
+        assert_eq!(side, None);
+    }
+
+    #[test]
+    fn limits_mann_whitney_lower() {
+        let log = bootstrap_logger();
+        let ranks = Ranks::new(LOG_DATA).unwrap();
+        let limits = MetricsLimits::new_mann_whitney(&log, &ranks, Some(*PERCENTILE), None);
+        assert_eq!(
+            OrderedFloat::from(limits.baseline.unwrap()),
+            OrderedFloat::from(RANK_MEDIAN)
+        );
+        assert_eq!(limits.lower, Some(MetricsLimit { value: RANK_LOWER }));
+        assert_eq!(limits.upper, None);
+
+        let side = limits.outlier(RANK_NEGATIVE_OUTLIER);
+        assert_eq!(side, Some(BoundaryLimit::Lower));
+
+        let side = limits.outlier(RANK_MEDIAN);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(RANK_POSITIVE_OUTLIER);
+        assert_eq!(side, None);
+    }
+
+    #[test]
+    fn limits_mann_whitney_upper() {
+        let log = bootstrap_logger();
+        let ranks = Ranks::new(LOG_DATA).unwrap();
+        let limits = MetricsLimits::new_mann_whitney(&log, &ranks, None, Some(*PERCENTILE));
+        assert_eq!(
+            OrderedFloat::from(limits.baseline.unwrap()),
+            OrderedFloat::from(RANK_MEDIAN)
+        );
+        assert_eq!(limits.lower, None);
+        assert_eq!(limits.upper, Some(MetricsLimit { value: RANK_UPPER }));
+
+        let side = limits.outlier(RANK_NEGATIVE_OUTLIER);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(RANK_MEDIAN);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(RANK_POSITIVE_OUTLIER);
+        assert_eq!(side, Some(BoundaryLimit::Upper));
+    }
+
+    #[test]
+    fn limits_mann_whitney_both() {
+        let log = bootstrap_logger();
+        let ranks = Ranks::new(LOG_DATA).unwrap();
+        let limits =
+            MetricsLimits::new_mann_whitney(&log, &ranks, Some(*PERCENTILE), Some(*PERCENTILE));
+        assert_eq!(
+            OrderedFloat::from(limits.baseline.unwrap()),
+            OrderedFloat::from(RANK_MEDIAN)
+        );
+        assert_eq!(limits.lower, Some(MetricsLimit { value: RANK_LOWER }));
+        assert_eq!(limits.upper, Some(MetricsLimit { value: RANK_UPPER }));
+
+        let side = limits.outlier(RANK_NEGATIVE_OUTLIER);
+        assert_eq!(side, Some(BoundaryLimit::Lower));
+
+        let side = limits.outlier(RANK_MEDIAN);
+        assert_eq!(side, None);
+
+        let side = limits.outlier(RANK_POSITIVE_OUTLIER);
+        assert_eq!(side, Some(BoundaryLimit::Upper));
+    }
 }
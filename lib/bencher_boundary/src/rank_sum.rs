@@ -0,0 +1,207 @@
+use std::cmp;
+
+use bencher_json::{CdfBoundary, project::boundary::BoundaryLimit};
+use statrs::distribution::{ContinuousCDF as _, Normal};
+
+// The Mann-Whitney U test (Wilcoxon rank-sum test) for a sample against the historical data.
+// The U statistic is for the sample, so a large U means the sample tends to be greater than the historical data.
+// For larger samples, U is approximately normal with a variance that is corrected for ties.
+// https://en.wikipedia.org/wiki/Mann%E2%80%93Whitney_U_test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RankSum {
+    pub u: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+impl RankSum {
+    #[expect(clippy::cast_precision_loss)]
+    pub fn new(data: &[f64], sample: &[f64]) -> Option<Self> {
+        if data.is_empty()
+            || sample.is_empty()
+            || data.iter().chain(sample).any(|datum| !datum.is_finite())
+        {
+            return None;
+        }
+
+        // Pool both samples, keeping track of which values are from the sample.
+        let mut pooled = data
+            .iter()
+            .map(|&datum| (datum, false))
+            .chain(sample.iter().map(|&datum| (datum, true)))
+            .collect::<Vec<_>>();
+        pooled.sort_unstable_by(|(x, _), (y, _)| x.partial_cmp(y).unwrap_or(cmp::Ordering::Equal));
+
+        // Tied values are all given the average of their ranks.
+        let mut rank_sum = 0.0;
+        let mut tie_correction = 0.0;
+        let mut position = 0;
+        for ties in pooled.chunk_by(|(x, _), (y, _)| x.partial_cmp(y) == Some(cmp::Ordering::Equal))
+        {
+            let ties_len = ties.len() as f64;
+            let mid_rank = position as f64 + f64::midpoint(ties_len, 1.0);
+            let sample_ties = ties.iter().filter(|(_, is_sample)| *is_sample).count() as f64;
+            rank_sum += mid_rank * sample_ties;
+            tie_correction += ties_len.powi(3) - ties_len;
+            position += ties.len();
+        }
+
+        let data_len = data.len() as f64;
+        let sample_len = sample.len() as f64;
+        let total_len = data_len + sample_len;
+        let u = rank_sum - sample_len * (sample_len + 1.0) / 2.0;
+        let mean = sample_len * data_len / 2.0;
+        let variance = sample_len * data_len / 12.0
+            * ((total_len + 1.0) - tie_correction / (total_len * (total_len - 1.0)));
+
+        Some(Self {
+            u,
+            mean,
+            std_dev: variance.sqrt(),
+        })
+    }
+
+    // The z-score of the U statistic, with a continuity correction.
+    // If every value is tied, then there is no variance and no z-score.
+    pub fn z_score(&self) -> Option<f64> {
+        if !(self.std_dev > 0.0 && self.std_dev.is_finite()) {
+            return None;
+        }
+        let diff = self.u - self.mean;
+        let corrected = diff.signum() * (diff.abs() - 0.5).max(0.0);
+        Some(corrected / self.std_dev)
+    }
+
+    // An outlier occurs when the probability of a U statistic at least as extreme,
+    // in the direction of the boundary, is less than `1 - boundary`.
+    pub fn outlier(
+        &self,
+        lower_boundary: Option<CdfBoundary>,
+        upper_boundary: Option<CdfBoundary>,
+    ) -> Option<BoundaryLimit> {
+        let z_score = self.z_score()?;
+        let normal = Normal::standard();
+        if lower_boundary.is_some_and(|boundary| normal.cdf(z_score) < 1.0 - f64::from(boundary)) {
+            Some(BoundaryLimit::Lower)
+        } else if upper_boundary
+            .is_some_and(|boundary| normal.sf(z_score) < 1.0 - f64::from(boundary))
+        {
+            Some(BoundaryLimit::Upper)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::float_cmp)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use bencher_json::{CdfBoundary, project::boundary::BoundaryLimit};
+    use pretty_assertions::assert_eq;
+
+    use super::RankSum;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_FIVE: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    const DATA_FIVE_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const DATA_TWENTY: &[f64] = &[
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+        17.0, 18.0, 19.0, 20.0,
+    ];
+    const SAMPLE_ONE: &[f64] = &[3.0];
+    const SAMPLE_NAN: &[f64] = &[f64::NAN];
+    const SAMPLE_TIES: &[f64] = &[3.0, 3.0];
+    const SAMPLE_MIDDLE: &[f64] = &[8.5, 10.5, 12.5];
+    const SAMPLE_HIGH: &[f64] = &[18.5, 19.5, 20.5, 21.5, 22.5];
+    const SAMPLE_LOW: &[f64] = &[-1.5, -0.5, 0.5, 1.5, 2.5];
+    // A single large value is not enough to shift the whole sample.
+    const SAMPLE_ONE_HIGH: &[f64] = &[9.5, 10.5, 11.5, 100.0];
+
+    static NINETY_FIVE: LazyLock<CdfBoundary> = LazyLock::new(|| 0.95.try_into().unwrap());
+
+    #[test]
+    fn rank_sum_empty() {
+        assert_eq!(RankSum::new(DATA_ZERO, SAMPLE_ONE), None);
+        assert_eq!(RankSum::new(DATA_FIVE, &[]), None);
+        assert_eq!(RankSum::new(DATA_FIVE, SAMPLE_NAN), None);
+    }
+
+    #[test]
+    fn rank_sum_const() {
+        let rank_sum = RankSum::new(DATA_FIVE_CONST, &[1.0]).unwrap();
+        assert_eq!(rank_sum.u, 2.5);
+        assert_eq!(rank_sum.std_dev, 0.0);
+        assert_eq!(rank_sum.z_score(), None);
+        assert_eq!(
+            rank_sum.outlier(Some(*NINETY_FIVE), Some(*NINETY_FIVE)),
+            None
+        );
+    }
+
+    #[test]
+    fn rank_sum_one() {
+        let rank_sum = RankSum::new(DATA_FIVE, SAMPLE_ONE).unwrap();
+        // Two values are less than the sample and one is tied.
+        assert_eq!(rank_sum.u, 2.5);
+        assert_eq!(rank_sum.mean, 2.5);
+        assert_eq!(rank_sum.z_score(), Some(0.0));
+    }
+
+    #[test]
+    fn rank_sum_ties() {
+        let rank_sum = RankSum::new(DATA_FIVE, SAMPLE_TIES).unwrap();
+        // Pooled ranks for the three tied values are all 4, so R = 8 and U = 8 - 3.
+        assert_eq!(rank_sum.u, 5.0);
+        assert_eq!(rank_sum.mean, 5.0);
+        // 2 * 5 / 12 * (8 - (27 - 3) / (7 * 6))
+        let std_dev = (10.0f64 / 12.0 * (8.0 - 24.0 / 42.0)).sqrt();
+        assert!((rank_sum.std_dev - std_dev).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn rank_sum_middle() {
+        let rank_sum = RankSum::new(DATA_TWENTY, SAMPLE_MIDDLE).unwrap();
+        assert_eq!(rank_sum.u, 30.0);
+        assert_eq!(rank_sum.mean, 30.0);
+        assert_eq!(
+            rank_sum.outlier(Some(*NINETY_FIVE), Some(*NINETY_FIVE)),
+            None
+        );
+    }
+
+    #[test]
+    fn rank_sum_high() {
+        let rank_sum = RankSum::new(DATA_TWENTY, SAMPLE_HIGH).unwrap();
+        assert_eq!(rank_sum.u, 97.0);
+        assert!(rank_sum.z_score().unwrap() > 0.0);
+        assert_eq!(
+            rank_sum.outlier(Some(*NINETY_FIVE), Some(*NINETY_FIVE)),
+            Some(BoundaryLimit::Upper)
+        );
+        assert_eq!(rank_sum.outlier(Some(*NINETY_FIVE), None), None);
+    }
+
+    #[test]
+    fn rank_sum_low() {
+        let rank_sum = RankSum::new(DATA_TWENTY, SAMPLE_LOW).unwrap();
+        assert_eq!(rank_sum.u, 3.0);
+        assert!(rank_sum.z_score().unwrap() < 0.0);
+        assert_eq!(
+            rank_sum.outlier(Some(*NINETY_FIVE), Some(*NINETY_FIVE)),
+            Some(BoundaryLimit::Lower)
+        );
+        assert_eq!(rank_sum.outlier(None, Some(*NINETY_FIVE)), None);
+    }
+
+    #[test]
+    fn rank_sum_one_high() {
+        let rank_sum = RankSum::new(DATA_TWENTY, SAMPLE_ONE_HIGH).unwrap();
+        assert_eq!(rank_sum.u, 50.0);
+        assert_eq!(
+            rank_sum.outlier(Some(*NINETY_FIVE), Some(*NINETY_FIVE)),
+            None
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use std::cmp;
+
+use bencher_json::CdfBoundary;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ranks {
+    sorted_data: Vec<f64>,
+}
+
+impl Ranks {
+    pub fn new(data: &[f64]) -> Option<Self> {
+        if data.is_empty() || data.iter().any(|datum| !datum.is_finite()) {
+            return None;
+        }
+
+        let mut sorted_data = data.to_vec();
+        sorted_data.sort_unstable_by(|x, y| x.partial_cmp(y).unwrap_or(cmp::Ordering::Equal));
+
+        Some(Self { sorted_data })
+    }
+
+    #[expect(clippy::integer_division)]
+    pub fn median(&self) -> f64 {
+        let len = self.sorted_data.len();
+        let mid = len / 2;
+        #[expect(clippy::indexing_slicing)]
+        if len.is_multiple_of(2) {
+            f64::midpoint(self.sorted_data[mid - 1], self.sorted_data[mid])
+        } else {
+            self.sorted_data[mid]
+        }
+    }
+
+    // Under the null hypothesis, the rank of the new datum among all `n + 1` values is uniform.
+    // So the probability that the datum exceeds the `k`-th smallest historical value is `(n + 1 - k) / (n + 1)`.
+    // The upper limit is the smallest order statistic where that probability is at most `1 - boundary`.
+    // If the sample is too small to ever reach that significance, the largest value is used.
+    pub fn upper(&self, boundary: CdfBoundary) -> f64 {
+        let index = self.order_index(boundary);
+        self.sorted_data
+            .get(index)
+            .or_else(|| self.sorted_data.last())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    // The lower limit mirrors the upper limit from the other end of the sorted data.
+    pub fn lower(&self, boundary: CdfBoundary) -> f64 {
+        let index = self.order_index(boundary);
+        self.sorted_data
+            .len()
+            .checked_sub(index + 1)
+            .and_then(|index| self.sorted_data.get(index))
+            .or_else(|| self.sorted_data.first())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn order_index(&self, boundary: CdfBoundary) -> usize {
+        let len = self.sorted_data.len();
+        let rank = (f64::from(boundary) * (len + 1) as f64).ceil() as usize;
+        // Convert the one-based rank into a zero-based index, clamped to the data.
+        rank.saturating_sub(1).min(len - 1)
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::float_cmp)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use bencher_json::CdfBoundary;
+    use pretty_assertions::assert_eq;
+
+    use super::Ranks;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_TWO: &[f64] = &[1.0, 2.0];
+    const DATA_FIVE_DESC: &[f64] = &[5.0, 4.0, 3.0, 2.0, 1.0];
+    const DATA_NAN: &[f64] = &[1.0, f64::NAN];
+    const DATA_TWENTY: &[f64] = &[
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+        17.0, 18.0, 19.0, 20.0,
+    ];
+    const DATA_BIMODAL: &[f64] = &[
+        1.0, 1.1, 1.2, 1.0, 1.1, 10.0, 10.2, 10.1, 10.0, 1.2, 1.1, 10.1, 1.0, 10.2, 1.1, 10.0, 1.2,
+        10.1, 1.0, 10.2,
+    ];
+
+    static NINETY: LazyLock<CdfBoundary> = LazyLock::new(|| 0.9.try_into().unwrap());
+    static NINETY_NINE: LazyLock<CdfBoundary> = LazyLock::new(|| 0.99.try_into().unwrap());
+
+    #[test]
+    fn ranks_zero() {
+        let ranks = Ranks::new(DATA_ZERO);
+        assert_eq!(ranks, None);
+    }
+
+    #[test]
+    fn ranks_nan() {
+        let ranks = Ranks::new(DATA_NAN);
+        assert_eq!(ranks, None);
+    }
+
+    #[test]
+    fn ranks_one() {
+        let ranks = Ranks::new(DATA_ONE).unwrap();
+        assert_eq!(ranks.median(), 1.0);
+        assert_eq!(ranks.lower(*NINETY), 1.0);
+        assert_eq!(ranks.upper(*NINETY), 1.0);
+    }
+
+    #[test]
+    fn ranks_two() {
+        let ranks = Ranks::new(DATA_TWO).unwrap();
+        assert_eq!(ranks.median(), 1.5);
+        assert_eq!(ranks.lower(*NINETY), 1.0);
+        assert_eq!(ranks.upper(*NINETY), 2.0);
+    }
+
+    #[test]
+    fn ranks_five_desc() {
+        let ranks = Ranks::new(DATA_FIVE_DESC).unwrap();
+        assert_eq!(ranks.median(), 3.0);
+    }
+
+    #[test]
+    fn ranks_twenty() {
+        let ranks = Ranks::new(DATA_TWENTY).unwrap();
+        assert_eq!(ranks.median(), 10.5);
+        // ceil(0.9 * 21) = 19
+        assert_eq!(ranks.lower(*NINETY), 2.0);
+        assert_eq!(ranks.upper(*NINETY), 19.0);
+        // ceil(0.99 * 21) = 21, which is clamped to the extremes
+        assert_eq!(ranks.lower(*NINETY_NINE), 1.0);
+        assert_eq!(ranks.upper(*NINETY_NINE), 20.0);
+    }
+
+    #[test]
+    fn ranks_bimodal() {
+        let ranks = Ranks::new(DATA_BIMODAL).unwrap();
+        assert_eq!(ranks.median(), 1.2);
+        assert_eq!(ranks.lower(*NINETY), 1.0);
+        assert_eq!(ranks.upper(*NINETY), 10.2);
+    }
+}
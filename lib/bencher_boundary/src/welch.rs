@@ -0,0 +1,103 @@
+use crate::mean::{mean, variance};
+
+// The parameters of the Student's t distribution for the difference between
+// the mean of a sample and the mean of the historical data.
+// https://en.wikipedia.org/wiki/Welch%27s_t-test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Welch {
+    pub mean: f64,
+    pub std_err: f64,
+    pub freedom: f64,
+}
+
+impl Welch {
+    #[expect(clippy::cast_precision_loss)]
+    pub fn new(data: &[f64], sample: &[f64]) -> Option<Self> {
+        let data_mean = mean(data)?;
+        let data_variance = variance(data_mean, data)?;
+        let data_len = data.len() as f64;
+        let sample_len = sample.len() as f64;
+        let data_term = data_variance / data_len;
+
+        let (std_err, freedom) = if sample.len() < 2 {
+            // With a single sample there is no estimate of the sample variance,
+            // so assume it is the same as the historical variance.
+            // This is the prediction interval for a single new observation.
+            let std_err = (data_term + data_variance).sqrt();
+            (std_err, data_len - 1.0)
+        } else {
+            let sample_mean = mean(sample)?;
+            let sample_variance = variance(sample_mean, sample)?;
+            let sample_term = sample_variance / sample_len;
+            let std_err = (data_term + sample_term).sqrt();
+            // Welch–Satterthwaite equation
+            let freedom = (data_term + sample_term).powi(2)
+                / (data_term.powi(2) / (data_len - 1.0) + sample_term.powi(2) / (sample_len - 1.0));
+            (std_err, freedom)
+        };
+
+        (std_err > 0.0 && std_err.is_finite() && freedom > 0.0 && freedom.is_finite()).then_some(
+            Self {
+                mean: data_mean,
+                std_err,
+                freedom,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unreadable_literal)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::Welch;
+
+    const DATA_ZERO: &[f64] = &[];
+    const DATA_ONE: &[f64] = &[1.0];
+    const DATA_FIVE: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    const DATA_FIVE_CONST: &[f64] = &[1.0, 1.0, 1.0, 1.0, 1.0];
+    const SAMPLE_ONE: &[f64] = &[3.0];
+    const SAMPLE_THREE: &[f64] = &[5.0, 6.0, 7.0];
+
+    #[test]
+    fn welch_zero() {
+        assert_eq!(Welch::new(DATA_ZERO, SAMPLE_ONE), None);
+    }
+
+    #[test]
+    fn welch_one() {
+        assert_eq!(Welch::new(DATA_ONE, SAMPLE_ONE), None);
+    }
+
+    #[test]
+    fn welch_const() {
+        assert_eq!(Welch::new(DATA_FIVE_CONST, SAMPLE_ONE), None);
+    }
+
+    #[test]
+    fn welch_single_sample() {
+        let welch = Welch::new(DATA_FIVE, SAMPLE_ONE).unwrap();
+        assert_eq!(
+            welch,
+            Welch {
+                mean: 3.0,
+                std_err: 1.5491933384829668,
+                freedom: 4.0,
+            }
+        );
+    }
+
+    #[test]
+    fn welch_sample() {
+        let welch = Welch::new(DATA_FIVE, SAMPLE_THREE).unwrap();
+        assert_eq!(
+            welch,
+            Welch {
+                mean: 3.0,
+                std_err: 0.7888106377466155,
+                freedom: 5.98473282442748,
+            }
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::env;
 
 use bencher_json::{BENCHER_API_URL, Jwt};
+use futures_util::StreamExt as _;
 use reqwest::ClientBuilder;
 use serde::{Serialize, de::DeserializeOwned};
 use tokio::time::{Duration, sleep};
@@ -236,6 +237,98 @@ impl BencherClient {
         Err(ClientError::SendTimeout(attempts))
     }
 
+    /// Send a request to the Bencher API that responds with a stream of bytes
+    ///
+    /// # Parameters
+    ///
+    /// - `sender`: A function that takes a `codegen::Client` and returns a `Future` that resolves
+    ///   to a `Result` containing a `ResponseValue` byte stream or an `Error`
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the response byte stream or an `Error`
+    pub async fn send_stream<F, R>(
+        &self,
+        sender: F,
+    ) -> Result<progenitor_client::ByteStream, ClientError>
+    where
+        F: Fn(codegen::Client) -> R,
+        R: Future<
+            Output = Result<
+                progenitor_client::ResponseValue<progenitor_client::ByteStream>,
+                codegen::Error<progenitor_client::ByteStream>,
+            >,
+        >,
+    {
+        let reqwest_client = self
+            .client_builder()?
+            .build()
+            .map_err(ClientError::BuildClient)?;
+        let client = codegen::Client::new_with_client(self.host.as_ref(), reqwest_client);
+
+        let attempts = self.attempts;
+        let max_attempts = attempts.checked_sub(1).unwrap_or_default();
+        let mut retry_after = self.retry_after;
+
+        for attempt in 0..attempts {
+            match sender(client.clone()).await {
+                Ok(response_value) => return Ok(response_value.into_inner()),
+                #[expect(clippy::print_stderr)]
+                Err(codegen::Error::CommunicationError(e)) => {
+                    if self.log {
+                        eprintln!("\nSend attempt #{}/{attempts}: {e}", attempt + 1);
+                    }
+                    if attempt != max_attempts {
+                        if self.log {
+                            eprintln!("Will retry after {retry_after} second(s).");
+                        }
+                        sleep(Duration::from_secs(retry_after)).await;
+                        retry_after *= 2;
+                    }
+                },
+                Err(codegen::Error::InvalidRequest(e)) => {
+                    return Err(ClientError::InvalidRequest(e));
+                },
+                Err(codegen::Error::ErrorResponse(e)) => {
+                    let status = e.status();
+                    let headers = e.headers().clone();
+                    // The error body is still JSON, even though the success body is not.
+                    let mut byte_stream = e.into_inner().into_inner();
+                    let mut body = Vec::new();
+                    while let Some(chunk) = byte_stream.next().await {
+                        body.extend(chunk.map_err(ClientError::ResponseBodyError)?);
+                    }
+                    let http_error: codegen::types::Error = serde_json::from_slice(&body)
+                        .map_err(ClientError::InvalidResponsePayload)?;
+                    return Err(ClientError::ErrorResponse(Box::new(ErrorResponse {
+                        status,
+                        headers,
+                        request_id: http_error.request_id,
+                        error_code: http_error.error_code,
+                        message: http_error.message,
+                    })));
+                },
+                Err(codegen::Error::InvalidUpgrade(e)) => {
+                    return Err(ClientError::InvalidUpgrade(e));
+                },
+                Err(codegen::Error::ResponseBodyError(e)) => {
+                    return Err(ClientError::ResponseBodyError(e));
+                },
+                Err(codegen::Error::InvalidResponsePayload(_bytes, e)) => {
+                    return Err(ClientError::InvalidResponsePayload(e));
+                },
+                Err(codegen::Error::UnexpectedResponse(response)) => {
+                    return Err(ClientError::UnexpectedResponseErr(Box::new(response)));
+                },
+                Err(codegen::Error::Custom(e)) => {
+                    return Err(ClientError::Custom(e));
+                },
+            }
+        }
+
+        Err(ClientError::SendTimeout(attempts))
+    }
+
     fn client_builder(&self) -> Result<ClientBuilder, ClientError> {
         let mut client_builder = ClientBuilder::new().connect_timeout(self.timeout);
 
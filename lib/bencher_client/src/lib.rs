@@ -43,7 +43,10 @@ from_client!(
     NonEmpty,
     ResourceName,
     SampleSize,
+    Secret,
     Slug,
+    TagSelectors,
+    TagValue,
     Url,
     UserName,
     Window
@@ -52,6 +55,7 @@ from_client!(
 from_client!(
     OrganizationUuid,
     ProjectUuid,
+    ReportGroupUuid,
     ReportUuid,
     PlotUuid,
     BranchUuid,
@@ -63,6 +67,8 @@ from_client!(
     ThresholdUuid,
     ModelUuid,
     AlertUuid,
+    WebhookUuid,
+    RoleUuid,
     UserUuid,
     TokenUuid
 );
@@ -112,12 +118,18 @@ try_from_client!(
     JsonOrganization,
     JsonMembers,
     JsonMember,
+    JsonRoles,
+    JsonRole,
     JsonAllowed,
     JsonProjects,
     JsonProject,
+    JsonProjectMembers,
+    JsonProjectMember,
+    JsonProjectExport,
     JsonReports,
     JsonReport,
     JsonPerf,
+    JsonCompare,
     JsonPlots,
     JsonPlot,
     JsonBranches,
@@ -134,6 +146,11 @@ try_from_client!(
     JsonModel,
     JsonAlerts,
     JsonAlert,
+    JsonRetention,
+    JsonRetentionPrune,
+    JsonWebhooks,
+    JsonWebhook,
+    JsonAuditEvents,
     JsonUsers,
     JsonUser,
     JsonPubUser,
@@ -174,6 +191,38 @@ impl From<bencher_json::DateTimeMillis> for types::DateTimeMillis {
     }
 }
 
+impl From<bencher_json::PerfBucket> for types::PerfBucket {
+    fn from(bucket: bencher_json::PerfBucket) -> Self {
+        match bucket {
+            bencher_json::PerfBucket::Hour => Self::Hour,
+            bencher_json::PerfBucket::Day => Self::Day,
+            bencher_json::PerfBucket::Week => Self::Week,
+            bencher_json::PerfBucket::Version => Self::Version,
+        }
+    }
+}
+
+impl From<bencher_json::PerfAggregate> for types::PerfAggregate {
+    fn from(aggregate: bencher_json::PerfAggregate) -> Self {
+        match aggregate {
+            bencher_json::PerfAggregate::Mean => Self::Mean,
+            bencher_json::PerfAggregate::Median => Self::Median,
+            bencher_json::PerfAggregate::Min => Self::Min,
+            bencher_json::PerfAggregate::Max => Self::Max,
+            bencher_json::PerfAggregate::P90 => Self::P90,
+        }
+    }
+}
+
+impl From<bencher_json::PerfExportFormat> for types::PerfExportFormat {
+    fn from(format: bencher_json::PerfExportFormat) -> Self {
+        match format {
+            bencher_json::PerfExportFormat::Csv => Self::Csv,
+            bencher_json::PerfExportFormat::Parquet => Self::Parquet,
+        }
+    }
+}
+
 macro_rules! into_uuids {
     ($($list:ident[$name:ident]),*) => {
         $(
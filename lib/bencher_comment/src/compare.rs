@@ -0,0 +1,395 @@
+use bencher_json::{
+    JsonCompare, Units,
+    project::compare::{CompareVerdict, JsonCompareReport, JsonCompareResult},
+};
+
+pub struct CompareComment {
+    json_compare: JsonCompare,
+}
+
+impl CompareComment {
+    pub fn new(json_compare: JsonCompare) -> Self {
+        Self { json_compare }
+    }
+
+    pub fn human(&self) -> String {
+        let mut text = String::new();
+        self.human_reports(&mut text);
+        self.human_summary(&mut text);
+        self.human_results(&mut text);
+        text
+    }
+
+    fn human_reports(&self, text: &mut String) {
+        for (side, report) in [
+            ("Baseline", &self.json_compare.baseline),
+            ("Candidate", &self.json_compare.candidate),
+        ] {
+            text.push_str(&format!(
+                "{side}: {branch} on {testbed} ({uuid})\n",
+                branch = report.branch.name,
+                testbed = report.testbed.name,
+                uuid = report.uuid,
+            ));
+        }
+    }
+
+    fn human_summary(&self, text: &mut String) {
+        let summary = &self.json_compare.summary;
+        text.push_str(&format!(
+            "\n{improved} improved, {regressed} regressed, {unchanged} unchanged, {added} added, {removed} removed\n",
+            improved = summary.improved,
+            regressed = summary.regressed,
+            unchanged = summary.unchanged,
+            added = summary.added,
+            removed = summary.removed,
+        ));
+    }
+
+    fn human_results(&self, text: &mut String) {
+        for result in &self.json_compare.results {
+            let (units, baseline, candidate, delta) = scaled_values(result);
+            text.push_str(&format!(
+                "\n{icon} {benchmark} ({measure}): {baseline} -> {candidate}{delta}",
+                icon = verdict_icon(result.verdict),
+                benchmark = result.benchmark.name,
+                measure = result.measure.name,
+                baseline = format_value(baseline, &units),
+                candidate = format_value(candidate, &units),
+                delta = delta.map_or_else(String::new, |delta| format!(
+                    " ({delta}{percent})",
+                    delta = format_delta(delta, &units),
+                    percent = format_percent(result.percent)
+                        .map(|percent| format!(", {percent}"))
+                        .unwrap_or_default(),
+                )),
+            ));
+        }
+    }
+
+    pub fn json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&self.json_compare)
+    }
+
+    pub fn html(&self) -> String {
+        let mut html = String::new();
+        let html_mut = &mut html;
+        html_mut.push_str(
+            r#"<h2><img src="https://bencher.dev/favicon.svg" width="24" height="24" alt="🐰" /> Bencher Compare</h2>"#,
+        );
+        self.html_reports(html_mut);
+        self.html_summary(html_mut);
+        self.html_results(html_mut);
+        html
+    }
+
+    fn html_reports(&self, html: &mut String) {
+        html.push_str("<table>");
+        html.push_str("<tr><th></th><th>Branch</th><th>Testbed</th><th>Report</th></tr>");
+        for (side, report) in [
+            ("Baseline", &self.json_compare.baseline),
+            ("Candidate", &self.json_compare.candidate),
+        ] {
+            html_report_row(html, side, report);
+        }
+        html.push_str("</table>");
+    }
+
+    fn html_summary(&self, html: &mut String) {
+        let summary = &self.json_compare.summary;
+        html.push_str("<table>");
+        html.push_str("<tr>");
+        for verdict in VERDICTS {
+            html.push_str(&format!(
+                "<th>{icon} {name}</th>",
+                icon = verdict_icon(verdict),
+                name = verdict_name(verdict),
+            ));
+        }
+        html.push_str("</tr>");
+        html.push_str("<tr>");
+        for count in [
+            summary.improved,
+            summary.regressed,
+            summary.unchanged,
+            summary.added,
+            summary.removed,
+        ] {
+            html.push_str(&format!("<td>{count}</td>"));
+        }
+        html.push_str("</tr>");
+        html.push_str("</table>");
+    }
+
+    fn html_results(&self, html: &mut String) {
+        if self.json_compare.results.is_empty() {
+            html.push_str("<blockquote><h3>⚠️ WARNING: No benchmarks found!</h3></blockquote>");
+            return;
+        }
+
+        html.push_str("<table>");
+        html.push_str("<tr><th>Benchmark</th><th>Measure</th><th>Baseline</th><th>Candidate</th><th>Delta</th><th>Verdict</th></tr>");
+        for result in &self.json_compare.results {
+            let (units, baseline, candidate, delta) = scaled_values(result);
+            html.push_str("<tr>");
+            html.push_str(&format!("<td>{}</td>", result.benchmark.name));
+            html.push_str(&format!("<td>{}</td>", result.measure.name));
+            html.push_str(&format!("<td>{}</td>", format_value(baseline, &units)));
+            html.push_str(&format!("<td>{}</td>", format_value(candidate, &units)));
+            html.push_str("<td>");
+            if let Some(delta) = delta {
+                html.push_str(&format_delta(delta, &units));
+                if let Some(percent) = format_percent(result.percent) {
+                    html.push_str(&format!("<br />({percent})"));
+                }
+            }
+            html.push_str("</td>");
+            let verdict = format!(
+                "{icon} {name}",
+                icon = verdict_icon(result.verdict),
+                name = verdict_name(result.verdict),
+            );
+            if matches!(result.verdict, CompareVerdict::Regressed) {
+                html.push_str(&format!("<td><b>{verdict}</b></td>"));
+            } else {
+                html.push_str(&format!("<td>{verdict}</td>"));
+            }
+            html.push_str("</tr>");
+        }
+        html.push_str("</table>");
+    }
+}
+
+const VERDICTS: [CompareVerdict; 5] = [
+    CompareVerdict::Improved,
+    CompareVerdict::Regressed,
+    CompareVerdict::Unchanged,
+    CompareVerdict::Added,
+    CompareVerdict::Removed,
+];
+
+fn verdict_icon(verdict: CompareVerdict) -> &'static str {
+    match verdict {
+        CompareVerdict::Improved => "🟢",
+        CompareVerdict::Regressed => "🔴",
+        CompareVerdict::Unchanged => "⚪",
+        CompareVerdict::Added => "🆕",
+        CompareVerdict::Removed => "➖",
+    }
+}
+
+fn verdict_name(verdict: CompareVerdict) -> &'static str {
+    match verdict {
+        CompareVerdict::Improved => "Improved",
+        CompareVerdict::Regressed => "Regressed",
+        CompareVerdict::Unchanged => "Unchanged",
+        CompareVerdict::Added => "Added",
+        CompareVerdict::Removed => "Removed",
+    }
+}
+
+fn html_report_row(html: &mut String, side: &str, report: &JsonCompareReport) {
+    html.push_str(&format!(
+        "<tr><td>{side}</td><td>{branch}</td><td>{testbed}</td><td><code>{uuid}</code></td></tr>",
+        branch = report.branch.name,
+        testbed = report.testbed.name,
+        uuid = report.uuid,
+    ));
+}
+
+// Scale the values for a result using the smallest of its values.
+fn scaled_values(result: &JsonCompareResult) -> (Units, Option<f64>, Option<f64>, Option<f64>) {
+    let min = [result.baseline, result.candidate]
+        .into_iter()
+        .flatten()
+        .map(f64::abs)
+        .reduce(f64::min)
+        .unwrap_or_default();
+    let units = Units::new(min, result.measure.units.clone());
+    let factor: f64 = units.scale_factor().into();
+    let scale = |value: Option<f64>| value.map(|value| value / factor);
+    (
+        units,
+        scale(result.baseline),
+        scale(result.candidate),
+        scale(result.delta),
+    )
+}
+
+fn format_value(value: Option<f64>, units: &Units) -> String {
+    value.map_or_else(
+        || "-".to_owned(),
+        |value| {
+            format!(
+                "{value} {symbol}",
+                value = Units::format_float(value),
+                symbol = units.scale_units_symbol()
+            )
+        },
+    )
+}
+
+fn format_delta(delta: f64, units: &Units) -> String {
+    let plus = if delta > 0.0 { "+" } else { "" };
+    format!("{plus}{}", format_value(Some(delta), units))
+}
+
+fn format_percent(percent: Option<f64>) -> Option<String> {
+    percent.map(|percent| {
+        let plus = if percent > 0.0 { "+" } else { "" };
+        format!("{plus}{}%", Units::format_float(percent))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::JsonCompare;
+    use serde_json::json;
+
+    use super::CompareComment;
+
+    const CREATED: &str = "2025-01-01T00:00:00Z";
+    const PROJECT: &str = "00000000-0000-4000-8000-000000000000";
+
+    fn report(uuid: &str, branch: &str) -> serde_json::Value {
+        json!({
+            "uuid": uuid,
+            "branch": {
+                "uuid": "00000000-0000-4000-8000-000000000001",
+                "project": PROJECT,
+                "name": branch,
+                "slug": branch,
+                "head": {
+                    "uuid": "00000000-0000-4000-8000-000000000002",
+                    "start_point": null,
+                    "version": null,
+                    "created": CREATED,
+                    "replaced": null
+                },
+                "created": CREATED,
+                "modified": CREATED,
+                "archived": null
+            },
+            "testbed": {
+                "uuid": "00000000-0000-4000-8000-000000000003",
+                "project": PROJECT,
+                "name": "localhost",
+                "slug": "localhost",
+                "created": CREATED,
+                "modified": CREATED,
+                "archived": null
+            },
+            "start_time": CREATED,
+            "end_time": CREATED
+        })
+    }
+
+    fn result(
+        benchmark: &str,
+        baseline: Option<f64>,
+        candidate: Option<f64>,
+        verdict: &str,
+    ) -> serde_json::Value {
+        let delta = baseline
+            .zip(candidate)
+            .map(|(baseline, candidate)| candidate - baseline);
+        let percent = baseline
+            .zip(delta)
+            .map(|(baseline, delta)| delta / baseline * 100.0);
+        json!({
+            "benchmark": {
+                "uuid": "00000000-0000-4000-8000-000000000004",
+                "project": PROJECT,
+                "name": benchmark,
+                "slug": benchmark.replace('_', "-"),
+                "created": CREATED,
+                "modified": CREATED,
+                "archived": null
+            },
+            "measure": {
+                "uuid": "00000000-0000-4000-8000-000000000005",
+                "project": PROJECT,
+                "name": "Latency",
+                "slug": "latency",
+                "units": "nanoseconds (ns)",
+                "created": CREATED,
+                "modified": CREATED,
+                "archived": null
+            },
+            "baseline": baseline,
+            "candidate": candidate,
+            "delta": delta,
+            "percent": percent,
+            "verdict": verdict
+        })
+    }
+
+    fn compare_comment(results: &[serde_json::Value]) -> CompareComment {
+        let json_compare: JsonCompare = serde_json::from_value(json!({
+            "baseline": report("00000000-0000-4000-8000-00000000000a", "main"),
+            "candidate": report("00000000-0000-4000-8000-00000000000b", "feature"),
+            "test": "percentage",
+            "lower_boundary": null,
+            "upper_boundary": 0.1,
+            "results": results,
+            "summary": {
+                "improved": 1,
+                "regressed": 1,
+                "unchanged": 0,
+                "added": 1,
+                "removed": 0
+            }
+        }))
+        .unwrap();
+        CompareComment::new(json_compare)
+    }
+
+    #[test]
+    fn compare_comment_human() {
+        let comment = compare_comment(&[
+            result("bench_fast", Some(2_000.0), Some(1_000.0), "improved"),
+            result("bench_slow", Some(1_000.0), Some(1_500.0), "regressed"),
+            result("bench_new", None, Some(3_000.0), "added"),
+        ]);
+        let human = comment.human();
+        assert!(
+            human.contains("Baseline: main on localhost (00000000-0000-4000-8000-00000000000a)")
+        );
+        assert!(
+            human
+                .contains("Candidate: feature on localhost (00000000-0000-4000-8000-00000000000b)")
+        );
+        assert!(human.contains("1 improved, 1 regressed, 0 unchanged, 1 added, 0 removed"));
+        assert!(human.contains("🟢 bench_fast (Latency): 2.00 µs -> 1.00 µs (-1.00 µs, -50.00%)"));
+        assert!(human.contains("🔴 bench_slow (Latency): 1.00 µs -> 1.50 µs (+0.50 µs, +50.00%)"));
+        assert!(human.contains("🆕 bench_new (Latency): - -> 3.00 µs"));
+    }
+
+    #[test]
+    fn compare_comment_html() {
+        let comment = compare_comment(&[result(
+            "bench_slow",
+            Some(1_000.0),
+            Some(1_500.0),
+            "regressed",
+        )]);
+        let html = comment.html();
+        assert!(html.contains("<th>🟢 Improved</th><th>🔴 Regressed</th>"));
+        assert!(html.contains("<td>1</td><td>1</td><td>0</td><td>1</td><td>0</td>"));
+        assert!(html.contains(
+            "<tr><td>bench_slow</td><td>Latency</td><td>1.00 µs</td><td>1.50 µs</td><td>+0.50 µs<br />(+50.00%)</td><td><b>🔴 Regressed</b></td></tr>"
+        ));
+        assert!(!html.contains("No benchmarks found"));
+
+        let empty = compare_comment(&[]).html();
+        assert!(empty.contains("⚠️ WARNING: No benchmarks found!"));
+    }
+
+    #[test]
+    fn compare_comment_json() {
+        let comment = compare_comment(&[result("bench_new", None, Some(3_000.0), "added")]);
+        let json: JsonCompare = serde_json::from_str(&comment.json().unwrap()).unwrap();
+        assert_eq!(json.results.len(), 1);
+        assert_eq!(json.summary.added, 1);
+    }
+}
@@ -20,6 +20,12 @@ use bencher_json::{
 use ordered_float::OrderedFloat;
 use url::Url;
 
+mod compare;
+mod matrix;
+
+pub use compare::CompareComment;
+pub use matrix::MatrixComment;
+
 // 30 days
 const DEFAULT_REPORT_HISTORY: Duration = Duration::from_secs(30 * 24 * 60 * 60);
 
@@ -581,12 +587,15 @@ impl ReportComment {
 
     // The Bencher tag allows us to easily check whether a comment is a Bencher report when updating
     pub fn bencher_tag(&self, id: Option<&str>) -> String {
+        self.bencher_tag_for_testbed(id, self.json_report.testbed.slug.as_ref())
+    }
+
+    fn bencher_tag_for_testbed(&self, id: Option<&str>, testbed: &str) -> String {
         let id = id.map_or_else(
             || {
                 format!(
                     "{branch}/{testbed}/{adapter}{build_time}{file_size}",
                     branch = self.json_report.branch.slug,
-                    testbed = self.json_report.testbed.slug,
                     adapter = self.json_report.adapter,
                     build_time = if self.sub_adapter.build_time {
                         "-build_time"
@@ -717,11 +726,14 @@ impl ReportComment {
             heads: vec![Some(self.json_report.branch.head.uuid)],
             testbeds: vec![self.json_report.testbed.uuid],
             benchmarks: vec![benchmark.uuid],
+            tags: None,
             measures: vec![measure.uuid],
             start_time: Some(
                 (self.json_report.start_time.into_inner() - DEFAULT_REPORT_HISTORY).into(),
             ),
             end_time: Some(self.json_report.end_time),
+            bucket: None,
+            aggregate: None,
         };
         let mut query_string = vec![("report", Some(self.json_report.uuid.to_string()))];
         if boundary_limits.is_some_and(|bl| bl.lower) {
@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+
+use bencher_json::{
+    JsonBenchmark, Units,
+    project::report::{JsonReportMeasure, JsonReportResult},
+};
+
+use crate::{EMPTY_CELL, Measure, ReportComment, Resource, value_cell};
+
+pub struct MatrixComment {
+    report_comments: Vec<ReportComment>,
+}
+
+// Benchmark results are grouped by iteration, benchmark name, and measure.
+type MatrixKey = (usize, String, Measure);
+// Each row has at most one result for each report, in report order.
+type MatrixRow<'c> = (
+    &'c JsonBenchmark,
+    Vec<Option<(&'c JsonReportResult, &'c JsonReportMeasure)>>,
+);
+
+impl MatrixComment {
+    pub fn new(report_comments: Vec<ReportComment>) -> Self {
+        Self { report_comments }
+    }
+
+    pub fn human(&self) -> String {
+        self.report_comments
+            .iter()
+            .map(|report_comment| {
+                format!(
+                    "Testbed: {testbed}\n{human}",
+                    testbed = report_comment.json_report.testbed.name,
+                    human = report_comment.human()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    pub fn json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(
+            &self
+                .report_comments
+                .iter()
+                .map(|report_comment| &report_comment.json_report)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    pub fn html(&self, require_threshold: bool, id: Option<&str>) -> String {
+        self.html_inner(require_threshold, id, true)
+    }
+
+    pub fn html_with_max_length(
+        &self,
+        require_threshold: bool,
+        id: Option<&str>,
+        max_length: usize,
+    ) -> String {
+        let html = self.html_inner(require_threshold, id, true);
+        if html.len() > max_length {
+            self.html_inner(require_threshold, id, false)
+        } else {
+            html
+        }
+    }
+
+    fn html_inner(
+        &self,
+        require_threshold: bool,
+        id: Option<&str>,
+        include_benchmarks: bool,
+    ) -> String {
+        let mut html = String::new();
+        let html_mut = &mut html;
+        Self::html_header(html_mut);
+        self.html_report_table(html_mut);
+        if include_benchmarks {
+            self.html_benchmarks(html_mut, require_threshold);
+        }
+        self.html_footer(html_mut);
+        // DO NOT MOVE: The Bencher tag must be the last thing in the HTML for updates to work
+        html_mut.push_str(&self.bencher_tag(id));
+        html
+    }
+
+    fn html_header(html: &mut String) {
+        html.push_str(
+            r#"<h2><img src="https://bencher.dev/favicon.svg" width="24" height="24" alt="🐰" /> Bencher Matrix Report</h2>"#,
+        );
+    }
+
+    fn html_report_table(&self, html: &mut String) {
+        html.push_str("<table>");
+        html.push_str("<tr><th>Branch</th><th>Testbed</th><th>Alerts</th></tr>");
+        for report_comment in &self.report_comments {
+            let json_report = &report_comment.json_report;
+            html.push_str(&format!(
+                r#"<tr><td><a href="{branch_url}">{branch}</a></td><td><a href="{testbed_url}">{testbed}</a></td><td>{alerts}</td></tr>"#,
+                branch_url =
+                    report_comment.resource_url(Resource::Branch(json_report.branch.slug.clone())),
+                branch = json_report.branch.name,
+                testbed_url = report_comment
+                    .resource_url(Resource::Testbed(json_report.testbed.slug.clone())),
+                testbed = json_report.testbed.name,
+                alerts = if json_report.alerts.is_empty() {
+                    "✅".to_owned()
+                } else {
+                    format!("🚨 {}", json_report.alerts.len())
+                },
+            ));
+        }
+        html.push_str("</table>");
+    }
+
+    fn html_benchmarks(&self, html: &mut String, require_threshold: bool) {
+        if self
+            .report_comments
+            .iter()
+            .all(|report_comment| report_comment.benchmark_count == 0)
+        {
+            html.push_str("<blockquote><h3>⚠️ WARNING: No benchmarks found!</h3></blockquote>");
+            return;
+        }
+        self.html_alerts(html);
+        self.html_matrix_table(html, require_threshold);
+    }
+
+    fn html_alerts(&self, html: &mut String) {
+        for report_comment in &self.report_comments {
+            let alerts_len = report_comment.json_report.alerts.len();
+            if alerts_len == 0 {
+                continue;
+            }
+            html.push_str(&format!(
+                "<h3>🚨 {alerts_len} {alert} on {testbed}</h3>",
+                alert = if alerts_len == 1 { "Alert" } else { "Alerts" },
+                testbed = report_comment.json_report.testbed.name,
+            ));
+            report_comment.html_alerts_table(html);
+        }
+    }
+
+    fn html_matrix_table(&self, html: &mut String, require_threshold: bool) {
+        let Some(first) = self.report_comments.first() else {
+            return;
+        };
+        let multiple_iterations = self
+            .report_comments
+            .iter()
+            .any(|report_comment| report_comment.multiple_iterations);
+
+        html.push_str("<table>");
+        html.push_str("<thead><tr><th>Benchmark</th><th>Measure</th>");
+        if multiple_iterations {
+            html.push_str("<th>Iteration</th>");
+        }
+        for report_comment in &self.report_comments {
+            let testbed = &report_comment.json_report.testbed;
+            html.push_str(&format!(
+                "<th><a href=\"{url}\">{name}</a></th>",
+                url = report_comment.resource_url(Resource::Testbed(testbed.slug.clone())),
+                name = testbed.name,
+            ));
+        }
+        html.push_str("</tr></thead>");
+
+        html.push_str("<tbody>");
+        for ((iteration, _, measure), (benchmark, cells)) in self.matrix_rows(require_threshold) {
+            // Scale all of the values in the row to the same units
+            let min = cells
+                .iter()
+                .flatten()
+                .map(|(_, report_measure)| report_measure.metric.value)
+                .min()
+                .unwrap_or_default();
+            let (factor, units_symbol) = {
+                let units = Units::new(min.into(), measure.units.clone());
+                (units.scale_factor(), units.scale_units_symbol())
+            };
+
+            html.push_str("<tr>");
+            html.push_str(&format!(
+                "<td><a href=\"{url}\">{name}</a></td>",
+                url = first.resource_url(Resource::Benchmark(benchmark.slug.clone())),
+                name = benchmark.name,
+            ));
+            html.push_str(&format!(
+                "<td><a href=\"{url}\">{name}</a></td>",
+                url = first.resource_url(Resource::Measure(measure.slug.clone())),
+                name = measure.name,
+            ));
+            if multiple_iterations {
+                html.push_str(&format!("<td>{iteration}</td>"));
+            }
+            for (report_comment, cell) in self.report_comments.iter().zip(cells) {
+                if let Some((result, report_measure)) = cell {
+                    value_cell(
+                        html,
+                        report_measure.metric.value,
+                        report_measure.boundary.and_then(|b| b.baseline),
+                        factor,
+                        &units_symbol,
+                        report_comment.find_alert(result, &measure).is_some(),
+                    );
+                } else {
+                    html.push_str(EMPTY_CELL);
+                }
+            }
+            html.push_str("</tr>");
+        }
+        html.push_str("</tbody>");
+        html.push_str("</table>");
+    }
+
+    fn matrix_rows(&self, require_threshold: bool) -> BTreeMap<MatrixKey, MatrixRow<'_>> {
+        let reports_len = self.report_comments.len();
+        let mut rows = BTreeMap::new();
+        for (index, report_comment) in self.report_comments.iter().enumerate() {
+            for (iteration, results) in report_comment.json_report.results.iter().enumerate() {
+                for result in results {
+                    for report_measure in &result.measures {
+                        if require_threshold && report_measure.threshold.is_none() {
+                            continue;
+                        }
+                        let measure = Measure::from(report_measure.measure.clone());
+                        let key = (iteration, result.benchmark.name.to_string(), measure);
+                        let (_, cells) = rows
+                            .entry(key)
+                            .or_insert_with(|| (&result.benchmark, vec![None; reports_len]));
+                        if let Some(cell) = cells.get_mut(index) {
+                            *cell = Some((result, report_measure));
+                        }
+                    }
+                }
+            }
+        }
+        rows
+    }
+
+    fn html_footer(&self, html: &mut String) {
+        for report_comment in &self.report_comments {
+            let json_report = &report_comment.json_report;
+            html.push_str(&format!(
+                r#"<a href="{url}">🐰 View full continuous benchmarking report for {testbed} in Bencher</a><br />"#,
+                url = report_comment.resource_url(Resource::Report(json_report.uuid)),
+                testbed = json_report.testbed.name,
+            ));
+        }
+    }
+
+    // The Bencher tag for a matrix includes all of its testbeds,
+    // so it does not collide with the tag for a single testbed report.
+    pub fn bencher_tag(&self, id: Option<&str>) -> String {
+        let Some(first) = self.report_comments.first() else {
+            return String::new();
+        };
+        let testbeds = self
+            .report_comments
+            .iter()
+            .map(|report_comment| report_comment.json_report.testbed.slug.to_string())
+            .collect::<Vec<_>>()
+            .join("+");
+        first.bencher_tag_for_testbed(id, &testbeds)
+    }
+
+    pub fn has_threshold(&self) -> bool {
+        self.report_comments
+            .iter()
+            .any(ReportComment::has_threshold)
+    }
+
+    pub fn has_alert(&self) -> bool {
+        self.report_comments.iter().any(ReportComment::has_alert)
+    }
+}
@@ -11,11 +11,13 @@ use bencher_json::{
     },
 };
 use bencher_rbac::init_rbac;
-use bencher_schema::context::{ApiContext, Database, DbConnection};
 #[cfg(feature = "plus")]
 use bencher_schema::{conn_lock, context::RateLimiting, model::server::QueryServer};
+use bencher_schema::{
+    context::{ApiContext, Database, DatabaseUrl, DbConnection},
+    model::project::retention::QueryRetention,
+};
 use bencher_token::TokenKey;
-use diesel::Connection as _;
 #[cfg(feature = "plus")]
 use diesel::connection::SimpleConnection as _;
 use dropshot::{
@@ -44,6 +46,9 @@ pub enum ConfigTxError {
     Migrations(#[from] bencher_schema::MigrationError),
     #[error("Failed to run database pragma: {0}")]
     Pragma(diesel::result::Error),
+    #[cfg(feature = "plus")]
+    #[error("Litestream replication is only supported for SQLite databases")]
+    LitestreamPostgres,
     #[error("Failed to parse role based access control (RBAC) rules: {0}")]
     Polar(Box<oso::OsoError>),
     #[error("Invalid endpoint URL: {0}")]
@@ -73,6 +78,34 @@ pub enum ConfigTxError {
 
 impl ConfigTx {
     pub async fn into_server<R>(self) -> Result<HttpServer<ApiContext>, ConfigTxError>
+    where
+        R: Registrar,
+    {
+        let log = into_log(self.config.0.logging.clone())?;
+        let server = Box::pin(self.into_inner::<R>(&log))
+            .await
+            .inspect_err(|e| error!(&log, "{e}"))?;
+
+        // Enforce the project data retention policies in the background.
+        QueryRetention::spawn_prune(
+            log.clone(),
+            server.app_private().database.connection.clone(),
+        );
+
+        // Bencher Cloud does not need to send stats, it uses OpenTelemetry.
+        #[cfg(feature = "plus")]
+        if !server.app_private().is_bencher_cloud {
+            spawn_stats(log.clone(), server.app_private())
+                .await
+                .inspect_err(|e| error!(&log, "{e}"))?;
+        }
+
+        Ok(server)
+    }
+
+    /// Create a server that is only meant to be used locally and in-process.
+    /// Unlike `into_server`, a local server never sends server stats.
+    pub async fn into_local_server<R>(self) -> Result<HttpServer<ApiContext>, ConfigTxError>
     where
         R: Registrar,
     {
@@ -128,9 +161,6 @@ impl ConfigTx {
         if context.is_bencher_cloud {
             // This is only needed for testing. In production, Bencher Cloud should already have a server ID.
             QueryServer::get_or_create(conn_lock!(context)).map_err(ConfigTxError::ServerId)?;
-        } else {
-            // Bencher Cloud does not need to send stats, it uses OpenTelemetry.
-            spawn_stats(log.clone(), &context).await?;
         }
 
         let mut api_description = ApiDescription::new();
@@ -168,12 +198,18 @@ async fn into_context(
 
     let rbac = init_rbac().map_err(ConfigTxError::Polar)?.into();
 
-    let database_path = json_database.file.to_string_lossy();
-    diesel_database_url(log, &database_path);
+    let database_url = if let Some(url) = json_database.url {
+        DatabaseUrl::Postgres(url)
+    } else {
+        let database_path = json_database.file.to_string_lossy();
+        diesel_database_url(log, &database_path);
+        DatabaseUrl::Sqlite(json_database.file)
+    };
 
-    info!(&log, "Connecting to database: {database_path}");
-    let mut database_connection = DbConnection::establish(&database_path)
-        .map_err(|e| ConfigTxError::DatabaseConnection(database_path.to_string(), e))?;
+    info!(&log, "Connecting to database: {database_url}");
+    let mut database_connection = database_url
+        .establish()
+        .map_err(|e| ConfigTxError::DatabaseConnection(database_url.to_string(), e))?;
 
     #[cfg(feature = "plus")]
     if let Some(litestream) = plus.as_ref().and_then(|plus| plus.litestream.as_ref()) {
@@ -191,7 +227,7 @@ async fn into_context(
     };
 
     let database = Database {
-        path: json_database.file,
+        url: database_url,
         connection: Arc::new(tokio::sync::Mutex::new(database_connection)),
         data_store,
     };
@@ -210,6 +246,7 @@ async fn into_context(
     let Plus {
         github_client,
         google_client,
+        oidc_client,
         stats,
         biller,
         licensor,
@@ -247,6 +284,8 @@ async fn into_context(
         #[cfg(feature = "plus")]
         google_client,
         #[cfg(feature = "plus")]
+        oidc_client,
+        #[cfg(feature = "plus")]
         stats,
         #[cfg(feature = "plus")]
         biller,
@@ -288,6 +327,10 @@ fn run_litestream(
     database: &mut DbConnection,
     litestream: &JsonLitestream,
 ) -> Result<(), ConfigTxError> {
+    if matches!(database, DbConnection::Postgres(_)) {
+        return Err(ConfigTxError::LitestreamPostgres);
+    }
+
     // Enable WAL mode
     // https://litestream.io/tips/#wal-journal-mode
     // https://sqlite.org/wal.html
@@ -388,7 +431,7 @@ async fn spawn_stats(log: Logger, context: &ApiContext) -> Result<(), ConfigTxEr
     query_server.spawn_stats(
         log.clone(),
         server_stats_url,
-        context.database.path.clone(),
+        context.database.url.clone(),
         context.database.connection.clone(),
         context.stats,
         context.licensor.clone(),
@@ -2,6 +2,7 @@ use std::sync::LazyLock;
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
     ops::Deref,
+    path::PathBuf,
 };
 
 use bencher_json::{
@@ -200,6 +201,18 @@ impl Config {
     pub fn into_inner(self) -> JsonConfig {
         self.0
     }
+
+    /// A config for a local API server that only listens on the loopback interface
+    /// on a random port and stores its data in the given database file.
+    pub fn local(database: PathBuf) -> Self {
+        let Self(mut json_config) = Self::default();
+        json_config.server.bind_address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0);
+        json_config.database.file = database;
+        json_config.logging.log = ServerLog::StderrTerminal {
+            level: LogLevel::Error,
+        };
+        Self(json_config)
+    }
 }
 
 impl Default for Config {
@@ -219,6 +232,7 @@ impl Default for Config {
             },
             database: JsonDatabase {
                 file: DEFAULT_DB_PATH.into(),
+                url: None,
                 data_store: None,
             },
             smtp: None,
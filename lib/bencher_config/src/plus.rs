@@ -5,9 +5,12 @@ use bencher_github_client::GitHubClient;
 use bencher_google_client::GoogleClient;
 use bencher_json::{
     is_bencher_cloud,
-    system::config::{JsonCloud, JsonGitHub, JsonGoogle, JsonPlus, JsonRecaptcha},
+    system::config::{
+        JsonCloud, JsonGitHub, JsonGoogle, JsonOidc, JsonOidcClaims, JsonPlus, JsonRecaptcha,
+    },
 };
 use bencher_license::Licensor;
+use bencher_oidc_client::{OidcClaims, OidcClient};
 use bencher_recaptcha::RecaptchaClient;
 use bencher_schema::context::{Indexer, StatsSettings};
 use tokio::runtime::Handle;
@@ -16,6 +19,7 @@ use url::Url;
 pub struct Plus {
     pub github_client: Option<GitHubClient>,
     pub google_client: Option<GoogleClient>,
+    pub oidc_client: Option<OidcClient>,
     pub indexer: Option<Indexer>,
     pub stats: StatsSettings,
     pub biller: Option<Biller>,
@@ -31,6 +35,10 @@ pub enum PlusError {
     LicenseCloud(bencher_license::LicenseError),
     #[error("Failed to create Google OAuth redirect URL: {0}")]
     RedirectUrl(url::ParseError),
+    #[error("Failed to parse OpenID Connect issuer URL: {0}")]
+    OidcIssuer(bencher_json::ValidError),
+    #[error("Failed to create OpenID Connect redirect URL: {0}")]
+    OidcRedirectUrl(url::ParseError),
     #[error("Tried to init Bencher Cloud for other Console URL: {0}")]
     BencherCloud(Url),
     #[error("Failed to setup billing: {0}")]
@@ -45,6 +53,7 @@ impl Plus {
             return Ok(Self {
                 github_client: None,
                 google_client: None,
+                oidc_client: None,
                 indexer: None,
                 stats: StatsSettings::default(),
                 biller: None,
@@ -77,6 +86,11 @@ impl Plus {
             )
             .transpose()?;
 
+        let oidc_client = plus
+            .oidc
+            .map(|oidc| oidc_client(console_url, oidc))
+            .transpose()?;
+
         let stats = plus.stats.map(Into::into).unwrap_or_default();
 
         let Some(JsonCloud {
@@ -90,6 +104,7 @@ impl Plus {
             return Ok(Self {
                 github_client,
                 google_client,
+                oidc_client,
                 indexer: None,
                 stats,
                 biller: None,
@@ -119,6 +134,7 @@ impl Plus {
         Ok(Self {
             github_client,
             google_client,
+            oidc_client,
             indexer,
             stats,
             biller,
@@ -127,3 +143,40 @@ impl Plus {
         })
     }
 }
+
+fn oidc_client(console_url: &Url, oidc: JsonOidc) -> Result<OidcClient, PlusError> {
+    let JsonOidc {
+        issuer,
+        client_id,
+        client_secret,
+        scopes,
+        claims,
+    } = oidc;
+    let issuer = issuer.try_into().map_err(PlusError::OidcIssuer)?;
+    let redirect_url = console_url
+        .join("/auth/oidc")
+        .map_err(PlusError::OidcRedirectUrl)?;
+    let claims = claims.map(oidc_claims).unwrap_or_default();
+    Ok(OidcClient::new(
+        issuer,
+        client_id,
+        client_secret,
+        redirect_url,
+        scopes.unwrap_or_default(),
+        claims,
+    ))
+}
+
+fn oidc_claims(claims: JsonOidcClaims) -> OidcClaims {
+    let JsonOidcClaims {
+        email,
+        email_verified,
+        name,
+    } = claims;
+    let default = OidcClaims::default();
+    OidcClaims {
+        email: email.map_or(default.email, Into::into),
+        email_verified: email_verified.map_or(default.email_verified, Into::into),
+        name: name.map_or(default.name, Into::into),
+    }
+}
@@ -0,0 +1,105 @@
+use gix::{ObjectId, Repository};
+
+use super::find_repo;
+
+// Prefer the remote-tracking branch, as the local branch may be out of date in CI.
+const REMOTE: &str = "origin";
+
+#[derive(Debug, Clone)]
+pub struct MergeBase {
+    /// The full `git` hash of the merge-base commit.
+    pub hash: String,
+    /// The first-parent ancestors of the merge-base commit, ordered from nearest to furthest.
+    pub ancestors: Vec<String>,
+}
+
+impl MergeBase {
+    /// Find the merge-base between `HEAD` and the start point branch in the current repository.
+    pub fn current(start_point: &str, max_ancestors: usize) -> Option<Self> {
+        Self::new(&find_repo()?, start_point, max_ancestors)
+    }
+
+    fn new(repo: &Repository, start_point: &str, max_ancestors: usize) -> Option<Self> {
+        let head_id = repo.head_id().ok()?;
+        let start_point_id = start_point_id(repo, start_point)?;
+        let merge_base = repo.merge_base(head_id, start_point_id).ok()?.detach();
+        let ancestors = repo
+            .rev_walk([merge_base])
+            .first_parent_only()
+            .all()
+            .ok()?
+            // Skip the merge-base commit itself.
+            .skip(1)
+            .take(max_ancestors)
+            .map_while(Result::ok)
+            .map(|info| info.id.to_string())
+            .collect();
+        Some(Self {
+            hash: merge_base.to_string(),
+            ancestors,
+        })
+    }
+}
+
+fn start_point_id(repo: &Repository, start_point: &str) -> Option<ObjectId> {
+    [format!("{REMOTE}/{start_point}"), start_point.to_owned()]
+        .iter()
+        .find_map(|spec| repo.rev_parse_single(spec.as_str()).ok())
+        .map(gix::Id::detach)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path, process::Command};
+
+    use super::MergeBase;
+
+    fn git(repo: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args([
+                "-c",
+                "user.name=bencher",
+                "-c",
+                "user.email=bencher@nowhere.com",
+            ])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8(output.stdout).unwrap().trim().to_owned()
+    }
+
+    fn commit(repo: &Path, message: &str) -> String {
+        git(repo, &["commit", "--allow-empty", "-m", message]);
+        git(repo, &["rev-parse", "HEAD"])
+    }
+
+    #[test]
+    fn merge_base_first_parent_ancestors() {
+        let repo = std::env::temp_dir().join(format!("bencher_merge_base_{}", std::process::id()));
+        drop(fs::remove_dir_all(&repo));
+        fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        let first = commit(&repo, "first");
+        let second = commit(&repo, "second");
+        let fork_point = commit(&repo, "fork point");
+        git(&repo, &["checkout", "-b", "feature"]);
+        commit(&repo, "feature");
+        git(&repo, &["checkout", "main"]);
+        commit(&repo, "main");
+        git(&repo, &["checkout", "feature"]);
+
+        let gix_repo = gix::open(&repo).unwrap();
+        let merge_base = MergeBase::new(&gix_repo, "main", 10).unwrap();
+        assert_eq!(merge_base.hash, fork_point);
+        assert_eq!(merge_base.ancestors, [second.clone(), first]);
+
+        let merge_base = MergeBase::new(&gix_repo, "main", 1).unwrap();
+        assert_eq!(merge_base.ancestors, [second]);
+
+        assert!(MergeBase::new(&gix_repo, "missing", 10).is_none());
+
+        fs::remove_dir_all(&repo).unwrap();
+    }
+}
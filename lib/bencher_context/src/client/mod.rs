@@ -5,9 +5,11 @@ use gix::Repository;
 use crate::{ContextPath, RunContext};
 
 mod fingerprint;
+mod merge_base;
 mod operating_system;
 
 use fingerprint::Fingerprint;
+pub use merge_base::MergeBase;
 use operating_system::OperatingSystem;
 
 const ROOT: &str = "root";
@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "client")]
 mod client;
+#[cfg(feature = "client")]
+pub use client::MergeBase;
 #[cfg(feature = "server")]
 mod server;
 
@@ -0,0 +1,329 @@
+use bencher_valid::{DateTime, DateTimeMillis};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{OrganizationUuid, ProjectUuid, TokenUuid, UserUuid};
+
+crate::typed_uuid::typed_uuid!(AuditEventUuid);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAuditEvents(pub Vec<JsonAuditEvent>);
+
+crate::from_vec!(JsonAuditEvents[JsonAuditEvent]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAuditEvent {
+    pub uuid: AuditEventUuid,
+    /// The user that made the change.
+    pub user: UserUuid,
+    /// How the user was authenticated.
+    pub credential: AuditCredential,
+    /// The API token used to make the change, if known.
+    pub token: Option<TokenUuid>,
+    pub action: AuditAction,
+    pub resource: AuditResource,
+    /// The UUID of the resource that was changed, if any.
+    pub resource_uuid: Option<uuid::Uuid>,
+    /// The organization for the resource, if any.
+    pub organization: Option<OrganizationUuid>,
+    /// The project for the resource, if any.
+    pub project: Option<ProjectUuid>,
+    /// The resource before the change, if any.
+    pub before: Option<serde_json::Value>,
+    /// The resource after the change, if any.
+    pub after: Option<serde_json::Value>,
+    pub created: DateTime,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAuditQuery {
+    /// Filter by the UUID of the user that made the change.
+    pub user: Option<UserUuid>,
+    /// Filter by action.
+    pub action: Option<AuditAction>,
+    /// Filter by resource kind.
+    pub resource: Option<AuditResource>,
+    /// Filter by the UUID of the resource that was changed.
+    pub resource_uuid: Option<uuid::Uuid>,
+    /// Search for events after the given date time in milliseconds.
+    pub start_time: Option<DateTimeMillis>,
+    /// Search for events before the given date time in milliseconds.
+    pub end_time: Option<DateTimeMillis>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEventsSort {
+    /// Sort by the date time the event was created.
+    /// By default, the newest events are first.
+    #[default]
+    Created,
+}
+
+const SESSION_INT: i32 = 0;
+const API_KEY_INT: i32 = 1;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum AuditCredential {
+    /// A console login session.
+    Session = SESSION_INT,
+    /// An API token.
+    ApiKey = API_KEY_INT,
+}
+
+const CREATE_INT: i32 = 0;
+const UPDATE_INT: i32 = 1;
+const DELETE_INT: i32 = 2;
+const INVITE_INT: i32 = 3;
+const PROVISION_INT: i32 = 4;
+const DEPROVISION_INT: i32 = 5;
+const BACKUP_INT: i32 = 6;
+const RESTART_INT: i32 = 7;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum AuditAction {
+    Create = CREATE_INT,
+    Update = UPDATE_INT,
+    Delete = DELETE_INT,
+    /// A user was invited to an organization.
+    Invite = INVITE_INT,
+    /// A user was provisioned with SCIM.
+    Provision = PROVISION_INT,
+    /// A user was deprovisioned with SCIM.
+    Deprovision = DEPROVISION_INT,
+    /// A server backup was started.
+    Backup = BACKUP_INT,
+    /// A server restart was requested.
+    Restart = RESTART_INT,
+}
+
+const SERVER_INT: i32 = 0;
+const ORGANIZATION_INT: i32 = 1;
+const MEMBER_INT: i32 = 2;
+const SSO_INT: i32 = 3;
+const PLAN_INT: i32 = 4;
+const ROLE_INT: i32 = 5;
+const PROJECT_INT: i32 = 10;
+const BRANCH_INT: i32 = 11;
+const TESTBED_INT: i32 = 12;
+const BENCHMARK_INT: i32 = 13;
+const MEASURE_INT: i32 = 14;
+const THRESHOLD_INT: i32 = 15;
+const ALERT_INT: i32 = 16;
+const REPORT_INT: i32 = 17;
+const PLOT_INT: i32 = 18;
+const WEBHOOK_INT: i32 = 19;
+const RETENTION_INT: i32 = 20;
+const PROJECT_MEMBER_INT: i32 = 21;
+const USER_INT: i32 = 30;
+const TOKEN_INT: i32 = 31;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Integer))]
+#[serde(rename_all = "snake_case")]
+#[repr(i32)]
+pub enum AuditResource {
+    Server = SERVER_INT,
+    Organization = ORGANIZATION_INT,
+    Member = MEMBER_INT,
+    Sso = SSO_INT,
+    Plan = PLAN_INT,
+    /// A custom organization role.
+    Role = ROLE_INT,
+    Project = PROJECT_INT,
+    Branch = BRANCH_INT,
+    Testbed = TESTBED_INT,
+    Benchmark = BENCHMARK_INT,
+    Measure = MEASURE_INT,
+    Threshold = THRESHOLD_INT,
+    Alert = ALERT_INT,
+    Report = REPORT_INT,
+    Plot = PLOT_INT,
+    Webhook = WEBHOOK_INT,
+    Retention = RETENTION_INT,
+    /// A custom role assignment for a project member.
+    ProjectMember = PROJECT_MEMBER_INT,
+    User = USER_INT,
+    Token = TOKEN_INT,
+}
+
+#[cfg(feature = "db")]
+mod audit_db {
+    use super::{
+        ALERT_INT, API_KEY_INT, AuditAction, AuditCredential, AuditResource, BACKUP_INT,
+        BENCHMARK_INT, BRANCH_INT, CREATE_INT, DELETE_INT, DEPROVISION_INT, INVITE_INT,
+        MEASURE_INT, MEMBER_INT, ORGANIZATION_INT, PLAN_INT, PLOT_INT, PROJECT_INT,
+        PROJECT_MEMBER_INT, PROVISION_INT, REPORT_INT, RESTART_INT, RETENTION_INT, ROLE_INT,
+        SERVER_INT, SESSION_INT, SSO_INT, TESTBED_INT, THRESHOLD_INT, TOKEN_INT, UPDATE_INT,
+        USER_INT, WEBHOOK_INT,
+    };
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum AuditError {
+        #[error("Invalid audit credential value: {0}")]
+        Credential(i32),
+        #[error("Invalid audit action value: {0}")]
+        Action(i32),
+        #[error("Invalid audit resource value: {0}")]
+        Resource(i32),
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for AuditCredential
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Session => SESSION_INT.to_sql(out),
+                Self::ApiKey => API_KEY_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for AuditCredential
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                SESSION_INT => Ok(Self::Session),
+                API_KEY_INT => Ok(Self::ApiKey),
+                value => Err(Box::new(AuditError::Credential(value))),
+            }
+        }
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for AuditAction
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Create => CREATE_INT.to_sql(out),
+                Self::Update => UPDATE_INT.to_sql(out),
+                Self::Delete => DELETE_INT.to_sql(out),
+                Self::Invite => INVITE_INT.to_sql(out),
+                Self::Provision => PROVISION_INT.to_sql(out),
+                Self::Deprovision => DEPROVISION_INT.to_sql(out),
+                Self::Backup => BACKUP_INT.to_sql(out),
+                Self::Restart => RESTART_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for AuditAction
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                CREATE_INT => Ok(Self::Create),
+                UPDATE_INT => Ok(Self::Update),
+                DELETE_INT => Ok(Self::Delete),
+                INVITE_INT => Ok(Self::Invite),
+                PROVISION_INT => Ok(Self::Provision),
+                DEPROVISION_INT => Ok(Self::Deprovision),
+                BACKUP_INT => Ok(Self::Backup),
+                RESTART_INT => Ok(Self::Restart),
+                value => Err(Box::new(AuditError::Action(value))),
+            }
+        }
+    }
+
+    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for AuditResource
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>,
+    {
+        fn to_sql<'b>(
+            &'b self,
+            out: &mut diesel::serialize::Output<'b, '_, DB>,
+        ) -> diesel::serialize::Result {
+            match self {
+                Self::Server => SERVER_INT.to_sql(out),
+                Self::Organization => ORGANIZATION_INT.to_sql(out),
+                Self::Member => MEMBER_INT.to_sql(out),
+                Self::Sso => SSO_INT.to_sql(out),
+                Self::Plan => PLAN_INT.to_sql(out),
+                Self::Role => ROLE_INT.to_sql(out),
+                Self::Project => PROJECT_INT.to_sql(out),
+                Self::Branch => BRANCH_INT.to_sql(out),
+                Self::Testbed => TESTBED_INT.to_sql(out),
+                Self::Benchmark => BENCHMARK_INT.to_sql(out),
+                Self::Measure => MEASURE_INT.to_sql(out),
+                Self::Threshold => THRESHOLD_INT.to_sql(out),
+                Self::Alert => ALERT_INT.to_sql(out),
+                Self::Report => REPORT_INT.to_sql(out),
+                Self::Plot => PLOT_INT.to_sql(out),
+                Self::Webhook => WEBHOOK_INT.to_sql(out),
+                Self::Retention => RETENTION_INT.to_sql(out),
+                Self::ProjectMember => PROJECT_MEMBER_INT.to_sql(out),
+                Self::User => USER_INT.to_sql(out),
+                Self::Token => TOKEN_INT.to_sql(out),
+            }
+        }
+    }
+
+    impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for AuditResource
+    where
+        DB: diesel::backend::Backend,
+        i32: diesel::deserialize::FromSql<diesel::sql_types::Integer, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+            match i32::from_sql(bytes)? {
+                SERVER_INT => Ok(Self::Server),
+                ORGANIZATION_INT => Ok(Self::Organization),
+                MEMBER_INT => Ok(Self::Member),
+                SSO_INT => Ok(Self::Sso),
+                PLAN_INT => Ok(Self::Plan),
+                ROLE_INT => Ok(Self::Role),
+                PROJECT_INT => Ok(Self::Project),
+                BRANCH_INT => Ok(Self::Branch),
+                TESTBED_INT => Ok(Self::Testbed),
+                BENCHMARK_INT => Ok(Self::Benchmark),
+                MEASURE_INT => Ok(Self::Measure),
+                THRESHOLD_INT => Ok(Self::Threshold),
+                ALERT_INT => Ok(Self::Alert),
+                REPORT_INT => Ok(Self::Report),
+                PLOT_INT => Ok(Self::Plot),
+                WEBHOOK_INT => Ok(Self::Webhook),
+                RETENTION_INT => Ok(Self::Retention),
+                PROJECT_MEMBER_INT => Ok(Self::ProjectMember),
+                USER_INT => Ok(Self::User),
+                TOKEN_INT => Ok(Self::Token),
+                value => Err(Box::new(AuditError::Resource(value))),
+            }
+        }
+    }
+}
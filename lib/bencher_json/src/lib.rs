@@ -1,11 +1,13 @@
 use std::sync::LazyLock;
 
+#[cfg(feature = "client")]
+pub use bencher_context::MergeBase;
 pub use bencher_context::RunContext;
 pub use bencher_valid::{
     BenchmarkName, Boundary, BranchName, CdfBoundary, DateTime, DateTimeMillis, Email, GitHash,
     Index, IntoResourceId, IqrBoundary, Jwt, Model, ModelTest, NameId, NonEmpty,
     PercentageBoundary, ResourceId, ResourceName, SampleSize, Sanitize, Search, Secret, Slug,
-    Units, Url, UserName, ValidError, Window,
+    TagKey, TagSelector, TagSelectors, TagValue, Units, Url, UserName, ValidError, Window,
 };
 #[cfg(feature = "plus")]
 pub use bencher_valid::{
@@ -18,6 +20,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(all(not(feature = "plus"), feature = "db"))]
 use serde_yaml as _;
 
+pub mod audit;
 pub mod big_int;
 pub mod organization;
 pub mod pagination;
@@ -30,16 +33,25 @@ pub(crate) mod typed_uuid;
 pub mod urlencoded;
 pub mod user;
 
+pub use audit::{
+    AuditAction, AuditCredential, AuditEventUuid, AuditEventsSort, AuditResource, JsonAuditEvent,
+    JsonAuditEvents, JsonAuditQuery,
+};
 pub use big_int::BigInt;
 pub use organization::{
     JsonNewOrganization, JsonOrganization, JsonOrganizations, OrganizationResourceId,
     OrganizationSlug, OrganizationUuid,
     claim::JsonNewClaim,
     member::{JsonMember, JsonMembers},
+    role::{JsonNewRole, JsonRole, JsonRoles, JsonUpdateRole, RoleUuid},
 };
 #[cfg(feature = "plus")]
 pub use organization::{
     plan::JsonPlan,
+    scim::{
+        JsonScimGroup, JsonScimGroups, JsonScimNewUser, JsonScimPatch, JsonScimUser, JsonScimUsers,
+        ScimGroupId,
+    },
     sso::{JsonNewSso, JsonSso, JsonSsos, SsoUuid},
     usage::JsonUsage,
 };
@@ -49,30 +61,40 @@ pub use project::{
     alert::{AlertUuid, JsonAlert, JsonAlerts},
     benchmark::{
         BenchmarkNameId, BenchmarkResourceId, BenchmarkSlug, BenchmarkUuid, JsonBenchmark,
-        JsonBenchmarks,
+        JsonBenchmarkTags, JsonBenchmarks,
     },
     boundary::{BoundaryUuid, JsonBoundaries, JsonBoundary},
     branch::{
         BranchNameId, BranchResourceId, BranchSlug, BranchUuid, JsonBranch, JsonBranches,
         JsonNewBranch, JsonNewStartPoint,
     },
+    change_point::{ChangePointUuid, JsonChangePoint, JsonChangePoints},
+    compare::{JsonCompare, JsonCompareQuery},
+    export::JsonProjectExport,
     head::{HeadUuid, JsonHead, JsonStartPoint, VersionUuid},
     measure::{
         JsonMeasure, JsonMeasures, JsonNewMeasure, MeasureNameId, MeasureResourceId, MeasureSlug,
         MeasureUuid,
     },
+    member::{JsonProjectMember, JsonProjectMembers, JsonUpdateProjectMember},
     metric::{
         JsonMetric, JsonMetricsMap, JsonNewMetric, JsonOneMetric, JsonResultsMap, MetricUuid,
     },
     model::{JsonModel, ModelUuid},
-    perf::{JsonPerf, JsonPerfQuery, ReportBenchmarkUuid},
+    perf::{
+        JsonPerf, JsonPerfQuery, PerfAggregate, PerfBucket, PerfExportFormat, ReportBenchmarkUuid,
+    },
     plot::{JsonNewPlot, JsonPlot, JsonPlots, PlotUuid},
-    report::{JsonNewReport, JsonReport, JsonReports, ReportUuid},
+    report::{JsonNewReport, JsonReport, JsonReports, ReportGroupUuid, ReportUuid},
+    retention::{JsonRetention, JsonRetentionPrune, JsonUpdateRetention},
     testbed::{
         JsonNewTestbed, JsonTestbed, JsonTestbeds, TestbedNameId, TestbedResourceId, TestbedSlug,
         TestbedUuid,
     },
     threshold::{JsonNewThreshold, JsonThreshold, JsonThresholds, ThresholdUuid},
+    webhook::{
+        JsonNewWebhook, JsonUpdateWebhook, JsonWebhook, JsonWebhookEvent, JsonWebhooks, WebhookUuid,
+    },
 };
 pub use run::JsonNewRun;
 pub use system::{
@@ -90,7 +112,7 @@ pub use system::{
 };
 pub use user::{
     JsonPubUser, JsonUpdateUser, JsonUser, JsonUsers, UserResourceId, UserSlug, UserUuid,
-    token::{JsonNewToken, JsonToken, JsonTokens, TokenUuid},
+    token::{JsonNewToken, JsonToken, JsonTokenScope, JsonTokens, TokenPermission, TokenUuid},
 };
 
 pub const BENCHER_API_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -14,6 +14,8 @@ use crate::{ProjectSlug, UserSlug, UserUuid};
 pub mod claim;
 pub mod member;
 pub mod plan;
+pub mod role;
+pub mod scim;
 pub mod sso;
 pub mod usage;
 
@@ -0,0 +1,49 @@
+use bencher_valid::{DateTime, ResourceName};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{OrganizationUuid, project::ProjectPermission};
+
+crate::typed_uuid::typed_uuid!(RoleUuid);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewRole {
+    /// The name of the role.
+    /// Maximum length is 64 characters.
+    pub name: ResourceName,
+    /// The project permissions granted by the role.
+    pub permissions: Vec<ProjectPermission>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRoles(pub Vec<JsonRole>);
+
+crate::from_vec!(JsonRoles[JsonRole]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRole {
+    pub uuid: RoleUuid,
+    pub organization: OrganizationUuid,
+    pub name: ResourceName,
+    pub permissions: Vec<ProjectPermission>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateRole {
+    /// The new name of the role.
+    /// Maximum length is 64 characters.
+    pub name: Option<ResourceName>,
+    /// The new project permissions granted by the role.
+    /// This replaces all of the existing permissions.
+    pub permissions: Option<Vec<ProjectPermission>>,
+}
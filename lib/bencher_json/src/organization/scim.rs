@@ -0,0 +1,423 @@
+#![cfg(feature = "plus")]
+
+use std::{fmt, str::FromStr};
+
+use bencher_valid::{DateTime, Email, UserName};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{ProjectUuid, UserUuid, organization::member::OrganizationRole, project::ProjectRole};
+
+pub const SCIM_USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const SCIM_GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+pub const SCIM_LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+
+/// A SCIM 2.0 user to provision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct JsonScimNewUser {
+    pub schemas: Option<Vec<String>>,
+    /// The unique identifier for the user, usually their email.
+    pub user_name: String,
+    pub external_id: Option<String>,
+    pub name: Option<JsonScimName>,
+    pub display_name: Option<String>,
+    pub emails: Option<Vec<JsonScimEmail>>,
+    /// If `false`, the user is not added to the organization.
+    pub active: Option<bool>,
+}
+
+impl JsonScimNewUser {
+    /// The email for the user.
+    /// This is the primary email, then the first email, then the user name.
+    pub fn email(&self) -> Option<Email> {
+        let emails = self.emails.as_deref().unwrap_or_default();
+        emails
+            .iter()
+            .find(|email| email.primary.unwrap_or_default())
+            .or_else(|| emails.first())
+            .map_or(self.user_name.as_str(), |email| email.value.as_str())
+            .parse()
+            .ok()
+    }
+
+    /// The name for the user.
+    /// This is the display name, then the formatted name, then the given and family names.
+    pub fn user_name(&self) -> Option<UserName> {
+        let name = self.name.as_ref();
+        let given_family =
+            name.and_then(
+                |name| match (name.given_name.as_deref(), name.family_name.as_deref()) {
+                    (Some(given), Some(family)) => Some(format!("{given} {family}")),
+                    (Some(name), None) | (None, Some(name)) => Some(name.to_owned()),
+                    (None, None) => None,
+                },
+            );
+        [
+            self.display_name.clone(),
+            name.and_then(|name| name.formatted.clone()),
+            given_family,
+        ]
+        .into_iter()
+        .flatten()
+        .find_map(|name| name.parse().ok())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct JsonScimName {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonScimEmail {
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<bool>,
+}
+
+/// A SCIM 2.0 user that is a member of the organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct JsonScimUser {
+    pub schemas: Vec<String>,
+    pub id: UserUuid,
+    pub user_name: Email,
+    pub name: JsonScimName,
+    pub display_name: UserName,
+    pub emails: Vec<JsonScimEmail>,
+    pub active: bool,
+    pub groups: Vec<JsonScimMember>,
+    pub meta: JsonScimMeta,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct JsonScimUsers {
+    pub schemas: Vec<String>,
+    pub total_results: u32,
+    pub start_index: u32,
+    pub items_per_page: u32,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<JsonScimUser>,
+}
+
+/// A SCIM 2.0 group, which maps to an organization or project role.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct JsonScimGroup {
+    pub schemas: Vec<String>,
+    pub id: ScimGroupId,
+    pub display_name: String,
+    pub members: Vec<JsonScimMember>,
+    pub meta: JsonScimMeta,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct JsonScimGroups {
+    pub schemas: Vec<String>,
+    pub total_results: u32,
+    pub start_index: u32,
+    pub items_per_page: u32,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<JsonScimGroup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonScimMember {
+    /// The ID of the user or group.
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct JsonScimMeta {
+    pub resource_type: String,
+    pub created: DateTime,
+    pub last_modified: DateTime,
+}
+
+/// A SCIM 2.0 `PatchOp` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonScimPatch {
+    pub schemas: Option<Vec<String>>,
+    #[serde(rename = "Operations")]
+    pub operations: Vec<JsonScimPatchOperation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonScimPatchOperation {
+    pub op: ScimPatchOp,
+    pub path: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ScimPatchOp {
+    #[serde(alias = "Add")]
+    Add,
+    #[serde(alias = "Remove")]
+    Remove,
+    #[serde(alias = "Replace")]
+    Replace,
+}
+
+impl JsonScimPatchOperation {
+    /// The value of the `active` attribute, if set by this operation.
+    pub fn active(&self) -> Option<bool> {
+        let value = self.value.as_ref()?;
+        match self.path.as_deref() {
+            Some("active") => value_bool(value),
+            Some(_) => None,
+            None => value.get("active").and_then(value_bool),
+        }
+    }
+
+    /// The user IDs for the members added or removed by this operation.
+    pub fn members(&self) -> Vec<String> {
+        let path = self.path.as_deref().unwrap_or_default();
+        // Remove a single member with a filter: `members[value eq "<user>"]`
+        if let Some(filter) = path
+            .strip_prefix("members[")
+            .and_then(|filter| filter.strip_suffix(']'))
+        {
+            return ScimFilter::from_str(filter)
+                .ok()
+                .filter(|filter| filter.attribute == "value")
+                .map(|filter| vec![filter.value])
+                .unwrap_or_default();
+        }
+        let value = match (path, self.value.as_ref()) {
+            ("members", Some(value)) => Some(value),
+            ("", Some(value)) => value.get("members"),
+            _ => None,
+        };
+        value
+            .and_then(serde_json::Value::as_array)
+            .map(|members| {
+                members
+                    .iter()
+                    .filter_map(|member| member.get("value"))
+                    .filter_map(serde_json::Value::as_str)
+                    .map(ToOwned::to_owned)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn value_bool(value: &serde_json::Value) -> Option<bool> {
+    match value {
+        serde_json::Value::Bool(b) => Some(*b),
+        // Some identity providers send booleans as strings
+        serde_json::Value::String(s) => s.to_lowercase().parse().ok(),
+        serde_json::Value::Null
+        | serde_json::Value::Number(_)
+        | serde_json::Value::Array(_)
+        | serde_json::Value::Object(_) => None,
+    }
+}
+
+/// A SCIM 2.0 filter.
+/// Only a single equality expression is supported, for example: `userName eq "bjorn@example.com"`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScimFilter {
+    pub attribute: String,
+    pub value: String,
+}
+
+impl FromStr for ScimFilter {
+    type Err = String;
+
+    fn from_str(filter: &str) -> Result<Self, Self::Err> {
+        let mut parts = filter.trim().splitn(3, ' ');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(attribute), Some(op), Some(value)) if op.eq_ignore_ascii_case("eq") => {
+                let value = value.trim();
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|value| value.strip_suffix('"'))
+                    .unwrap_or(value);
+                Ok(Self {
+                    attribute: attribute.to_owned(),
+                    value: value.to_owned(),
+                })
+            },
+            _ => Err(format!(
+                "Unsupported SCIM filter ({filter}). Only `<attribute> eq \"<value>\"` is supported."
+            )),
+        }
+    }
+}
+
+/// The ID of a SCIM group.
+/// Organization role groups use the role name, for example: `leader`
+/// Project role groups use the project UUID and role name, for example: `<project>.maintainer`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum ScimGroupId {
+    Organization(OrganizationRole),
+    Project(ProjectUuid, ProjectRole),
+}
+
+#[cfg(feature = "schema")]
+impl JsonSchema for ScimGroupId {
+    fn schema_name() -> String {
+        "ScimGroupId".to_owned()
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(generator)
+    }
+}
+
+impl fmt::Display for ScimGroupId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Organization(role) => write!(f, "{role}"),
+            Self::Project(project, role) => write!(f, "{project}.{role}"),
+        }
+    }
+}
+
+impl FromStr for ScimGroupId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((project, role)) = s.split_once('.') {
+            let project = project
+                .parse()
+                .map_err(|_err| format!("Invalid SCIM group project ({project})"))?;
+            Ok(Self::Project(project, role.parse()?))
+        } else {
+            Ok(Self::Organization(s.parse()?))
+        }
+    }
+}
+
+impl TryFrom<String> for ScimGroupId {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<ScimGroupId> for String {
+    fn from(group: ScimGroupId) -> Self {
+        group.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{JsonScimPatch, JsonScimPatchOperation, ScimFilter, ScimGroupId};
+    use crate::{organization::member::OrganizationRole, project::ProjectRole};
+
+    #[test]
+    fn scim_filter() {
+        assert_eq!(
+            "userName eq \"bjorn@example.com\"".parse::<ScimFilter>(),
+            Ok(ScimFilter {
+                attribute: "userName".into(),
+                value: "bjorn@example.com".into(),
+            })
+        );
+        assert_eq!(
+            "displayName EQ \"leader\"".parse::<ScimFilter>(),
+            Ok(ScimFilter {
+                attribute: "displayName".into(),
+                value: "leader".into(),
+            })
+        );
+        assert!("userName sw \"bjorn\"".parse::<ScimFilter>().is_err());
+        assert!("userName".parse::<ScimFilter>().is_err());
+    }
+
+    #[test]
+    fn scim_group_id() {
+        assert_eq!(
+            "leader".parse::<ScimGroupId>(),
+            Ok(ScimGroupId::Organization(OrganizationRole::Leader))
+        );
+        let project = "3d8b5a4e-a8b8-4b0a-9d44-5a8e6a9a7b3c";
+        let group_id = format!("{project}.maintainer");
+        assert_eq!(
+            group_id.parse::<ScimGroupId>(),
+            Ok(ScimGroupId::Project(
+                project.parse().unwrap(),
+                ProjectRole::Maintainer
+            ))
+        );
+        assert_eq!(
+            group_id.parse::<ScimGroupId>().unwrap().to_string(),
+            group_id
+        );
+        assert!("follower".parse::<ScimGroupId>().is_err());
+        assert!("not-a-uuid.maintainer".parse::<ScimGroupId>().is_err());
+    }
+
+    #[test]
+    fn scim_patch_active() {
+        let patch: JsonScimPatch = serde_json::from_str(
+            r#"{"schemas":["urn:ietf:params:scim:api:messages:2.0:PatchOp"],"Operations":[{"op":"Replace","path":"active","value":"False"}]}"#,
+        )
+        .unwrap();
+        let active = patch
+            .operations
+            .iter()
+            .find_map(JsonScimPatchOperation::active);
+        assert_eq!(active, Some(false));
+
+        let patch: JsonScimPatch =
+            serde_json::from_str(r#"{"Operations":[{"op":"replace","value":{"active":true}}]}"#)
+                .unwrap();
+        let active = patch
+            .operations
+            .iter()
+            .find_map(JsonScimPatchOperation::active);
+        assert_eq!(active, Some(true));
+    }
+
+    #[test]
+    fn scim_patch_members() {
+        let patch: JsonScimPatch = serde_json::from_str(
+            r#"{"Operations":[{"op":"add","path":"members","value":[{"value":"a"},{"value":"b"}]},{"op":"remove","path":"members[value eq \"c\"]"}]}"#,
+        )
+        .unwrap();
+        let members = patch
+            .operations
+            .iter()
+            .map(JsonScimPatchOperation::members)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            members,
+            vec![vec!["a".to_owned(), "b".to_owned()], vec!["c".to_owned()]]
+        );
+    }
+}
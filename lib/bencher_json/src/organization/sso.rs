@@ -14,6 +14,10 @@ pub struct JsonNewSso {
     /// The SSO domain.
     /// For example: example.com
     pub domain: NonEmpty,
+    /// Allow users with an email at the SSO domain
+    /// to log in with the OIDC provider configured for the server.
+    /// Defaults to `false`.
+    pub oidc: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,5 +32,6 @@ crate::from_vec!(JsonSsos[JsonSso]);
 pub struct JsonSso {
     pub uuid: SsoUuid,
     pub domain: NonEmpty,
+    pub oidc: bool,
     pub created: DateTime,
 }
@@ -1,6 +1,6 @@
-use std::fmt;
+use std::{collections::BTreeMap, fmt};
 
-use bencher_valid::{BenchmarkName, DateTime, NameId, ResourceId};
+use bencher_valid::{BenchmarkName, DateTime, NameId, ResourceId, TagKey, TagValue};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -18,6 +18,9 @@ pub type BenchmarkResourceId = ResourceId<BenchmarkUuid, BenchmarkSlug>;
 #[typeshare::typeshare]
 pub type BenchmarkNameId = NameId<BenchmarkUuid, BenchmarkSlug, BenchmarkName>;
 
+/// The key/value tags for a benchmark.
+pub type JsonBenchmarkTags = BTreeMap<TagKey, TagValue>;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonNewBenchmark {
@@ -29,6 +32,11 @@ pub struct JsonNewBenchmark {
     /// If the provided or generated slug is already in use, a unique slug will be generated.
     /// Maximum length is 64 characters.
     pub slug: Option<BenchmarkSlug>,
+    /// The key/value tags for the benchmark.
+    /// Tag keys may only contain ASCII letters, numbers, `_`, `-`, and `.`.
+    /// Tag values may not contain a `,`.
+    /// Maximum length for both is 64 characters.
+    pub tags: Option<JsonBenchmarkTags>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +56,9 @@ pub struct JsonBenchmark {
     pub created: DateTime,
     pub modified: DateTime,
     pub archived: Option<DateTime>,
+    /// The key/value tags for the benchmark.
+    /// Only included when the benchmark is returned by the benchmark endpoints.
+    pub tags: Option<JsonBenchmarkTags>,
 }
 
 impl fmt::Display for JsonBenchmark {
@@ -67,4 +78,8 @@ pub struct JsonUpdateBenchmark {
     pub slug: Option<BenchmarkSlug>,
     /// Set whether the benchmark is archived.
     pub archived: Option<bool>,
+    /// The new key/value tags for the benchmark.
+    /// This replaces all of the current tags for the benchmark.
+    /// Set to an empty object to remove all tags.
+    pub tags: Option<JsonBenchmarkTags>,
 }
@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use crate::{JsonHead, ProjectUuid};
 
 pub const START_POINT_MAX_VERSIONS: u32 = 255;
+/// The maximum number of start point ancestor hashes that are checked.
+pub const START_POINT_MAX_ANCESTORS: usize = 255;
 
 pub const BRANCH_MAIN_STR: &str = "main";
 #[expect(clippy::expect_used)]
@@ -81,6 +83,12 @@ pub struct JsonNewStartPoint {
     /// The full `git` hash of the branch to use as the start point.
     /// Requires the `branch` field to be set.
     pub hash: Option<GitHash>,
+    /// The first-parent ancestors of the start point `hash`, ordered from nearest to furthest.
+    /// If the start point branch does not have a version for `hash`,
+    /// then the nearest ancestor that it does have a version for is used instead.
+    /// Only the nearest 255 ancestors are checked.
+    /// Requires the `hash` field to be set.
+    pub ancestor_hashes: Option<Vec<GitHash>>,
     /// The maximum number of historical branch versions to include.
     /// Versions beyond this number will be omitted.
     /// The default is 255.
@@ -141,6 +149,12 @@ pub struct JsonUpdateStartPoint {
     /// The full git hash of the branch to use as the start point.
     /// Requires the `branch` field to be set.
     pub hash: Option<GitHash>,
+    /// The first-parent ancestors of the start point `hash`, ordered from nearest to furthest.
+    /// If the start point branch does not have a version for `hash`,
+    /// then the nearest ancestor that it does have a version for is used instead.
+    /// Only the nearest 255 ancestors are checked.
+    /// Requires the `hash` field to be set.
+    pub ancestor_hashes: Option<Vec<GitHash>>,
     /// The maximum number of historical branch versions to include.
     /// Versions beyond this number will be omitted.
     /// The default is 255.
@@ -161,6 +175,7 @@ impl From<JsonUpdateStartPoint> for Option<JsonNewStartPoint> {
         let JsonUpdateStartPoint {
             branch,
             hash,
+            ancestor_hashes,
             max_versions,
             clone_thresholds,
             // We don't care about the reset field since it is a new start point anyway.
@@ -170,6 +185,7 @@ impl From<JsonUpdateStartPoint> for Option<JsonNewStartPoint> {
             // The branch field is required for a new start point.
             branch: branch?,
             hash,
+            ancestor_hashes,
             max_versions,
             clone_thresholds,
         })
@@ -0,0 +1,35 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{DateTime, JsonBenchmark, JsonThreshold};
+
+use super::{head::JsonVersion, report::Iteration, report::ReportUuid};
+
+crate::typed_uuid::typed_uuid!(ChangePointUuid);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonChangePoints(pub Vec<JsonChangePoint>);
+
+crate::from_vec!(JsonChangePoints[JsonChangePoint]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonChangePoint {
+    pub uuid: ChangePointUuid,
+    /// The report where the change in performance was first detected.
+    pub report: ReportUuid,
+    /// The report iteration where the change in performance was first detected.
+    pub iteration: Iteration,
+    /// The version where the change in performance was first detected.
+    pub version: JsonVersion,
+    pub benchmark: JsonBenchmark,
+    pub threshold: JsonThreshold,
+    /// The mean of the metrics before the change point.
+    pub baseline: f64,
+    /// The mean of the metrics after the change point.
+    pub value: f64,
+    pub created: DateTime,
+}
@@ -0,0 +1,380 @@
+use bencher_valid::{Boundary, DateTime, GitHash, Model, ModelTest, ValidError};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BranchNameId, HeadUuid, JsonBenchmark, JsonBranch, JsonMeasure, JsonTestbed, ReportUuid,
+    TestbedNameId,
+};
+
+pub const BASELINE: &str = "baseline";
+pub const CANDIDATE: &str = "candidate";
+
+/// `JsonCompareQueryParams` is the actual query parameters accepted by the server.
+/// Each side of the comparison must be either a report, a branch head, or a branch.
+/// `JsonCompareQueryParams` should always be converted into `JsonCompareQuery` for full type level validation.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareQueryParams {
+    /// The baseline report UUID.
+    pub baseline_report: Option<ReportUuid>,
+    /// The baseline branch head UUID.
+    /// The most recent report for the branch head is used.
+    pub baseline_head: Option<HeadUuid>,
+    /// The baseline branch UUID, slug, or name.
+    /// The most recent report for the current branch head is used.
+    pub baseline_branch: Option<BranchNameId>,
+    /// The baseline `git` hash.
+    /// Requires either `baseline_head` or `baseline_branch`.
+    pub baseline_hash: Option<GitHash>,
+    /// The candidate report UUID.
+    pub candidate_report: Option<ReportUuid>,
+    /// The candidate branch head UUID.
+    /// The most recent report for the branch head is used.
+    pub candidate_head: Option<HeadUuid>,
+    /// The candidate branch UUID, slug, or name.
+    /// The most recent report for the current branch head is used.
+    pub candidate_branch: Option<BranchNameId>,
+    /// The candidate `git` hash.
+    /// Requires either `candidate_head` or `candidate_branch`.
+    pub candidate_hash: Option<GitHash>,
+    /// The testbed UUID, slug, or name to use when selecting the most recent report
+    /// for a branch head or branch.
+    pub testbed: Option<TestbedNameId>,
+    /// The test used to determine if a change is significant.
+    /// The default is `percentage`.
+    pub test: Option<ModelTest>,
+    /// The lower boundary used to determine if a change is significant.
+    pub lower_boundary: Option<Boundary>,
+    /// The upper boundary used to determine if a change is significant.
+    /// If neither boundary is set, then the default upper boundary for the test is used:
+    /// `0.1` for `percentage`, `3.0` for `iqr` and `delta_iqr`, and `0.99` for all other statistical tests.
+    pub upper_boundary: Option<Boundary>,
+}
+
+/// `JsonCompareQuery` is the full, strongly typed version of `JsonCompareQueryParams`.
+/// It should always be used to validate `JsonCompareQueryParams`.
+#[derive(Debug, Clone)]
+pub struct JsonCompareQuery {
+    pub baseline: JsonCompareSide,
+    pub candidate: JsonCompareSide,
+    pub testbed: Option<TestbedNameId>,
+    pub model: Model,
+}
+
+#[derive(Debug, Clone)]
+pub enum JsonCompareSide {
+    Report(ReportUuid),
+    Head {
+        head: HeadUuid,
+        hash: Option<GitHash>,
+    },
+    Branch {
+        branch: BranchNameId,
+        hash: Option<GitHash>,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompareQueryError {
+    #[error("Exactly one of `{0}_report`, `{0}_head`, or `{0}_branch` must be set")]
+    Side(&'static str),
+    #[error("The `{0}_hash` parameter can not be used with `{0}_report`")]
+    ReportHash(&'static str),
+    #[error("Invalid comparison model: {0}")]
+    Model(ValidError),
+}
+
+impl TryFrom<JsonCompareQueryParams> for JsonCompareQuery {
+    type Error = CompareQueryError;
+
+    fn try_from(query_params: JsonCompareQueryParams) -> Result<Self, Self::Error> {
+        let JsonCompareQueryParams {
+            baseline_report,
+            baseline_head,
+            baseline_branch,
+            baseline_hash,
+            candidate_report,
+            candidate_head,
+            candidate_branch,
+            candidate_hash,
+            testbed,
+            test,
+            lower_boundary,
+            upper_boundary,
+        } = query_params;
+
+        let baseline = JsonCompareSide::new(
+            BASELINE,
+            baseline_report,
+            baseline_head,
+            baseline_branch,
+            baseline_hash,
+        )?;
+        let candidate = JsonCompareSide::new(
+            CANDIDATE,
+            candidate_report,
+            candidate_head,
+            candidate_branch,
+            candidate_hash,
+        )?;
+
+        let test = test.unwrap_or(ModelTest::Percentage);
+        let model = Model {
+            test,
+            min_sample_size: None,
+            max_sample_size: None,
+            window: None,
+            lower_boundary,
+            upper_boundary: if lower_boundary.is_none() && upper_boundary.is_none() {
+                default_upper_boundary(test)
+            } else {
+                upper_boundary
+            },
+        };
+        model.validate().map_err(CompareQueryError::Model)?;
+
+        Ok(Self {
+            baseline,
+            candidate,
+            testbed,
+            model,
+        })
+    }
+}
+
+// A static test requires an explicit boundary.
+fn default_upper_boundary(test: ModelTest) -> Option<Boundary> {
+    match test {
+        ModelTest::Static => None,
+        ModelTest::Percentage => Some(Boundary::TEN),
+        ModelTest::ZScore
+        | ModelTest::TTest
+        | ModelTest::WelchTTest
+        | ModelTest::LogNormal
+        | ModelTest::MannWhitney => Some(Boundary::NINETY_NINE),
+        ModelTest::Iqr | ModelTest::DeltaIqr => Some(Boundary::THREE),
+    }
+}
+
+impl JsonCompareSide {
+    fn new(
+        side: &'static str,
+        report: Option<ReportUuid>,
+        head: Option<HeadUuid>,
+        branch: Option<BranchNameId>,
+        hash: Option<GitHash>,
+    ) -> Result<Self, CompareQueryError> {
+        match (report, head, branch) {
+            (Some(report), None, None) => {
+                if hash.is_some() {
+                    Err(CompareQueryError::ReportHash(side))
+                } else {
+                    Ok(Self::Report(report))
+                }
+            },
+            (None, Some(head), None) => Ok(Self::Head { head, hash }),
+            (None, None, Some(branch)) => Ok(Self::Branch { branch, hash }),
+            _ => Err(CompareQueryError::Side(side)),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompare {
+    pub baseline: JsonCompareReport,
+    pub candidate: JsonCompareReport,
+    /// The test used to determine if a change is significant.
+    pub test: ModelTest,
+    pub lower_boundary: Option<Boundary>,
+    pub upper_boundary: Option<Boundary>,
+    /// The comparison for every benchmark and measure in either report.
+    pub results: Vec<JsonCompareResult>,
+    pub summary: JsonCompareSummary,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareReport {
+    pub uuid: ReportUuid,
+    pub branch: JsonBranch,
+    pub testbed: JsonTestbed,
+    pub start_time: DateTime,
+    pub end_time: DateTime,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareResult {
+    pub benchmark: JsonBenchmark,
+    pub measure: JsonMeasure,
+    /// The mean of the baseline metrics across all report iterations.
+    pub baseline: Option<f64>,
+    /// The mean of the candidate metrics across all report iterations.
+    pub candidate: Option<f64>,
+    /// The absolute change from the baseline to the candidate.
+    pub delta: Option<f64>,
+    /// The relative change from the baseline to the candidate, as a percentage.
+    pub percent: Option<f64>,
+    pub verdict: CompareVerdict,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum CompareVerdict {
+    /// The candidate is significantly better than the baseline.
+    Improved,
+    /// The candidate is significantly worse than the baseline.
+    Regressed,
+    /// There is no significant difference between the baseline and the candidate.
+    Unchanged,
+    /// The benchmark measure is only in the candidate.
+    Added,
+    /// The benchmark measure is only in the baseline.
+    Removed,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonCompareSummary {
+    pub improved: u32,
+    pub regressed: u32,
+    pub unchanged: u32,
+    pub added: u32,
+    pub removed: u32,
+}
+
+impl JsonCompareSummary {
+    pub fn new<I>(verdicts: I) -> Self
+    where
+        I: IntoIterator<Item = CompareVerdict>,
+    {
+        let mut summary = Self::default();
+        for verdict in verdicts {
+            let count = match verdict {
+                CompareVerdict::Improved => &mut summary.improved,
+                CompareVerdict::Regressed => &mut summary.regressed,
+                CompareVerdict::Unchanged => &mut summary.unchanged,
+                CompareVerdict::Added => &mut summary.added,
+                CompareVerdict::Removed => &mut summary.removed,
+            };
+            *count += 1;
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_valid::{Boundary, ModelTest};
+    use pretty_assertions::assert_eq;
+
+    use super::{
+        CompareQueryError, CompareVerdict, JsonCompareQuery, JsonCompareQueryParams,
+        JsonCompareSide, JsonCompareSummary,
+    };
+
+    const REPORT: &str = "e3c2d9b4-9b6a-4d6a-a7d4-3d1f2c5c6a1b";
+    const HASH: &str = "68b12758509ca5e2b1e3983525625e532eb5f562";
+
+    fn query(query: &str) -> Result<JsonCompareQuery, CompareQueryError> {
+        serde_urlencoded::from_str::<JsonCompareQueryParams>(query)
+            .unwrap()
+            .try_into()
+    }
+
+    #[test]
+    fn compare_query_report_branch() {
+        let query = query(&format!(
+            "baseline_branch=main&baseline_hash={HASH}&candidate_report={REPORT}"
+        ))
+        .unwrap();
+        assert!(matches!(
+            query.baseline,
+            JsonCompareSide::Branch { hash: Some(_), .. }
+        ));
+        assert!(matches!(query.candidate, JsonCompareSide::Report(_)));
+        assert_eq!(query.model.test, ModelTest::Percentage);
+        assert_eq!(query.model.lower_boundary, None);
+        assert_eq!(query.model.upper_boundary, Some(Boundary::TEN));
+    }
+
+    #[test]
+    fn compare_query_model() {
+        let lower = query(&format!(
+            "baseline_report={REPORT}&candidate_report={REPORT}&test=t_test&lower_boundary=0.95"
+        ))
+        .unwrap();
+        assert_eq!(lower.model.test, ModelTest::TTest);
+        assert_eq!(lower.model.lower_boundary, Some(Boundary::NINETY_FIVE));
+        assert_eq!(lower.model.upper_boundary, None);
+
+        let default = query(&format!(
+            "baseline_report={REPORT}&candidate_report={REPORT}&test=t_test"
+        ))
+        .unwrap();
+        assert_eq!(default.model.lower_boundary, None);
+        assert_eq!(default.model.upper_boundary, Some(Boundary::NINETY_NINE));
+    }
+
+    #[test]
+    fn compare_query_err() {
+        assert!(matches!(
+            query(&format!("candidate_report={REPORT}")),
+            Err(CompareQueryError::Side("baseline"))
+        ));
+        assert!(matches!(
+            query(&format!(
+                "baseline_report={REPORT}&baseline_branch=main&candidate_report={REPORT}"
+            )),
+            Err(CompareQueryError::Side("baseline"))
+        ));
+        assert!(matches!(
+            query(&format!(
+                "baseline_report={REPORT}&candidate_report={REPORT}&candidate_hash={HASH}"
+            )),
+            Err(CompareQueryError::ReportHash("candidate"))
+        ));
+        assert!(matches!(
+            query(&format!(
+                "baseline_report={REPORT}&candidate_report={REPORT}&test=t_test&upper_boundary=0.1"
+            )),
+            Err(CompareQueryError::Model(_))
+        ));
+        assert!(matches!(
+            query(&format!(
+                "baseline_report={REPORT}&candidate_report={REPORT}&test=static"
+            )),
+            Err(CompareQueryError::Model(_))
+        ));
+    }
+
+    #[test]
+    fn compare_summary() {
+        let summary = JsonCompareSummary::new([
+            CompareVerdict::Improved,
+            CompareVerdict::Regressed,
+            CompareVerdict::Regressed,
+            CompareVerdict::Unchanged,
+            CompareVerdict::Added,
+        ]);
+        assert_eq!(
+            summary,
+            JsonCompareSummary {
+                improved: 1,
+                regressed: 2,
+                unchanged: 1,
+                added: 1,
+                removed: 0,
+            }
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use bencher_valid::{
+    BenchmarkName, Boundary, BranchName, DateTime, GitHash, ModelTest, ResourceName, SampleSize,
+    TagSelectors, Url, Window,
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AlertUuid, BenchmarkSlug, BenchmarkUuid, BoundaryUuid, BranchSlug, BranchUuid, ChangePointUuid,
+    HeadUuid, JsonBenchmarkTags, MeasureSlug, MeasureUuid, MetricUuid, ModelUuid, PlotUuid,
+    ProjectSlug, ProjectUuid, ReportBenchmarkUuid, ReportGroupUuid, ReportUuid, TestbedSlug,
+    TestbedUuid, ThresholdUuid, VersionUuid,
+    project::{
+        Visibility,
+        alert::AlertStatus,
+        boundary::BoundaryLimit,
+        head::VersionNumber,
+        plot::XAxis,
+        report::{Adapter, Iteration},
+    },
+};
+
+/// A versioned archive of all of the data for a project.
+/// All references between resources use their UUIDs.
+/// Every list is in creation order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonProjectExport {
+    /// The archive format version.
+    pub version: u32,
+    pub project: JsonExportProject,
+    pub measures: Vec<JsonExportMeasure>,
+    pub testbeds: Vec<JsonExportTestbed>,
+    pub benchmarks: Vec<JsonExportBenchmark>,
+    pub versions: Vec<JsonExportVersion>,
+    pub branches: Vec<JsonExportBranch>,
+    pub heads: Vec<JsonExportHead>,
+    pub thresholds: Vec<JsonExportThreshold>,
+    pub models: Vec<JsonExportModel>,
+    pub reports: Vec<JsonExportReport>,
+    pub boundaries: Vec<JsonExportBoundary>,
+    pub alerts: Vec<JsonExportAlert>,
+    pub change_points: Vec<JsonExportChangePoint>,
+    pub plots: Vec<JsonExportPlot>,
+}
+
+impl JsonProjectExport {
+    /// The current archive format version.
+    pub const VERSION: u32 = 1;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportProject {
+    pub uuid: ProjectUuid,
+    pub name: ResourceName,
+    pub slug: ProjectSlug,
+    pub url: Option<Url>,
+    pub visibility: Visibility,
+    #[serde(default)]
+    pub openmetrics: bool,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportMeasure {
+    pub uuid: MeasureUuid,
+    pub name: ResourceName,
+    pub slug: MeasureSlug,
+    pub units: ResourceName,
+    pub created: DateTime,
+    pub modified: DateTime,
+    pub archived: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportTestbed {
+    pub uuid: TestbedUuid,
+    pub name: ResourceName,
+    pub slug: TestbedSlug,
+    pub created: DateTime,
+    pub modified: DateTime,
+    pub archived: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportBenchmark {
+    pub uuid: BenchmarkUuid,
+    pub name: BenchmarkName,
+    pub slug: BenchmarkSlug,
+    pub tags: Option<JsonBenchmarkTags>,
+    pub created: DateTime,
+    pub modified: DateTime,
+    pub archived: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportVersion {
+    pub uuid: VersionUuid,
+    pub number: VersionNumber,
+    pub hash: Option<GitHash>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportBranch {
+    pub uuid: BranchUuid,
+    pub name: BranchName,
+    pub slug: BranchSlug,
+    /// The current head of the branch.
+    pub head: Option<HeadUuid>,
+    pub created: DateTime,
+    pub modified: DateTime,
+    pub archived: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportHead {
+    pub uuid: HeadUuid,
+    pub branch: BranchUuid,
+    pub start_point: Option<JsonExportStartPoint>,
+    /// The versions that belong to the head.
+    pub versions: Vec<VersionUuid>,
+    pub created: DateTime,
+    pub replaced: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportStartPoint {
+    pub head: HeadUuid,
+    pub version: VersionUuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportThreshold {
+    pub uuid: ThresholdUuid,
+    pub branch: BranchUuid,
+    pub testbed: TestbedUuid,
+    pub measure: MeasureUuid,
+    pub benchmark_tags: Option<TagSelectors>,
+    /// The current model for the threshold.
+    pub model: Option<ModelUuid>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportModel {
+    pub uuid: ModelUuid,
+    pub threshold: ThresholdUuid,
+    pub test: ModelTest,
+    pub min_sample_size: Option<SampleSize>,
+    pub max_sample_size: Option<SampleSize>,
+    pub window: Option<Window>,
+    pub lower_boundary: Option<Boundary>,
+    pub upper_boundary: Option<Boundary>,
+    pub created: DateTime,
+    pub replaced: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportReport {
+    pub uuid: ReportUuid,
+    pub head: HeadUuid,
+    pub version: VersionUuid,
+    pub testbed: TestbedUuid,
+    pub adapter: Adapter,
+    pub start_time: DateTime,
+    pub end_time: DateTime,
+    pub group: Option<ReportGroupUuid>,
+    pub created: DateTime,
+    pub results: Vec<JsonExportReportResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportReportResult {
+    pub uuid: ReportBenchmarkUuid,
+    pub iteration: Iteration,
+    pub benchmark: BenchmarkUuid,
+    pub metrics: Vec<JsonExportMetric>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportMetric {
+    pub uuid: MetricUuid,
+    pub measure: MeasureUuid,
+    pub value: f64,
+    pub lower_value: Option<f64>,
+    pub upper_value: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportBoundary {
+    pub uuid: BoundaryUuid,
+    pub metric: MetricUuid,
+    pub threshold: ThresholdUuid,
+    pub model: ModelUuid,
+    pub baseline: Option<f64>,
+    pub lower_limit: Option<f64>,
+    pub upper_limit: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportAlert {
+    pub uuid: AlertUuid,
+    pub boundary: BoundaryUuid,
+    pub limit: BoundaryLimit,
+    pub status: AlertStatus,
+    pub modified: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportChangePoint {
+    pub uuid: ChangePointUuid,
+    pub metric: MetricUuid,
+    pub threshold: ThresholdUuid,
+    pub model: ModelUuid,
+    pub baseline: f64,
+    pub value: f64,
+    pub created: DateTime,
+}
+
+#[expect(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonExportPlot {
+    pub uuid: PlotUuid,
+    pub title: Option<ResourceName>,
+    pub lower_value: bool,
+    pub upper_value: bool,
+    pub lower_boundary: bool,
+    pub upper_boundary: bool,
+    pub x_axis: XAxis,
+    pub window: Window,
+    pub branches: Vec<BranchUuid>,
+    pub testbeds: Vec<TestbedUuid>,
+    pub benchmarks: Vec<BenchmarkUuid>,
+    pub benchmark_tags: Option<TagSelectors>,
+    pub measures: Vec<MeasureUuid>,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
@@ -61,20 +61,9 @@ mod version_number {
         }
     }
 
-    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for VersionNumber
-    where
-        DB: diesel::backend::Backend,
-        for<'a> i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
-    {
-        fn to_sql<'b>(
-            &'b self,
-            out: &mut diesel::serialize::Output<'b, '_, DB>,
-        ) -> diesel::serialize::Result {
-            out.set_value(i32::try_from(self.0)?);
-            Ok(diesel::serialize::IsNull::No)
-        }
-    }
+    bencher_valid::db_to_sql!(VersionNumber, diesel::sql_types::Integer, |value| {
+        i32::try_from(value.0)?
+    });
 
     impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for VersionNumber
     where
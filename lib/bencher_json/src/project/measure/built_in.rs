@@ -81,6 +81,27 @@ pub mod json {
     create_measure!(FileSize, "File Size", "file-size", BYTES);
 }
 
+pub mod go {
+    create_measure!(
+        BytesPerOp,
+        "Bytes per Operation",
+        "bytes-per-op",
+        "bytes / operation (B/op)"
+    );
+    create_measure!(
+        AllocsPerOp,
+        "Allocations per Operation",
+        "allocs-per-op",
+        "allocations / operation (allocs/op)"
+    );
+    create_measure!(
+        MegabytesPerSecond,
+        "Megabytes per Second",
+        "megabytes-per-second",
+        "megabytes / second (MB/s)"
+    );
+}
+
 pub mod iai {
     create_measure!(Instructions, "Instructions", "instructions", "instructions");
     create_measure!(L1Accesses, "L1 Accesses", "l1-accesses", "accesses");
@@ -94,6 +115,47 @@ pub mod iai {
     );
 }
 
+pub mod perf_stat {
+    create_measure!(TaskClock, "Task Clock", "task-clock", "milliseconds (ms)");
+    create_measure!(CpuClock, "CPU Clock", "cpu-clock", "milliseconds (ms)");
+    create_measure!(
+        ContextSwitches,
+        "Context Switches",
+        "context-switches",
+        "switches"
+    );
+    create_measure!(
+        CpuMigrations,
+        "CPU Migrations",
+        "cpu-migrations",
+        "migrations"
+    );
+    create_measure!(PageFaults, "Page Faults", "page-faults", "faults");
+    create_measure!(Cycles, "Cycles", "cycles", "cycles");
+    create_measure!(
+        StalledCyclesFrontend,
+        "Stalled Cycles Frontend",
+        "stalled-cycles-frontend",
+        "cycles"
+    );
+    create_measure!(
+        StalledCyclesBackend,
+        "Stalled Cycles Backend",
+        "stalled-cycles-backend",
+        "cycles"
+    );
+    create_measure!(Instructions, "Instructions", "instructions", "instructions");
+    create_measure!(Branches, "Branches", "branches", "branches");
+    create_measure!(BranchMisses, "Branch Misses", "branch-misses", "misses");
+    create_measure!(
+        CacheReferences,
+        "Cache References",
+        "cache-references",
+        "references"
+    );
+    create_measure!(CacheMisses, "Cache Misses", "cache-misses", "misses");
+}
+
 pub mod gungraun {
     use bencher_valid::BYTES;
 
@@ -0,0 +1,41 @@
+use bencher_valid::{DateTime, Email, Slug, UserName};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{UserUuid, organization::role::RoleUuid};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonProjectMembers(pub Vec<JsonProjectMember>);
+
+crate::from_vec!(JsonProjectMembers[JsonProjectMember]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonProjectMember {
+    /// The member UUID.
+    pub uuid: UserUuid,
+    /// The member user name.
+    pub name: UserName,
+    /// The member slug.
+    pub slug: Slug,
+    /// The member email.
+    pub email: Email,
+    /// The custom organization role assigned to the member for the project.
+    pub role: RoleUuid,
+    /// The date time the role was assigned.
+    pub created: DateTime,
+    /// The date time the role assignment was last modified.
+    pub modified: DateTime,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateProjectMember {
+    /// The custom role to assign to the member for the project.
+    /// The role must belong to the organization that owns the project.
+    pub role: RoleUuid,
+}
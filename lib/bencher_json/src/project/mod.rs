@@ -17,15 +17,21 @@ pub mod alert;
 pub mod benchmark;
 pub mod boundary;
 pub mod branch;
+pub mod change_point;
+pub mod compare;
+pub mod export;
 pub mod head;
 pub mod measure;
+pub mod member;
 pub mod metric;
 pub mod model;
 pub mod perf;
 pub mod plot;
 pub mod report;
+pub mod retention;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 crate::typed_uuid::typed_uuid!(ProjectUuid);
 crate::typed_slug::typed_slug!(ProjectSlug, ResourceName);
@@ -77,6 +83,8 @@ pub struct JsonProject {
     pub slug: ProjectSlug,
     pub url: Option<Url>,
     pub visibility: Visibility,
+    #[serde(default)]
+    pub openmetrics: bool,
     pub created: DateTime,
     pub modified: DateTime,
     pub claimed: Option<DateTime>,
@@ -122,6 +130,8 @@ pub struct JsonProjectPatch {
     /// ➕ Bencher Plus: Set the new visibility of the project.
     /// Moving to a `private` project requires a valid Bencher Plus subscription.
     pub visibility: Option<Visibility>,
+    /// Enable or disable the `OpenMetrics` endpoint for the project.
+    pub openmetrics: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -131,6 +141,7 @@ pub struct JsonProjectPatchNull {
     pub slug: Option<ProjectSlug>,
     pub url: (),
     pub visibility: Option<Visibility>,
+    pub openmetrics: Option<bool>,
 }
 
 impl<'de> Deserialize<'de> for JsonUpdateProject {
@@ -142,7 +153,14 @@ impl<'de> Deserialize<'de> for JsonUpdateProject {
         const SLUG_FIELD: &str = "slug";
         const URL_FIELD: &str = "url";
         const VISIBILITY_FIELD: &str = "visibility";
-        const FIELDS: &[&str] = &[NAME_FIELD, SLUG_FIELD, URL_FIELD, VISIBILITY_FIELD];
+        const OPENMETRICS_FIELD: &str = "openmetrics";
+        const FIELDS: &[&str] = &[
+            NAME_FIELD,
+            SLUG_FIELD,
+            URL_FIELD,
+            VISIBILITY_FIELD,
+            OPENMETRICS_FIELD,
+        ];
 
         #[derive(Deserialize)]
         #[serde(field_identifier, rename_all = "snake_case")]
@@ -151,6 +169,7 @@ impl<'de> Deserialize<'de> for JsonUpdateProject {
             Slug,
             Url,
             Visibility,
+            Openmetrics,
         }
 
         struct UpdateProjectVisitor;
@@ -170,6 +189,7 @@ impl<'de> Deserialize<'de> for JsonUpdateProject {
                 let mut slug = None;
                 let mut url = None;
                 let mut visibility = None;
+                let mut openmetrics = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -197,6 +217,12 @@ impl<'de> Deserialize<'de> for JsonUpdateProject {
                             }
                             visibility = Some(map.next_value()?);
                         },
+                        Field::Openmetrics => {
+                            if openmetrics.is_some() {
+                                return Err(de::Error::duplicate_field(OPENMETRICS_FIELD));
+                            }
+                            openmetrics = Some(map.next_value()?);
+                        },
                     }
                 }
 
@@ -206,18 +232,21 @@ impl<'de> Deserialize<'de> for JsonUpdateProject {
                         slug,
                         url: Some(url),
                         visibility,
+                        openmetrics,
                     }),
                     Some(None) => Self::Value::Null(JsonProjectPatchNull {
                         name,
                         slug,
                         url: (),
                         visibility,
+                        openmetrics,
                     }),
                     None => Self::Value::Patch(JsonProjectPatch {
                         name,
                         slug,
                         url: None,
                         visibility,
+                        openmetrics,
                     }),
                 })
             }
@@ -320,6 +349,8 @@ pub enum ProjectPermission {
     Delete,
     #[display("manage")]
     Manage,
+    #[display("create_report")]
+    CreateReport,
     #[display("view_role")]
     ViewRole,
     #[display("create_role")]
@@ -386,17 +417,15 @@ mod organization_role {
     impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for ProjectRole
     where
         DB: diesel::backend::Backend,
-        for<'a> String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+        str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
     {
         fn to_sql<'b>(
             &'b self,
             out: &mut diesel::serialize::Output<'b, '_, DB>,
         ) -> diesel::serialize::Result {
             match self {
-                Self::Maintainer => out.set_value(MAINTAINER_ROLE.to_owned()),
+                Self::Maintainer => MAINTAINER_ROLE.to_sql(out),
             }
-            Ok(diesel::serialize::IsNull::No)
         }
     }
 
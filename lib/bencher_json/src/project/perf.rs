@@ -1,3 +1,6 @@
+use std::fmt;
+
+use ordered_float::OrderedFloat;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::ser::{self, SerializeStruct as _};
@@ -5,12 +8,12 @@ use serde::{Deserialize, Serialize, Serializer};
 use url::Url;
 
 use crate::urlencoded::{
-    UrlEncodedError, from_urlencoded_list, from_urlencoded_nullable_list, to_urlencoded,
-    to_urlencoded_list, to_urlencoded_optional_list,
+    UrlEncodedError, from_urlencoded, from_urlencoded_list, from_urlencoded_nullable_list,
+    to_urlencoded, to_urlencoded_list, to_urlencoded_optional_list,
 };
 use crate::{
     BenchmarkUuid, BranchUuid, DateTime, DateTimeMillis, HeadUuid, JsonBenchmark, JsonBranch,
-    JsonMeasure, JsonProject, JsonTestbed, MeasureUuid, ReportUuid, TestbedUuid,
+    JsonMeasure, JsonProject, JsonTestbed, MeasureUuid, ReportUuid, TagSelectors, TestbedUuid,
 };
 
 use super::alert::JsonPerfAlert;
@@ -38,13 +41,24 @@ pub struct JsonPerfQueryParams {
     /// A comma separated list of testbed UUIDs to query.
     pub testbeds: String,
     /// A comma separated list of benchmark UUIDs to query.
+    /// This may be empty if `tags` is provided.
     pub benchmarks: String,
+    /// An optional comma separated list of `key=value` benchmark tag selectors.
+    /// All benchmarks that match every selector are added to the query.
+    pub tags: Option<String>,
     /// A comma separated list of measure UUIDs to query.
     pub measures: String,
     /// Search for metrics after the given date time in milliseconds.
     pub start_time: Option<DateTimeMillis>,
     /// Search for metrics before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// Downsample the metrics into buckets of the given size.
+    /// If not provided, every metric is returned.
+    pub bucket: Option<PerfBucket>,
+    /// The aggregation used for each bucket.
+    /// If not provided, the mean is used.
+    /// This is ignored if `bucket` is not provided.
+    pub aggregate: Option<PerfAggregate>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -61,13 +75,166 @@ pub struct JsonPerfImgQueryParams {
     /// A comma separated list of testbed UUIDs to query.
     pub testbeds: String,
     /// A comma separated list of benchmark UUIDs to query.
+    /// This may be empty if `tags` is provided.
     pub benchmarks: String,
+    /// An optional comma separated list of `key=value` benchmark tag selectors.
+    /// All benchmarks that match every selector are added to the query.
+    pub tags: Option<String>,
     /// A comma separated list of measure UUIDs to query.
     pub measures: String,
     /// Search for metrics after the given date time in milliseconds.
     pub start_time: Option<DateTimeMillis>,
     /// Search for metrics before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// Downsample the metrics into buckets of the given size.
+    /// If not provided, a bucket size will be chosen for long time windows.
+    pub bucket: Option<PerfBucket>,
+    /// The aggregation used for each bucket.
+    /// If not provided, the mean is used.
+    pub aggregate: Option<PerfAggregate>,
+    /// The image format.
+    /// If not provided, a PNG image will be generated.
+    pub format: Option<PerfImgFormat>,
+    /// The image width in pixels.
+    /// If not provided, the image will be 1024 pixels wide.
+    pub width: Option<u32>,
+    /// The image height in pixels.
+    /// If not provided, the image will be 768 pixels tall.
+    pub height: Option<u32>,
+    /// The color theme for the image.
+    /// If not provided, the light theme will be used.
+    pub theme: Option<PerfImgTheme>,
+    /// Use a log scale for the y-axis.
+    /// If not provided, a log scale will be used when the data span at least an order of magnitude.
+    pub log_scale: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfExportQueryParams {
+    /// A comma separated list of branch UUIDs to query.
+    pub branches: String,
+    /// An optional comma separated list of branch head UUIDs.
+    /// To not specify a particular branch head leave an empty entry in the list.
+    pub heads: Option<String>,
+    /// A comma separated list of testbed UUIDs to query.
+    pub testbeds: String,
+    /// A comma separated list of benchmark UUIDs to query.
+    /// This may be empty if `tags` is provided.
+    pub benchmarks: String,
+    /// An optional comma separated list of `key=value` benchmark tag selectors.
+    /// All benchmarks that match every selector are added to the query.
+    pub tags: Option<String>,
+    /// A comma separated list of measure UUIDs to query.
+    pub measures: String,
+    /// Search for metrics after the given date time in milliseconds.
+    pub start_time: Option<DateTimeMillis>,
+    /// Search for metrics before the given date time in milliseconds.
+    pub end_time: Option<DateTimeMillis>,
+    /// Downsample the metrics into buckets of the given size.
+    /// If not provided, every metric is exported.
+    pub bucket: Option<PerfBucket>,
+    /// The aggregation used for each bucket.
+    /// If not provided, the mean is used.
+    /// This is ignored if `bucket` is not provided.
+    pub aggregate: Option<PerfAggregate>,
+    /// The export file format.
+    /// If not provided, CSV will be used.
+    pub format: Option<PerfExportFormat>,
+}
+
+impl From<JsonPerfExportQueryParams> for JsonPerfQueryParams {
+    fn from(query: JsonPerfExportQueryParams) -> Self {
+        let JsonPerfExportQueryParams {
+            branches,
+            heads,
+            testbeds,
+            benchmarks,
+            tags,
+            measures,
+            start_time,
+            end_time,
+            bucket,
+            aggregate,
+            format: _,
+        } = query;
+        Self {
+            branches,
+            heads,
+            testbeds,
+            benchmarks,
+            tags,
+            measures,
+            start_time,
+            end_time,
+            bucket,
+            aggregate,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfExportFormat {
+    /// Comma separated values.
+    #[default]
+    Csv,
+    /// Apache Parquet.
+    Parquet,
+}
+
+impl PerfExportFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfImgFormat {
+    /// A JPEG raster image.
+    Jpeg,
+    /// A PNG raster image.
+    #[default]
+    Png,
+    /// An SVG vector image.
+    Svg,
+}
+
+impl PerfImgFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::Svg => "image/svg+xml",
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfImgTheme {
+    /// Dark text on a light background.
+    #[default]
+    Light,
+    /// Light text on a dark background.
+    Dark,
 }
 
 impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
@@ -78,18 +245,106 @@ impl From<JsonPerfImgQueryParams> for JsonPerfQueryParams {
             heads,
             testbeds,
             benchmarks,
+            tags,
             measures,
             start_time,
             end_time,
+            bucket,
+            aggregate,
+            format: _,
+            width: _,
+            height: _,
+            theme: _,
+            log_scale: _,
         } = query;
         Self {
             branches,
             heads,
             testbeds,
             benchmarks,
+            tags,
             measures,
             start_time,
             end_time,
+            bucket,
+            aggregate,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfBucket {
+    /// One bucket per hour, by report start time.
+    Hour,
+    /// One bucket per day, by report start time.
+    Day,
+    /// One bucket per week starting on Monday, by report start time.
+    Week,
+    /// One bucket per branch version.
+    Version,
+}
+
+impl PerfBucket {
+    // Time windows longer than these are automatically downsampled.
+    const AUTO_DAY_SECONDS: i64 = 90 * 24 * 60 * 60;
+    const AUTO_WEEK_SECONDS: i64 = 2 * 365 * 24 * 60 * 60;
+
+    /// Choose a bucket size for a time window, if it is long enough to need one.
+    /// Unbounded time windows are never automatically downsampled.
+    pub fn auto(start_time: Option<DateTime>, end_time: Option<DateTime>) -> Option<Self> {
+        let start_time = start_time?;
+        let end_time = end_time.unwrap_or_else(DateTime::now);
+        let window = end_time.timestamp() - start_time.timestamp();
+        if window > Self::AUTO_WEEK_SECONDS {
+            Some(Self::Week)
+        } else if window > Self::AUTO_DAY_SECONDS {
+            Some(Self::Day)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for PerfBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hour => write!(f, "hour"),
+            Self::Day => write!(f, "day"),
+            Self::Week => write!(f, "week"),
+            Self::Version => write!(f, "version"),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PerfAggregate {
+    /// The mean of the metric values in each bucket.
+    #[default]
+    Mean,
+    /// The median of the metric values in each bucket.
+    Median,
+    /// The minimum metric value in each bucket.
+    Min,
+    /// The maximum metric value in each bucket.
+    Max,
+    /// The 90th percentile of the metric values in each bucket.
+    P90,
+}
+
+impl fmt::Display for PerfAggregate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mean => write!(f, "mean"),
+            Self::Median => write!(f, "median"),
+            Self::Min => write!(f, "min"),
+            Self::Max => write!(f, "max"),
+            Self::P90 => write!(f, "p90"),
         }
     }
 }
@@ -103,9 +358,12 @@ pub struct JsonPerfQuery {
     pub heads: Vec<Option<HeadUuid>>,
     pub testbeds: Vec<TestbedUuid>,
     pub benchmarks: Vec<BenchmarkUuid>,
+    pub tags: Option<TagSelectors>,
     pub measures: Vec<MeasureUuid>,
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
+    pub bucket: Option<PerfBucket>,
+    pub aggregate: Option<PerfAggregate>,
 }
 
 impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
@@ -117,9 +375,12 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
             heads,
             testbeds,
             benchmarks,
+            tags,
             measures,
             start_time,
             end_time,
+            bucket,
+            aggregate,
         } = query_params;
 
         if branches.is_empty() {
@@ -128,7 +389,13 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
         if testbeds.is_empty() {
             return Err(UrlEncodedError::EmptyTestbeds);
         }
-        if benchmarks.is_empty() {
+        // The benchmarks may be selected by their tags instead.
+        let tags = if let Some(tags) = tags.filter(|tags| !tags.is_empty()) {
+            Some(from_urlencoded(&tags)?)
+        } else {
+            None
+        };
+        if benchmarks.is_empty() && tags.is_none() {
             return Err(UrlEncodedError::EmptyBenchmarks);
         }
         if measures.is_empty() {
@@ -138,7 +405,11 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
         let branches = from_urlencoded_list(&branches)?;
         let heads = from_urlencoded_nullable_list(heads.as_deref())?;
         let testbeds = from_urlencoded_list(&testbeds)?;
-        let benchmarks = from_urlencoded_list(&benchmarks)?;
+        let benchmarks = if benchmarks.is_empty() {
+            Vec::new()
+        } else {
+            from_urlencoded_list(&benchmarks)?
+        };
         let measures = from_urlencoded_list(&measures)?;
 
         // Guarantee that the `heads` array is the same length as the `branches` array.
@@ -149,9 +420,12 @@ impl TryFrom<JsonPerfQueryParams> for JsonPerfQuery {
             heads,
             testbeds,
             benchmarks,
+            tags,
             measures,
             start_time: start_time.map(Into::into),
             end_time: end_time.map(Into::into),
+            bucket,
+            aggregate,
         })
     }
 }
@@ -210,7 +484,7 @@ impl JsonPerfQuery {
         serde_urlencoded::to_string(query).map_err(Into::into)
     }
 
-    fn urlencoded(&self) -> Result<[(&'static str, Option<String>); 7], UrlEncodedError> {
+    fn urlencoded(&self) -> Result<[(&'static str, Option<String>); 10], UrlEncodedError> {
         QUERY_KEYS
             .into_iter()
             .zip([
@@ -218,9 +492,12 @@ impl JsonPerfQuery {
                 self.heads(),
                 Some(self.testbeds()),
                 Some(self.benchmarks()),
+                self.tags(),
                 Some(self.measures()),
                 self.start_time_str(),
                 self.end_time_str(),
+                self.bucket_str(),
+                self.aggregate_str(),
             ])
             .collect::<Vec<_>>()
             .try_into()
@@ -247,6 +524,10 @@ impl JsonPerfQuery {
         to_urlencoded_list(&self.benchmarks)
     }
 
+    pub fn tags(&self) -> Option<String> {
+        self.tags.as_ref().map(to_urlencoded)
+    }
+
     pub fn measures(&self) -> String {
         to_urlencoded_list(&self.measures)
     }
@@ -266,6 +547,14 @@ impl JsonPerfQuery {
     fn end_time_str(&self) -> Option<String> {
         self.end_time().as_ref().map(to_urlencoded)
     }
+
+    fn bucket_str(&self) -> Option<String> {
+        self.bucket.as_ref().map(to_urlencoded)
+    }
+
+    fn aggregate_str(&self) -> Option<String> {
+        self.aggregate.as_ref().map(to_urlencoded)
+    }
 }
 
 #[typeshare::typeshare]
@@ -277,20 +566,26 @@ pub enum PerfQueryKey {
     Heads,
     Testbeds,
     Benchmarks,
+    Tags,
     Measures,
     StartTime,
     EndTime,
+    Bucket,
+    Aggregate,
 }
 
 pub const BRANCHES: &str = "branches";
 pub const HEADS: &str = "heads";
 pub const TESTBEDS: &str = "testbeds";
 pub const BENCHMARKS: &str = "benchmarks";
+pub const TAGS: &str = "tags";
 pub const MEASURES: &str = "measures";
 pub const START_TIME: &str = "start_time";
 pub const END_TIME: &str = "end_time";
-const QUERY_KEYS: [&str; 7] = [
-    BRANCHES, HEADS, TESTBEDS, BENCHMARKS, MEASURES, START_TIME, END_TIME,
+pub const BUCKET: &str = "bucket";
+pub const AGGREGATE: &str = "aggregate";
+const QUERY_KEYS: [&str; 10] = [
+    BRANCHES, HEADS, TESTBEDS, BENCHMARKS, TAGS, MEASURES, START_TIME, END_TIME, BUCKET, AGGREGATE,
 ];
 
 #[typeshare::typeshare]
@@ -300,6 +595,8 @@ pub struct JsonPerf {
     pub project: JsonProject,
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
+    pub bucket: Option<PerfBucket>,
+    pub aggregate: Option<PerfAggregate>,
     pub results: Vec<JsonPerfMetrics>,
 }
 
@@ -328,6 +625,24 @@ pub struct JsonPerfMetric {
     pub threshold: Option<JsonThresholdModel>,
     pub boundary: Option<JsonBoundary>,
     pub alert: Option<JsonPerfAlert>,
+    // Only set when the metrics are downsampled into buckets
+    pub bucket: Option<JsonPerfBucket>,
+}
+
+/// The envelope of the metrics that were aggregated into a single bucket.
+/// The other fields of the perf metric are from the latest metric in the bucket.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonPerfBucket {
+    /// The number of metrics in the bucket.
+    pub count: u32,
+    /// The start time of the earliest report in the bucket.
+    pub start_time: DateTime,
+    /// The minimum metric value in the bucket.
+    pub min: OrderedFloat<f64>,
+    /// The maximum metric value in the bucket.
+    pub max: OrderedFloat<f64>,
 }
 
 #[cfg(feature = "table")]
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use bencher_valid::{DateTime, Index, ResourceName, Window};
+use bencher_valid::{DateTime, Index, ResourceName, TagSelectors, Window};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{
@@ -43,8 +43,12 @@ pub struct JsonNewPlot {
     /// At least one testbed must be specified.
     pub testbeds: Vec<TestbedUuid>,
     /// The benchmarks to include in the plot.
-    /// At least one benchmark must be specified.
+    /// At least one benchmark must be specified, unless `benchmark_tags` is provided.
     pub benchmarks: Vec<BenchmarkUuid>,
+    /// An optional comma separated list of `key=value` benchmark tag selectors.
+    /// All benchmarks that match every selector are included in the plot,
+    /// including benchmarks that are created after the plot.
+    pub benchmark_tags: Option<TagSelectors>,
     /// The measures to include in the plot.
     /// At least one measure must be specified.
     pub measures: Vec<MeasureUuid>,
@@ -73,6 +77,7 @@ pub struct JsonPlot {
     pub branches: Vec<BranchUuid>,
     pub testbeds: Vec<TestbedUuid>,
     pub benchmarks: Vec<BenchmarkUuid>,
+    pub benchmark_tags: Option<TagSelectors>,
     pub measures: Vec<MeasureUuid>,
     pub created: DateTime,
     pub modified: DateTime,
@@ -14,6 +14,7 @@ use crate::{
 use super::{branch::JsonUpdateStartPoint, threshold::JsonThresholdModel};
 
 crate::typed_uuid::typed_uuid!(ReportUuid);
+crate::typed_uuid::typed_uuid!(ReportGroupUuid);
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -51,6 +52,10 @@ pub struct JsonNewReport {
     pub results: Vec<String>,
     /// Settings for how to handle the report.
     pub settings: Option<JsonReportSettings>,
+    /// Report group UUID.
+    /// Reports that share a group UUID were generated together,
+    /// such as the variants of a `bencher run --matrix` across multiple testbeds.
+    pub group: Option<ReportGroupUuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,7 +71,7 @@ pub struct JsonReportThresholds {
     pub reset: Option<bool>,
 }
 
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonReportSettings {
     /// The benchmark harness adapter for parsing the benchmark results.
@@ -78,10 +83,42 @@ pub struct JsonReportSettings {
     /// Fold multiple results into a single result using the selected operation.
     /// This can be useful for taking the min, max, mean, or median of the benchmark results.
     pub fold: Option<JsonFold>,
+    /// The specification for parsing the benchmark results with the `custom` adapter.
+    /// Required if the adapter is `custom` and ignored otherwise.
+    pub adapter_spec: Option<JsonAdapterSpec>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAdapterSpec {
+    /// A regular expression that is applied to each line of the benchmark results.
+    /// It must have a `name` named capture group for the benchmark name
+    /// and a `value` named capture group for the metric value.
+    /// It may also have `lower_value`, `upper_value`, and `unit` named capture groups.
+    /// Lines that do not match are ignored.
+    pub regex: String,
+    /// The measure for metrics without a `unit` that is in `units`.
+    /// If no measure is specified, then `latency` will be used.
+    pub measure: Option<MeasureNameId>,
+    /// A map from the captured `unit` to its measure and scale.
+    pub units: Option<HashMap<String, JsonAdapterSpecUnit>>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonAdapterSpecUnit {
+    /// The measure for metrics with this unit.
+    pub measure: MeasureNameId,
+    /// The factor to multiply the metric value, lower value, and upper value by.
+    /// For example, use `1000000` to convert from milliseconds to nanoseconds.
+    pub scale: Option<f64>,
 }
 
 const MAGIC_INT: i32 = 0;
 const JSON_INT: i32 = 10;
+const CUSTOM_INT: i32 = 11;
 const RUST_INT: i32 = 20;
 const RUST_BENCH_INT: i32 = 21;
 const RUST_CRITERION_INT: i32 = 22;
@@ -106,6 +143,7 @@ const RUBY_INT: i32 = 90;
 const RUBY_BENCHMARK_INT: i32 = 91;
 const SHELL_INT: i32 = 100;
 const SHELL_HYPERFINE_INT: i32 = 101;
+const SHELL_PERF_STAT_INT: i32 = 102;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
@@ -118,6 +156,7 @@ pub enum Adapter {
     #[default]
     Magic = MAGIC_INT,
     Json = JSON_INT,
+    Custom = CUSTOM_INT,
     Rust = RUST_INT,
     RustBench = RUST_BENCH_INT,
     RustCriterion = RUST_CRITERION_INT,
@@ -145,6 +184,7 @@ pub enum Adapter {
     RubyBenchmark = RUBY_BENCHMARK_INT,
     Shell = SHELL_INT,
     ShellHyperfine = SHELL_HYPERFINE_INT,
+    ShellPerfStat = SHELL_PERF_STAT_INT,
 }
 
 impl fmt::Display for Adapter {
@@ -152,6 +192,7 @@ impl fmt::Display for Adapter {
         match self {
             Self::Magic => write!(f, "magic"),
             Self::Json => write!(f, "json"),
+            Self::Custom => write!(f, "custom"),
             Self::Rust => write!(f, "rust"),
             Self::RustBench => write!(f, "rust_bench"),
             Self::RustCriterion => write!(f, "rust_criterion"),
@@ -176,6 +217,7 @@ impl fmt::Display for Adapter {
             Self::RubyBenchmark => write!(f, "ruby_benchmark"),
             Self::Shell => write!(f, "shell"),
             Self::ShellHyperfine => write!(f, "shell_hyperfine"),
+            Self::ShellPerfStat => write!(f, "shell_perf_stat"),
         }
     }
 }
@@ -184,10 +226,10 @@ impl fmt::Display for Adapter {
 mod adapter {
     use super::{
         Adapter, C_SHARP_DOT_NET_INT, C_SHARP_INT, CPP_CATCH2_INT, CPP_GOOGLE_INT, CPP_INT,
-        GO_BENCH_INT, GO_INT, JAVA_INT, JAVA_JMH_INT, JS_BENCHMARK_INT, JS_INT, JS_TIME_INT,
-        JSON_INT, MAGIC_INT, PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYTEST_INT, RUBY_BENCHMARK_INT,
-        RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_GUNGRAUN_INT, RUST_IAI_INT, RUST_INT,
-        SHELL_HYPERFINE_INT, SHELL_INT,
+        CUSTOM_INT, GO_BENCH_INT, GO_INT, JAVA_INT, JAVA_JMH_INT, JS_BENCHMARK_INT, JS_INT,
+        JS_TIME_INT, JSON_INT, MAGIC_INT, PYTHON_ASV_INT, PYTHON_INT, PYTHON_PYTEST_INT,
+        RUBY_BENCHMARK_INT, RUBY_INT, RUST_BENCH_INT, RUST_CRITERION_INT, RUST_GUNGRAUN_INT,
+        RUST_IAI_INT, RUST_INT, SHELL_HYPERFINE_INT, SHELL_INT, SHELL_PERF_STAT_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -208,6 +250,7 @@ mod adapter {
             match self {
                 Self::Magic => MAGIC_INT.to_sql(out),
                 Self::Json => JSON_INT.to_sql(out),
+                Self::Custom => CUSTOM_INT.to_sql(out),
                 Self::Rust => RUST_INT.to_sql(out),
                 Self::RustBench => RUST_BENCH_INT.to_sql(out),
                 Self::RustCriterion => RUST_CRITERION_INT.to_sql(out),
@@ -232,6 +275,7 @@ mod adapter {
                 Self::RubyBenchmark => RUBY_BENCHMARK_INT.to_sql(out),
                 Self::Shell => SHELL_INT.to_sql(out),
                 Self::ShellHyperfine => SHELL_HYPERFINE_INT.to_sql(out),
+                Self::ShellPerfStat => SHELL_PERF_STAT_INT.to_sql(out),
             }
         }
     }
@@ -245,6 +289,7 @@ mod adapter {
             match i32::from_sql(bytes)? {
                 MAGIC_INT => Ok(Self::Magic),
                 JSON_INT => Ok(Self::Json),
+                CUSTOM_INT => Ok(Self::Custom),
                 RUST_INT => Ok(Self::Rust),
                 RUST_BENCH_INT => Ok(Self::RustBench),
                 RUST_CRITERION_INT => Ok(Self::RustCriterion),
@@ -269,6 +314,7 @@ mod adapter {
                 RUBY_BENCHMARK_INT => Ok(Self::RubyBenchmark),
                 SHELL_INT => Ok(Self::Shell),
                 SHELL_HYPERFINE_INT => Ok(Self::ShellHyperfine),
+                SHELL_PERF_STAT_INT => Ok(Self::ShellPerfStat),
                 value => Err(Box::new(AdapterError::Invalid(value))),
             }
         }
@@ -314,6 +360,7 @@ pub struct JsonReport {
     pub adapter: Adapter,
     pub results: JsonReportResults,
     pub alerts: JsonReportAlerts,
+    pub group: Option<ReportGroupUuid>,
     pub created: DateTime,
 }
 
@@ -356,6 +403,8 @@ pub struct JsonReportQueryParams {
     pub start_time: Option<DateTimeMillis>,
     /// Filter for reports before the given date time in milliseconds.
     pub end_time: Option<DateTimeMillis>,
+    /// Filter by report group UUID exact match.
+    pub group: Option<ReportGroupUuid>,
     /// If set to `true`, only return reports with an archived branch or testbed.
     /// If not set or set to `false`, only returns reports with non-archived branches and testbeds.
     pub archived: Option<bool>,
@@ -367,6 +416,7 @@ pub struct JsonReportQuery {
     pub testbed: Option<TestbedNameId>,
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
+    pub group: Option<ReportGroupUuid>,
     pub archived: Option<bool>,
 }
 
@@ -379,6 +429,7 @@ impl TryFrom<JsonReportQueryParams> for JsonReportQuery {
             testbed,
             start_time,
             end_time,
+            group,
             archived,
         } = query_params;
 
@@ -398,6 +449,7 @@ impl TryFrom<JsonReportQueryParams> for JsonReportQuery {
             testbed,
             start_time: start_time.map(Into::into),
             end_time: end_time.map(Into::into),
+            group,
             archived,
         })
     }
@@ -446,20 +498,9 @@ mod iteration {
         }
     }
 
-    impl<DB> diesel::serialize::ToSql<diesel::sql_types::Integer, DB> for Iteration
-    where
-        DB: diesel::backend::Backend,
-        for<'a> i32: diesel::serialize::ToSql<diesel::sql_types::Integer, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
-    {
-        fn to_sql<'b>(
-            &'b self,
-            out: &mut diesel::serialize::Output<'b, '_, DB>,
-        ) -> diesel::serialize::Result {
-            out.set_value(i32::try_from(self.0)?);
-            Ok(diesel::serialize::IsNull::No)
-        }
-    }
+    bencher_valid::db_to_sql!(Iteration, diesel::sql_types::Integer, |value| {
+        i32::try_from(value.0)?
+    });
 
     impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Integer, DB> for Iteration
     where
@@ -0,0 +1,50 @@
+use std::num::NonZeroU32;
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::ProjectUuid;
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateRetention {
+    /// The number of days to keep reports, by report start time.
+    /// Reports for a version that is used as a branch start point
+    /// or that has an active alert are always kept.
+    /// If not provided, reports are kept forever.
+    pub reports: Option<NonZeroU32>,
+    /// The number of days to keep archived branches, by archive time.
+    /// An archived branch is deleted along with all of its reports and thresholds.
+    /// Archived branches that are used as a start point by another branch
+    /// or that have an active alert are always kept.
+    /// If not provided, archived branches are kept forever.
+    pub archived_branches: Option<NonZeroU32>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRetention {
+    pub project: ProjectUuid,
+    /// The number of days to keep reports.
+    pub reports: Option<NonZeroU32>,
+    /// The number of days to keep archived branches.
+    pub archived_branches: Option<NonZeroU32>,
+}
+
+/// The data that was or would be deleted by a retention policy.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonRetentionPrune {
+    /// The number of archived branches.
+    pub branches: u32,
+    /// The number of reports, including those for archived branches.
+    pub reports: u32,
+    /// The number of metrics for those reports.
+    pub metrics: u32,
+    /// The number of versions that no longer have any reports.
+    pub versions: u32,
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use bencher_valid::{DateTime, Model};
+use bencher_valid::{DateTime, Model, TagSelectors};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{
@@ -25,6 +25,10 @@ pub struct JsonNewThreshold {
     pub testbed: TestbedNameId,
     /// The UUID, slug, or name of the threshold measure.
     pub measure: MeasureNameId,
+    /// An optional comma separated list of `key=value` benchmark tag selectors.
+    /// If set, the threshold only applies to benchmarks that match every selector.
+    /// Benchmarks that do not match fall back to the threshold without benchmark tags.
+    pub benchmark_tags: Option<TagSelectors>,
     #[serde(flatten)]
     pub model: Model,
 }
@@ -44,6 +48,7 @@ pub struct JsonThreshold {
     pub branch: JsonBranch,
     pub testbed: JsonTestbed,
     pub measure: JsonMeasure,
+    pub benchmark_tags: Option<TagSelectors>,
     pub model: Option<JsonModel>,
     pub created: DateTime,
     pub modified: DateTime,
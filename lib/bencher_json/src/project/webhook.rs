@@ -0,0 +1,68 @@
+use bencher_valid::{DateTime, Secret, Url};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{JsonAlert, ProjectUuid};
+
+crate::typed_uuid::typed_uuid!(WebhookUuid);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonNewWebhook {
+    /// The URL to send webhook events to.
+    pub url: Url,
+    /// The secret used to sign webhook events.
+    /// The HMAC-SHA256 signature of the request body is sent in the `X-Bencher-Signature` header,
+    /// formatted as `sha256=<hex digest>`.
+    pub secret: Secret,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonUpdateWebhook {
+    /// The new URL to send webhook events to.
+    pub url: Option<Url>,
+    /// The new secret used to sign webhook events.
+    pub secret: Option<Secret>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhooks(pub Vec<JsonWebhook>);
+
+crate::from_vec!(JsonWebhooks[JsonWebhook]);
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhook {
+    pub uuid: WebhookUuid,
+    pub project: ProjectUuid,
+    pub url: Url,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+/// The payload sent to a webhook URL.
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonWebhookEvent {
+    pub event: WebhookEvent,
+    pub alert: JsonAlert,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, derive_more::Display, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// An alert was raised by a threshold.
+    #[display("alert_created")]
+    AlertCreated,
+    /// The status of an alert was changed.
+    #[display("alert_updated")]
+    AlertUpdated,
+}
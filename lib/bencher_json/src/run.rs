@@ -8,7 +8,7 @@ use crate::{
     BranchNameId, ProjectResourceId, TestbedNameId,
     project::{
         branch::JsonUpdateStartPoint,
-        report::{JsonReportSettings, JsonReportThresholds},
+        report::{JsonReportSettings, JsonReportThresholds, ReportGroupUuid},
     },
 };
 
@@ -59,6 +59,10 @@ pub struct JsonNewRun {
     pub settings: Option<JsonReportSettings>,
     /// Context for the report.
     pub context: Option<RunContext>,
+    /// Report group UUID.
+    /// Reports that share a group UUID were generated together,
+    /// such as the variants of a `bencher run --matrix` across multiple testbeds.
+    pub group: Option<ReportGroupUuid>,
 }
 
 #[cfg(feature = "server")]
@@ -76,6 +80,7 @@ impl From<JsonNewRun> for JsonNewReport {
             results,
             settings,
             context,
+            group,
         } = run;
         let branch = branch
             .or_else(|| {
@@ -109,6 +114,7 @@ impl From<JsonNewRun> for JsonNewReport {
             end_time,
             results,
             settings,
+            group,
         }
     }
 }
@@ -9,12 +9,17 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct JsonDatabase {
     pub file: PathBuf,
+    /// A Postgres connection URL.
+    /// If set, then Postgres is used instead of the `file` database.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Secret>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data_store: Option<DataStore>,
 }
 
 impl Sanitize for JsonDatabase {
     fn sanitize(&mut self) {
+        self.url.sanitize();
         self.data_store.sanitize();
     }
 }
@@ -16,7 +16,7 @@ pub use database::{DataStore, JsonDatabase};
 pub use logging::{IfExists, JsonLogging, LogLevel, ServerLog};
 #[cfg(feature = "plus")]
 pub use plus::{
-    JsonGitHub, JsonGoogle, JsonPlus,
+    JsonGitHub, JsonGoogle, JsonOidc, JsonOidcClaims, JsonPlus,
     cloud::{
         JsonCloud,
         billing::{JsonBilling, JsonProduct, JsonProducts},
@@ -9,6 +9,7 @@ pub mod cloud;
 pub mod github;
 pub mod google;
 pub mod litestream;
+pub mod oidc;
 pub mod rate_limiting;
 pub mod stats;
 
@@ -16,6 +17,7 @@ pub use cloud::JsonCloud;
 pub use github::JsonGitHub;
 pub use google::JsonGoogle;
 pub use litestream::JsonLitestream;
+pub use oidc::{JsonOidc, JsonOidcClaims};
 pub use rate_limiting::JsonRateLimiting;
 pub use stats::JsonStats;
 
@@ -28,6 +30,8 @@ pub struct JsonPlus {
     pub github: Option<JsonGitHub>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub google: Option<JsonGoogle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc: Option<JsonOidc>,
     #[serde(alias = "disaster_recovery", skip_serializing_if = "Option::is_none")]
     pub litestream: Option<JsonLitestream>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -39,6 +43,7 @@ pub struct JsonPlus {
 impl Sanitize for JsonPlus {
     fn sanitize(&mut self) {
         self.github.sanitize();
+        self.oidc.sanitize();
         self.litestream.sanitize();
         self.cloud.sanitize();
     }
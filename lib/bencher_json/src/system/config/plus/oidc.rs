@@ -0,0 +1,39 @@
+use bencher_valid::{NonEmpty, Sanitize, Secret, Url};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonOidc {
+    /// OIDC issuer URL, used for discovery.
+    /// For example: `https://example.okta.com` or `https://keycloak.example.com/realms/example`
+    pub issuer: Url,
+    pub client_id: NonEmpty,
+    pub client_secret: Secret,
+    /// Additional scopes to request, besides `openid`, `email`, and `profile`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<NonEmpty>>,
+    /// Claim name mappings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claims: Option<JsonOidcClaims>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonOidcClaims {
+    /// The email claim (default: `email`)
+    pub email: Option<NonEmpty>,
+    /// The boolean email verified claim (default: `email_verified`)
+    /// The email must be verified in order to log in.
+    pub email_verified: Option<NonEmpty>,
+    /// The user name claim (default: `name`)
+    /// If missing, then `preferred_username` and then the email local-part are used.
+    pub name: Option<NonEmpty>,
+}
+
+impl Sanitize for JsonOidc {
+    fn sanitize(&mut self) {
+        self.client_secret.sanitize();
+    }
+}
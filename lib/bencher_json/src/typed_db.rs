@@ -1,25 +1,30 @@
 // https://github.com/diesel-rs/diesel/blob/master/diesel_tests/tests/custom_types.rs
 macro_rules! typed_db {
     ($name:ident) => {
+        #[cfg(feature = "db")]
+        bencher_valid::db_to_sql!($name, diesel::sql_types::Text, |value| value.to_string());
+
+        $crate::typed_db::typed_db!(@from_sql $name);
+    };
+    // The inner value is already stored as-is, so it can be bound by reference.
+    ($name:ident, inner) => {
         #[cfg(feature = "db")]
         impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for $name
         where
             DB: diesel::backend::Backend,
-            for<'a> String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>
-                + Into<
-                    <DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer,
-                >,
+            $crate::Slug: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
         {
             fn to_sql<'b>(
                 &'b self,
                 out: &mut diesel::serialize::Output<'b, '_, DB>,
             ) -> diesel::serialize::Result {
-                // https://docs.rs/diesel/latest/diesel/serialize/trait.ToSql.html#examples
-                out.set_value(self.to_string());
-                Ok(diesel::serialize::IsNull::No)
+                self.0.to_sql(out)
             }
         }
 
+        $crate::typed_db::typed_db!(@from_sql $name);
+    };
+    (@from_sql $name:ident) => {
         #[cfg(feature = "db")]
         impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for $name
         where
@@ -56,7 +56,7 @@ macro_rules! typed_slug {
             }
         }
 
-        $crate::typed_db::typed_db!($slug);
+        $crate::typed_db::typed_db!($slug, inner);
     };
     ($slug:ident, $name:ident) => {
         $crate::typed_slug::typed_slug!($slug);
@@ -3,7 +3,7 @@ use bencher_valid::{DateTime, Jwt, ResourceName};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::UserUuid;
+use crate::{OrganizationUuid, ProjectUuid, UserUuid};
 
 crate::typed_uuid::typed_uuid!(TokenUuid);
 
@@ -17,6 +17,41 @@ pub struct JsonNewToken {
     /// The time-to-live (TTL) for the token in seconds.
     /// If not provided, the token will not expire for over 128 years.
     pub ttl: Option<u32>,
+    /// Restrict what the token is allowed to access.
+    /// If not provided, the token has all of the permissions of its user.
+    pub scope: Option<JsonTokenScope>,
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct JsonTokenScope {
+    /// Only allow access to these organizations and their projects.
+    /// If not provided, the token is not restricted to any organizations.
+    pub organizations: Option<Vec<OrganizationUuid>>,
+    /// Only allow access to these projects.
+    /// If not provided, the token is not restricted to any projects.
+    pub projects: Option<Vec<ProjectUuid>>,
+    /// The most permissive action that the token is allowed to take.
+    /// If not provided, the token is not restricted to any permission.
+    pub permission: Option<TokenPermission>,
+}
+
+impl JsonTokenScope {
+    pub fn is_unrestricted(&self) -> bool {
+        self.organizations.is_none() && self.projects.is_none() && self.permission.is_none()
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TokenPermission {
+    /// Read-only access
+    Read,
+    /// Read access and the ability to create reports
+    Report,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +70,7 @@ pub struct JsonToken {
     pub token: Jwt,
     pub creation: DateTime,
     pub expiration: DateTime,
+    pub scope: Option<JsonTokenScope>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
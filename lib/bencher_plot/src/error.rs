@@ -1,13 +1,26 @@
 use plotters::{prelude::DrawingAreaErrorKind, style::FontError};
-use plotters_bitmap::BitMapBackendError;
 use thiserror::Error;
 
+use crate::LinePlot;
+
 #[derive(Debug, Error)]
 pub enum PlotError {
     #[error("Failed to cast integer: {0}")]
     IntError(#[from] std::num::TryFromIntError),
+    #[error(
+        "Invalid image width ({0}), must be between {min} and {max} pixels",
+        min = LinePlot::MIN_WIDTH,
+        max = LinePlot::MAX_WIDTH
+    )]
+    Width(u32),
+    #[error(
+        "Invalid image height ({0}), must be between {min} and {max} pixels",
+        min = LinePlot::MIN_HEIGHT,
+        max = LinePlot::MAX_HEIGHT
+    )]
+    Height(u32),
     #[error("Failed to draw plot: {0}")]
-    BitMap(#[from] DrawingAreaErrorKind<BitMapBackendError>),
+    Draw(String),
     #[error("Failed to generate image buffer")]
     ImageBuffer,
     #[error("Failed to generate image: {0}")]
@@ -15,3 +28,14 @@ pub enum PlotError {
     #[error("Failed to generate font: {0}")]
     Font(FontError),
 }
+
+// The drawing error type depends on the backend,
+// so it is stringified to support both bitmap and SVG images.
+impl<E> From<DrawingAreaErrorKind<E>> for PlotError
+where
+    E: std::error::Error + Send + Sync,
+{
+    fn from(error: DrawingAreaErrorKind<E>) -> Self {
+        Self::Draw(error.to_string())
+    }
+}
@@ -1,5 +1,6 @@
 mod error;
 mod line;
+mod theme;
 
 pub use error::PlotError;
 pub use line::LinePlot;
@@ -1,8 +1,10 @@
 use crate::PlotError;
+use crate::theme::{self, Palette};
+use bencher_json::project::perf::{PerfImgFormat, PerfImgTheme};
 use bencher_json::{JsonMeasure, Units};
 use bencher_json::{JsonPerf, project::perf::JsonPerfMetrics};
 use chrono::{DateTime, Duration, Utc};
-use image::{GenericImageView as _, ImageBuffer};
+use image::ImageBuffer;
 use ordered_float::{FloatCore as _, OrderedFloat};
 use plotters::chart::{ChartContext, DualCoordChartContext};
 use plotters::coord::ranged1d::{DefaultFormatting, KeyPointHint};
@@ -10,12 +12,14 @@ use plotters::prelude::{Cartesian2d, IntoLogRange as _, LogCoord, RangedDateTime
 use plotters::{
     coord::{Shift, types::RangedCoordf64},
     prelude::{
-        BitMapBackend, BitMapElement, ChartBuilder, DrawingArea, IntoDrawingArea as _,
-        MultiLineText, Ranged, Rectangle,
+        ChartBuilder, DrawingArea, DrawingBackend, IntoDrawingArea as _, MultiLineText, Ranged,
+        Rectangle,
     },
     series::LineSeries,
-    style::{Color as _, FontFamily, RGBColor, ShapeStyle, WHITE},
+    style::{Color as _, FontFamily, IntoFont as _, RGBColor, ShapeStyle, TextStyle},
 };
+use plotters_bitmap::BitMapBackend;
+use plotters_svg::SVGBackend;
 use std::sync::LazyLock;
 use std::{io::Cursor, ops::Range};
 
@@ -24,32 +28,23 @@ const IMG_HEIGHT: u32 = 768;
 const TITLE_HEIGHT: u32 = 48;
 const PLOT_HEIGHT: u32 = 600;
 const KEY_HEIGHT: u32 = IMG_HEIGHT - PLOT_HEIGHT;
+// The key area below the plot is always the same height,
+// so the plot itself grows or shrinks with the image height.
+const KEY_AREA_HEIGHT: u32 = IMG_HEIGHT - TITLE_HEIGHT - PLOT_HEIGHT;
 
 const MAX_TITLE_LEN: usize = 28;
 const X_LABELS: i64 = 5;
 const Y_LABELS: usize = 5;
 const DATE_TIME_FMT: &str = "%d %b %Y %H:%M:%S";
 
-// RGB is three units in size
-// https://docs.rs/image/latest/image/struct.Rgb.html
-const BUFFER_SIZE: usize = IMG_WIDTH as usize * IMG_HEIGHT as usize * 3;
-
 const MAX_LINES: usize = 10;
 
-pub const BENCHER_WORDMARK: &[u8; 4406] = include_bytes!("../wordmark.png");
-#[expect(clippy::expect_used)]
-static WORDMARK_ELEMENT: LazyLock<BitMapElement<(i32, i32)>> = LazyLock::new(|| {
-    let wordmark_cursor = Cursor::new(BENCHER_WORDMARK);
-    let wordmark_image =
-        image::load(wordmark_cursor, image::ImageFormat::Png).expect("Failed to load wordmark");
-    let size = wordmark_image.dimensions();
-    let buf = wordmark_image.to_rgb8().into_raw();
-    BitMapElement::with_owned_buffer((0, 5), size, buf).expect("Failed to create wordmark")
-});
-
 pub struct LinePlot {
     width: u32,
     height: u32,
+    format: PerfImgFormat,
+    theme: PerfImgTheme,
+    log_scale: Option<bool>,
 }
 
 impl Default for LinePlot {
@@ -57,64 +52,140 @@ impl Default for LinePlot {
         Self {
             width: IMG_WIDTH,
             height: IMG_HEIGHT,
+            format: PerfImgFormat::default(),
+            theme: PerfImgTheme::default(),
+            log_scale: None,
         }
     }
 }
 
 impl LinePlot {
+    pub const MIN_WIDTH: u32 = 512;
+    pub const MAX_WIDTH: u32 = 4096;
+    pub const MIN_HEIGHT: u32 = 384;
+    pub const MAX_HEIGHT: u32 = 4096;
+
     pub fn new() -> LinePlot {
         Self::default()
     }
 
+    pub fn width(mut self, width: u32) -> Result<Self, PlotError> {
+        if !(Self::MIN_WIDTH..=Self::MAX_WIDTH).contains(&width) {
+            return Err(PlotError::Width(width));
+        }
+        self.width = width;
+        Ok(self)
+    }
+
+    pub fn height(mut self, height: u32) -> Result<Self, PlotError> {
+        if !(Self::MIN_HEIGHT..=Self::MAX_HEIGHT).contains(&height) {
+            return Err(PlotError::Height(height));
+        }
+        self.height = height;
+        Ok(self)
+    }
+
+    #[must_use]
+    pub fn format(mut self, format: PerfImgFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[must_use]
+    pub fn theme(mut self, theme: PerfImgTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Force either a log (`true`) or linear (`false`) scale for the y-axis.
+    /// By default, the scale is picked based on the range of the data.
+    #[must_use]
+    pub fn log_scale(mut self, log_scale: Option<bool>) -> Self {
+        self.log_scale = log_scale;
+        self
+    }
+
     pub fn draw(&self, title: Option<&str>, json_perf: &JsonPerf) -> Result<Vec<u8>, PlotError> {
-        let mut plot_buffer = vec![0; BUFFER_SIZE];
-        self.draw_inner(title, json_perf, &mut plot_buffer)?;
+        match self.format {
+            PerfImgFormat::Jpeg => self.draw_bitmap(title, json_perf, image::ImageFormat::Jpeg),
+            PerfImgFormat::Png => self.draw_bitmap(title, json_perf, image::ImageFormat::Png),
+            PerfImgFormat::Svg => self.draw_svg(title, json_perf),
+        }
+    }
+
+    fn draw_bitmap(
+        &self,
+        title: Option<&str>,
+        json_perf: &JsonPerf,
+        image_format: image::ImageFormat,
+    ) -> Result<Vec<u8>, PlotError> {
+        // RGB is three units in size
+        // https://docs.rs/image/latest/image/struct.Rgb.html
+        let buffer_size = usize::try_from(self.width)? * usize::try_from(self.height)? * 3;
+        let mut plot_buffer = vec![0; buffer_size];
+        let root_area = BitMapBackend::with_buffer(&mut plot_buffer, (self.width, self.height))
+            .into_drawing_area();
+        self.draw_inner(title, json_perf, &root_area)?;
+        drop(root_area);
 
         let image_buffer: ImageBuffer<image::Rgb<u8>, Vec<u8>> =
             ImageBuffer::from_vec(self.width, self.height, plot_buffer)
                 .ok_or(PlotError::ImageBuffer)?;
-        let mut image_cursor = Cursor::new(Vec::with_capacity(BUFFER_SIZE));
-        image_buffer.write_to(&mut image_cursor, image::ImageFormat::Jpeg)?;
+        let mut image_cursor = Cursor::new(Vec::with_capacity(buffer_size));
+        image_buffer.write_to(&mut image_cursor, image_format)?;
 
         Ok(image_cursor.into_inner())
     }
 
-    fn draw_inner(
+    fn draw_svg(&self, title: Option<&str>, json_perf: &JsonPerf) -> Result<Vec<u8>, PlotError> {
+        let mut svg = String::new();
+        let root_area =
+            SVGBackend::with_string(&mut svg, (self.width, self.height)).into_drawing_area();
+        self.draw_inner(title, json_perf, &root_area)?;
+        drop(root_area);
+
+        Ok(svg.into_bytes())
+    }
+
+    // Both the bitmap and SVG backends use the same drawing logic,
+    // so the layout of the image is the same regardless of the format.
+    fn draw_inner<DB: DrawingBackend>(
         &self,
         title: Option<&str>,
         json_perf: &JsonPerf,
-        plot_buffer: &mut [u8],
+        root_area: &Area<DB>,
     ) -> Result<(), PlotError> {
-        let root_area =
-            BitMapBackend::with_buffer(plot_buffer, (self.width, self.height)).into_drawing_area();
-
-        let (header_area, plot_area) = Self::split_header(&root_area)?;
-        Self::header(title, json_perf, &header_area)?;
+        let palette = Palette::from(self.theme);
+        let (header_area, plot_area) = self.split_header(root_area, palette)?;
+        Self::header(title, json_perf, &header_area, palette)?;
 
         // Marshal the perf data into a plot-able form
         let perf_data = PerfData::new(json_perf);
         let Some(perf_data) = perf_data else {
-            return Self::no_data_found(&root_area, &plot_area);
+            return Self::no_data_found(root_area, &plot_area, palette);
         };
 
-        Self::plot(perf_data, &plot_area)?;
+        self.plot(perf_data, &plot_area, palette)?;
 
         root_area.present().map_err(Into::into)
     }
 
-    fn split_header<'b>(
-        root_area: &DrawingArea<BitMapBackend<'b>, Shift>,
-    ) -> Result<(Area<'b>, Area<'b>), PlotError> {
-        root_area.fill(&WHITE)?;
+    fn split_header<DB: DrawingBackend>(
+        &self,
+        root_area: &Area<DB>,
+        palette: Palette,
+    ) -> Result<(Area<DB>, Area<DB>), PlotError> {
+        root_area.fill(&palette.background)?;
         // Bencher Wordmark
-        root_area.draw(&*WORDMARK_ELEMENT)?;
+        root_area.draw(theme::wordmark(self.theme))?;
         Ok(root_area.split_vertically(TITLE_HEIGHT))
     }
 
-    fn header(
+    fn header<DB: DrawingBackend>(
         title: Option<&str>,
         json_perf: &JsonPerf,
-        header_area: &Area<'_>,
+        header_area: &Area<DB>,
+        palette: Palette,
     ) -> Result<(), PlotError> {
         // Adaptive title sizing
         let title = title.unwrap_or(json_perf.project.name.as_ref());
@@ -125,34 +196,47 @@ impl LinePlot {
         } else {
             TITLE_HEIGHT
         };
-        header_area.titled(title, (FontFamily::Monospace, size))?;
+        header_area.titled(title, text_style(size, palette))?;
         Ok(())
     }
 
-    fn no_data_found(root_area: &Area<'_>, plot_area: &Area<'_>) -> Result<(), PlotError> {
+    fn no_data_found<DB: DrawingBackend>(
+        root_area: &Area<DB>,
+        plot_area: &Area<DB>,
+        palette: Palette,
+    ) -> Result<(), PlotError> {
         // Return an informative message if there is no perf data found
         let _chart_context = ChartBuilder::on(plot_area)
             .margin_top(TITLE_HEIGHT)
             .caption(
                 format!("No Data Found: {}", Utc::now().format(DATE_TIME_FMT)),
-                (FontFamily::Monospace, 32),
+                text_style(32, palette),
             )
             .build_cartesian_2d(PerfData::default_x_range(), PerfData::default_y_range())?;
 
         root_area.present().map_err(Into::into)
     }
 
-    fn plot(
+    fn plot<DB: DrawingBackend>(
+        &self,
         perf_data: PerfData,
-        plot_area: &DrawingArea<BitMapBackend<'_>, Shift>,
+        plot_area: &Area<DB>,
+        palette: Palette,
     ) -> Result<(), PlotError> {
-        let (plot_area, key_area) = plot_area.split_vertically(PLOT_HEIGHT);
-        let mut chart = Chart::new(&perf_data, &plot_area)?;
-        chart.layout_plot(&perf_data)?;
-        chart.plot_lines(perf_data, &key_area)
+        let plot_height = self.height - TITLE_HEIGHT - KEY_AREA_HEIGHT;
+        let (plot_area, key_area) = plot_area.split_vertically(plot_height);
+        let mut chart = Chart::new(&perf_data, &plot_area, self.log_scale)?;
+        chart.layout_plot(&perf_data, palette)?;
+        chart.plot_lines(perf_data, &key_area, self.width, palette)
     }
 }
 
+fn text_style(size: u32, palette: Palette) -> TextStyle<'static> {
+    (FontFamily::Monospace, size)
+        .into_font()
+        .color(&palette.foreground)
+}
+
 /// Ranged type that can support either linear or log scaling
 enum RangedCoord {
     Linear(RangedCoordf64),
@@ -185,47 +269,46 @@ impl Ranged for RangedCoord {
     }
 }
 
-impl From<Range<f64>> for RangedCoord {
-    fn from(range: Range<f64>) -> Self {
-        let relative_difference = if range.start.is_normal() {
-            range.end / range.start
+impl RangedCoord {
+    fn new(range: Range<f64>, log_scale: Option<bool>) -> Self {
+        let log_scale = log_scale.unwrap_or_else(|| {
+            let relative_difference = if range.start.is_normal() {
+                range.end / range.start
+            } else {
+                range.end
+            };
+            relative_difference >= 10.0
+        });
+        // A log scale requires a positive upper bound
+        if log_scale && range.end > 0.0 {
+            RangedCoord::Log(range.log_scale().into())
         } else {
-            range.end
-        };
-        if relative_difference < 10.0 {
             RangedCoord::Linear(range.into())
-        } else {
-            RangedCoord::Log(range.log_scale().into())
         }
     }
 }
 
 // https://github.com/plotters-rs/plotters/blob/v0.3.7/plotters/examples/two-scales.rs
 #[expect(clippy::large_enum_variant, clippy::type_complexity)]
-enum Chart<'b> {
-    Single(
-        ChartContext<
-            'b,
-            BitMapBackend<'b>,
-            Cartesian2d<RangedDateTime<DateTime<Utc>>, RangedCoord>,
-        >,
-    ),
+enum Chart<'b, DB: DrawingBackend> {
+    Single(ChartContext<'b, DB, Cartesian2d<RangedDateTime<DateTime<Utc>>, RangedCoord>>),
     Dual(
         DualCoordChartContext<
             'b,
-            BitMapBackend<'b>,
+            DB,
             Cartesian2d<RangedDateTime<DateTime<Utc>>, RangedCoord>,
             Cartesian2d<RangedDateTime<DateTime<Utc>>, RangedCoord>,
         >,
     ),
 }
 
-impl<'b> Chart<'b> {
+impl<DB: DrawingBackend> Chart<'_, DB> {
     fn new(
         perf_data: &PerfData,
-        plot_area: &DrawingArea<BitMapBackend<'b>, Shift>,
+        plot_area: &Area<DB>,
+        log_scale: Option<bool>,
     ) -> Result<Self, PlotError> {
-        let left_y_range = RangedCoord::from(perf_data.left_y_range());
+        let left_y_range = RangedCoord::new(perf_data.left_y_range(), log_scale);
         let chart_context = ChartBuilder::on(plot_area)
             .x_label_area_size(40)
             .y_label_area_size(perf_data.left_y_label_area_size()?)
@@ -236,7 +319,7 @@ impl<'b> Chart<'b> {
             .build_cartesian_2d(perf_data.x_range(), left_y_range)?;
 
         Ok(if let Some(right_y_range) = perf_data.right_y_range() {
-            let right_y_range = RangedCoord::from(right_y_range);
+            let right_y_range = RangedCoord::new(right_y_range, log_scale);
             Self::Dual(chart_context.set_secondary_coord(perf_data.x_range(), right_y_range))
         } else {
             Self::Single(chart_context)
@@ -244,15 +327,18 @@ impl<'b> Chart<'b> {
     }
 
     #[expect(clippy::items_after_statements)]
-    fn layout_plot(&mut self, perf_data: &PerfData) -> Result<(), PlotError> {
-        const AXIS_DESC_STYLE: (FontFamily, u32) = (FontFamily::Monospace, 20);
+    fn layout_plot(&mut self, perf_data: &PerfData, palette: Palette) -> Result<(), PlotError> {
+        let axis_desc_style = text_style(20, palette);
         const X_DESC: &str = "Benchmark Date and Time";
         let x_labels = usize::try_from(X_LABELS)?;
-        const X_LABEL_STYLE: (FontFamily, u32) = (FontFamily::Monospace, 16);
+        let x_label_style = text_style(16, palette);
         #[expect(clippy::type_complexity)]
         let x_label_formatter: Box<dyn Fn(&DateTime<Utc>) -> String> =
             Box::new(move |x| perf_data.x_label_fmt(x));
-        const Y_LABEL_STYLE: (FontFamily, u32) = (FontFamily::Monospace, 12);
+        let y_label_style = text_style(12, palette);
+        let axis_color = palette.foreground;
+        let bold_line_color = palette.foreground.mix(0.2);
+        let light_line_color = palette.foreground.mix(0.1);
         fn y_label_formatter(trim_decimal: bool) -> Box<dyn Fn(&f64) -> String> {
             Box::new(move |&y| Units::format_number(y, trim_decimal))
         }
@@ -262,14 +348,17 @@ impl<'b> Chart<'b> {
             Chart::Single(chart_context) => {
                 chart_context
                     .configure_mesh()
-                    .axis_desc_style(AXIS_DESC_STYLE)
+                    .axis_style(axis_color)
+                    .bold_line_style(bold_line_color)
+                    .light_line_style(light_line_color)
+                    .axis_desc_style(axis_desc_style.clone())
                     .x_desc(X_DESC)
                     .x_labels(x_labels)
-                    .x_label_style(X_LABEL_STYLE)
+                    .x_label_style(x_label_style.clone())
                     .x_label_formatter(&x_label_formatter)
                     .y_desc(&perf_data.left_y_desc)
                     .y_labels(Y_LABELS)
-                    .y_label_style(Y_LABEL_STYLE)
+                    .y_label_style(y_label_style.clone())
                     .y_label_formatter(&y_label_formatter(perf_data.trim_left_key_point_decimal()))
                     .max_light_lines(MAX_LIGHT_LINES)
                     .draw()?;
@@ -277,14 +366,17 @@ impl<'b> Chart<'b> {
             Chart::Dual(chart_context) => {
                 chart_context
                     .configure_mesh()
-                    .axis_desc_style(AXIS_DESC_STYLE)
+                    .axis_style(axis_color)
+                    .bold_line_style(bold_line_color)
+                    .light_line_style(light_line_color)
+                    .axis_desc_style(axis_desc_style.clone())
                     .x_desc(X_DESC)
                     .x_labels(x_labels)
-                    .x_label_style(X_LABEL_STYLE)
+                    .x_label_style(x_label_style.clone())
                     .x_label_formatter(&x_label_formatter)
                     .y_desc(&perf_data.left_y_desc)
                     .y_labels(Y_LABELS)
-                    .y_label_style(Y_LABEL_STYLE)
+                    .y_label_style(y_label_style.clone())
                     .y_label_formatter(&y_label_formatter(perf_data.trim_left_key_point_decimal()))
                     .max_light_lines(MAX_LIGHT_LINES)
                     .draw()?;
@@ -295,10 +387,11 @@ impl<'b> Chart<'b> {
                 ) {
                     chart_context
                         .configure_secondary_axes()
-                        .axis_desc_style(AXIS_DESC_STYLE)
+                        .axis_style(axis_color)
+                        .axis_desc_style(axis_desc_style)
                         .y_desc(right_y_desc)
                         .y_labels(Y_LABELS)
-                        .label_style(Y_LABEL_STYLE)
+                        .label_style(y_label_style)
                         .y_label_formatter(&y_label_formatter(right_trim_decimal))
                         .draw()?;
                 }
@@ -310,9 +403,11 @@ impl<'b> Chart<'b> {
     fn plot_lines(
         &mut self,
         perf_data: PerfData,
-        key_area: &DrawingArea<BitMapBackend<'_>, Shift>,
+        key_area: &Area<DB>,
+        width: u32,
+        palette: Palette,
     ) -> Result<(), PlotError> {
-        let plot_box = perf_data.plot_box()?;
+        let plot_box = perf_data.plot_box(width)?;
         let mut box_x_left = plot_box.x_left;
         for LineData {
             data,
@@ -336,7 +431,7 @@ impl<'b> Chart<'b> {
                 let text = MultiLineText::from_str(
                     dimensions.as_str(),
                     (box_x_left, plot_box.text_start),
-                    (FontFamily::Monospace, font),
+                    text_style(font, palette),
                     plot_box.text_width,
                 );
                 let (_, text_height) = text.estimate_dimension().map_err(PlotError::Font)?;
@@ -381,7 +476,7 @@ impl<'b> Chart<'b> {
     }
 }
 
-type Area<'b> = DrawingArea<BitMapBackend<'b>, Shift>;
+type Area<DB> = DrawingArea<DB, Shift>;
 
 struct PerfData {
     lines: Vec<LineData>,
@@ -577,13 +672,14 @@ impl PerfData {
         u32::try_from(y_len).map_err(Into::into)
     }
 
-    fn plot_box(&self) -> Result<PlotBox, PlotError> {
+    fn plot_box(&self, width: u32) -> Result<PlotBox, PlotError> {
         const KEY_LEFT_MARGIN: usize = 48;
         const BOX_GAP: usize = 12;
         const BOX_HEIGHT: i32 = 24;
         const TEXT_START: i32 = BOX_HEIGHT + 4;
 
         let lines_len = self.lines.len();
+        let img_width = usize::try_from(width)?;
         let (box_x_left, box_width, box_gap) = if lines_len > 3 {
             const MIN_GAP: usize = 4;
             let extra_lines = lines_len - 4;
@@ -591,10 +687,13 @@ impl PerfData {
             let box_gap = std::cmp::max(MIN_GAP, BOX_GAP - extra_lines);
             let box_gaps = lines_len * box_gap;
             #[expect(clippy::integer_division)]
-            let width = (usize::try_from(IMG_WIDTH)? - box_x_left - box_gaps) / lines_len;
+            let width = (img_width - box_x_left - box_gaps) / lines_len;
             (box_x_left, width, box_gap)
         } else {
-            (KEY_LEFT_MARGIN, 256, BOX_GAP)
+            // Shrink the boxes to fit narrow images
+            #[expect(clippy::integer_division)]
+            let width = (img_width - KEY_LEFT_MARGIN - 3 * BOX_GAP) / 3;
+            (KEY_LEFT_MARGIN, std::cmp::min(width, 256), BOX_GAP)
         };
 
         let text_end = i32::try_from(KEY_HEIGHT)? - TEXT_START - 48;
@@ -791,11 +890,19 @@ impl LineData {
 
 #[cfg(test)]
 mod tests {
-    use std::{fs::File, io::Write as _, sync::LazyLock};
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        sync::LazyLock,
+    };
 
-    use bencher_json::JsonPerf;
+    use bencher_json::{
+        JsonPerf,
+        project::perf::{PerfImgFormat, PerfImgTheme},
+    };
+    use image::{GenericImageView as _, ImageFormat};
 
-    use crate::LinePlot;
+    use crate::{LinePlot, PlotError};
 
     pub const PERF_DOT_JSON: &str = include_str!("../perf.json");
     static JSON_PERF: LazyLock<JsonPerf> = LazyLock::new(|| {
@@ -818,9 +925,56 @@ mod tests {
         serde_json::from_str(DECIMAL_DOT_JSON).expect("Failed to serialize decimal JSON")
     });
 
-    fn save_jpeg(jpeg: &[u8], name: &str) {
-        let mut file = File::create(format!("{name}.jpeg")).unwrap();
-        file.write_all(jpeg).unwrap();
+    // Set `BENCHER_UPDATE_SNAPSHOTS` to overwrite the snapshots with the current images.
+    const UPDATE_SNAPSHOTS: &str = "BENCHER_UPDATE_SNAPSHOTS";
+    // Allow for small differences in anti-aliasing between font rasterizers.
+    const CHANNEL_TOLERANCE: u8 = 32;
+    // At most one in a thousand pixels may differ from the snapshot.
+    const PIXEL_TOLERANCE: usize = 1_000;
+
+    fn snapshot_path(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("snapshots")
+            .join(format!("{name}.png"))
+    }
+
+    fn assert_snapshot(png: &[u8], name: &str) {
+        let path = snapshot_path(name);
+        if env::var_os(UPDATE_SNAPSHOTS).is_some() {
+            fs::write(&path, png).unwrap();
+            return;
+        }
+
+        let actual = image::load_from_memory_with_format(png, ImageFormat::Png)
+            .unwrap()
+            .to_rgb8();
+        let expected = image::open(&path)
+            .expect("Failed to open snapshot")
+            .to_rgb8();
+        assert_eq!(actual.dimensions(), expected.dimensions(), "{name}");
+
+        let diff = actual
+            .pixels()
+            .zip(expected.pixels())
+            .filter(|(actual, expected)| {
+                actual
+                    .0
+                    .iter()
+                    .zip(expected.0.iter())
+                    .any(|(a, e)| a.abs_diff(*e) > CHANNEL_TOLERANCE)
+            })
+            .count();
+        let max_diff = actual.pixels().len() / PIXEL_TOLERANCE;
+        // Save the current image next to the snapshot to compare them by eye
+        let actual_path = path.with_extension("actual.png");
+        if diff > max_diff {
+            fs::write(&actual_path, png).unwrap();
+        }
+        assert!(
+            diff <= max_diff,
+            "{name} differs from its snapshot by {diff} pixels (max {max_diff}), see {}",
+            actual_path.display()
+        );
     }
 
     #[test]
@@ -829,7 +983,9 @@ mod tests {
         let plot_buffer = plot
             .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF)
             .unwrap();
-        save_jpeg(&plot_buffer, "perf");
+        let image = image::load_from_memory_with_format(&plot_buffer, ImageFormat::Png).unwrap();
+        assert_eq!(image.dimensions(), (1024, 768));
+        assert_snapshot(&plot_buffer, "perf");
     }
 
     #[test]
@@ -838,7 +994,7 @@ mod tests {
         let plot_buffer = plot
             .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF_LOG)
             .unwrap();
-        save_jpeg(&plot_buffer, "perf_log");
+        assert_snapshot(&plot_buffer, "perf_log");
     }
 
     #[test]
@@ -847,7 +1003,7 @@ mod tests {
         let plot_buffer = plot
             .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF_DUAL_AXES)
             .unwrap();
-        save_jpeg(&plot_buffer, "perf_dual_axes");
+        assert_snapshot(&plot_buffer, "perf_dual_axes");
     }
 
     #[test]
@@ -856,15 +1012,130 @@ mod tests {
         let plot_buffer = plot
             .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF_DECIMAL)
             .unwrap();
-        save_jpeg(&plot_buffer, "decimal");
+        assert_snapshot(&plot_buffer, "decimal");
     }
 
+    // The empty plot includes the current time, so it can not be compared to a snapshot.
     #[test]
     fn plot_empty() {
         let plot = LinePlot::new();
         let mut json_perf = JSON_PERF.clone();
         json_perf.results.clear();
         let plot_buffer = plot.draw(None, &json_perf).unwrap();
-        save_jpeg(&plot_buffer, "empty");
+        let image = image::load_from_memory_with_format(&plot_buffer, ImageFormat::Png).unwrap();
+        assert_eq!(image.dimensions(), (1024, 768));
+    }
+
+    #[test]
+    fn plot_jpeg() {
+        let plot = LinePlot::new().format(PerfImgFormat::Jpeg);
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF)
+            .unwrap();
+        let image = image::load_from_memory_with_format(&plot_buffer, ImageFormat::Jpeg).unwrap();
+        assert_eq!(image.dimensions(), (1024, 768));
+    }
+
+    #[test]
+    fn plot_svg() {
+        let plot = LinePlot::new().format(PerfImgFormat::Svg);
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF)
+            .unwrap();
+        let svg = String::from_utf8(plot_buffer).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(r#"width="1024" height="768""#));
+        assert!(svg.contains("Benchmark Adapter Comparison"));
+    }
+
+    // The first key box is drawn at the same position in both the PNG and the SVG.
+    #[test]
+    fn plot_svg_png_layout() {
+        const WIDTH: u32 = 800;
+        const HEIGHT: u32 = 600;
+        // With six lines, the key boxes shift left to fit
+        const KEY_X: u32 = 32;
+        // The key area is below the title and the plot
+        const KEY_Y: u32 = HEIGHT - 120;
+        let plot = LinePlot::new()
+            .width(WIDTH)
+            .unwrap()
+            .height(HEIGHT)
+            .unwrap();
+
+        let png = plot
+            .format(PerfImgFormat::Png)
+            .draw(None, &JSON_PERF_DUAL_AXES)
+            .unwrap();
+        let image = image::load_from_memory_with_format(&png, ImageFormat::Png).unwrap();
+        assert_eq!(image.dimensions(), (WIDTH, HEIGHT));
+        let image::Rgba([r, g, b, _]) = image.get_pixel(KEY_X + 8, KEY_Y + 8);
+        assert_eq!((r, g, b), (78, 121, 167));
+
+        let plot = LinePlot::new()
+            .width(WIDTH)
+            .unwrap()
+            .height(HEIGHT)
+            .unwrap();
+        let svg = plot
+            .format(PerfImgFormat::Svg)
+            .draw(None, &JSON_PERF_DUAL_AXES)
+            .unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains(&format!(r#"width="{WIDTH}" height="{HEIGHT}""#)));
+        assert!(svg.contains(&format!(r#"<rect x="{KEY_X}" y="{KEY_Y}""#)));
+        assert!(svg.contains(r##"fill="#4E79A7""##));
+    }
+
+    #[test]
+    fn plot_dark() {
+        let plot = LinePlot::new()
+            .format(PerfImgFormat::Png)
+            .theme(PerfImgTheme::Dark);
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF)
+            .unwrap();
+        let image = image::load_from_memory_with_format(&plot_buffer, ImageFormat::Png).unwrap();
+        let image::Rgba([r, g, b, _]) = image.get_pixel(1023, 767);
+        assert_eq!((r, g, b), (13, 17, 23));
+        assert_snapshot(&plot_buffer, "perf_dark");
+
+        let plot = LinePlot::new()
+            .format(PerfImgFormat::Svg)
+            .theme(PerfImgTheme::Dark);
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF)
+            .unwrap();
+        let svg = String::from_utf8(plot_buffer).unwrap();
+        assert!(svg.contains(r##"fill="#0D1117""##));
+    }
+
+    #[test]
+    fn plot_log_scale() {
+        let plot = LinePlot::new().log_scale(Some(true));
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF)
+            .unwrap();
+        assert_snapshot(&plot_buffer, "perf_log_scale");
+
+        let plot = LinePlot::new().log_scale(Some(false));
+        let plot_buffer = plot
+            .draw(Some("Benchmark Adapter Comparison"), &JSON_PERF_LOG)
+            .unwrap();
+        assert_snapshot(&plot_buffer, "perf_linear_scale");
+    }
+
+    #[test]
+    fn plot_size() {
+        assert!(LinePlot::new().width(LinePlot::MIN_WIDTH).is_ok());
+        assert!(LinePlot::new().height(LinePlot::MAX_HEIGHT).is_ok());
+        assert!(matches!(
+            LinePlot::new().width(LinePlot::MIN_WIDTH - 1),
+            Err(PlotError::Width(_))
+        ));
+        assert!(matches!(
+            LinePlot::new().height(LinePlot::MAX_HEIGHT + 1),
+            Err(PlotError::Height(_))
+        ));
     }
 }
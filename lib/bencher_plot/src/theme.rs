@@ -0,0 +1,91 @@
+use std::{io::Cursor, sync::LazyLock};
+
+use bencher_json::project::perf::PerfImgTheme;
+use image::{GenericImageView as _, Rgba};
+use plotters::{
+    prelude::BitMapElement,
+    style::{BLACK, RGBColor, WHITE},
+};
+
+pub const BENCHER_WORDMARK: &[u8; 4406] = include_bytes!("../wordmark.png");
+static LIGHT_WORDMARK_ELEMENT: LazyLock<BitMapElement<(i32, i32)>> =
+    LazyLock::new(|| wordmark_element(PerfImgTheme::Light));
+static DARK_WORDMARK_ELEMENT: LazyLock<BitMapElement<(i32, i32)>> =
+    LazyLock::new(|| wordmark_element(PerfImgTheme::Dark));
+
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    pub background: RGBColor,
+    pub foreground: RGBColor,
+}
+
+const LIGHT_PALETTE: Palette = Palette {
+    background: WHITE,
+    foreground: BLACK,
+};
+
+const DARK_PALETTE: Palette = Palette {
+    // #0d1117
+    background: RGBColor(13, 17, 23),
+    // #e6edf3
+    foreground: RGBColor(230, 237, 243),
+};
+
+impl From<PerfImgTheme> for Palette {
+    fn from(theme: PerfImgTheme) -> Self {
+        match theme {
+            PerfImgTheme::Light => LIGHT_PALETTE,
+            PerfImgTheme::Dark => DARK_PALETTE,
+        }
+    }
+}
+
+pub fn wordmark(theme: PerfImgTheme) -> &'static BitMapElement<'static, (i32, i32)> {
+    match theme {
+        PerfImgTheme::Light => &LIGHT_WORDMARK_ELEMENT,
+        PerfImgTheme::Dark => &DARK_WORDMARK_ELEMENT,
+    }
+}
+
+// The wordmark is dark text on a white background.
+// For the dark theme, the gray scale pixels are treated as text coverage
+// and redrawn using the foreground and background colors for the theme.
+#[expect(clippy::expect_used)]
+fn wordmark_element(theme: PerfImgTheme) -> BitMapElement<'static, (i32, i32)> {
+    let Palette {
+        background,
+        foreground,
+    } = theme.into();
+    let wordmark_cursor = Cursor::new(BENCHER_WORDMARK);
+    let wordmark_image =
+        image::load(wordmark_cursor, image::ImageFormat::Png).expect("Failed to load wordmark");
+    let size = wordmark_image.dimensions();
+    let buf = wordmark_image
+        .to_rgba8()
+        .pixels()
+        .flat_map(|&Rgba([r, g, b, alpha])| {
+            let text = matches!(theme, PerfImgTheme::Dark) && r == g && g == b;
+            [
+                (r, foreground.0, background.0),
+                (g, foreground.1, background.1),
+                (b, foreground.2, background.2),
+            ]
+            .map(|(color, fg, bg)| {
+                if text {
+                    blend(fg, bg, u8::MAX - color)
+                } else {
+                    blend(color, bg, alpha)
+                }
+            })
+        })
+        .collect();
+    BitMapElement::with_owned_buffer((0, 5), size, buf).expect("Failed to create wordmark")
+}
+
+#[expect(clippy::integer_division)]
+fn blend(color: u8, background: u8, alpha: u8) -> u8 {
+    let max = u16::from(u8::MAX);
+    let alpha = u16::from(alpha);
+    let blended = (u16::from(color) * alpha + u16::from(background) * (max - alpha)) / max;
+    u8::try_from(blended).unwrap_or(u8::MAX)
+}
@@ -15,6 +15,7 @@ const CREATE_PERM: &str = "create";
 const EDIT_PERM: &str = "edit";
 const DELETE_PERM: &str = "delete";
 const MANAGE_PERM: &str = "manage";
+const CREATE_REPORT_PERM: &str = "create_report";
 
 const VIEW_ROLE_PERM: &str = "view_role";
 const CREATE_ROLE_PERM: &str = "create_role";
@@ -66,6 +67,7 @@ mod tests {
             locked: false,
             organizations: HashMap::new(),
             projects: HashMap::new(),
+            project_permissions: HashMap::new(),
         };
 
         assert!(
@@ -82,6 +84,7 @@ mod tests {
             locked: false,
             organizations: HashMap::new(),
             projects: HashMap::new(),
+            project_permissions: HashMap::new(),
         };
 
         assert!(
@@ -98,6 +101,7 @@ mod tests {
             locked: true,
             organizations: HashMap::new(),
             projects: HashMap::new(),
+            project_permissions: HashMap::new(),
         };
 
         assert!(
@@ -114,6 +118,7 @@ mod tests {
             locked: true,
             organizations: HashMap::new(),
             projects: HashMap::new(),
+            project_permissions: HashMap::new(),
         };
 
         assert!(
@@ -135,6 +140,7 @@ mod tests {
                 org_id.to_string() => OrgRole::Leader
             },
             projects: HashMap::new(),
+            project_permissions: HashMap::new(),
         };
 
         let org_member = User {
@@ -144,6 +150,7 @@ mod tests {
                 org_id.to_string() => OrgRole::Member
             },
             projects: HashMap::new(),
+            project_permissions: HashMap::new(),
         };
 
         let proj_member = User {
@@ -155,6 +162,7 @@ mod tests {
             projects: literally::hmap! {
                 proj_id.to_string() => ProjRole::Developer
             },
+            project_permissions: HashMap::new(),
         };
 
         let org = Organization {
@@ -250,6 +258,14 @@ mod tests {
             oso.is_allowed(proj_member.clone(), ProjPerm::Create, proj.clone())
                 .unwrap()
         );
+        assert!(
+            oso.is_allowed(proj_member.clone(), ProjPerm::CreateReport, proj.clone())
+                .unwrap()
+        );
+        assert!(
+            !oso.is_allowed(org_member.clone(), ProjPerm::CreateReport, proj.clone())
+                .unwrap()
+        );
         assert!(
             !oso.is_allowed(proj_member.clone(), ProjPerm::Manage, proj)
                 .unwrap()
@@ -345,4 +361,62 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn rbac_custom_role() {
+        let oso = &*OSO;
+
+        let org_id = Uuid::new_v4();
+        let proj_id = Uuid::new_v4();
+        let other_proj_id = Uuid::new_v4();
+
+        // A CI bot that can create reports but not delete branches
+        let ci_bot = User {
+            admin: false,
+            locked: false,
+            organizations: literally::hmap! {
+                org_id.to_string() => OrgRole::Member
+            },
+            projects: HashMap::new(),
+            project_permissions: literally::hmap! {
+                proj_id.to_string() => vec![ProjPerm::View, ProjPerm::CreateReport]
+            },
+        };
+
+        let proj = Project {
+            id: proj_id.to_string(),
+            organization_id: org_id.to_string(),
+        };
+        let other_proj = Project {
+            id: other_proj_id.to_string(),
+            organization_id: org_id.to_string(),
+        };
+
+        assert!(
+            oso.is_allowed(ci_bot.clone(), ProjPerm::View, proj.clone())
+                .unwrap()
+        );
+        assert!(
+            oso.is_allowed(ci_bot.clone(), ProjPerm::CreateReport, proj.clone())
+                .unwrap()
+        );
+        assert!(
+            !oso.is_allowed(ci_bot.clone(), ProjPerm::Delete, proj.clone())
+                .unwrap()
+        );
+        assert!(
+            !oso.is_allowed(ci_bot.clone(), ProjPerm::Manage, proj.clone())
+                .unwrap()
+        );
+        assert!(
+            !oso.is_allowed(ci_bot.clone(), ProjPerm::CreateReport, other_proj)
+                .unwrap()
+        );
+
+        let locked_ci_bot = User {
+            locked: true,
+            ..ci_bot
+        };
+        assert!(!oso.is_allowed(locked_ci_bot, ProjPerm::View, proj).unwrap());
+    }
 }
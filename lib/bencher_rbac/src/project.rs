@@ -3,8 +3,8 @@ use std::{fmt, str::FromStr};
 use oso::{PolarClass, PolarValue, ToPolar};
 
 use crate::{
-    CREATE_PERM, CREATE_ROLE_PERM, DELETE_PERM, DELETE_ROLE_PERM, EDIT_PERM, EDIT_ROLE_PERM,
-    MANAGE_PERM, VIEW_PERM, VIEW_ROLE_PERM,
+    CREATE_PERM, CREATE_REPORT_PERM, CREATE_ROLE_PERM, DELETE_PERM, DELETE_ROLE_PERM, EDIT_PERM,
+    EDIT_ROLE_PERM, MANAGE_PERM, VIEW_PERM, VIEW_ROLE_PERM,
 };
 
 const VIEWER_ROLE: &str = "viewer";
@@ -66,6 +66,7 @@ pub enum Permission {
     Edit,
     Delete,
     Manage,
+    CreateReport,
     ViewRole,
     CreateRole,
     EditRole,
@@ -83,6 +84,7 @@ impl fmt::Display for Permission {
                 Self::Edit => EDIT_PERM,
                 Self::Delete => DELETE_PERM,
                 Self::Manage => MANAGE_PERM,
+                Self::CreateReport => CREATE_REPORT_PERM,
                 Self::ViewRole => VIEW_ROLE_PERM,
                 Self::CreateRole => CREATE_ROLE_PERM,
                 Self::EditRole => EDIT_ROLE_PERM,
@@ -13,7 +13,11 @@ pub struct User {
     pub organizations: OrganizationRoles,
     #[polar(attribute)]
     pub projects: ProjectRoles,
+    #[polar(attribute)]
+    pub project_permissions: ProjectPermissions,
 }
 
 pub type OrganizationRoles = HashMap<String, crate::organization::Role>;
 pub type ProjectRoles = HashMap<String, crate::project::Role>;
+/// The permissions granted by custom roles, keyed by project.
+pub type ProjectPermissions = HashMap<String, Vec<crate::project::Permission>>;
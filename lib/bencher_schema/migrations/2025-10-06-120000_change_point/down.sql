@@ -0,0 +1 @@
+DROP TABLE IF EXISTS change_point;
\ No newline at end of file
@@ -0,0 +1,14 @@
+CREATE TABLE change_point (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    metric_id INTEGER NOT NULL,
+    threshold_id INTEGER NOT NULL,
+    model_id INTEGER NOT NULL,
+    baseline DOUBLE NOT NULL,
+    value DOUBLE NOT NULL,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (metric_id) REFERENCES metric (id) ON DELETE CASCADE,
+    FOREIGN KEY (threshold_id) REFERENCES threshold (id) ON DELETE CASCADE,
+    FOREIGN KEY (model_id) REFERENCES model (id) ON DELETE CASCADE,
+    UNIQUE(metric_id, threshold_id)
+);
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS index_benchmark_tag_key_value;
+DROP TABLE IF EXISTS benchmark_tag;
+ALTER TABLE threshold DROP COLUMN benchmark_tags;
+ALTER TABLE plot DROP COLUMN benchmark_tags;
@@ -0,0 +1,13 @@
+CREATE TABLE benchmark_tag (
+    id INTEGER PRIMARY KEY NOT NULL,
+    benchmark_id INTEGER NOT NULL,
+    key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    FOREIGN KEY (benchmark_id) REFERENCES benchmark (id) ON DELETE CASCADE,
+    UNIQUE(benchmark_id, key)
+);
+CREATE INDEX index_benchmark_tag_key_value ON benchmark_tag(key, value);
+ALTER TABLE threshold
+ADD COLUMN benchmark_tags TEXT;
+ALTER TABLE plot
+ADD COLUMN benchmark_tags TEXT;
@@ -0,0 +1,2 @@
+ALTER TABLE project
+ADD COLUMN openmetrics BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,8 @@
+CREATE TABLE project_retention (
+    id INTEGER PRIMARY KEY NOT NULL,
+    project_id INTEGER NOT NULL UNIQUE,
+    reports INTEGER,
+    archived_branches INTEGER,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
@@ -0,0 +1 @@
+ALTER TABLE sso DROP COLUMN oidc;
@@ -0,0 +1,2 @@
+ALTER TABLE sso
+ADD COLUMN oidc BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,6 @@
+DROP TRIGGER IF EXISTS audit_event_no_delete;
+DROP TRIGGER IF EXISTS audit_event_no_update;
+DROP INDEX IF EXISTS index_audit_event_project;
+DROP INDEX IF EXISTS index_audit_event_organization;
+DROP INDEX IF EXISTS index_audit_event_created;
+DROP TABLE IF EXISTS audit_event;
@@ -0,0 +1,25 @@
+CREATE TABLE audit_event (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    user_uuid TEXT NOT NULL,
+    credential INTEGER NOT NULL,
+    token_uuid TEXT,
+    action INTEGER NOT NULL,
+    resource INTEGER NOT NULL,
+    resource_uuid TEXT,
+    organization_uuid TEXT,
+    project_uuid TEXT,
+    before TEXT,
+    after TEXT,
+    created BIGINT NOT NULL
+);
+CREATE INDEX index_audit_event_created ON audit_event(created);
+CREATE INDEX index_audit_event_organization ON audit_event(organization_uuid, created);
+CREATE INDEX index_audit_event_project ON audit_event(project_uuid, created);
+CREATE TRIGGER audit_event_no_update BEFORE
+UPDATE ON audit_event BEGIN
+SELECT RAISE(ABORT, 'audit_event is append-only');
+END;
+CREATE TRIGGER audit_event_no_delete BEFORE DELETE ON audit_event BEGIN
+SELECT RAISE(ABORT, 'audit_event is append-only');
+END;
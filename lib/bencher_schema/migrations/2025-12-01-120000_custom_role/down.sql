@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS index_project_member_role;
+DROP TABLE IF EXISTS project_member;
+DROP TABLE IF EXISTS custom_role;
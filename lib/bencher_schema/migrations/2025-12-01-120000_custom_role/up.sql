@@ -0,0 +1,24 @@
+CREATE TABLE custom_role (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    organization_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    permissions TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (organization_id) REFERENCES organization (id) ON DELETE CASCADE,
+    UNIQUE(organization_id, name)
+);
+CREATE TABLE project_member (
+    id INTEGER PRIMARY KEY NOT NULL,
+    user_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    role_id INTEGER NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user (id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (role_id) REFERENCES custom_role (id) ON DELETE CASCADE,
+    UNIQUE(user_id, project_id)
+);
+CREATE INDEX index_project_member_role ON project_member(role_id);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS index_report_group_uuid;
+ALTER TABLE report DROP COLUMN group_uuid;
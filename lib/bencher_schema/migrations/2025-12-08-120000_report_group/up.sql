@@ -0,0 +1,3 @@
+ALTER TABLE report
+ADD COLUMN group_uuid TEXT;
+CREATE INDEX index_report_group_uuid ON report(group_uuid);
@@ -0,0 +1,48 @@
+PRAGMA foreign_keys = off;
+DROP INDEX IF EXISTS index_threshold_benchmark_tags;
+-- threshold
+CREATE TABLE down_threshold (
+    id INTEGER PRIMARY KEY NOT NULL,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    branch_id INTEGER NOT NULL,
+    testbed_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    model_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    benchmark_tags TEXT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (branch_id) REFERENCES branch (id),
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id),
+    FOREIGN KEY (measure_id) REFERENCES measure (id),
+    FOREIGN KEY (model_id) REFERENCES model (id),
+    UNIQUE(branch_id, testbed_id, measure_id)
+);
+INSERT INTO down_threshold(
+        id,
+        uuid,
+        project_id,
+        branch_id,
+        testbed_id,
+        measure_id,
+        model_id,
+        created,
+        modified,
+        benchmark_tags
+    )
+SELECT id,
+    uuid,
+    project_id,
+    branch_id,
+    testbed_id,
+    measure_id,
+    model_id,
+    created,
+    modified,
+    benchmark_tags
+FROM threshold;
+DROP TABLE threshold;
+ALTER TABLE down_threshold
+    RENAME TO threshold;
+PRAGMA foreign_keys = on;
\ No newline at end of file
@@ -0,0 +1,40 @@
+DROP TABLE IF EXISTS project_member;
+DROP TABLE IF EXISTS custom_role;
+DROP TABLE IF EXISTS audit_event;
+DROP FUNCTION IF EXISTS audit_event_append_only;
+DROP TABLE IF EXISTS webhook;
+DROP TABLE IF EXISTS sso;
+DROP TABLE IF EXISTS plot_measure;
+DROP TABLE IF EXISTS plot_benchmark;
+DROP TABLE IF EXISTS plot_testbed;
+DROP TABLE IF EXISTS plot_branch;
+DROP TABLE IF EXISTS plot;
+DROP TABLE IF EXISTS change_point;
+DROP TABLE IF EXISTS alert;
+DROP VIEW IF EXISTS metric_boundary;
+DROP TABLE IF EXISTS boundary;
+DROP TABLE IF EXISTS metric;
+DROP TABLE IF EXISTS report_benchmark;
+DROP TABLE IF EXISTS report;
+ALTER TABLE threshold DROP CONSTRAINT IF EXISTS threshold_model_id_fkey;
+DROP TABLE IF EXISTS model;
+DROP TABLE IF EXISTS threshold;
+ALTER TABLE branch DROP CONSTRAINT IF EXISTS branch_head_id_fkey;
+ALTER TABLE head DROP CONSTRAINT IF EXISTS head_start_point_id_fkey;
+DROP TABLE IF EXISTS head_version;
+DROP TABLE IF EXISTS head;
+DROP TABLE IF EXISTS branch;
+DROP TABLE IF EXISTS measure;
+DROP TABLE IF EXISTS benchmark_tag;
+DROP TABLE IF EXISTS benchmark;
+DROP TABLE IF EXISTS testbed;
+DROP TABLE IF EXISTS version;
+DROP TABLE IF EXISTS project_retention;
+DROP TABLE IF EXISTS project_role;
+DROP TABLE IF EXISTS project;
+DROP TABLE IF EXISTS server;
+DROP TABLE IF EXISTS plan;
+DROP TABLE IF EXISTS organization_role;
+DROP TABLE IF EXISTS organization;
+DROP TABLE IF EXISTS token;
+DROP TABLE IF EXISTS "user";
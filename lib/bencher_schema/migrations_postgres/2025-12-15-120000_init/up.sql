@@ -0,0 +1,464 @@
+-- The PostgreSQL schema starts from the final SQLite schema as of `2025-12-08-120000_report_group`.
+-- Any later SQLite migrations must have a matching PostgreSQL migration.
+CREATE TABLE "user" (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL UNIQUE,
+    email TEXT NOT NULL UNIQUE,
+    admin BOOLEAN NOT NULL,
+    locked BOOLEAN NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    tokens_revoked BIGINT
+);
+CREATE TABLE token (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    user_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    jwt TEXT NOT NULL,
+    creation BIGINT NOT NULL,
+    expiration BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES "user" (id)
+);
+CREATE TABLE organization (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL UNIQUE,
+    license TEXT UNIQUE,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL
+);
+CREATE TABLE organization_role (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    organization_id INTEGER NOT NULL,
+    role TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES "user" (id),
+    FOREIGN KEY (organization_id) REFERENCES organization (id) ON DELETE CASCADE,
+    UNIQUE(user_id, organization_id)
+);
+CREATE TABLE plan (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    organization_id INTEGER NOT NULL UNIQUE,
+    metered_plan TEXT UNIQUE,
+    licensed_plan TEXT UNIQUE,
+    license TEXT UNIQUE,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    CONSTRAINT only_one_plan CHECK (
+        metered_plan IS NOT NULL
+        OR licensed_plan IS NOT NULL
+        AND NOT (
+            metered_plan IS NOT NULL
+            AND licensed_plan IS NOT NULL
+        )
+    ),
+    CONSTRAINT metered_plan_no_license CHECK (
+        NOT (
+            metered_plan IS NOT NULL
+            AND license IS NOT NULL
+        )
+    ),
+    CONSTRAINT licensed_plan_has_license CHECK (
+        NOT (
+            licensed_plan IS NOT NULL
+            AND license IS NULL
+        )
+    )
+);
+CREATE TABLE server (
+    id INTEGER PRIMARY KEY NOT NULL DEFAULT 1,
+    uuid TEXT NOT NULL UNIQUE,
+    created BIGINT NOT NULL,
+    CONSTRAINT only_one_server CHECK (id = 1)
+);
+CREATE TABLE project (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    organization_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL UNIQUE,
+    url TEXT,
+    visibility INTEGER NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    openmetrics BOOLEAN NOT NULL DEFAULT FALSE,
+    FOREIGN KEY (organization_id) REFERENCES organization (id) ON DELETE CASCADE,
+    UNIQUE(organization_id, name)
+);
+CREATE TABLE project_role (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    role TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES "user" (id),
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(user_id, project_id)
+);
+CREATE TABLE project_retention (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    project_id INTEGER NOT NULL UNIQUE,
+    reports INTEGER,
+    archived_branches INTEGER,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+CREATE TABLE version (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    number INTEGER NOT NULL,
+    hash TEXT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+CREATE TABLE testbed (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    archived BIGINT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+CREATE TABLE benchmark (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    archived BIGINT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+CREATE TABLE benchmark_tag (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    benchmark_id INTEGER NOT NULL,
+    key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    FOREIGN KEY (benchmark_id) REFERENCES benchmark (id) ON DELETE CASCADE,
+    UNIQUE(benchmark_id, key)
+);
+CREATE INDEX index_benchmark_tag_key_value ON benchmark_tag(key, value);
+CREATE TABLE measure (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    units TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    archived BIGINT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+CREATE INDEX index_measure_project ON measure(uuid, project_id);
+-- The branch, head, and head version tables reference each other,
+-- so those foreign keys are added once all three tables exist.
+CREATE TABLE branch (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    head_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    archived BIGINT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    UNIQUE(project_id, name),
+    UNIQUE(project_id, slug)
+);
+CREATE TABLE head (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    branch_id INTEGER NOT NULL,
+    start_point_id INTEGER,
+    created BIGINT NOT NULL,
+    replaced BIGINT,
+    FOREIGN KEY (branch_id) REFERENCES branch (id) ON DELETE CASCADE
+);
+CREATE TABLE head_version (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    head_id INTEGER NOT NULL,
+    version_id INTEGER NOT NULL,
+    FOREIGN KEY (head_id) REFERENCES head (id) ON DELETE CASCADE,
+    FOREIGN KEY (version_id) REFERENCES version (id) ON DELETE CASCADE,
+    UNIQUE(head_id, version_id)
+);
+ALTER TABLE branch
+ADD CONSTRAINT branch_head_id_fkey FOREIGN KEY (head_id) REFERENCES head (id);
+ALTER TABLE head
+ADD CONSTRAINT head_start_point_id_fkey FOREIGN KEY (start_point_id) REFERENCES head_version (id) ON DELETE
+SET NULL;
+CREATE INDEX index_branch_head ON branch(uuid, project_id, head_id);
+-- The threshold and model tables reference each other,
+-- so that foreign key is added once both tables exist.
+CREATE TABLE threshold (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    branch_id INTEGER NOT NULL,
+    testbed_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    model_id INTEGER,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    benchmark_tags TEXT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (branch_id) REFERENCES branch (id),
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id),
+    FOREIGN KEY (measure_id) REFERENCES measure (id),
+    UNIQUE(branch_id, testbed_id, measure_id)
+);
+CREATE TABLE model (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    threshold_id INTEGER NOT NULL,
+    test INTEGER NOT NULL,
+    min_sample_size BIGINT,
+    max_sample_size BIGINT,
+    "window" BIGINT,
+    lower_boundary DOUBLE PRECISION,
+    upper_boundary DOUBLE PRECISION,
+    created BIGINT NOT NULL,
+    replaced BIGINT,
+    FOREIGN KEY (threshold_id) REFERENCES threshold (id) ON DELETE CASCADE
+);
+ALTER TABLE threshold
+ADD CONSTRAINT threshold_model_id_fkey FOREIGN KEY (model_id) REFERENCES model (id);
+CREATE TABLE report (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    user_id INTEGER,
+    project_id INTEGER NOT NULL,
+    -- Connect to the head and version individually and not to their head_version
+    -- This is necessary in order for cloned heads to work
+    -- Cloned heads will *not* have a report tied to their specific head_version
+    -- So we don't want to have to query through the head_version table
+    -- to filter on the branch and list all of the versions
+    head_id INTEGER NOT NULL,
+    version_id INTEGER NOT NULL,
+    testbed_id INTEGER NOT NULL,
+    adapter INTEGER NOT NULL,
+    start_time BIGINT NOT NULL,
+    end_time BIGINT NOT NULL,
+    created BIGINT NOT NULL,
+    group_uuid TEXT,
+    FOREIGN KEY (user_id) REFERENCES "user" (id),
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (head_id) REFERENCES head (id),
+    FOREIGN KEY (version_id) REFERENCES version (id),
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id)
+);
+CREATE INDEX index_report_testbed_end_time ON report(testbed_id, end_time);
+CREATE INDEX index_report_version ON report(version_id, end_time);
+CREATE INDEX index_report_group_uuid ON report(group_uuid);
+CREATE TABLE report_benchmark (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    report_id INTEGER NOT NULL,
+    iteration INTEGER NOT NULL,
+    benchmark_id INTEGER NOT NULL,
+    FOREIGN KEY (report_id) REFERENCES report (id) ON DELETE CASCADE,
+    FOREIGN KEY (benchmark_id) REFERENCES benchmark (id),
+    UNIQUE(report_id, iteration, benchmark_id)
+);
+CREATE INDEX index_report_benchmark ON report_benchmark(report_id, benchmark_id);
+CREATE TABLE metric (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    report_benchmark_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    value DOUBLE PRECISION NOT NULL,
+    lower_value DOUBLE PRECISION,
+    upper_value DOUBLE PRECISION,
+    FOREIGN KEY (report_benchmark_id) REFERENCES report_benchmark (id) ON DELETE CASCADE,
+    FOREIGN KEY (measure_id) REFERENCES measure (id),
+    UNIQUE(report_benchmark_id, measure_id)
+);
+CREATE TABLE boundary (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    metric_id INTEGER NOT NULL UNIQUE,
+    threshold_id INTEGER NOT NULL,
+    model_id INTEGER NOT NULL,
+    baseline DOUBLE PRECISION,
+    lower_limit DOUBLE PRECISION,
+    upper_limit DOUBLE PRECISION,
+    FOREIGN KEY (metric_id) REFERENCES metric (id) ON DELETE CASCADE,
+    FOREIGN KEY (threshold_id) REFERENCES threshold (id),
+    FOREIGN KEY (model_id) REFERENCES model (id)
+);
+CREATE VIEW metric_boundary AS
+SELECT metric.id AS metric_id,
+    metric.uuid AS metric_uuid,
+    metric.report_benchmark_id,
+    metric.measure_id,
+    metric.value,
+    metric.lower_value,
+    metric.upper_value,
+    boundary.id AS boundary_id,
+    boundary.uuid AS boundary_uuid,
+    boundary.threshold_id,
+    boundary.model_id,
+    boundary.baseline,
+    boundary.lower_limit,
+    boundary.upper_limit
+FROM metric
+    LEFT OUTER JOIN boundary ON (boundary.metric_id = metric.id);
+CREATE TABLE alert (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    boundary_id INTEGER NOT NULL,
+    boundary_limit BOOLEAN NOT NULL,
+    status INTEGER NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (boundary_id) REFERENCES boundary (id) ON DELETE CASCADE
+);
+CREATE INDEX index_alert_boundary ON alert(boundary_id);
+CREATE TABLE change_point (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    metric_id INTEGER NOT NULL,
+    threshold_id INTEGER NOT NULL,
+    model_id INTEGER NOT NULL,
+    baseline DOUBLE PRECISION NOT NULL,
+    value DOUBLE PRECISION NOT NULL,
+    created BIGINT NOT NULL,
+    FOREIGN KEY (metric_id) REFERENCES metric (id) ON DELETE CASCADE,
+    FOREIGN KEY (threshold_id) REFERENCES threshold (id) ON DELETE CASCADE,
+    FOREIGN KEY (model_id) REFERENCES model (id) ON DELETE CASCADE,
+    UNIQUE(metric_id, threshold_id)
+);
+CREATE TABLE plot (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    rank BIGINT NOT NULL,
+    title TEXT,
+    lower_value BOOLEAN NOT NULL,
+    upper_value BOOLEAN NOT NULL,
+    lower_boundary BOOLEAN NOT NULL,
+    upper_boundary BOOLEAN NOT NULL,
+    x_axis INTEGER NOT NULL,
+    "window" BIGINT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    benchmark_tags TEXT,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+CREATE TABLE plot_branch (
+    plot_id INTEGER NOT NULL,
+    branch_id INTEGER NOT NULL,
+    rank BIGINT NOT NULL,
+    FOREIGN KEY (plot_id) REFERENCES plot (id) ON DELETE CASCADE,
+    FOREIGN KEY (branch_id) REFERENCES branch (id) ON DELETE CASCADE,
+    PRIMARY KEY (plot_id, branch_id)
+);
+CREATE TABLE plot_testbed (
+    plot_id INTEGER NOT NULL,
+    testbed_id INTEGER NOT NULL,
+    rank BIGINT NOT NULL,
+    FOREIGN KEY (plot_id) REFERENCES plot (id) ON DELETE CASCADE,
+    FOREIGN KEY (testbed_id) REFERENCES testbed (id) ON DELETE CASCADE,
+    PRIMARY KEY (plot_id, testbed_id)
+);
+CREATE TABLE plot_benchmark (
+    plot_id INTEGER NOT NULL,
+    benchmark_id INTEGER NOT NULL,
+    rank BIGINT NOT NULL,
+    FOREIGN KEY (plot_id) REFERENCES plot (id) ON DELETE CASCADE,
+    FOREIGN KEY (benchmark_id) REFERENCES benchmark (id) ON DELETE CASCADE,
+    PRIMARY KEY (plot_id, benchmark_id)
+);
+CREATE TABLE plot_measure (
+    plot_id INTEGER NOT NULL,
+    measure_id INTEGER NOT NULL,
+    rank BIGINT NOT NULL,
+    FOREIGN KEY (plot_id) REFERENCES plot (id) ON DELETE CASCADE,
+    FOREIGN KEY (measure_id) REFERENCES measure (id) ON DELETE CASCADE,
+    PRIMARY KEY (plot_id, measure_id)
+);
+CREATE TABLE sso (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    organization_id INTEGER NOT NULL,
+    domain TEXT NOT NULL UNIQUE,
+    created BIGINT NOT NULL,
+    oidc BOOLEAN NOT NULL DEFAULT FALSE,
+    FOREIGN KEY (organization_id) REFERENCES organization (id) ON DELETE CASCADE
+);
+CREATE TABLE webhook (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    project_id INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE
+);
+CREATE TABLE audit_event (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    user_uuid TEXT NOT NULL,
+    credential INTEGER NOT NULL,
+    token_uuid TEXT,
+    action INTEGER NOT NULL,
+    resource INTEGER NOT NULL,
+    resource_uuid TEXT,
+    organization_uuid TEXT,
+    project_uuid TEXT,
+    before TEXT,
+    after TEXT,
+    created BIGINT NOT NULL
+);
+CREATE INDEX index_audit_event_created ON audit_event(created);
+CREATE INDEX index_audit_event_organization ON audit_event(organization_uuid, created);
+CREATE INDEX index_audit_event_project ON audit_event(project_uuid, created);
+CREATE FUNCTION audit_event_append_only() RETURNS TRIGGER AS $$ BEGIN RAISE EXCEPTION 'audit_event is append-only';
+END;
+$$ LANGUAGE plpgsql;
+CREATE TRIGGER audit_event_no_update BEFORE
+UPDATE ON audit_event FOR EACH ROW EXECUTE FUNCTION audit_event_append_only();
+CREATE TRIGGER audit_event_no_delete BEFORE DELETE ON audit_event FOR EACH ROW EXECUTE FUNCTION audit_event_append_only();
+CREATE TABLE custom_role (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    uuid TEXT NOT NULL UNIQUE,
+    organization_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    permissions TEXT NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (organization_id) REFERENCES organization (id) ON DELETE CASCADE,
+    UNIQUE(organization_id, name)
+);
+CREATE TABLE project_member (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    project_id INTEGER NOT NULL,
+    role_id INTEGER NOT NULL,
+    created BIGINT NOT NULL,
+    modified BIGINT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES "user" (id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES project (id) ON DELETE CASCADE,
+    FOREIGN KEY (role_id) REFERENCES custom_role (id) ON DELETE CASCADE,
+    UNIQUE(user_id, project_id)
+);
+CREATE INDEX index_project_member_role ON project_member(role_id);
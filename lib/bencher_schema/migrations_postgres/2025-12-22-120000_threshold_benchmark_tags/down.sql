@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS index_threshold_benchmark_tags;
+ALTER TABLE threshold
+ADD CONSTRAINT threshold_branch_id_testbed_id_measure_id_key UNIQUE(branch_id, testbed_id, measure_id);
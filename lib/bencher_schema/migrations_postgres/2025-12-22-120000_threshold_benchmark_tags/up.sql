@@ -0,0 +1,4 @@
+ALTER TABLE threshold DROP CONSTRAINT threshold_branch_id_testbed_id_measure_id_key;
+-- There can be at most one threshold without benchmark tags
+-- and one threshold per set of benchmark tag selectors.
+CREATE UNIQUE INDEX index_threshold_benchmark_tags ON threshold(branch_id, testbed_id, measure_id, COALESCE(benchmark_tags, ''));
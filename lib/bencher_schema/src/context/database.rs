@@ -1,20 +1,120 @@
+#![expect(
+    clippy::field_scoped_visibility_modifiers,
+    reason = "generated by diesel::MultiConnection"
+)]
+
 use std::{
+    fmt,
     path::{Path, PathBuf},
     str::FromStr,
     sync::Arc,
 };
 
-use bencher_json::{Secret, system::config::DataStore as DataStoreConfig};
+use bencher_json::{
+    Secret,
+    project::{head::VersionNumber, report::Iteration},
+    system::config::DataStore as DataStoreConfig,
+};
 use camino::Utf8PathBuf;
 
-pub type DbConnection = diesel::SqliteConnection;
+// The API server can use either SQLite or PostgreSQL for its database.
+// Queries are written once against the common subset of both backends.
+#[derive(diesel::MultiConnection)]
+pub enum DbConnection {
+    Sqlite(diesel::SqliteConnection),
+    Postgres(diesel::PgConnection),
+}
+
+pub type DbBackend = <DbConnection as diesel::Connection>::Backend;
+
+// Values that are converted before they are bound can not be borrowed by the multi-backend,
+// so they are bound as-is and then converted by the concrete backend.
+macro_rules! multi_backend_to_sql {
+    ($sql_type:path => $($name:ty),+ $(,)?) => {
+        $(
+            impl diesel::serialize::ToSql<$sql_type, DbBackend> for $name {
+                fn to_sql<'b>(
+                    &'b self,
+                    out: &mut diesel::serialize::Output<'b, '_, DbBackend>,
+                ) -> diesel::serialize::Result {
+                    out.set_value(($sql_type, self));
+                    Ok(diesel::serialize::IsNull::No)
+                }
+            }
+        )+
+    };
+}
+
+multi_backend_to_sql!(diesel::sql_types::BigInt => bencher_json::DateTime, bencher_json::SampleSize, bencher_json::Window);
+multi_backend_to_sql!(diesel::sql_types::Integer => Iteration, VersionNumber);
+multi_backend_to_sql!(
+    diesel::sql_types::Text =>
+    bencher_json::Search,
+    bencher_json::TagSelectors,
+    bencher_json::AlertUuid,
+    bencher_json::AuditEventUuid,
+    bencher_json::BenchmarkUuid,
+    bencher_json::BoundaryUuid,
+    bencher_json::BranchUuid,
+    bencher_json::ChangePointUuid,
+    bencher_json::HeadUuid,
+    bencher_json::MeasureUuid,
+    bencher_json::MetricUuid,
+    bencher_json::ModelUuid,
+    bencher_json::OrganizationUuid,
+    bencher_json::PlotUuid,
+    bencher_json::ProjectUuid,
+    bencher_json::ReportBenchmarkUuid,
+    bencher_json::ReportGroupUuid,
+    bencher_json::ReportUuid,
+    bencher_json::RoleUuid,
+    bencher_json::ServerUuid,
+    bencher_json::SsoUuid,
+    bencher_json::TestbedUuid,
+    bencher_json::ThresholdUuid,
+    bencher_json::TokenUuid,
+    bencher_json::UserUuid,
+    bencher_json::VersionUuid,
+    bencher_json::WebhookUuid,
+);
 
 pub struct Database {
-    pub path: PathBuf,
+    pub url: DatabaseUrl,
     pub connection: Arc<tokio::sync::Mutex<DbConnection>>,
     pub data_store: Option<DataStore>,
 }
 
+#[derive(Debug, Clone)]
+pub enum DatabaseUrl {
+    Sqlite(PathBuf),
+    Postgres(Secret),
+}
+
+impl DatabaseUrl {
+    pub fn establish(&self) -> diesel::ConnectionResult<DbConnection> {
+        use diesel::Connection as _;
+
+        // Connect to the backend explicitly instead of guessing from the URL.
+        Ok(match self {
+            Self::Sqlite(path) => DbConnection::Sqlite(diesel::SqliteConnection::establish(
+                &path.to_string_lossy(),
+            )?),
+            Self::Postgres(url) => {
+                DbConnection::Postgres(diesel::PgConnection::establish(url.as_ref())?)
+            },
+        })
+    }
+}
+
+impl fmt::Display for DatabaseUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sqlite(path) => write!(f, "{}", path.display()),
+            Self::Postgres(_) => write!(f, "PostgreSQL"),
+        }
+    }
+}
+
 pub enum DataStore {
     AwsS3(AwsS3),
 }
@@ -6,6 +6,8 @@ use bencher_github_client::GitHubClient;
 use bencher_google_client::GoogleClient;
 #[cfg(feature = "plus")]
 use bencher_license::Licensor;
+#[cfg(feature = "plus")]
+use bencher_oidc_client::OidcClient;
 use bencher_token::TokenKey;
 use tokio::sync::mpsc::Sender;
 use url::Url;
@@ -24,7 +26,7 @@ mod stats;
 
 #[cfg(feature = "plus")]
 use bencher_recaptcha::RecaptchaClient;
-pub use database::{DataStore, DataStoreError, Database, DbConnection};
+pub use database::{DataStore, DataStoreError, Database, DatabaseUrl, DbBackend, DbConnection};
 #[cfg(feature = "plus")]
 pub use indexer::{IndexError, Indexer};
 #[cfg(feature = "plus")]
@@ -32,7 +34,7 @@ pub use messenger::ServerStatsBody;
 pub use messenger::{Body, ButtonBody, Email, Message, Messenger, NewUserBody};
 #[cfg(feature = "plus")]
 pub use rate_limiting::{HeaderMap, RateLimiting, RateLimitingError};
-pub use rbac::{Rbac, RbacError};
+pub use rbac::{Rbac, RbacError, RbacScope};
 #[cfg(feature = "plus")]
 pub use stats::StatsSettings;
 
@@ -50,6 +52,8 @@ pub struct ApiContext {
     #[cfg(feature = "plus")]
     pub google_client: Option<GoogleClient>,
     #[cfg(feature = "plus")]
+    pub oidc_client: Option<OidcClient>,
+    #[cfg(feature = "plus")]
     pub indexer: Option<Indexer>,
     #[cfg(feature = "plus")]
     pub stats: StatsSettings,
@@ -1,8 +1,11 @@
-use bencher_json::Sanitize as _;
+use bencher_json::{Sanitize as _, TokenPermission};
 use bencher_rbac::{Organization, Project};
 use oso::{Oso, ToPolar};
 
-use crate::model::user::auth::AuthUser;
+use crate::model::{
+    organization::OrganizationId,
+    user::auth::{AuthUser, OrgProjectId},
+};
 
 pub struct Rbac(pub Oso);
 
@@ -30,6 +33,93 @@ pub enum RbacError {
         permission: bencher_rbac::project::Permission,
         project: Project,
     },
+    #[error(
+        "Permission ({permission}) is outside of the API token scope ({scope:?}) on organization ({organization:?})"
+    )]
+    ScopeOrganization {
+        scope: Box<RbacScope>,
+        permission: bencher_rbac::organization::Permission,
+        organization: Organization,
+    },
+    #[error(
+        "Permission ({permission}) is outside of the API token scope ({scope:?}) on project ({project:?})"
+    )]
+    ScopeProject {
+        scope: Box<RbacScope>,
+        permission: bencher_rbac::project::Permission,
+        project: Project,
+    },
+}
+
+/// The scope of an API token, resolved to the organizations and projects that exist on this server.
+/// Any organization or project in the token scope that does not exist is ignored.
+#[derive(Debug, Clone)]
+pub struct RbacScope {
+    pub organizations: Option<Vec<OrganizationId>>,
+    pub projects: Option<Vec<OrgProjectId>>,
+    pub permission: Option<TokenPermission>,
+}
+
+impl RbacScope {
+    fn is_allowed_organization(
+        &self,
+        permission: bencher_rbac::organization::Permission,
+        organization: &Organization,
+    ) -> bool {
+        use bencher_rbac::organization::Permission;
+
+        let in_organizations = self.organizations.as_ref().is_none_or(|organizations| {
+            organizations
+                .iter()
+                .any(|org_id| org_id.to_string() == organization.id)
+        });
+        // A token that is restricted to projects can only view the organizations for those projects.
+        let in_projects = self.projects.as_ref().is_none_or(|projects| {
+            matches!(permission, Permission::View)
+                && projects
+                    .iter()
+                    .any(|project| project.org_id.to_string() == organization.id)
+        });
+        let below_ceiling = match self.permission {
+            None => true,
+            Some(TokenPermission::Read | TokenPermission::Report) => {
+                matches!(permission, Permission::View | Permission::ViewRole)
+            },
+        };
+
+        in_organizations && in_projects && below_ceiling
+    }
+
+    fn is_allowed_project(
+        &self,
+        permission: bencher_rbac::project::Permission,
+        project: &Project,
+    ) -> bool {
+        use bencher_rbac::project::Permission;
+
+        let in_organizations = self.organizations.as_ref().is_none_or(|organizations| {
+            organizations
+                .iter()
+                .any(|org_id| org_id.to_string() == project.organization_id)
+        });
+        let in_projects = self.projects.as_ref().is_none_or(|projects| {
+            projects
+                .iter()
+                .any(|org_project| org_project.project_id.to_string() == project.id)
+        });
+        let below_ceiling = match self.permission {
+            None => true,
+            Some(TokenPermission::Read) => {
+                matches!(permission, Permission::View | Permission::ViewRole)
+            },
+            Some(TokenPermission::Report) => matches!(
+                permission,
+                Permission::View | Permission::ViewRole | Permission::CreateReport
+            ),
+        };
+
+        in_organizations && in_projects && below_ceiling
+    }
 }
 
 impl Rbac {
@@ -82,6 +172,15 @@ impl Rbac {
         O: Into<Organization>,
     {
         let organization = organization.into();
+        if let Some(scope) = auth_user.scope.as_ref()
+            && !scope.is_allowed_organization(permission, &organization)
+        {
+            return Err(RbacError::ScopeOrganization {
+                scope: Box::new(scope.clone()),
+                permission,
+                organization,
+            });
+        }
         self.is_allowed_unwrap(auth_user, permission, organization.clone())
             .then_some(())
             .ok_or_else(|| {
@@ -105,6 +204,15 @@ impl Rbac {
         P: Into<Project>,
     {
         let project = project.into();
+        if let Some(scope) = auth_user.scope.as_ref()
+            && !scope.is_allowed_project(permission, &project)
+        {
+            return Err(RbacError::ScopeProject {
+                scope: Box::new(scope.clone()),
+                permission,
+                project,
+            });
+        }
         self.is_allowed_unwrap(auth_user, permission, project.clone())
             .then_some(())
             .ok_or_else(|| {
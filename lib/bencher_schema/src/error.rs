@@ -10,8 +10,11 @@ pub const BEARER_TOKEN_FORMAT: &str = "Expected format is `Authorization: Bearer
 pub enum BencherResource {
     Organization,
     OrganizationRole,
+    CustomRole,
     Project,
     ProjectRole,
+    ProjectMember,
+    ProjectRetention,
     Report,
     ReportBenchmark,
     Plot,
@@ -25,14 +28,18 @@ pub enum BencherResource {
     HeadVersion,
     Testbed,
     Benchmark,
+    BenchmarkTag,
     Measure,
     Metric,
     Threshold,
     Model,
     Boundary,
     Alert,
+    ChangePoint,
+    Webhook,
     User,
     Token,
+    AuditEvent,
     #[cfg(feature = "plus")]
     Plan,
     #[cfg(feature = "plus")]
@@ -49,8 +56,11 @@ impl fmt::Display for BencherResource {
             match self {
                 Self::Organization => "Organization",
                 Self::OrganizationRole => "Organization Role",
+                Self::CustomRole => "Custom Role",
                 Self::Project => "Project",
                 Self::ProjectRole => "Project Role",
+                Self::ProjectMember => "Project Member",
+                Self::ProjectRetention => "Project Retention",
                 Self::Report => "Report",
                 Self::ReportBenchmark => "Report Benchmark",
                 Self::Plot => "Plot",
@@ -64,14 +74,18 @@ impl fmt::Display for BencherResource {
                 Self::HeadVersion => "Head Version",
                 Self::Testbed => "Testbed",
                 Self::Benchmark => "Benchmark",
+                Self::BenchmarkTag => "Benchmark Tag",
                 Self::Measure => "Measure",
                 Self::Metric => "Metric",
                 Self::Threshold => "Threshold",
                 Self::Model => "Model",
                 Self::Boundary => "Boundary",
                 Self::Alert => "Alert",
+                Self::ChangePoint => "Change Point",
+                Self::Webhook => "Webhook",
                 Self::User => "User",
                 Self::Token => "Token",
+                Self::AuditEvent => "Audit Event",
                 #[cfg(feature = "plus")]
                 Self::Plan => "Plan",
                 #[cfg(feature = "plus")]
@@ -184,6 +198,20 @@ where
     ))
 }
 
+pub fn bad_gateway_error<E>(error: E) -> HttpError
+where
+    E: fmt::Display,
+{
+    let message = error.to_string();
+    cors_headers(HttpError {
+        status_code: ErrorStatusCode::BAD_GATEWAY,
+        error_code: None,
+        external_message: message.clone(),
+        internal_message: message,
+        headers: None,
+    })
+}
+
 pub fn resource_not_found_error<V, E>(resource: BencherResource, value: V, error: E) -> HttpError
 where
     V: fmt::Debug,
@@ -13,6 +13,7 @@ pub use context::ApiContext;
 pub use context::{HeaderMap, RateLimiting};
 
 const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+const POSTGRES_MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations_postgres");
 
 // TODO Custom max TTL
 pub const INVITE_TOKEN_TTL: u32 = u32::MAX;
@@ -29,6 +30,16 @@ pub enum MigrationError {
 }
 
 pub fn run_migrations(database: &mut context::DbConnection) -> Result<(), MigrationError> {
+    match database {
+        context::DbConnection::Sqlite(database) => run_sqlite_migrations(database),
+        context::DbConnection::Postgres(database) => database
+            .run_pending_migrations(POSTGRES_MIGRATIONS)
+            .map(|_| ())
+            .map_err(MigrationError::Migrations),
+    }
+}
+
+fn run_sqlite_migrations(database: &mut diesel::SqliteConnection) -> Result<(), MigrationError> {
     // It is not possible to enable or disable foreign key constraints in the middle of a multi-statement transaction
     // (when SQLite is not in autocommit mode).
     // Attempting to do so does not return an error; it simply has no effect.
@@ -48,3 +59,118 @@ pub fn run_migrations(database: &mut context::DbConnection) -> Result<(), Migrat
 
     Ok(())
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::BTreeSet;
+
+    use diesel::{
+        Connection as _, QueryableByName, RunQueryDsl as _, connection::SimpleConnection as _,
+        sql_types::Text,
+    };
+
+    use crate::{context::DbConnection, run_migrations};
+
+    // Set this environment variable to a PostgreSQL connection URL
+    // to also run the database tests against PostgreSQL.
+    pub(crate) const TEST_POSTGRES_URL: &str = "BENCHER_TEST_POSTGRES_URL";
+
+    /// A migrated Postgres database in its own schema, if a test server is configured.
+    pub(crate) fn postgres_database() -> Option<DbConnection> {
+        let url = std::env::var(TEST_POSTGRES_URL).ok()?;
+        let mut conn = DbConnection::Postgres(diesel::PgConnection::establish(&url).unwrap());
+        let schema = format!("test_{}", uuid::Uuid::new_v4().simple());
+        conn.batch_execute(&format!(
+            "CREATE SCHEMA {schema}; SET search_path TO {schema};"
+        ))
+        .unwrap();
+        run_migrations(&mut conn).unwrap();
+        Some(conn)
+    }
+
+    #[derive(QueryableByName)]
+    struct TableColumn {
+        #[diesel(sql_type = Text)]
+        table_name: String,
+        #[diesel(sql_type = Text)]
+        column_name: String,
+    }
+
+    fn table_columns(conn: &mut DbConnection) -> BTreeSet<(String, String)> {
+        let query = match conn {
+            DbConnection::Sqlite(_) => {
+                "SELECT m.name AS table_name, p.name AS column_name FROM sqlite_master m JOIN pragma_table_info(m.name) p WHERE m.type IN ('table', 'view') AND m.name NOT LIKE 'sqlite_%'"
+            },
+            DbConnection::Postgres(_) => {
+                "SELECT table_name::TEXT AS table_name, column_name::TEXT AS column_name FROM information_schema.columns WHERE table_schema = current_schema()"
+            },
+        };
+        diesel::sql_query(query)
+            .load::<TableColumn>(conn)
+            .unwrap()
+            .into_iter()
+            .filter(|table_column| table_column.table_name != "__diesel_schema_migrations")
+            .map(|table_column| (table_column.table_name, table_column.column_name))
+            .collect()
+    }
+
+    #[test]
+    fn postgres_migrations_match_sqlite() {
+        let Some(mut postgres) = postgres_database() else {
+            return;
+        };
+        let mut sqlite = DbConnection::establish(":memory:").unwrap();
+        run_migrations(&mut sqlite).unwrap();
+
+        let sqlite_columns = table_columns(&mut sqlite);
+        assert!(!sqlite_columns.is_empty());
+        assert_eq!(sqlite_columns, table_columns(&mut postgres));
+    }
+
+    #[test]
+    fn threshold_benchmark_tags_unique() {
+        let mut sqlite = DbConnection::establish(":memory:").unwrap();
+        run_migrations(&mut sqlite).unwrap();
+        // Skip the foreign key checks, as only the threshold uniqueness is under test.
+        sqlite.batch_execute("PRAGMA foreign_keys = OFF").unwrap();
+        assert_threshold_benchmark_tags_unique(&mut sqlite);
+
+        if let Some(mut postgres) = postgres_database() {
+            postgres
+                .batch_execute("SET session_replication_role = replica")
+                .unwrap();
+            assert_threshold_benchmark_tags_unique(&mut postgres);
+        }
+    }
+
+    fn assert_threshold_benchmark_tags_unique(conn: &mut DbConnection) {
+        let mut insert = |uuid: u8, benchmark_tags: &str| {
+            conn.batch_execute(&format!(
+                "INSERT INTO threshold (uuid, project_id, branch_id, testbed_id, measure_id, created, modified, benchmark_tags) VALUES ('{uuid}', 1, 1, 1, 1, 0, 0, {benchmark_tags})"
+            ))
+        };
+        assert!(insert(1, "NULL").is_ok());
+        assert!(insert(2, "NULL").is_err());
+        assert!(insert(3, "'suite=io'").is_ok());
+        assert!(insert(4, "'suite=io'").is_err());
+        assert!(insert(5, "'os=linux,suite=io'").is_ok());
+    }
+
+    #[test]
+    fn postgres_audit_event_append_only() {
+        let Some(mut postgres) = postgres_database() else {
+            return;
+        };
+        postgres
+            .batch_execute(
+                "INSERT INTO audit_event (uuid, user_uuid, credential, action, resource, created) VALUES ('00000000-0000-4000-8000-000000000000', '00000000-0000-4000-8000-000000000001', 0, 0, 0, 0)",
+            )
+            .unwrap();
+        assert!(
+            postgres
+                .batch_execute("UPDATE audit_event SET created = 1")
+                .is_err()
+        );
+        assert!(postgres.batch_execute("DELETE FROM audit_event").is_err());
+    }
+}
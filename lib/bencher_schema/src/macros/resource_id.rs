@@ -5,7 +5,7 @@ macro_rules! fn_eq_resource_id {
         ) -> Box<
             dyn diesel::BoxableExpression<
                     $crate::schema::$table::table,
-                    diesel::sqlite::Sqlite,
+                    $crate::context::DbBackend,
                     SqlType = diesel::sql_types::Bool,
                 >,
         > {
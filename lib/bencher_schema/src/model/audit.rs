@@ -0,0 +1,307 @@
+use bencher_json::{
+    AuditAction, AuditCredential, AuditEventUuid, AuditEventsSort, AuditResource, DateTime,
+    JsonAuditEvent, JsonAuditEvents, JsonAuditQuery, JsonDirection, JsonPagination,
+    OrganizationUuid, ProjectUuid, TokenUuid, UserUuid,
+};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+use serde::Serialize;
+
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    schema::{self, audit_event as audit_event_table},
+};
+
+use super::{
+    organization::QueryOrganization,
+    project::QueryProject,
+    user::auth::{AuthCredential, AuthUser},
+};
+
+crate::macros::typed_id::typed_id!(AuditEventId);
+
+pub type AuditEventsPagination = JsonPagination<AuditEventsSort>;
+
+/// The level at which audit events are queried.
+#[derive(Debug, Clone, Copy)]
+pub enum AuditScope {
+    /// All audit events on the server.
+    Server,
+    /// Audit events for an organization and all of its projects.
+    Organization(OrganizationUuid),
+    /// Audit events for a single project.
+    Project(ProjectUuid),
+}
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Selectable)]
+#[diesel(table_name = audit_event_table)]
+pub struct QueryAuditEvent {
+    pub id: AuditEventId,
+    pub uuid: AuditEventUuid,
+    pub user_uuid: UserUuid,
+    pub credential: AuditCredential,
+    pub token_uuid: Option<TokenUuid>,
+    pub action: AuditAction,
+    pub resource: AuditResource,
+    pub resource_uuid: Option<String>,
+    pub organization_uuid: Option<OrganizationUuid>,
+    pub project_uuid: Option<ProjectUuid>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub created: DateTime,
+}
+
+impl QueryAuditEvent {
+    pub fn list(
+        conn: &mut DbConnection,
+        scope: AuditScope,
+        pagination_params: &AuditEventsPagination,
+        query_params: &JsonAuditQuery,
+    ) -> Result<(JsonAuditEvents, i64), HttpError> {
+        let audit_events =
+            Self::order_list_query(Self::list_query(scope, query_params), pagination_params)
+                .offset(pagination_params.offset())
+                .limit(pagination_params.limit())
+                .load::<Self>(conn)
+                .map_err(resource_not_found_err!(
+                    AuditEvent,
+                    (scope, pagination_params, query_params)
+                ))?;
+        let total_count = Self::list_query(scope, query_params)
+            .count()
+            .get_result::<i64>(conn)
+            .map_err(resource_not_found_err!(
+                AuditEvent,
+                (scope, pagination_params, query_params)
+            ))?;
+
+        let json_audit_events = audit_events.into_iter().map(Self::into_json).collect();
+        Ok((json_audit_events, total_count))
+    }
+
+    fn list_query(
+        scope: AuditScope,
+        query_params: &JsonAuditQuery,
+    ) -> schema::audit_event::BoxedQuery<'static, crate::context::DbBackend> {
+        let mut query = schema::audit_event::table.into_boxed();
+
+        match scope {
+            AuditScope::Server => {},
+            AuditScope::Organization(organization_uuid) => {
+                query = query.filter(schema::audit_event::organization_uuid.eq(organization_uuid));
+            },
+            AuditScope::Project(project_uuid) => {
+                query = query.filter(schema::audit_event::project_uuid.eq(project_uuid));
+            },
+        }
+
+        if let Some(user) = query_params.user {
+            query = query.filter(schema::audit_event::user_uuid.eq(user));
+        }
+        if let Some(action) = query_params.action {
+            query = query.filter(schema::audit_event::action.eq(action));
+        }
+        if let Some(resource) = query_params.resource {
+            query = query.filter(schema::audit_event::resource.eq(resource));
+        }
+        if let Some(resource_uuid) = query_params.resource_uuid {
+            query = query.filter(schema::audit_event::resource_uuid.eq(resource_uuid.to_string()));
+        }
+        if let Some(start_time) = query_params.start_time {
+            query = query.filter(schema::audit_event::created.ge(DateTime::from(start_time)));
+        }
+        if let Some(end_time) = query_params.end_time {
+            query = query.filter(schema::audit_event::created.le(DateTime::from(end_time)));
+        }
+
+        query
+    }
+
+    // The ordering is kept separate from the filters,
+    // because PostgreSQL does not allow ordering the total count query.
+    fn order_list_query(
+        query: schema::audit_event::BoxedQuery<'static, crate::context::DbBackend>,
+        pagination_params: &AuditEventsPagination,
+    ) -> schema::audit_event::BoxedQuery<'static, crate::context::DbBackend> {
+        match pagination_params.order() {
+            AuditEventsSort::Created => match pagination_params.direction {
+                Some(JsonDirection::Asc) => query.order((
+                    schema::audit_event::created.asc(),
+                    schema::audit_event::id.asc(),
+                )),
+                Some(JsonDirection::Desc) | None => query.order((
+                    schema::audit_event::created.desc(),
+                    schema::audit_event::id.desc(),
+                )),
+            },
+        }
+    }
+
+    pub fn into_json(self) -> JsonAuditEvent {
+        let Self {
+            uuid,
+            user_uuid,
+            credential,
+            token_uuid,
+            action,
+            resource,
+            resource_uuid,
+            organization_uuid,
+            project_uuid,
+            before,
+            after,
+            created,
+            ..
+        } = self;
+        let resource_uuid = resource_uuid.and_then(|resource_uuid| {
+            resource_uuid
+                .parse()
+                .inspect_err(|e| {
+                    debug_assert!(
+                        false,
+                        "Invalid audit event resource UUID ({resource_uuid}): {e}"
+                    );
+                })
+                .ok()
+        });
+        JsonAuditEvent {
+            uuid,
+            user: user_uuid,
+            credential,
+            token: token_uuid,
+            action,
+            resource,
+            resource_uuid,
+            organization: organization_uuid,
+            project: project_uuid,
+            before: before.and_then(|before| serde_json::from_str(&before).ok()),
+            after: after.and_then(|after| serde_json::from_str(&after).ok()),
+            created,
+        }
+    }
+}
+
+/// An append-only record of a change made by a user.
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = audit_event_table)]
+pub struct InsertAuditEvent {
+    pub uuid: AuditEventUuid,
+    pub user_uuid: UserUuid,
+    pub credential: AuditCredential,
+    pub token_uuid: Option<TokenUuid>,
+    pub action: AuditAction,
+    pub resource: AuditResource,
+    pub resource_uuid: Option<String>,
+    pub organization_uuid: Option<OrganizationUuid>,
+    pub project_uuid: Option<ProjectUuid>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub created: DateTime,
+}
+
+impl InsertAuditEvent {
+    /// An audit event for the server itself, such as a config update or backup.
+    pub fn server(auth_user: &AuthUser, action: AuditAction) -> Self {
+        let (credential, token_uuid) = match auth_user.credential {
+            AuthCredential::Session => (AuditCredential::Session, None),
+            AuthCredential::ApiKey(token_uuid) => (AuditCredential::ApiKey, token_uuid),
+        };
+        Self {
+            uuid: AuditEventUuid::new(),
+            user_uuid: auth_user.user.uuid,
+            credential,
+            token_uuid,
+            action,
+            resource: AuditResource::Server,
+            resource_uuid: None,
+            organization_uuid: None,
+            project_uuid: None,
+            before: None,
+            after: None,
+            created: DateTime::now(),
+        }
+    }
+
+    /// An audit event for a resource that does not belong to an organization, such as a user.
+    pub fn user<U>(
+        auth_user: &AuthUser,
+        action: AuditAction,
+        resource: AuditResource,
+        resource_uuid: U,
+    ) -> Self
+    where
+        U: Into<uuid::Uuid>,
+    {
+        Self {
+            resource,
+            resource_uuid: Some(resource_uuid.into().to_string()),
+            ..Self::server(auth_user, action)
+        }
+    }
+
+    /// An audit event for a resource that belongs to an organization.
+    pub fn organization<U>(
+        auth_user: &AuthUser,
+        query_organization: &QueryOrganization,
+        action: AuditAction,
+        resource: AuditResource,
+        resource_uuid: U,
+    ) -> Self
+    where
+        U: Into<uuid::Uuid>,
+    {
+        Self {
+            organization_uuid: Some(query_organization.uuid),
+            ..Self::user(auth_user, action, resource, resource_uuid)
+        }
+    }
+
+    /// An audit event for a resource that belongs to a project.
+    pub fn project<U>(
+        conn: &mut DbConnection,
+        auth_user: &AuthUser,
+        query_project: &QueryProject,
+        action: AuditAction,
+        resource: AuditResource,
+        resource_uuid: U,
+    ) -> Result<Self, HttpError>
+    where
+        U: Into<uuid::Uuid>,
+    {
+        let organization_uuid = QueryOrganization::get_uuid(conn, query_project.organization_id)?;
+        Ok(Self {
+            organization_uuid: Some(organization_uuid),
+            project_uuid: Some(query_project.uuid),
+            ..Self::user(auth_user, action, resource, resource_uuid)
+        })
+    }
+
+    /// Record the state of the resource before the change.
+    #[must_use]
+    pub fn before<T>(mut self, json: &T) -> Self
+    where
+        T: Serialize,
+    {
+        self.before = serde_json::to_string(json).ok();
+        self
+    }
+
+    /// Record the state of the resource after the change.
+    #[must_use]
+    pub fn after<T>(mut self, json: &T) -> Self
+    where
+        T: Serialize,
+    {
+        self.after = serde_json::to_string(json).ok();
+        self
+    }
+
+    pub fn insert(self, conn: &mut DbConnection) -> Result<(), HttpError> {
+        diesel::insert_into(schema::audit_event::table)
+            .values(&self)
+            .execute(conn)
+            .map_err(resource_conflict_err!(AuditEvent, &self))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use bencher_json::{
+    DateTime, JsonNewRole, JsonRole, JsonUpdateRole, ResourceName, RoleUuid,
+    project::ProjectPermission,
+};
+use diesel::{BelongingToDsl as _, ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+
+use super::{OrganizationId, QueryOrganization};
+use crate::{
+    context::DbConnection,
+    error::{
+        BencherResource, assert_parentage, bad_request_error, issue_error, resource_not_found_err,
+    },
+    macros::fn_get::{fn_get, fn_get_id, fn_get_uuid},
+    model::project::project_role::Permission,
+    schema::custom_role as custom_role_table,
+};
+
+crate::macros::typed_id::typed_id!(CustomRoleId);
+
+/// An organization-defined role, made up of a set of project permissions.
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = custom_role_table)]
+#[diesel(belongs_to(QueryOrganization, foreign_key = organization_id))]
+pub struct QueryCustomRole {
+    pub id: CustomRoleId,
+    pub uuid: RoleUuid,
+    pub organization_id: OrganizationId,
+    pub name: ResourceName,
+    pub permissions: String,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryCustomRole {
+    fn_get!(custom_role, CustomRoleId);
+    fn_get_id!(custom_role, CustomRoleId, RoleUuid);
+    fn_get_uuid!(custom_role, CustomRoleId, RoleUuid);
+
+    pub fn get_with_uuid(
+        conn: &mut DbConnection,
+        query_organization: &QueryOrganization,
+        uuid: RoleUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(&query_organization)
+            .filter(custom_role_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(
+                CustomRole,
+                (query_organization, uuid)
+            ))
+    }
+
+    pub fn project_permissions(&self) -> Result<Vec<ProjectPermission>, HttpError> {
+        parse_permissions(&self.permissions)
+    }
+
+    /// The permissions granted by the role, for use in the RBAC policy.
+    pub fn rbac_permissions(
+        permissions: &str,
+    ) -> Result<Vec<bencher_rbac::project::Permission>, HttpError> {
+        parse_permissions(permissions).map(|permissions| {
+            permissions
+                .into_iter()
+                .map(|permission| Permission::from(permission).into())
+                .collect()
+        })
+    }
+
+    pub fn into_json_for_organization(
+        self,
+        query_organization: &QueryOrganization,
+    ) -> Result<JsonRole, HttpError> {
+        assert_parentage(
+            BencherResource::Organization,
+            query_organization.id,
+            BencherResource::CustomRole,
+            self.organization_id,
+        );
+        let permissions = self.project_permissions()?;
+        let Self {
+            uuid,
+            name,
+            created,
+            modified,
+            ..
+        } = self;
+        Ok(JsonRole {
+            uuid,
+            organization: query_organization.uuid,
+            name,
+            permissions,
+            created,
+            modified,
+        })
+    }
+}
+
+#[derive(Debug, Clone, diesel::Insertable)]
+#[diesel(table_name = custom_role_table)]
+pub struct InsertCustomRole {
+    pub uuid: RoleUuid,
+    pub organization_id: OrganizationId,
+    pub name: ResourceName,
+    pub permissions: String,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertCustomRole {
+    pub fn from_json(
+        organization_id: OrganizationId,
+        role: JsonNewRole,
+    ) -> Result<Self, HttpError> {
+        let JsonNewRole { name, permissions } = role;
+        let timestamp = DateTime::now();
+        Ok(Self {
+            uuid: RoleUuid::new(),
+            organization_id,
+            name,
+            permissions: serialize_permissions(permissions)?,
+            created: timestamp,
+            modified: timestamp,
+        })
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = custom_role_table)]
+pub struct UpdateCustomRole {
+    pub name: Option<ResourceName>,
+    pub permissions: Option<String>,
+    pub modified: DateTime,
+}
+
+impl TryFrom<JsonUpdateRole> for UpdateCustomRole {
+    type Error = HttpError;
+
+    fn try_from(update: JsonUpdateRole) -> Result<Self, Self::Error> {
+        let JsonUpdateRole { name, permissions } = update;
+        Ok(Self {
+            name,
+            permissions: permissions.map(serialize_permissions).transpose()?,
+            modified: DateTime::now(),
+        })
+    }
+}
+
+fn serialize_permissions(mut permissions: Vec<ProjectPermission>) -> Result<String, HttpError> {
+    if permissions.is_empty() {
+        return Err(bad_request_error(
+            "A custom role must have at least one permission",
+        ));
+    }
+    permissions.sort_by_key(ToString::to_string);
+    permissions.dedup_by_key(|permission| permission.to_string());
+    serde_json::to_string(&permissions).map_err(|e| {
+        issue_error(
+            "Failed to serialize custom role permissions",
+            &format!("Failed to serialize custom role permissions ({permissions:?})"),
+            e,
+        )
+    })
+}
+
+fn parse_permissions(permissions: &str) -> Result<Vec<ProjectPermission>, HttpError> {
+    serde_json::from_str(permissions).map_err(|e| {
+        issue_error(
+            "Failed to parse custom role permissions",
+            &format!("Failed to parse custom role permissions ({permissions})"),
+            e,
+        )
+    })
+}
@@ -37,9 +37,11 @@ use crate::{
 
 use super::user::QueryUser;
 
+pub mod custom_role;
 pub mod member;
 pub mod organization_role;
 pub mod plan;
+pub mod scim;
 pub mod sso;
 
 crate::macros::typed_id::typed_id!(OrganizationId);
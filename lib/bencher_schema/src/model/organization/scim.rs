@@ -0,0 +1,510 @@
+#![cfg(feature = "plus")]
+
+use bencher_json::{
+    DateTime, Email, JsonScimGroup, JsonScimNewUser, JsonScimUser, ProjectUuid, ResourceName,
+    ScimGroupId, UserUuid,
+    organization::{
+        member::OrganizationRole,
+        scim::{
+            JsonScimEmail, JsonScimMember, JsonScimMeta, JsonScimName, SCIM_GROUP_SCHEMA,
+            SCIM_USER_SCHEMA,
+        },
+    },
+    project::ProjectRole,
+};
+use diesel::{ExpressionMethods as _, OptionalExtension as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+
+use crate::{
+    context::DbConnection,
+    error::{bad_request_error, conflict_error, forbidden_error},
+    model::{
+        organization::{QueryOrganization, organization_role::InsertOrganizationRole},
+        project::{ProjectId, project_member::QueryProjectMember, project_role::InsertProjectRole},
+        user::{InsertUser, QueryUser, UserId},
+    },
+    resource_conflict_err, resource_not_found_err, schema,
+};
+
+const SCIM_USER_RESOURCE: &str = "User";
+const SCIM_GROUP_RESOURCE: &str = "Group";
+
+/// The SCIM 2.0 view of the members and roles of an organization.
+pub struct ScimOrganization<'a> {
+    pub organization: &'a QueryOrganization,
+}
+
+impl<'a> ScimOrganization<'a> {
+    pub fn new(organization: &'a QueryOrganization) -> Self {
+        Self { organization }
+    }
+
+    /// Get the IDs for all members of the organization, ordered by when they joined.
+    pub fn member_ids(&self, conn: &mut DbConnection) -> Result<Vec<UserId>, HttpError> {
+        schema::organization_role::table
+            .filter(schema::organization_role::organization_id.eq(self.organization.id))
+            .order(schema::organization_role::created.asc())
+            .select(schema::organization_role::user_id)
+            .load(conn)
+            .map_err(resource_not_found_err!(
+                OrganizationRole,
+                self.organization.id
+            ))
+    }
+
+    /// Get a member of the organization by user UUID.
+    pub fn member(&self, conn: &mut DbConnection, uuid: UserUuid) -> Result<QueryUser, HttpError> {
+        schema::user::table
+            .inner_join(schema::organization_role::table)
+            .filter(schema::user::uuid.eq(uuid))
+            .filter(schema::organization_role::organization_id.eq(self.organization.id))
+            .select(schema::user::all_columns)
+            .first(conn)
+            .map_err(resource_not_found_err!(
+                OrganizationRole,
+                (uuid, self.organization.id)
+            ))
+    }
+
+    fn organization_role(
+        &self,
+        conn: &mut DbConnection,
+        user_id: UserId,
+    ) -> Result<Option<(OrganizationRole, DateTime)>, HttpError> {
+        schema::organization_role::table
+            .filter(schema::organization_role::organization_id.eq(self.organization.id))
+            .filter(schema::organization_role::user_id.eq(user_id))
+            .select((
+                schema::organization_role::role,
+                schema::organization_role::created,
+            ))
+            .first(conn)
+            .optional()
+            .map_err(resource_not_found_err!(
+                OrganizationRole,
+                (user_id, self.organization.id)
+            ))
+    }
+
+    /// Check that the email is at one of the SSO domains for the organization.
+    /// Only users at an SSO domain can be provisioned by the organization.
+    fn check_sso_domain(&self, conn: &mut DbConnection, email: &Email) -> Result<(), HttpError> {
+        if self.is_sso_domain(conn, email)? {
+            Ok(())
+        } else {
+            Err(forbidden_error(format!(
+                "User ({email}) is not at an SSO domain for organization ({organization})",
+                organization = self.organization.uuid
+            )))
+        }
+    }
+
+    fn is_sso_domain(&self, conn: &mut DbConnection, email: &Email) -> Result<bool, HttpError> {
+        let email_domain = email.domain();
+        schema::sso::table
+            .filter(schema::sso::organization_id.eq(self.organization.id))
+            .filter(schema::sso::domain.eq(&email_domain))
+            .count()
+            .get_result::<i64>(conn)
+            .map(|count| count > 0)
+            .map_err(resource_not_found_err!(Sso, &email_domain))
+    }
+
+    /// Provision a user as a member of the organization.
+    /// If there is no user with the given email, then a new user is created.
+    /// Returns the user and the newly created user, if any.
+    pub fn provision(
+        &self,
+        conn: &mut DbConnection,
+        json_user: &JsonScimNewUser,
+    ) -> Result<(QueryUser, Option<InsertUser>), HttpError> {
+        let email = json_user.email().ok_or_else(|| {
+            bad_request_error(format!(
+                "SCIM user ({user_name}) does not have a valid email",
+                user_name = json_user.user_name
+            ))
+        })?;
+        self.check_sso_domain(conn, &email)?;
+
+        let (query_user, insert_user) =
+            if let Ok(query_user) = QueryUser::get_with_email(conn, &email) {
+                (query_user, None)
+            } else {
+                let name = json_user
+                    .user_name()
+                    .or_else(|| email.local_part().parse().ok())
+                    .ok_or_else(|| {
+                        bad_request_error(format!("SCIM user ({email}) does not have a valid name"))
+                    })?;
+                let insert_user = InsertUser::new(conn, name, None, email);
+                diesel::insert_into(schema::user::table)
+                    .values(&insert_user)
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(User, insert_user))?;
+                let query_user = QueryUser::get_with_email(conn, &insert_user.email)?;
+                (query_user, Some(insert_user))
+            };
+
+        if self.organization_role(conn, query_user.id)?.is_some() {
+            return Err(conflict_error(format!(
+                "User ({email}) is already a member of organization ({organization})",
+                email = query_user.email,
+                organization = self.organization.uuid
+            )));
+        }
+        if json_user.active.unwrap_or(true) {
+            self.activate(conn, &query_user)?;
+        }
+
+        Ok((query_user, insert_user))
+    }
+
+    /// Add a user to the organization, if they are not already a member.
+    pub fn activate(
+        &self,
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+    ) -> Result<(), HttpError> {
+        self.check_sso_domain(conn, &query_user.email)?;
+        query_user.check_is_locked()?;
+        if self.organization_role(conn, query_user.id)?.is_some() {
+            return Ok(());
+        }
+        let timestamp = DateTime::now();
+        let insert_org_role = InsertOrganizationRole {
+            user_id: query_user.id,
+            organization_id: self.organization.id,
+            role: OrganizationRole::Leader,
+            created: timestamp,
+            modified: timestamp,
+        };
+        diesel::insert_into(schema::organization_role::table)
+            .values(&insert_org_role)
+            .execute(conn)
+            .map_err(resource_conflict_err!(OrganizationRole, insert_org_role))?;
+        Ok(())
+    }
+
+    /// Remove a user from the organization and all of its projects.
+    /// If the user is at an SSO domain for the organization, then all of their tokens are also revoked.
+    pub fn deprovision(
+        &self,
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+    ) -> Result<(), HttpError> {
+        let project_ids = self.project_ids(conn)?;
+        diesel::delete(
+            schema::project_role::table
+                .filter(schema::project_role::user_id.eq(query_user.id))
+                .filter(schema::project_role::project_id.eq_any(&project_ids)),
+        )
+        .execute(conn)
+        .map_err(resource_conflict_err!(
+            ProjectRole,
+            (query_user.id, &project_ids)
+        ))?;
+        QueryProjectMember::delete_for_organization(conn, query_user.id, self.organization.id)?;
+        diesel::delete(
+            schema::organization_role::table
+                .filter(schema::organization_role::user_id.eq(query_user.id))
+                .filter(schema::organization_role::organization_id.eq(self.organization.id)),
+        )
+        .execute(conn)
+        .map_err(resource_conflict_err!(
+            OrganizationRole,
+            (query_user.id, self.organization.id)
+        ))?;
+        if self.is_sso_domain(conn, &query_user.email)? {
+            query_user.revoke_tokens(conn)?;
+        }
+        Ok(())
+    }
+
+    fn project_ids(&self, conn: &mut DbConnection) -> Result<Vec<ProjectId>, HttpError> {
+        schema::project::table
+            .filter(schema::project::organization_id.eq(self.organization.id))
+            .select(schema::project::id)
+            .load(conn)
+            .map_err(resource_not_found_err!(Project, self.organization.id))
+    }
+
+    /// Get the SCIM user for a user.
+    /// The user is active if they are a member of the organization.
+    pub fn user(
+        &self,
+        conn: &mut DbConnection,
+        query_user: QueryUser,
+    ) -> Result<JsonScimUser, HttpError> {
+        let org_role = self.organization_role(conn, query_user.id)?;
+        let mut groups = Vec::new();
+        if let Some((role, _)) = org_role {
+            groups.push(JsonScimMember {
+                value: ScimGroupId::Organization(role).to_string(),
+                display: Some(organization_group_name(role)),
+            });
+        }
+        let project_roles = schema::project_role::table
+            .inner_join(schema::project::table)
+            .filter(schema::project::organization_id.eq(self.organization.id))
+            .filter(schema::project_role::user_id.eq(query_user.id))
+            .order(schema::project::name.asc())
+            .select((
+                schema::project::uuid,
+                schema::project::name,
+                schema::project_role::role,
+            ))
+            .load::<(ProjectUuid, ResourceName, ProjectRole)>(conn)
+            .map_err(resource_not_found_err!(ProjectRole, query_user.id))?;
+        for (project_uuid, project_name, role) in project_roles {
+            groups.push(JsonScimMember {
+                value: ScimGroupId::Project(project_uuid, role).to_string(),
+                display: Some(project_group_name(&project_name, role)),
+            });
+        }
+
+        let QueryUser {
+            uuid,
+            name,
+            email,
+            created,
+            modified,
+            ..
+        } = query_user;
+        Ok(JsonScimUser {
+            schemas: vec![SCIM_USER_SCHEMA.into()],
+            id: uuid,
+            user_name: email.clone(),
+            name: JsonScimName {
+                formatted: Some(name.to_string()),
+                ..Default::default()
+            },
+            display_name: name,
+            emails: vec![JsonScimEmail {
+                value: email.into(),
+                primary: Some(true),
+            }],
+            active: org_role.is_some(),
+            groups,
+            meta: JsonScimMeta {
+                resource_type: SCIM_USER_RESOURCE.into(),
+                created,
+                last_modified: org_role.map_or(modified, |(_, joined)| joined),
+            },
+        })
+    }
+
+    /// Get the IDs for all of the groups in the organization.
+    /// There is one group for each organization role
+    /// and one group for each project role of each project in the organization.
+    pub fn group_ids(&self, conn: &mut DbConnection) -> Result<Vec<ScimGroupId>, HttpError> {
+        let project_uuids = schema::project::table
+            .filter(schema::project::organization_id.eq(self.organization.id))
+            .order(schema::project::name.asc())
+            .select(schema::project::uuid)
+            .load::<ProjectUuid>(conn)
+            .map_err(resource_not_found_err!(Project, self.organization.id))?;
+        Ok(
+            std::iter::once(ScimGroupId::Organization(OrganizationRole::Leader))
+                .chain(
+                    project_uuids
+                        .into_iter()
+                        .map(|uuid| ScimGroupId::Project(uuid, ProjectRole::Maintainer)),
+                )
+                .collect(),
+        )
+    }
+
+    fn project(
+        &self,
+        conn: &mut DbConnection,
+        project_uuid: ProjectUuid,
+    ) -> Result<(ProjectId, ResourceName, DateTime), HttpError> {
+        schema::project::table
+            .filter(schema::project::organization_id.eq(self.organization.id))
+            .filter(schema::project::uuid.eq(project_uuid))
+            .select((
+                schema::project::id,
+                schema::project::name,
+                schema::project::created,
+            ))
+            .first(conn)
+            .map_err(resource_not_found_err!(Project, project_uuid))
+    }
+
+    /// Get the SCIM group for a group ID.
+    pub fn group(
+        &self,
+        conn: &mut DbConnection,
+        group_id: ScimGroupId,
+    ) -> Result<JsonScimGroup, HttpError> {
+        let (display_name, members, created) = match group_id {
+            ScimGroupId::Organization(role) => {
+                let members = schema::organization_role::table
+                    .inner_join(schema::user::table)
+                    .filter(schema::organization_role::organization_id.eq(self.organization.id))
+                    .filter(schema::organization_role::role.eq(role.to_string()))
+                    .order(schema::user::name.asc())
+                    .select((schema::user::uuid, schema::user::name))
+                    .load::<(UserUuid, String)>(conn)
+                    .map_err(resource_not_found_err!(
+                        OrganizationRole,
+                        self.organization.id
+                    ))?;
+                (
+                    organization_group_name(role),
+                    members,
+                    self.organization.created,
+                )
+            },
+            ScimGroupId::Project(project_uuid, role) => {
+                let (project_id, project_name, created) = self.project(conn, project_uuid)?;
+                let members = schema::project_role::table
+                    .inner_join(schema::user::table)
+                    .filter(schema::project_role::project_id.eq(project_id))
+                    .filter(schema::project_role::role.eq(role.to_string()))
+                    .order(schema::user::name.asc())
+                    .select((schema::user::uuid, schema::user::name))
+                    .load::<(UserUuid, String)>(conn)
+                    .map_err(resource_not_found_err!(ProjectRole, project_id))?;
+                (project_group_name(&project_name, role), members, created)
+            },
+        };
+
+        Ok(JsonScimGroup {
+            schemas: vec![SCIM_GROUP_SCHEMA.into()],
+            id: group_id,
+            display_name,
+            members: members
+                .into_iter()
+                .map(|(uuid, name)| JsonScimMember {
+                    value: uuid.to_string(),
+                    display: Some(name),
+                })
+                .collect(),
+            meta: JsonScimMeta {
+                resource_type: SCIM_GROUP_RESOURCE.into(),
+                created,
+                last_modified: created,
+            },
+        })
+    }
+
+    /// Add a user to a group.
+    /// Adding a user to a project role group requires them to already be a member of the organization.
+    pub fn add_to_group(
+        &self,
+        conn: &mut DbConnection,
+        group_id: ScimGroupId,
+        uuid: UserUuid,
+    ) -> Result<(), HttpError> {
+        match group_id {
+            ScimGroupId::Organization(role) => {
+                let query_user = QueryUser::from_uuid(conn, uuid)?;
+                match self.organization_role(conn, query_user.id)? {
+                    Some((current_role, _)) if current_role == role => {},
+                    Some(_) => {
+                        diesel::update(
+                            schema::organization_role::table
+                                .filter(schema::organization_role::user_id.eq(query_user.id))
+                                .filter(
+                                    schema::organization_role::organization_id
+                                        .eq(self.organization.id),
+                                ),
+                        )
+                        .set((
+                            schema::organization_role::role.eq(role.to_string()),
+                            schema::organization_role::modified.eq(DateTime::now()),
+                        ))
+                        .execute(conn)
+                        .map_err(resource_conflict_err!(
+                            OrganizationRole,
+                            (query_user.id, self.organization.id, role)
+                        ))?;
+                    },
+                    None => self.activate(conn, &query_user)?,
+                }
+            },
+            ScimGroupId::Project(project_uuid, role) => {
+                let query_user = self.member(conn, uuid)?;
+                let (project_id, _, _) = self.project(conn, project_uuid)?;
+                diesel::delete(
+                    schema::project_role::table
+                        .filter(schema::project_role::user_id.eq(query_user.id))
+                        .filter(schema::project_role::project_id.eq(project_id)),
+                )
+                .execute(conn)
+                .map_err(resource_conflict_err!(
+                    ProjectRole,
+                    (query_user.id, project_id)
+                ))?;
+                let timestamp = DateTime::now();
+                let insert_proj_role = InsertProjectRole {
+                    user_id: query_user.id,
+                    project_id,
+                    role,
+                    created: timestamp,
+                    modified: timestamp,
+                };
+                diesel::insert_into(schema::project_role::table)
+                    .values(&insert_proj_role)
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(ProjectRole, insert_proj_role))?;
+            },
+        }
+        Ok(())
+    }
+
+    /// Remove a user from a group.
+    /// Removing a user from an organization role group removes them from the organization,
+    /// but it does not revoke their tokens.
+    pub fn remove_from_group(
+        &self,
+        conn: &mut DbConnection,
+        group_id: ScimGroupId,
+        uuid: UserUuid,
+    ) -> Result<(), HttpError> {
+        let user_id = QueryUser::get_id(conn, uuid)?;
+        match group_id {
+            ScimGroupId::Organization(role) => {
+                diesel::delete(
+                    schema::organization_role::table
+                        .filter(schema::organization_role::user_id.eq(user_id))
+                        .filter(schema::organization_role::organization_id.eq(self.organization.id))
+                        .filter(schema::organization_role::role.eq(role.to_string())),
+                )
+                .execute(conn)
+                .map_err(resource_conflict_err!(
+                    OrganizationRole,
+                    (user_id, self.organization.id, role)
+                ))?;
+            },
+            ScimGroupId::Project(project_uuid, role) => {
+                let (project_id, _, _) = self.project(conn, project_uuid)?;
+                diesel::delete(
+                    schema::project_role::table
+                        .filter(schema::project_role::user_id.eq(user_id))
+                        .filter(schema::project_role::project_id.eq(project_id))
+                        .filter(schema::project_role::role.eq(role.to_string())),
+                )
+                .execute(conn)
+                .map_err(resource_conflict_err!(
+                    ProjectRole,
+                    (user_id, project_id, role)
+                ))?;
+            },
+        }
+        Ok(())
+    }
+}
+
+fn organization_group_name(role: OrganizationRole) -> String {
+    match role {
+        OrganizationRole::Leader => "Leader".to_owned(),
+    }
+}
+
+fn project_group_name(project_name: &ResourceName, role: ProjectRole) -> String {
+    match role {
+        ProjectRole::Maintainer => format!("{project_name} Maintainer"),
+    }
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "plus")]
 
-use bencher_json::{DateTime, JsonNewSso, JsonSso, NonEmpty, OrganizationUuid, SsoUuid};
+use bencher_json::{DateTime, Email, JsonNewSso, JsonSso, NonEmpty, OrganizationUuid, SsoUuid};
 use diesel::{
     BoolExpressionMethods as _, ExpressionMethods as _, JoinOnDsl as _, QueryDsl as _,
     RunQueryDsl as _,
@@ -32,6 +32,7 @@ pub struct QuerySso {
     pub organization_id: OrganizationId,
     pub domain: NonEmpty,
     pub created: DateTime,
+    pub oidc: bool,
 }
 
 impl QuerySso {
@@ -42,6 +43,18 @@ impl QuerySso {
             .map_err(resource_not_found_err!(Sso, uuid))
     }
 
+    /// Check whether OIDC login is enabled for the domain of an email.
+    pub fn is_oidc(conn: &mut DbConnection, email: &Email) -> Result<bool, HttpError> {
+        let email_domain = email.domain();
+        schema::sso::table
+            .filter(schema::sso::domain.eq(&email_domain))
+            .filter(schema::sso::oidc.eq(true))
+            .count()
+            .get_result::<i64>(conn)
+            .map(|count| count > 0)
+            .map_err(resource_not_found_err!(Sso, &email_domain))
+    }
+
     pub async fn join_all(
         context: &ApiContext,
         query_user: &QueryUser,
@@ -89,11 +102,13 @@ impl QuerySso {
             uuid,
             domain,
             created,
+            oidc,
             ..
         } = self;
         JsonSso {
             uuid,
             domain,
+            oidc,
             created,
         }
     }
@@ -106,16 +121,18 @@ pub struct InsertSso {
     pub organization_id: OrganizationId,
     pub domain: NonEmpty,
     pub created: DateTime,
+    pub oidc: bool,
 }
 
 impl InsertSso {
     pub fn from_json(organization_id: OrganizationId, json_new_sso: JsonNewSso) -> Self {
-        let JsonNewSso { domain } = json_new_sso;
+        let JsonNewSso { domain, oidc } = json_new_sso;
         Self {
             uuid: SsoUuid::new(),
             organization_id,
             domain,
             created: DateTime::now(),
+            oidc: oidc.unwrap_or_default(),
         }
     }
 }
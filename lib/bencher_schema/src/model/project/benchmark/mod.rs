@@ -18,6 +18,10 @@ use crate::{
     schema::{self, benchmark as benchmark_table},
 };
 
+pub mod tag;
+
+use tag::{InsertBenchmarkTag, QueryBenchmarkTag};
+
 crate::macros::typed_id::typed_id!(BenchmarkId);
 
 #[derive(
@@ -95,18 +99,23 @@ impl QueryBenchmark {
             return Ok(benchmark);
         }
 
-        let json_benchmark = JsonNewBenchmark { name, slug: None };
+        let json_benchmark = JsonNewBenchmark {
+            name,
+            slug: None,
+            tags: None,
+        };
         Self::create(context, project_id, json_benchmark).await
     }
 
     pub async fn create(
         context: &ApiContext,
         project_id: ProjectId,
-        json_benchmark: JsonNewBenchmark,
+        mut json_benchmark: JsonNewBenchmark,
     ) -> Result<Self, HttpError> {
         #[cfg(feature = "plus")]
         InsertBenchmark::rate_limit(context, project_id).await?;
 
+        let tags = json_benchmark.tags.take();
         let insert_benchmark =
             InsertBenchmark::from_json(conn_lock!(context), project_id, json_benchmark);
         diesel::insert_into(schema::benchmark::table)
@@ -114,7 +123,23 @@ impl QueryBenchmark {
             .execute(conn_lock!(context))
             .map_err(resource_conflict_err!(Benchmark, &insert_benchmark))?;
 
-        Self::from_uuid(conn_lock!(context), project_id, insert_benchmark.uuid)
+        let query_benchmark =
+            Self::from_uuid(conn_lock!(context), project_id, insert_benchmark.uuid)?;
+        if let Some(tags) = tags {
+            InsertBenchmarkTag::upsert(conn_lock!(context), query_benchmark.id, tags)?;
+        }
+        Ok(query_benchmark)
+    }
+
+    pub fn into_json_with_tags(
+        self,
+        conn: &mut DbConnection,
+        project: &QueryProject,
+    ) -> Result<JsonBenchmark, HttpError> {
+        let tags = QueryBenchmarkTag::get_tags(conn, self.id)?;
+        let mut json_benchmark = self.into_json_for_project(project);
+        json_benchmark.tags = Some(tags);
+        Ok(json_benchmark)
     }
 
     pub fn into_json_for_project(self, project: &QueryProject) -> JsonBenchmark {
@@ -142,6 +167,7 @@ impl QueryBenchmark {
             created,
             modified,
             archived,
+            tags: None,
         }
     }
 }
@@ -167,7 +193,7 @@ impl InsertBenchmark {
         project_id: ProjectId,
         benchmark: JsonNewBenchmark,
     ) -> Self {
-        let JsonNewBenchmark { name, slug } = benchmark;
+        let JsonNewBenchmark { name, slug, .. } = benchmark;
         let slug = ok_slug!(conn, project_id, &name, slug, benchmark, QueryBenchmark);
         let timestamp = DateTime::now();
         Self {
@@ -197,6 +223,8 @@ impl From<JsonUpdateBenchmark> for UpdateBenchmark {
             name,
             slug,
             archived,
+            // The tags are stored in their own table.
+            tags: _,
         } = update;
         let modified = DateTime::now();
         let archived = archived.map(|archived| archived.then_some(modified));
@@ -215,6 +243,7 @@ impl UpdateBenchmark {
             name: None,
             slug: None,
             archived: Some(false),
+            tags: None,
         }
         .into()
     }
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use bencher_json::{BenchmarkUuid, JsonBenchmarkTags, TagKey, TagSelector, TagSelectors, TagValue};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+
+use crate::{
+    context::{DbBackend, DbConnection},
+    error::{resource_conflict_err, resource_not_found_err},
+    model::project::ProjectId,
+    schema::{self, benchmark_tag as benchmark_tag_table},
+};
+
+use super::{BenchmarkId, QueryBenchmark};
+
+crate::macros::typed_id::typed_id!(BenchmarkTagId);
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = benchmark_tag_table)]
+#[diesel(belongs_to(QueryBenchmark, foreign_key = benchmark_id))]
+pub struct QueryBenchmarkTag {
+    pub id: BenchmarkTagId,
+    pub benchmark_id: BenchmarkId,
+    pub key: TagKey,
+    pub value: TagValue,
+}
+
+impl QueryBenchmarkTag {
+    pub fn get_tags(
+        conn: &mut DbConnection,
+        benchmark_id: BenchmarkId,
+    ) -> Result<JsonBenchmarkTags, HttpError> {
+        schema::benchmark_tag::table
+            .filter(schema::benchmark_tag::benchmark_id.eq(benchmark_id))
+            .select((schema::benchmark_tag::key, schema::benchmark_tag::value))
+            .load::<(TagKey, TagValue)>(conn)
+            .map(|tags| tags.into_iter().collect())
+            .map_err(resource_not_found_err!(BenchmarkTag, benchmark_id))
+    }
+
+    // Get the tags for many benchmarks at once.
+    // Benchmarks without any tags are given an empty set of tags.
+    pub fn get_tags_for(
+        conn: &mut DbConnection,
+        benchmark_ids: &[BenchmarkId],
+    ) -> Result<HashMap<BenchmarkId, JsonBenchmarkTags>, HttpError> {
+        let mut benchmark_tags = benchmark_ids
+            .iter()
+            .map(|benchmark_id| (*benchmark_id, JsonBenchmarkTags::new()))
+            .collect::<HashMap<_, _>>();
+        let tags = schema::benchmark_tag::table
+            .filter(schema::benchmark_tag::benchmark_id.eq_any(benchmark_ids))
+            .select((
+                schema::benchmark_tag::benchmark_id,
+                schema::benchmark_tag::key,
+                schema::benchmark_tag::value,
+            ))
+            .load::<(BenchmarkId, TagKey, TagValue)>(conn)
+            .map_err(resource_not_found_err!(BenchmarkTag, benchmark_ids))?;
+        for (benchmark_id, key, value) in tags {
+            benchmark_tags
+                .entry(benchmark_id)
+                .or_default()
+                .insert(key, value);
+        }
+        Ok(benchmark_tags)
+    }
+
+    // Filter for the benchmarks that have a tag matching the selector.
+    pub fn eq_selector(
+        selector: &TagSelector,
+    ) -> Box<
+        dyn diesel::BoxableExpression<
+                schema::benchmark::table,
+                DbBackend,
+                SqlType = diesel::sql_types::Bool,
+            > + '_,
+    > {
+        Box::new(
+            schema::benchmark::id.eq_any(
+                schema::benchmark_tag::table
+                    .filter(schema::benchmark_tag::key.eq(&selector.key))
+                    .filter(schema::benchmark_tag::value.eq(&selector.value))
+                    .select(schema::benchmark_tag::benchmark_id),
+            ),
+        )
+    }
+
+    // Get all of the non-archived benchmarks in a project that match every tag selector.
+    pub fn get_benchmark_uuids(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        tags: &TagSelectors,
+    ) -> Result<Vec<BenchmarkUuid>, HttpError> {
+        let mut query = schema::benchmark::table
+            .filter(schema::benchmark::project_id.eq(project_id))
+            .filter(schema::benchmark::archived.is_null())
+            .into_boxed();
+        for selector in tags.iter() {
+            query = query.filter(Self::eq_selector(selector));
+        }
+        query
+            .order(schema::benchmark::name)
+            .select(schema::benchmark::uuid)
+            .load::<BenchmarkUuid>(conn)
+            .map_err(resource_not_found_err!(BenchmarkTag, (project_id, tags)))
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = benchmark_tag_table)]
+pub struct InsertBenchmarkTag {
+    pub benchmark_id: BenchmarkId,
+    pub key: TagKey,
+    pub value: TagValue,
+}
+
+impl InsertBenchmarkTag {
+    /// Add the tags to the benchmark, overwriting the value of any existing tag with the same key.
+    pub fn upsert(
+        conn: &mut DbConnection,
+        benchmark_id: BenchmarkId,
+        tags: JsonBenchmarkTags,
+    ) -> Result<(), HttpError> {
+        for (key, value) in tags {
+            diesel::delete(
+                schema::benchmark_tag::table
+                    .filter(schema::benchmark_tag::benchmark_id.eq(benchmark_id))
+                    .filter(schema::benchmark_tag::key.eq(&key)),
+            )
+            .execute(conn)
+            .map_err(resource_conflict_err!(BenchmarkTag, (benchmark_id, &key)))?;
+            let insert_tag = Self {
+                benchmark_id,
+                key,
+                value,
+            };
+            diesel::insert_into(schema::benchmark_tag::table)
+                .values(&insert_tag)
+                .execute(conn)
+                .map_err(resource_conflict_err!(BenchmarkTag, insert_tag))?;
+        }
+        Ok(())
+    }
+
+    /// Replace all of the tags for the benchmark.
+    pub fn replace(
+        conn: &mut DbConnection,
+        benchmark_id: BenchmarkId,
+        tags: JsonBenchmarkTags,
+    ) -> Result<(), HttpError> {
+        diesel::delete(
+            schema::benchmark_tag::table
+                .filter(schema::benchmark_tag::benchmark_id.eq(benchmark_id)),
+        )
+        .execute(conn)
+        .map_err(resource_conflict_err!(BenchmarkTag, benchmark_id))?;
+        Self::upsert(conn, benchmark_id, tags)
+    }
+}
@@ -1,6 +1,11 @@
-use bencher_json::{BranchUuid, GitHash, HeadUuid, JsonStartPoint};
+use std::collections::HashMap;
+
+use bencher_json::{
+    BranchUuid, GitHash, HeadUuid, JsonStartPoint, project::branch::START_POINT_MAX_ANCESTORS,
+};
 use diesel::{
-    ExpressionMethods as _, JoinOnDsl as _, QueryDsl as _, RunQueryDsl as _, SelectableHelper as _,
+    ExpressionMethods as _, JoinOnDsl as _, OptionalExtension as _, QueryDsl as _,
+    RunQueryDsl as _, SelectableHelper as _,
 };
 use dropshot::HttpError;
 
@@ -31,12 +36,13 @@ pub struct QueryHeadVersion {
 impl QueryHeadVersion {
     fn_get!(head_version, HeadVersionId);
 
+    // Returns `None` if the branch does not have a version with the hash.
     pub async fn get_latest_for_branch(
         context: &ApiContext,
         project_id: ProjectId,
         query_branch: &QueryBranch,
         hash: Option<&GitHash>,
-    ) -> Result<Self, HttpError> {
+    ) -> Result<Option<Self>, HttpError> {
         let head_id = query_branch.head_id()?;
         let mut query = schema::head_version::table
             .inner_join(schema::version::table)
@@ -56,12 +62,41 @@ impl QueryHeadVersion {
             .order(schema::version::number.desc())
             .select(Self::as_select())
             .first::<Self>(conn_lock!(context))
+            .optional()
             .map_err(resource_not_found_err!(
                 HeadVersion,
                 (query_branch, hash)
             ))
     }
 
+    // The ancestor hashes are ordered from nearest to furthest.
+    // Only the nearest `START_POINT_MAX_ANCESTORS` are checked.
+    pub async fn get_nearest_ancestor_for_branch(
+        context: &ApiContext,
+        project_id: ProjectId,
+        query_branch: &QueryBranch,
+        ancestor_hashes: &[GitHash],
+    ) -> Result<Option<Self>, HttpError> {
+        let ancestor_hashes = ancestor_hashes
+            .get(..START_POINT_MAX_ANCESTORS)
+            .unwrap_or(ancestor_hashes);
+        let head_id = query_branch.head_id()?;
+        let head_versions = schema::head_version::table
+            .inner_join(schema::version::table)
+            // Filter for the branch head
+            .filter(schema::head_version::head_id.eq(head_id))
+            // Sanity check that we are in the right project
+            .filter(schema::version::project_id.eq(project_id))
+            .filter(schema::version::hash.eq_any(ancestor_hashes))
+            .select((Self::as_select(), schema::version::hash))
+            .load::<(Self, Option<GitHash>)>(conn_lock!(context))
+            .map_err(resource_not_found_err!(
+                HeadVersion,
+                (query_branch, ancestor_hashes)
+            ))?;
+        Ok(nearest_ancestor(ancestor_hashes, head_versions))
+    }
+
     pub fn into_start_point_json(
         self,
         conn: &mut DbConnection,
@@ -87,3 +122,55 @@ pub struct InsertHeadVersion {
     pub head_id: HeadId,
     pub version_id: VersionId,
 }
+
+// Find the versioned item for the nearest ancestor hash.
+fn nearest_ancestor<T>(
+    ancestor_hashes: &[GitHash],
+    versions: Vec<(T, Option<GitHash>)>,
+) -> Option<T> {
+    let mut versions = versions
+        .into_iter()
+        .filter_map(|(version, hash)| hash.map(|hash| (hash, version)))
+        .collect::<HashMap<_, _>>();
+    ancestor_hashes
+        .iter()
+        .find_map(|ancestor_hash| versions.remove(ancestor_hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::GitHash;
+
+    use super::nearest_ancestor;
+
+    fn hash(c: char) -> GitHash {
+        c.to_string().repeat(40).parse().unwrap()
+    }
+
+    #[test]
+    fn nearest_ancestor_order() {
+        let ancestor_hashes = [hash('a'), hash('b'), hash('c')];
+        assert_eq!(nearest_ancestor::<&str>(&ancestor_hashes, Vec::new()), None);
+        assert_eq!(
+            nearest_ancestor(
+                &ancestor_hashes,
+                vec![("d", Some(hash('d'))), ("none", None)]
+            ),
+            None
+        );
+        assert_eq!(
+            nearest_ancestor(
+                &ancestor_hashes,
+                vec![("c", Some(hash('c'))), ("b", Some(hash('b')))]
+            ),
+            Some("b")
+        );
+        assert_eq!(
+            nearest_ancestor(
+                &ancestor_hashes,
+                vec![("c", Some(hash('c'))), ("a", Some(hash('a')))]
+            ),
+            Some("a")
+        );
+    }
+}
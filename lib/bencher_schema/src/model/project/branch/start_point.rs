@@ -4,7 +4,12 @@ use bencher_json::{
 };
 use dropshot::HttpError;
 
-use crate::{conn_lock, context::ApiContext, model::project::ProjectId};
+use crate::{
+    conn_lock,
+    context::ApiContext,
+    error::{BencherResource, resource_not_found_error},
+    model::project::ProjectId,
+};
 
 use super::{
     QueryBranch,
@@ -44,12 +49,35 @@ impl StartPoint {
         project_id: ProjectId,
         query_branch: QueryBranch,
         hash: Option<&GitHash>,
+        ancestor_hashes: &[GitHash],
         max_versions: Option<u32>,
         clone_thresholds: Option<bool>,
     ) -> Result<Self, HttpError> {
-        let head_version =
+        let latest_head_version =
             QueryHeadVersion::get_latest_for_branch(context, project_id, &query_branch, hash)
                 .await?;
+        let head_version = match latest_head_version {
+            Some(head_version) => Some(head_version),
+            // If the start point branch does not have a version for the hash,
+            // then fall back to the nearest ancestor that it does have a version for.
+            None if hash.is_some() && !ancestor_hashes.is_empty() => {
+                QueryHeadVersion::get_nearest_ancestor_for_branch(
+                    context,
+                    project_id,
+                    &query_branch,
+                    ancestor_hashes,
+                )
+                .await?
+            },
+            None => None,
+        }
+        .ok_or_else(|| {
+            resource_not_found_error(
+                BencherResource::HeadVersion,
+                (&query_branch, hash),
+                diesel::result::Error::NotFound,
+            )
+        })?;
         Self::new(
             context,
             query_branch,
@@ -68,6 +96,7 @@ impl StartPoint {
         let JsonNewStartPoint {
             branch,
             hash,
+            ancestor_hashes,
             max_versions,
             clone_thresholds,
         } = json;
@@ -77,6 +106,7 @@ impl StartPoint {
             project_id,
             query_branch,
             hash.as_ref(),
+            ancestor_hashes.as_deref().unwrap_or_default(),
             max_versions,
             clone_thresholds,
         )
@@ -92,6 +122,7 @@ impl StartPoint {
         let Some(JsonUpdateStartPoint {
             branch: Some(branch),
             hash,
+            ancestor_hashes,
             max_versions,
             clone_thresholds,
             reset: _,
@@ -110,6 +141,7 @@ impl StartPoint {
             project_id,
             query_branch,
             hash.as_ref(),
+            ancestor_hashes.as_deref().unwrap_or_default(),
             *max_versions,
             *clone_thresholds,
         )
@@ -0,0 +1,577 @@
+use std::collections::HashMap;
+
+use bencher_json::{
+    BenchmarkUuid, BranchUuid, JsonBenchmarkTags, JsonProjectExport, MeasureUuid, MetricUuid,
+    TestbedUuid,
+    project::export::{
+        JsonExportAlert, JsonExportBenchmark, JsonExportBoundary, JsonExportBranch,
+        JsonExportChangePoint, JsonExportHead, JsonExportMeasure, JsonExportMetric,
+        JsonExportModel, JsonExportPlot, JsonExportProject, JsonExportReport,
+        JsonExportReportResult, JsonExportStartPoint, JsonExportTestbed, JsonExportThreshold,
+        JsonExportVersion,
+    },
+};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+
+use super::{
+    QueryProject,
+    benchmark::{BenchmarkId, QueryBenchmark, tag::QueryBenchmarkTag},
+    branch::{
+        BranchId, QueryBranch,
+        head::{HeadId, QueryHead},
+        head_version::{HeadVersionId, QueryHeadVersion},
+        version::{QueryVersion, VersionId},
+    },
+    measure::{MeasureId, QueryMeasure},
+    metric::{MetricId, QueryMetric},
+    plot::{PlotId, QueryPlot},
+    report::{QueryReport, ReportId, report_benchmark::QueryReportBenchmark},
+    testbed::{QueryTestbed, TestbedId},
+    threshold::{
+        QueryThreshold, ThresholdId,
+        alert::QueryAlert,
+        boundary::{BoundaryId, QueryBoundary},
+        change_point::QueryChangePoint,
+        model::{ModelId, QueryModel},
+    },
+};
+use crate::{context::DbConnection, error::resource_not_found_err, schema};
+
+#[expect(clippy::too_many_lines)]
+pub(super) fn export(
+    conn: &mut DbConnection,
+    query_project: &QueryProject,
+) -> Result<JsonProjectExport, HttpError> {
+    let project = JsonExportProject {
+        uuid: query_project.uuid,
+        name: query_project.name.clone(),
+        slug: query_project.slug.clone(),
+        url: query_project.url.clone(),
+        visibility: query_project.visibility,
+        openmetrics: query_project.openmetrics,
+        created: query_project.created,
+        modified: query_project.modified,
+    };
+
+    let query_measures = schema::measure::table
+        .filter(schema::measure::project_id.eq(query_project.id))
+        .order(schema::measure::id.asc())
+        .load::<QueryMeasure>(conn)
+        .map_err(resource_not_found_err!(Measure, query_project))?;
+    let measure_uuids: HashMap<MeasureId, MeasureUuid> = query_measures
+        .iter()
+        .map(|measure| (measure.id, measure.uuid))
+        .collect();
+    let measures = query_measures
+        .into_iter()
+        .map(|measure| JsonExportMeasure {
+            uuid: measure.uuid,
+            name: measure.name,
+            slug: measure.slug,
+            units: measure.units,
+            created: measure.created,
+            modified: measure.modified,
+            archived: measure.archived,
+        })
+        .collect();
+
+    let query_testbeds = schema::testbed::table
+        .filter(schema::testbed::project_id.eq(query_project.id))
+        .order(schema::testbed::id.asc())
+        .load::<QueryTestbed>(conn)
+        .map_err(resource_not_found_err!(Testbed, query_project))?;
+    let testbed_uuids: HashMap<TestbedId, TestbedUuid> = query_testbeds
+        .iter()
+        .map(|testbed| (testbed.id, testbed.uuid))
+        .collect();
+    let testbeds = query_testbeds
+        .into_iter()
+        .map(|testbed| JsonExportTestbed {
+            uuid: testbed.uuid,
+            name: testbed.name,
+            slug: testbed.slug,
+            created: testbed.created,
+            modified: testbed.modified,
+            archived: testbed.archived,
+        })
+        .collect();
+
+    let query_benchmarks = schema::benchmark::table
+        .filter(schema::benchmark::project_id.eq(query_project.id))
+        .order(schema::benchmark::id.asc())
+        .load::<QueryBenchmark>(conn)
+        .map_err(resource_not_found_err!(Benchmark, query_project))?;
+    let benchmark_uuids: HashMap<BenchmarkId, BenchmarkUuid> = query_benchmarks
+        .iter()
+        .map(|benchmark| (benchmark.id, benchmark.uuid))
+        .collect();
+    let mut benchmark_tags = HashMap::<BenchmarkId, JsonBenchmarkTags>::new();
+    for tag in schema::benchmark_tag::table
+        .inner_join(schema::benchmark::table)
+        .filter(schema::benchmark::project_id.eq(query_project.id))
+        .select(schema::benchmark_tag::all_columns)
+        .load::<QueryBenchmarkTag>(conn)
+        .map_err(resource_not_found_err!(BenchmarkTag, query_project))?
+    {
+        benchmark_tags
+            .entry(tag.benchmark_id)
+            .or_default()
+            .insert(tag.key, tag.value);
+    }
+    let benchmarks = query_benchmarks
+        .into_iter()
+        .map(|benchmark| JsonExportBenchmark {
+            uuid: benchmark.uuid,
+            name: benchmark.name,
+            slug: benchmark.slug,
+            tags: benchmark_tags.remove(&benchmark.id),
+            created: benchmark.created,
+            modified: benchmark.modified,
+            archived: benchmark.archived,
+        })
+        .collect();
+
+    let query_versions = schema::version::table
+        .filter(schema::version::project_id.eq(query_project.id))
+        .order(schema::version::id.asc())
+        .load::<QueryVersion>(conn)
+        .map_err(resource_not_found_err!(Version, query_project))?;
+    let version_uuids: HashMap<VersionId, _> = query_versions
+        .iter()
+        .map(|version| (version.id, version.uuid))
+        .collect();
+    let versions = query_versions
+        .into_iter()
+        .map(|version| JsonExportVersion {
+            uuid: version.uuid,
+            number: version.number,
+            hash: version.hash,
+        })
+        .collect();
+
+    let query_branches = schema::branch::table
+        .filter(schema::branch::project_id.eq(query_project.id))
+        .order(schema::branch::id.asc())
+        .load::<QueryBranch>(conn)
+        .map_err(resource_not_found_err!(Branch, query_project))?;
+    let branch_uuids: HashMap<BranchId, BranchUuid> = query_branches
+        .iter()
+        .map(|branch| (branch.id, branch.uuid))
+        .collect();
+
+    let project_branches = schema::branch::table
+        .filter(schema::branch::project_id.eq(query_project.id))
+        .select(schema::branch::id);
+    let query_heads = schema::head::table
+        .filter(schema::head::branch_id.eq_any(project_branches))
+        .order(schema::head::id.asc())
+        .load::<QueryHead>(conn)
+        .map_err(resource_not_found_err!(Head, query_project))?;
+    let head_uuids: HashMap<HeadId, _> = query_heads
+        .iter()
+        .map(|head| (head.id, head.uuid))
+        .collect();
+    let query_head_versions = schema::head_version::table
+        .filter(
+            schema::head_version::head_id.eq_any(
+                schema::head::table
+                    .filter(schema::head::branch_id.eq_any(project_branches))
+                    .select(schema::head::id),
+            ),
+        )
+        .order(schema::head_version::id.asc())
+        .load::<QueryHeadVersion>(conn)
+        .map_err(resource_not_found_err!(HeadVersion, query_project))?;
+    let mut head_versions = HashMap::<HeadId, Vec<_>>::new();
+    let mut start_points = HashMap::<HeadVersionId, JsonExportStartPoint>::new();
+    for head_version in query_head_versions {
+        let head = get_uuid(&head_uuids, head_version.head_id)?;
+        let version = get_uuid(&version_uuids, head_version.version_id)?;
+        head_versions
+            .entry(head_version.head_id)
+            .or_default()
+            .push(version);
+        start_points.insert(head_version.id, JsonExportStartPoint { head, version });
+    }
+
+    let branches = query_branches
+        .into_iter()
+        .map(|branch| {
+            Ok(JsonExportBranch {
+                uuid: branch.uuid,
+                name: branch.name,
+                slug: branch.slug,
+                head: branch
+                    .head_id
+                    .map(|head_id| get_uuid(&head_uuids, head_id))
+                    .transpose()?,
+                created: branch.created,
+                modified: branch.modified,
+                archived: branch.archived,
+            })
+        })
+        .collect::<Result<_, HttpError>>()?;
+    let heads = query_heads
+        .into_iter()
+        .map(|head| {
+            Ok(JsonExportHead {
+                uuid: head.uuid,
+                branch: get_uuid(&branch_uuids, head.branch_id)?,
+                start_point: head
+                    .start_point_id
+                    .map(|start_point_id| get_uuid(&start_points, start_point_id))
+                    .transpose()?,
+                versions: head_versions.remove(&head.id).unwrap_or_default(),
+                created: head.created,
+                replaced: head.replaced,
+            })
+        })
+        .collect::<Result<_, HttpError>>()?;
+
+    let query_thresholds = schema::threshold::table
+        .filter(schema::threshold::project_id.eq(query_project.id))
+        .order(schema::threshold::id.asc())
+        .load::<QueryThreshold>(conn)
+        .map_err(resource_not_found_err!(Threshold, query_project))?;
+    let threshold_uuids: HashMap<ThresholdId, _> = query_thresholds
+        .iter()
+        .map(|threshold| (threshold.id, threshold.uuid))
+        .collect();
+    let project_thresholds = schema::threshold::table
+        .filter(schema::threshold::project_id.eq(query_project.id))
+        .select(schema::threshold::id);
+    let query_models = schema::model::table
+        .filter(schema::model::threshold_id.eq_any(project_thresholds))
+        .order(schema::model::id.asc())
+        .load::<QueryModel>(conn)
+        .map_err(resource_not_found_err!(Model, query_project))?;
+    let model_uuids: HashMap<ModelId, _> = query_models
+        .iter()
+        .map(|model| (model.id, model.uuid))
+        .collect();
+    let thresholds = query_thresholds
+        .into_iter()
+        .map(|threshold| {
+            Ok(JsonExportThreshold {
+                uuid: threshold.uuid,
+                branch: get_uuid(&branch_uuids, threshold.branch_id)?,
+                testbed: get_uuid(&testbed_uuids, threshold.testbed_id)?,
+                measure: get_uuid(&measure_uuids, threshold.measure_id)?,
+                benchmark_tags: threshold.benchmark_tags,
+                model: threshold
+                    .model_id
+                    .map(|model_id| get_uuid(&model_uuids, model_id))
+                    .transpose()?,
+                created: threshold.created,
+                modified: threshold.modified,
+            })
+        })
+        .collect::<Result<_, HttpError>>()?;
+    let models = query_models
+        .into_iter()
+        .map(|model| {
+            Ok(JsonExportModel {
+                uuid: model.uuid,
+                threshold: get_uuid(&threshold_uuids, model.threshold_id)?,
+                test: model.test,
+                min_sample_size: model.min_sample_size,
+                max_sample_size: model.max_sample_size,
+                window: model.window,
+                lower_boundary: model.lower_boundary,
+                upper_boundary: model.upper_boundary,
+                created: model.created,
+                replaced: model.replaced,
+            })
+        })
+        .collect::<Result<_, HttpError>>()?;
+
+    let query_metrics = schema::metric::table
+        .inner_join(schema::report_benchmark::table.inner_join(schema::report::table))
+        .filter(schema::report::project_id.eq(query_project.id))
+        .order(schema::metric::id.asc())
+        .select(schema::metric::all_columns)
+        .load::<QueryMetric>(conn)
+        .map_err(resource_not_found_err!(Metric, query_project))?;
+    let metric_uuids: HashMap<MetricId, MetricUuid> = query_metrics
+        .iter()
+        .map(|metric| (metric.id, metric.uuid))
+        .collect();
+    let mut metrics = HashMap::<_, Vec<_>>::new();
+    for metric in query_metrics {
+        metrics
+            .entry(metric.report_benchmark_id)
+            .or_default()
+            .push(JsonExportMetric {
+                uuid: metric.uuid,
+                measure: get_uuid(&measure_uuids, metric.measure_id)?,
+                value: metric.value,
+                lower_value: metric.lower_value,
+                upper_value: metric.upper_value,
+            });
+    }
+    let mut results = HashMap::<ReportId, Vec<_>>::new();
+    for report_benchmark in schema::report_benchmark::table
+        .inner_join(schema::report::table)
+        .filter(schema::report::project_id.eq(query_project.id))
+        .order(schema::report_benchmark::id.asc())
+        .select(schema::report_benchmark::all_columns)
+        .load::<QueryReportBenchmark>(conn)
+        .map_err(resource_not_found_err!(ReportBenchmark, query_project))?
+    {
+        results
+            .entry(report_benchmark.report_id)
+            .or_default()
+            .push(JsonExportReportResult {
+                uuid: report_benchmark.uuid,
+                iteration: report_benchmark.iteration,
+                benchmark: get_uuid(&benchmark_uuids, report_benchmark.benchmark_id)?,
+                metrics: metrics.remove(&report_benchmark.id).unwrap_or_default(),
+            });
+    }
+    let reports = schema::report::table
+        .filter(schema::report::project_id.eq(query_project.id))
+        .order(schema::report::id.asc())
+        .load::<QueryReport>(conn)
+        .map_err(resource_not_found_err!(Report, query_project))?
+        .into_iter()
+        .map(|report| {
+            Ok(JsonExportReport {
+                uuid: report.uuid,
+                head: get_uuid(&head_uuids, report.head_id)?,
+                version: get_uuid(&version_uuids, report.version_id)?,
+                testbed: get_uuid(&testbed_uuids, report.testbed_id)?,
+                adapter: report.adapter,
+                start_time: report.start_time,
+                end_time: report.end_time,
+                group: report.group_uuid,
+                created: report.created,
+                results: results.remove(&report.id).unwrap_or_default(),
+            })
+        })
+        .collect::<Result<_, HttpError>>()?;
+
+    let query_boundaries = schema::boundary::table
+        .inner_join(schema::threshold::table)
+        .filter(schema::threshold::project_id.eq(query_project.id))
+        .order(schema::boundary::id.asc())
+        .select(schema::boundary::all_columns)
+        .load::<QueryBoundary>(conn)
+        .map_err(resource_not_found_err!(Boundary, query_project))?;
+    let boundary_uuids: HashMap<BoundaryId, _> = query_boundaries
+        .iter()
+        .map(|boundary| (boundary.id, boundary.uuid))
+        .collect();
+    let boundaries = query_boundaries
+        .into_iter()
+        .map(|boundary| {
+            Ok(JsonExportBoundary {
+                uuid: boundary.uuid,
+                metric: get_uuid(&metric_uuids, boundary.metric_id)?,
+                threshold: get_uuid(&threshold_uuids, boundary.threshold_id)?,
+                model: get_uuid(&model_uuids, boundary.model_id)?,
+                baseline: boundary.baseline,
+                lower_limit: boundary.lower_limit,
+                upper_limit: boundary.upper_limit,
+            })
+        })
+        .collect::<Result<_, HttpError>>()?;
+
+    let alerts = schema::alert::table
+        .inner_join(schema::boundary::table.inner_join(schema::threshold::table))
+        .filter(schema::threshold::project_id.eq(query_project.id))
+        .order(schema::alert::id.asc())
+        .select(schema::alert::all_columns)
+        .load::<QueryAlert>(conn)
+        .map_err(resource_not_found_err!(Alert, query_project))?
+        .into_iter()
+        .map(|alert| {
+            Ok(JsonExportAlert {
+                uuid: alert.uuid,
+                boundary: get_uuid(&boundary_uuids, alert.boundary_id)?,
+                limit: alert.boundary_limit,
+                status: alert.status,
+                modified: alert.modified,
+            })
+        })
+        .collect::<Result<_, HttpError>>()?;
+
+    let change_points = schema::change_point::table
+        .inner_join(schema::threshold::table)
+        .filter(schema::threshold::project_id.eq(query_project.id))
+        .order(schema::change_point::id.asc())
+        .select(schema::change_point::all_columns)
+        .load::<QueryChangePoint>(conn)
+        .map_err(resource_not_found_err!(ChangePoint, query_project))?
+        .into_iter()
+        .map(|change_point| {
+            Ok(JsonExportChangePoint {
+                uuid: change_point.uuid,
+                metric: get_uuid(&metric_uuids, change_point.metric_id)?,
+                threshold: get_uuid(&threshold_uuids, change_point.threshold_id)?,
+                model: get_uuid(&model_uuids, change_point.model_id)?,
+                baseline: change_point.baseline,
+                value: change_point.value,
+                created: change_point.created,
+            })
+        })
+        .collect::<Result<_, HttpError>>()?;
+
+    let plots = export_plots(
+        conn,
+        query_project,
+        &branch_uuids,
+        &testbed_uuids,
+        &benchmark_uuids,
+        &measure_uuids,
+    )?;
+
+    Ok(JsonProjectExport {
+        version: JsonProjectExport::VERSION,
+        project,
+        measures,
+        testbeds,
+        benchmarks,
+        versions,
+        branches,
+        heads,
+        thresholds,
+        models,
+        reports,
+        boundaries,
+        alerts,
+        change_points,
+        plots,
+    })
+}
+
+// Plots are exported in rank order,
+// and the branches, testbeds, benchmarks, and measures for each plot are also in rank order.
+#[expect(clippy::too_many_lines)]
+fn export_plots(
+    conn: &mut DbConnection,
+    query_project: &QueryProject,
+    branch_uuids: &HashMap<BranchId, BranchUuid>,
+    testbed_uuids: &HashMap<TestbedId, TestbedUuid>,
+    benchmark_uuids: &HashMap<BenchmarkId, BenchmarkUuid>,
+    measure_uuids: &HashMap<MeasureId, MeasureUuid>,
+) -> Result<Vec<JsonExportPlot>, HttpError> {
+    let mut plot_branches = HashMap::<PlotId, Vec<_>>::new();
+    for (plot_id, branch_id) in schema::plot_branch::table
+        .inner_join(schema::plot::table)
+        .filter(schema::plot::project_id.eq(query_project.id))
+        .order((
+            schema::plot_branch::plot_id,
+            schema::plot_branch::rank.asc(),
+        ))
+        .select((schema::plot_branch::plot_id, schema::plot_branch::branch_id))
+        .load::<(PlotId, BranchId)>(conn)
+        .map_err(resource_not_found_err!(PlotBranch, query_project))?
+    {
+        plot_branches
+            .entry(plot_id)
+            .or_default()
+            .push(get_uuid(branch_uuids, branch_id)?);
+    }
+    let mut plot_testbeds = HashMap::<PlotId, Vec<_>>::new();
+    for (plot_id, testbed_id) in schema::plot_testbed::table
+        .inner_join(schema::plot::table)
+        .filter(schema::plot::project_id.eq(query_project.id))
+        .order((
+            schema::plot_testbed::plot_id,
+            schema::plot_testbed::rank.asc(),
+        ))
+        .select((
+            schema::plot_testbed::plot_id,
+            schema::plot_testbed::testbed_id,
+        ))
+        .load::<(PlotId, TestbedId)>(conn)
+        .map_err(resource_not_found_err!(PlotTestbed, query_project))?
+    {
+        plot_testbeds
+            .entry(plot_id)
+            .or_default()
+            .push(get_uuid(testbed_uuids, testbed_id)?);
+    }
+    let mut plot_benchmarks = HashMap::<PlotId, Vec<_>>::new();
+    for (plot_id, benchmark_id) in schema::plot_benchmark::table
+        .inner_join(schema::plot::table)
+        .filter(schema::plot::project_id.eq(query_project.id))
+        .order((
+            schema::plot_benchmark::plot_id,
+            schema::plot_benchmark::rank.asc(),
+        ))
+        .select((
+            schema::plot_benchmark::plot_id,
+            schema::plot_benchmark::benchmark_id,
+        ))
+        .load::<(PlotId, BenchmarkId)>(conn)
+        .map_err(resource_not_found_err!(PlotBenchmark, query_project))?
+    {
+        plot_benchmarks
+            .entry(plot_id)
+            .or_default()
+            .push(get_uuid(benchmark_uuids, benchmark_id)?);
+    }
+    let mut plot_measures = HashMap::<PlotId, Vec<_>>::new();
+    for (plot_id, measure_id) in schema::plot_measure::table
+        .inner_join(schema::plot::table)
+        .filter(schema::plot::project_id.eq(query_project.id))
+        .order((
+            schema::plot_measure::plot_id,
+            schema::plot_measure::rank.asc(),
+        ))
+        .select((
+            schema::plot_measure::plot_id,
+            schema::plot_measure::measure_id,
+        ))
+        .load::<(PlotId, MeasureId)>(conn)
+        .map_err(resource_not_found_err!(PlotMeasure, query_project))?
+    {
+        plot_measures
+            .entry(plot_id)
+            .or_default()
+            .push(get_uuid(measure_uuids, measure_id)?);
+    }
+
+    Ok(schema::plot::table
+        .filter(schema::plot::project_id.eq(query_project.id))
+        .order(schema::plot::rank.asc())
+        .load::<QueryPlot>(conn)
+        .map_err(resource_not_found_err!(Plot, query_project))?
+        .into_iter()
+        .map(|plot| JsonExportPlot {
+            uuid: plot.uuid,
+            title: plot.title,
+            lower_value: plot.lower_value,
+            upper_value: plot.upper_value,
+            lower_boundary: plot.lower_boundary,
+            upper_boundary: plot.upper_boundary,
+            x_axis: plot.x_axis,
+            window: plot.window,
+            branches: plot_branches.remove(&plot.id).unwrap_or_default(),
+            testbeds: plot_testbeds.remove(&plot.id).unwrap_or_default(),
+            benchmarks: plot_benchmarks.remove(&plot.id).unwrap_or_default(),
+            benchmark_tags: plot.benchmark_tags,
+            measures: plot_measures.remove(&plot.id).unwrap_or_default(),
+            created: plot.created,
+            modified: plot.modified,
+        })
+        .collect())
+}
+
+// All of the resources for a project are loaded up front,
+// so a missing reference means that the database is inconsistent.
+fn get_uuid<Id, Uuid>(uuids: &HashMap<Id, Uuid>, id: Id) -> Result<Uuid, HttpError>
+where
+    Id: Copy + Eq + std::hash::Hash + std::fmt::Debug,
+    Uuid: Clone,
+{
+    uuids.get(&id).cloned().ok_or_else(|| {
+        crate::error::issue_error(
+            "Failed to export project",
+            "Failed to find a referenced resource while exporting a project:",
+            format!("{id:?}"),
+        )
+    })
+}
@@ -0,0 +1,1144 @@
+use std::collections::HashMap;
+
+use bencher_json::{
+    BenchmarkUuid, BoundaryUuid, BranchUuid, HeadUuid, JsonProjectExport, MeasureUuid, MetricUuid,
+    ModelUuid, ProjectUuid, TestbedUuid, ThresholdUuid, VersionUuid,
+    project::export::JsonExportProject,
+};
+use bencher_rank::RankGenerator;
+use diesel::{
+    Connection as _, ExpressionMethods as _, OptionalExtension as _, QueryDsl as _,
+    RunQueryDsl as _,
+};
+use dropshot::HttpError;
+
+use super::{
+    ProjectId, QueryProject,
+    benchmark::{BenchmarkId, InsertBenchmark, tag::InsertBenchmarkTag},
+    branch::{
+        BranchId, InsertBranch,
+        head::{HeadId, InsertHead},
+        head_version::{HeadVersionId, InsertHeadVersion},
+        version::{InsertVersion, VersionId},
+    },
+    measure::{InsertMeasure, MeasureId},
+    metric::{InsertMetric, MetricId},
+    plot::{InsertPlot, PlotId},
+    report::{InsertReport, report_benchmark::InsertReportBenchmark},
+    testbed::{InsertTestbed, TestbedId},
+    threshold::{
+        InsertThreshold, ThresholdId,
+        alert::{AlertId, InsertAlert},
+        boundary::{BoundaryId, InsertBoundary},
+        change_point::{ChangePointId, InsertChangePoint},
+        model::{InsertModel, ModelId},
+    },
+};
+use crate::{
+    context::DbConnection,
+    error::{
+        BencherResource, bad_request_error, issue_error, resource_conflict_err,
+        resource_conflict_error, resource_not_found_err,
+    },
+    model::organization::{OrganizationId, QueryOrganization},
+    schema,
+};
+
+// Find a resource by its UUID.
+// If it already exists with the same parent, then it is reused.
+// If it already exists with a different parent, then it is a conflict.
+// Otherwise, it is inserted.
+// Evaluates to the resource ID and whether or not it was inserted.
+macro_rules! get_or_insert {
+    ($conn:expr, $table:ident, $resource:ident, $uuid:expr, $parent:ident = $parent_id:expr, $insert:expr) => {{
+        let uuid = $uuid;
+        let parent_id = $parent_id;
+        let existing = schema::$table::table
+            .filter(schema::$table::uuid.eq(uuid))
+            .select((schema::$table::id, schema::$table::$parent))
+            .first::<(_, _)>($conn)
+            .optional()
+            .map_err(resource_not_found_err!($resource, uuid))?;
+        match existing {
+            Some((id, existing_parent_id)) if is_parent(existing_parent_id, parent_id) => {
+                (id, false)
+            },
+            Some(_) => {
+                return Err(resource_conflict_error(
+                    BencherResource::$resource,
+                    uuid,
+                    "The UUID already belongs to a different parent",
+                )
+                .into());
+            },
+            None => {
+                let insert = $insert;
+                diesel::insert_into(schema::$table::table)
+                    .values(&insert)
+                    .execute($conn)
+                    .map_err(resource_conflict_err!($resource, insert))?;
+                let id = schema::$table::table
+                    .filter(schema::$table::uuid.eq(uuid))
+                    .select(schema::$table::id)
+                    .first($conn)
+                    .map_err(resource_not_found_err!($resource, uuid))?;
+                (id, true)
+            },
+        }
+    }};
+}
+
+// Diesel transactions require an error type that can be created from a Diesel error.
+struct ImportError(HttpError);
+
+impl From<HttpError> for ImportError {
+    fn from(error: HttpError) -> Self {
+        Self(error)
+    }
+}
+
+impl From<diesel::result::Error> for ImportError {
+    fn from(error: diesel::result::Error) -> Self {
+        Self(issue_error(
+            "Failed to import project",
+            "Failed to run project import transaction:",
+            error,
+        ))
+    }
+}
+
+pub(super) fn import_uuid(
+    conn: &mut DbConnection,
+    query_organization: &QueryOrganization,
+    export_project: &JsonExportProject,
+) -> Result<ProjectUuid, HttpError> {
+    let organization_id = schema::project::table
+        .filter(schema::project::uuid.eq(export_project.uuid))
+        .select(schema::project::organization_id)
+        .first::<OrganizationId>(conn)
+        .optional()
+        .map_err(resource_not_found_err!(Project, export_project.uuid))?;
+    Ok(match organization_id {
+        Some(organization_id) if organization_id != query_organization.id => {
+            remap_uuid(query_organization.uuid.into(), export_project.uuid)
+        },
+        Some(_) | None => export_project.uuid,
+    })
+}
+
+pub(super) fn import(
+    conn: &mut DbConnection,
+    query_project: &QueryProject,
+    json_export: &JsonProjectExport,
+) -> Result<(), HttpError> {
+    if json_export.version != JsonProjectExport::VERSION {
+        return Err(bad_request_error(format!(
+            "Unsupported project archive version ({}). The supported version is {}.",
+            json_export.version,
+            JsonProjectExport::VERSION
+        )));
+    }
+    let namespace =
+        (query_project.uuid != json_export.project.uuid).then(|| query_project.uuid.into());
+    conn.transaction(|conn| Importer::new(query_project.id, namespace).import(conn, json_export))
+        .map_err(|ImportError(error)| error)
+}
+
+fn remap_uuid<U>(namespace: uuid::Uuid, uuid: U) -> U
+where
+    U: From<uuid::Uuid> + Into<uuid::Uuid>,
+{
+    uuid::Uuid::new_v5(&namespace, uuid.into().as_bytes()).into()
+}
+
+struct Importer {
+    project_id: ProjectId,
+    namespace: Option<uuid::Uuid>,
+    measure_ids: HashMap<MeasureUuid, MeasureId>,
+    testbed_ids: HashMap<TestbedUuid, TestbedId>,
+    benchmark_ids: HashMap<BenchmarkUuid, BenchmarkId>,
+    version_ids: HashMap<VersionUuid, VersionId>,
+    branch_ids: HashMap<BranchUuid, BranchId>,
+    head_ids: HashMap<HeadUuid, HeadId>,
+    head_version_ids: HashMap<(HeadId, VersionId), HeadVersionId>,
+    threshold_ids: HashMap<ThresholdUuid, ThresholdId>,
+    model_ids: HashMap<ModelUuid, ModelId>,
+    metric_ids: HashMap<MetricUuid, MetricId>,
+    boundary_ids: HashMap<BoundaryUuid, BoundaryId>,
+}
+
+impl Importer {
+    fn new(project_id: ProjectId, namespace: Option<uuid::Uuid>) -> Self {
+        Self {
+            project_id,
+            namespace,
+            measure_ids: HashMap::new(),
+            testbed_ids: HashMap::new(),
+            benchmark_ids: HashMap::new(),
+            version_ids: HashMap::new(),
+            branch_ids: HashMap::new(),
+            head_ids: HashMap::new(),
+            head_version_ids: HashMap::new(),
+            threshold_ids: HashMap::new(),
+            model_ids: HashMap::new(),
+            metric_ids: HashMap::new(),
+            boundary_ids: HashMap::new(),
+        }
+    }
+
+    fn uuid<U>(&self, uuid: U) -> U
+    where
+        U: From<uuid::Uuid> + Into<uuid::Uuid>,
+    {
+        if let Some(namespace) = self.namespace {
+            remap_uuid(namespace, uuid)
+        } else {
+            uuid
+        }
+    }
+
+    fn import(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        self.import_measures(conn, json_export)?;
+        self.import_testbeds(conn, json_export)?;
+        self.import_benchmarks(conn, json_export)?;
+        self.import_versions(conn, json_export)?;
+        self.import_branches(conn, json_export)?;
+        self.import_heads(conn, json_export)?;
+        self.import_thresholds(conn, json_export)?;
+        self.import_reports(conn, json_export)?;
+        self.import_boundaries(conn, json_export)?;
+        self.import_alerts(conn, json_export)?;
+        self.import_change_points(conn, json_export)?;
+        self.import_plots(conn, json_export)
+    }
+
+    fn import_measures(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        let project_id = self.project_id;
+
+        for measure in &json_export.measures {
+            let (id, _) = get_or_insert!(
+                conn,
+                measure,
+                Measure,
+                self.uuid(measure.uuid),
+                project_id = project_id,
+                InsertMeasure {
+                    uuid: self.uuid(measure.uuid),
+                    project_id,
+                    name: measure.name.clone(),
+                    slug: measure.slug.clone(),
+                    units: measure.units.clone(),
+                    created: measure.created,
+                    modified: measure.modified,
+                    archived: measure.archived,
+                }
+            );
+            self.measure_ids.insert(measure.uuid, id);
+        }
+        Ok(())
+    }
+
+    fn import_testbeds(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        let project_id = self.project_id;
+
+        for testbed in &json_export.testbeds {
+            let (id, _) = get_or_insert!(
+                conn,
+                testbed,
+                Testbed,
+                self.uuid(testbed.uuid),
+                project_id = project_id,
+                InsertTestbed {
+                    uuid: self.uuid(testbed.uuid),
+                    project_id,
+                    name: testbed.name.clone(),
+                    slug: testbed.slug.clone(),
+                    created: testbed.created,
+                    modified: testbed.modified,
+                    archived: testbed.archived,
+                }
+            );
+            self.testbed_ids.insert(testbed.uuid, id);
+        }
+        Ok(())
+    }
+
+    fn import_benchmarks(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        let project_id = self.project_id;
+
+        for benchmark in &json_export.benchmarks {
+            let (id, inserted) = get_or_insert!(
+                conn,
+                benchmark,
+                Benchmark,
+                self.uuid(benchmark.uuid),
+                project_id = project_id,
+                InsertBenchmark {
+                    uuid: self.uuid(benchmark.uuid),
+                    project_id,
+                    name: benchmark.name.clone(),
+                    slug: benchmark.slug.clone(),
+                    created: benchmark.created,
+                    modified: benchmark.modified,
+                    archived: benchmark.archived,
+                }
+            );
+            if inserted && let Some(tags) = &benchmark.tags {
+                InsertBenchmarkTag::upsert(conn, id, tags.clone())?;
+            }
+            self.benchmark_ids.insert(benchmark.uuid, id);
+        }
+        Ok(())
+    }
+
+    fn import_versions(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        let project_id = self.project_id;
+
+        for version in &json_export.versions {
+            let (id, _) = get_or_insert!(
+                conn,
+                version,
+                Version,
+                self.uuid(version.uuid),
+                project_id = project_id,
+                InsertVersion {
+                    uuid: self.uuid(version.uuid),
+                    project_id,
+                    number: version.number,
+                    hash: version.hash.clone(),
+                }
+            );
+            self.version_ids.insert(version.uuid, id);
+        }
+        Ok(())
+    }
+
+    fn import_branches(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        let project_id = self.project_id;
+
+        // Branches and heads reference each other,
+        // so the branch heads and head start points are set once they all exist.
+        for branch in &json_export.branches {
+            let (id, _) = get_or_insert!(
+                conn,
+                branch,
+                Branch,
+                self.uuid(branch.uuid),
+                project_id = project_id,
+                InsertBranch {
+                    uuid: self.uuid(branch.uuid),
+                    project_id,
+                    name: branch.name.clone(),
+                    slug: branch.slug.clone(),
+                    head_id: None,
+                    created: branch.created,
+                    modified: branch.modified,
+                    archived: branch.archived,
+                }
+            );
+            self.branch_ids.insert(branch.uuid, id);
+        }
+        Ok(())
+    }
+
+    fn import_heads(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        for head in &json_export.heads {
+            let branch_id = get_id(&self.branch_ids, BencherResource::Branch, head.branch)?;
+            let (id, _) = get_or_insert!(
+                conn,
+                head,
+                Head,
+                self.uuid(head.uuid),
+                branch_id = branch_id,
+                InsertHead {
+                    uuid: self.uuid(head.uuid),
+                    branch_id,
+                    start_point_id: None,
+                    created: head.created,
+                    replaced: head.replaced,
+                }
+            );
+            self.head_ids.insert(head.uuid, id);
+        }
+
+        for head in &json_export.heads {
+            let head_id = get_id(&self.head_ids, BencherResource::Head, head.uuid)?;
+            for version in &head.versions {
+                let version_id = get_id(&self.version_ids, BencherResource::Version, *version)?;
+                let id = get_or_insert_head_version(conn, head_id, version_id)?;
+                self.head_version_ids.insert((head_id, version_id), id);
+            }
+        }
+
+        for head in &json_export.heads {
+            let head_id = get_id(&self.head_ids, BencherResource::Head, head.uuid)?;
+            let start_point_id = if let Some(start_point) = &head.start_point {
+                let start_point_head_id =
+                    get_id(&self.head_ids, BencherResource::Head, start_point.head)?;
+                let version_id = get_id(
+                    &self.version_ids,
+                    BencherResource::Version,
+                    start_point.version,
+                )?;
+                Some(get_id(
+                    &self.head_version_ids,
+                    BencherResource::HeadVersion,
+                    (start_point_head_id, version_id),
+                )?)
+            } else {
+                None
+            };
+            diesel::update(schema::head::table.filter(schema::head::id.eq(head_id)))
+                .set((
+                    schema::head::start_point_id.eq(start_point_id),
+                    schema::head::replaced.eq(head.replaced),
+                ))
+                .execute(conn)
+                .map_err(resource_conflict_err!(Head, head.uuid))?;
+        }
+
+        for branch in &json_export.branches {
+            let branch_id = get_id(&self.branch_ids, BencherResource::Branch, branch.uuid)?;
+            let head_id = branch
+                .head
+                .map(|head| get_id(&self.head_ids, BencherResource::Head, head))
+                .transpose()?;
+            diesel::update(schema::branch::table.filter(schema::branch::id.eq(branch_id)))
+                .set(schema::branch::head_id.eq(head_id))
+                .execute(conn)
+                .map_err(resource_conflict_err!(Branch, branch.uuid))?;
+        }
+        Ok(())
+    }
+
+    fn import_thresholds(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        let project_id = self.project_id;
+
+        // Thresholds and models reference each other,
+        // so the threshold models are set once they all exist.
+        for threshold in &json_export.thresholds {
+            let (id, _) = get_or_insert!(
+                conn,
+                threshold,
+                Threshold,
+                self.uuid(threshold.uuid),
+                project_id = project_id,
+                InsertThreshold {
+                    uuid: self.uuid(threshold.uuid),
+                    project_id,
+                    branch_id: get_id(&self.branch_ids, BencherResource::Branch, threshold.branch)?,
+                    testbed_id: get_id(
+                        &self.testbed_ids,
+                        BencherResource::Testbed,
+                        threshold.testbed
+                    )?,
+                    measure_id: get_id(
+                        &self.measure_ids,
+                        BencherResource::Measure,
+                        threshold.measure
+                    )?,
+                    model_id: None,
+                    created: threshold.created,
+                    modified: threshold.modified,
+                    benchmark_tags: threshold.benchmark_tags.clone(),
+                }
+            );
+            self.threshold_ids.insert(threshold.uuid, id);
+        }
+
+        for model in &json_export.models {
+            let threshold_id = get_id(
+                &self.threshold_ids,
+                BencherResource::Threshold,
+                model.threshold,
+            )?;
+            let (id, _) = get_or_insert!(
+                conn,
+                model,
+                Model,
+                self.uuid(model.uuid),
+                threshold_id = threshold_id,
+                InsertModel {
+                    uuid: self.uuid(model.uuid),
+                    threshold_id,
+                    test: model.test,
+                    min_sample_size: model.min_sample_size,
+                    max_sample_size: model.max_sample_size,
+                    window: model.window,
+                    lower_boundary: model.lower_boundary,
+                    upper_boundary: model.upper_boundary,
+                    created: model.created,
+                    replaced: model.replaced,
+                }
+            );
+            diesel::update(schema::model::table.filter(schema::model::id.eq(id)))
+                .set(schema::model::replaced.eq(model.replaced))
+                .execute(conn)
+                .map_err(resource_conflict_err!(Model, model.uuid))?;
+            self.model_ids.insert(model.uuid, id);
+        }
+
+        for threshold in &json_export.thresholds {
+            let threshold_id = get_id(
+                &self.threshold_ids,
+                BencherResource::Threshold,
+                threshold.uuid,
+            )?;
+            let model_id = threshold
+                .model
+                .map(|model| get_id(&self.model_ids, BencherResource::Model, model))
+                .transpose()?;
+            diesel::update(schema::threshold::table.filter(schema::threshold::id.eq(threshold_id)))
+                .set(schema::threshold::model_id.eq(model_id))
+                .execute(conn)
+                .map_err(resource_conflict_err!(Threshold, threshold.uuid))?;
+        }
+        Ok(())
+    }
+
+    fn import_reports(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        let project_id = self.project_id;
+
+        for report in &json_export.reports {
+            let (report_id, _) = get_or_insert!(
+                conn,
+                report,
+                Report,
+                self.uuid(report.uuid),
+                project_id = project_id,
+                InsertReport {
+                    uuid: self.uuid(report.uuid),
+                    user_id: None,
+                    project_id,
+                    head_id: get_id(&self.head_ids, BencherResource::Head, report.head)?,
+                    version_id: get_id(
+                        &self.version_ids,
+                        BencherResource::Version,
+                        report.version
+                    )?,
+                    testbed_id: get_id(
+                        &self.testbed_ids,
+                        BencherResource::Testbed,
+                        report.testbed
+                    )?,
+                    adapter: report.adapter,
+                    start_time: report.start_time,
+                    end_time: report.end_time,
+                    created: report.created,
+                    group_uuid: report.group.map(|group| self.uuid(group)),
+                }
+            );
+            for result in &report.results {
+                let (report_benchmark_id, _) = get_or_insert!(
+                    conn,
+                    report_benchmark,
+                    ReportBenchmark,
+                    self.uuid(result.uuid),
+                    report_id = report_id,
+                    InsertReportBenchmark {
+                        uuid: self.uuid(result.uuid),
+                        report_id,
+                        iteration: result.iteration,
+                        benchmark_id: get_id(
+                            &self.benchmark_ids,
+                            BencherResource::Benchmark,
+                            result.benchmark
+                        )?,
+                    }
+                );
+                for metric in &result.metrics {
+                    let (id, _) = get_or_insert!(
+                        conn,
+                        metric,
+                        Metric,
+                        self.uuid(metric.uuid),
+                        report_benchmark_id = report_benchmark_id,
+                        InsertMetric {
+                            uuid: self.uuid(metric.uuid),
+                            report_benchmark_id,
+                            measure_id: get_id(
+                                &self.measure_ids,
+                                BencherResource::Measure,
+                                metric.measure
+                            )?,
+                            value: metric.value,
+                            lower_value: metric.lower_value,
+                            upper_value: metric.upper_value,
+                        }
+                    );
+                    self.metric_ids.insert(metric.uuid, id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn import_boundaries(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        for boundary in &json_export.boundaries {
+            let metric_id = get_id(&self.metric_ids, BencherResource::Metric, boundary.metric)?;
+            let (id, _) = get_or_insert!(
+                conn,
+                boundary,
+                Boundary,
+                self.uuid(boundary.uuid),
+                metric_id = metric_id,
+                InsertBoundary {
+                    uuid: self.uuid(boundary.uuid),
+                    metric_id,
+                    threshold_id: get_id(
+                        &self.threshold_ids,
+                        BencherResource::Threshold,
+                        boundary.threshold
+                    )?,
+                    model_id: get_id(&self.model_ids, BencherResource::Model, boundary.model)?,
+                    baseline: boundary.baseline,
+                    lower_limit: boundary.lower_limit,
+                    upper_limit: boundary.upper_limit,
+                }
+            );
+            self.boundary_ids.insert(boundary.uuid, id);
+        }
+        Ok(())
+    }
+
+    fn import_alerts(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        for alert in &json_export.alerts {
+            let boundary_id: BoundaryId = get_id(
+                &self.boundary_ids,
+                BencherResource::Boundary,
+                alert.boundary,
+            )?;
+            let (id, inserted): (AlertId, bool) = get_or_insert!(
+                conn,
+                alert,
+                Alert,
+                self.uuid(alert.uuid),
+                boundary_id = boundary_id,
+                InsertAlert {
+                    uuid: self.uuid(alert.uuid),
+                    boundary_id,
+                    boundary_limit: alert.limit,
+                    status: alert.status,
+                    modified: alert.modified,
+                }
+            );
+            // Keep the alert status in sync, as it may have been dismissed since the last import.
+            if !inserted {
+                diesel::update(schema::alert::table.filter(schema::alert::id.eq(id)))
+                    .set((
+                        schema::alert::status.eq(alert.status),
+                        schema::alert::modified.eq(alert.modified),
+                    ))
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(Alert, alert.uuid))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn import_change_points(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        for change_point in &json_export.change_points {
+            let metric_id = get_id(
+                &self.metric_ids,
+                BencherResource::Metric,
+                change_point.metric,
+            )?;
+            let _: (ChangePointId, bool) = get_or_insert!(
+                conn,
+                change_point,
+                ChangePoint,
+                self.uuid(change_point.uuid),
+                metric_id = metric_id,
+                InsertChangePoint {
+                    uuid: self.uuid(change_point.uuid),
+                    metric_id,
+                    threshold_id: get_id(
+                        &self.threshold_ids,
+                        BencherResource::Threshold,
+                        change_point.threshold
+                    )?,
+                    model_id: get_id(&self.model_ids, BencherResource::Model, change_point.model)?,
+                    baseline: change_point.baseline,
+                    value: change_point.value,
+                    created: change_point.created,
+                }
+            );
+        }
+        Ok(())
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn import_plots(
+        &mut self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), ImportError> {
+        let project_id = self.project_id;
+
+        // Plots keep their relative order.
+        // The branches, testbeds, benchmarks, and measures for a plot are only added when it is created.
+        for (plot, rank) in json_export
+            .plots
+            .iter()
+            .zip(RankGenerator::new(json_export.plots.len()))
+        {
+            let (plot_id, inserted): (PlotId, bool) = get_or_insert!(
+                conn,
+                plot,
+                Plot,
+                self.uuid(plot.uuid),
+                project_id = project_id,
+                InsertPlot {
+                    uuid: self.uuid(plot.uuid),
+                    project_id,
+                    rank,
+                    title: plot.title.clone(),
+                    lower_value: plot.lower_value,
+                    upper_value: plot.upper_value,
+                    lower_boundary: plot.lower_boundary,
+                    upper_boundary: plot.upper_boundary,
+                    x_axis: plot.x_axis,
+                    window: plot.window,
+                    created: plot.created,
+                    modified: plot.modified,
+                    benchmark_tags: plot.benchmark_tags.clone(),
+                }
+            );
+            if !inserted {
+                continue;
+            }
+            for (branch, rank) in plot
+                .branches
+                .iter()
+                .zip(RankGenerator::new(plot.branches.len()))
+            {
+                diesel::insert_into(schema::plot_branch::table)
+                    .values((
+                        schema::plot_branch::plot_id.eq(plot_id),
+                        schema::plot_branch::branch_id.eq(get_id(
+                            &self.branch_ids,
+                            BencherResource::Branch,
+                            *branch,
+                        )?),
+                        schema::plot_branch::rank.eq(rank),
+                    ))
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(PlotBranch, (plot.uuid, branch)))?;
+            }
+            for (testbed, rank) in plot
+                .testbeds
+                .iter()
+                .zip(RankGenerator::new(plot.testbeds.len()))
+            {
+                diesel::insert_into(schema::plot_testbed::table)
+                    .values((
+                        schema::plot_testbed::plot_id.eq(plot_id),
+                        schema::plot_testbed::testbed_id.eq(get_id(
+                            &self.testbed_ids,
+                            BencherResource::Testbed,
+                            *testbed,
+                        )?),
+                        schema::plot_testbed::rank.eq(rank),
+                    ))
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(PlotTestbed, (plot.uuid, testbed)))?;
+            }
+            for (benchmark, rank) in plot
+                .benchmarks
+                .iter()
+                .zip(RankGenerator::new(plot.benchmarks.len()))
+            {
+                diesel::insert_into(schema::plot_benchmark::table)
+                    .values((
+                        schema::plot_benchmark::plot_id.eq(plot_id),
+                        schema::plot_benchmark::benchmark_id.eq(get_id(
+                            &self.benchmark_ids,
+                            BencherResource::Benchmark,
+                            *benchmark,
+                        )?),
+                        schema::plot_benchmark::rank.eq(rank),
+                    ))
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(
+                        PlotBenchmark,
+                        (plot.uuid, benchmark)
+                    ))?;
+            }
+            for (measure, rank) in plot
+                .measures
+                .iter()
+                .zip(RankGenerator::new(plot.measures.len()))
+            {
+                diesel::insert_into(schema::plot_measure::table)
+                    .values((
+                        schema::plot_measure::plot_id.eq(plot_id),
+                        schema::plot_measure::measure_id.eq(get_id(
+                            &self.measure_ids,
+                            BencherResource::Measure,
+                            *measure,
+                        )?),
+                        schema::plot_measure::rank.eq(rank),
+                    ))
+                    .execute(conn)
+                    .map_err(resource_conflict_err!(PlotMeasure, (plot.uuid, measure)))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn get_or_insert_head_version(
+    conn: &mut DbConnection,
+    head_id: HeadId,
+    version_id: VersionId,
+) -> Result<HeadVersionId, HttpError> {
+    let select_id = || {
+        schema::head_version::table
+            .filter(schema::head_version::head_id.eq(head_id))
+            .filter(schema::head_version::version_id.eq(version_id))
+            .select(schema::head_version::id)
+    };
+    if let Some(id) = select_id()
+        .first(conn)
+        .optional()
+        .map_err(resource_not_found_err!(HeadVersion, (head_id, version_id)))?
+    {
+        return Ok(id);
+    }
+    let insert_head_version = InsertHeadVersion {
+        head_id,
+        version_id,
+    };
+    diesel::insert_into(schema::head_version::table)
+        .values(&insert_head_version)
+        .execute(conn)
+        .map_err(resource_conflict_err!(HeadVersion, insert_head_version))?;
+    select_id()
+        .first(conn)
+        .map_err(resource_not_found_err!(HeadVersion, (head_id, version_id)))
+}
+
+fn is_parent<Id>(existing_parent_id: Id, parent_id: Id) -> bool
+where
+    Id: Copy + PartialEq,
+{
+    existing_parent_id == parent_id
+}
+
+// Every reference in the archive must be to a resource that is also in the archive.
+fn get_id<K, Id>(ids: &HashMap<K, Id>, resource: BencherResource, key: K) -> Result<Id, HttpError>
+where
+    K: Copy + Eq + std::hash::Hash + std::fmt::Debug,
+    Id: Copy,
+{
+    ids.get(&key).copied().ok_or_else(|| {
+        bad_request_error(format!(
+            "The project archive references a missing {resource} ({key:?})"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::{JsonProjectExport, ProjectUuid};
+    use diesel::{
+        Connection as _, ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _,
+        connection::SimpleConnection as _,
+    };
+
+    use crate::{
+        context::DbConnection,
+        model::{
+            organization::OrganizationId,
+            project::{InsertProject, QueryProject},
+        },
+        run_migrations, schema,
+    };
+
+    const CREATED: &str = "2024-01-01T00:00:00Z";
+    const MODIFIED: &str = "2024-02-01T00:00:00Z";
+
+    fn uuid(n: u8) -> String {
+        format!("00000000-0000-4000-8000-0000000000{n:02}")
+    }
+
+    fn archive() -> JsonProjectExport {
+        serde_json::from_value(serde_json::json!({
+            "version": 1,
+            "project": { "uuid": uuid(1), "name": "Project", "slug": "project", "url": null, "visibility": "public", "created": CREATED, "modified": MODIFIED },
+            "measures": [
+                { "uuid": uuid(2), "name": "Latency", "slug": "latency", "units": "nanoseconds (ns)", "created": CREATED, "modified": MODIFIED, "archived": null }
+            ],
+            "testbeds": [
+                { "uuid": uuid(3), "name": "localhost", "slug": "localhost", "created": CREATED, "modified": MODIFIED, "archived": null }
+            ],
+            "benchmarks": [
+                { "uuid": uuid(4), "name": "bench_a", "slug": "bench-a", "tags": { "os": "linux" }, "created": CREATED, "modified": MODIFIED, "archived": null },
+                { "uuid": uuid(5), "name": "bench_b", "slug": "bench-b", "tags": null, "created": CREATED, "modified": MODIFIED, "archived": MODIFIED }
+            ],
+            "versions": [
+                { "uuid": uuid(6), "number": 0, "hash": "1234567890abcdef1234567890abcdef12345678" },
+                { "uuid": uuid(7), "number": 1, "hash": null }
+            ],
+            "branches": [
+                { "uuid": uuid(8), "name": "main", "slug": "main", "head": uuid(10), "created": CREATED, "modified": MODIFIED, "archived": null },
+                { "uuid": uuid(9), "name": "feature", "slug": "feature", "head": uuid(11), "created": CREATED, "modified": MODIFIED, "archived": null }
+            ],
+            "heads": [
+                { "uuid": uuid(10), "branch": uuid(8), "start_point": null, "versions": [uuid(6), uuid(7)], "created": CREATED, "replaced": null },
+                { "uuid": uuid(11), "branch": uuid(9), "start_point": { "head": uuid(10), "version": uuid(6) }, "versions": [uuid(6)], "created": CREATED, "replaced": null }
+            ],
+            "thresholds": [
+                { "uuid": uuid(12), "branch": uuid(8), "testbed": uuid(3), "measure": uuid(2), "benchmark_tags": null, "model": uuid(14), "created": CREATED, "modified": MODIFIED }
+            ],
+            "models": [
+                { "uuid": uuid(13), "threshold": uuid(12), "test": "static", "min_sample_size": null, "max_sample_size": null, "window": null, "lower_boundary": null, "upper_boundary": 2.0, "created": CREATED, "replaced": MODIFIED },
+                { "uuid": uuid(14), "threshold": uuid(12), "test": "t_test", "min_sample_size": 2, "max_sample_size": 64, "window": 1000, "lower_boundary": null, "upper_boundary": 0.99, "created": MODIFIED, "replaced": null }
+            ],
+            "reports": [
+                { "uuid": uuid(15), "head": uuid(10), "version": uuid(6), "testbed": uuid(3), "adapter": "json", "start_time": CREATED, "end_time": MODIFIED, "created": MODIFIED, "results": [
+                    { "uuid": uuid(16), "iteration": 0, "benchmark": uuid(4), "metrics": [
+                        { "uuid": uuid(17), "measure": uuid(2), "value": 1.5, "lower_value": 1.0, "upper_value": 2.0 }
+                    ] },
+                    { "uuid": uuid(18), "iteration": 0, "benchmark": uuid(5), "metrics": [
+                        { "uuid": uuid(19), "measure": uuid(2), "value": 0.300_000_000_000_000_04, "lower_value": null, "upper_value": null }
+                    ] }
+                ] }
+            ],
+            "boundaries": [
+                { "uuid": uuid(20), "metric": uuid(17), "threshold": uuid(12), "model": uuid(14), "baseline": 1.25, "lower_limit": null, "upper_limit": 1.4 }
+            ],
+            "alerts": [
+                { "uuid": uuid(21), "boundary": uuid(20), "limit": "upper", "status": "dismissed", "modified": MODIFIED }
+            ],
+            "change_points": [
+                { "uuid": uuid(22), "metric": uuid(17), "threshold": uuid(12), "model": uuid(14), "baseline": 1.25, "value": 1.5, "created": MODIFIED }
+            ],
+            "plots": [
+                { "uuid": uuid(23), "title": "Latency", "lower_value": false, "upper_value": true, "lower_boundary": false, "upper_boundary": true, "x_axis": "date_time", "window": 2_419_200, "branches": [uuid(9), uuid(8)], "testbeds": [uuid(3)], "benchmarks": [uuid(5), uuid(4)], "benchmark_tags": null, "measures": [uuid(2)], "created": CREATED, "modified": MODIFIED },
+                { "uuid": uuid(24), "title": null, "lower_value": true, "upper_value": false, "lower_boundary": true, "upper_boundary": false, "x_axis": "version", "window": 86_400, "branches": [uuid(8)], "testbeds": [uuid(3)], "benchmarks": [uuid(4)], "benchmark_tags": null, "measures": [uuid(2)], "created": CREATED, "modified": MODIFIED }
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn database() -> DbConnection {
+        let mut conn = DbConnection::establish(":memory:").unwrap();
+        run_migrations(&mut conn).unwrap();
+        with_organization(conn)
+    }
+
+    fn postgres_database() -> DbConnection {
+        with_organization(crate::tests::postgres_database().unwrap())
+    }
+
+    fn with_organization(mut conn: DbConnection) -> DbConnection {
+        conn.batch_execute(&format!(
+            "INSERT INTO organization (uuid, name, slug, created, modified) VALUES ('{}', 'Organization', 'organization', 0, 0)",
+            uuid(0)
+        ))
+        .unwrap();
+        conn
+    }
+
+    fn project(
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+        uuid: ProjectUuid,
+        copy: Option<&str>,
+    ) -> QueryProject {
+        let organization_id = schema::organization::table
+            .select(schema::organization::id)
+            .first::<OrganizationId>(conn)
+            .unwrap();
+        let mut export_project = json_export.project.clone();
+        if let Some(copy) = copy {
+            export_project.name = copy.parse().unwrap();
+            export_project.slug = copy.parse().unwrap();
+        }
+        diesel::insert_into(schema::project::table)
+            .values(&InsertProject::from_export(
+                organization_id,
+                uuid,
+                export_project,
+            ))
+            .execute(conn)
+            .unwrap();
+        schema::project::table
+            .filter(schema::project::uuid.eq(uuid))
+            .first::<QueryProject>(conn)
+            .unwrap()
+    }
+
+    fn to_value(json_export: &JsonProjectExport) -> serde_json::Value {
+        serde_json::to_value(json_export).unwrap()
+    }
+
+    #[test]
+    fn project_export_import_round_trip() {
+        export_import_round_trip(database);
+    }
+
+    #[test]
+    fn project_export_import_round_trip_postgres() {
+        if std::env::var(crate::tests::TEST_POSTGRES_URL).is_err() {
+            return;
+        }
+        export_import_round_trip(postgres_database);
+    }
+
+    fn export_import_round_trip(database: fn() -> DbConnection) {
+        let json_archive = archive();
+
+        let mut conn = database();
+        let query_project = project(&mut conn, &json_archive, json_archive.project.uuid, None);
+        query_project.import(&mut conn, &json_archive).unwrap();
+        let json_export = query_project.export(&mut conn).unwrap();
+        assert_eq!(to_value(&json_archive), to_value(&json_export));
+
+        // Importing the same archive again does not change anything.
+        query_project.import(&mut conn, &json_export).unwrap();
+        assert_eq!(
+            to_value(&json_export),
+            to_value(&query_project.export(&mut conn).unwrap())
+        );
+
+        // Export -> import into another server -> export is identical.
+        let mut other_conn = database();
+        let other_project = project(
+            &mut other_conn,
+            &json_export,
+            json_export.project.uuid,
+            None,
+        );
+        other_project.import(&mut other_conn, &json_export).unwrap();
+        assert_eq!(
+            to_value(&json_export),
+            to_value(&other_project.export(&mut other_conn).unwrap())
+        );
+    }
+
+    #[test]
+    fn project_import_remap() {
+        let json_archive = archive();
+
+        let mut conn = database();
+        let query_project = project(&mut conn, &json_archive, json_archive.project.uuid, None);
+        query_project.import(&mut conn, &json_archive).unwrap();
+
+        // Import a copy of the project into the same server.
+        let copy_uuid = ProjectUuid::new();
+        let copy_project = project(&mut conn, &json_archive, copy_uuid, Some("copy"));
+        copy_project.import(&mut conn, &json_archive).unwrap();
+        copy_project.import(&mut conn, &json_archive).unwrap();
+        let json_copy = copy_project.export(&mut conn).unwrap();
+
+        assert_eq!(
+            to_value(&json_archive),
+            to_value(&query_project.export(&mut conn).unwrap())
+        );
+        assert_eq!(json_copy.project.uuid, copy_uuid);
+        assert_eq!(json_copy.reports.len(), json_archive.reports.len());
+        assert_eq!(json_copy.plots.len(), json_archive.plots.len());
+        for (copy, original) in json_copy.branches.iter().zip(&json_archive.branches) {
+            assert_eq!(copy.name, original.name);
+            assert_ne!(copy.uuid, original.uuid);
+        }
+
+        // The copy can also be imported into another server.
+        let mut other_conn = database();
+        let other_project = project(&mut other_conn, &json_copy, copy_uuid, Some("copy"));
+        other_project.import(&mut other_conn, &json_copy).unwrap();
+        assert_eq!(
+            to_value(&json_copy),
+            to_value(&other_project.export(&mut other_conn).unwrap())
+        );
+    }
+
+    #[test]
+    fn project_import_conflict() {
+        let json_archive = archive();
+
+        let mut conn = database();
+        let query_project = project(&mut conn, &json_archive, json_archive.project.uuid, None);
+        query_project.import(&mut conn, &json_archive).unwrap();
+
+        // A different project with the same UUIDs in the archive is a conflict,
+        // and nothing is imported.
+        let mut json_conflict = json_archive.clone();
+        json_conflict.project.uuid = ProjectUuid::new();
+        let conflict_project = project(
+            &mut conn,
+            &json_conflict,
+            json_conflict.project.uuid,
+            Some("conflict"),
+        );
+        let mut conflict_archive = json_archive.clone();
+        conflict_archive.project.uuid = conflict_project.uuid;
+        let error = conflict_project
+            .import(&mut conn, &conflict_archive)
+            .unwrap_err();
+        assert_eq!(error.status_code.as_u16(), 409);
+        assert!(
+            conflict_project
+                .export(&mut conn)
+                .unwrap()
+                .measures
+                .is_empty()
+        );
+
+        let mut json_version = json_archive;
+        json_version.version += 1;
+        let error = query_project.import(&mut conn, &json_version).unwrap_err();
+        assert_eq!(error.status_code.as_u16(), 400);
+    }
+}
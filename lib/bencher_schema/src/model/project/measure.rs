@@ -73,6 +73,7 @@ impl QueryMeasure {
         Ok(query_measure.id)
     }
 
+    #[expect(clippy::too_many_lines)]
     async fn get_or_create_inner(
         context: &ApiContext,
         project_id: ProjectId,
@@ -93,11 +94,28 @@ impl QueryMeasure {
             .or_else(|| built_in::default::Throughput::from_str(measure_str))
             .or_else(|| built_in::json::BuildTime::from_str(measure_str))
             .or_else(|| built_in::json::FileSize::from_str(measure_str))
+            .or_else(|| built_in::go::BytesPerOp::from_str(measure_str))
+            .or_else(|| built_in::go::AllocsPerOp::from_str(measure_str))
+            .or_else(|| built_in::go::MegabytesPerSecond::from_str(measure_str))
             .or_else(|| built_in::iai::Instructions::from_str(measure_str))
             .or_else(|| built_in::iai::L1Accesses::from_str(measure_str))
             .or_else(|| built_in::iai::L2Accesses::from_str(measure_str))
             .or_else(|| built_in::iai::RamAccesses::from_str(measure_str))
             .or_else(|| built_in::iai::EstimatedCycles::from_str(measure_str))
+            // perf stat
+            .or_else(|| built_in::perf_stat::TaskClock::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::CpuClock::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::ContextSwitches::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::CpuMigrations::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::PageFaults::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::Cycles::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::StalledCyclesFrontend::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::StalledCyclesBackend::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::Instructions::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::Branches::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::BranchMisses::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::CacheReferences::from_str(measure_str))
+            .or_else(|| built_in::perf_stat::CacheMisses::from_str(measure_str))
             // Gungraun:
             // callgrind/cachegrind
             .or_else(|| built_in::gungraun::Instructions::from_str(measure_str))
@@ -1,9 +1,12 @@
 use std::{string::ToString as _, sync::LazyLock};
 
 use bencher_json::{
-    DateTime, JsonNewProject, JsonProject, ProjectResourceId, ProjectSlug, ProjectUuid,
-    ResourceName, Url,
-    project::{JsonProjectPatch, JsonProjectPatchNull, JsonUpdateProject, ProjectRole, Visibility},
+    DateTime, JsonNewProject, JsonProject, JsonProjectExport, ProjectResourceId, ProjectSlug,
+    ProjectUuid, ResourceName, Url,
+    project::{
+        JsonProjectPatch, JsonProjectPatchNull, JsonUpdateProject, ProjectRole, Visibility,
+        export::JsonExportProject,
+    },
 };
 use bencher_rbac::{Organization, Project, project::Permission};
 use diesel::{
@@ -35,14 +38,19 @@ use super::organization::OrganizationId;
 
 pub mod benchmark;
 pub mod branch;
+mod export;
+mod import;
 pub mod measure;
 pub mod metric;
 pub mod metric_boundary;
 pub mod plot;
+pub mod project_member;
 pub mod project_role;
 pub mod report;
+pub mod retention;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 crate::macros::typed_id::typed_id!(ProjectId);
 
@@ -66,6 +74,7 @@ pub struct QueryProject {
     pub visibility: Visibility,
     pub created: DateTime,
     pub modified: DateTime,
+    pub openmetrics: bool,
 }
 
 impl QueryProject {
@@ -357,6 +366,36 @@ impl QueryProject {
             )))
     }
 
+    /// Export all of the data for the project as a versioned archive.
+    /// Every list is in creation order, so exporting the same data always produces the same archive.
+    pub fn export(&self, conn: &mut DbConnection) -> Result<JsonProjectExport, HttpError> {
+        export::export(conn, self)
+    }
+
+    /// The UUID to use for a project imported into an organization.
+    /// The archive project UUID is preserved,
+    /// unless it is already used by a project in a different organization.
+    pub fn import_uuid(
+        conn: &mut DbConnection,
+        query_organization: &QueryOrganization,
+        export_project: &JsonExportProject,
+    ) -> Result<ProjectUuid, HttpError> {
+        import::import_uuid(conn, query_organization, export_project)
+    }
+
+    /// Import all of the data from a project archive into this project.
+    /// If the project UUID differs from the archive project UUID,
+    /// then all of the archive UUIDs are deterministically remapped for this project.
+    /// Resources that already exist are reused, so importing the same archive more than once is idempotent.
+    /// Everything is imported in a single transaction.
+    pub fn import(
+        &self,
+        conn: &mut DbConnection,
+        json_export: &JsonProjectExport,
+    ) -> Result<(), HttpError> {
+        import::import(conn, self, json_export)
+    }
+
     pub fn is_allowed(
         conn: &mut DbConnection,
         rbac: &Rbac,
@@ -463,6 +502,7 @@ impl QueryProject {
             visibility,
             created,
             modified,
+            openmetrics,
             ..
         } = self;
         assert_parentage(
@@ -479,6 +519,7 @@ impl QueryProject {
             slug,
             url,
             visibility,
+            openmetrics,
             created,
             modified,
             claimed,
@@ -497,6 +538,7 @@ pub struct InsertProject {
     pub visibility: Visibility,
     pub created: DateTime,
     pub modified: DateTime,
+    pub openmetrics: bool,
 }
 
 impl InsertProject {
@@ -543,6 +585,34 @@ impl InsertProject {
         )
     }
 
+    pub fn from_export(
+        organization_id: OrganizationId,
+        uuid: ProjectUuid,
+        export_project: JsonExportProject,
+    ) -> Self {
+        let JsonExportProject {
+            name,
+            slug,
+            url,
+            visibility,
+            openmetrics,
+            created,
+            modified,
+            ..
+        } = export_project;
+        Self {
+            uuid,
+            organization_id,
+            name,
+            slug,
+            url,
+            visibility,
+            created,
+            modified,
+            openmetrics,
+        }
+    }
+
     pub fn new(
         organization_id: OrganizationId,
         name: ResourceName,
@@ -578,6 +648,7 @@ impl InsertProject {
             visibility: visibility.unwrap_or_default(),
             created: timestamp,
             modified: timestamp,
+            openmetrics: false,
         }
     }
 
@@ -620,6 +691,7 @@ pub struct UpdateProject {
     pub url: Option<Option<Url>>,
     pub visibility: Option<Visibility>,
     pub modified: DateTime,
+    pub openmetrics: Option<bool>,
 }
 
 impl From<JsonUpdateProject> for UpdateProject {
@@ -631,6 +703,7 @@ impl From<JsonUpdateProject> for UpdateProject {
                     slug,
                     url,
                     visibility,
+                    openmetrics,
                 } = patch;
                 Self {
                     name,
@@ -638,6 +711,7 @@ impl From<JsonUpdateProject> for UpdateProject {
                     url: url.map(Some),
                     visibility,
                     modified: DateTime::now(),
+                    openmetrics,
                 }
             },
             JsonUpdateProject::Null(patch_url) => {
@@ -646,6 +720,7 @@ impl From<JsonUpdateProject> for UpdateProject {
                     slug,
                     url: (),
                     visibility,
+                    openmetrics,
                 } = patch_url;
                 Self {
                     name,
@@ -653,6 +728,7 @@ impl From<JsonUpdateProject> for UpdateProject {
                     url: Some(None),
                     visibility,
                     modified: DateTime::now(),
+                    openmetrics,
                 }
             },
         }
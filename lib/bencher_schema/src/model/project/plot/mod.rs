@@ -1,5 +1,5 @@
 use bencher_json::{
-    DateTime, Index, JsonNewPlot, JsonPlot, PlotUuid, ResourceName, Window,
+    DateTime, Index, JsonNewPlot, JsonPlot, PlotUuid, ResourceName, TagSelectors, Window,
     project::plot::{JsonPlotPatch, JsonPlotPatchNull, JsonUpdatePlot, XAxis},
 };
 use bencher_rank::{Rank, RankGenerator, Ranked};
@@ -49,6 +49,7 @@ pub struct QueryPlot {
     pub window: Window,
     pub created: DateTime,
     pub modified: DateTime,
+    pub benchmark_tags: Option<TagSelectors>,
 }
 
 impl QueryPlot {
@@ -177,6 +178,7 @@ impl QueryPlot {
             window,
             created,
             modified,
+            benchmark_tags,
             ..
         } = self;
         Ok(JsonPlot {
@@ -192,6 +194,7 @@ impl QueryPlot {
             branches,
             testbeds,
             benchmarks,
+            benchmark_tags,
             measures,
             created,
             modified,
@@ -221,6 +224,7 @@ pub struct InsertPlot {
     pub window: Window,
     pub created: DateTime,
     pub modified: DateTime,
+    pub benchmark_tags: Option<TagSelectors>,
 }
 
 impl InsertPlot {
@@ -244,6 +248,7 @@ impl InsertPlot {
             branches,
             testbeds,
             benchmarks,
+            benchmark_tags,
             measures,
         } = plot;
         let rank = QueryPlot::new_rank(conn_lock!(context), query_project, index)?;
@@ -261,6 +266,7 @@ impl InsertPlot {
             window,
             created: timestamp,
             modified: timestamp,
+            benchmark_tags,
         };
         diesel::insert_into(plot_table::table)
             .values(&insert_plot)
@@ -0,0 +1,145 @@
+use bencher_json::{DateTime, Email, JsonProjectMember, RoleUuid, Slug, UserName, UserUuid};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+
+use super::{ProjectId, QueryProject};
+use crate::{
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    model::{
+        organization::{OrganizationId, custom_role::CustomRoleId},
+        user::UserId,
+    },
+    schema::{self, project_member as project_member_table},
+};
+
+crate::macros::typed_id::typed_id!(ProjectMemberId);
+
+/// A project member with a custom role.
+#[derive(diesel::Queryable)]
+pub struct QueryProjectMember {
+    pub uuid: UserUuid,
+    pub name: UserName,
+    pub slug: Slug,
+    pub email: Email,
+    pub role: RoleUuid,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryProjectMember {
+    pub fn get(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        user_id: UserId,
+    ) -> Result<Self, HttpError> {
+        schema::project_member::table
+            .inner_join(schema::user::table)
+            .inner_join(schema::custom_role::table)
+            .filter(schema::project_member::project_id.eq(query_project.id))
+            .filter(schema::project_member::user_id.eq(user_id))
+            .select((
+                schema::user::uuid,
+                schema::user::name,
+                schema::user::slug,
+                schema::user::email,
+                schema::custom_role::uuid,
+                schema::project_member::created,
+                schema::project_member::modified,
+            ))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(
+                ProjectMember,
+                (query_project, user_id)
+            ))
+    }
+
+    /// Remove all of the custom role assignments that a user has in an organization.
+    pub fn delete_for_organization(
+        conn: &mut DbConnection,
+        user_id: UserId,
+        organization_id: OrganizationId,
+    ) -> Result<(), HttpError> {
+        let role_ids = schema::custom_role::table
+            .filter(schema::custom_role::organization_id.eq(organization_id))
+            .select(schema::custom_role::id);
+        diesel::delete(
+            schema::project_member::table
+                .filter(schema::project_member::user_id.eq(user_id))
+                .filter(schema::project_member::role_id.eq_any(role_ids)),
+        )
+        .execute(conn)
+        .map_err(resource_conflict_err!(
+            ProjectMember,
+            (user_id, organization_id)
+        ))?;
+        Ok(())
+    }
+
+    pub fn into_json(self) -> JsonProjectMember {
+        let Self {
+            uuid,
+            name,
+            slug,
+            email,
+            role,
+            created,
+            modified,
+        } = self;
+        JsonProjectMember {
+            uuid,
+            name,
+            slug,
+            email,
+            role,
+            created,
+            modified,
+        }
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = project_member_table)]
+pub struct InsertProjectMember {
+    pub user_id: UserId,
+    pub project_id: ProjectId,
+    pub role_id: CustomRoleId,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertProjectMember {
+    pub fn new(user_id: UserId, project_id: ProjectId, role_id: CustomRoleId) -> Self {
+        let timestamp = DateTime::now();
+        Self {
+            user_id,
+            project_id,
+            role_id,
+            created: timestamp,
+            modified: timestamp,
+        }
+    }
+
+    /// Assign the custom role, replacing any custom role the user already has for the project.
+    pub fn upsert(&self, conn: &mut DbConnection) -> Result<(), HttpError> {
+        let updated = diesel::update(
+            schema::project_member::table
+                .filter(schema::project_member::user_id.eq(self.user_id))
+                .filter(schema::project_member::project_id.eq(self.project_id)),
+        )
+        .set((
+            schema::project_member::role_id.eq(self.role_id),
+            schema::project_member::modified.eq(self.modified),
+        ))
+        .execute(conn)
+        .map_err(resource_conflict_err!(ProjectMember, self))?;
+        if updated > 0 {
+            return Ok(());
+        }
+        diesel::insert_into(schema::project_member::table)
+            .values(self)
+            .execute(conn)
+            .map_err(resource_conflict_err!(ProjectMember, self))?;
+        Ok(())
+    }
+}
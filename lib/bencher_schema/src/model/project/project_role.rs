@@ -35,6 +35,7 @@ pub enum Permission {
     Edit,
     Delete,
     Manage,
+    CreateReport,
     ViewRole,
     CreateRole,
     EditRole,
@@ -49,6 +50,7 @@ impl From<ProjectPermission> for Permission {
             ProjectPermission::Edit => Self::Edit,
             ProjectPermission::Delete => Self::Delete,
             ProjectPermission::Manage => Self::Manage,
+            ProjectPermission::CreateReport => Self::CreateReport,
             ProjectPermission::ViewRole => Self::ViewRole,
             ProjectPermission::CreateRole => Self::CreateRole,
             ProjectPermission::EditRole => Self::EditRole,
@@ -65,6 +67,7 @@ impl From<Permission> for bencher_rbac::project::Permission {
             Permission::Edit => Self::Edit,
             Permission::Delete => Self::Delete,
             Permission::Manage => Self::Manage,
+            Permission::CreateReport => Self::CreateReport,
             Permission::ViewRole => Self::ViewRole,
             Permission::CreateRole => Self::CreateRole,
             Permission::EditRole => Self::EditRole,
@@ -1,5 +1,5 @@
 use bencher_json::{
-    DateTime, JsonNewReport, JsonReport, ReportUuid,
+    DateTime, JsonNewReport, JsonReport, ReportGroupUuid, ReportUuid,
     project::report::{
         Adapter, Iteration, JsonReportAlerts, JsonReportMeasure, JsonReportResult,
         JsonReportResults,
@@ -62,6 +62,7 @@ pub struct QueryReport {
     pub start_time: DateTime,
     pub end_time: DateTime,
     pub created: DateTime,
+    pub group_uuid: Option<ReportGroupUuid>,
 }
 
 impl QueryReport {
@@ -207,6 +208,7 @@ impl QueryReport {
             start_time,
             end_time,
             created,
+            group_uuid,
         } = self;
 
         let query_project = QueryProject::get(conn_lock!(context), project_id)?;
@@ -234,6 +236,7 @@ impl QueryReport {
             adapter,
             results,
             alerts,
+            group: group_uuid,
             created,
         })
     }
@@ -281,6 +284,7 @@ async fn get_report_results(
                 schema::threshold::model_id,
                 schema::threshold::created,
                 schema::threshold::modified,
+                schema::threshold::benchmark_tags,
             ),
             (
                 schema::model::id,
@@ -463,6 +467,7 @@ pub struct InsertReport {
     pub start_time: DateTime,
     pub end_time: DateTime,
     pub created: DateTime,
+    pub group_uuid: Option<ReportGroupUuid>,
 }
 
 impl InsertReport {
@@ -489,6 +494,7 @@ impl InsertReport {
             start_time: report.start_time,
             end_time: report.end_time,
             created: DateTime::now(),
+            group_uuid: report.group,
         }
     }
 }
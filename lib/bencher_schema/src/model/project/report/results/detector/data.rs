@@ -8,13 +8,33 @@ use crate::{
     context::DbConnection,
     error::not_found_error,
     model::project::{
-        benchmark::BenchmarkId, branch::head::HeadId, measure::MeasureId, testbed::TestbedId,
+        benchmark::BenchmarkId, branch::head::HeadId, measure::MeasureId, metric::MetricId,
+        report::ReportId, testbed::TestbedId,
     },
     schema,
 };
 
 use super::threshold::ThresholdModel;
 
+#[derive(Debug, Clone)]
+pub struct HistoricalMetrics {
+    // The metric IDs in the same order as the metrics data.
+    pub metric_ids: Vec<MetricId>,
+    // The metrics data ordered from the most recent to the oldest.
+    pub metrics_data: MetricsData,
+}
+
+impl HistoricalMetrics {
+    // The metric IDs and data ordered from the oldest to the most recent.
+    pub fn chronological(&self) -> (Vec<MetricId>, Vec<f64>) {
+        let metric_ids = self.metric_ids.iter().rev().copied().collect();
+        let data = self.metrics_data.data.iter().rev().copied().collect();
+        (metric_ids, data)
+    }
+}
+
+// If an excluded report is given, then its metrics are left out of the historical data.
+#[expect(clippy::too_many_arguments)]
 pub fn metrics_data(
     log: &Logger,
     conn: &mut DbConnection,
@@ -23,7 +43,8 @@ pub fn metrics_data(
     benchmark_id: BenchmarkId,
     measure_id: MeasureId,
     model: &ThresholdModel,
-) -> Result<MetricsData, HttpError> {
+    exclude_report_id: Option<ReportId>,
+) -> Result<HistoricalMetrics, HttpError> {
     let mut query = schema::metric::table
         .inner_join(
             schema::report_benchmark::table
@@ -47,6 +68,10 @@ pub fn metrics_data(
         .filter(schema::metric::measure_id.eq(measure_id))
         .into_boxed();
 
+    if let Some(report_id) = exclude_report_id {
+        query = query.filter(schema::report::id.ne(report_id));
+    }
+
     if let Some(window) = model.window {
         let now = Utc::now().timestamp();
         if let Some(start_time) = now.checked_sub(window.into()) {
@@ -70,12 +95,35 @@ pub fn metrics_data(
         query = query.limit(max_sample_size.into());
     }
 
-    let data = query
-        .select(schema::metric::value)
-        .load::<f64>(conn)
+    let (metric_ids, data) = query
+        .select((schema::metric::id, schema::metric::value))
+        .load::<(MetricId, f64)>(conn)
         .map_err(not_found_error)?
         .into_iter()
-        .collect();
+        .unzip();
+
+    Ok(HistoricalMetrics {
+        metric_ids,
+        metrics_data: MetricsData { data },
+    })
+}
+
+// The metrics for all of the iterations of a benchmark measure in a single report.
+pub fn sample_data(
+    conn: &mut DbConnection,
+    report_id: ReportId,
+    benchmark_id: BenchmarkId,
+    measure_id: MeasureId,
+) -> Result<MetricsData, HttpError> {
+    let data = schema::metric::table
+        .inner_join(schema::report_benchmark::table)
+        .filter(schema::report_benchmark::report_id.eq(report_id))
+        .filter(schema::report_benchmark::benchmark_id.eq(benchmark_id))
+        .filter(schema::metric::measure_id.eq(measure_id))
+        .order(schema::report_benchmark::iteration.asc())
+        .select(schema::metric::value)
+        .load::<f64>(conn)
+        .map_err(not_found_error)?;
 
     Ok(MetricsData { data })
 }
@@ -1,20 +1,24 @@
-use bencher_boundary::MetricsBoundary;
-use bencher_json::BoundaryUuid;
+use bencher_boundary::{MetricsBoundary, MetricsChangePoints};
+use bencher_json::{BoundaryUuid, project::webhook::WebhookEvent};
 use diesel::RunQueryDsl as _;
 use dropshot::HttpError;
 use slog::Logger;
 
 use crate::{
     conn_lock,
-    context::{ApiContext, DbConnection},
+    context::ApiContext,
     error::{bad_request_error, resource_conflict_err},
     model::project::{
         benchmark::BenchmarkId,
-        branch::{BranchId, head::HeadId},
+        branch::head::HeadId,
         measure::MeasureId,
         metric::QueryMetric,
+        report::ReportId,
         testbed::TestbedId,
-        threshold::{alert::InsertAlert, boundary::InsertBoundary},
+        threshold::{
+            alert::InsertAlert, boundary::InsertBoundary, change_point::InsertChangePoint,
+        },
+        webhook::QueryWebhook,
     },
     schema,
 };
@@ -22,7 +26,7 @@ use crate::{
 pub mod data;
 pub mod threshold;
 
-use data::metrics_data;
+use data::{HistoricalMetrics, metrics_data, sample_data};
 use threshold::Threshold;
 
 #[derive(Debug, Clone)]
@@ -35,20 +39,17 @@ pub struct Detector {
 
 impl Detector {
     pub fn new(
-        conn: &mut DbConnection,
-        branch_id: BranchId,
         head_id: HeadId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
-    ) -> Option<Self> {
-        // Check to see if there is a threshold for the branch/testbed/measure grouping.
-        // If not, then there will be nothing to detect.
-        Threshold::new(conn, branch_id, testbed_id, measure_id).map(|threshold| Self {
+        threshold: Threshold,
+    ) -> Self {
+        Self {
             head_id,
             testbed_id,
             measure_id,
             threshold,
-        })
+        }
     }
 
     pub async fn detect(
@@ -60,7 +61,7 @@ impl Detector {
         ignore_benchmark: bool,
     ) -> Result<(), HttpError> {
         // Query the historical population/sample data for the benchmark
-        let metrics_data = metrics_data(
+        let historical_metrics = metrics_data(
             log,
             conn_lock!(context),
             self.head_id,
@@ -68,13 +69,14 @@ impl Detector {
             benchmark_id,
             self.measure_id,
             &self.threshold.model,
+            None,
         )?;
 
         // Check to see if the metric has a boundary check for the given threshold model.
         let boundary = MetricsBoundary::new(
             log,
             query_metric.value,
-            &metrics_data,
+            &historical_metrics.metrics_data,
             self.threshold.model.test,
             self.threshold.model.min_sample_size,
             self.threshold.model.lower_boundary,
@@ -82,6 +84,78 @@ impl Detector {
         )
         .map_err(bad_request_error)?;
 
+        self.record(
+            log,
+            context,
+            query_metric,
+            &historical_metrics,
+            boundary,
+            ignore_benchmark,
+        )
+        .await
+    }
+
+    // Detect using all of the iterations for the benchmark measure in the report as a single sample.
+    // The boundary and any alert are recorded for the given metric, which should be the last iteration.
+    pub async fn detect_sample(
+        &self,
+        log: &Logger,
+        context: &ApiContext,
+        report_id: ReportId,
+        benchmark_id: BenchmarkId,
+        query_metric: &QueryMetric,
+        ignore_benchmark: bool,
+    ) -> Result<(), HttpError> {
+        // Query the historical population/sample data for the benchmark,
+        // leaving out the current report as it is the sample being tested.
+        let historical_metrics = metrics_data(
+            log,
+            conn_lock!(context),
+            self.head_id,
+            self.testbed_id,
+            benchmark_id,
+            self.measure_id,
+            &self.threshold.model,
+            Some(report_id),
+        )?;
+        let sample = sample_data(
+            conn_lock!(context),
+            report_id,
+            benchmark_id,
+            self.measure_id,
+        )?;
+
+        let boundary = MetricsBoundary::new_sample(
+            log,
+            &sample,
+            &historical_metrics.metrics_data,
+            self.threshold.model.test,
+            self.threshold.model.min_sample_size,
+            self.threshold.model.lower_boundary,
+            self.threshold.model.upper_boundary,
+        )
+        .map_err(bad_request_error)?;
+
+        self.record(
+            log,
+            context,
+            query_metric,
+            &historical_metrics,
+            boundary,
+            ignore_benchmark,
+        )
+        .await
+    }
+
+    async fn record(
+        &self,
+        log: &Logger,
+        context: &ApiContext,
+        query_metric: &QueryMetric,
+        historical_metrics: &HistoricalMetrics,
+        boundary: MetricsBoundary,
+        ignore_benchmark: bool,
+    ) -> Result<(), HttpError> {
         let boundary_uuid = BoundaryUuid::new();
         let insert_boundary = InsertBoundary {
             uuid: boundary_uuid,
@@ -101,14 +175,30 @@ impl Detector {
         #[cfg(feature = "otel")]
         bencher_otel::ApiMeter::increment(bencher_otel::ApiCounter::MetricCreate);
 
-        // If the boundary check detects an outlier then create an alert for it on the given side.
-        // As long as the benchmark is not being ignored.
+        // Change points and alerts are only recorded as long as the benchmark is not being ignored.
         if ignore_benchmark {
-            Ok(())
-        } else if let Some(boundary_limit) = boundary.outlier {
-            InsertAlert::from_boundary(conn_lock!(context), boundary_uuid, boundary_limit)
-        } else {
-            Ok(())
+            return Ok(());
+        }
+
+        // Record any step changes in the historical series, so that a shift in performance
+        // is still visible after it becomes the new normal for the threshold model.
+        let (metric_ids, data) = historical_metrics.chronological();
+        let change_points = MetricsChangePoints::new(log, &data);
+        InsertChangePoint::from_change_points(
+            conn_lock!(context),
+            self.threshold.id,
+            self.threshold.model.id,
+            &metric_ids,
+            change_points,
+        )?;
+
+        // If the boundary check detects an outlier then create an alert for it on the given side.
+        if let Some(boundary_limit) = boundary.outlier {
+            let alert_id =
+                InsertAlert::from_boundary(conn_lock!(context), boundary_uuid, boundary_limit)?;
+            QueryWebhook::notify_alert(log, context, alert_id, WebhookEvent::AlertCreated).await;
         }
+
+        Ok(())
     }
 }
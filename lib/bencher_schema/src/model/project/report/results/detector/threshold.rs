@@ -1,4 +1,4 @@
-use bencher_json::{Boundary, ModelTest, SampleSize, Window};
+use bencher_json::{Boundary, JsonBenchmarkTags, ModelTest, SampleSize, TagSelectors, Window};
 use diesel::{
     ExpressionMethods as _, JoinOnDsl as _, NullableExpressionMethods as _, QueryDsl as _,
     RunQueryDsl as _, SelectableHelper as _,
@@ -21,6 +21,7 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Threshold {
     pub id: ThresholdId,
+    pub benchmark_tags: Option<TagSelectors>,
     pub model: ThresholdModel,
 }
 
@@ -36,12 +37,14 @@ pub struct ThresholdModel {
 }
 
 impl Threshold {
-    pub fn new(
+    // Get all of the thresholds with a model for the branch/testbed/measure grouping.
+    // There may be one threshold without benchmark tags and any number of thresholds with them.
+    pub fn all(
         conn: &mut DbConnection,
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
-    ) -> Option<Self> {
+    ) -> Vec<Self> {
         schema::model::table
             .inner_join(
                 schema::threshold::table
@@ -50,33 +53,148 @@ impl Threshold {
             .filter(schema::threshold::branch_id.eq(branch_id))
             .filter(schema::threshold::testbed_id.eq(testbed_id))
             .filter(schema::threshold::measure_id.eq(measure_id))
-            .select((schema::threshold::id, QueryModel::as_select()))
-            .first::<(ThresholdId, QueryModel)>(conn)
-            .map(|(threshold_id, query_model)| {
-                let QueryModel {
-                    id,
-                    test,
-                    min_sample_size,
-                    max_sample_size,
-                    window,
-                    lower_boundary,
-                    upper_boundary,
-                    ..
-                } = query_model;
-                let model = ThresholdModel {
-                    id,
-                    test,
-                    min_sample_size,
-                    max_sample_size,
-                    window,
-                    lower_boundary,
-                    upper_boundary,
-                };
-                Self {
-                    id: threshold_id,
-                    model,
-                }
+            .select((
+                schema::threshold::id,
+                schema::threshold::benchmark_tags,
+                QueryModel::as_select(),
+            ))
+            .order(schema::threshold::id)
+            .load::<(ThresholdId, Option<TagSelectors>, QueryModel)>(conn)
+            .map(|thresholds| {
+                thresholds
+                    .into_iter()
+                    .map(|(threshold_id, benchmark_tags, query_model)| {
+                        let QueryModel {
+                            id,
+                            test,
+                            min_sample_size,
+                            max_sample_size,
+                            window,
+                            lower_boundary,
+                            upper_boundary,
+                            ..
+                        } = query_model;
+                        let model = ThresholdModel {
+                            id,
+                            test,
+                            min_sample_size,
+                            max_sample_size,
+                            window,
+                            lower_boundary,
+                            upper_boundary,
+                        };
+                        Self {
+                            id: threshold_id,
+                            benchmark_tags,
+                            model,
+                        }
+                    })
+                    .collect()
             })
-            .ok()
+            .unwrap_or_default()
+    }
+
+    // Select the threshold to use for a benchmark with the given tags.
+    // The most specific threshold whose benchmark tag selectors all match is used.
+    // If there are no matching tagged thresholds, then fall back to the threshold without benchmark tags.
+    pub fn select<'t>(thresholds: &'t [Self], tags: &JsonBenchmarkTags) -> Option<&'t Self> {
+        thresholds
+            .iter()
+            .filter_map(|threshold| match &threshold.benchmark_tags {
+                Some(benchmark_tags) => benchmark_tags
+                    .matches(tags)
+                    .then(|| (benchmark_tags.iter().count(), threshold)),
+                None => Some((0, threshold)),
+            })
+            // Thresholds are ordered by ID, so the oldest threshold wins any tie.
+            .rev()
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, threshold)| threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bencher_json::{JsonBenchmarkTags, ModelTest, TagSelectors};
+
+    use super::{ModelId, Threshold, ThresholdId, ThresholdModel};
+
+    fn threshold(benchmark_tags: Option<&str>) -> Threshold {
+        Threshold {
+            id: ThresholdId::default(),
+            benchmark_tags: benchmark_tags.map(|tags| tags.parse().unwrap()),
+            model: ThresholdModel {
+                id: ModelId::default(),
+                test: ModelTest::Static,
+                min_sample_size: None,
+                max_sample_size: None,
+                window: None,
+                lower_boundary: None,
+                upper_boundary: None,
+            },
+        }
+    }
+
+    fn tags(tags: &[(&str, &str)]) -> JsonBenchmarkTags {
+        tags.iter()
+            .map(|(key, value)| (key.parse().unwrap(), value.parse().unwrap()))
+            .collect()
+    }
+
+    fn selected<'t>(
+        thresholds: &'t [Threshold],
+        benchmark_tags: &JsonBenchmarkTags,
+    ) -> Option<&'t TagSelectors> {
+        Threshold::select(thresholds, benchmark_tags)
+            .expect("Expected a threshold")
+            .benchmark_tags
+            .as_ref()
+    }
+
+    #[test]
+    fn threshold_select_none() {
+        assert!(Threshold::select(&[], &tags(&[])).is_none());
+        let thresholds = [threshold(Some("suite=io"))];
+        assert!(Threshold::select(&thresholds, &tags(&[])).is_none());
+        assert!(Threshold::select(&thresholds, &tags(&[("suite", "cpu")])).is_none());
+    }
+
+    #[test]
+    fn threshold_select_untagged_fallback() {
+        let thresholds = [threshold(Some("suite=io")), threshold(None)];
+        assert_eq!(selected(&thresholds, &tags(&[])), None);
+        assert_eq!(selected(&thresholds, &tags(&[("suite", "cpu")])), None);
+        assert_eq!(
+            selected(&thresholds, &tags(&[("suite", "io")])),
+            Some(&"suite=io".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn threshold_select_most_specific() {
+        let thresholds = [
+            threshold(None),
+            threshold(Some("suite=io")),
+            threshold(Some("os=linux,suite=io")),
+            threshold(Some("os=linux")),
+        ];
+        assert_eq!(
+            selected(&thresholds, &tags(&[("suite", "io"), ("os", "linux")])),
+            Some(&"os=linux,suite=io".parse().unwrap())
+        );
+        assert_eq!(
+            selected(&thresholds, &tags(&[("suite", "io"), ("os", "mac")])),
+            Some(&"suite=io".parse().unwrap())
+        );
+        // The oldest threshold wins a tie.
+        assert_eq!(
+            selected(&thresholds, &tags(&[("suite", "cpu"), ("os", "linux")])),
+            Some(&"os=linux".parse().unwrap())
+        );
+        let thresholds = [threshold(Some("os=linux")), threshold(Some("suite=io"))];
+        assert_eq!(
+            selected(&thresholds, &tags(&[("suite", "io"), ("os", "linux")])),
+            Some(&"os=linux".parse().unwrap())
+        );
     }
 }
@@ -1,16 +1,16 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, mem};
 
 use bencher_adapter::{
-    AdapterResults, AdapterResultsArray, Settings as AdapterSettings,
-    results::adapter_metrics::AdapterMetrics,
+    AdapterError, AdapterResults, AdapterResultsArray, Settings as AdapterSettings,
+    adapters::custom::AdapterCustom, results::adapter_metrics::AdapterMetrics,
 };
 use bencher_json::{
-    BenchmarkName, MeasureNameId,
+    BenchmarkName, JsonBenchmarkTags, MeasureNameId, ModelTest,
     project::report::{Adapter, Iteration, JsonReportSettings},
 };
 use diesel::RunQueryDsl as _;
 use dropshot::HttpError;
-use slog::Logger;
+use slog::{Logger, warn};
 
 use crate::{
     conn_lock,
@@ -18,7 +18,10 @@ use crate::{
     error::{bad_request_error, issue_error, resource_conflict_err},
     model::project::{
         ProjectId,
-        benchmark::{BenchmarkId, QueryBenchmark},
+        benchmark::{
+            BenchmarkId, QueryBenchmark,
+            tag::{InsertBenchmarkTag, QueryBenchmarkTag},
+        },
         branch::{BranchId, head::HeadId},
         measure::{MeasureId, QueryMeasure},
         metric::{InsertMetric, QueryMetric},
@@ -30,7 +33,7 @@ use crate::{
 
 pub mod detector;
 
-use detector::Detector;
+use detector::{Detector, threshold::Threshold};
 
 use super::ReportId;
 
@@ -43,7 +46,17 @@ pub struct ReportResults {
     pub report_id: ReportId,
     pub benchmark_cache: HashMap<BenchmarkName, BenchmarkId>,
     pub measure_cache: HashMap<MeasureNameId, MeasureId>,
-    pub detector_cache: HashMap<MeasureId, Option<Detector>>,
+    pub threshold_cache: HashMap<MeasureId, Vec<Threshold>>,
+    pub sample_detections: Vec<SampleDetection>,
+}
+
+/// `SampleDetection` is a detection that is deferred until all iterations of the report are processed.
+/// Only the most recent iteration of each benchmark measure is kept.
+pub struct SampleDetection {
+    pub detector: Detector,
+    pub benchmark_id: BenchmarkId,
+    pub query_metric: QueryMetric,
+    pub ignore_benchmark: bool,
 }
 
 impl ReportResults {
@@ -62,7 +75,8 @@ impl ReportResults {
             report_id,
             benchmark_cache: HashMap::new(),
             measure_cache: HashMap::new(),
-            detector_cache: HashMap::new(),
+            threshold_cache: HashMap::new(),
+            sample_detections: Vec::new(),
         }
     }
 
@@ -76,13 +90,29 @@ impl ReportResults {
         #[cfg(feature = "plus")] usage: &mut u32,
     ) -> Result<(), HttpError> {
         let adapter_settings = AdapterSettings::new(settings.average);
-        let results_array = AdapterResultsArray::new(results_array, adapter, adapter_settings)
-            .map_err(|e| {
+        let results_array = if let Adapter::Custom = adapter {
+            let Some(adapter_spec) = settings.adapter_spec.as_ref() else {
+                return Err(bad_request_error(
+                    "The custom adapter requires an adapter spec.\nRead more about adapters here: https://bencher.dev/docs/explanation/adapters/",
+                ));
+            };
+            AdapterCustom::new(adapter_spec).and_then(|adapter_custom| {
+                AdapterResultsArray::new(results_array, &adapter_custom, adapter_settings)
+            })
+        } else {
+            AdapterResultsArray::new(results_array, &adapter, adapter_settings)
+        };
+        let results_array = results_array
+            .map_err(|e: AdapterError| {
                 bad_request_error(format!(
                     "Failed to convert results with adapter ({adapter} | {settings:?}): {e}\n\nAre you sure {adapter} is the right adapter?\nRead more about adapters here: https://bencher.dev/docs/explanation/adapters/"
                 ))
             })?;
 
+        for warning in results_array.warnings() {
+            warn!(log, "{warning}");
+        }
+
         if let Some(fold) = settings.fold {
             let results = results_array.fold(fold);
             self.results(
@@ -108,6 +138,26 @@ impl ReportResults {
             }
         }
 
+        // Run any detections that use all of the iterations in the report as a single sample.
+        for sample_detection in mem::take(&mut self.sample_detections) {
+            let SampleDetection {
+                detector,
+                benchmark_id,
+                query_metric,
+                ignore_benchmark,
+            } = sample_detection;
+            detector
+                .detect_sample(
+                    log,
+                    context,
+                    self.report_id,
+                    benchmark_id,
+                    &query_metric,
+                    ignore_benchmark,
+                )
+                .await?;
+        }
+
         Ok(())
     }
 
@@ -146,6 +196,9 @@ impl ReportResults {
         // If benchmark name is ignored then strip the special suffix before querying
         let (benchmark_name, ignore_benchmark) = benchmark_name.to_strip_ignore();
         let benchmark_id = self.benchmark_id(context, benchmark_name).await?;
+        if let Some(tags) = metrics.tags {
+            InsertBenchmarkTag::upsert(conn_lock!(context), benchmark_id, tags)?;
+        }
 
         let insert_report_benchmark =
             InsertReportBenchmark::from_json(self.report_id, iteration, benchmark_id);
@@ -174,7 +227,7 @@ impl ReportResults {
                 *usage += 1;
             }
 
-            let Some(detector) = self.detector(context, measure_id).await else {
+            let Some(detector) = self.detector(context, benchmark_id, measure_id).await? else {
                 continue;
             };
             let query_metric = QueryMetric::from_uuid(conn_lock!(context), insert_metric.uuid).map_err(|e| {
@@ -184,6 +237,13 @@ impl ReportResults {
                         e,
                     )
                 })?;
+            if matches!(
+                detector.threshold.model.test,
+                ModelTest::WelchTTest | ModelTest::MannWhitney
+            ) {
+                self.defer_detection(detector, benchmark_id, query_metric, ignore_benchmark);
+                continue;
+            }
             detector
                 .detect(log, context, benchmark_id, &query_metric, ignore_benchmark)
                 .await?;
@@ -192,6 +252,30 @@ impl ReportResults {
         Ok(())
     }
 
+    fn defer_detection(
+        &mut self,
+        detector: Detector,
+        benchmark_id: BenchmarkId,
+        query_metric: QueryMetric,
+        ignore_benchmark: bool,
+    ) {
+        let sample_detection = SampleDetection {
+            detector,
+            benchmark_id,
+            query_metric,
+            ignore_benchmark,
+        };
+        // Iterations are processed in order, so a later iteration replaces an earlier one.
+        if let Some(existing) = self.sample_detections.iter_mut().find(|existing| {
+            existing.benchmark_id == benchmark_id
+                && existing.detector.measure_id == sample_detection.detector.measure_id
+        }) {
+            *existing = sample_detection;
+        } else {
+            self.sample_detections.push(sample_detection);
+        }
+    }
+
     async fn benchmark_id(
         &mut self,
         context: &ApiContext,
@@ -225,19 +309,37 @@ impl ReportResults {
         })
     }
 
-    async fn detector(&mut self, context: &ApiContext, measure_id: MeasureId) -> Option<Detector> {
-        if let Some(detector) = self.detector_cache.get(&measure_id) {
-            detector.clone()
-        } else {
-            let detector = Detector::new(
+    async fn detector(
+        &mut self,
+        context: &ApiContext,
+        benchmark_id: BenchmarkId,
+        measure_id: MeasureId,
+    ) -> Result<Option<Detector>, HttpError> {
+        if !self.threshold_cache.contains_key(&measure_id) {
+            // Check to see if there are any thresholds for the branch/testbed/measure grouping.
+            // If not, then there will be nothing to detect.
+            let thresholds = Threshold::all(
                 conn_lock!(context),
                 self.branch_id,
-                self.head_id,
                 self.testbed_id,
                 measure_id,
             );
-            self.detector_cache.insert(measure_id, detector.clone());
-            detector
+            self.threshold_cache.insert(measure_id, thresholds);
         }
+        let Some(thresholds) = self.threshold_cache.get(&measure_id) else {
+            return Ok(None);
+        };
+        // The benchmark tags are only needed if a threshold has benchmark tag selectors.
+        let tags = if thresholds
+            .iter()
+            .any(|threshold| threshold.benchmark_tags.is_some())
+        {
+            QueryBenchmarkTag::get_tags(conn_lock!(context), benchmark_id)?
+        } else {
+            JsonBenchmarkTags::new()
+        };
+        Ok(Threshold::select(thresholds, &tags).map(|threshold| {
+            Detector::new(self.head_id, self.testbed_id, measure_id, threshold.clone())
+        }))
     }
 }
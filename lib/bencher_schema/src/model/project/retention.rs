@@ -0,0 +1,496 @@
+use std::{
+    collections::{HashMap, HashSet},
+    num::{NonZeroI64, NonZeroU32},
+    sync::Arc,
+    time::Duration,
+};
+
+use bencher_json::{
+    DateTime, JsonRetention, JsonRetentionPrune, JsonUpdateRetention, project::alert::AlertStatus,
+};
+use diesel::{
+    ExpressionMethods as _, JoinOnDsl as _, NullableExpressionMethods as _, OptionalExtension as _,
+    QueryDsl as _, RunQueryDsl as _,
+};
+use dropshot::HttpError;
+use slog::{Logger, error, info};
+use tokio::sync::Mutex;
+
+use super::{
+    ProjectId, QueryProject,
+    branch::{BranchId, head::HeadId, version::VersionId},
+    report::ReportId,
+};
+use crate::{
+    connection_lock,
+    context::DbConnection,
+    error::{resource_conflict_err, resource_not_found_err},
+    schema::{self, project_retention as project_retention_table},
+};
+
+/// How often the retention policies for all projects are enforced.
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// The maximum number of rows to delete at once.
+/// The database connection is released between batches,
+/// so that pruning a large project does not block other requests.
+const PRUNE_BATCH_SIZE: usize = 512;
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+crate::macros::typed_id::typed_id!(ProjectRetentionId);
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Selectable)]
+#[diesel(table_name = project_retention_table)]
+pub struct QueryRetention {
+    pub id: ProjectRetentionId,
+    pub project_id: ProjectId,
+    pub reports: Option<i32>,
+    pub archived_branches: Option<i32>,
+    pub modified: DateTime,
+}
+
+impl QueryRetention {
+    pub fn get_for_project(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+    ) -> Result<Option<Self>, HttpError> {
+        schema::project_retention::table
+            .filter(schema::project_retention::project_id.eq(query_project.id))
+            .first::<Self>(conn)
+            .optional()
+            .map_err(resource_not_found_err!(ProjectRetention, query_project))
+    }
+
+    pub fn policy(&self) -> RetentionPolicy {
+        RetentionPolicy {
+            reports: self.reports.and_then(into_days),
+            archived_branches: self.archived_branches.and_then(into_days),
+        }
+    }
+
+    /// Enforce the retention policies for all projects in the background.
+    pub fn spawn_prune(log: Logger, db_connection: Arc<Mutex<DbConnection>>) {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(PRUNE_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                Self::prune_all(&log, &db_connection).await;
+            }
+        });
+    }
+
+    async fn prune_all(log: &Logger, db_connection: &Mutex<DbConnection>) {
+        let retentions =
+            match schema::project_retention::table.load::<Self>(connection_lock!(db_connection)) {
+                Ok(retentions) => retentions,
+                Err(e) => {
+                    error!(log, "Failed to load project retention policies: {e}");
+                    return;
+                },
+            };
+        for retention in retentions {
+            let project_id = retention.project_id;
+            let policy = retention.policy();
+            let result = match policy
+                .plan(db_connection, project_id, DateTime::now())
+                .await
+            {
+                Ok(plan) => plan.execute(db_connection).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(pruned) if pruned == JsonRetentionPrune::default() => {},
+                Ok(pruned) => info!(log, "Pruned project ({project_id}): {pruned:?}"),
+                Err(e) => {
+                    error!(log, "Failed to prune project ({project_id}): {e}");
+                    #[cfg(feature = "sentry")]
+                    sentry::capture_error(&e);
+                },
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RetentionPolicy {
+    pub reports: Option<NonZeroU32>,
+    pub archived_branches: Option<NonZeroU32>,
+}
+
+impl From<JsonUpdateRetention> for RetentionPolicy {
+    fn from(update: JsonUpdateRetention) -> Self {
+        let JsonUpdateRetention {
+            reports,
+            archived_branches,
+        } = update;
+        Self {
+            reports,
+            archived_branches,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    pub fn get_for_project(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+    ) -> Result<Self, HttpError> {
+        Ok(QueryRetention::get_for_project(conn, query_project)?
+            .map(|retention| retention.policy())
+            .unwrap_or_default())
+    }
+
+    pub fn into_json_for_project(self, query_project: &QueryProject) -> JsonRetention {
+        let Self {
+            reports,
+            archived_branches,
+        } = self;
+        JsonRetention {
+            project: query_project.uuid,
+            reports,
+            archived_branches,
+        }
+    }
+
+    /// Replace the retention policy for a project.
+    pub fn update(
+        self,
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+    ) -> Result<(), HttpError> {
+        let update = UpdateRetention {
+            reports: self.reports.map(from_days),
+            archived_branches: self.archived_branches.map(from_days),
+            modified: DateTime::now(),
+        };
+        let updated = diesel::update(
+            schema::project_retention::table
+                .filter(schema::project_retention::project_id.eq(query_project.id)),
+        )
+        .set(&update)
+        .execute(conn)
+        .map_err(resource_conflict_err!(
+            ProjectRetention,
+            (query_project, &update)
+        ))?;
+        if updated == 0 {
+            let insert = InsertRetention {
+                project_id: query_project.id,
+                reports: update.reports,
+                archived_branches: update.archived_branches,
+                modified: update.modified,
+            };
+            diesel::insert_into(schema::project_retention::table)
+                .values(&insert)
+                .execute(conn)
+                .map_err(resource_conflict_err!(
+                    ProjectRetention,
+                    (query_project, &insert)
+                ))?;
+        }
+        Ok(())
+    }
+
+    /// Find all of the data that is no longer retained by the policy.
+    pub async fn plan(
+        self,
+        db_connection: &Mutex<DbConnection>,
+        project_id: ProjectId,
+        now: DateTime,
+    ) -> Result<RetentionPlan, HttpError> {
+        let protected_versions = protected_versions(db_connection, project_id).await?;
+
+        let mut branches = Vec::new();
+        if let Some(cutoff) = self.archived_branches.and_then(|days| cutoff(now, days)) {
+            let archived_branches = schema::branch::table
+                .filter(schema::branch::project_id.eq(project_id))
+                .filter(schema::branch::archived.lt(cutoff))
+                .select(schema::branch::id)
+                .load::<BranchId>(connection_lock!(db_connection))
+                .map_err(resource_not_found_err!(Branch, project_id))?;
+            for branch_id in archived_branches {
+                if !is_branch_protected(db_connection, branch_id).await? {
+                    branches.push(branch_id);
+                }
+            }
+        }
+
+        let mut reports = HashMap::new();
+        if let Some(cutoff) = self.reports.and_then(|days| cutoff(now, days)) {
+            schema::report::table
+                .filter(schema::report::project_id.eq(project_id))
+                .filter(schema::report::start_time.lt(cutoff))
+                .select((schema::report::id, schema::report::version_id))
+                .load::<(ReportId, VersionId)>(connection_lock!(db_connection))
+                .map_err(resource_not_found_err!(Report, project_id))?
+                .into_iter()
+                .filter(|(_, version_id)| !protected_versions.contains(version_id))
+                .for_each(|(report_id, version_id)| {
+                    reports.insert(report_id, version_id);
+                });
+        }
+        // All of the reports for an archived branch are deleted along with it.
+        for branch_id in &branches {
+            schema::report::table
+                .filter(
+                    schema::report::head_id.eq_any(
+                        schema::head::table
+                            .filter(schema::head::branch_id.eq(branch_id))
+                            .select(schema::head::id),
+                    ),
+                )
+                .select((schema::report::id, schema::report::version_id))
+                .load::<(ReportId, VersionId)>(connection_lock!(db_connection))
+                .map_err(resource_not_found_err!(Report, branch_id))?
+                .into_iter()
+                .for_each(|(report_id, version_id)| {
+                    reports.insert(report_id, version_id);
+                });
+        }
+
+        // A version is deleted once all of its reports have been deleted.
+        // Version numbers are left as is, so the remaining versions are not renumbered.
+        let mut deleted_reports_per_version = HashMap::<VersionId, i64>::new();
+        for version_id in reports.values() {
+            *deleted_reports_per_version.entry(*version_id).or_default() += 1;
+        }
+        let candidate_versions = deleted_reports_per_version
+            .keys()
+            .filter(|version_id| !protected_versions.contains(version_id))
+            .copied()
+            .collect::<Vec<_>>();
+        let mut versions = Vec::new();
+        for batch in candidate_versions.chunks(PRUNE_BATCH_SIZE) {
+            let reports_per_version = schema::report::table
+                .filter(schema::report::version_id.eq_any(batch))
+                .group_by(schema::report::version_id)
+                .select((schema::report::version_id, diesel::dsl::count_star()))
+                .load::<(VersionId, i64)>(connection_lock!(db_connection))
+                .map_err(resource_not_found_err!(Version, project_id))?;
+            versions.extend(
+                reports_per_version
+                    .into_iter()
+                    .filter(|(version_id, count)| {
+                        deleted_reports_per_version.get(version_id) == Some(count)
+                    })
+                    .map(|(version_id, _)| version_id),
+            );
+        }
+
+        Ok(RetentionPlan {
+            branches,
+            reports: reports.into_keys().collect(),
+            versions,
+        })
+    }
+}
+
+/// The data that is no longer retained by a retention policy.
+#[derive(Debug, Default)]
+pub struct RetentionPlan {
+    pub branches: Vec<BranchId>,
+    pub reports: Vec<ReportId>,
+    pub versions: Vec<VersionId>,
+}
+
+impl RetentionPlan {
+    /// Summarize what would be deleted, without deleting anything.
+    pub async fn into_json(
+        self,
+        db_connection: &Mutex<DbConnection>,
+    ) -> Result<JsonRetentionPrune, HttpError> {
+        let metrics = self.count_metrics(db_connection).await?;
+        Ok(self.into_json_with_metrics(metrics))
+    }
+
+    /// Delete all of the data that is no longer retained.
+    pub async fn execute(
+        self,
+        db_connection: &Mutex<DbConnection>,
+    ) -> Result<JsonRetentionPrune, HttpError> {
+        let metrics = self.count_metrics(db_connection).await?;
+
+        // This will cascade and delete all report benchmarks, metrics, boundaries, and alerts.
+        for batch in self.reports.chunks(PRUNE_BATCH_SIZE) {
+            diesel::delete(schema::report::table.filter(schema::report::id.eq_any(batch)))
+                .execute(connection_lock!(db_connection))
+                .map_err(resource_conflict_err!(Report, batch))?;
+        }
+        // Thresholds must be deleted before their branch.
+        for branch_id in &self.branches {
+            diesel::delete(
+                schema::threshold::table.filter(schema::threshold::branch_id.eq(branch_id)),
+            )
+            .execute(connection_lock!(db_connection))
+            .map_err(resource_conflict_err!(Threshold, branch_id))?;
+            // This will cascade and delete all heads and head versions for the branch.
+            diesel::delete(schema::branch::table.filter(schema::branch::id.eq(branch_id)))
+                .execute(connection_lock!(db_connection))
+                .map_err(resource_conflict_err!(Branch, branch_id))?;
+        }
+        for batch in self.versions.chunks(PRUNE_BATCH_SIZE) {
+            diesel::delete(schema::version::table.filter(schema::version::id.eq_any(batch)))
+                .execute(connection_lock!(db_connection))
+                .map_err(resource_conflict_err!(Version, batch))?;
+        }
+
+        Ok(self.into_json_with_metrics(metrics))
+    }
+
+    async fn count_metrics(&self, db_connection: &Mutex<DbConnection>) -> Result<i64, HttpError> {
+        let mut metrics = 0;
+        for batch in self.reports.chunks(PRUNE_BATCH_SIZE) {
+            metrics += schema::metric::table
+                .inner_join(schema::report_benchmark::table)
+                .filter(schema::report_benchmark::report_id.eq_any(batch))
+                .count()
+                .get_result::<i64>(connection_lock!(db_connection))
+                .map_err(resource_not_found_err!(Metric, batch))?;
+        }
+        Ok(metrics)
+    }
+
+    fn into_json_with_metrics(self, metrics: i64) -> JsonRetentionPrune {
+        JsonRetentionPrune {
+            branches: saturating_u32(self.branches.len()),
+            reports: saturating_u32(self.reports.len()),
+            metrics: u32::try_from(metrics).unwrap_or(u32::MAX),
+            versions: saturating_u32(self.versions.len()),
+        }
+    }
+}
+
+// Versions that are used as a start point or that have an active alert are always kept.
+async fn protected_versions(
+    db_connection: &Mutex<DbConnection>,
+    project_id: ProjectId,
+) -> Result<HashSet<VersionId>, HttpError> {
+    let mut protected_versions = schema::head::table
+        .inner_join(
+            schema::head_version::table
+                .on(schema::head::start_point_id.eq(schema::head_version::id.nullable())),
+        )
+        .inner_join(schema::branch::table.on(schema::branch::id.eq(schema::head::branch_id)))
+        .filter(schema::branch::project_id.eq(project_id))
+        .select(schema::head_version::version_id)
+        .load::<VersionId>(connection_lock!(db_connection))
+        .map_err(resource_not_found_err!(HeadVersion, project_id))?
+        .into_iter()
+        .collect::<HashSet<_>>();
+    protected_versions.extend(
+        schema::alert::table
+            .inner_join(
+                schema::boundary::table.inner_join(
+                    schema::metric::table.inner_join(
+                        schema::report_benchmark::table.inner_join(schema::report::table),
+                    ),
+                ),
+            )
+            .filter(schema::report::project_id.eq(project_id))
+            .filter(schema::alert::status.eq(AlertStatus::Active))
+            .select(schema::report::version_id)
+            .distinct()
+            .load::<VersionId>(connection_lock!(db_connection))
+            .map_err(resource_not_found_err!(Alert, project_id))?,
+    );
+    Ok(protected_versions)
+}
+
+// Archived branches that are used as a start point by another branch or that have an active alert are always kept.
+async fn is_branch_protected(
+    db_connection: &Mutex<DbConnection>,
+    branch_id: BranchId,
+) -> Result<bool, HttpError> {
+    let branch_heads = schema::head::table
+        .filter(schema::head::branch_id.eq(branch_id))
+        .select(schema::head::id)
+        .load::<HeadId>(connection_lock!(db_connection))
+        .map_err(resource_not_found_err!(Head, branch_id))?;
+
+    let start_points = schema::head::table
+        .filter(schema::head::branch_id.ne(branch_id))
+        .filter(
+            schema::head::start_point_id.eq_any(
+                schema::head_version::table
+                    .filter(schema::head_version::head_id.eq_any(&branch_heads))
+                    .select(schema::head_version::id.nullable()),
+            ),
+        )
+        .count()
+        .get_result::<i64>(connection_lock!(db_connection))
+        .map_err(resource_not_found_err!(Head, branch_id))?;
+    if start_points > 0 {
+        return Ok(true);
+    }
+
+    let active_alerts = schema::alert::table
+        .inner_join(
+            schema::boundary::table.inner_join(
+                schema::metric::table
+                    .inner_join(schema::report_benchmark::table.inner_join(schema::report::table)),
+            ),
+        )
+        .filter(schema::report::head_id.eq_any(&branch_heads))
+        .filter(schema::alert::status.eq(AlertStatus::Active))
+        .count()
+        .get_result::<i64>(connection_lock!(db_connection))
+        .map_err(resource_not_found_err!(Alert, branch_id))?;
+    Ok(active_alerts > 0)
+}
+
+// If the cutoff is too far in the past to be represented, then nothing is old enough to prune.
+fn cutoff(now: DateTime, days: NonZeroU32) -> Option<DateTime> {
+    let seconds = NonZeroI64::from(days).get().saturating_mul(SECONDS_PER_DAY);
+    DateTime::try_from(now.timestamp().saturating_sub(seconds)).ok()
+}
+
+fn into_days(days: i32) -> Option<NonZeroU32> {
+    u32::try_from(days).ok().and_then(NonZeroU32::new)
+}
+
+fn from_days(days: NonZeroU32) -> i32 {
+    i32::try_from(days.get()).unwrap_or(i32::MAX)
+}
+
+fn saturating_u32(len: usize) -> u32 {
+    u32::try_from(len).unwrap_or(u32::MAX)
+}
+
+#[derive(Debug, diesel::AsChangeset)]
+#[diesel(table_name = project_retention_table)]
+#[diesel(treat_none_as_null = true)]
+struct UpdateRetention {
+    reports: Option<i32>,
+    archived_branches: Option<i32>,
+    modified: DateTime,
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = project_retention_table)]
+struct InsertRetention {
+    project_id: ProjectId,
+    reports: Option<i32>,
+    archived_branches: Option<i32>,
+    modified: DateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use bencher_json::DateTime;
+
+    use super::cutoff;
+
+    #[test]
+    fn retention_cutoff() {
+        let now = DateTime::try_from(1_700_000_000).unwrap();
+        let days = NonZeroU32::new(30).unwrap();
+        assert_eq!(
+            cutoff(now, days).as_ref().map(DateTime::timestamp),
+            Some(1_700_000_000 - 30 * 24 * 60 * 60)
+        );
+        let days = NonZeroU32::new(u32::MAX).unwrap();
+        assert!(cutoff(now, days).is_none());
+    }
+}
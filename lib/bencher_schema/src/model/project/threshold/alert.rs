@@ -221,7 +221,7 @@ impl InsertAlert {
         conn: &mut DbConnection,
         boundary_uuid: BoundaryUuid,
         boundary_limit: BoundaryLimit,
-    ) -> Result<(), HttpError> {
+    ) -> Result<AlertId, HttpError> {
         let insert_alert = InsertAlert {
             uuid: AlertUuid::new(),
             boundary_id: QueryBoundary::get_id(conn, boundary_uuid)?,
@@ -235,7 +235,7 @@ impl InsertAlert {
             .execute(conn)
             .map_err(resource_conflict_err!(Alert, insert_alert))?;
 
-        Ok(())
+        QueryAlert::get_id(conn, insert_alert.uuid)
     }
 }
 
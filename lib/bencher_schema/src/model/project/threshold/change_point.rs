@@ -0,0 +1,186 @@
+use bencher_boundary::MetricsChangePoints;
+use bencher_json::{
+    ChangePointUuid, DateTime, ReportUuid,
+    project::{change_point::JsonChangePoint, report::Iteration},
+};
+use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _, SelectableHelper as _};
+use dropshot::HttpError;
+
+use super::{QueryThreshold, ThresholdId, model::ModelId};
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    error::{resource_conflict_err, resource_not_found_err},
+    macros::fn_get::{fn_get, fn_get_id, fn_get_uuid},
+    model::project::{
+        ProjectId, QueryProject,
+        benchmark::QueryBenchmark,
+        branch::{
+            head::HeadId,
+            version::{QueryVersion, VersionId},
+        },
+        metric::MetricId,
+    },
+    schema::{self, change_point as change_point_table},
+};
+
+crate::macros::typed_id::typed_id!(ChangePointId);
+
+#[derive(Debug, Clone, diesel::Queryable, diesel::Selectable)]
+#[diesel(table_name = change_point_table)]
+pub struct QueryChangePoint {
+    pub id: ChangePointId,
+    pub uuid: ChangePointUuid,
+    pub metric_id: MetricId,
+    pub threshold_id: ThresholdId,
+    pub model_id: ModelId,
+    pub baseline: f64,
+    pub value: f64,
+    pub created: DateTime,
+}
+
+impl QueryChangePoint {
+    fn_get!(change_point, ChangePointId);
+    fn_get_id!(change_point, ChangePointId, ChangePointUuid);
+    fn_get_uuid!(change_point, ChangePointId, ChangePointUuid);
+
+    pub fn from_uuid(
+        conn: &mut DbConnection,
+        project_id: ProjectId,
+        uuid: ChangePointUuid,
+    ) -> Result<Self, HttpError> {
+        schema::change_point::table
+            .filter(schema::change_point::uuid.eq(uuid.to_string()))
+            .inner_join(
+                schema::metric::table.inner_join(
+                    schema::report_benchmark::table.inner_join(schema::benchmark::table),
+                ),
+            )
+            .filter(schema::benchmark::project_id.eq(project_id))
+            .select(QueryChangePoint::as_select())
+            .first(conn)
+            .map_err(resource_not_found_err!(ChangePoint, (project_id, uuid)))
+    }
+
+    pub async fn into_json(self, context: &ApiContext) -> Result<JsonChangePoint, HttpError> {
+        let (report_uuid, head_id, version_id, iteration, query_benchmark) =
+            schema::change_point::table
+                .filter(schema::change_point::id.eq(self.id))
+                .inner_join(
+                    schema::metric::table.inner_join(
+                        schema::report_benchmark::table
+                            .inner_join(schema::report::table)
+                            .inner_join(schema::benchmark::table),
+                    ),
+                )
+                .select((
+                    schema::report::uuid,
+                    schema::report::head_id,
+                    schema::report::version_id,
+                    schema::report_benchmark::iteration,
+                    QueryBenchmark::as_select(),
+                ))
+                .first::<(ReportUuid, HeadId, VersionId, Iteration, QueryBenchmark)>(conn_lock!(
+                    context
+                ))
+                .map_err(resource_not_found_err!(ChangePoint, self))?;
+        let project = QueryProject::get(conn_lock!(context), query_benchmark.project_id)?;
+        let version = QueryVersion::get(conn_lock!(context), version_id)?.into_json();
+        let threshold = QueryThreshold::get_alert_json(
+            context,
+            self.threshold_id,
+            self.model_id,
+            head_id,
+            version_id,
+        )
+        .await?;
+        let Self {
+            uuid,
+            baseline,
+            value,
+            created,
+            ..
+        } = self;
+        Ok(JsonChangePoint {
+            uuid,
+            report: report_uuid,
+            iteration,
+            version,
+            benchmark: query_benchmark.into_json_for_project(&project),
+            threshold,
+            baseline,
+            value,
+            created,
+        })
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = change_point_table)]
+pub struct InsertChangePoint {
+    pub uuid: ChangePointUuid,
+    pub metric_id: MetricId,
+    pub threshold_id: ThresholdId,
+    pub model_id: ModelId,
+    pub baseline: f64,
+    pub value: f64,
+    pub created: DateTime,
+}
+
+impl InsertChangePoint {
+    // The metric IDs must be in the same chronological order as the data used to detect the change points.
+    // A change point that has already been recorded for the threshold,
+    // at or near the same metric in the series, is left as is.
+    pub fn from_change_points(
+        conn: &mut DbConnection,
+        threshold_id: ThresholdId,
+        model_id: ModelId,
+        metric_ids: &[MetricId],
+        mut metrics_change_points: MetricsChangePoints,
+    ) -> Result<(), HttpError> {
+        if metrics_change_points.change_points.is_empty() {
+            return Ok(());
+        }
+
+        let recorded_metric_ids: Vec<MetricId> = schema::change_point::table
+            .filter(schema::change_point::threshold_id.eq(threshold_id))
+            .filter(schema::change_point::metric_id.eq_any(metric_ids))
+            .select(schema::change_point::metric_id)
+            .load(conn)
+            .map_err(resource_not_found_err!(
+                ChangePoint,
+                (threshold_id, metric_ids)
+            ))?;
+        let recorded = metric_ids
+            .iter()
+            .enumerate()
+            .filter_map(|(index, metric_id)| {
+                recorded_metric_ids.contains(metric_id).then_some(index)
+            })
+            .collect::<Vec<_>>();
+        metrics_change_points.retain_new(&recorded);
+
+        let now = DateTime::now();
+        for change_point in &metrics_change_points.change_points {
+            let Some(&metric_id) = metric_ids.get(change_point.index) else {
+                debug_assert!(false, "Change point index out of bounds: {change_point:?}");
+                continue;
+            };
+            let insert_change_point = InsertChangePoint {
+                uuid: ChangePointUuid::new(),
+                metric_id,
+                threshold_id,
+                model_id,
+                baseline: change_point.baseline,
+                value: change_point.value,
+                created: now,
+            };
+            diesel::insert_into(schema::change_point::table)
+                .values(&insert_change_point)
+                .execute(conn)
+                .map_err(resource_conflict_err!(ChangePoint, insert_change_point))?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use bencher_json::{
-    DateTime, Model, ModelUuid, ThresholdUuid,
+    DateTime, Model, ModelUuid, TagSelectors, ThresholdUuid,
     project::{
         report::JsonReportThresholds,
         threshold::{JsonThreshold, JsonThresholdModel},
@@ -32,6 +32,7 @@ use crate::{
 
 pub mod alert;
 pub mod boundary;
+pub mod change_point;
 pub mod model;
 
 crate::macros::typed_id::typed_id!(ThresholdId);
@@ -51,6 +52,7 @@ pub struct QueryThreshold {
     pub model_id: Option<ModelId>,
     pub created: DateTime,
     pub modified: DateTime,
+    pub benchmark_tags: Option<TagSelectors>,
 }
 
 impl QueryThreshold {
@@ -217,6 +219,7 @@ impl QueryThreshold {
             measure_id,
             created,
             modified,
+            benchmark_tags,
             ..
         } = self;
         let query_project = QueryProject::get(conn_lock!(context), project_id)?;
@@ -236,6 +239,7 @@ impl QueryThreshold {
             branch,
             testbed,
             measure,
+            benchmark_tags,
             model,
             created,
             modified,
@@ -280,6 +284,7 @@ pub struct InsertThreshold {
     pub model_id: Option<ModelId>,
     pub created: DateTime,
     pub modified: DateTime,
+    pub benchmark_tags: Option<TagSelectors>,
 }
 
 impl InsertThreshold {
@@ -291,6 +296,7 @@ impl InsertThreshold {
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark_tags: Option<TagSelectors>,
     ) -> Self {
         let timestamp = DateTime::now();
         Self {
@@ -302,6 +308,7 @@ impl InsertThreshold {
             model_id: None,
             created: timestamp,
             modified: timestamp,
+            benchmark_tags,
         }
     }
 
@@ -311,6 +318,7 @@ impl InsertThreshold {
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark_tags: Option<TagSelectors>,
         model: Model,
     ) -> Result<ThresholdId, HttpError> {
         #[cfg(feature = "plus")]
@@ -321,6 +329,7 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            benchmark_tags,
             model,
         )
     }
@@ -331,10 +340,17 @@ impl InsertThreshold {
         branch_id: BranchId,
         testbed_id: TestbedId,
         measure_id: MeasureId,
+        benchmark_tags: Option<TagSelectors>,
         model: Model,
     ) -> Result<ThresholdId, HttpError> {
         // Create the new threshold
-        let insert_threshold = InsertThreshold::new(project_id, branch_id, testbed_id, measure_id);
+        let insert_threshold = InsertThreshold::new(
+            project_id,
+            branch_id,
+            testbed_id,
+            measure_id,
+            benchmark_tags,
+        );
         diesel::insert_into(schema::threshold::table)
             .values(&insert_threshold)
             .execute(conn)
@@ -378,6 +394,7 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            None,
             Model::lower_boundary(),
         )
     }
@@ -395,6 +412,7 @@ impl InsertThreshold {
             branch_id,
             testbed_id,
             measure_id,
+            None,
             Model::upper_boundary(),
         )
     }
@@ -421,32 +439,17 @@ impl InsertThreshold {
             branch_start_point.branch.project_id,
         );
 
-        let mut current_thresholds = schema::threshold::table
-            .filter(schema::threshold::branch_id.eq(query_branch.id))
-            .load::<QueryThreshold>(conn_lock!(context))
-            .map_err(resource_not_found_err!(
-                Threshold,
-                &branch_start_point.branch
-            ))?
-            .into_iter()
-            .map(|threshold| ((threshold.testbed_id, threshold.measure_id), threshold))
-            .collect::<HashMap<_, _>>();
+        let mut current_thresholds = branch_thresholds(conn_lock!(context), query_branch)?;
         slog::debug!(log, "Current thresholds: {current_thresholds:?}");
 
-        let start_point_thresholds = schema::threshold::table
-            .filter(schema::threshold::branch_id.eq(branch_start_point.branch.id))
-            .load::<QueryThreshold>(conn_lock!(context))
-            .map_err(resource_not_found_err!(
-                Threshold,
-                &branch_start_point.branch
-            ))?
-            .into_iter()
-            .map(|threshold| ((threshold.testbed_id, threshold.measure_id), threshold))
-            .collect::<HashMap<_, _>>();
+        let start_point_thresholds =
+            branch_thresholds(conn_lock!(context), &branch_start_point.branch)?;
         slog::debug!(log, "Start point thresholds: {start_point_thresholds:?}");
 
-        for ((start_point_testbed_id, start_point_measure_id), start_point_threshold) in
-            start_point_thresholds
+        for (
+            (start_point_testbed_id, start_point_measure_id, start_point_benchmark_tags),
+            start_point_threshold,
+        ) in start_point_thresholds
         {
             let start_point_model = start_point_threshold
                 .model(conn_lock!(context))?
@@ -455,9 +458,11 @@ impl InsertThreshold {
                 log,
                 "Processing start point threshold ({start_point_threshold:?}) with model ({start_point_model:?}) for testbed ({start_point_testbed_id}) and measure ({start_point_measure_id})"
             );
-            if let Some(current_threshold) =
-                current_thresholds.remove(&(start_point_testbed_id, start_point_measure_id))
-            {
+            if let Some(current_threshold) = current_thresholds.remove(&(
+                start_point_testbed_id,
+                start_point_measure_id,
+                start_point_benchmark_tags.clone(),
+            )) {
                 slog::debug!(
                     log,
                     "Updating current threshold ({current_threshold:?}) for testbed ({start_point_testbed_id}) and measure ({start_point_measure_id})"
@@ -480,6 +485,7 @@ impl InsertThreshold {
                     query_branch.id,
                     start_point_testbed_id,
                     start_point_measure_id,
+                    start_point_benchmark_tags,
                     start_point_model,
                 )
                 .await?;
@@ -532,11 +538,13 @@ impl InsertThreshold {
             return Ok(());
         }
 
-        // Get all thresholds for the report branch and testbed
+        // Get all thresholds for the report branch and testbed.
+        // Report thresholds never have benchmark tags.
         let mut current_thresholds = schema::threshold::table
             .filter(schema::threshold::project_id.eq(project_id))
             .filter(schema::threshold::branch_id.eq(branch_id))
             .filter(schema::threshold::testbed_id.eq(testbed_id))
+            .filter(schema::threshold::benchmark_tags.is_null())
             .load::<QueryThreshold>(conn_lock!(context))
             .map_err(resource_not_found_err!(Threshold, (branch_id, testbed_id)))?
             .into_iter()
@@ -560,7 +568,7 @@ impl InsertThreshold {
                 } else {
                     slog::debug!(log, "Creating threshold for measure {measure_id}");
                     Self::from_model(
-                        context, project_id, branch_id, testbed_id, measure_id, model,
+                        context, project_id, branch_id, testbed_id, measure_id, None, model,
                     )
                     .await?;
                     slog::debug!(log, "Created threshold for measure {measure_id}");
@@ -581,6 +589,34 @@ impl InsertThreshold {
     }
 }
 
+// The thresholds for a branch, keyed by their testbed, measure, and benchmark tags.
+type BranchThresholds = HashMap<(TestbedId, MeasureId, Option<TagSelectors>), QueryThreshold>;
+
+fn branch_thresholds(
+    conn: &mut DbConnection,
+    query_branch: &QueryBranch,
+) -> Result<BranchThresholds, HttpError> {
+    schema::threshold::table
+        .filter(schema::threshold::branch_id.eq(query_branch.id))
+        .load::<QueryThreshold>(conn)
+        .map(|thresholds| {
+            thresholds
+                .into_iter()
+                .map(|threshold| {
+                    (
+                        (
+                            threshold.testbed_id,
+                            threshold.measure_id,
+                            threshold.benchmark_tags.clone(),
+                        ),
+                        threshold,
+                    )
+                })
+                .collect()
+        })
+        .map_err(resource_not_found_err!(Threshold, query_branch))
+}
+
 #[derive(Debug, Clone, diesel::AsChangeset)]
 #[diesel(table_name = threshold_table)]
 pub struct UpdateThreshold {
@@ -0,0 +1,609 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+
+use bencher_json::{
+    DateTime, JsonNewWebhook, JsonUpdateWebhook, JsonWebhook, JsonWebhookEvent, Secret, Url,
+    WebhookUuid, project::webhook::WebhookEvent,
+};
+use diesel::{BelongingToDsl as _, ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
+use dropshot::HttpError;
+use hmac::{Hmac, Mac as _};
+use sha2::Sha256;
+use slog::{Logger, debug, error, warn};
+
+use super::{
+    ProjectId, QueryProject,
+    threshold::alert::{AlertId, QueryAlert},
+};
+use crate::{
+    conn_lock,
+    context::{ApiContext, DbConnection},
+    error::{BencherResource, assert_parentage, issue_error, resource_not_found_err},
+    macros::fn_get::{fn_get, fn_get_id, fn_get_uuid},
+    schema::{self, webhook as webhook_table},
+};
+
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Bencher-Signature";
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Bencher-Event";
+
+const MAX_ATTEMPTS: u32 = 5;
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+crate::macros::typed_id::typed_id!(WebhookId);
+
+#[derive(
+    Debug, Clone, diesel::Queryable, diesel::Identifiable, diesel::Associations, diesel::Selectable,
+)]
+#[diesel(table_name = webhook_table)]
+#[diesel(belongs_to(QueryProject, foreign_key = project_id))]
+pub struct QueryWebhook {
+    pub id: WebhookId,
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: Secret,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl QueryWebhook {
+    fn_get!(webhook, WebhookId);
+    fn_get_id!(webhook, WebhookId, WebhookUuid);
+    fn_get_uuid!(webhook, WebhookId, WebhookUuid);
+
+    pub fn get_with_uuid(
+        conn: &mut DbConnection,
+        query_project: &QueryProject,
+        uuid: WebhookUuid,
+    ) -> Result<Self, HttpError> {
+        Self::belonging_to(&query_project)
+            .filter(webhook_table::uuid.eq(uuid))
+            .first::<Self>(conn)
+            .map_err(resource_not_found_err!(Webhook, (query_project, uuid)))
+    }
+
+    pub fn into_json_for_project(self, project: &QueryProject) -> JsonWebhook {
+        assert_parentage(
+            BencherResource::Project,
+            project.id,
+            BencherResource::Webhook,
+            self.project_id,
+        );
+        let Self {
+            uuid,
+            url,
+            created,
+            modified,
+            ..
+        } = self;
+        JsonWebhook {
+            uuid,
+            project: project.uuid,
+            url,
+            created,
+            modified,
+        }
+    }
+
+    // Send the alert to all of the webhooks for its project.
+    // Delivery happens in the background, so any errors are logged instead of returned.
+    pub async fn notify_alert(
+        log: &Logger,
+        context: &ApiContext,
+        alert_id: AlertId,
+        event: WebhookEvent,
+    ) {
+        if let Err(err) = Self::notify_alert_inner(log, context, alert_id, event).await {
+            error!(log, "Failed to send webhooks for alert ({alert_id}): {err}");
+            #[cfg(feature = "sentry")]
+            sentry::capture_error(&err);
+        }
+    }
+
+    async fn notify_alert_inner(
+        log: &Logger,
+        context: &ApiContext,
+        alert_id: AlertId,
+        event: WebhookEvent,
+    ) -> Result<(), HttpError> {
+        let project_id = schema::alert::table
+            .filter(schema::alert::id.eq(alert_id))
+            .inner_join(schema::boundary::table.inner_join(schema::threshold::table))
+            .select(schema::threshold::project_id)
+            .first::<ProjectId>(conn_lock!(context))
+            .map_err(resource_not_found_err!(Alert, alert_id))?;
+        let webhooks = schema::webhook::table
+            .filter(schema::webhook::project_id.eq(project_id))
+            .load::<Self>(conn_lock!(context))
+            .map_err(resource_not_found_err!(Webhook, project_id))?;
+        if webhooks.is_empty() {
+            return Ok(());
+        }
+
+        // Separate out these queries to prevent a deadlock when getting the conn_lock
+        let query_alert = QueryAlert::get(conn_lock!(context), alert_id)?;
+        let alert = query_alert.into_json(context).await?;
+        let json_event = JsonWebhookEvent { event, alert };
+        let body = serde_json::to_vec(&json_event).map_err(|e| {
+            issue_error(
+                "Failed to serialize webhook event",
+                &format!("Failed to serialize webhook event ({json_event:?})"),
+                e,
+            )
+        })?;
+
+        for webhook in webhooks {
+            let signature = sign(&webhook.secret, &body).map_err(|e| {
+                issue_error(
+                    "Failed to sign webhook event",
+                    &format!(
+                        "Failed to sign webhook event for webhook ({})",
+                        webhook.uuid
+                    ),
+                    e,
+                )
+            })?;
+            deliver(log.clone(), webhook.url, signature, event, body.clone());
+        }
+
+        Ok(())
+    }
+}
+
+// The hex encoded HMAC-SHA256 of the request body, using the webhook secret as the key.
+fn sign(secret: &Secret, body: &[u8]) -> Result<String, hmac::digest::InvalidLength> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_ref().as_bytes())?;
+    mac.update(body);
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+// Deliver the webhook event in the background, retrying with exponential backoff.
+fn deliver(log: Logger, url: Url, signature: String, event: WebhookEvent, body: Vec<u8>) {
+    tokio::spawn(async move {
+        let client = match public_client(&url).await {
+            Ok(client) => client,
+            Err(e) => {
+                error!(
+                    log,
+                    "Refusing to deliver webhook event ({event}) to {url}: {e}"
+                );
+                return;
+            },
+        };
+        send_with_retry(
+            &log,
+            &client,
+            &url,
+            &signature,
+            event,
+            &body,
+            INITIAL_BACKOFF,
+        )
+        .await;
+    });
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    #[error("Failed to parse webhook URL ({url}): {error}")]
+    Url { url: Url, error: url::ParseError },
+    #[error("Webhook URL ({0}) must use HTTP or HTTPS")]
+    Scheme(Url),
+    #[error("Webhook URL ({0}) does not have a host")]
+    Host(Url),
+    #[error("Failed to resolve webhook URL ({url}): {error}")]
+    Resolve { url: Url, error: std::io::Error },
+    #[error("Webhook URL ({url}) resolves to a non-public address ({ip})")]
+    NonPublic { url: Url, ip: IpAddr },
+    #[error("Failed to create webhook client: {0}")]
+    Client(reqwest::Error),
+    #[error("Failed to send webhook request: {0}")]
+    Send(reqwest::Error),
+    #[error("Webhook request failed with status: {0}")]
+    Status(reqwest::StatusCode),
+}
+
+// Redirects are never followed, as they could point to an internal address.
+fn client_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(REQUEST_TIMEOUT)
+}
+
+// Resolve the webhook host once, check that every address is public,
+// and then pin the client to those addresses.
+// This way the host can not be pointed at an internal address in between the check and the request.
+async fn public_client(url: &Url) -> Result<reqwest::Client, WebhookError> {
+    let parsed_url = url::Url::parse(url.as_ref()).map_err(|error| WebhookError::Url {
+        url: url.clone(),
+        error,
+    })?;
+    if !matches!(parsed_url.scheme(), "http" | "https") {
+        return Err(WebhookError::Scheme(url.clone()));
+    }
+    let (Some(host), Some(port)) = (parsed_url.host(), parsed_url.port_or_known_default()) else {
+        return Err(WebhookError::Host(url.clone()));
+    };
+    let (domain, addrs) = match host {
+        url::Host::Domain(domain) => {
+            let addrs = tokio::net::lookup_host((domain, port))
+                .await
+                .map_err(|error| WebhookError::Resolve {
+                    url: url.clone(),
+                    error,
+                })?
+                .collect::<Vec<_>>();
+            (Some(domain), addrs)
+        },
+        url::Host::Ipv4(ip) => (None, vec![SocketAddr::new(ip.into(), port)]),
+        url::Host::Ipv6(ip) => (None, vec![SocketAddr::new(ip.into(), port)]),
+    };
+    if addrs.is_empty() {
+        return Err(WebhookError::Host(url.clone()));
+    }
+    if let Some(addr) = addrs.iter().find(|addr| !is_public_ip(addr.ip())) {
+        return Err(WebhookError::NonPublic {
+            url: url.clone(),
+            ip: addr.ip(),
+        });
+    }
+
+    let builder = client_builder();
+    let builder = if let Some(domain) = domain {
+        builder.resolve_to_addrs(domain, &addrs)
+    } else {
+        builder
+    };
+    builder.build().map_err(WebhookError::Client)
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                is_public_ipv4(ip)
+            } else {
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local())
+            }
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        // This network (0.0.0.0/8)
+        || first == 0
+        // Shared address space (100.64.0.0/10)
+        || (first == 100 && (second & 0b1100_0000) == 64))
+}
+
+// Returns whether the webhook event was delivered.
+async fn send_with_retry(
+    log: &Logger,
+    client: &reqwest::Client,
+    url: &Url,
+    signature: &str,
+    event: WebhookEvent,
+    body: &[u8],
+    initial_backoff: Duration,
+) -> bool {
+    let mut backoff = initial_backoff;
+    for attempt in 1..=MAX_ATTEMPTS {
+        match send(client, url, signature, event, body.to_vec()).await {
+            Ok(()) => {
+                debug!(log, "Delivered webhook event ({event}) to {url}");
+                return true;
+            },
+            Err(e) => warn!(
+                log,
+                "Failed to deliver webhook event ({event}) to {url} (attempt {attempt}/{MAX_ATTEMPTS}): {e}"
+            ),
+        }
+        if attempt < MAX_ATTEMPTS {
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+    }
+    error!(
+        log,
+        "Giving up on webhook event ({event}) to {url} after {MAX_ATTEMPTS} attempts"
+    );
+    false
+}
+
+async fn send(
+    client: &reqwest::Client,
+    url: &Url,
+    signature: &str,
+    event: WebhookEvent,
+    body: Vec<u8>,
+) -> Result<(), WebhookError> {
+    let response = client
+        .post(url.as_ref())
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(WEBHOOK_SIGNATURE_HEADER, format!("sha256={signature}"))
+        .header(WEBHOOK_EVENT_HEADER, event.to_string())
+        .body(body)
+        .send()
+        .await
+        .map_err(WebhookError::Send)?;
+    // A redirect is not followed, so it also counts as a failed delivery.
+    let status = response.status();
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(WebhookError::Status(status))
+    }
+}
+
+#[derive(Debug, diesel::Insertable)]
+#[diesel(table_name = webhook_table)]
+pub struct InsertWebhook {
+    pub uuid: WebhookUuid,
+    pub project_id: ProjectId,
+    pub url: Url,
+    pub secret: Secret,
+    pub created: DateTime,
+    pub modified: DateTime,
+}
+
+impl InsertWebhook {
+    pub fn from_json(project_id: ProjectId, webhook: JsonNewWebhook) -> Self {
+        let JsonNewWebhook { url, secret } = webhook;
+        let timestamp = DateTime::now();
+        Self {
+            uuid: WebhookUuid::new(),
+            project_id,
+            url,
+            secret,
+            created: timestamp,
+            modified: timestamp,
+        }
+    }
+}
+
+#[derive(Debug, Clone, diesel::AsChangeset)]
+#[diesel(table_name = webhook_table)]
+pub struct UpdateWebhook {
+    pub url: Option<Url>,
+    pub secret: Option<Secret>,
+    pub modified: DateTime,
+}
+
+impl From<JsonUpdateWebhook> for UpdateWebhook {
+    fn from(update: JsonUpdateWebhook) -> Self {
+        let JsonUpdateWebhook { url, secret } = update;
+        Self {
+            url,
+            secret,
+            modified: DateTime::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::IpAddr, time::Duration};
+
+    use bencher_json::{Secret, Url, project::webhook::WebhookEvent};
+    use mockito::{Matcher, Server};
+
+    use super::{
+        MAX_ATTEMPTS, WEBHOOK_EVENT_HEADER, WEBHOOK_SIGNATURE_HEADER, WebhookError, client_builder,
+        is_public_ip, public_client, send_with_retry, sign,
+    };
+
+    const BACKOFF: Duration = Duration::from_millis(1);
+
+    fn log() -> slog::Logger {
+        slog::Logger::root(slog::Discard, slog::o!())
+    }
+
+    fn url(server: &mockito::ServerGuard, path: &str) -> Url {
+        format!("{}{path}", server.url()).parse().unwrap()
+    }
+
+    #[test]
+    fn webhook_sign() {
+        let secret: Secret = "key".parse().unwrap();
+        let signature = sign(&secret, b"The quick brown fox jumps over the lazy dog").unwrap();
+        assert_eq!(
+            signature,
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    fn webhook_is_public_ip() {
+        for ip in ["1.1.1.1", "8.8.8.8", "2606:4700:4700::1111"] {
+            assert!(is_public_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+        }
+        for ip in [
+            "127.0.0.1",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "255.255.255.255",
+            "224.0.0.1",
+            "::1",
+            "::",
+            "fc00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "::ffff:10.0.0.1",
+        ] {
+            assert!(!is_public_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+        }
+    }
+
+    #[tokio::test]
+    async fn webhook_public_client_rejects_internal() {
+        for url in [
+            "http://127.0.0.1:61016/v0/server/backup",
+            "http://localhost/",
+            "http://10.0.0.1/",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]/",
+        ] {
+            let url: Url = url.parse().unwrap();
+            assert!(
+                matches!(
+                    public_client(&url).await,
+                    Err(WebhookError::NonPublic { .. })
+                ),
+                "{url}"
+            );
+        }
+
+        let url: Url = "ftp://1.1.1.1/".parse().unwrap();
+        assert!(matches!(
+            public_client(&url).await,
+            Err(WebhookError::Scheme(_))
+        ));
+
+        let url: Url = "http://1.1.1.1/".parse().unwrap();
+        assert!(public_client(&url).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn webhook_send_with_retry_delivered() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .match_header(WEBHOOK_SIGNATURE_HEADER, "sha256=signature")
+            .match_header(WEBHOOK_EVENT_HEADER, "alert_created")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Exact("{}".to_owned()))
+            .with_status(200)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = client_builder().build().unwrap();
+        let delivered = send_with_retry(
+            &log(),
+            &client,
+            &url(&server, "/hook"),
+            "signature",
+            WebhookEvent::AlertCreated,
+            b"{}",
+            BACKOFF,
+        )
+        .await;
+
+        assert!(delivered);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn webhook_send_with_retry_recovers() {
+        let mut server = Server::new_async().await;
+        let failure = server
+            .mock("POST", "/hook")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let success = server
+            .mock("POST", "/hook")
+            .with_status(204)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = client_builder().build().unwrap();
+        let delivered = send_with_retry(
+            &log(),
+            &client,
+            &url(&server, "/hook"),
+            "signature",
+            WebhookEvent::AlertUpdated,
+            b"{}",
+            BACKOFF,
+        )
+        .await;
+
+        assert!(delivered);
+        failure.assert_async().await;
+        success.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn webhook_send_with_retry_gives_up() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .with_status(500)
+            .expect(MAX_ATTEMPTS as usize)
+            .create_async()
+            .await;
+
+        let client = client_builder().build().unwrap();
+        let delivered = send_with_retry(
+            &log(),
+            &client,
+            &url(&server, "/hook"),
+            "signature",
+            WebhookEvent::AlertUpdated,
+            b"{}",
+            BACKOFF,
+        )
+        .await;
+
+        assert!(!delivered);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn webhook_send_with_retry_no_redirect() {
+        let mut server = Server::new_async().await;
+        let redirect_url = url(&server, "/internal");
+        let redirect = server
+            .mock("POST", "/hook")
+            .with_status(307)
+            .with_header("location", redirect_url.as_ref())
+            .expect(MAX_ATTEMPTS as usize)
+            .create_async()
+            .await;
+        let internal = server
+            .mock("POST", "/internal")
+            .with_status(200)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let client = client_builder().build().unwrap();
+        let delivered = send_with_retry(
+            &log(),
+            &client,
+            &url(&server, "/hook"),
+            "signature",
+            WebhookEvent::AlertCreated,
+            b"{}",
+            BACKOFF,
+        )
+        .await;
+
+        assert!(!delivered);
+        redirect.assert_async().await;
+        internal.assert_async().await;
+    }
+}
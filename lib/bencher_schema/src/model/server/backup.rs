@@ -8,7 +8,7 @@ use tokio::{
     io::{AsyncReadExt as _, AsyncWriteExt as _, BufReader, BufWriter},
 };
 
-use crate::context::DataStore;
+use crate::context::{DataStore, DatabaseUrl};
 
 // https://www.sqlite.org/pgszchng2016.html
 const SQLITE_PAGE_SIZE: usize = 4096;
@@ -62,14 +62,22 @@ pub enum ServerBackupError {
     DataStore(crate::context::DataStoreError),
     #[error("No data store")]
     NoDataStore,
+    #[error(
+        "Server backups are only supported for SQLite databases. Use `pg_dump` to back up a PostgreSQL database."
+    )]
+    Postgres,
 }
 
 impl ServerBackup {
     pub async fn run(
-        file_path: PathBuf,
+        database_url: DatabaseUrl,
         data_store: Option<&DataStore>,
         json_backup: JsonBackup,
     ) -> Result<JsonBackupCreated, ServerBackupError> {
+        let DatabaseUrl::Sqlite(file_path) = database_url else {
+            return Err(ServerBackupError::Postgres);
+        };
+
         // Create a database backup
         let Self {
             file_path: backup_file_path,
@@ -1,6 +1,5 @@
 #![cfg(feature = "plus")]
 
-use std::path::PathBuf;
 use std::sync::LazyLock;
 use std::{cmp, sync::Arc};
 
@@ -19,7 +18,7 @@ use url::Url;
 use crate::{
     connection_lock,
     context::StatsSettings,
-    context::{Body, DbConnection, Message, Messenger, ServerStatsBody},
+    context::{Body, DatabaseUrl, DbConnection, Message, Messenger, ServerStatsBody},
     error::{request_timeout_error, resource_conflict_err},
     macros::fn_get::fn_get,
     model::{organization::plan::LicenseUsage, user::QueryUser},
@@ -73,7 +72,7 @@ impl QueryServer {
         self,
         log: Logger,
         server_stats_url: Url,
-        db_path: PathBuf,
+        database_url: DatabaseUrl,
         db_connection: Arc<Mutex<DbConnection>>,
         stats: StatsSettings,
         licensor: Licensor,
@@ -144,7 +143,8 @@ impl QueryServer {
                     }
                 }
 
-                let Some(json_stats_str) = self.get_stats_str(&log, db_path.clone()).await else {
+                let Some(json_stats_str) = self.get_stats_str(&log, database_url.clone()).await
+                else {
                     slog::error!(log, "Failed to get stats string");
                     continue;
                 };
@@ -169,16 +169,16 @@ impl QueryServer {
     pub async fn get_stats(
         self,
         log: Logger,
-        db_path: PathBuf,
+        database_url: DatabaseUrl,
     ) -> Result<JsonServerStats, HttpError> {
-        tokio::task::spawn_blocking(move || stats::get_stats(&log, &db_path, self))
+        tokio::task::spawn_blocking(move || stats::get_stats(&log, &database_url, self))
             .await
             .map_err(request_timeout_error)?
     }
 
-    async fn get_stats_str(self, log: &Logger, db_path: PathBuf) -> Option<String> {
+    async fn get_stats_str(self, log: &Logger, database_url: DatabaseUrl) -> Option<String> {
         let json_stats = self
-            .get_stats(log.clone(), db_path)
+            .get_stats(log.clone(), database_url)
             .await
             .inspect_err(|e| {
                 slog::error!(log, "Failed to get stats: {e}");
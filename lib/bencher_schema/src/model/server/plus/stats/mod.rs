@@ -1,11 +1,14 @@
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 
 use bencher_json::{DateTime, JsonServerStats};
-use diesel::Connection as _;
 use dropshot::HttpError;
 use slog::Logger;
 
-use crate::{context::DbConnection, error::bad_request_error, model::server::ServerBackup};
+use crate::{
+    context::{DatabaseUrl, DbConnection},
+    error::bad_request_error,
+    model::server::ServerBackup,
+};
 
 use super::QueryServer;
 
@@ -34,15 +37,25 @@ enum ProjectState {
 
 pub(super) fn get_stats(
     log: &Logger,
-    db_path: &PathBuf,
+    database_url: &DatabaseUrl,
     query_server: QueryServer,
 ) -> Result<JsonServerStats, HttpError> {
+    // A PostgreSQL server can handle the stats queries alongside the API server,
+    // so there is no need to make a copy of the database first.
+    let db_path = match database_url {
+        DatabaseUrl::Sqlite(db_path) => db_path,
+        DatabaseUrl::Postgres(_) => {
+            let mut database_connection = database_url.establish().map_err(bad_request_error)?;
+            return get_stats_inner(&mut database_connection, query_server);
+        },
+    };
+
     let ServerBackup {
         file_path: backup_path,
         ..
     } = ServerBackup::backup_database(db_path).map_err(bad_request_error)?;
 
-    let server_stats = connect_and_get_stats(&backup_path, query_server);
+    let server_stats = connect_and_get_stats(backup_path.clone(), query_server);
 
     if let Err(error) = std::fs::remove_file(&backup_path) {
         slog::error!(log, "Failed to remove database backup file: {error}");
@@ -52,10 +65,11 @@ pub(super) fn get_stats(
 }
 
 fn connect_and_get_stats(
-    backup_path: &Path,
+    backup_path: PathBuf,
     query_server: QueryServer,
 ) -> Result<JsonServerStats, HttpError> {
-    let mut database_connection = DbConnection::establish(backup_path.to_string_lossy().as_ref())
+    let mut database_connection = DatabaseUrl::Sqlite(backup_path)
+        .establish()
         .map_err(bad_request_error)?;
 
     get_stats_inner(&mut database_connection, query_server)
@@ -3,11 +3,11 @@ use std::ops::Deref;
 use async_trait::async_trait;
 #[cfg(feature = "plus")]
 use bencher_json::system::payment::JsonCustomer;
-use bencher_json::{Jwt, Sanitize};
+use bencher_json::{JsonTokenScope, Jwt, Sanitize, TokenUuid};
 use bencher_rbac::{
     Organization, Project, Server, User as RbacUser,
     server::Permission,
-    user::{OrganizationRoles, ProjectRoles},
+    user::{OrganizationRoles, ProjectPermissions, ProjectRoles},
 };
 use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
 use dropshot::{
@@ -18,9 +18,12 @@ use oso::{PolarValue, ToPolar};
 
 use crate::{
     conn_lock,
-    context::{ApiContext, DbConnection, Rbac},
-    error::{BEARER_TOKEN_FORMAT, bad_request_error},
-    model::{organization::OrganizationId, project::ProjectId},
+    context::{ApiContext, DbConnection, Rbac, RbacScope},
+    error::{BEARER_TOKEN_FORMAT, bad_request_error, forbidden_error},
+    model::{
+        organization::{OrganizationId, custom_role::QueryCustomRole},
+        project::ProjectId,
+    },
     schema,
 };
 
@@ -32,6 +35,17 @@ pub struct AuthUser {
     pub organizations: Vec<OrganizationId>,
     pub projects: Vec<OrgProjectId>,
     pub rbac: RbacUser,
+    pub scope: Option<RbacScope>,
+    pub credential: AuthCredential,
+}
+
+/// How a user authenticated for a request.
+#[derive(Debug, Clone, Copy)]
+pub enum AuthCredential {
+    /// A console login session.
+    Session,
+    /// An API token, with its UUID if it is stored on the server.
+    ApiKey(Option<TokenUuid>),
 }
 
 impl AuthUser {
@@ -83,25 +97,54 @@ impl AuthUser {
         let conn = conn_lock!(context);
         let query_user = QueryUser::get_with_email(conn, email)?;
         query_user.check_is_locked()?;
+        query_user.check_is_revoked(claims.issued_at())?;
         #[cfg(feature = "plus")]
         context.rate_limiting.user_request(query_user.uuid)?;
 
-        Self::load(conn, query_user)
+        let scope = claims
+            .scope()
+            .filter(|scope| !scope.is_unrestricted())
+            .map(|scope| Self::scope(conn, &query_user, scope))
+            .transpose()?;
+        let credential = if claims.is_api_key() {
+            let token_uuid = schema::token::table
+                .filter(schema::token::user_id.eq(query_user.id))
+                .filter(schema::token::jwt.eq(&*bearer_token))
+                .select(schema::token::uuid)
+                .first::<TokenUuid>(conn)
+                .ok();
+            AuthCredential::ApiKey(token_uuid)
+        } else {
+            AuthCredential::Session
+        };
+        Self::load(conn, query_user, scope, credential)
     }
 
     pub fn reload(&self, conn: &mut DbConnection) -> Result<Self, HttpError> {
-        Self::load(conn, self.user.clone())
+        Self::load(conn, self.user.clone(), self.scope.clone(), self.credential)
     }
 
-    fn load(conn: &mut DbConnection, query_user: QueryUser) -> Result<Self, HttpError> {
+    fn load(
+        conn: &mut DbConnection,
+        query_user: QueryUser,
+        scope: Option<RbacScope>,
+        credential: AuthCredential,
+    ) -> Result<Self, HttpError> {
         let (org_ids, org_roles) = Self::organization_roles(conn, &query_user)?;
-        let (proj_ids, proj_roles) = Self::project_roles(conn, &query_user)?;
+        let (mut proj_ids, proj_roles) = Self::project_roles(conn, &query_user)?;
+        let (custom_proj_ids, proj_permissions) = Self::project_permissions(conn, &query_user)?;
+        for org_project_id in custom_proj_ids {
+            if !proj_ids.contains(&org_project_id) {
+                proj_ids.push(org_project_id);
+            }
+        }
 
         let rbac = RbacUser {
             admin: query_user.admin,
             locked: query_user.locked,
             organizations: org_roles,
             projects: proj_roles,
+            project_permissions: proj_permissions,
         };
 
         Ok(Self {
@@ -109,6 +152,61 @@ impl AuthUser {
             organizations: org_ids,
             projects: proj_ids,
             rbac,
+            scope,
+            credential,
+        })
+    }
+
+    fn scope(
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+        scope: &JsonTokenScope,
+    ) -> Result<RbacScope, HttpError> {
+        let scope_error = |e| {
+            crate::error::issue_error(
+                "User can't query API token scope",
+                &format!(
+                    "My user ({email}) on Bencher failed to query API token scope.",
+                    email = query_user.email
+                ),
+                e,
+            )
+        };
+
+        let organizations = scope
+            .organizations
+            .as_ref()
+            .map(|organizations| {
+                schema::organization::table
+                    .filter(schema::organization::uuid.eq_any(organizations))
+                    .order(schema::organization::id)
+                    .select(schema::organization::id)
+                    .load::<OrganizationId>(conn)
+                    .map_err(scope_error)
+            })
+            .transpose()?;
+        let projects = scope
+            .projects
+            .as_ref()
+            .map(|projects| {
+                schema::project::table
+                    .filter(schema::project::uuid.eq_any(projects))
+                    .order(schema::project::id)
+                    .select((schema::project::organization_id, schema::project::id))
+                    .load::<(OrganizationId, ProjectId)>(conn)
+                    .map(|ids| {
+                        ids.into_iter()
+                            .map(|(org_id, project_id)| OrgProjectId { org_id, project_id })
+                            .collect()
+                    })
+                    .map_err(scope_error)
+            })
+            .transpose()?;
+
+        Ok(RbacScope {
+            organizations,
+            projects,
+            permission: scope.permission,
         })
     }
 
@@ -207,8 +305,90 @@ impl AuthUser {
         Ok((ids, roles))
     }
 
+    fn project_permissions(
+        conn: &mut DbConnection,
+        query_user: &QueryUser,
+    ) -> Result<(Vec<OrgProjectId>, ProjectPermissions), HttpError> {
+        let permissions = schema::project_member::table
+            .filter(schema::project_member::user_id.eq(query_user.id))
+            .inner_join(schema::project::table)
+            .inner_join(schema::custom_role::table)
+            .order(schema::project_member::project_id)
+            .select((
+                schema::project::organization_id,
+                schema::project_member::project_id,
+                schema::custom_role::permissions,
+            ))
+            .load::<(OrganizationId, ProjectId, String)>(conn)
+            .map_err(|e| {
+                crate::error::issue_error(
+                    "User can't query custom project roles",
+                    &format!(
+                        "My user ({email}) on Bencher failed to query custom project roles.",
+                        email = query_user.email
+                    ),
+                    e,
+                )
+            })?;
+
+        let ids = permissions
+            .iter()
+            .map(|(org_id, project_id, _)| OrgProjectId {
+                org_id: *org_id,
+                project_id: *project_id,
+            })
+            .collect();
+        // Any invalid permissions have already been reported as an issue, so just skip them
+        let permissions = permissions
+            .into_iter()
+            .filter_map(|(_, id, permissions)| {
+                QueryCustomRole::rbac_permissions(&permissions)
+                    .ok()
+                    .map(|permissions| (id.to_string(), permissions))
+            })
+            .collect();
+
+        Ok((ids, permissions))
+    }
+
     pub fn is_admin(&self, rbac: &Rbac) -> bool {
-        rbac.is_allowed_unwrap(self, Permission::Administer, Server {})
+        // A scoped API token never has server admin permissions
+        self.scope.is_none() && rbac.is_allowed_unwrap(self, Permission::Administer, Server {})
+    }
+
+    pub fn check_is_unscoped(&self) -> Result<(), HttpError> {
+        if self.scope.is_some() {
+            let mut auth_user = self.clone();
+            auth_user.sanitize();
+            Err(forbidden_error(format!(
+                "This action is not allowed with a scoped API token: {auth_user:?}",
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Check that the API token is scoped to exactly one organization and nothing else.
+    pub fn check_is_organization_scoped(
+        &self,
+        organization_id: OrganizationId,
+    ) -> Result<(), HttpError> {
+        let is_organization_scoped = self.scope.as_ref().is_some_and(|scope| {
+            scope.projects.is_none()
+                && scope
+                    .organizations
+                    .as_ref()
+                    .is_some_and(|organizations| organizations.as_slice() == [organization_id])
+        });
+        if is_organization_scoped {
+            Ok(())
+        } else {
+            let mut auth_user = self.clone();
+            auth_user.sanitize();
+            Err(forbidden_error(format!(
+                "This action requires an API token scoped to only this organization: {auth_user:?}",
+            )))
+        }
     }
 
     pub fn organizations(
@@ -219,7 +399,8 @@ impl AuthUser {
         self.organizations
             .iter()
             .filter_map(|org_id| {
-                rbac.is_allowed_unwrap(self, action, Organization::from(*org_id))
+                rbac.is_allowed_organization(self, action, Organization::from(*org_id))
+                    .is_ok()
                     .then_some(*org_id)
             })
             .collect()
@@ -233,7 +414,8 @@ impl AuthUser {
         self.projects
             .iter()
             .filter_map(|org_project_id| {
-                rbac.is_allowed_unwrap(self, action, Project::from(*org_project_id))
+                rbac.is_allowed_project(self, action, Project::from(*org_project_id))
+                    .is_ok()
                     .then_some(org_project_id.project_id)
             })
             .collect()
@@ -344,7 +526,7 @@ impl SharedExtractor for PubBearerToken {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct OrgProjectId {
     pub org_id: OrganizationId,
     pub project_id: ProjectId,
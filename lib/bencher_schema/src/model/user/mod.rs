@@ -12,7 +12,7 @@ use url::Url;
 
 use crate::{
     context::{Body, DbConnection, Message, Messenger, NewUserBody},
-    error::{forbidden_error, resource_conflict_err, resource_not_found_err},
+    error::{forbidden_error, resource_conflict_err, resource_not_found_err, unauthorized_error},
     macros::{
         fn_get::{fn_get, fn_get_id, fn_get_uuid},
         resource_id::{fn_eq_resource_id, fn_from_resource_id},
@@ -53,13 +53,14 @@ pub struct QueryUser {
     pub locked: bool,
     pub created: DateTime,
     pub modified: DateTime,
+    pub tokens_revoked: Option<DateTime>,
 }
 
 impl QueryUser {
     fn_eq_resource_id!(user, UserResourceId);
     fn_from_resource_id!(user, User, UserResourceId);
 
-    fn from_uuid(conn: &mut DbConnection, uuid: UserUuid) -> Result<Self, HttpError> {
+    pub fn from_uuid(conn: &mut DbConnection, uuid: UserUuid) -> Result<Self, HttpError> {
         schema::user::table
             .filter(schema::user::uuid.eq(uuid))
             .first(conn)
@@ -121,6 +122,33 @@ impl QueryUser {
         }
     }
 
+    /// Check to see if a token was issued before the user's tokens were revoked
+    pub fn check_is_revoked(&self, issued_at: DateTime) -> Result<(), HttpError> {
+        if self
+            .tokens_revoked
+            .is_some_and(|tokens_revoked| issued_at.timestamp() <= tokens_revoked.timestamp())
+        {
+            Err(unauthorized_error(format!(
+                "This token was issued at {issued_at} and all tokens for user ({uuid}) were revoked afterwards. Please log in again or create a new API token.",
+                uuid = self.uuid,
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Revoke all existing tokens for the user
+    pub fn revoke_tokens(&self, conn: &mut DbConnection) -> Result<(), HttpError> {
+        diesel::delete(schema::token::table.filter(schema::token::user_id.eq(self.id)))
+            .execute(conn)
+            .map_err(resource_conflict_err!(Token, self.id))?;
+        diesel::update(schema::user::table.filter(schema::user::id.eq(self.id)))
+            .set(schema::user::tokens_revoked.eq(DateTime::now()))
+            .execute(conn)
+            .map_err(resource_conflict_err!(User, self.id))?;
+        Ok(())
+    }
+
     /// Rate limit authentication attempts for this user
     #[cfg(feature = "plus")]
     pub fn rate_limit_auth(&self, context: &crate::ApiContext) -> Result<(), HttpError> {
@@ -188,10 +216,16 @@ pub struct InsertUser {
     pub locked: bool,
     pub created: DateTime,
     pub modified: DateTime,
+    pub tokens_revoked: Option<DateTime>,
 }
 
 impl InsertUser {
-    fn new(conn: &mut DbConnection, name: UserName, slug: Option<UserSlug>, email: Email) -> Self {
+    pub(crate) fn new(
+        conn: &mut DbConnection,
+        name: UserName,
+        slug: Option<UserSlug>,
+        email: Email,
+    ) -> Self {
         let slug = ok_slug!(conn, &name, slug, user, QueryUser);
         let timestamp = DateTime::now();
         Self {
@@ -203,6 +237,7 @@ impl InsertUser {
             locked: false,
             created: timestamp,
             modified: timestamp,
+            tokens_revoked: None,
         }
     }
 
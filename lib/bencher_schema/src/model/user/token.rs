@@ -2,7 +2,7 @@ use bencher_json::{
     DateTime, JsonNewToken, JsonToken, Jwt, ResourceName, TokenUuid, UserResourceId,
     user::token::JsonUpdateToken,
 };
-use bencher_token::TokenKey;
+use bencher_token::{Claims, TokenKey};
 use diesel::{ExpressionMethods as _, QueryDsl as _, RunQueryDsl as _};
 use dropshot::HttpError;
 
@@ -64,6 +64,9 @@ impl QueryToken {
             expiration,
             ..
         } = self;
+        let claims = Claims::decode_unvalidated(&jwt);
+        debug_assert!(claims.is_ok(), "API key claims are invalid");
+        let scope = claims.ok().and_then(|claims| claims.scope);
         assert_parentage(
             BencherResource::User,
             query_user.id,
@@ -77,6 +80,7 @@ impl QueryToken {
             token: jwt,
             creation,
             expiration,
+            scope,
         }
     }
 }
@@ -101,10 +105,12 @@ impl InsertToken {
         token: JsonNewToken,
         auth_user: &AuthUser,
     ) -> Result<Self, HttpError> {
-        let JsonNewToken { name, ttl } = token;
+        let JsonNewToken { name, ttl, scope } = token;
 
         let query_user = QueryUser::from_resource_id(conn, user)?;
         same_user!(auth_user, rbac, query_user.uuid);
+        // Do not allow a scoped API token to create a new API token with a broader scope
+        auth_user.check_is_unscoped()?;
 
         // TODO Custom max TTL
         let max_ttl = u32::MAX;
@@ -119,13 +125,16 @@ impl InsertToken {
             max_ttl
         };
 
-        let jwt = token_key.new_api_key(query_user.email, ttl).map_err(|e| {
-            issue_error(
-                "Failed to create new API key",
-                "Failed to create new API key.",
-                e,
-            )
-        })?;
+        let scope = scope.filter(|scope| !scope.is_unrestricted());
+        let jwt = token_key
+            .new_api_key(query_user.email, ttl, scope)
+            .map_err(|e| {
+                issue_error(
+                    "Failed to create new API key",
+                    "Failed to create new API key.",
+                    e,
+                )
+            })?;
 
         let claims = token_key.validate_api_key(&jwt).map_err(|e| {
             issue_error(
@@ -11,6 +11,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    audit_event (id) {
+        id -> Integer,
+        uuid -> Text,
+        user_uuid -> Text,
+        credential -> Integer,
+        token_uuid -> Nullable<Text>,
+        action -> Integer,
+        resource -> Integer,
+        resource_uuid -> Nullable<Text>,
+        organization_uuid -> Nullable<Text>,
+        project_uuid -> Nullable<Text>,
+        before -> Nullable<Text>,
+        after -> Nullable<Text>,
+        created -> BigInt,
+    }
+}
+
 diesel::table! {
     benchmark (id) {
         id -> Integer,
@@ -24,6 +42,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    benchmark_tag (id) {
+        id -> Integer,
+        benchmark_id -> Integer,
+        key -> Text,
+        value -> Text,
+    }
+}
+
 diesel::table! {
     boundary (id) {
         id -> Integer,
@@ -51,6 +78,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    change_point (id) {
+        id -> Integer,
+        uuid -> Text,
+        metric_id -> Integer,
+        threshold_id -> Integer,
+        model_id -> Integer,
+        baseline -> Double,
+        value -> Double,
+        created -> BigInt,
+    }
+}
+
+diesel::table! {
+    custom_role (id) {
+        id -> Integer,
+        uuid -> Text,
+        organization_id -> Integer,
+        name -> Text,
+        permissions -> Text,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::table! {
     head (id) {
         id -> Integer,
@@ -162,6 +214,7 @@ diesel::table! {
         window -> BigInt,
         created -> BigInt,
         modified -> BigInt,
+        benchmark_tags -> Nullable<Text>,
     }
 }
 
@@ -208,6 +261,28 @@ diesel::table! {
         visibility -> Integer,
         created -> BigInt,
         modified -> BigInt,
+        openmetrics -> Bool,
+    }
+}
+
+diesel::table! {
+    project_member (id) {
+        id -> Integer,
+        user_id -> Integer,
+        project_id -> Integer,
+        role_id -> Integer,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
+diesel::table! {
+    project_retention (id) {
+        id -> Integer,
+        project_id -> Integer,
+        reports -> Nullable<Integer>,
+        archived_branches -> Nullable<Integer>,
+        modified -> BigInt,
     }
 }
 
@@ -235,6 +310,7 @@ diesel::table! {
         start_time -> BigInt,
         end_time -> BigInt,
         created -> BigInt,
+        group_uuid -> Nullable<Text>,
     }
 }
 
@@ -263,6 +339,7 @@ diesel::table! {
         organization_id -> Integer,
         domain -> Text,
         created -> BigInt,
+        oidc -> Bool,
     }
 }
 
@@ -290,6 +367,7 @@ diesel::table! {
         model_id -> Nullable<Integer>,
         created -> BigInt,
         modified -> BigInt,
+        benchmark_tags -> Nullable<Text>,
     }
 }
 
@@ -316,6 +394,7 @@ diesel::table! {
         locked -> Bool,
         created -> BigInt,
         modified -> BigInt,
+        tokens_revoked -> Nullable<BigInt>,
     }
 }
 
@@ -329,12 +408,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    webhook (id) {
+        id -> Integer,
+        uuid -> Text,
+        project_id -> Integer,
+        url -> Text,
+        secret -> Text,
+        created -> BigInt,
+        modified -> BigInt,
+    }
+}
+
 diesel::joinable!(alert -> boundary (boundary_id));
 diesel::joinable!(benchmark -> project (project_id));
+diesel::joinable!(benchmark_tag -> benchmark (benchmark_id));
 diesel::joinable!(boundary -> metric (metric_id));
 diesel::joinable!(boundary -> model (model_id));
 diesel::joinable!(boundary -> threshold (threshold_id));
 diesel::joinable!(branch -> project (project_id));
+diesel::joinable!(change_point -> metric (metric_id));
+diesel::joinable!(change_point -> model (model_id));
+diesel::joinable!(change_point -> threshold (threshold_id));
+diesel::joinable!(custom_role -> organization (organization_id));
 diesel::joinable!(head_version -> version (version_id));
 diesel::joinable!(measure -> project (project_id));
 diesel::joinable!(metric -> measure (measure_id));
@@ -351,6 +447,10 @@ diesel::joinable!(plot_measure -> plot (plot_id));
 diesel::joinable!(plot_testbed -> plot (plot_id));
 diesel::joinable!(plot_testbed -> testbed (testbed_id));
 diesel::joinable!(project -> organization (organization_id));
+diesel::joinable!(project_member -> custom_role (role_id));
+diesel::joinable!(project_member -> project (project_id));
+diesel::joinable!(project_member -> user (user_id));
+diesel::joinable!(project_retention -> project (project_id));
 diesel::joinable!(project_role -> project (project_id));
 diesel::joinable!(project_role -> user (user_id));
 diesel::joinable!(report -> head (head_id));
@@ -368,12 +468,17 @@ diesel::joinable!(threshold -> project (project_id));
 diesel::joinable!(threshold -> testbed (testbed_id));
 diesel::joinable!(token -> user (user_id));
 diesel::joinable!(version -> project (project_id));
+diesel::joinable!(webhook -> project (project_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     alert,
+    audit_event,
     benchmark,
+    benchmark_tag,
     boundary,
     branch,
+    change_point,
+    custom_role,
     head,
     head_version,
     measure,
@@ -388,6 +493,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     plot_measure,
     plot_testbed,
     project,
+    project_member,
+    project_retention,
     project_role,
     report,
     report_benchmark,
@@ -398,4 +505,5 @@ diesel::allow_tables_to_appear_in_same_query!(
     token,
     user,
     version,
+    webhook,
 );
@@ -1,7 +1,7 @@
 #[cfg(feature = "plus")]
 use bencher_json::PlanLevel;
 use bencher_json::{
-    DateTime, Email, Jwt, OrganizationUuid, organization::member::OrganizationRole,
+    DateTime, Email, JsonTokenScope, Jwt, OrganizationUuid, organization::member::OrganizationRole,
 };
 use chrono::Utc;
 use jsonwebtoken::errors::ErrorKind as JsonWebTokenErrorKind;
@@ -11,13 +11,14 @@ use super::{TokenError, audience::Audience};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
-    pub aud: String,                // Audience
-    pub exp: i64,                   // Expiration time (as UTC timestamp)
-    pub iat: i64,                   // Issued at (as UTC timestamp)
-    pub iss: String,                // Issuer
-    pub sub: Email,                 // Subject (whom token refers to)
-    pub org: Option<OrgClaims>,     // Organization (for invitation)
-    pub state: Option<StateClaims>, // State (for OAuth)
+    pub aud: String,                   // Audience
+    pub exp: i64,                      // Expiration time (as UTC timestamp)
+    pub iat: i64,                      // Issued at (as UTC timestamp)
+    pub iss: String,                   // Issuer
+    pub sub: Email,                    // Subject (whom token refers to)
+    pub org: Option<OrgClaims>,        // Organization (for invitation)
+    pub state: Option<StateClaims>,    // State (for OAuth)
+    pub scope: Option<JsonTokenScope>, // Scope (for API keys)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +43,7 @@ impl Claims {
         ttl: u32,
         org: Option<OrgClaims>,
         state: Option<StateClaims>,
+        scope: Option<JsonTokenScope>,
     ) -> Self {
         let now = Utc::now().timestamp();
         Self {
@@ -52,9 +54,22 @@ impl Claims {
             sub: email,
             org,
             state,
+            scope,
         }
     }
 
+    /// Decode the claims of a JSON Web Token without validating it.
+    /// This must only be used for tokens that have already been validated,
+    /// such as the API keys stored in the database.
+    pub fn decode_unvalidated(token: &Jwt) -> Result<Self, TokenError> {
+        jsonwebtoken::dangerous::insecure_decode(token.as_ref())
+            .map(|token_data| token_data.claims)
+            .map_err(|error| TokenError::Decode {
+                token: token.clone(),
+                error,
+            })
+    }
+
     pub fn email(&self) -> &Email {
         &self.sub
     }
@@ -65,6 +80,14 @@ impl Claims {
         date_time.unwrap_or_default()
     }
 
+    pub fn scope(&self) -> Option<&JsonTokenScope> {
+        self.scope.as_ref()
+    }
+
+    pub fn is_api_key(&self) -> bool {
+        self.aud == Audience::ApiKey.to_string()
+    }
+
     pub fn expiration(&self) -> DateTime {
         let date_time = DateTime::try_from(self.exp);
         debug_assert!(date_time.is_ok(), "Expiration time is invalid");
@@ -1,7 +1,9 @@
 use std::str::FromStr as _;
 use std::sync::LazyLock;
 
-use bencher_json::{Email, Jwt, OrganizationUuid, Secret, organization::member::OrganizationRole};
+use bencher_json::{
+    Email, JsonTokenScope, Jwt, OrganizationUuid, Secret, organization::member::OrganizationRole,
+};
 use chrono::Utc;
 use jsonwebtoken::{
     Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation, decode, encode,
@@ -35,8 +37,9 @@ impl TokenKey {
         ttl: u32,
         org: Option<OrgClaims>,
         state: Option<StateClaims>,
+        scope: Option<JsonTokenScope>,
     ) -> Result<Jwt, TokenError> {
-        let claims = Claims::new(audience, self.issuer.clone(), email, ttl, org, state);
+        let claims = Claims::new(audience, self.issuer.clone(), email, ttl, org, state, scope);
         Jwt::from_str(&encode(&HEADER, &claims, &self.encoding).map_err(|e| {
             TokenError::Encode {
                 claims: Box::new(claims),
@@ -47,15 +50,20 @@ impl TokenKey {
     }
 
     pub fn new_auth(&self, email: Email, ttl: u32) -> Result<Jwt, TokenError> {
-        self.new_jwt(Audience::Auth, email, ttl, None, None)
+        self.new_jwt(Audience::Auth, email, ttl, None, None, None)
     }
 
     pub fn new_client(&self, email: Email, ttl: u32) -> Result<Jwt, TokenError> {
-        self.new_jwt(Audience::Client, email, ttl, None, None)
+        self.new_jwt(Audience::Client, email, ttl, None, None, None)
     }
 
-    pub fn new_api_key(&self, email: Email, ttl: u32) -> Result<Jwt, TokenError> {
-        self.new_jwt(Audience::ApiKey, email, ttl, None, None)
+    pub fn new_api_key(
+        &self,
+        email: Email,
+        ttl: u32,
+        scope: Option<JsonTokenScope>,
+    ) -> Result<Jwt, TokenError> {
+        self.new_jwt(Audience::ApiKey, email, ttl, None, None, scope)
     }
 
     pub fn new_invite(
@@ -69,11 +77,11 @@ impl TokenKey {
             uuid: org_uuid,
             role,
         };
-        self.new_jwt(Audience::Invite, email, ttl, Some(org_claims), None)
+        self.new_jwt(Audience::Invite, email, ttl, Some(org_claims), None, None)
     }
 
     pub fn new_oauth(&self, email: Email, ttl: u32, state: StateClaims) -> Result<Jwt, TokenError> {
-        self.new_jwt(Audience::OAuth, email, ttl, None, Some(state))
+        self.new_jwt(Audience::OAuth, email, ttl, None, Some(state), None)
     }
 
     fn validate(
@@ -131,9 +139,12 @@ impl TokenKey {
 mod tests {
     use std::{sync::LazyLock, thread, time};
 
-    use bencher_json::{Email, OrganizationUuid, organization::member::OrganizationRole};
+    use bencher_json::{
+        Email, JsonTokenScope, OrganizationUuid, ProjectUuid, TokenPermission,
+        organization::member::OrganizationRole,
+    };
 
-    use crate::{Audience, DEFAULT_SECRET_KEY};
+    use crate::{Audience, Claims, DEFAULT_SECRET_KEY};
 
     use super::TokenKey;
 
@@ -201,7 +212,7 @@ mod tests {
     fn jwt_api_key() {
         let secret_key = TokenKey::new(BENCHER_DOT_DEV_ISSUER.to_owned(), &DEFAULT_SECRET_KEY);
 
-        let token = secret_key.new_api_key(EMAIL.clone(), TTL).unwrap();
+        let token = secret_key.new_api_key(EMAIL.clone(), TTL, None).unwrap();
 
         let claims = secret_key.validate_api_key(&token).unwrap();
 
@@ -215,13 +226,36 @@ mod tests {
     fn jwt_api_key_expired() {
         let secret_key = TokenKey::new(BENCHER_DOT_DEV_ISSUER.to_owned(), &DEFAULT_SECRET_KEY);
 
-        let token = secret_key.new_api_key(EMAIL.clone(), 0).unwrap();
+        let token = secret_key.new_api_key(EMAIL.clone(), 0, None).unwrap();
 
         sleep_for_a_second();
 
         assert!(secret_key.validate_api_key(&token).is_err());
     }
 
+    #[test]
+    fn jwt_api_key_scope() {
+        let secret_key = TokenKey::new(BENCHER_DOT_DEV_ISSUER.to_owned(), &DEFAULT_SECRET_KEY);
+
+        let scope = JsonTokenScope {
+            organizations: None,
+            projects: Some(vec![ProjectUuid::new()]),
+            permission: Some(TokenPermission::Report),
+        };
+
+        let token = secret_key
+            .new_api_key(EMAIL.clone(), TTL, Some(scope.clone()))
+            .unwrap();
+
+        let claims = secret_key.validate_api_key(&token).unwrap();
+        assert_eq!(claims.aud, Audience::ApiKey.to_string());
+        assert_eq!(claims.sub, *EMAIL);
+        assert_eq!(claims.scope.as_ref(), Some(&scope));
+
+        let claims = Claims::decode_unvalidated(&token).unwrap();
+        assert_eq!(claims.scope, Some(scope));
+    }
+
     #[test]
     fn jwt_invite() {
         let secret_key = TokenKey::new(BENCHER_DOT_DEV_ISSUER.to_owned(), &DEFAULT_SECRET_KEY);
@@ -144,20 +144,9 @@ impl Visitor<'_> for DateTimeMillisVisitor {
 mod db {
     use super::DateTime;
 
-    impl<DB> diesel::serialize::ToSql<diesel::sql_types::BigInt, DB> for DateTime
-    where
-        DB: diesel::backend::Backend,
-        for<'a> i64: diesel::serialize::ToSql<diesel::sql_types::BigInt, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
-    {
-        fn to_sql<'b>(
-            &'b self,
-            out: &mut diesel::serialize::Output<'b, '_, DB>,
-        ) -> diesel::serialize::Result {
-            out.set_value(self.0.timestamp());
-            Ok(diesel::serialize::IsNull::No)
-        }
-    }
+    crate::db_to_sql!(DateTime, diesel::sql_types::BigInt, |value| value
+        .0
+        .timestamp());
 
     impl<DB> diesel::deserialize::FromSql<diesel::sql_types::BigInt, DB> for DateTime
     where
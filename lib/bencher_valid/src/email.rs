@@ -86,6 +86,13 @@ impl Email {
             .domain()
             .to_owned()
     }
+
+    pub fn local_part(&self) -> String {
+        // This is safe because the `Email` struct guarantees validity
+        EmailAddress::new_unchecked(self.as_ref())
+            .local_part()
+            .to_owned()
+    }
 }
 
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
@@ -38,6 +38,12 @@ pub enum ValidError {
     GitHash(String),
     #[error("Failed to validate secret: {0}")]
     Secret(String),
+    #[error("Failed to validate tag key: {0}")]
+    TagKey(String),
+    #[error("Failed to validate tag value: {0}")]
+    TagValue(String),
+    #[error("Failed to validate tag selector (expected `key=value`): {0}")]
+    TagSelector(String),
     #[error("Invalid model boundary: {0}")]
     Boundary(f64),
     #[error("Failed to parse model boundary: {0}")]
@@ -20,6 +20,7 @@ mod resource_name;
 mod search;
 mod secret;
 mod slug;
+mod tag;
 mod units;
 mod url;
 mod user_name;
@@ -54,6 +55,7 @@ pub use resource_id::{IntoResourceId, ResourceId};
 pub use resource_name::ResourceName;
 pub use search::Search;
 pub use secret::Secret;
+pub use tag::{TagKey, TagSelector, TagSelectors, TagValue};
 pub use units::{BYTES, NANOSECONDS, SECONDS, Units};
 pub use user_name::UserName;
 
@@ -82,26 +84,33 @@ where
     }
 }
 
+// Values that are stored as-is can be bound by reference, which works for every backend.
+// Values that must first be converted into another type, like a `Vec` into a comma separated `String`,
+// use `db_to_sql` instead.
 #[cfg(feature = "db")]
 macro_rules! typed_string {
     ($name:ident) => {
         impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for $name
         where
             DB: diesel::backend::Backend,
-            for<'a> String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>
-                + Into<
-                    <DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer,
-                >,
+            String: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
         {
             fn to_sql<'b>(
                 &'b self,
                 out: &mut diesel::serialize::Output<'b, '_, DB>,
             ) -> diesel::serialize::Result {
-                out.set_value(self.to_string());
-                Ok(diesel::serialize::IsNull::No)
+                self.0.to_sql(out)
             }
         }
 
+        $crate::typed_string!(@from_sql $name);
+    };
+    ($name:ident, to_string) => {
+        $crate::db_to_sql!($name, diesel::sql_types::Text, |value| value.to_string());
+
+        $crate::typed_string!(@from_sql $name);
+    };
+    (@from_sql $name:ident) => {
         impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for $name
         where
             DB: diesel::backend::Backend,
@@ -117,6 +126,42 @@ macro_rules! typed_string {
     };
 }
 
+/// Implement `ToSql` for a type that has to be converted into an owned value before it can be bound.
+/// The `Sqlite` backend takes ownership of the converted value, while the `Pg` backend serializes it right away.
+/// A multi-backend connection binds values by reference,
+/// so it also needs its own `ToSql` implementation that defers to these two.
+// https://docs.rs/diesel/latest/diesel/serialize/trait.ToSql.html#examples
+#[cfg(feature = "db")]
+#[macro_export]
+macro_rules! db_to_sql {
+    ($name:ty, $sql_type:ty, |$value:ident| $into:expr) => {
+        impl diesel::serialize::ToSql<$sql_type, diesel::sqlite::Sqlite> for $name {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut diesel::serialize::Output<'b, '_, diesel::sqlite::Sqlite>,
+            ) -> diesel::serialize::Result {
+                let $value = self;
+                out.set_value($into);
+                Ok(diesel::serialize::IsNull::No)
+            }
+        }
+
+        impl diesel::serialize::ToSql<$sql_type, diesel::pg::Pg> for $name {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>,
+            ) -> diesel::serialize::Result {
+                let $value = self;
+                let value = $into;
+                diesel::serialize::ToSql::<$sql_type, diesel::pg::Pg>::to_sql(
+                    &value,
+                    &mut out.reborrow(),
+                )
+            }
+        }
+    };
+}
+
 #[cfg(feature = "db")]
 pub(crate) use typed_string;
 
@@ -80,6 +80,8 @@ impl Visitor<'_> for BoundaryVisitor {
 
 impl Boundary {
     pub const ZERO: Self = Self(OrderedFloat(0.0));
+    pub const TEN: Self = Self(OrderedFloat(0.1));
+    pub const THREE: Self = Self(OrderedFloat(3.0));
     pub const MIN_STATISTICAL: Self = Self::FIFTY;
     pub const FIFTY: Self = Self(OrderedFloat(0.5));
     pub const FIFTY_FIVE: Self = Self(OrderedFloat(0.55));
@@ -124,15 +126,13 @@ mod db {
     impl<DB> diesel::serialize::ToSql<diesel::sql_types::Double, DB> for Boundary
     where
         DB: diesel::backend::Backend,
-        for<'a> f64: diesel::serialize::ToSql<diesel::sql_types::Double, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
+        f64: diesel::serialize::ToSql<diesel::sql_types::Double, DB>,
     {
         fn to_sql<'b>(
             &'b self,
             out: &mut diesel::serialize::Output<'b, '_, DB>,
         ) -> diesel::serialize::Result {
-            out.set_value(f64::from(*self));
-            Ok(diesel::serialize::IsNull::No)
+            self.0.0.to_sql(out)
         }
     }
 
@@ -102,7 +102,11 @@ pub fn validate_model(model: Model) -> Result<(), ValidError> {
             validate_sample_size(min_sample_size, max_sample_size)?;
             validate_boundary::<PercentageBoundary>(lower_boundary, upper_boundary)
         },
-        ModelTest::ZScore | ModelTest::TTest | ModelTest::LogNormal => {
+        ModelTest::ZScore
+        | ModelTest::TTest
+        | ModelTest::WelchTTest
+        | ModelTest::LogNormal
+        | ModelTest::MannWhitney => {
             validate_sample_size(min_sample_size, max_sample_size)?;
             validate_boundary::<CdfBoundary>(lower_boundary, upper_boundary)
         },
@@ -10,6 +10,8 @@ const T_TEST_INT: i32 = 1;
 const LOG_NORMAL_INT: i32 = 10;
 const IQR_INT: i32 = 40;
 const DELTA_IQR_INT: i32 = 41;
+const MANN_WHITNEY_INT: i32 = 50;
+const WELCH_T_TEST_INT: i32 = 2;
 
 #[typeshare::typeshare]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, Serialize, Deserialize)]
@@ -28,13 +30,15 @@ pub enum ModelTest {
     LogNormal = LOG_NORMAL_INT,
     Iqr = IQR_INT,
     DeltaIqr = DELTA_IQR_INT,
+    MannWhitney = MANN_WHITNEY_INT,
+    WelchTTest = WELCH_T_TEST_INT,
 }
 
 #[cfg(feature = "db")]
 mod db {
     use super::{
-        DELTA_IQR_INT, IQR_INT, LOG_NORMAL_INT, ModelTest, PERCENTAGE_INT, STATIC_INT, T_TEST_INT,
-        Z_SCORE_INT,
+        DELTA_IQR_INT, IQR_INT, LOG_NORMAL_INT, MANN_WHITNEY_INT, ModelTest, PERCENTAGE_INT,
+        STATIC_INT, T_TEST_INT, WELCH_T_TEST_INT, Z_SCORE_INT,
     };
 
     #[derive(Debug, thiserror::Error)]
@@ -60,6 +64,8 @@ mod db {
                 Self::LogNormal => LOG_NORMAL_INT.to_sql(out),
                 Self::Iqr => IQR_INT.to_sql(out),
                 Self::DeltaIqr => DELTA_IQR_INT.to_sql(out),
+                Self::MannWhitney => MANN_WHITNEY_INT.to_sql(out),
+                Self::WelchTTest => WELCH_T_TEST_INT.to_sql(out),
             }
         }
     }
@@ -78,6 +84,8 @@ mod db {
                 LOG_NORMAL_INT => Ok(Self::LogNormal),
                 IQR_INT => Ok(Self::Iqr),
                 DELTA_IQR_INT => Ok(Self::DeltaIqr),
+                MANN_WHITNEY_INT => Ok(Self::MannWhitney),
+                WELCH_T_TEST_INT => Ok(Self::WelchTTest),
                 value => Err(Box::new(ModelTestError::Invalid(value))),
             }
         }
@@ -96,20 +96,7 @@ impl Visitor<'_> for WindowVisitor {
 mod db {
     use super::Window;
 
-    impl<DB> diesel::serialize::ToSql<diesel::sql_types::BigInt, DB> for Window
-    where
-        DB: diesel::backend::Backend,
-        for<'a> i64: diesel::serialize::ToSql<diesel::sql_types::BigInt, DB>
-            + Into<<DB::BindCollector<'a> as diesel::query_builder::BindCollector<'a, DB>>::Buffer>,
-    {
-        fn to_sql<'b>(
-            &'b self,
-            out: &mut diesel::serialize::Output<'b, '_, DB>,
-        ) -> diesel::serialize::Result {
-            out.set_value(i64::from(*self));
-            Ok(diesel::serialize::IsNull::No)
-        }
-    }
+    crate::db_to_sql!(Window, diesel::sql_types::BigInt, |value| i64::from(*value));
 
     impl<DB> diesel::deserialize::FromSql<diesel::sql_types::BigInt, DB> for Window
     where
@@ -0,0 +1,376 @@
+use derive_more::Display;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use std::{collections::BTreeMap, fmt, str::FromStr};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Visitor},
+};
+
+use crate::{ValidError, is_valid_len};
+
+const SELECTOR_DELIMITER: char = ',';
+const KEY_VALUE_DELIMITER: char = '=';
+
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+pub struct TagKey(String);
+
+#[cfg(feature = "db")]
+crate::typed_string!(TagKey);
+
+impl FromStr for TagKey {
+    type Err = ValidError;
+
+    fn from_str(tag_key: &str) -> Result<Self, Self::Err> {
+        if is_valid_tag_key(tag_key) {
+            Ok(Self(tag_key.into()))
+        } else {
+            Err(ValidError::TagKey(tag_key.into()))
+        }
+    }
+}
+
+impl AsRef<str> for TagKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<TagKey> for String {
+    fn from(tag_key: TagKey) -> Self {
+        tag_key.0
+    }
+}
+
+impl<'de> Deserialize<'de> for TagKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TagKeyVisitor)
+    }
+}
+
+struct TagKeyVisitor;
+
+impl Visitor<'_> for TagKeyVisitor {
+    type Value = TagKey;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a tag key string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+}
+
+impl TagKey {
+    pub const MAX_LEN: usize = crate::MAX_LEN;
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_tag_key(tag_key: &str) -> bool {
+    is_valid_len(tag_key)
+        && tag_key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+#[typeshare::typeshare]
+#[derive(Debug, Display, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+pub struct TagValue(String);
+
+#[cfg(feature = "db")]
+crate::typed_string!(TagValue);
+
+impl FromStr for TagValue {
+    type Err = ValidError;
+
+    fn from_str(tag_value: &str) -> Result<Self, Self::Err> {
+        if is_valid_tag_value(tag_value) {
+            Ok(Self(tag_value.into()))
+        } else {
+            Err(ValidError::TagValue(tag_value.into()))
+        }
+    }
+}
+
+impl AsRef<str> for TagValue {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<TagValue> for String {
+    fn from(tag_value: TagValue) -> Self {
+        tag_value.0
+    }
+}
+
+impl<'de> Deserialize<'de> for TagValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TagValueVisitor)
+    }
+}
+
+struct TagValueVisitor;
+
+impl Visitor<'_> for TagValueVisitor {
+    type Value = TagValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a tag value string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+}
+
+impl TagValue {
+    pub const MAX_LEN: usize = crate::MAX_LEN;
+}
+
+// Tag values can not contain a comma, as it is used to delimit tag selectors.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn is_valid_tag_value(tag_value: &str) -> bool {
+    is_valid_len(tag_value) && !tag_value.contains(SELECTOR_DELIMITER)
+}
+
+/// A single `key=value` tag selector.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TagSelector {
+    pub key: TagKey,
+    pub value: TagValue,
+}
+
+impl fmt::Display for TagSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{KEY_VALUE_DELIMITER}{}", self.key, self.value)
+    }
+}
+
+impl FromStr for TagSelector {
+    type Err = ValidError;
+
+    fn from_str(tag_selector: &str) -> Result<Self, Self::Err> {
+        let (key, value) = tag_selector
+            .split_once(KEY_VALUE_DELIMITER)
+            .ok_or_else(|| ValidError::TagSelector(tag_selector.into()))?;
+        Ok(Self {
+            key: key.parse()?,
+            value: value.parse()?,
+        })
+    }
+}
+
+/// A comma separated list of `key=value` tag selectors.
+/// A set of tags matches only if it matches every selector.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[cfg_attr(feature = "db", derive(diesel::FromSqlRow, diesel::AsExpression))]
+#[cfg_attr(feature = "db", diesel(sql_type = diesel::sql_types::Text))]
+pub struct TagSelectors(
+    #[cfg_attr(feature = "schema", schemars(with = "String"))] Vec<TagSelector>,
+);
+
+#[cfg(feature = "db")]
+crate::typed_string!(TagSelectors, to_string);
+
+impl fmt::Display for TagSelectors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, selector) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, "{SELECTOR_DELIMITER}")?;
+            }
+            write!(f, "{selector}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TagSelectors {
+    type Err = ValidError;
+
+    fn from_str(tag_selectors: &str) -> Result<Self, Self::Err> {
+        let mut selectors = tag_selectors
+            .split(SELECTOR_DELIMITER)
+            .map(str::parse)
+            .collect::<Result<Vec<TagSelector>, _>>()?;
+        selectors.sort();
+        selectors.dedup();
+        Ok(Self(selectors))
+    }
+}
+
+impl From<TagSelectors> for String {
+    fn from(tag_selectors: TagSelectors) -> Self {
+        tag_selectors.to_string()
+    }
+}
+
+impl From<TagSelector> for TagSelectors {
+    fn from(selector: TagSelector) -> Self {
+        Self(vec![selector])
+    }
+}
+
+impl TagSelectors {
+    pub fn iter(&self) -> impl Iterator<Item = &TagSelector> {
+        self.0.iter()
+    }
+
+    pub fn matches(&self, tags: &BTreeMap<TagKey, TagValue>) -> bool {
+        self.0
+            .iter()
+            .all(|selector| tags.get(&selector.key) == Some(&selector.value))
+    }
+}
+
+impl Serialize for TagSelectors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TagSelectors {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TagSelectorsVisitor)
+    }
+}
+
+struct TagSelectorsVisitor;
+
+impl Visitor<'_> for TagSelectorsVisitor {
+    type Value = TagSelectors;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a comma separated list of key=value tag selectors")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::tests::{LEN_0_STR, LEN_64_STR, LEN_65_STR};
+
+    use super::{TagSelectors, is_valid_tag_key, is_valid_tag_value};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn is_valid_tag_key_true() {
+        for value in [
+            "a",
+            "ab",
+            "ABC",
+            "suite",
+            "os.family",
+            "team-core_2",
+            LEN_64_STR,
+        ] {
+            assert_eq!(true, is_valid_tag_key(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn is_valid_tag_key_false() {
+        for value in [LEN_0_STR, LEN_65_STR, " a", "a b", "a=b", "a,b", "a/b", "ä"] {
+            assert_eq!(false, is_valid_tag_key(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn is_valid_tag_value_true() {
+        for value in ["a", "parser", "a b", "a=b", "v1.2.3", LEN_64_STR] {
+            assert_eq!(true, is_valid_tag_value(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn is_valid_tag_value_false() {
+        for value in [LEN_0_STR, LEN_65_STR, " a", "a ", "a,b"] {
+            assert_eq!(false, is_valid_tag_value(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn tag_selectors_parse_ok() {
+        for value in ["suite=parser", "suite=parser,os=linux", "a=b=c"] {
+            assert_eq!(true, value.parse::<TagSelectors>().is_ok(), "{value}");
+        }
+    }
+
+    #[test]
+    fn tag_selectors_parse_err() {
+        for value in [
+            LEN_0_STR,
+            "suite",
+            "=parser",
+            "suite=",
+            "suite=parser,",
+            ",",
+        ] {
+            assert_eq!(false, value.parse::<TagSelectors>().is_ok(), "{value}");
+        }
+    }
+
+    #[test]
+    fn tag_selectors_display() {
+        let selectors: TagSelectors = "suite=parser,os=linux,suite=parser".parse().unwrap();
+        assert_eq!(selectors.to_string(), "os=linux,suite=parser");
+    }
+
+    #[test]
+    fn tag_selectors_matches() {
+        let selectors: TagSelectors = "suite=parser,os=linux".parse().unwrap();
+        let tags = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(key, value)| (key.parse().unwrap(), value.parse().unwrap()))
+                .collect::<BTreeMap<_, _>>()
+        };
+        assert!(selectors.matches(&tags(&[("suite", "parser"), ("os", "linux")])));
+        assert!(selectors.matches(&tags(&[
+            ("suite", "parser"),
+            ("os", "linux"),
+            ("team", "core")
+        ])));
+        assert!(!selectors.matches(&tags(&[("suite", "parser")])));
+        assert!(!selectors.matches(&tags(&[("suite", "parser"), ("os", "macos")])));
+        assert!(!selectors.matches(&tags(&[])));
+    }
+}
@@ -0,0 +1,629 @@
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use bencher_valid::{Email, Jwt, NonEmpty, Secret, UserName};
+use hmac::{Hmac, Mac as _};
+use openidconnect::{
+    AuthorizationCode, ClientId, ClientSecret, CsrfToken, EndpointMaybeSet, EndpointNotSet,
+    EndpointSet, IssuerUrl, Nonce, OAuth2TokenResponse as _, PkceCodeChallenge, PkceCodeVerifier,
+    RedirectUrl, Scope, TokenResponse as _,
+    core::{CoreAuthenticationFlow, CoreClient, CoreIdToken, CoreProviderMetadata},
+};
+use serde_json::{Map, Value};
+use sha2::Sha256;
+use tokio::sync::OnceCell;
+use url::Url;
+
+const OPENID_SCOPE: &str = "openid";
+const EMAIL_SCOPE: &str = "email";
+const PROFILE_SCOPE: &str = "profile";
+
+const SUBJECT_CLAIM: &str = "sub";
+const DEFAULT_EMAIL_CLAIM: &str = "email";
+const DEFAULT_EMAIL_VERIFIED_CLAIM: &str = "email_verified";
+const DEFAULT_NAME_CLAIM: &str = "name";
+const PREFERRED_USERNAME_CLAIM: &str = "preferred_username";
+
+// Domain separation for the values derived from the OAuth state
+const PKCE_LABEL: &[u8] = b"pkce";
+const NONCE_LABEL: &[u8] = b"nonce";
+
+type HmacSha256 = Hmac<Sha256>;
+type DiscoveredClient = CoreClient<
+    EndpointSet,
+    EndpointNotSet,
+    EndpointNotSet,
+    EndpointNotSet,
+    EndpointMaybeSet,
+    EndpointMaybeSet,
+>;
+
+#[derive(Debug, Clone)]
+pub struct OidcClient {
+    issuer_url: IssuerUrl,
+    client_id: ClientId,
+    client_secret: ClientSecret,
+    redirect_url: RedirectUrl,
+    scopes: Vec<Scope>,
+    claims: OidcClaims,
+    // The provider metadata is discovered on first use,
+    // so an unavailable identity provider does not prevent the server from starting.
+    provider: OnceCell<DiscoveredClient>,
+}
+
+/// The names of the claims used to create a user.
+#[derive(Debug, Clone)]
+pub struct OidcClaims {
+    pub email: String,
+    pub email_verified: String,
+    pub name: String,
+}
+
+impl Default for OidcClaims {
+    fn default() -> Self {
+        Self {
+            email: DEFAULT_EMAIL_CLAIM.to_owned(),
+            email_verified: DEFAULT_EMAIL_VERIFIED_CLAIM.to_owned(),
+            name: DEFAULT_NAME_CLAIM.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum OidcClientError {
+    #[error("Failed to create a reqwest client: {0}")]
+    Reqwest(reqwest::Error),
+    #[error("Failed to discover OpenID Connect provider metadata: {0}")]
+    Discovery(openidconnect::DiscoveryError<openidconnect::HttpClientError<reqwest::Error>>),
+    #[error("Failed to derive a value from the OAuth state")]
+    Derive,
+    #[error("OpenID Connect provider does not have a token endpoint: {0}")]
+    TokenEndpoint(openidconnect::ConfigurationError),
+    #[error("Failed to exchange code for tokens: {0}")]
+    Exchange(
+        openidconnect::RequestTokenError<
+            openidconnect::HttpClientError<reqwest::Error>,
+            openidconnect::StandardErrorResponse<openidconnect::core::CoreErrorResponseType>,
+        >,
+    ),
+    #[error("Missing ID token in token response")]
+    NoIdToken,
+    #[error("Failed to verify ID token: {0}")]
+    IdToken(openidconnect::ClaimsVerificationError),
+    #[error("Failed to decode ID token claims")]
+    IdTokenClaims,
+    #[error("Failed to call OpenID Connect UserInfo endpoint: {0}")]
+    UserInfoReq(reqwest::Error),
+    #[error("Failed to parse OpenID Connect UserInfo response: {0}")]
+    UserInfoJson(reqwest::Error),
+    #[error(
+        "OpenID Connect UserInfo subject ({userinfo}) does not match ID token subject ({id_token})"
+    )]
+    UserInfoSubject { id_token: String, userinfo: String },
+    #[error("Account email is not verified")]
+    NotEmailVerified,
+    #[error("Missing email claim ({0})")]
+    NoEmail(String),
+    #[error("Failed to parse user name: {0}")]
+    BadUserName(bencher_valid::ValidError),
+    #[error("Failed to parse email: {0}")]
+    BadEmail(bencher_valid::ValidError),
+}
+
+impl OidcClientError {
+    /// The identity provider could not be reached, as opposed to rejecting the user.
+    pub fn is_unavailable(&self) -> bool {
+        matches!(self, Self::Reqwest(_) | Self::Discovery(_))
+    }
+}
+
+impl OidcClient {
+    pub fn new(
+        issuer: Url,
+        client_id: NonEmpty,
+        client_secret: Secret,
+        redirect_url: Url,
+        scopes: Vec<NonEmpty>,
+        claims: OidcClaims,
+    ) -> Self {
+        // The `openid` scope is always requested by the OpenID Connect client.
+        let scopes = [EMAIL_SCOPE, PROFILE_SCOPE]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .chain(scopes.into_iter().map(Into::into))
+            .fold(Vec::new(), |mut scopes, scope| {
+                if scope != OPENID_SCOPE && !scopes.contains(&scope) {
+                    scopes.push(scope);
+                }
+                scopes
+            })
+            .into_iter()
+            .map(Scope::new)
+            .collect();
+        Self {
+            issuer_url: IssuerUrl::from_url(issuer),
+            client_id: ClientId::new(client_id.into()),
+            client_secret: ClientSecret::new(client_secret.into()),
+            redirect_url: RedirectUrl::from_url(redirect_url),
+            scopes,
+            claims,
+            provider: OnceCell::new(),
+        }
+    }
+
+    pub async fn auth_url(&self, state: Jwt) -> Result<Url, OidcClientError> {
+        let http_client = http_client()?;
+        let provider = self.provider(&http_client).await?;
+
+        let pkce_verifier = self.pkce_verifier(&state)?;
+        let pkce_challenge = PkceCodeChallenge::from_code_verifier_sha256(&pkce_verifier);
+        let nonce = self.nonce(&state)?;
+        let state_fn = || CsrfToken::new(state.into());
+        let nonce_fn = || nonce;
+        let (auth_url, _csrf_token, _nonce) = provider
+            .authorize_url(
+                CoreAuthenticationFlow::AuthorizationCode,
+                state_fn,
+                nonce_fn,
+            )
+            .add_scopes(self.scopes.iter().cloned())
+            .set_pkce_challenge(pkce_challenge)
+            .url();
+        Ok(auth_url)
+    }
+
+    pub async fn oauth_user(
+        &self,
+        state: &Jwt,
+        code: Secret,
+    ) -> Result<(UserName, Email), OidcClientError> {
+        let http_client = http_client()?;
+        let provider = self.provider(&http_client).await?;
+
+        let token = provider
+            .exchange_code(AuthorizationCode::new(code.into()))
+            .map_err(OidcClientError::TokenEndpoint)?
+            .set_pkce_verifier(self.pkce_verifier(state)?)
+            .request_async(&http_client)
+            .await
+            .map_err(OidcClientError::Exchange)?;
+
+        let id_token = token.id_token().ok_or(OidcClientError::NoIdToken)?;
+        let nonce = self.nonce(state)?;
+        let subject = id_token
+            .claims(&provider.id_token_verifier(), &nonce)
+            .map_err(OidcClientError::IdToken)?
+            .subject()
+            .to_string();
+        let mut claims = id_token_claims(id_token)?;
+
+        // The UserInfo claims take precedence, as they may include claims that are not in the ID token.
+        if let Some(user_info_url) = provider.user_info_url() {
+            let user_info: Map<String, Value> = http_client
+                .get(user_info_url.url().clone())
+                .bearer_auth(token.access_token().secret())
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(OidcClientError::UserInfoReq)?
+                .json()
+                .await
+                .map_err(OidcClientError::UserInfoJson)?;
+            // https://openid.net/specs/openid-connect-core-1_0.html#UserInfoResponse
+            let user_info_subject = user_info.get(SUBJECT_CLAIM).and_then(Value::as_str);
+            if user_info_subject != Some(subject.as_str()) {
+                return Err(OidcClientError::UserInfoSubject {
+                    id_token: subject,
+                    userinfo: user_info_subject.unwrap_or_default().to_owned(),
+                });
+            }
+            claims.extend(user_info);
+        }
+
+        self.claims.user(&claims)
+    }
+
+    async fn provider(
+        &self,
+        http_client: &reqwest::Client,
+    ) -> Result<&DiscoveredClient, OidcClientError> {
+        self.provider
+            .get_or_try_init(|| async {
+                let provider_metadata =
+                    CoreProviderMetadata::discover_async(self.issuer_url.clone(), http_client)
+                        .await
+                        .map_err(OidcClientError::Discovery)?;
+                Ok(CoreClient::from_provider_metadata(
+                    provider_metadata,
+                    self.client_id.clone(),
+                    Some(self.client_secret.clone()),
+                )
+                .set_redirect_uri(self.redirect_url.clone()))
+            })
+            .await
+    }
+
+    // The PKCE verifier and nonce are derived from the signed OAuth state,
+    // so they do not need to be stored between the authorization request and the code exchange.
+    // Only the server can derive them, as they are keyed with the client secret.
+    fn pkce_verifier(&self, state: &Jwt) -> Result<PkceCodeVerifier, OidcClientError> {
+        self.derive(PKCE_LABEL, state).map(PkceCodeVerifier::new)
+    }
+
+    fn nonce(&self, state: &Jwt) -> Result<Nonce, OidcClientError> {
+        self.derive(NONCE_LABEL, state).map(Nonce::new)
+    }
+
+    fn derive(&self, label: &[u8], state: &Jwt) -> Result<String, OidcClientError> {
+        let mut mac = HmacSha256::new_from_slice(self.client_secret.secret().as_bytes())
+            .map_err(|_err| OidcClientError::Derive)?;
+        mac.update(label);
+        mac.update(state.as_ref().as_bytes());
+        Ok(URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes()))
+    }
+}
+
+impl OidcClaims {
+    fn user(&self, claims: &Map<String, Value>) -> Result<(UserName, Email), OidcClientError> {
+        let is_email_verified = match claims.get(&self.email_verified) {
+            Some(Value::Bool(verified)) => *verified,
+            // Some providers send boolean claims as strings
+            Some(Value::String(verified)) => verified == "true",
+            _ => false,
+        };
+        if !is_email_verified {
+            return Err(OidcClientError::NotEmailVerified);
+        }
+
+        let email_str = claims
+            .get(&self.email)
+            .and_then(Value::as_str)
+            .ok_or_else(|| OidcClientError::NoEmail(self.email.clone()))?;
+        let email: Email = email_str.parse().map_err(OidcClientError::BadEmail)?;
+
+        // Choose a username: name claim > preferred username > email local-part
+        let candidate_name = [self.name.as_str(), PREFERRED_USERNAME_CLAIM]
+            .into_iter()
+            .find_map(|claim| claims.get(claim).and_then(Value::as_str))
+            .unwrap_or_else(|| email_str.split('@').next().unwrap_or(email_str));
+        let user_name: UserName = candidate_name
+            .parse()
+            .map_err(OidcClientError::BadUserName)?;
+
+        Ok((user_name, email))
+    }
+}
+
+fn http_client() -> Result<reqwest::Client, OidcClientError> {
+    reqwest::ClientBuilder::new()
+        // Following redirects opens the client up to SSRF vulnerabilities.
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(OidcClientError::Reqwest)
+}
+
+// The ID token must already be verified.
+// All of its claims are decoded, so that any claim can be mapped.
+fn id_token_claims(id_token: &CoreIdToken) -> Result<Map<String, Value>, OidcClientError> {
+    let id_token = id_token.to_string();
+    let payload = id_token
+        .split('.')
+        .nth(1)
+        .ok_or(OidcClientError::IdTokenClaims)?;
+    let payload = URL_SAFE_NO_PAD
+        .decode(payload)
+        .map_err(|_err| OidcClientError::IdTokenClaims)?;
+    serde_json::from_slice(&payload).map_err(|_err| OidcClientError::IdTokenClaims)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bencher_valid::{Jwt, NonEmpty, Secret};
+    use chrono::{Duration, Utc};
+    use mockito::{Matcher, Mock, Server};
+    use openidconnect::{
+        AdditionalClaims, Audience, EndUserEmail, IdToken, IdTokenClaims, IssuerUrl, JsonWebKeyId,
+        PkceCodeChallenge, PrivateSigningKey as _, StandardClaims, SubjectIdentifier,
+        core::{
+            CoreGenderClaim, CoreJsonWebKeySet, CoreJweContentEncryptionAlgorithm,
+            CoreJwsSigningAlgorithm, CoreRsaPrivateSigningKey,
+        },
+    };
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+
+    use super::{OidcClaims, OidcClient, OidcClientError};
+
+    const CLIENT_ID: &str = "bencher";
+    const CLIENT_SECRET: &str = "mock-idp-client-secret";
+    const REDIRECT_URL: &str = "http://localhost:3000/auth/oidc";
+    const CODE: &str = "mock-idp-code";
+    const SUBJECT: &str = "248289761001";
+    const ACCESS_TOKEN: &str = "mock-idp-access-token";
+
+    #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+    struct MockClaims {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mail: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mail_verified: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        display_name: Option<String>,
+    }
+
+    impl AdditionalClaims for MockClaims {}
+
+    type MockIdToken = IdToken<
+        MockClaims,
+        CoreGenderClaim,
+        CoreJweContentEncryptionAlgorithm,
+        CoreJwsSigningAlgorithm,
+    >;
+
+    struct MockIdp {
+        server: mockito::ServerGuard,
+        signing_key: CoreRsaPrivateSigningKey,
+    }
+
+    impl MockIdp {
+        async fn new(user_info: bool) -> Self {
+            let mut server = Server::new_async().await;
+            let signing_key = CoreRsaPrivateSigningKey::from_pem(
+                include_str!("mock_idp.pem"),
+                Some(JsonWebKeyId::new("mock-idp".to_owned())),
+            )
+            .unwrap();
+
+            let issuer = issuer(&server);
+            let mut discovery = serde_json::json!({
+                "issuer": issuer,
+                "authorization_endpoint": format!("{issuer}authorize"),
+                "token_endpoint": format!("{issuer}token"),
+                "jwks_uri": format!("{issuer}jwks"),
+                "response_types_supported": ["code"],
+                "subject_types_supported": ["public"],
+                "id_token_signing_alg_values_supported": ["RS256"],
+                "code_challenge_methods_supported": ["S256"],
+            });
+            if user_info {
+                discovery.as_object_mut().unwrap().insert(
+                    "userinfo_endpoint".to_owned(),
+                    format!("{issuer}userinfo").into(),
+                );
+            }
+            server
+                .mock("GET", "/.well-known/openid-configuration")
+                .with_header("content-type", "application/json")
+                .with_body(discovery.to_string())
+                .create_async()
+                .await;
+            let jwks = CoreJsonWebKeySet::new(vec![signing_key.as_verification_key()]);
+            server
+                .mock("GET", "/jwks")
+                .with_header("content-type", "application/json")
+                .with_body(serde_json::to_string(&jwks).unwrap())
+                .create_async()
+                .await;
+
+            Self {
+                server,
+                signing_key,
+            }
+        }
+
+        fn client(&self, claims: OidcClaims) -> OidcClient {
+            OidcClient::new(
+                self.server.url().parse().unwrap(),
+                CLIENT_ID.parse::<NonEmpty>().unwrap(),
+                CLIENT_SECRET.parse::<Secret>().unwrap(),
+                REDIRECT_URL.parse().unwrap(),
+                vec!["groups".parse().unwrap()],
+                claims,
+            )
+        }
+
+        fn id_token(
+            &self,
+            client: &OidcClient,
+            state: &Jwt,
+            standard_claims: StandardClaims<CoreGenderClaim>,
+            mock_claims: MockClaims,
+        ) -> String {
+            let now = Utc::now();
+            let claims = IdTokenClaims::new(
+                IssuerUrl::new(issuer(&self.server)).unwrap(),
+                vec![Audience::new(CLIENT_ID.to_owned())],
+                now + Duration::minutes(5),
+                now,
+                standard_claims,
+                mock_claims,
+            )
+            .set_nonce(Some(client.nonce(state).unwrap()));
+            MockIdToken::new(
+                claims,
+                &self.signing_key,
+                CoreJwsSigningAlgorithm::RsaSsaPkcs1V15Sha256,
+                None,
+                None,
+            )
+            .unwrap()
+            .to_string()
+        }
+
+        async fn mock_token(&mut self, client: &OidcClient, state: &Jwt, id_token: String) -> Mock {
+            let code_verifier = client.pkce_verifier(state).unwrap();
+            self.server
+                .mock("POST", "/token")
+                .match_body(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("grant_type".into(), "authorization_code".into()),
+                    Matcher::UrlEncoded("code".into(), CODE.into()),
+                    Matcher::UrlEncoded("code_verifier".into(), code_verifier.secret().clone()),
+                    Matcher::UrlEncoded("redirect_uri".into(), REDIRECT_URL.into()),
+                ]))
+                .with_header("content-type", "application/json")
+                .with_body(
+                    serde_json::json!({
+                        "access_token": ACCESS_TOKEN,
+                        "token_type": "Bearer",
+                        "expires_in": 300,
+                        "id_token": id_token,
+                    })
+                    .to_string(),
+                )
+                .create_async()
+                .await
+        }
+
+        async fn mock_user_info(&mut self, user_info: serde_json::Value) -> Mock {
+            self.server
+                .mock("GET", "/userinfo")
+                .match_header("authorization", format!("Bearer {ACCESS_TOKEN}").as_str())
+                .with_header("content-type", "application/json")
+                .with_body(user_info.to_string())
+                .create_async()
+                .await
+        }
+    }
+
+    fn issuer(server: &mockito::ServerGuard) -> String {
+        format!("{}/", server.url())
+    }
+
+    fn code() -> Secret {
+        CODE.parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn oidc_auth_url() {
+        let idp = MockIdp::new(true).await;
+        let client = idp.client(OidcClaims::default());
+        let state = Jwt::test_token();
+
+        let auth_url = client.auth_url(state.clone()).await.unwrap();
+        assert_eq!(
+            auth_url.as_str().split('?').next(),
+            Some(format!("{}authorize", issuer(&idp.server)).as_str())
+        );
+        let query = auth_url
+            .query_pairs()
+            .into_owned()
+            .collect::<HashMap<_, _>>();
+        let code_challenge =
+            PkceCodeChallenge::from_code_verifier_sha256(&client.pkce_verifier(&state).unwrap());
+        assert_eq!(query.get("response_type").map(String::as_str), Some("code"));
+        assert_eq!(query.get("client_id").map(String::as_str), Some(CLIENT_ID));
+        assert_eq!(query.get("state").map(String::as_str), Some(state.as_ref()));
+        assert_eq!(
+            query.get("scope").map(String::as_str),
+            Some("openid email profile groups")
+        );
+        assert_eq!(
+            query.get("code_challenge").map(String::as_str),
+            Some(code_challenge.as_str())
+        );
+        assert_eq!(
+            query.get("code_challenge_method").map(String::as_str),
+            Some("S256")
+        );
+        assert_eq!(
+            query.get("nonce").map(String::as_str),
+            Some(client.nonce(&state).unwrap().secret().as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn oidc_user_info() {
+        let mut idp = MockIdp::new(true).await;
+        let client = idp.client(OidcClaims::default());
+        let state = Jwt::test_token();
+
+        let id_token = idp.id_token(
+            &client,
+            &state,
+            StandardClaims::new(SubjectIdentifier::new(SUBJECT.to_owned())),
+            MockClaims::default(),
+        );
+        let token = idp.mock_token(&client, &state, id_token).await;
+        let user_info = idp
+            .mock_user_info(serde_json::json!({
+                "sub": SUBJECT,
+                "name": "Muriel Bagge",
+                "email": "muriel.bagge@nowhere.com",
+                "email_verified": true,
+            }))
+            .await;
+
+        let (name, email) = client.oauth_user(&state, code()).await.unwrap();
+        assert_eq!(name.as_ref(), "Muriel Bagge");
+        assert_eq!(email.as_ref(), "muriel.bagge@nowhere.com");
+        token.assert_async().await;
+        user_info.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn oidc_claim_mapping() {
+        let mut idp = MockIdp::new(false).await;
+        let client = idp.client(OidcClaims {
+            email: "mail".to_owned(),
+            email_verified: "mail_verified".to_owned(),
+            name: "display_name".to_owned(),
+        });
+        let state = Jwt::test_token();
+
+        let id_token = idp.id_token(
+            &client,
+            &state,
+            StandardClaims::new(SubjectIdentifier::new(SUBJECT.to_owned()))
+                .set_email(Some(EndUserEmail::new("courage@nowhere.com".to_owned()))),
+            MockClaims {
+                mail: Some("eustace.bagge@nowhere.com".to_owned()),
+                mail_verified: Some(true),
+                display_name: Some("Eustace Bagge".to_owned()),
+            },
+        );
+        let token = idp.mock_token(&client, &state, id_token).await;
+
+        let (name, email) = client.oauth_user(&state, code()).await.unwrap();
+        assert_eq!(name.as_ref(), "Eustace Bagge");
+        assert_eq!(email.as_ref(), "eustace.bagge@nowhere.com");
+        token.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn oidc_email_not_verified() {
+        let mut idp = MockIdp::new(false).await;
+        let client = idp.client(OidcClaims::default());
+        let state = Jwt::test_token();
+
+        let id_token = idp.id_token(
+            &client,
+            &state,
+            StandardClaims::new(SubjectIdentifier::new(SUBJECT.to_owned()))
+                .set_email(Some(EndUserEmail::new("courage@nowhere.com".to_owned()))),
+            MockClaims::default(),
+        );
+        let _token = idp.mock_token(&client, &state, id_token).await;
+
+        let err = client.oauth_user(&state, code()).await.unwrap_err();
+        assert!(matches!(err, OidcClientError::NotEmailVerified), "{err}");
+    }
+
+    #[tokio::test]
+    async fn oidc_wrong_nonce() {
+        let mut idp = MockIdp::new(false).await;
+        let client = idp.client(OidcClaims::default());
+        let state = Jwt::test_token();
+
+        // An ID token issued for a different login attempt
+        let id_token = idp.id_token(
+            &client,
+            &Jwt::test_admin_token(),
+            StandardClaims::new(SubjectIdentifier::new(SUBJECT.to_owned())),
+            MockClaims::default(),
+        );
+        let _token = idp.mock_token(&client, &state, id_token).await;
+
+        let err = client.oauth_user(&state, code()).await.unwrap_err();
+        assert!(matches!(err, OidcClientError::IdToken(_)), "{err}");
+    }
+}
@@ -264,6 +264,7 @@ impl AuthMethod {
 pub enum OAuthProvider {
     GitHub,
     Google,
+    Oidc,
 }
 
 impl fmt::Display for OAuthProvider {
@@ -271,6 +272,7 @@ impl fmt::Display for OAuthProvider {
         match self {
             Self::GitHub => write!(f, "github"),
             Self::Google => write!(f, "google"),
+            Self::Oidc => write!(f, "oidc"),
         }
     }
 }
@@ -1,7 +1,6 @@
 // Needed for binary
 #[cfg(feature = "plus")]
 use api_checkout as _;
-use bencher_config as _;
 use bencher_json as _;
 use bencher_logger as _;
 #[cfg(feature = "otel")]
@@ -12,12 +11,12 @@ use bencher_otel_provider as _;
 use sentry as _;
 use serde_yaml as _;
 use slog as _;
-use thiserror as _;
-use tokio as _;
 use tokio_rustls as _;
 // Needed for distroless builds
 use libsqlite3_sys as _;
+use pq_sys as _;
 
 pub mod api;
+pub mod local;
 
 pub use api_server::{SPEC, SPEC_STR};
@@ -0,0 +1,60 @@
+use std::{net::SocketAddr, path::PathBuf};
+
+use bencher_config::{Config, ConfigTx, ConfigTxError};
+use bencher_schema::context::ApiContext;
+use dropshot::HttpServer;
+use tokio::sync::mpsc;
+
+use crate::api::Api;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LocalServerError {
+    #[error("Failed to create local database directory ({path}): {error}")]
+    CreateDir { path: String, error: std::io::Error },
+    #[error("Failed to start local API server: {0}")]
+    Start(ConfigTxError),
+    #[error("Failed to stop local API server: {0}")]
+    Stop(String),
+}
+
+/// An in-process API server that stores all of its data in a local `SQLite` database file.
+/// It only listens on the loopback interface, and it never sends server stats.
+pub struct LocalServer {
+    server: HttpServer<ApiContext>,
+}
+
+impl LocalServer {
+    pub async fn start(database: PathBuf) -> Result<Self, LocalServerError> {
+        if let Some(parent) = database
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            tokio::fs::create_dir_all(parent).await.map_err(|error| {
+                LocalServerError::CreateDir {
+                    path: parent.display().to_string(),
+                    error,
+                }
+            })?;
+        }
+
+        // A local server is never restarted, so the receiver is dropped.
+        let (restart_tx, _restart_rx) = mpsc::channel(1);
+        let config_tx = ConfigTx {
+            config: Config::local(database),
+            restart_tx,
+        };
+        let server = config_tx
+            .into_local_server::<Api>()
+            .await
+            .map_err(LocalServerError::Start)?;
+        Ok(Self { server })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.server.local_addr()
+    }
+
+    pub async fn stop(self) -> Result<(), LocalServerError> {
+        self.server.close().await.map_err(LocalServerError::Stop)
+    }
+}
@@ -177,6 +177,8 @@ pub enum LitestreamError {
     Yaml(serde_yaml::Error),
     #[error("Failed to write Litestream config ({0}): {1}")]
     WriteYaml(PathBuf, std::io::Error),
+    #[error("Litestream replication is only supported for SQLite databases")]
+    Postgres,
     #[error("Failed to run `litestream restore`: {0}")]
     Restore(std::io::Error),
     #[error("Failed to run `litestream replicate`: {0}")]
@@ -198,6 +200,9 @@ fn run_litestream(
     litestream: JsonLitestream,
     replicate_tx: sync::oneshot::Sender<()>,
 ) -> Result<JoinHandle<Result<(), LitestreamError>>, LitestreamError> {
+    if config.database.url.is_some() {
+        return Err(LitestreamError::Postgres);
+    }
     // Get the absolute database path from the config
     let db_path = if config.database.file.is_absolute() {
         config.database.file.clone()
@@ -1,4 +1,4 @@
-use std::{fmt, ops::Deref, time::Duration};
+use std::{fmt, net::SocketAddr, ops::Deref, time::Duration};
 
 use bencher_json::{BENCHER_API_URL, BENCHER_URL, JsonApiVersion, JsonConsole, Jwt};
 use serde::{Serialize, de::DeserializeOwned};
@@ -24,6 +24,8 @@ pub struct Backend {
 pub enum BackendError {
     #[error("Failed to parse host URL: {0}")]
     ParseHost(bencher_json::ValidError),
+    #[error("Failed to parse local host URL: {0}")]
+    LocalHost(url::ParseError),
     #[error("Failed to parse API token: {0}")]
     ParseToken(bencher_json::ValidError),
     #[error(
@@ -115,6 +117,16 @@ impl PubBackend {
         self.inner.client.log = log;
         self
     }
+
+    /// Use a local API server as the backend.
+    /// Any API token is for the backend host, so it is not sent to the local API server.
+    pub fn local(mut self, addr: SocketAddr) -> Result<Self, BackendError> {
+        self.inner.client.host = format!("http://{addr}")
+            .parse()
+            .map_err(BackendError::LocalHost)?;
+        self.inner.client.token = None;
+        Ok(self)
+    }
 }
 
 impl Deref for AuthBackend {
@@ -185,6 +197,32 @@ impl Backend {
         })
     }
 
+    pub async fn send_stream<F, R>(
+        &self,
+        sender: F,
+    ) -> Result<progenitor_client::ByteStream, BackendError>
+    where
+        F: Fn(bencher_client::Client) -> R,
+        R: Future<
+            Output = Result<
+                progenitor_client::ResponseValue<progenitor_client::ByteStream>,
+                bencher_client::Error<progenitor_client::ByteStream>,
+            >,
+        >,
+    {
+        let mismatch = self.check_version().await?;
+        self.client.send_stream(sender).await.map_err(|err| {
+            if let Some(mismatch) = mismatch {
+                BackendError::ClientMismatch {
+                    mismatch: Box::new(mismatch),
+                    err,
+                }
+            } else {
+                err.into()
+            }
+        })
+    }
+
     pub async fn check_version(&self) -> Result<Option<VersionMismatch>, BackendError> {
         let json_api_version: JsonApiVersion = self
             .client
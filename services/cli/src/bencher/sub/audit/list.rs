@@ -0,0 +1,199 @@
+use bencher_client::types::{AuditAction, AuditEventsSort, AuditResource, JsonDirection};
+use bencher_json::{DateTime, DateTimeMillis, OrganizationResourceId, ProjectResourceId, UserUuid};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::{
+        CliPagination,
+        audit::{CliAuditAction, CliAuditEventsSort, CliAuditList, CliAuditResource},
+    },
+};
+
+#[derive(Debug)]
+pub struct List {
+    pub scope: Scope,
+    pub user: Option<UserUuid>,
+    pub action: Option<AuditAction>,
+    pub resource: Option<AuditResource>,
+    pub resource_uuid: Option<uuid::Uuid>,
+    pub start_time: Option<DateTime>,
+    pub end_time: Option<DateTime>,
+    pub pagination: Pagination,
+    pub backend: AuthBackend,
+}
+
+#[derive(Debug)]
+pub enum Scope {
+    Server,
+    Organization(OrganizationResourceId),
+    Project(ProjectResourceId),
+}
+
+#[derive(Debug)]
+pub struct Pagination {
+    pub sort: Option<AuditEventsSort>,
+    pub direction: Option<JsonDirection>,
+    pub per_page: Option<u8>,
+    pub page: Option<u32>,
+}
+
+impl TryFrom<CliAuditList> for List {
+    type Error = CliError;
+
+    fn try_from(list: CliAuditList) -> Result<Self, Self::Error> {
+        let CliAuditList {
+            organization,
+            project,
+            user,
+            action,
+            resource,
+            resource_uuid,
+            start_time,
+            end_time,
+            pagination,
+            backend,
+        } = list;
+        let scope = match (organization, project) {
+            (Some(organization), _) => Scope::Organization(organization),
+            (None, Some(project)) => Scope::Project(project),
+            (None, None) => Scope::Server,
+        };
+        Ok(Self {
+            scope,
+            user,
+            action: action.map(Into::into),
+            resource: resource.map(Into::into),
+            resource_uuid,
+            start_time,
+            end_time,
+            pagination: pagination.into(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<CliPagination<CliAuditEventsSort>> for Pagination {
+    fn from(pagination: CliPagination<CliAuditEventsSort>) -> Self {
+        let CliPagination {
+            sort,
+            direction,
+            per_page,
+            page,
+        } = pagination;
+        Self {
+            sort: sort.map(|sort| match sort {
+                CliAuditEventsSort::Created => AuditEventsSort::Created,
+            }),
+            direction: direction.map(Into::into),
+            page,
+            per_page,
+        }
+    }
+}
+
+impl From<CliAuditAction> for AuditAction {
+    fn from(action: CliAuditAction) -> Self {
+        match action {
+            CliAuditAction::Create => Self::Create,
+            CliAuditAction::Update => Self::Update,
+            CliAuditAction::Delete => Self::Delete,
+            CliAuditAction::Invite => Self::Invite,
+            CliAuditAction::Provision => Self::Provision,
+            CliAuditAction::Deprovision => Self::Deprovision,
+            CliAuditAction::Backup => Self::Backup,
+            CliAuditAction::Restart => Self::Restart,
+        }
+    }
+}
+
+impl From<CliAuditResource> for AuditResource {
+    fn from(resource: CliAuditResource) -> Self {
+        match resource {
+            CliAuditResource::Server => Self::Server,
+            CliAuditResource::Organization => Self::Organization,
+            CliAuditResource::Member => Self::Member,
+            CliAuditResource::Sso => Self::Sso,
+            CliAuditResource::Plan => Self::Plan,
+            CliAuditResource::Role => Self::Role,
+            CliAuditResource::Project => Self::Project,
+            CliAuditResource::Branch => Self::Branch,
+            CliAuditResource::Testbed => Self::Testbed,
+            CliAuditResource::Benchmark => Self::Benchmark,
+            CliAuditResource::Measure => Self::Measure,
+            CliAuditResource::Threshold => Self::Threshold,
+            CliAuditResource::Alert => Self::Alert,
+            CliAuditResource::Report => Self::Report,
+            CliAuditResource::Plot => Self::Plot,
+            CliAuditResource::Webhook => Self::Webhook,
+            CliAuditResource::Retention => Self::Retention,
+            CliAuditResource::ProjectMember => Self::ProjectMember,
+            CliAuditResource::User => Self::User,
+            CliAuditResource::Token => Self::Token,
+        }
+    }
+}
+
+// The server, organization, and project audit endpoints all share the same query parameters,
+// but each has its own generated request builder.
+macro_rules! audit_query {
+    ($list:ident, $client:ident) => {{
+        let mut client = $client;
+        if let Some(user) = $list.user {
+            client = client.user(user);
+        }
+        if let Some(action) = $list.action {
+            client = client.action(action);
+        }
+        if let Some(resource) = $list.resource {
+            client = client.resource(resource);
+        }
+        if let Some(resource_uuid) = $list.resource_uuid {
+            client = client.resource_uuid(resource_uuid);
+        }
+        if let Some(start_time) = $list.start_time {
+            client = client.start_time(DateTimeMillis::from(start_time));
+        }
+        if let Some(end_time) = $list.end_time {
+            client = client.end_time(DateTimeMillis::from(end_time));
+        }
+        if let Some(sort) = $list.pagination.sort {
+            client = client.sort(sort);
+        }
+        if let Some(direction) = $list.pagination.direction {
+            client = client.direction(direction);
+        }
+        if let Some(per_page) = $list.pagination.per_page {
+            client = client.per_page(per_page);
+        }
+        if let Some(page) = $list.pagination.page {
+            client = client.page(page);
+        }
+        client.send().await
+    }};
+}
+
+impl SubCmd for List {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                match &self.scope {
+                    Scope::Server => {
+                        let client = client.server_audit_get();
+                        audit_query!(self, client)
+                    },
+                    Scope::Organization(organization) => {
+                        let client = client.org_audit_get().organization(organization.clone());
+                        audit_query!(self, client)
+                    },
+                    Scope::Project(project) => {
+                        let client = client.proj_audit_get().project(project.clone());
+                        audit_query!(self, client)
+                    },
+                }
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{CliError, bencher::sub::SubCmd, parser::audit::CliAudit};
+
+mod list;
+
+#[derive(Debug)]
+pub enum Audit {
+    List(list::List),
+}
+
+impl TryFrom<CliAudit> for Audit {
+    type Error = CliError;
+
+    fn try_from(audit: CliAudit) -> Result<Self, Self::Error> {
+        Ok(match audit {
+            CliAudit::List(list) => Self::List(list.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Audit {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+        }
+    }
+}
@@ -116,6 +116,7 @@ impl Mock {
                     .map_err(MockError::ParseBenchmarkName)?,
                 AdapterMetrics {
                     inner: measures_map,
+                    tags: None,
                 },
             );
         }
@@ -1,5 +1,6 @@
 use crate::{CliError, parser::CliSub};
 
+mod audit;
 mod compose;
 mod mock;
 mod organization;
@@ -7,37 +8,49 @@ mod project;
 mod run;
 mod sub_cmd;
 mod system;
+mod upload;
 mod user;
 
+use audit::Audit;
 pub use compose::DockerError;
 use compose::{down::Down, logs::Logs, up::Up};
 use mock::Mock;
 pub use mock::MockError;
-use organization::{member::Member, organization::Organization};
+use organization::{member::Member, organization::Organization, role::Role};
 use project::{
     alert::Alert,
     archive::{Archive, ArchiveAction},
     benchmark::Benchmark,
     branch::Branch,
+    compare::Compare,
     measure::Measure,
+    member::ProjectMember,
     metric::Metric,
     perf::Perf,
     plot::Plot,
     project::Project,
     report::Report,
+    retention::Retention,
     testbed::Testbed,
     threshold::Threshold,
+    webhook::Webhook,
+};
+pub use project::{
+    archive::ArchiveError, compare::CompareError, perf::PerfError, project::ProjectError,
+    report::ThresholdsError, threshold::ThresholdError,
 };
-pub use project::{archive::ArchiveError, report::ThresholdsError, threshold::ThresholdError};
 use run::Run;
 pub use run::{RunError, runner::output::Output};
 pub use sub_cmd::SubCmd;
 use system::{auth::Auth, server::Server};
+use upload::Upload;
+pub use upload::UploadError;
 use user::{token::Token, user::User};
 
 #[derive(Debug)]
 pub enum Sub {
     Run(Box<Run>),
+    Upload(Upload),
     Mock(Mock),
     Archive(Archive),
     Up(Up),
@@ -45,13 +58,16 @@ pub enum Sub {
     Down(Down),
     Organization(Organization),
     Member(Member),
+    Role(Role),
     #[cfg(feature = "plus")]
     Plan(organization::plan::Plan),
     #[cfg(feature = "plus")]
     Sso(organization::sso::Sso),
     Project(Project),
+    ProjectMember(ProjectMember),
     Report(Report),
     Perf(Perf),
+    Compare(Compare),
     Plot(Plot),
     Branch(Branch),
     Testbed(Testbed),
@@ -60,6 +76,9 @@ pub enum Sub {
     Metric(Metric),
     Threshold(Threshold),
     Alert(Alert),
+    Webhook(Webhook),
+    Retention(Retention),
+    Audit(Audit),
     User(User),
     Token(Token),
     Server(Server),
@@ -72,6 +91,7 @@ impl TryFrom<CliSub> for Sub {
     fn try_from(sub: CliSub) -> Result<Self, Self::Error> {
         Ok(match sub {
             CliSub::Run(run) => Self::Run(Box::new((*run).try_into()?)),
+            CliSub::Upload(upload) => Self::Upload(upload.try_into()?),
             CliSub::Mock(mock) => Self::Mock(mock.into()),
             CliSub::Archive(archive) => {
                 Self::Archive((archive, ArchiveAction::Archive).try_into()?)
@@ -84,13 +104,16 @@ impl TryFrom<CliSub> for Sub {
             CliSub::Down(down) => Self::Down(down.into()),
             CliSub::Organization(organization) => Self::Organization(organization.try_into()?),
             CliSub::Member(member) => Self::Member(member.try_into()?),
+            CliSub::Role(role) => Self::Role(role.try_into()?),
             #[cfg(feature = "plus")]
             CliSub::Plan(plan) => Self::Plan(plan.try_into()?),
             #[cfg(feature = "plus")]
             CliSub::Sso(sso) => Self::Sso(sso.try_into()?),
             CliSub::Project(project) => Self::Project(project.try_into()?),
+            CliSub::ProjectMember(member) => Self::ProjectMember(member.try_into()?),
             CliSub::Report(report) => Self::Report(report.try_into()?),
             CliSub::Perf(perf) => Self::Perf(perf.try_into()?),
+            CliSub::Compare(compare) => Self::Compare(compare.try_into()?),
             CliSub::Plot(plot) => Self::Plot(plot.try_into()?),
             CliSub::Branch(branch) => Self::Branch(branch.try_into()?),
             CliSub::Testbed(testbed) => Self::Testbed(testbed.try_into()?),
@@ -99,6 +122,9 @@ impl TryFrom<CliSub> for Sub {
             CliSub::Metric(metric) => Self::Metric(metric.try_into()?),
             CliSub::Threshold(threshold) => Self::Threshold(threshold.try_into()?),
             CliSub::Alert(alert) => Self::Alert(alert.try_into()?),
+            CliSub::Webhook(webhook) => Self::Webhook(webhook.try_into()?),
+            CliSub::Retention(retention) => Self::Retention(retention.try_into()?),
+            CliSub::Audit(audit) => Self::Audit(audit.try_into()?),
             CliSub::User(user) => Self::User(user.try_into()?),
             CliSub::Token(token) => Self::Token(token.try_into()?),
             CliSub::Server(server) => Self::Server(server.try_into()?),
@@ -112,6 +138,7 @@ impl SubCmd for Sub {
     async fn exec(&self) -> Result<(), CliError> {
         match self {
             Self::Run(run) => run.exec().await,
+            Self::Upload(upload) => upload.exec().await,
             Self::Mock(mock) => mock.exec().await,
             Self::Archive(archive) => archive.exec().await,
             Self::Up(up) => up.exec().await,
@@ -119,13 +146,16 @@ impl SubCmd for Sub {
             Self::Down(down) => down.exec().await,
             Self::Organization(organization) => organization.exec().await,
             Self::Member(member) => member.exec().await,
+            Self::Role(role) => role.exec().await,
             #[cfg(feature = "plus")]
             Self::Plan(plan) => plan.exec().await,
             #[cfg(feature = "plus")]
             Self::Sso(sso) => sso.exec().await,
             Self::Project(project) => project.exec().await,
+            Self::ProjectMember(member) => member.exec().await,
             Self::Report(report) => report.exec().await,
             Self::Perf(perf) => perf.exec().await,
+            Self::Compare(compare) => compare.exec().await,
             Self::Plot(plot) => plot.exec().await,
             Self::Branch(branch) => branch.exec().await,
             Self::Testbed(testbed) => testbed.exec().await,
@@ -134,6 +164,9 @@ impl SubCmd for Sub {
             Self::Metric(metric) => metric.exec().await,
             Self::Threshold(threshold) => threshold.exec().await,
             Self::Alert(alert) => alert.exec().await,
+            Self::Webhook(webhook) => webhook.exec().await,
+            Self::Retention(retention) => retention.exec().await,
+            Self::Audit(audit) => audit.exec().await,
             Self::User(user) => user.exec().await,
             Self::Token(token) => token.exec().await,
             Self::Server(server) => server.exec().await,
@@ -2,4 +2,5 @@ pub mod member;
 #[expect(clippy::module_inception)]
 pub mod organization;
 pub mod plan;
+pub mod role;
 pub mod sso;
@@ -0,0 +1,64 @@
+use bencher_client::types::{JsonNewRole, ProjectPermission};
+use bencher_json::{OrganizationResourceId, ResourceName};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::organization::role::CliRoleCreate,
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub organization: OrganizationResourceId,
+    pub name: ResourceName,
+    pub permissions: Vec<ProjectPermission>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRoleCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliRoleCreate) -> Result<Self, Self::Error> {
+        let CliRoleCreate {
+            organization,
+            name,
+            permissions,
+            backend,
+        } = create;
+        Ok(Self {
+            organization,
+            name,
+            permissions: permissions.into_iter().map(Into::into).collect(),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Create> for JsonNewRole {
+    fn from(create: Create) -> Self {
+        let Create {
+            name, permissions, ..
+        } = create;
+        Self {
+            name: name.into(),
+            permissions,
+        }
+    }
+}
+
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .org_role_post()
+                    .organization(self.organization.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
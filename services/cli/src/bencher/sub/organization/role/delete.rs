@@ -0,0 +1,48 @@
+use bencher_json::{OrganizationResourceId, RoleUuid};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::organization::role::CliRoleDelete,
+};
+
+#[derive(Debug)]
+pub struct Delete {
+    pub organization: OrganizationResourceId,
+    pub role: RoleUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRoleDelete> for Delete {
+    type Error = CliError;
+
+    fn try_from(delete: CliRoleDelete) -> Result<Self, Self::Error> {
+        let CliRoleDelete {
+            organization,
+            role,
+            backend,
+        } = delete;
+        Ok(Self {
+            organization,
+            role,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Delete {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .org_role_delete()
+                    .organization(self.organization.clone())
+                    .role(self.role)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
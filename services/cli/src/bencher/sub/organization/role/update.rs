@@ -0,0 +1,69 @@
+use bencher_client::types::{JsonUpdateRole, ProjectPermission};
+use bencher_json::{OrganizationResourceId, ResourceName, RoleUuid};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::organization::role::CliRoleUpdate,
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub organization: OrganizationResourceId,
+    pub role: RoleUuid,
+    pub name: Option<ResourceName>,
+    pub permissions: Option<Vec<ProjectPermission>>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRoleUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliRoleUpdate) -> Result<Self, Self::Error> {
+        let CliRoleUpdate {
+            organization,
+            role,
+            name,
+            permissions,
+            backend,
+        } = update;
+        Ok(Self {
+            organization,
+            role,
+            name,
+            permissions: permissions
+                .map(|permissions| permissions.into_iter().map(Into::into).collect()),
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Update> for JsonUpdateRole {
+    fn from(update: Update) -> Self {
+        let Update {
+            name, permissions, ..
+        } = update;
+        Self {
+            name: name.map(Into::into),
+            permissions,
+        }
+    }
+}
+
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .org_role_patch()
+                    .organization(self.organization.clone())
+                    .role(self.role)
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use bencher_json::{OrganizationResourceId, RoleUuid};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::organization::role::CliRoleView,
+};
+
+#[derive(Debug)]
+pub struct View {
+    pub organization: OrganizationResourceId,
+    pub role: RoleUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRoleView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliRoleView) -> Result<Self, Self::Error> {
+        let CliRoleView {
+            organization,
+            role,
+            backend,
+        } = view;
+        Ok(Self {
+            organization,
+            role,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .org_role_get()
+                    .organization(self.organization.clone())
+                    .role(self.role)
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
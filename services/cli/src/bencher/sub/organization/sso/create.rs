@@ -11,6 +11,7 @@ use crate::{
 pub struct Create {
     pub organization: OrganizationResourceId,
     pub domain: NonEmpty,
+    pub oidc: bool,
     pub backend: AuthBackend,
 }
 
@@ -21,11 +22,13 @@ impl TryFrom<CliSsoCreate> for Create {
         let CliSsoCreate {
             organization,
             domain,
+            oidc,
             backend,
         } = create;
         Ok(Self {
             organization,
             domain,
+            oidc,
             backend: backend.try_into()?,
         })
     }
@@ -33,9 +36,10 @@ impl TryFrom<CliSsoCreate> for Create {
 
 impl From<Create> for JsonNewSso {
     fn from(create: Create) -> Self {
-        let Create { domain, .. } = create;
+        let Create { domain, oidc, .. } = create;
         Self {
             domain: domain.into(),
+            oidc: Some(oidc),
         }
     }
 }
@@ -214,6 +214,7 @@ impl Dimension {
         let update = &JsonUpdateBenchmark {
             name: None,
             slug: None,
+            tags: None,
             archived: Some(action.into()),
         };
         backend
@@ -1,5 +1,5 @@
 use bencher_client::types::JsonNewBenchmark;
-use bencher_json::{BenchmarkName, BenchmarkSlug, ProjectResourceId};
+use bencher_json::{BenchmarkName, BenchmarkSlug, ProjectResourceId, TagSelector};
 
 use crate::{
     CliError,
@@ -7,11 +7,14 @@ use crate::{
     parser::project::benchmark::CliBenchmarkCreate,
 };
 
+use super::map_tags;
+
 #[derive(Debug, Clone)]
 pub struct Create {
     pub project: ProjectResourceId,
     pub name: BenchmarkName,
     pub slug: Option<BenchmarkSlug>,
+    pub tag: Vec<TagSelector>,
     pub backend: AuthBackend,
 }
 
@@ -23,12 +26,14 @@ impl TryFrom<CliBenchmarkCreate> for Create {
             project,
             name,
             slug,
+            tag,
             backend,
         } = create;
         Ok(Self {
             project,
             name,
             slug,
+            tag,
             backend: backend.try_into()?,
         })
     }
@@ -36,10 +41,13 @@ impl TryFrom<CliBenchmarkCreate> for Create {
 
 impl From<Create> for JsonNewBenchmark {
     fn from(create: Create) -> Self {
-        let Create { name, slug, .. } = create;
+        let Create {
+            name, slug, tag, ..
+        } = create;
         Self {
             name: name.into(),
             slug: slug.map(Into::into),
+            tags: (!tag.is_empty()).then(|| map_tags(tag)),
         }
     }
 }
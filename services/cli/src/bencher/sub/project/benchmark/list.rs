@@ -1,5 +1,5 @@
 use bencher_client::types::{JsonDirection, ProjBenchmarksSort};
-use bencher_json::{BenchmarkName, ProjectResourceId};
+use bencher_json::{BenchmarkName, ProjectResourceId, TagSelectors};
 
 use crate::{
     CliError,
@@ -15,6 +15,7 @@ pub struct List {
     pub project: ProjectResourceId,
     pub name: Option<BenchmarkName>,
     pub search: Option<String>,
+    pub tags: Option<TagSelectors>,
     pub pagination: Pagination,
     pub archived: bool,
     pub backend: PubBackend,
@@ -36,6 +37,7 @@ impl TryFrom<CliBenchmarkList> for List {
             project,
             name,
             search,
+            tags,
             pagination,
             archived,
             backend,
@@ -44,6 +46,7 @@ impl TryFrom<CliBenchmarkList> for List {
             project,
             name,
             search,
+            tags,
             pagination: pagination.into(),
             archived,
             backend: backend.try_into()?,
@@ -82,6 +85,9 @@ impl SubCmd for List {
                 if let Some(search) = self.search.clone() {
                     client = client.search(search);
                 }
+                if let Some(tags) = self.tags.clone() {
+                    client = client.tags(tags);
+                }
                 if let Some(sort) = self.pagination.sort {
                     client = client.sort(sort);
                 }
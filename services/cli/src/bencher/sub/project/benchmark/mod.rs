@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+
+use bencher_json::TagSelector;
+
 use crate::{CliError, bencher::sub::SubCmd, parser::project::benchmark::CliBenchmark};
 
 mod create;
@@ -40,3 +44,10 @@ impl SubCmd for Benchmark {
         }
     }
 }
+
+// The last value for a repeated tag key wins.
+fn map_tags(tags: Vec<TagSelector>) -> HashMap<String, bencher_client::types::TagValue> {
+    tags.into_iter()
+        .map(|TagSelector { key, value }| (key.into(), value.into()))
+        .collect()
+}
@@ -1,5 +1,7 @@
 use bencher_client::types::JsonUpdateBenchmark;
-use bencher_json::{BenchmarkName, BenchmarkResourceId, BenchmarkSlug, ProjectResourceId};
+use bencher_json::{
+    BenchmarkName, BenchmarkResourceId, BenchmarkSlug, ProjectResourceId, TagSelector,
+};
 
 use crate::{
     CliError,
@@ -7,12 +9,15 @@ use crate::{
     parser::project::benchmark::CliBenchmarkUpdate,
 };
 
+use super::map_tags;
+
 #[derive(Debug, Clone)]
 pub struct Update {
     pub project: ProjectResourceId,
     pub benchmark: BenchmarkResourceId,
     pub name: Option<BenchmarkName>,
     pub slug: Option<BenchmarkSlug>,
+    pub tag: Vec<TagSelector>,
     pub archived: Option<bool>,
     pub backend: AuthBackend,
 }
@@ -26,6 +31,7 @@ impl TryFrom<CliBenchmarkUpdate> for Update {
             benchmark,
             name,
             slug,
+            tag,
             archived,
             backend,
         } = create;
@@ -34,6 +40,7 @@ impl TryFrom<CliBenchmarkUpdate> for Update {
             benchmark,
             name,
             slug,
+            tag,
             archived: archived.into(),
             backend: backend.try_into()?,
         })
@@ -45,12 +52,14 @@ impl From<Update> for JsonUpdateBenchmark {
         let Update {
             name,
             slug,
+            tag,
             archived,
             ..
         } = update;
         Self {
             name: name.map(Into::into),
             slug: slug.map(Into::into),
+            tags: (!tag.is_empty()).then(|| map_tags(tag)),
             archived,
         }
     }
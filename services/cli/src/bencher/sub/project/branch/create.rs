@@ -63,6 +63,7 @@ impl From<Create> for JsonNewBranch {
         let start_point = start_point_branch.map(|branch| JsonNewStartPoint {
             branch: branch.into(),
             hash: start_point_hash.map(Into::into),
+            ancestor_hashes: None,
             max_versions: Some(start_point_max_versions),
             clone_thresholds: Some(start_point_clone_thresholds),
         });
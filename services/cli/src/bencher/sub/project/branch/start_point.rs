@@ -7,6 +7,7 @@ use crate::parser::project::branch::CliStartPointUpdate;
 pub struct StartPoint {
     pub branch: Option<BranchNameId>,
     pub hash: Option<GitHash>,
+    pub ancestor_hashes: Vec<GitHash>,
     pub max_versions: u32,
     pub clone_thresholds: bool,
     pub reset: bool,
@@ -24,6 +25,7 @@ impl From<CliStartPointUpdate> for StartPoint {
         Self {
             branch: start_point_branch,
             hash: start_point_hash,
+            ancestor_hashes: Vec::new(),
             max_versions: start_point_max_versions,
             clone_thresholds: start_point_clone_thresholds,
             reset: start_point_reset,
@@ -36,6 +38,7 @@ impl From<StartPoint> for Option<JsonUpdateStartPoint> {
         let StartPoint {
             branch,
             hash,
+            ancestor_hashes,
             max_versions,
             clone_thresholds,
             reset,
@@ -43,6 +46,8 @@ impl From<StartPoint> for Option<JsonUpdateStartPoint> {
         (branch.is_some() || reset).then(|| JsonUpdateStartPoint {
             branch: branch.map(Into::into),
             hash: hash.map(Into::into),
+            ancestor_hashes: (!ancestor_hashes.is_empty())
+                .then(|| ancestor_hashes.into_iter().map(Into::into).collect()),
             max_versions: Some(max_versions),
             clone_thresholds: Some(clone_thresholds),
             reset: Some(reset),
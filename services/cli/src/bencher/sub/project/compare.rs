@@ -0,0 +1,177 @@
+use bencher_comment::CompareComment;
+use bencher_json::{
+    Boundary, BranchNameId, GitHash, HeadUuid, JsonCompare, JsonCompareQuery, ProjectResourceId,
+    ReportUuid, TestbedNameId,
+    project::compare::{CompareQueryError, JsonCompareQueryParams},
+};
+
+use crate::{
+    CliError,
+    bencher::{backend::PubBackend, sub::SubCmd, sub::run::format::Format},
+    cli_println,
+    parser::project::{
+        compare::{CliCompare, CliCompareModel},
+        threshold::CliModelTest,
+    },
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum CompareError {
+    #[error("Invalid comparison: {0}")]
+    BadQuery(CompareQueryError),
+    #[error("Failed to serialize comparison: {0}")]
+    SerializeCompare(serde_json::Error),
+}
+
+#[derive(Debug)]
+pub struct Compare {
+    project: ProjectResourceId,
+    baseline: CompareSide,
+    candidate: CompareSide,
+    testbed: Option<TestbedNameId>,
+    test: Option<CliModelTest>,
+    lower_boundary: Option<Boundary>,
+    upper_boundary: Option<Boundary>,
+    format: Format,
+    backend: PubBackend,
+}
+
+#[derive(Debug)]
+struct CompareSide {
+    report: Option<ReportUuid>,
+    head: Option<HeadUuid>,
+    branch: Option<BranchNameId>,
+    hash: Option<GitHash>,
+}
+
+impl TryFrom<CliCompare> for Compare {
+    type Error = CliError;
+
+    fn try_from(compare: CliCompare) -> Result<Self, Self::Error> {
+        let CliCompare {
+            project,
+            baseline_report,
+            baseline_head,
+            baseline_branch,
+            baseline_hash,
+            candidate_report,
+            candidate_head,
+            candidate_branch,
+            candidate_hash,
+            testbed,
+            model,
+            format,
+            backend,
+        } = compare;
+        let CliCompareModel {
+            test,
+            lower_boundary,
+            upper_boundary,
+        } = model;
+        let baseline = CompareSide {
+            report: baseline_report,
+            head: baseline_head,
+            branch: baseline_branch,
+            hash: baseline_hash,
+        };
+        let candidate = CompareSide {
+            report: candidate_report,
+            head: candidate_head,
+            branch: candidate_branch,
+            hash: candidate_hash,
+        };
+
+        // Validate the query before sending it to the server
+        JsonCompareQuery::try_from(JsonCompareQueryParams {
+            baseline_report: baseline.report,
+            baseline_head: baseline.head,
+            baseline_branch: baseline.branch.clone(),
+            baseline_hash: baseline.hash.clone(),
+            candidate_report: candidate.report,
+            candidate_head: candidate.head,
+            candidate_branch: candidate.branch.clone(),
+            candidate_hash: candidate.hash.clone(),
+            testbed: testbed.clone(),
+            test: test.map(Into::into),
+            lower_boundary,
+            upper_boundary,
+        })
+        .map_err(CompareError::BadQuery)?;
+
+        Ok(Self {
+            project,
+            baseline,
+            candidate,
+            testbed,
+            test,
+            lower_boundary,
+            upper_boundary,
+            format: format.into(),
+            backend: PubBackend::try_from(backend)?.log(false),
+        })
+    }
+}
+
+impl SubCmd for Compare {
+    async fn exec(&self) -> Result<(), CliError> {
+        let json_compare: JsonCompare = self
+            .backend
+            .send_with(|client| async move {
+                let mut client = client.proj_compare_get().project(self.project.clone());
+
+                if let Some(report) = self.baseline.report {
+                    client = client.baseline_report(report);
+                }
+                if let Some(head) = self.baseline.head {
+                    client = client.baseline_head(head);
+                }
+                if let Some(branch) = self.baseline.branch.clone() {
+                    client = client.baseline_branch(branch);
+                }
+                if let Some(hash) = self.baseline.hash.clone() {
+                    client = client.baseline_hash(hash);
+                }
+
+                if let Some(report) = self.candidate.report {
+                    client = client.candidate_report(report);
+                }
+                if let Some(head) = self.candidate.head {
+                    client = client.candidate_head(head);
+                }
+                if let Some(branch) = self.candidate.branch.clone() {
+                    client = client.candidate_branch(branch);
+                }
+                if let Some(hash) = self.candidate.hash.clone() {
+                    client = client.candidate_hash(hash);
+                }
+
+                if let Some(testbed) = self.testbed.clone() {
+                    client = client.testbed(testbed);
+                }
+                if let Some(test) = self.test {
+                    client = client.test(bencher_client::types::ModelTest::from(test));
+                }
+                if let Some(lower_boundary) = self.lower_boundary {
+                    client = client.lower_boundary(lower_boundary);
+                }
+                if let Some(upper_boundary) = self.upper_boundary {
+                    client = client.upper_boundary(upper_boundary);
+                }
+
+                client.send().await
+            })
+            .await?;
+
+        let compare_comment = CompareComment::new(json_compare);
+        let compare_str = match self.format {
+            Format::Human => compare_comment.human(),
+            Format::Json => compare_comment
+                .json()
+                .map_err(CompareError::SerializeCompare)?,
+            Format::Html => compare_comment.html(),
+        };
+        cli_println!("{compare_str}");
+
+        Ok(())
+    }
+}
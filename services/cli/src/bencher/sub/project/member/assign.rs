@@ -0,0 +1,61 @@
+use bencher_client::types::JsonUpdateProjectMember;
+use bencher_json::{ProjectResourceId, RoleUuid, UserResourceId};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::member::CliProjectMemberAssign,
+};
+
+#[derive(Debug, Clone)]
+pub struct Assign {
+    pub project: ProjectResourceId,
+    pub user: UserResourceId,
+    pub role: RoleUuid,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectMemberAssign> for Assign {
+    type Error = CliError;
+
+    fn try_from(assign: CliProjectMemberAssign) -> Result<Self, Self::Error> {
+        let CliProjectMemberAssign {
+            project,
+            user,
+            role,
+            backend,
+        } = assign;
+        Ok(Self {
+            project,
+            user,
+            role,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Assign> for JsonUpdateProjectMember {
+    fn from(assign: Assign) -> Self {
+        Self {
+            role: assign.role.into(),
+        }
+    }
+}
+
+impl SubCmd for Assign {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_member_put()
+                    .project(self.project.clone())
+                    .user(self.user.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{CliError, bencher::sub::SubCmd, parser::project::member::CliProjectMember};
+
+mod assign;
+mod list;
+mod remove;
+mod view;
+
+#[derive(Debug)]
+pub enum ProjectMember {
+    List(list::List),
+    Assign(assign::Assign),
+    View(view::View),
+    Remove(remove::Remove),
+}
+
+impl TryFrom<CliProjectMember> for ProjectMember {
+    type Error = CliError;
+
+    fn try_from(member: CliProjectMember) -> Result<Self, Self::Error> {
+        Ok(match member {
+            CliProjectMember::List(list) => Self::List(list.try_into()?),
+            CliProjectMember::Assign(assign) => Self::Assign(assign.try_into()?),
+            CliProjectMember::View(view) => Self::View(view.try_into()?),
+            CliProjectMember::Remove(remove) => Self::Remove(remove.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for ProjectMember {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Assign(assign) => assign.exec().await,
+            Self::View(view) => view.exec().await,
+            Self::Remove(remove) => remove.exec().await,
+        }
+    }
+}
@@ -2,12 +2,16 @@ pub mod alert;
 pub mod archive;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
 pub mod measure;
+pub mod member;
 pub mod metric;
 pub mod perf;
 pub mod plot;
 #[expect(clippy::module_inception)]
 pub mod project;
 pub mod report;
+pub mod retention;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
@@ -0,0 +1,36 @@
+use bencher_json::project::perf::{PerfAggregate, PerfBucket, PerfExportFormat};
+
+use crate::parser::project::perf::{CliPerfAggregate, CliPerfBucket, CliPerfFormat};
+
+impl From<CliPerfBucket> for PerfBucket {
+    fn from(bucket: CliPerfBucket) -> Self {
+        match bucket {
+            CliPerfBucket::Hour => Self::Hour,
+            CliPerfBucket::Day => Self::Day,
+            CliPerfBucket::Week => Self::Week,
+            CliPerfBucket::Version => Self::Version,
+        }
+    }
+}
+
+impl From<CliPerfAggregate> for PerfAggregate {
+    fn from(aggregate: CliPerfAggregate) -> Self {
+        match aggregate {
+            CliPerfAggregate::Mean => Self::Mean,
+            CliPerfAggregate::Median => Self::Median,
+            CliPerfAggregate::Min => Self::Min,
+            CliPerfAggregate::Max => Self::Max,
+            CliPerfAggregate::P90 => Self::P90,
+        }
+    }
+}
+
+impl From<CliPerfFormat> for Option<PerfExportFormat> {
+    fn from(format: CliPerfFormat) -> Self {
+        match format {
+            CliPerfFormat::Json => None,
+            CliPerfFormat::Csv => Some(PerfExportFormat::Csv),
+            CliPerfFormat::Parquet => Some(PerfExportFormat::Parquet),
+        }
+    }
+}
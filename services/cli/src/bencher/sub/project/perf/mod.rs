@@ -1,10 +1,13 @@
 use std::future::Future;
+use std::io::Write as _;
 use std::pin::Pin;
 
 use bencher_json::{
     BenchmarkUuid, BranchUuid, DateTime, HeadUuid, JsonPerf, JsonPerfQuery, MeasureUuid,
-    ProjectResourceId, TestbedUuid,
+    ProjectResourceId, TagSelectors, TestbedUuid,
+    project::perf::{PerfAggregate, PerfBucket, PerfExportFormat},
 };
+use futures_util::StreamExt as _;
 use tabled::Table;
 
 use crate::parser::ElidedOption;
@@ -12,10 +15,19 @@ use crate::{CliError, bencher::backend::PubBackend, cli_println, parser::project
 
 use crate::bencher::SubCmd;
 
+mod bucket;
 mod table_style;
 
 use table_style::TableStyle;
 
+#[derive(thiserror::Error, Debug)]
+pub enum PerfError {
+    #[error("Failed to read perf export: {0}")]
+    Read(reqwest::Error),
+    #[error("Failed to write perf export: {0}")]
+    Write(std::io::Error),
+}
+
 #[derive(Debug, Clone)]
 #[expect(clippy::option_option)]
 pub struct Perf {
@@ -24,10 +36,14 @@ pub struct Perf {
     heads: Vec<Option<HeadUuid>>,
     testbeds: Vec<TestbedUuid>,
     benchmarks: Vec<BenchmarkUuid>,
+    tags: Option<TagSelectors>,
     measures: Vec<MeasureUuid>,
     start_time: Option<DateTime>,
     end_time: Option<DateTime>,
+    bucket: Option<PerfBucket>,
+    aggregate: Option<PerfAggregate>,
     table: Option<Option<TableStyle>>,
+    format: Option<PerfExportFormat>,
     backend: PubBackend,
 }
 
@@ -41,23 +57,41 @@ impl TryFrom<CliPerf> for Perf {
             heads,
             testbeds,
             benchmarks,
+            tags,
             measures,
             start_time,
             end_time,
+            bucket,
+            no_bucket,
+            aggregate,
             table,
+            format,
             backend,
         } = perf;
-        let backend = PubBackend::try_from(backend)?.log(table.is_none());
+        // Downsample long time windows, unless explicitly disabled.
+        let bucket = if no_bucket {
+            None
+        } else {
+            bucket
+                .map(Into::into)
+                .or_else(|| PerfBucket::auto(start_time, end_time))
+        };
+        let format = format.and_then(Into::into);
+        let backend = PubBackend::try_from(backend)?.log(table.is_none() && format.is_none());
         Ok(Self {
             project,
             branches,
             heads: heads.into_iter().map(ElidedOption::into).collect(),
             testbeds,
             benchmarks,
+            tags,
             measures,
             start_time,
             end_time,
+            bucket,
+            aggregate: aggregate.map(Into::into),
             table: table.map(|t| t.map(Into::into)),
+            format,
             backend,
         })
     }
@@ -70,9 +104,12 @@ impl From<Perf> for JsonPerfQuery {
             heads,
             testbeds,
             benchmarks,
+            tags,
             measures,
             start_time,
             end_time,
+            bucket,
+            aggregate,
             ..
         } = perf;
         Self {
@@ -80,15 +117,21 @@ impl From<Perf> for JsonPerfQuery {
             heads,
             testbeds,
             benchmarks,
+            tags,
             measures,
             start_time,
             end_time,
+            bucket,
+            aggregate,
         }
     }
 }
 
 impl SubCmd for Perf {
     async fn exec(&self) -> Result<(), CliError> {
+        if let Some(format) = self.format {
+            return self.export(format).await;
+        }
         let sender = perf_sender(self.project.clone(), self.clone());
         if let Some(table_style) = self.table {
             let json_perf: JsonPerf = self.backend.send_with(sender).await?;
@@ -104,6 +147,22 @@ impl SubCmd for Perf {
     }
 }
 
+impl Perf {
+    async fn export(&self, format: PerfExportFormat) -> Result<(), CliError> {
+        let sender = export_sender(self.project.clone(), self.clone(), format);
+        let mut byte_stream = self.backend.send_stream(sender).await?;
+        // Write each chunk as it arrives, so the export is never buffered in memory.
+        while let Some(chunk) = byte_stream.next().await {
+            let chunk = chunk.map_err(PerfError::Read)?;
+            std::io::stdout()
+                .write_all(&chunk)
+                .map_err(PerfError::Write)?;
+        }
+        std::io::stdout().flush().map_err(PerfError::Write)?;
+        Ok(())
+    }
+}
+
 type PerfQueryResult = Pin<
     Box<
         dyn Future<
@@ -135,6 +194,10 @@ fn perf_sender(
                 client = client.heads(heads);
             }
 
+            if let Some(tags) = json_perf_query.tags() {
+                client = client.tags(tags);
+            }
+
             if let Some(start_time) = json_perf_query.start_time() {
                 client = client.start_time(start_time);
             }
@@ -142,6 +205,69 @@ fn perf_sender(
                 client = client.end_time(end_time);
             }
 
+            if let Some(bucket) = json_perf_query.bucket {
+                client = client.bucket(bucket);
+            }
+            if let Some(aggregate) = json_perf_query.aggregate {
+                client = client.aggregate(aggregate);
+            }
+
+            client.send().await
+        })
+    })
+}
+
+type PerfExportResult = Pin<
+    Box<
+        dyn Future<
+                Output = Result<
+                    progenitor_client::ResponseValue<progenitor_client::ByteStream>,
+                    bencher_client::Error<progenitor_client::ByteStream>,
+                >,
+            > + Send,
+    >,
+>;
+fn export_sender(
+    project: ProjectResourceId,
+    json_perf_query: impl Into<JsonPerfQuery>,
+    format: PerfExportFormat,
+) -> Box<dyn Fn(bencher_client::Client) -> PerfExportResult + Send> {
+    let json_perf_query: JsonPerfQuery = json_perf_query.into();
+    Box::new(move |client: bencher_client::Client| {
+        let project = project.clone();
+        let json_perf_query = json_perf_query.clone();
+        Box::pin(async move {
+            let mut client = client
+                .proj_perf_export_get()
+                .project(project.clone())
+                .branches(json_perf_query.branches())
+                .testbeds(json_perf_query.testbeds())
+                .benchmarks(json_perf_query.benchmarks())
+                .measures(json_perf_query.measures())
+                .format(format);
+
+            if let Some(heads) = json_perf_query.heads() {
+                client = client.heads(heads);
+            }
+
+            if let Some(tags) = json_perf_query.tags() {
+                client = client.tags(tags);
+            }
+
+            if let Some(start_time) = json_perf_query.start_time() {
+                client = client.start_time(start_time);
+            }
+            if let Some(end_time) = json_perf_query.end_time() {
+                client = client.end_time(end_time);
+            }
+
+            if let Some(bucket) = json_perf_query.bucket {
+                client = client.bucket(bucket);
+            }
+            if let Some(aggregate) = json_perf_query.aggregate {
+                client = client.aggregate(aggregate);
+            }
+
             client.send().await
         })
     })
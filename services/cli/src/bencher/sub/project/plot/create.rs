@@ -1,7 +1,7 @@
 use bencher_client::types::JsonNewPlot;
 use bencher_json::{
-    BenchmarkUuid, BranchUuid, Index, MeasureUuid, ProjectResourceId, ResourceName, TestbedUuid,
-    Window, project::plot::XAxis,
+    BenchmarkUuid, BranchUuid, Index, MeasureUuid, ProjectResourceId, ResourceName, TagSelectors,
+    TestbedUuid, Window, project::plot::XAxis,
 };
 
 use crate::{
@@ -25,6 +25,7 @@ pub struct Create {
     pub branches: Vec<BranchUuid>,
     pub testbeds: Vec<TestbedUuid>,
     pub benchmarks: Vec<BenchmarkUuid>,
+    pub benchmark_tags: Option<TagSelectors>,
     pub measures: Vec<MeasureUuid>,
     pub backend: AuthBackend,
 }
@@ -46,6 +47,7 @@ impl TryFrom<CliPlotCreate> for Create {
             branches,
             testbeds,
             benchmarks,
+            benchmark_tags,
             measures,
             backend,
         } = create;
@@ -65,6 +67,7 @@ impl TryFrom<CliPlotCreate> for Create {
             branches,
             testbeds,
             benchmarks,
+            benchmark_tags,
             measures,
             backend: backend.try_into()?,
         })
@@ -85,6 +88,7 @@ impl From<Create> for JsonNewPlot {
             branches,
             testbeds,
             benchmarks,
+            benchmark_tags,
             measures,
             ..
         } = create;
@@ -103,6 +107,7 @@ impl From<Create> for JsonNewPlot {
             branches: branches.into_iter().map(Into::into).collect(),
             testbeds: testbeds.into_iter().map(Into::into).collect(),
             benchmarks: benchmarks.into_iter().map(Into::into).collect(),
+            benchmark_tags: benchmark_tags.map(Into::into),
             measures: measures.into_iter().map(Into::into).collect(),
         }
     }
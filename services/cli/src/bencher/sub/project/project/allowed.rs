@@ -39,6 +39,7 @@ impl From<CliProjectPermission> for ProjectPermission {
             CliProjectPermission::Edit => Self::Edit,
             CliProjectPermission::Delete => Self::Delete,
             CliProjectPermission::Manage => Self::Manage,
+            CliProjectPermission::CreateReport => Self::CreateReport,
             CliProjectPermission::ViewRole => Self::ViewRole,
             CliProjectPermission::CreateRole => Self::CreateRole,
             CliProjectPermission::EditRole => Self::EditRole,
@@ -0,0 +1,67 @@
+use bencher_json::{JsonProjectExport, ProjectResourceId};
+use camino::Utf8PathBuf;
+
+use super::ProjectError;
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    cli_println,
+    parser::project::CliProjectExport,
+};
+
+#[derive(Debug)]
+pub struct Export {
+    pub project: ProjectResourceId,
+    pub output: Option<Utf8PathBuf>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectExport> for Export {
+    type Error = CliError;
+
+    fn try_from(export: CliProjectExport) -> Result<Self, Self::Error> {
+        let CliProjectExport {
+            project,
+            output,
+            backend,
+        } = export;
+        Ok(Self {
+            project,
+            output,
+            // The project archive is output directly, so do not log the response.
+            backend: AuthBackend::try_from(backend)?.log(false),
+        })
+    }
+}
+
+impl SubCmd for Export {
+    async fn exec(&self) -> Result<(), CliError> {
+        let json_export: JsonProjectExport = self
+            .backend
+            .send_with(|client| async move {
+                client
+                    .proj_export_get()
+                    .project(self.project.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        let archive =
+            serde_json::to_string_pretty(&json_export).map_err(ProjectError::Serialize)?;
+
+        if let Some(path) = &self.output {
+            std::fs::write(path, archive).map_err(|error| ProjectError::Write {
+                path: path.clone(),
+                error,
+            })?;
+            cli_println!(
+                "Exported project {slug} ({reports} reports) to {path}",
+                slug = json_export.project.slug,
+                reports = json_export.reports.len()
+            );
+        } else {
+            cli_println!("{archive}");
+        }
+        Ok(())
+    }
+}
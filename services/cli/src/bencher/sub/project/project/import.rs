@@ -0,0 +1,85 @@
+use bencher_json::{JsonProjectExport, OrganizationResourceId, ProjectSlug, ResourceName};
+use camino::Utf8PathBuf;
+
+use super::ProjectError;
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::CliProjectImport,
+};
+
+#[derive(Debug)]
+pub struct Import {
+    pub organization: OrganizationResourceId,
+    pub file: Utf8PathBuf,
+    pub name: Option<ResourceName>,
+    pub slug: Option<ProjectSlug>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliProjectImport> for Import {
+    type Error = CliError;
+
+    fn try_from(import: CliProjectImport) -> Result<Self, Self::Error> {
+        let CliProjectImport {
+            organization,
+            file,
+            name,
+            slug,
+            backend,
+        } = import;
+        Ok(Self {
+            organization,
+            file,
+            name,
+            slug,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for Import {
+    async fn exec(&self) -> Result<(), CliError> {
+        let json_export = self.archive()?;
+        // Validate the archive locally before converting it to the client type.
+        let body: bencher_client::types::JsonProjectExport = serde_json::to_value(json_export)
+            .and_then(serde_json::from_value)
+            .map_err(ProjectError::Convert)?;
+        let _json = self
+            .backend
+            .send(|client| {
+                let body = body.clone();
+                async move {
+                    client
+                        .org_project_import_post()
+                        .organization(self.organization.clone())
+                        .body(body)
+                        .send()
+                        .await
+                }
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl Import {
+    fn archive(&self) -> Result<JsonProjectExport, ProjectError> {
+        let archive = std::fs::read_to_string(&self.file).map_err(|error| ProjectError::Read {
+            path: self.file.clone(),
+            error,
+        })?;
+        let mut json_export: JsonProjectExport =
+            serde_json::from_str(&archive).map_err(|error| ProjectError::Parse {
+                path: self.file.clone(),
+                error,
+            })?;
+        if let Some(name) = self.name.clone() {
+            json_export.project.name = name;
+        }
+        if let Some(slug) = self.slug.clone() {
+            json_export.project.slug = slug;
+        }
+        Ok(json_export)
+    }
+}
@@ -1,12 +1,39 @@
+use camino::Utf8PathBuf;
+
 use crate::{CliError, bencher::sub::SubCmd, parser::project::CliProject};
 
 mod allowed;
 mod create;
 mod delete;
+mod export;
+mod import;
 mod list;
 mod update;
 mod view;
 
+#[derive(thiserror::Error, Debug)]
+pub enum ProjectError {
+    #[error("Failed to serialize project archive: {0}")]
+    Serialize(serde_json::Error),
+    #[error("Failed to write project archive to {path}: {error}")]
+    Write {
+        path: Utf8PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Failed to read project archive from {path}: {error}")]
+    Read {
+        path: Utf8PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Failed to parse project archive from {path}: {error}")]
+    Parse {
+        path: Utf8PathBuf,
+        error: serde_json::Error,
+    },
+    #[error("Failed to convert project archive: {0}")]
+    Convert(serde_json::Error),
+}
+
 #[derive(Debug)]
 pub enum Project {
     Create(create::Create),
@@ -15,6 +42,8 @@ pub enum Project {
     Update(update::Update),
     Delete(delete::Delete),
     Allowed(allowed::Allowed),
+    Export(export::Export),
+    Import(import::Import),
 }
 
 impl TryFrom<CliProject> for Project {
@@ -28,6 +57,8 @@ impl TryFrom<CliProject> for Project {
             CliProject::Update(update) => Self::Update(update.try_into()?),
             CliProject::Delete(delete) => Self::Delete(delete.try_into()?),
             CliProject::Allowed(allowed) => Self::Allowed(allowed.try_into()?),
+            CliProject::Export(export) => Self::Export(export.try_into()?),
+            CliProject::Import(import) => Self::Import(import.try_into()?),
         })
     }
 }
@@ -41,6 +72,8 @@ impl SubCmd for Project {
             Self::Update(update) => update.exec().await,
             Self::Delete(delete) => delete.exec().await,
             Self::Allowed(allowed) => allowed.exec().await,
+            Self::Export(export) => export.exec().await,
+            Self::Import(import) => import.exec().await,
         }
     }
 }
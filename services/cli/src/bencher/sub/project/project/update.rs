@@ -17,6 +17,7 @@ pub struct Update {
     pub slug: Option<ProjectSlug>,
     pub url: Option<Option<Url>>,
     pub visibility: Option<Visibility>,
+    pub openmetrics: Option<bool>,
     pub backend: AuthBackend,
 }
 
@@ -30,6 +31,7 @@ impl TryFrom<CliProjectUpdate> for Update {
             slug,
             url,
             visibility,
+            openmetrics,
             backend,
         } = create;
         Ok(Self {
@@ -38,6 +40,7 @@ impl TryFrom<CliProjectUpdate> for Update {
             slug,
             url: url.map(Into::into),
             visibility: visibility.map(Into::into),
+            openmetrics,
             backend: backend.try_into()?,
         })
     }
@@ -50,6 +53,7 @@ impl From<Update> for JsonUpdateProject {
             slug,
             url,
             visibility,
+            openmetrics,
             ..
         } = update;
         match url {
@@ -59,6 +63,7 @@ impl From<Update> for JsonUpdateProject {
                     slug: slug.map(Into::into),
                     url: Some(url.into()),
                     visibility,
+                    openmetrics,
                 }),
                 subtype_1: None,
             },
@@ -69,6 +74,7 @@ impl From<Update> for JsonUpdateProject {
                     slug: slug.map(Into::into),
                     url: (),
                     visibility,
+                    openmetrics,
                 }),
             },
             None => Self {
@@ -77,6 +83,7 @@ impl From<Update> for JsonUpdateProject {
                     slug: slug.map(Into::into),
                     url: None,
                     visibility,
+                    openmetrics,
                 }),
                 subtype_1: None,
             },
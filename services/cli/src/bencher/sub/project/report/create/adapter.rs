@@ -7,6 +7,7 @@ impl From<CliReportAdapter> for Adapter {
         match adapter {
             CliReportAdapter::Magic => Self::Magic,
             CliReportAdapter::Json => Self::Json,
+            CliReportAdapter::Custom => Self::Custom,
             CliReportAdapter::CSharp => Self::CSharp,
             CliReportAdapter::CSharpDotNet => Self::CSharpDotNet,
             CliReportAdapter::Cpp => Self::Cpp,
@@ -31,6 +32,7 @@ impl From<CliReportAdapter> for Adapter {
             CliReportAdapter::RustGungraun => Self::RustGungraun,
             CliReportAdapter::Shell => Self::Shell,
             CliReportAdapter::ShellHyperfine => Self::ShellHyperfine,
+            CliReportAdapter::ShellPerfStat => Self::ShellPerfStat,
         }
     }
 }
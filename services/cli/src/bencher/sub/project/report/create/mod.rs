@@ -2,7 +2,7 @@ use bencher_client::types::{
     Adapter, DateTime, GitHash, JsonAverage, JsonFold, JsonNewReport, JsonReportSettings,
     JsonUpdateStartPoint,
 };
-use bencher_json::{BranchNameId, ProjectResourceId, TestbedNameId};
+use bencher_json::{BranchNameId, ProjectResourceId, ReportGroupUuid, TestbedNameId};
 
 use crate::{
     CliError,
@@ -34,6 +34,7 @@ pub struct Create {
     pub adapter: Option<Adapter>,
     pub average: Option<JsonAverage>,
     pub fold: Option<JsonFold>,
+    pub group: Option<ReportGroupUuid>,
     pub backend: AuthBackend,
 }
 
@@ -54,6 +55,7 @@ impl TryFrom<CliReportCreate> for Create {
             adapter,
             average,
             fold,
+            group,
             backend,
         } = create;
         Ok(Self {
@@ -69,6 +71,7 @@ impl TryFrom<CliReportCreate> for Create {
             adapter: adapter.map(Into::into),
             average: average.map(Into::into),
             fold: fold.map(Into::into),
+            group,
             backend: backend.try_into()?,
         })
     }
@@ -87,6 +90,7 @@ impl From<Create> for JsonNewReport {
             adapter,
             average,
             fold,
+            group,
             ..
         } = create;
         Self {
@@ -102,7 +106,9 @@ impl From<Create> for JsonNewReport {
                 adapter,
                 average,
                 fold,
+                adapter_spec: None,
             }),
+            group: group.map(Into::into),
         }
     }
 }
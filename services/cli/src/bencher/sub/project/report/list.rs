@@ -1,6 +1,7 @@
 use bencher_client::types::{JsonDirection, ProjReportsSort};
 use bencher_json::{
-    BranchNameId, DateTime, ProjectResourceId, TestbedNameId, project::report::JsonReportQuery,
+    BranchNameId, DateTime, ProjectResourceId, ReportGroupUuid, TestbedNameId,
+    project::report::JsonReportQuery,
 };
 
 use crate::{
@@ -19,6 +20,7 @@ pub struct List {
     pub testbed: Option<TestbedNameId>,
     pub start_time: Option<DateTime>,
     pub end_time: Option<DateTime>,
+    pub group: Option<ReportGroupUuid>,
     pub pagination: Pagination,
     pub archived: bool,
     pub backend: PubBackend,
@@ -42,6 +44,7 @@ impl TryFrom<CliReportList> for List {
             testbed,
             start_time,
             end_time,
+            group,
             pagination,
             archived,
             backend,
@@ -52,6 +55,7 @@ impl TryFrom<CliReportList> for List {
             testbed,
             start_time,
             end_time,
+            group,
             pagination: pagination.into(),
             archived,
             backend: backend.try_into()?,
@@ -85,6 +89,7 @@ impl From<List> for JsonReportQuery {
             testbed,
             start_time,
             end_time,
+            group,
             archived,
             ..
         } = list;
@@ -93,6 +98,7 @@ impl From<List> for JsonReportQuery {
             testbed,
             start_time,
             end_time,
+            group,
             archived: archived.then_some(archived),
         }
     }
@@ -119,6 +125,9 @@ impl SubCmd for List {
                 if let Some(end_time) = json_report_query.end_time() {
                     client = client.end_time(end_time);
                 }
+                if let Some(group) = json_report_query.group {
+                    client = client.group(group);
+                }
 
                 if let Some(archived) = json_report_query.archived {
                     client = client.archived(archived);
@@ -0,0 +1,41 @@
+use bencher_json::ProjectResourceId;
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::retention::CliRetentionDryRun,
+};
+
+#[derive(Debug)]
+pub struct DryRun {
+    pub project: ProjectResourceId,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRetentionDryRun> for DryRun {
+    type Error = CliError;
+
+    fn try_from(dry_run: CliRetentionDryRun) -> Result<Self, Self::Error> {
+        let CliRetentionDryRun { project, backend } = dry_run;
+        Ok(Self {
+            project,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for DryRun {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_retention_dry_run_get()
+                    .project(self.project.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
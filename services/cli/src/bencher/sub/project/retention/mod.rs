@@ -0,0 +1,34 @@
+use crate::{CliError, bencher::sub::SubCmd, parser::project::retention::CliRetention};
+
+mod dry_run;
+mod update;
+mod view;
+
+#[derive(Debug)]
+pub enum Retention {
+    View(view::View),
+    Update(update::Update),
+    DryRun(dry_run::DryRun),
+}
+
+impl TryFrom<CliRetention> for Retention {
+    type Error = CliError;
+
+    fn try_from(retention: CliRetention) -> Result<Self, Self::Error> {
+        Ok(match retention {
+            CliRetention::View(view) => Self::View(view.try_into()?),
+            CliRetention::Update(update) => Self::Update(update.try_into()?),
+            CliRetention::DryRun(dry_run) => Self::DryRun(dry_run.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Retention {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::View(view) => view.exec().await,
+            Self::Update(update) => update.exec().await,
+            Self::DryRun(dry_run) => dry_run.exec().await,
+        }
+    }
+}
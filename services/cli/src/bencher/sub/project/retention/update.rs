@@ -0,0 +1,68 @@
+use std::num::NonZeroU32;
+
+use bencher_client::types::JsonUpdateRetention;
+use bencher_json::ProjectResourceId;
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::retention::CliRetentionUpdate,
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub project: ProjectResourceId,
+    pub reports: Option<NonZeroU32>,
+    pub archived_branches: Option<NonZeroU32>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRetentionUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliRetentionUpdate) -> Result<Self, Self::Error> {
+        let CliRetentionUpdate {
+            project,
+            reports,
+            archived_branches,
+            backend,
+        } = update;
+        Ok(Self {
+            project,
+            reports,
+            archived_branches,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Update> for JsonUpdateRetention {
+    fn from(update: Update) -> Self {
+        let Update {
+            reports,
+            archived_branches,
+            ..
+        } = update;
+        Self {
+            reports,
+            archived_branches,
+        }
+    }
+}
+
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_retention_put()
+                    .project(self.project.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
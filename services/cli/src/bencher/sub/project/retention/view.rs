@@ -0,0 +1,41 @@
+use bencher_json::ProjectResourceId;
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::retention::CliRetentionView,
+};
+
+#[derive(Debug)]
+pub struct View {
+    pub project: ProjectResourceId,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliRetentionView> for View {
+    type Error = CliError;
+
+    fn try_from(view: CliRetentionView) -> Result<Self, Self::Error> {
+        let CliRetentionView { project, backend } = view;
+        Ok(Self {
+            project,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl SubCmd for View {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_retention_get()
+                    .project(self.project.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
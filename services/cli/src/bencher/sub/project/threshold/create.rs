@@ -1,5 +1,5 @@
 use bencher_client::types::JsonNewThreshold;
-use bencher_json::{BranchNameId, MeasureNameId, ProjectResourceId, TestbedNameId};
+use bencher_json::{BranchNameId, MeasureNameId, ProjectResourceId, TagSelectors, TestbedNameId};
 
 use super::{ThresholdError, model::Model};
 use crate::{
@@ -14,6 +14,7 @@ pub struct Create {
     pub branch: BranchNameId,
     pub testbed: TestbedNameId,
     pub measure: MeasureNameId,
+    pub benchmark_tags: Option<TagSelectors>,
     pub model: Model,
     pub backend: AuthBackend,
 }
@@ -27,6 +28,7 @@ impl TryFrom<CliThresholdCreate> for Create {
             branch,
             testbed,
             measure,
+            benchmark_tags,
             model,
             backend,
         } = create;
@@ -35,6 +37,7 @@ impl TryFrom<CliThresholdCreate> for Create {
             branch,
             testbed,
             measure,
+            benchmark_tags,
             model: model.try_into()?,
             backend: backend.try_into()?,
         })
@@ -58,6 +61,7 @@ impl From<Create> for JsonNewThreshold {
             testbed,
             model,
             measure,
+            benchmark_tags,
             ..
         } = create;
         let Model {
@@ -72,6 +76,7 @@ impl From<Create> for JsonNewThreshold {
             branch: branch.into(),
             testbed: testbed.into(),
             measure: measure.into(),
+            benchmark_tags: benchmark_tags.map(Into::into),
             test,
             min_sample_size,
             max_sample_size,
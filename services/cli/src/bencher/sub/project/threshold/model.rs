@@ -54,9 +54,11 @@ impl From<CliModelTest> for bencher_json::ModelTest {
             CliModelTest::Percentage => Self::Percentage,
             CliModelTest::ZScore => Self::ZScore,
             CliModelTest::TTest => Self::TTest,
+            CliModelTest::WelchTTest => Self::WelchTTest,
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::MannWhitney => Self::MannWhitney,
         }
     }
 }
@@ -68,9 +70,11 @@ impl From<CliModelTest> for ModelTest {
             CliModelTest::Percentage => Self::Percentage,
             CliModelTest::ZScore => Self::ZScore,
             CliModelTest::TTest => Self::TTest,
+            CliModelTest::WelchTTest => Self::WelchTTest,
             CliModelTest::LogNormal => Self::LogNormal,
             CliModelTest::Iqr => Self::Iqr,
             CliModelTest::DeltaIqr => Self::DeltaIqr,
+            CliModelTest::MannWhitney => Self::MannWhitney,
         }
     }
 }
@@ -0,0 +1,62 @@
+use bencher_client::types::JsonNewWebhook;
+use bencher_json::{ProjectResourceId, Secret, Url};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::CliWebhookCreate,
+};
+
+#[derive(Debug, Clone)]
+pub struct Create {
+    pub project: ProjectResourceId,
+    pub url: Url,
+    pub secret: Secret,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliWebhookCreate> for Create {
+    type Error = CliError;
+
+    fn try_from(create: CliWebhookCreate) -> Result<Self, Self::Error> {
+        let CliWebhookCreate {
+            project,
+            url,
+            secret,
+            backend,
+        } = create;
+        Ok(Self {
+            project,
+            url,
+            secret,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Create> for JsonNewWebhook {
+    fn from(create: Create) -> Self {
+        let Create { url, secret, .. } = create;
+        Self {
+            url: url.into(),
+            secret: secret.into(),
+        }
+    }
+}
+
+impl SubCmd for Create {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_post()
+                    .project(self.project.clone())
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{CliError, bencher::sub::SubCmd, parser::project::webhook::CliWebhook};
+
+mod create;
+mod delete;
+mod list;
+mod update;
+
+#[derive(Debug)]
+pub enum Webhook {
+    List(list::List),
+    Create(create::Create),
+    Update(update::Update),
+    Delete(delete::Delete),
+}
+
+impl TryFrom<CliWebhook> for Webhook {
+    type Error = CliError;
+
+    fn try_from(webhook: CliWebhook) -> Result<Self, Self::Error> {
+        Ok(match webhook {
+            CliWebhook::List(list) => Self::List(list.try_into()?),
+            CliWebhook::Create(create) => Self::Create(create.try_into()?),
+            CliWebhook::Update(update) => Self::Update(update.try_into()?),
+            CliWebhook::Delete(delete) => Self::Delete(delete.try_into()?),
+        })
+    }
+}
+
+impl SubCmd for Webhook {
+    async fn exec(&self) -> Result<(), CliError> {
+        match self {
+            Self::List(list) => list.exec().await,
+            Self::Create(create) => create.exec().await,
+            Self::Update(update) => update.exec().await,
+            Self::Delete(delete) => delete.exec().await,
+        }
+    }
+}
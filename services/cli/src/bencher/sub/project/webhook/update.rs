@@ -0,0 +1,66 @@
+use bencher_client::types::JsonUpdateWebhook;
+use bencher_json::{ProjectResourceId, Secret, Url, WebhookUuid};
+
+use crate::{
+    CliError,
+    bencher::{backend::AuthBackend, sub::SubCmd},
+    parser::project::webhook::CliWebhookUpdate,
+};
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pub project: ProjectResourceId,
+    pub webhook: WebhookUuid,
+    pub url: Option<Url>,
+    pub secret: Option<Secret>,
+    pub backend: AuthBackend,
+}
+
+impl TryFrom<CliWebhookUpdate> for Update {
+    type Error = CliError;
+
+    fn try_from(update: CliWebhookUpdate) -> Result<Self, Self::Error> {
+        let CliWebhookUpdate {
+            project,
+            webhook,
+            url,
+            secret,
+            backend,
+        } = update;
+        Ok(Self {
+            project,
+            webhook,
+            url,
+            secret,
+            backend: backend.try_into()?,
+        })
+    }
+}
+
+impl From<Update> for JsonUpdateWebhook {
+    fn from(update: Update) -> Self {
+        let Update { url, secret, .. } = update;
+        Self {
+            url: url.map(Into::into),
+            secret: secret.map(Into::into),
+        }
+    }
+}
+
+impl SubCmd for Update {
+    async fn exec(&self) -> Result<(), CliError> {
+        let _json = self
+            .backend
+            .send(|client| async move {
+                client
+                    .proj_webhook_patch()
+                    .project(self.project.clone())
+                    .webhook(self.webhook)
+                    .body(self.clone())
+                    .send()
+                    .await
+            })
+            .await?;
+        Ok(())
+    }
+}
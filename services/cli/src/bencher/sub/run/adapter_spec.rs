@@ -0,0 +1,110 @@
+use bencher_adapter::adapters::custom::{AdapterCustom, CustomLine};
+use bencher_json::project::report::JsonAdapterSpec;
+use camino::Utf8Path;
+
+use crate::{cli_println_quietable, parser::project::report::CliReportAdapter};
+
+#[derive(Debug, Clone)]
+pub struct AdapterSpec {
+    json: JsonAdapterSpec,
+    custom: AdapterCustom,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum AdapterSpecError {
+    #[error("The `custom` adapter requires an adapter spec. Use the `--adapter-spec` option.")]
+    NoSpec,
+    #[error("An adapter spec requires the `custom` adapter. Use the `--adapter custom` option.")]
+    NotCustom,
+    #[error("Failed to read adapter spec file ({0}): {1}")]
+    ReadFile(String, std::io::Error),
+    #[error("Failed to parse adapter spec ({0}): {1}")]
+    Parse(String, serde_json::Error),
+    #[error("Invalid adapter spec: {0}")]
+    Invalid(bencher_adapter::AdapterError),
+}
+
+impl TryFrom<String> for AdapterSpec {
+    type Error = AdapterSpecError;
+
+    // The adapter spec can either be a path to a JSON file or inline JSON.
+    fn try_from(adapter_spec: String) -> Result<Self, Self::Error> {
+        let spec_str = if Utf8Path::new(&adapter_spec).is_file() {
+            std::fs::read_to_string(&adapter_spec)
+                .map_err(|e| AdapterSpecError::ReadFile(adapter_spec, e))?
+        } else {
+            adapter_spec
+        };
+        let json: JsonAdapterSpec = serde_json::from_str(&spec_str)
+            .map_err(|e| AdapterSpecError::Parse(spec_str.clone(), e))?;
+        let custom = AdapterCustom::new(&json).map_err(AdapterSpecError::Invalid)?;
+        Ok(Self { json, custom })
+    }
+}
+
+impl AdapterSpec {
+    // Show which lines of each result matched the adapter spec.
+    pub fn dry_run(&self, results: &[String], log: bool) {
+        cli_println_quietable!(log, "\nCustom Adapter Spec Matches:");
+        for (iteration, result) in results.iter().enumerate() {
+            cli_println_quietable!(log, "Iteration {iteration}:");
+            for (line, custom_line) in result.lines().zip(self.custom.lines(result)) {
+                match custom_line {
+                    CustomLine::Metric {
+                        benchmark_name,
+                        measure,
+                        metric,
+                    } => cli_println_quietable!(
+                        log,
+                        "✅ {line}\n   {benchmark_name} | {measure} | {value}",
+                        value = metric.value
+                    ),
+                    CustomLine::Invalid(reason) => {
+                        cli_println_quietable!(log, "❌ {line}\n   {reason}");
+                    },
+                    CustomLine::NoMatch(_) => cli_println_quietable!(log, "➖ {line}"),
+                }
+            }
+        }
+    }
+}
+
+impl From<&AdapterSpec> for bencher_client::types::JsonAdapterSpec {
+    fn from(adapter_spec: &AdapterSpec) -> Self {
+        let JsonAdapterSpec {
+            regex,
+            measure,
+            units,
+        } = adapter_spec.json.clone();
+        Self {
+            regex,
+            measure: measure.map(Into::into),
+            units: units.map(|units| {
+                units
+                    .into_iter()
+                    .map(|(unit, spec_unit)| {
+                        (
+                            unit,
+                            bencher_client::types::JsonAdapterSpecUnit {
+                                measure: spec_unit.measure.into(),
+                                scale: spec_unit.scale,
+                            },
+                        )
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+pub fn map_adapter_spec(
+    adapter: &CliReportAdapter,
+    adapter_spec: Option<String>,
+) -> Result<Option<AdapterSpec>, AdapterSpecError> {
+    match (adapter, adapter_spec) {
+        (CliReportAdapter::Custom, Some(adapter_spec)) => adapter_spec.try_into().map(Some),
+        (CliReportAdapter::Custom, None) => Err(AdapterSpecError::NoSpec),
+        (_, Some(_)) => Err(AdapterSpecError::NotCustom),
+        (_, None) => Ok(None),
+    }
+}
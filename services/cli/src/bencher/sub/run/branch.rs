@@ -1,4 +1,4 @@
-use bencher_json::{BranchNameId, GitHash};
+use bencher_json::{BranchNameId, GitHash, MergeBase, project::branch::START_POINT_MAX_ANCESTORS};
 
 use crate::{bencher::sub::project::branch::start_point::StartPoint, parser::run::CliRunBranch};
 
@@ -14,6 +14,12 @@ pub struct Branch {
 pub enum BranchError {
     #[error("Failed to parse UUID, slug, or name for the branch: {0}")]
     ParseBranch(bencher_json::ValidError),
+    #[error(
+        "Failed to find the `git` merge-base between `HEAD` and the start point branch ({0}). Make sure the start point branch has been fetched and that the repository is not a shallow clone."
+    )]
+    MergeBase(String),
+    #[error("Failed to parse `git` hash ({0}): {1}")]
+    ParseHash(String, bencher_json::ValidError),
 }
 
 impl TryFrom<CliRunBranch> for Branch {
@@ -25,6 +31,7 @@ impl TryFrom<CliRunBranch> for Branch {
             hash,
             start_point,
             start_point_hash,
+            start_point_merge_base,
             start_point_max_versions,
             start_point_clone_thresholds,
             start_point_reset,
@@ -33,10 +40,11 @@ impl TryFrom<CliRunBranch> for Branch {
         let start_point = map_start_point(
             start_point,
             start_point_hash,
+            start_point_merge_base,
             start_point_max_versions,
             start_point_clone_thresholds,
             start_point_reset,
-        );
+        )?;
         Ok(Self {
             branch,
             hash,
@@ -49,21 +57,51 @@ impl TryFrom<CliRunBranch> for Branch {
 fn map_start_point(
     start_point: Vec<String>,
     start_point_hash: Option<GitHash>,
+    start_point_merge_base: bool,
     start_point_max_versions: u32,
     start_point_clone_thresholds: bool,
     start_point_reset: bool,
-) -> StartPoint {
+) -> Result<StartPoint, BranchError> {
     let branch = start_point.first().and_then(|b| {
         // This allows for "continue on empty" semantics for the branch start point.
         b.parse().ok()
     });
-    StartPoint {
+    let (hash, ancestor_hashes) = match (start_point.first(), start_point_merge_base) {
+        (Some(start_point), true) if branch.is_some() => {
+            merge_base(start_point, start_point_max_versions)?
+        },
+        _ => (start_point_hash, Vec::new()),
+    };
+    Ok(StartPoint {
         branch,
-        hash: start_point_hash,
+        hash,
+        ancestor_hashes,
         max_versions: start_point_max_versions,
         clone_thresholds: start_point_clone_thresholds,
         reset: start_point_reset,
-    }
+    })
+}
+
+fn merge_base(
+    start_point: &str,
+    max_versions: u32,
+) -> Result<(Option<GitHash>, Vec<GitHash>), BranchError> {
+    // The server only checks a limited number of ancestors.
+    let max_ancestors = usize::try_from(max_versions)
+        .unwrap_or(usize::MAX)
+        .min(START_POINT_MAX_ANCESTORS);
+    let MergeBase { hash, ancestors } = MergeBase::current(start_point, max_ancestors)
+        .ok_or_else(|| BranchError::MergeBase(start_point.to_owned()))?;
+    let hash = parse_hash(hash)?;
+    let ancestor_hashes = ancestors
+        .into_iter()
+        .map(parse_hash)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((Some(hash), ancestor_hashes))
+}
+
+fn parse_hash(hash: String) -> Result<GitHash, BranchError> {
+    hash.parse().map_err(|e| BranchError::ParseHash(hash, e))
 }
 
 impl From<Branch>
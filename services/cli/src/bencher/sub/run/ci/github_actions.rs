@@ -1,4 +1,3 @@
-use bencher_comment::ReportComment;
 use octocrab::{
     Octocrab,
     models::CommentId,
@@ -7,6 +6,8 @@ use octocrab::{
 
 use crate::{cli_eprintln_quietable, cli_println_quietable};
 
+use super::RunComment;
+
 const GITHUB_ACTIONS: &str = "GITHUB_ACTIONS";
 const GITHUB_EVENT_PATH: &str = "GITHUB_EVENT_PATH";
 const GITHUB_EVENT_NAME: &str = "GITHUB_EVENT_NAME";
@@ -164,7 +165,11 @@ impl GitHubActions {
         Ok(())
     }
 
-    pub async fn run(&self, report_comment: &ReportComment, log: bool) -> Result<(), GitHubError> {
+    pub async fn run<C: RunComment>(
+        &self,
+        report_comment: &C,
+        log: bool,
+    ) -> Result<(), GitHubError> {
         if !is_github_actions() {
             cli_println_quietable!(
                 log,
@@ -214,7 +219,7 @@ impl GitHubActions {
             .await
     }
 
-    fn create_job_summary(&self, report_comment: &ReportComment, log: bool) {
+    fn create_job_summary<C: RunComment>(&self, report_comment: &C, log: bool) {
         let summary = report_comment.html(self.ci_only_thresholds, self.ci_id.as_deref());
         // https://docs.github.com/en/actions/writing-workflows/choosing-what-your-workflow-does/workflow-commands-for-github-actions#adding-a-job-summary
         if let Ok(file_path) = std::env::var(GITHUB_STEP_SUMMARY)
@@ -227,9 +232,9 @@ impl GitHubActions {
         }
     }
 
-    async fn create_github_check(
+    async fn create_github_check<C: RunComment>(
         &self,
-        report_comment: &ReportComment,
+        report_comment: &C,
         log: bool,
         event_str: &str,
         event: &serde_json::Value,
@@ -283,9 +288,9 @@ impl GitHubActions {
         }
     }
 
-    pub async fn create_pull_request_comment(
+    pub async fn create_pull_request_comment<C: RunComment>(
         &self,
-        report_comment: &ReportComment,
+        report_comment: &C,
         log: bool,
         event_str: &str,
         event: &serde_json::Value,
@@ -0,0 +1,408 @@
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::cli_println_quietable;
+
+use super::RunComment;
+
+const GITLAB_CI: &str = "GITLAB_CI";
+const CI_API_V4_URL: &str = "CI_API_V4_URL";
+const CI_PROJECT_ID: &str = "CI_PROJECT_ID";
+const CI_MERGE_REQUEST_IID: &str = "CI_MERGE_REQUEST_IID";
+
+const PRIVATE_TOKEN: &str = "PRIVATE-TOKEN";
+
+#[derive(Debug)]
+pub struct GitLabCi {
+    pub token: String,
+    pub ci_only_thresholds: bool,
+    pub ci_only_on_alert: bool,
+    pub ci_public_links: bool,
+    pub ci_id: Option<String>,
+    pub ci_number: Option<u64>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GitLabError {
+    #[error("Failed to get GitLab API URL\n{}", docker_env(CI_API_V4_URL))]
+    NoApiUrl,
+    #[error("Failed to get GitLab project ID\n{}", docker_env(CI_PROJECT_ID))]
+    NoProjectId,
+    #[error("GitLab merge request IID is invalid ({0}): {1}")]
+    BadMergeRequestIid(String, std::num::ParseIntError),
+    #[error("GitLab token is not a valid HTTP header value: {0}")]
+    BadToken(reqwest::header::InvalidHeaderValue),
+    #[error("Failed to create GitLab API client: {0}")]
+    Client(reqwest::Error),
+    #[error("Failed to list GitLab merge request notes: {0}")]
+    Notes(reqwest::Error),
+    #[error("Failed to create GitLab merge request note: {0}")]
+    CreateNote(reqwest::Error),
+    #[error("Failed to update GitLab merge request note: {0}")]
+    UpdateNote(reqwest::Error),
+    #[error("{}", permissions_help(_0))]
+    BadNotePermissions(reqwest::Error),
+}
+
+// https://docs.gitlab.com/ee/user/profile/personal_access_tokens.html#personal-access-token-scopes
+fn permissions_help(err: &reqwest::Error) -> String {
+    format!(
+        "GitLab token does not have permission to comment on merge requests.\nTo fix, use a project, group, or personal access token with the `api` scope and at least the `Reporter` role.\nSee: https://bencher.dev/docs/how-to/gitlab-ci-cd/\nError: {err}",
+    )
+}
+
+fn docker_env(env_var: &str) -> String {
+    format!(
+        "If you are running in a Docker container, then you need to pass in the `{env_var}` environment variable. See https://bencher.dev/docs/explanation/bencher-run/#--gitlab-ci",
+    )
+}
+
+impl GitLabCi {
+    pub async fn run<C: RunComment>(
+        &self,
+        report_comment: &C,
+        log: bool,
+    ) -> Result<(), GitLabError> {
+        if !is_gitlab_ci() {
+            cli_println_quietable!(
+                log,
+                "Not running in GitLab CI/CD. Skipping CI integration.\n{}",
+                docker_env(GITLAB_CI)
+            );
+            return Ok(());
+        }
+
+        // Only post to CI if there are thresholds set
+        if self.ci_only_thresholds && !report_comment.has_threshold() {
+            cli_println_quietable!(log, "No thresholds set. Skipping CI integration.");
+            return Ok(());
+        }
+
+        let merge_request_iid = if let Some(merge_request_iid) = self.ci_number {
+            merge_request_iid
+        } else if let Ok(merge_request_iid) = std::env::var(CI_MERGE_REQUEST_IID) {
+            // https://docs.gitlab.com/ee/ci/variables/predefined_variables.html#predefined-variables-for-merge-request-pipelines
+            merge_request_iid
+                .parse()
+                .map_err(|e| GitLabError::BadMergeRequestIid(merge_request_iid, e))?
+        } else {
+            cli_println_quietable!(
+                log,
+                "Not running in a GitLab CI/CD merge request pipeline and the `--ci-number` option was not set. Skipping CI integration.\n{}",
+                docker_env(CI_MERGE_REQUEST_IID)
+            );
+            return Ok(());
+        };
+
+        let Ok(api_url) = std::env::var(CI_API_V4_URL) else {
+            return Err(GitLabError::NoApiUrl);
+        };
+        let Ok(project_id) = std::env::var(CI_PROJECT_ID) else {
+            return Err(GitLabError::NoProjectId);
+        };
+        let gitlab_client = GitLabClient::new(&api_url, project_id, &self.token)?;
+
+        let body = report_comment.html(self.ci_only_thresholds, self.ci_id.as_deref());
+        // Only create a new note if there is an alert, but always update an existing note
+        let create = !self.ci_only_on_alert || report_comment.has_alert();
+        let note = gitlab_client
+            .upsert_note(
+                merge_request_iid,
+                &report_comment.bencher_tag(self.ci_id.as_deref()),
+                body,
+                create,
+            )
+            .await?;
+        if note.is_none() {
+            cli_println_quietable!(log, "No alerts found. Skipping CI integration.");
+        }
+
+        Ok(())
+    }
+}
+
+// https://docs.gitlab.com/ee/ci/variables/predefined_variables.html
+// Mark that the job is executed in a GitLab CI/CD environment.
+fn is_gitlab_ci() -> bool {
+    std::env::var(GITLAB_CI).as_deref() == Ok("true")
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabNote {
+    id: u64,
+    body: String,
+}
+
+// https://docs.gitlab.com/ee/api/notes.html#merge-requests
+struct GitLabClient {
+    client: reqwest::Client,
+    api_url: String,
+    project_id: String,
+}
+
+impl GitLabClient {
+    fn new(api_url: &str, project_id: String, token: &str) -> Result<Self, GitLabError> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        let mut token =
+            reqwest::header::HeaderValue::from_str(token).map_err(GitLabError::BadToken)?;
+        token.set_sensitive(true);
+        headers.insert(PRIVATE_TOKEN, token);
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(GitLabError::Client)?;
+        Ok(Self {
+            client,
+            api_url: api_url.trim_end_matches('/').to_owned(),
+            project_id,
+        })
+    }
+
+    fn notes_url(&self, merge_request_iid: u64) -> String {
+        format!(
+            "{api_url}/projects/{project_id}/merge_requests/{merge_request_iid}/notes",
+            api_url = self.api_url,
+            project_id = self.project_id,
+        )
+    }
+
+    // Update the existing note with the Bencher tag if it exists.
+    // Otherwise, create a new note if `create` is set.
+    // Returns the ID of the note that was updated or created.
+    async fn upsert_note(
+        &self,
+        merge_request_iid: u64,
+        bencher_tag: &str,
+        body: String,
+        create: bool,
+    ) -> Result<Option<u64>, GitLabError> {
+        let note_id = self.get_note(merge_request_iid, bencher_tag).await?;
+
+        let notes_url = self.notes_url(merge_request_iid);
+        let request = if let Some(note_id) = note_id {
+            self.client.put(format!("{notes_url}/{note_id}"))
+        } else if create {
+            self.client.post(notes_url)
+        } else {
+            return Ok(None);
+        };
+        let note = request
+            .json(&serde_json::json!({ "body": body }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status);
+        match note {
+            Ok(response) => response
+                .json::<GitLabNote>()
+                .await
+                .map(|note| Some(note.id))
+                .map_err(if note_id.is_some() {
+                    GitLabError::UpdateNote
+                } else {
+                    GitLabError::CreateNote
+                }),
+            Err(e) => Err(if is_permissions_error(&e) {
+                GitLabError::BadNotePermissions(e)
+            } else if note_id.is_some() {
+                GitLabError::UpdateNote(e)
+            } else {
+                GitLabError::CreateNote(e)
+            }),
+        }
+    }
+
+    async fn get_note(
+        &self,
+        merge_request_iid: u64,
+        bencher_tag: &str,
+    ) -> Result<Option<u64>, GitLabError> {
+        const PER_PAGE: u8 = 100;
+
+        let notes_url = self.notes_url(merge_request_iid);
+        let mut page: u32 = 1;
+        loop {
+            let notes = self
+                .client
+                .get(&notes_url)
+                .query(&[
+                    ("sort", "asc".to_owned()),
+                    ("per_page", PER_PAGE.to_string()),
+                    ("page", page.to_string()),
+                ])
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(|e| {
+                    if is_permissions_error(&e) {
+                        GitLabError::BadNotePermissions(e)
+                    } else {
+                        GitLabError::Notes(e)
+                    }
+                })?
+                .json::<Vec<GitLabNote>>()
+                .await
+                .map_err(GitLabError::Notes)?;
+
+            let notes_len = notes.len();
+            if notes_len == 0 {
+                return Ok(None);
+            }
+
+            for note in notes {
+                if note.body.ends_with(bencher_tag) {
+                    return Ok(Some(note.id));
+                }
+            }
+
+            if notes_len < usize::from(PER_PAGE) {
+                return Ok(None);
+            }
+
+            page += 1;
+        }
+    }
+}
+
+fn is_permissions_error(err: &reqwest::Error) -> bool {
+    matches!(
+        err.status(),
+        Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{Matcher, Mock, Server};
+    use pretty_assertions::assert_eq;
+
+    use super::{GitLabClient, GitLabError, PRIVATE_TOKEN};
+
+    const TOKEN: &str = "glpat-bencher";
+    const PROJECT_ID: &str = "42";
+    const MERGE_REQUEST_IID: u64 = 7;
+    const NOTES_PATH: &str = "/projects/42/merge_requests/7/notes";
+    const BENCHER_TAG: &str =
+        r#"<div id="bencher.dev/projects/project/id/branch/testbed/adapter"></div>"#;
+
+    fn gitlab_client(server: &Server) -> GitLabClient {
+        GitLabClient::new(&server.url(), PROJECT_ID.to_owned(), TOKEN).unwrap()
+    }
+
+    async fn mock_list_notes(server: &mut Server, notes: serde_json::Value) -> Mock {
+        server
+            .mock("GET", NOTES_PATH)
+            .match_header(PRIVATE_TOKEN, TOKEN)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("per_page".into(), "100".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_header("content-type", "application/json")
+            .with_body(notes.to_string())
+            .create_async()
+            .await
+    }
+
+    fn note_body() -> String {
+        format!("Bencher Report{BENCHER_TAG}")
+    }
+
+    #[tokio::test]
+    async fn gitlab_create_note() {
+        let mut server = Server::new_async().await;
+        let list = mock_list_notes(
+            &mut server,
+            serde_json::json!([{ "id": 1, "body": "LGTM" }]),
+        )
+        .await;
+        let create = server
+            .mock("POST", NOTES_PATH)
+            .match_header(PRIVATE_TOKEN, TOKEN)
+            .match_body(Matcher::Json(serde_json::json!({ "body": note_body() })))
+            .with_status(201)
+            .with_body(serde_json::json!({ "id": 2, "body": note_body() }).to_string())
+            .create_async()
+            .await;
+
+        let note = gitlab_client(&server)
+            .upsert_note(MERGE_REQUEST_IID, BENCHER_TAG, note_body(), true)
+            .await
+            .unwrap();
+        assert_eq!(note, Some(2));
+        list.assert_async().await;
+        create.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn gitlab_update_note() {
+        let mut server = Server::new_async().await;
+        let list = mock_list_notes(
+            &mut server,
+            serde_json::json!([
+                { "id": 1, "body": "LGTM" },
+                { "id": 3, "body": format!("Old Bencher Report{BENCHER_TAG}") },
+            ]),
+        )
+        .await;
+        let create = server
+            .mock("POST", NOTES_PATH)
+            .expect(0)
+            .create_async()
+            .await;
+        let update = server
+            .mock("PUT", format!("{NOTES_PATH}/3").as_str())
+            .match_header(PRIVATE_TOKEN, TOKEN)
+            .match_body(Matcher::Json(serde_json::json!({ "body": note_body() })))
+            .with_body(serde_json::json!({ "id": 3, "body": note_body() }).to_string())
+            .create_async()
+            .await;
+
+        // An existing note is always updated, even if a new note would not be created.
+        let note = gitlab_client(&server)
+            .upsert_note(MERGE_REQUEST_IID, BENCHER_TAG, note_body(), false)
+            .await
+            .unwrap();
+        assert_eq!(note, Some(3));
+        list.assert_async().await;
+        create.assert_async().await;
+        update.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn gitlab_skip_note() {
+        let mut server = Server::new_async().await;
+        let list = mock_list_notes(&mut server, serde_json::json!([])).await;
+        let create = server
+            .mock("POST", NOTES_PATH)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let note = gitlab_client(&server)
+            .upsert_note(MERGE_REQUEST_IID, BENCHER_TAG, note_body(), false)
+            .await
+            .unwrap();
+        assert_eq!(note, None);
+        list.assert_async().await;
+        create.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn gitlab_note_permissions() {
+        let mut server = Server::new_async().await;
+        let list = mock_list_notes(&mut server, serde_json::json!([])).await;
+        let create = server
+            .mock("POST", NOTES_PATH)
+            .with_status(403)
+            .with_body(r#"{"message":"403 Forbidden"}"#)
+            .create_async()
+            .await;
+
+        let err = gitlab_client(&server)
+            .upsert_note(MERGE_REQUEST_IID, BENCHER_TAG, note_body(), true)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GitLabError::BadNotePermissions(_)), "{err}");
+        list.assert_async().await;
+        create.assert_async().await;
+    }
+}
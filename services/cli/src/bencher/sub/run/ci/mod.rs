@@ -1,20 +1,25 @@
-use bencher_comment::ReportComment;
-
 use crate::parser::run::CliRunCi;
 
+use super::comment::RunComment;
+
 mod github_actions;
+mod gitlab_ci;
 
 use github_actions::{GitHubActions, GitHubError};
+use gitlab_ci::{GitLabCi, GitLabError};
 
 #[derive(Debug)]
 pub enum Ci {
     GitHubActions(GitHubActions),
+    GitLabCi(GitLabCi),
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum CiError {
     #[error("{0}")]
     GitHub(#[from] GitHubError),
+    #[error("{0}")]
+    GitLab(#[from] GitLabError),
 }
 
 impl TryFrom<CliRunCi> for Option<Ci> {
@@ -23,6 +28,7 @@ impl TryFrom<CliRunCi> for Option<Ci> {
     fn try_from(ci: CliRunCi) -> Result<Self, Self::Error> {
         let CliRunCi {
             github_actions,
+            gitlab_ci,
             ci_only_thresholds,
             ci_only_on_alert,
             ci_public_links,
@@ -31,8 +37,8 @@ impl TryFrom<CliRunCi> for Option<Ci> {
             ci_i_am_vulnerable_to_pwn_requests,
             ci_deprecated: _,
         } = ci;
-        Ok(github_actions.map(|token| {
-            Ci::GitHubActions(GitHubActions {
+        Ok(if let Some(token) = github_actions {
+            Some(Ci::GitHubActions(GitHubActions {
                 token,
                 ci_only_thresholds,
                 ci_only_on_alert,
@@ -40,8 +46,19 @@ impl TryFrom<CliRunCi> for Option<Ci> {
                 ci_id,
                 ci_number,
                 ci_i_am_vulnerable_to_pwn_requests,
+            }))
+        } else {
+            gitlab_ci.map(|token| {
+                Ci::GitLabCi(GitLabCi {
+                    token,
+                    ci_only_thresholds,
+                    ci_only_on_alert,
+                    ci_public_links,
+                    ci_id,
+                    ci_number,
+                })
             })
-        }))
+        })
     }
 }
 
@@ -51,21 +68,27 @@ impl Ci {
             Self::GitHubActions(github_actions) => {
                 github_actions.safety_check(log).map_err(Into::into)
             },
+            // GitLab CI/CD does not expose secrets to merge requests from forks by default
+            Self::GitLabCi(_) => Ok(()),
         }
     }
 
-    pub async fn run(&self, report_comment: &ReportComment, log: bool) -> Result<(), CiError> {
+    pub async fn run<C: RunComment>(&self, report_comment: &C, log: bool) -> Result<(), CiError> {
         match self {
             Self::GitHubActions(github_actions) => github_actions
                 .run(report_comment, log)
                 .await
                 .map_err(Into::into),
+            Self::GitLabCi(gitlab_ci) => {
+                gitlab_ci.run(report_comment, log).await.map_err(Into::into)
+            },
         }
     }
 
     pub fn source(&self) -> String {
         match self {
             Self::GitHubActions(_) => "github".to_owned(),
+            Self::GitLabCi(_) => "gitlab".to_owned(),
         }
     }
 }
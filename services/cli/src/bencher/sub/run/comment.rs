@@ -0,0 +1,67 @@
+use bencher_comment::{MatrixComment, ReportComment};
+
+// The results of a run, either a single Report or a matrix of Reports,
+// that can be displayed or posted to CI.
+pub trait RunComment {
+    fn human(&self) -> String;
+
+    fn json(&self) -> Result<String, serde_json::Error>;
+
+    fn html(&self, require_threshold: bool, id: Option<&str>) -> String;
+
+    fn html_with_max_length(
+        &self,
+        require_threshold: bool,
+        id: Option<&str>,
+        max_length: usize,
+    ) -> String;
+
+    fn bencher_tag(&self, id: Option<&str>) -> String;
+
+    fn has_threshold(&self) -> bool;
+
+    fn has_alert(&self) -> bool;
+}
+
+macro_rules! impl_run_comment {
+    ($($comment:ty),*) => {
+        $(
+            impl RunComment for $comment {
+                fn human(&self) -> String {
+                    <$comment>::human(self)
+                }
+
+                fn json(&self) -> Result<String, serde_json::Error> {
+                    <$comment>::json(self)
+                }
+
+                fn html(&self, require_threshold: bool, id: Option<&str>) -> String {
+                    <$comment>::html(self, require_threshold, id)
+                }
+
+                fn html_with_max_length(
+                    &self,
+                    require_threshold: bool,
+                    id: Option<&str>,
+                    max_length: usize,
+                ) -> String {
+                    <$comment>::html_with_max_length(self, require_threshold, id, max_length)
+                }
+
+                fn bencher_tag(&self, id: Option<&str>) -> String {
+                    <$comment>::bencher_tag(self, id)
+                }
+
+                fn has_threshold(&self) -> bool {
+                    <$comment>::has_threshold(self)
+                }
+
+                fn has_alert(&self) -> bool {
+                    <$comment>::has_alert(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_run_comment!(ReportComment, MatrixComment);
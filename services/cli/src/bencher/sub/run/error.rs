@@ -15,7 +15,11 @@ pub enum RunError {
     #[error("{0}")]
     Branch(#[from] super::branch::BranchError),
     #[error("{0}")]
+    AdapterSpec(#[from] super::adapter_spec::AdapterSpecError),
+    #[error("{0}")]
     Thresholds(#[from] crate::bencher::sub::ThresholdsError),
+    #[error("{0}")]
+    Matrix(#[from] super::matrix::MatrixError),
 
     #[error(
         "No default shell command path for target family. Try setting a custom shell with the `--shell` option."
@@ -34,26 +38,26 @@ pub enum RunError {
     FlagWithExec(String),
     #[error("Failed to spawn command `{command}`: {err}")]
     SpawnCommand {
-        command: Command,
+        command: Box<Command>,
         err: std::io::Error,
     },
     #[error("Failed to pipe stdout for command `{0}`")]
-    PipeStdout(Command),
+    PipeStdout(Box<Command>),
     #[error("Failed to pipe stderr for command `{0}`")]
-    PipeStderr(Command),
+    PipeStderr(Box<Command>),
     #[error("Failed to run command `{command}: {err}")]
     RunCommand {
-        command: Command,
+        command: Box<Command>,
         err: std::io::Error,
     },
     #[error("Failed to join stdout for command `{command}`: {err}")]
     StdoutJoinError {
-        command: Command,
+        command: Box<Command>,
         err: tokio::task::JoinError,
     },
     #[error("Failed to join stderr for command `{command}`: {err}")]
     StderrJoinError {
-        command: Command,
+        command: Box<Command>,
         err: tokio::task::JoinError,
     },
     #[error("Failed to run command due to a non-zero exit code for runner `{runner}`: {output}")]
@@ -74,6 +78,11 @@ pub enum RunError {
     #[error("Failed to serialize file size results: {0}")]
     SerializeFileSize(serde_json::Error),
 
+    #[error("{0}")]
+    LocalServer(bencher_api::local::LocalServerError),
+    #[error("Failed to use local API server: {0}")]
+    LocalBackend(crate::bencher::BackendError),
+
     #[error("Failed to serialize report JSON: {0}")]
     SerializeReport(serde_json::Error),
     #[error("Failed to create new report: {0}")]
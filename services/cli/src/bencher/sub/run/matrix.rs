@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+
+use bencher_json::TestbedNameId;
+use camino::Utf8Path;
+use serde::Deserialize;
+
+use crate::parser::run::CliRunShell;
+
+use super::{
+    RunError,
+    runner::{Runner, command::Command},
+};
+
+#[derive(Debug)]
+pub struct Matrix {
+    pub order: MatrixOrder,
+    pub variants: Vec<Variant>,
+}
+
+#[derive(Debug)]
+pub struct Variant {
+    pub testbed: TestbedNameId,
+    pub runner: Runner,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum MatrixError {
+    #[error("Failed to read matrix file ({0}): {1}")]
+    ReadFile(String, std::io::Error),
+    #[error("Failed to parse matrix ({0}): {1}")]
+    Parse(String, serde_json::Error),
+    #[error("The matrix must have at least one variant.")]
+    NoVariants,
+    #[error("The matrix has more than one variant for testbed ({0}).")]
+    DuplicateTestbed(TestbedNameId),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonMatrix {
+    /// The order to run the variants in.
+    /// If no order is specified, then `sequential` will be used.
+    order: Option<MatrixOrder>,
+    /// The variants to run, one Report is created for each variant.
+    variants: Vec<JsonMatrixVariant>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonMatrixVariant {
+    /// Testbed name, slug, or UUID for the variant.
+    testbed: TestbedNameId,
+    /// Benchmark command for the variant, run as a shell command.
+    command: String,
+    /// Additional environment variables to set for the benchmark command.
+    env: Option<BTreeMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatrixOrder {
+    /// Run all of the iterations for one variant before moving on to the next variant.
+    #[default]
+    Sequential,
+    /// Run one iteration of each variant in turn, to spread out any machine drift across all variants.
+    Interleaved,
+}
+
+impl Matrix {
+    // The matrix can either be a path to a JSON file or inline JSON.
+    pub fn new(matrix: String, sh_c: &CliRunShell) -> Result<Self, RunError> {
+        let matrix_str = if Utf8Path::new(&matrix).is_file() {
+            std::fs::read_to_string(&matrix).map_err(|e| MatrixError::ReadFile(matrix, e))?
+        } else {
+            matrix
+        };
+        let JsonMatrix { order, variants } = serde_json::from_str(&matrix_str)
+            .map_err(|e| MatrixError::Parse(matrix_str.clone(), e))?;
+        if variants.is_empty() {
+            return Err(MatrixError::NoVariants.into());
+        }
+
+        let mut matrix_variants = Vec::with_capacity(variants.len());
+        for JsonMatrixVariant {
+            testbed,
+            command,
+            env,
+        } in variants
+        {
+            if matrix_variants
+                .iter()
+                .any(|variant: &Variant| variant.testbed == testbed)
+            {
+                return Err(MatrixError::DuplicateTestbed(testbed).into());
+            }
+            let command =
+                Command::new_shell(sh_c.clone(), command)?.with_env(env.unwrap_or_default());
+            matrix_variants.push(Variant {
+                testbed,
+                runner: Runner::Command(command, None),
+            });
+        }
+
+        Ok(Self {
+            order: order.unwrap_or_default(),
+            variants: matrix_variants,
+        })
+    }
+
+    // The index of the variant to run at each step,
+    // given the number of iterations for each variant.
+    pub fn schedule(&self, iter: usize) -> Vec<usize> {
+        let variants = 0..self.variants.len();
+        match self.order {
+            MatrixOrder::Sequential => variants
+                .flat_map(|variant| std::iter::repeat_n(variant, iter))
+                .collect(),
+            MatrixOrder::Interleaved => (0..iter).flat_map(|_| variants.clone()).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::run::CliRunShell;
+
+    use super::{Matrix, MatrixError, MatrixOrder};
+    use crate::bencher::sub::RunError;
+
+    fn sh_c() -> CliRunShell {
+        CliRunShell {
+            shell: Some("/bin/sh".to_owned()),
+            flag: Some("-c".to_owned()),
+        }
+    }
+
+    #[test]
+    fn matrix_schedule() {
+        let matrix = Matrix::new(
+            r#"{"variants": [{"testbed": "a", "command": "true"}, {"testbed": "b", "command": "true", "env": {"KEY": "value"}}]}"#.to_owned(),
+            &sh_c(),
+        )
+        .unwrap();
+        assert!(matches!(matrix.order, MatrixOrder::Sequential));
+        assert_eq!(matrix.schedule(2), vec![0, 0, 1, 1]);
+
+        let matrix = Matrix::new(
+            r#"{"order": "interleaved", "variants": [{"testbed": "a", "command": "true"}, {"testbed": "b", "command": "true"}]}"#.to_owned(),
+            &sh_c(),
+        )
+        .unwrap();
+        assert!(matches!(matrix.order, MatrixOrder::Interleaved));
+        assert_eq!(matrix.schedule(2), vec![0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn matrix_invalid() {
+        assert!(matches!(
+            Matrix::new(r#"{"variants": []}"#.to_owned(), &sh_c()),
+            Err(RunError::Matrix(MatrixError::NoVariants))
+        ));
+        assert!(matches!(
+            Matrix::new(
+                r#"{"variants": [{"testbed": "a", "command": "true"}, {"testbed": "a", "command": "false"}]}"#.to_owned(),
+                &sh_c(),
+            ),
+            Err(RunError::Matrix(MatrixError::DuplicateTestbed(_)))
+        ));
+        assert!(matches!(
+            Matrix::new(r#"{"variants": [{"testbed": "a"}]}"#.to_owned(), &sh_c()),
+            Err(RunError::Matrix(MatrixError::Parse(_, _)))
+        ));
+    }
+}
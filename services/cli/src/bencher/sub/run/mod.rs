@@ -1,8 +1,12 @@
 use std::{future::Future, pin::Pin};
 
+use bencher_api::local::LocalServer;
 use bencher_client::types::{Adapter, JsonAverage, JsonFold, JsonNewRun, JsonReportSettings};
-use bencher_comment::ReportComment;
-use bencher_json::{DateTime, JsonReport, ProjectResourceId, RunContext, TestbedNameId};
+use bencher_comment::{MatrixComment, ReportComment};
+use bencher_json::{
+    DateTime, JsonReport, ProjectResourceId, ReportGroupUuid, RunContext, TestbedNameId,
+};
+use camino::Utf8PathBuf;
 
 use crate::{
     CliError,
@@ -11,18 +15,24 @@ use crate::{
     parser::run::{CliRun, CliRunOutput},
 };
 
+mod adapter_spec;
 mod branch;
 mod ci;
+mod comment;
 mod error;
-mod format;
+pub mod format;
+mod matrix;
 mod project;
 pub mod runner;
 mod sub_adapter;
 
+use adapter_spec::{AdapterSpec, map_adapter_spec};
 use branch::Branch;
 use ci::Ci;
+use comment::RunComment;
 pub use error::RunError;
 use format::Format;
+use matrix::Matrix;
 use project::map_project;
 use runner::Runner;
 use sub_adapter::SubAdapter;
@@ -36,8 +46,9 @@ use super::project::report::Thresholds;
 pub struct Run {
     project: Option<ProjectResourceId>,
     branch: Branch,
-    testbed: Option<TestbedNameId>,
+    mode: RunMode,
     adapter: Adapter,
+    adapter_spec: Option<AdapterSpec>,
     sub_adapter: SubAdapter,
     average: Option<JsonAverage>,
     iter: usize,
@@ -49,12 +60,21 @@ pub struct Run {
     format: Format,
     log: bool,
     ci: Option<Ci>,
-    runner: Runner,
     #[expect(clippy::struct_field_names)]
     dry_run: bool,
+    local: Option<Utf8PathBuf>,
     backend: PubBackend,
 }
 
+#[derive(Debug)]
+enum RunMode {
+    Single {
+        testbed: Option<TestbedNameId>,
+        runner: Runner,
+    },
+    Matrix(Matrix),
+}
+
 impl TryFrom<CliRun> for Run {
     type Error = CliError;
 
@@ -64,6 +84,7 @@ impl TryFrom<CliRun> for Run {
             branch,
             testbed,
             adapter,
+            adapter_spec,
             average,
             iter,
             fold,
@@ -74,15 +95,29 @@ impl TryFrom<CliRun> for Run {
             output: CliRunOutput { format, quiet },
             ci,
             cmd,
+            matrix,
             dry_run,
+            local,
             backend,
         } = run;
+        let adapter_spec =
+            map_adapter_spec(&adapter, adapter_spec).map_err(RunError::AdapterSpec)?;
+        let sub_adapter = (&cmd).into();
+        let mode = if let Some(matrix) = matrix {
+            RunMode::Matrix(Matrix::new(matrix, &cmd.sh_c)?)
+        } else {
+            RunMode::Single {
+                testbed,
+                runner: cmd.try_into()?,
+            }
+        };
         Ok(Self {
             project: map_project(project)?,
             branch: branch.try_into().map_err(RunError::Branch)?,
-            testbed,
+            mode,
             adapter: adapter.into(),
-            sub_adapter: (&cmd).into(),
+            adapter_spec,
+            sub_adapter,
             average: average.map(Into::into),
             iter,
             fold: fold.map(Into::into),
@@ -93,8 +128,8 @@ impl TryFrom<CliRun> for Run {
             format: format.into(),
             log: !quiet,
             ci: ci.try_into().map_err(RunError::Ci)?,
-            runner: cmd.try_into()?,
             dry_run,
+            local,
             backend: PubBackend::try_from(backend)?.log(false),
         })
     }
@@ -108,8 +143,24 @@ impl SubCmd for Run {
 
 impl Run {
     async fn exec_inner(&self) -> Result<(), RunError> {
-        if let Some(mismatch) = self
-            .backend
+        let Some(local) = &self.local else {
+            return self.exec_backend(&self.backend).await;
+        };
+
+        // Run an in-process API server against the local database file
+        let local_server = LocalServer::start(local.clone().into())
+            .await
+            .map_err(RunError::LocalServer)?;
+        let result = match self.backend.clone().local(local_server.local_addr()) {
+            Ok(backend) => self.exec_backend(&backend).await,
+            Err(e) => Err(RunError::LocalBackend(e)),
+        };
+        local_server.stop().await.map_err(RunError::LocalServer)?;
+        result
+    }
+
+    async fn exec_backend(&self, backend: &PubBackend) -> Result<(), RunError> {
+        if let Some(mismatch) = backend
             .check_version()
             .await
             .map_err(RunError::ApiVersion)?
@@ -121,31 +172,47 @@ impl Run {
             ci.safety_check(self.log)?;
         }
 
-        let Some(json_new_run) = self.generate_report().await? else {
-            return Ok(());
+        let json_new_runs = match &self.mode {
+            RunMode::Single { testbed, runner } => {
+                vec![self.generate_report(testbed.as_ref(), runner).await?]
+            },
+            RunMode::Matrix(matrix) => self.generate_matrix_reports(matrix).await?,
         };
 
-        cli_println_quietable!(self.log, "\nBencher New Report:");
-        cli_println_quietable!(
-            self.log,
-            "{}",
-            serde_json::to_string_pretty(&json_new_run).map_err(RunError::SerializeReport)?
-        );
+        for json_new_run in &json_new_runs {
+            cli_println_quietable!(self.log, "\nBencher New Report:");
+            cli_println_quietable!(
+                self.log,
+                "{}",
+                serde_json::to_string_pretty(json_new_run).map_err(RunError::SerializeReport)?
+            );
+        }
 
         // If performing a dry run, don't actually send the report
         if self.dry_run {
+            if let Some(adapter_spec) = &self.adapter_spec {
+                for json_new_run in &json_new_runs {
+                    adapter_spec.dry_run(&json_new_run.results, self.log);
+                }
+            }
             return Ok(());
         }
 
-        let sender = run_sender(json_new_run);
-        let json_report: JsonReport = self
-            .backend
-            .send_with(sender)
-            .await
-            .map_err(RunError::SendReport)?;
+        let mut json_reports = Vec::with_capacity(json_new_runs.len());
+        for json_new_run in json_new_runs {
+            let sender = run_sender(json_new_run);
+            let json_report: JsonReport = backend
+                .send_with(sender)
+                .await
+                .map_err(RunError::SendReport)?;
+            json_reports.push(json_report);
+        }
 
-        let alerts_count = json_report.alerts.len();
-        self.display_results(json_report).await?;
+        let alerts_count = json_reports
+            .iter()
+            .map(|json_report| json_report.alerts.len())
+            .sum();
+        self.display_results(backend, json_reports).await?;
 
         if self.err && alerts_count > 0 {
             Err(RunError::Alerts(alerts_count))
@@ -154,31 +221,90 @@ impl Run {
         }
     }
 
-    async fn generate_report(&self) -> Result<Option<JsonNewRun>, RunError> {
+    async fn generate_report(
+        &self,
+        testbed: Option<&TestbedNameId>,
+        runner: &Runner,
+    ) -> Result<JsonNewRun, RunError> {
         let start_time = DateTime::now();
         let mut results = Vec::with_capacity(self.iter);
         for _ in 0..self.iter {
-            let outputs = self.runner.run(self.log).await?;
-            for output in outputs {
-                if output.is_success() {
-                    results.push(output.result());
-                } else if self.allow_failure {
-                    cli_eprintln_quietable!(self.log, "Skipping failure:\n{output}");
-                } else {
-                    return Err(RunError::ExitStatus {
-                        runner: Box::new(self.runner.clone()),
-                        output,
-                    });
-                }
+            self.run_runner(runner, &mut results).await?;
+        }
+        let end_time = DateTime::now();
+
+        Ok(self.new_run(testbed, results, start_time, end_time, None))
+    }
+
+    async fn generate_matrix_reports(&self, matrix: &Matrix) -> Result<Vec<JsonNewRun>, RunError> {
+        // Track the results and the start time of the first run and end time of the last run for each variant
+        let mut variant_runs = matrix
+            .variants
+            .iter()
+            .map(|_| (Vec::with_capacity(self.iter), None, None))
+            .collect::<Vec<_>>();
+        for index in matrix.schedule(self.iter) {
+            let (Some(variant), Some((results, start_time, end_time))) =
+                (matrix.variants.get(index), variant_runs.get_mut(index))
+            else {
+                continue;
+            };
+            cli_println_quietable!(self.log, "\nMatrix Testbed: {}", variant.testbed);
+            start_time.get_or_insert_with(DateTime::now);
+            self.run_runner(&variant.runner, results).await?;
+            *end_time = Some(DateTime::now());
+        }
+
+        // All of the reports for the matrix are linked together as a group
+        let group = ReportGroupUuid::new();
+        Ok(matrix
+            .variants
+            .iter()
+            .zip(variant_runs)
+            .map(|(variant, (results, start_time, end_time))| {
+                let start_time = start_time.unwrap_or_else(DateTime::now);
+                let end_time = end_time.unwrap_or(start_time);
+                self.new_run(
+                    Some(&variant.testbed),
+                    results,
+                    start_time,
+                    end_time,
+                    Some(group),
+                )
+            })
+            .collect())
+    }
+
+    async fn run_runner(&self, runner: &Runner, results: &mut Vec<String>) -> Result<(), RunError> {
+        let outputs = runner.run(self.log).await?;
+        for output in outputs {
+            if output.is_success() {
+                results.push(output.result());
+            } else if self.allow_failure {
+                cli_eprintln_quietable!(self.log, "Skipping failure:\n{output}");
+            } else {
+                return Err(RunError::ExitStatus {
+                    runner: Box::new(runner.clone()),
+                    output,
+                });
             }
         }
+        Ok(())
+    }
 
+    fn new_run(
+        &self,
+        testbed: Option<&TestbedNameId>,
+        results: Vec<String>,
+        start_time: DateTime,
+        end_time: DateTime,
+        group: Option<ReportGroupUuid>,
+    ) -> JsonNewRun {
         cli_println_quietable!(self.log, "\nBenchmark Harness Results:");
         for result in &results {
             cli_println_quietable!(self.log, "{result}");
         }
 
-        let end_time = DateTime::now();
         // If a backdate is set then use it as the start time and calculate the end time from there
         let (start_time, end_time) = if let Some(backdate) = self.backdate {
             let elapsed = end_time.into_inner() - start_time.into_inner();
@@ -188,12 +314,12 @@ impl Run {
         };
 
         let (branch, hash, start_point) = self.branch.clone().into();
-        Ok(Some(JsonNewRun {
+        JsonNewRun {
             project: self.project.clone().map(Into::into),
             branch,
             hash,
             start_point,
-            testbed: self.testbed.clone().map(Into::into),
+            testbed: testbed.cloned().map(Into::into),
             thresholds: self.thresholds.clone().into(),
             start_time: start_time.into(),
             end_time: end_time.into(),
@@ -202,14 +328,19 @@ impl Run {
                 adapter: Some(self.adapter),
                 average: self.average,
                 fold: self.fold,
+                adapter_spec: self.adapter_spec.as_ref().map(Into::into),
             }),
             context: Some(RunContext::current().into()),
-        }))
+            group: group.map(Into::into),
+        }
     }
 
-    async fn display_results(&self, json_report: JsonReport) -> Result<(), RunError> {
-        let console_url = self
-            .backend
+    async fn display_results(
+        &self,
+        backend: &PubBackend,
+        json_reports: Vec<JsonReport>,
+    ) -> Result<(), RunError> {
+        let console_url = backend
             .get_console_url()
             .await
             .map_err(RunError::ConsoleUrl)?;
@@ -217,19 +348,44 @@ impl Run {
             .ci
             .as_ref()
             .map_or_else(|| "cli".to_owned(), Ci::source);
-        let report_comment =
-            ReportComment::new(console_url, json_report, self.sub_adapter.into(), source);
+        let mut report_comments = json_reports
+            .into_iter()
+            .map(|json_report| {
+                ReportComment::new(
+                    console_url.clone(),
+                    json_report,
+                    self.sub_adapter.into(),
+                    source.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        match &self.mode {
+            RunMode::Single { .. } => {
+                if let Some(report_comment) = report_comments.pop() {
+                    self.display_comment(&report_comment).await?;
+                }
+            },
+            RunMode::Matrix(_) => {
+                self.display_comment(&MatrixComment::new(report_comments))
+                    .await?;
+            },
+        }
+
+        Ok(())
+    }
 
+    async fn display_comment<C: RunComment>(&self, comment: &C) -> Result<(), RunError> {
         let report_str = match self.format {
-            Format::Human => report_comment.human(),
-            Format::Json => report_comment.json().map_err(RunError::SerializeReport)?,
-            Format::Html => report_comment.html(false, None),
+            Format::Human => comment.human(),
+            Format::Json => comment.json().map_err(RunError::SerializeReport)?,
+            Format::Html => comment.html(false, None),
         };
         let newline_prefix = if self.log { "\n" } else { "" };
         cli_println!("{newline_prefix}{report_str}");
 
         if let Some(ci) = &self.ci {
-            ci.run(&report_comment, self.log).await?;
+            ci.run(comment, self.log).await?;
         }
 
         Ok(())
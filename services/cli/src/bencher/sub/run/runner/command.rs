@@ -1,4 +1,4 @@
-use std::{fmt, process::Stdio};
+use std::{collections::BTreeMap, fmt, process::Stdio};
 
 use chrono::Utc;
 use tokio::io::{AsyncBufReadExt as _, BufReader};
@@ -16,22 +16,30 @@ pub enum Command {
         shell: Shell,
         flag: Flag,
         command: String,
+        env: BTreeMap<String, String>,
     },
     Exec {
         program: String,
         arguments: Vec<String>,
+        env: BTreeMap<String, String>,
     },
 }
 
 impl fmt::Display for Command {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in self.env() {
+            write!(f, "{key}={value} ")?;
+        }
         match self {
             Self::Shell {
                 shell,
                 flag,
                 command,
+                ..
             } => write!(f, "{shell} {flag} {command}"),
-            Self::Exec { program, arguments } => {
+            Self::Exec {
+                program, arguments, ..
+            } => {
                 let args = arguments.join(" ");
                 write!(f, "{program} {args}")
             },
@@ -46,11 +54,32 @@ impl Command {
             shell: shell.try_into()?,
             flag: flag.try_into()?,
             command,
+            env: BTreeMap::new(),
         })
     }
 
     pub fn new_exec(program: String, arguments: Vec<String>) -> Self {
-        Self::Exec { program, arguments }
+        Self::Exec {
+            program,
+            arguments,
+            env: BTreeMap::new(),
+        }
+    }
+
+    // Set additional environment variables for the command,
+    // on top of those inherited from the current process.
+    #[must_use]
+    pub fn with_env(mut self, vars: BTreeMap<String, String>) -> Self {
+        match &mut self {
+            Self::Shell { env, .. } | Self::Exec { env, .. } => env.extend(vars),
+        }
+        self
+    }
+
+    fn env(&self) -> &BTreeMap<String, String> {
+        match self {
+            Self::Shell { env, .. } | Self::Exec { env, .. } => env,
+        }
     }
 
     pub async fn run(
@@ -70,27 +99,34 @@ impl Command {
                 shell,
                 flag,
                 command,
+                env,
             } => tokio::process::Command::new(shell.as_ref())
                 .arg(flag.as_ref())
                 .arg(command)
+                .envs(env)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
                 .spawn(),
-            Self::Exec { program, arguments } => tokio::process::Command::new(program)
+            Self::Exec {
+                program,
+                arguments,
+                env,
+            } => tokio::process::Command::new(program)
                 .args(arguments)
+                .envs(env)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
                 .spawn(),
         }
         .map_err(|err| RunError::SpawnCommand {
-            command: self.clone(),
+            command: Box::new(self.clone()),
             err,
         })?;
 
         let child_stdout = child
             .stdout
             .take()
-            .ok_or_else(|| RunError::PipeStdout(self.clone()))?;
+            .ok_or_else(|| RunError::PipeStdout(Box::new(self.clone())))?;
         let stdout = tokio::spawn(async move {
             let stdout_reader = BufReader::new(child_stdout);
             let mut stdout_lines = stdout_reader.lines();
@@ -111,7 +147,7 @@ impl Command {
         let child_stderr = child
             .stderr
             .take()
-            .ok_or_else(|| RunError::PipeStderr(self.clone()))?;
+            .ok_or_else(|| RunError::PipeStderr(Box::new(self.clone())))?;
         let stderr = tokio::spawn(async move {
             let stderr_reader = BufReader::new(child_stderr);
             let mut stderr_lines = stderr_reader.lines();
@@ -133,15 +169,15 @@ impl Command {
         let end_time = Utc::now();
 
         let status = status.map_err(|err| RunError::RunCommand {
-            command: self.clone(),
+            command: Box::new(self.clone()),
             err,
         })?;
         let stdout = stdout.map_err(|err| RunError::StdoutJoinError {
-            command: self.clone(),
+            command: Box::new(self.clone()),
             err,
         })?;
         let stderr = stderr.map_err(|err| RunError::StderrJoinError {
-            command: self.clone(),
+            command: Box::new(self.clone()),
             err,
         })?;
 
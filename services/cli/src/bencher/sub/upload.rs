@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use bencher_adapter::{
+    AdapterResults,
+    results::adapter_metrics::{AdapterMetrics, MetricsMap},
+};
+use bencher_api::local::{LocalServer, LocalServerError};
+use bencher_client::types::{
+    Adapter, JsonDirection, JsonNewRun, JsonReportSettings, ProjReportsSort,
+};
+use bencher_json::{
+    BranchNameId, DateTime, JsonNewMetric, JsonReport, JsonReports, MeasureNameId,
+    ProjectResourceId, ReportUuid, RunContext, TestbedNameId,
+    project::report::{JsonReportIteration, JsonReportQuery},
+};
+use camino::Utf8PathBuf;
+
+use crate::{
+    CliError,
+    bencher::{BackendError, backend::PubBackend},
+    cli_println,
+    parser::upload::CliUpload,
+};
+
+use super::SubCmd;
+
+const PER_PAGE: u8 = u8::MAX;
+
+#[derive(Debug)]
+pub struct Upload {
+    local: Utf8PathBuf,
+    project: ProjectResourceId,
+    branch: Option<BranchNameId>,
+    testbed: Option<TestbedNameId>,
+    start_time: Option<DateTime>,
+    end_time: Option<DateTime>,
+    backend: PubBackend,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum UploadError {
+    #[error("{0}")]
+    LocalServer(LocalServerError),
+    #[error("Failed to use local API server: {0}")]
+    LocalBackend(BackendError),
+    #[error("Failed to list local reports: {0}")]
+    ListReports(BackendError),
+    #[error("Failed to serialize results for local report ({uuid}): {error}")]
+    SerializeResults {
+        uuid: ReportUuid,
+        error: serde_json::Error,
+    },
+    #[error("Failed to upload local report ({uuid}): {error}")]
+    UploadReport {
+        uuid: ReportUuid,
+        error: BackendError,
+    },
+}
+
+impl TryFrom<CliUpload> for Upload {
+    type Error = CliError;
+
+    fn try_from(upload: CliUpload) -> Result<Self, Self::Error> {
+        let CliUpload {
+            local,
+            project,
+            branch,
+            testbed,
+            start_time,
+            end_time,
+            backend,
+        } = upload;
+        Ok(Self {
+            local,
+            project,
+            branch,
+            testbed,
+            start_time,
+            end_time,
+            backend: PubBackend::try_from(backend)?.log(false),
+        })
+    }
+}
+
+impl SubCmd for Upload {
+    async fn exec(&self) -> Result<(), CliError> {
+        self.exec_inner().await.map_err(Into::into)
+    }
+}
+
+impl Upload {
+    async fn exec_inner(&self) -> Result<(), UploadError> {
+        let json_reports = self.local_reports().await?;
+        if json_reports.is_empty() {
+            cli_println!("No local reports found to upload.");
+            return Ok(());
+        }
+
+        let count = json_reports.len();
+        for json_report in json_reports {
+            let uuid = json_report.uuid;
+            let json_new_run = new_run(json_report)?;
+            let uploaded: JsonReport = self
+                .backend
+                .send_with(|client| {
+                    let json_new_run = json_new_run.clone();
+                    async move { client.run_post().body(json_new_run).send().await }
+                })
+                .await
+                .map_err(|error| UploadError::UploadReport { uuid, error })?;
+            cli_println!("Uploaded local report {uuid} as {}", uploaded.uuid);
+        }
+        cli_println!("Uploaded {count} local report(s).");
+
+        Ok(())
+    }
+
+    // Read all of the matching reports from the local database file, oldest first.
+    async fn local_reports(&self) -> Result<Vec<JsonReport>, UploadError> {
+        let local_server = LocalServer::start(self.local.clone().into())
+            .await
+            .map_err(UploadError::LocalServer)?;
+        let result = match self.backend.clone().local(local_server.local_addr()) {
+            Ok(backend) => self.list_reports(&backend).await,
+            Err(e) => Err(UploadError::LocalBackend(e)),
+        };
+        local_server
+            .stop()
+            .await
+            .map_err(UploadError::LocalServer)?;
+        result
+    }
+
+    async fn list_reports(&self, backend: &PubBackend) -> Result<Vec<JsonReport>, UploadError> {
+        let json_report_query = &JsonReportQuery {
+            branch: self.branch.clone(),
+            testbed: self.testbed.clone(),
+            start_time: self.start_time,
+            end_time: self.end_time,
+            group: None,
+            archived: None,
+        };
+        let mut json_reports = Vec::new();
+        for page in 1.. {
+            let JsonReports(reports) = backend
+                .send_with(|client| async move {
+                    let mut client = client
+                        .proj_reports_get()
+                        .project(self.project.clone())
+                        .sort(ProjReportsSort::DateTime)
+                        .direction(JsonDirection::Asc)
+                        .per_page(PER_PAGE)
+                        .page(page);
+
+                    if let Some(branch) = json_report_query.branch() {
+                        client = client.branch(branch);
+                    }
+                    if let Some(testbed) = json_report_query.testbed() {
+                        client = client.testbed(testbed);
+                    }
+                    if let Some(start_time) = json_report_query.start_time() {
+                        client = client.start_time(start_time);
+                    }
+                    if let Some(end_time) = json_report_query.end_time() {
+                        client = client.end_time(end_time);
+                    }
+
+                    client.send().await
+                })
+                .await
+                .map_err(UploadError::ListReports)?;
+            let last_page = reports.len() < usize::from(PER_PAGE);
+            json_reports.extend(reports);
+            if last_page {
+                break;
+            }
+        }
+        Ok(json_reports)
+    }
+}
+
+// Recreate the run for a local report using Bencher Metric Format (BMF) JSON results.
+// Threshold boundaries and alerts are not uploaded,
+// as they are recalculated against the history on the backend host.
+fn new_run(json_report: JsonReport) -> Result<JsonNewRun, UploadError> {
+    let JsonReport {
+        uuid,
+        project,
+        branch,
+        testbed,
+        start_time,
+        end_time,
+        results,
+        group,
+        ..
+    } = json_report;
+    let results = results
+        .into_iter()
+        .map(|iteration| {
+            serde_json::to_string(&adapter_results(iteration))
+                .map_err(|error| UploadError::SerializeResults { uuid, error })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let hash = branch.head.version.and_then(|version| version.hash);
+    Ok(JsonNewRun {
+        project: Some(ProjectResourceId::Slug(project.slug).into()),
+        branch: Some(BranchNameId::Name(branch.name).into()),
+        hash: hash.map(Into::into),
+        start_point: None,
+        testbed: Some(TestbedNameId::Name(testbed.name).into()),
+        thresholds: None,
+        start_time: start_time.into(),
+        end_time: end_time.into(),
+        results,
+        settings: Some(JsonReportSettings {
+            adapter: Some(Adapter::Json),
+            average: None,
+            fold: None,
+            adapter_spec: None,
+        }),
+        // Used to name the project on the backend host, if it does not exist yet
+        context: Some(RunContext::current().into()),
+        group: group.map(Into::into),
+    })
+}
+
+fn adapter_results(iteration: JsonReportIteration) -> AdapterResults {
+    let mut results = HashMap::with_capacity(iteration.len());
+    for result in iteration {
+        let inner: MetricsMap = result
+            .measures
+            .into_iter()
+            .map(|report_measure| {
+                let metric = report_measure.metric;
+                (
+                    MeasureNameId::Slug(report_measure.measure.slug),
+                    JsonNewMetric {
+                        value: metric.value,
+                        lower_value: metric.lower_value,
+                        upper_value: metric.upper_value,
+                    },
+                )
+            })
+            .collect();
+        results.insert(
+            result.benchmark.name,
+            AdapterMetrics {
+                inner,
+                tags: result.benchmark.tags,
+            },
+        );
+    }
+    AdapterResults::from(results)
+}
@@ -1,10 +1,10 @@
-use bencher_client::types::JsonNewToken;
-use bencher_json::{ResourceName, UserResourceId};
+use bencher_client::types::{JsonNewToken, JsonTokenScope, TokenPermission};
+use bencher_json::{OrganizationUuid, ProjectUuid, ResourceName, UserResourceId};
 
 use crate::{
     CliError,
     bencher::{backend::AuthBackend, sub::SubCmd},
-    parser::user::token::CliTokenCreate,
+    parser::user::token::{CliTokenCreate, CliTokenPermission},
 };
 
 #[derive(Debug, Clone)]
@@ -12,6 +12,9 @@ pub struct Create {
     pub user: UserResourceId,
     pub name: ResourceName,
     pub ttl: Option<u32>,
+    pub organizations: Vec<OrganizationUuid>,
+    pub projects: Vec<ProjectUuid>,
+    pub permission: Option<TokenPermission>,
     pub backend: AuthBackend,
 }
 
@@ -23,23 +26,54 @@ impl TryFrom<CliTokenCreate> for Create {
             user,
             name,
             ttl,
+            organizations,
+            projects,
+            permission,
             backend,
         } = create;
         Ok(Self {
             user,
             name,
             ttl,
+            organizations,
+            projects,
+            permission: permission.map(Into::into),
             backend: backend.try_into()?,
         })
     }
 }
 
+impl From<CliTokenPermission> for TokenPermission {
+    fn from(permission: CliTokenPermission) -> Self {
+        match permission {
+            CliTokenPermission::Read => Self::Read,
+            CliTokenPermission::Report => Self::Report,
+        }
+    }
+}
+
 impl From<Create> for JsonNewToken {
     fn from(create: Create) -> Self {
-        let Create { name, ttl, .. } = create;
+        let Create {
+            name,
+            ttl,
+            organizations,
+            projects,
+            permission,
+            ..
+        } = create;
+        let scope = (!organizations.is_empty() || !projects.is_empty() || permission.is_some())
+            .then(|| JsonTokenScope {
+                organizations: (!organizations.is_empty())
+                    .then(|| organizations.into_iter().map(Into::into).collect()),
+                projects: (!projects.is_empty())
+                    .then(|| projects.into_iter().map(Into::into).collect()),
+                permission,
+            });
         Self {
             name: name.into(),
             ttl,
+            scope,
         }
     }
 }
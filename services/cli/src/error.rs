@@ -7,12 +7,20 @@ pub enum CliError {
     #[error("{0}")]
     Run(#[from] crate::bencher::sub::RunError),
     #[error("{0}")]
+    Upload(#[from] crate::bencher::sub::UploadError),
+    #[error("{0}")]
+    Perf(#[from] crate::bencher::sub::PerfError),
+    #[error("{0}")]
+    Project(#[from] crate::bencher::sub::ProjectError),
+    #[error("{0}")]
     Archive(#[from] crate::bencher::sub::ArchiveError),
     #[error("{0}")]
     Threshold(#[from] crate::bencher::sub::ThresholdError),
     #[error("{0}")]
     Thresholds(#[from] crate::bencher::sub::ThresholdsError),
     #[error("{0}")]
+    Compare(#[from] crate::bencher::sub::CompareError),
+    #[error("{0}")]
     Mock(#[from] crate::bencher::sub::MockError),
     #[error("{0}")]
     Docker(#[from] crate::bencher::sub::DockerError),
@@ -0,0 +1,131 @@
+use bencher_json::{DateTime, OrganizationResourceId, ProjectResourceId, UserUuid};
+use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliAudit {
+    /// List audit events
+    #[clap(alias = "ls")]
+    List(CliAuditList),
+}
+
+#[derive(Parser, Debug)]
+#[clap(group(
+    ArgGroup::new("audit_scope")
+        .multiple(false)
+        .args(&["organization", "project"]),
+))]
+pub struct CliAuditList {
+    /// List audit events for an organization and all of its projects (slug or UUID)
+    #[clap(long)]
+    pub organization: Option<OrganizationResourceId>,
+
+    /// List audit events for a project (slug or UUID)
+    /// If neither an organization nor a project is given, list all audit events on the server.
+    #[clap(long)]
+    pub project: Option<ProjectResourceId>,
+
+    /// Filter by the UUID of the user who made the change
+    #[clap(long)]
+    pub user: Option<UserUuid>,
+
+    /// Filter by action
+    #[clap(long)]
+    pub action: Option<CliAuditAction>,
+
+    /// Filter by resource kind
+    #[clap(long)]
+    pub resource: Option<CliAuditResource>,
+
+    /// Filter by the UUID of the changed resource
+    #[clap(long)]
+    pub resource_uuid: Option<uuid::Uuid>,
+
+    /// Start time (seconds since epoch)
+    #[clap(long, value_name = "SECONDS")]
+    pub start_time: Option<DateTime>,
+
+    /// End time (seconds since epoch)
+    #[clap(long, value_name = "SECONDS")]
+    pub end_time: Option<DateTime>,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliAuditEventsSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAuditEventsSort {
+    /// Creation date time of the audit event
+    Created,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAuditAction {
+    /// Create
+    Create,
+    /// Update
+    Update,
+    /// Delete
+    Delete,
+    /// Invite
+    Invite,
+    /// Provision
+    Provision,
+    /// Deprovision
+    Deprovision,
+    /// Backup
+    Backup,
+    /// Restart
+    Restart,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliAuditResource {
+    /// Server
+    Server,
+    /// Organization
+    Organization,
+    /// Organization member
+    Member,
+    /// Organization SSO domain
+    Sso,
+    /// Organization plan
+    Plan,
+    /// Organization custom role
+    Role,
+    /// Project
+    Project,
+    /// Branch
+    Branch,
+    /// Testbed
+    Testbed,
+    /// Benchmark
+    Benchmark,
+    /// Measure
+    Measure,
+    /// Threshold
+    Threshold,
+    /// Alert
+    Alert,
+    /// Report
+    Report,
+    /// Plot
+    Plot,
+    /// Webhook
+    Webhook,
+    /// Data retention policy
+    Retention,
+    /// Project custom role member
+    ProjectMember,
+    /// User
+    User,
+    /// User API token
+    Token,
+}
@@ -3,24 +3,29 @@ use std::str::FromStr;
 use bencher_json::{BENCHER_API_URL_STR, Jwt, Url};
 use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
+pub mod audit;
 pub mod compose;
 pub mod mock;
 pub mod organization;
 pub mod project;
 pub mod run;
 pub mod system;
+pub mod upload;
 pub mod user;
 
+use audit::CliAudit;
 use compose::{CliDown, CliLogs, CliUp};
 use mock::CliMock;
-use organization::{CliOrganization, member::CliMember};
+use organization::{CliOrganization, member::CliMember, role::CliRole};
 use project::{
     CliProject, alert::CliAlert, archive::CliArchive, benchmark::CliBenchmark, branch::CliBranch,
-    measure::CliMeasure, metric::CliMetric, perf::CliPerf, plot::CliPlot, report::CliReport,
-    testbed::CliTestbed, threshold::CliThreshold,
+    compare::CliCompare, measure::CliMeasure, member::CliProjectMember, metric::CliMetric,
+    perf::CliPerf, plot::CliPlot, report::CliReport, retention::CliRetention, testbed::CliTestbed,
+    threshold::CliThreshold, webhook::CliWebhook,
 };
 use run::CliRun;
 use system::{auth::CliAuth, server::CliServer};
+use upload::CliUpload;
 use user::{CliUser, token::CliToken};
 
 /// Bencher CLI
@@ -36,6 +41,8 @@ pub struct CliBencher {
 pub enum CliSub {
     /// Run benchmarks
     Run(Box<CliRun>),
+    /// Upload reports saved locally with `bencher run --local`
+    Upload(CliUpload),
     /// Generate mock benchmark data
     Mock(CliMock),
 
@@ -57,6 +64,9 @@ pub enum CliSub {
     /// Manage organization members
     #[clap(subcommand)]
     Member(CliMember),
+    /// Manage organization custom roles
+    #[clap(subcommand)]
+    Role(CliRole),
     #[cfg(feature = "plus")]
     /// Organization metered subscription plan
     #[clap(subcommand)]
@@ -69,12 +79,17 @@ pub enum CliSub {
     /// Manage projects
     #[clap(subcommand)]
     Project(CliProject),
+    /// Manage project member custom roles
+    #[clap(subcommand)]
+    ProjectMember(CliProjectMember),
 
     /// Manage reports
     #[clap(subcommand)]
     Report(CliReport),
     /// Query benchmark data
     Perf(CliPerf),
+    /// Compare two reports
+    Compare(CliCompare),
     /// Manage plots
     #[clap(subcommand)]
     Plot(CliPlot),
@@ -101,6 +116,16 @@ pub enum CliSub {
     /// Manage alerts
     #[clap(subcommand)]
     Alert(CliAlert),
+    /// Manage webhooks
+    #[clap(subcommand)]
+    Webhook(CliWebhook),
+    /// Manage data retention
+    #[clap(subcommand)]
+    Retention(CliRetention),
+
+    /// View the audit log
+    #[clap(subcommand)]
+    Audit(CliAudit),
 
     /// Manage user
     #[clap(subcommand)]
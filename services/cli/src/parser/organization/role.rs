@@ -0,0 +1,104 @@
+use bencher_json::{OrganizationResourceId, ResourceName, RoleUuid};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination, project::CliProjectPermission};
+
+#[derive(Subcommand, Debug)]
+pub enum CliRole {
+    /// List organization custom roles
+    #[clap(alias = "ls")]
+    List(CliRoleList),
+    /// Create an organization custom role
+    #[clap(alias = "add")]
+    Create(CliRoleCreate),
+    /// View an organization custom role
+    #[clap(alias = "get")]
+    View(CliRoleView),
+    /// Update an organization custom role
+    #[clap(alias = "edit")]
+    Update(CliRoleUpdate),
+    /// Delete an organization custom role
+    #[clap(alias = "rm")]
+    Delete(CliRoleDelete),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRoleList {
+    /// Organization slug or UUID
+    pub organization: OrganizationResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliRolesSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliRolesSort {
+    /// Name of the role
+    Name,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRoleCreate {
+    /// Organization slug or UUID
+    pub organization: OrganizationResourceId,
+
+    /// Role name
+    #[clap(long)]
+    pub name: ResourceName,
+
+    /// Project permission granted by the role (may be repeated)
+    #[clap(value_enum, long = "permission", required = true)]
+    pub permissions: Vec<CliProjectPermission>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRoleView {
+    /// Organization slug or UUID
+    pub organization: OrganizationResourceId,
+
+    /// Role UUID
+    pub role: RoleUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRoleUpdate {
+    /// Organization slug or UUID
+    pub organization: OrganizationResourceId,
+
+    /// Role UUID
+    pub role: RoleUuid,
+
+    /// Role name
+    #[clap(long)]
+    pub name: Option<ResourceName>,
+
+    /// Project permission granted by the role (may be repeated).
+    /// If set, this replaces all of the existing permissions.
+    #[clap(value_enum, long = "permission")]
+    pub permissions: Option<Vec<CliProjectPermission>>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRoleDelete {
+    /// Organization slug or UUID
+    pub organization: OrganizationResourceId,
+
+    /// Role UUID
+    pub role: RoleUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -49,6 +49,10 @@ pub struct CliSsoCreate {
     #[clap(long)]
     pub domain: NonEmpty,
 
+    /// Allow users at the SSO domain to log in with the server OIDC provider
+    #[clap(long)]
+    pub oidc: bool,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -1,4 +1,6 @@
-use bencher_json::{BenchmarkName, BenchmarkResourceId, BenchmarkSlug, ProjectResourceId};
+use bencher_json::{
+    BenchmarkName, BenchmarkResourceId, BenchmarkSlug, ProjectResourceId, TagSelector, TagSelectors,
+};
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliArchived, CliBackend, CliPagination};
@@ -35,6 +37,10 @@ pub struct CliBenchmarkList {
     #[clap(long, value_name = "QUERY")]
     pub search: Option<String>,
 
+    /// Benchmark tag selectors (ex: `suite=parser,os=linux`)
+    #[clap(long, value_name = "KEY=VALUE,...")]
+    pub tags: Option<TagSelectors>,
+
     #[clap(flatten)]
     pub pagination: CliPagination<CliBenchmarksSort>,
 
@@ -66,6 +72,10 @@ pub struct CliBenchmarkCreate {
     #[clap(long)]
     pub slug: Option<BenchmarkSlug>,
 
+    /// Benchmark tag (ex: `suite=parser`)
+    #[clap(long, value_name = "KEY=VALUE")]
+    pub tag: Vec<TagSelector>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -98,6 +108,11 @@ pub struct CliBenchmarkUpdate {
     #[clap(long)]
     pub slug: Option<BenchmarkSlug>,
 
+    /// Benchmark tag (ex: `suite=parser`).
+    /// If any tags are given, they replace all of the existing tags.
+    #[clap(long, value_name = "KEY=VALUE")]
+    pub tag: Vec<TagSelector>,
+
     #[clap(flatten)]
     pub archived: CliArchived,
 
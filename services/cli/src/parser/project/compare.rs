@@ -0,0 +1,94 @@
+use bencher_json::{
+    Boundary, BranchNameId, GitHash, HeadUuid, ProjectResourceId, ReportUuid, TestbedNameId,
+};
+use clap::{ArgGroup, Args, Parser};
+
+use crate::parser::{CliBackend, project::threshold::CliModelTest, run::CliRunFormat};
+
+#[derive(Parser, Debug)]
+#[clap(group(
+    ArgGroup::new("baseline")
+        .required(true)
+        .multiple(false)
+        .args(&["baseline_report", "baseline_head", "baseline_branch"]),
+))]
+#[clap(group(
+    ArgGroup::new("candidate")
+        .required(true)
+        .multiple(false)
+        .args(&["candidate_report", "candidate_head", "candidate_branch"]),
+))]
+pub struct CliCompare {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Baseline report UUID
+    #[clap(long, value_name = "REPORT")]
+    pub baseline_report: Option<ReportUuid>,
+
+    /// Baseline branch head UUID.
+    /// The most recent report for the branch head is used.
+    #[clap(long, value_name = "HEAD")]
+    pub baseline_head: Option<HeadUuid>,
+
+    /// Baseline branch name, slug, or UUID.
+    /// The most recent report for the current branch head is used.
+    #[clap(long, value_name = "BRANCH")]
+    pub baseline_branch: Option<BranchNameId>,
+
+    /// Baseline `git` commit hash.
+    /// The most recent report for this hash on the baseline branch or branch head is used.
+    #[clap(long, value_name = "HASH", conflicts_with = "baseline_report")]
+    pub baseline_hash: Option<GitHash>,
+
+    /// Candidate report UUID
+    #[clap(long, value_name = "REPORT")]
+    pub candidate_report: Option<ReportUuid>,
+
+    /// Candidate branch head UUID.
+    /// The most recent report for the branch head is used.
+    #[clap(long, value_name = "HEAD")]
+    pub candidate_head: Option<HeadUuid>,
+
+    /// Candidate branch name, slug, or UUID.
+    /// The most recent report for the current branch head is used.
+    #[clap(long, value_name = "BRANCH")]
+    pub candidate_branch: Option<BranchNameId>,
+
+    /// Candidate `git` commit hash.
+    /// The most recent report for this hash on the candidate branch or branch head is used.
+    #[clap(long, value_name = "HASH", conflicts_with = "candidate_report")]
+    pub candidate_hash: Option<GitHash>,
+
+    /// Testbed name, slug, or UUID.
+    /// Used to select the most recent report for a branch or branch head.
+    #[clap(long)]
+    pub testbed: Option<TestbedNameId>,
+
+    #[clap(flatten)]
+    pub model: CliCompareModel,
+
+    /// Format for the comparison
+    #[clap(long, default_value = "human")]
+    pub format: CliRunFormat,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Args, Debug)]
+pub struct CliCompareModel {
+    /// Test used to determine if a change is significant (default `percentage`)
+    #[clap(value_enum, long)]
+    pub test: Option<CliModelTest>,
+
+    /// Lower boundary.
+    /// A significant decrease is a regression.
+    #[clap(long, value_name = "BOUNDARY")]
+    pub lower_boundary: Option<Boundary>,
+
+    /// Upper boundary (default depends on the test if neither boundary is set).
+    /// A significant increase is a regression.
+    #[clap(long, value_name = "BOUNDARY")]
+    pub upper_boundary: Option<Boundary>,
+}
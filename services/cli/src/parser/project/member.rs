@@ -0,0 +1,79 @@
+use bencher_json::{ProjectResourceId, RoleUuid, UserResourceId};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliProjectMember {
+    /// List project members with a custom role
+    #[clap(alias = "ls")]
+    List(CliProjectMemberList),
+    /// Assign a custom role to a project member
+    #[clap(alias = "add")]
+    Assign(CliProjectMemberAssign),
+    /// View the custom role for a project member
+    #[clap(alias = "get")]
+    View(CliProjectMemberView),
+    /// Remove the custom role from a project member
+    #[clap(alias = "rm")]
+    Remove(CliProjectMemberRemove),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectMemberList {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliProjectMembersSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliProjectMembersSort {
+    /// Name of the member
+    Name,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectMemberAssign {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// User slug or UUID
+    pub user: UserResourceId,
+
+    /// Custom role UUID
+    #[clap(long)]
+    pub role: RoleUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectMemberView {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// User slug or UUID
+    pub user: UserResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectMemberRemove {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// User slug or UUID
+    pub user: UserResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
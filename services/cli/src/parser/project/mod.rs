@@ -1,4 +1,5 @@
 use bencher_json::{OrganizationResourceId, ProjectResourceId, ProjectSlug, ResourceName, Url};
+use camino::Utf8PathBuf;
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::CliBackend;
@@ -9,13 +10,17 @@ pub mod alert;
 pub mod archive;
 pub mod benchmark;
 pub mod branch;
+pub mod compare;
 pub mod measure;
+pub mod member;
 pub mod metric;
 pub mod perf;
 pub mod plot;
 pub mod report;
+pub mod retention;
 pub mod testbed;
 pub mod threshold;
+pub mod webhook;
 
 #[derive(Subcommand, Debug)]
 pub enum CliProject {
@@ -35,6 +40,10 @@ pub enum CliProject {
     Delete(CliProjectDelete),
     /// Check project permission
     Allowed(CliProjectAllowed),
+    /// Export all of the data for a project
+    Export(CliProjectExport),
+    /// Import a project exported from a Bencher server
+    Import(CliProjectImport),
 }
 
 #[derive(Parser, Debug)]
@@ -129,6 +138,10 @@ pub struct CliProjectUpdate {
     #[clap(long)]
     pub visibility: Option<CliProjectVisibility>,
 
+    /// Enable or disable the `OpenMetrics` endpoint for the project
+    #[clap(long)]
+    pub openmetrics: Option<bool>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -155,6 +168,41 @@ pub struct CliProjectAllowed {
     pub backend: CliBackend,
 }
 
+#[derive(Parser, Debug)]
+pub struct CliProjectExport {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// File path to save the project archive.
+    /// If not set, the project archive is printed to stdout.
+    #[clap(long, value_name = "PATH")]
+    pub output: Option<Utf8PathBuf>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliProjectImport {
+    /// Organization slug or UUID
+    pub organization: OrganizationResourceId,
+
+    /// File path to a project archive created by `bencher project export`
+    #[clap(long, value_name = "PATH")]
+    pub file: Utf8PathBuf,
+
+    /// Project name, if the project is created
+    #[clap(long)]
+    pub name: Option<ResourceName>,
+
+    /// Project slug, if the project is created
+    #[clap(long)]
+    pub slug: Option<ProjectSlug>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
 /// Project permission
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
@@ -164,6 +212,7 @@ pub enum CliProjectPermission {
     Edit,
     Delete,
     Manage,
+    CreateReport,
     ViewRole,
     CreateRole,
     EditRole,
@@ -1,5 +1,6 @@
 use bencher_json::{
-    BenchmarkUuid, BranchUuid, DateTime, HeadUuid, MeasureUuid, ProjectResourceId, TestbedUuid,
+    BenchmarkUuid, BranchUuid, DateTime, HeadUuid, MeasureUuid, ProjectResourceId, TagSelectors,
+    TestbedUuid,
 };
 use clap::{Parser, ValueEnum};
 
@@ -25,9 +26,14 @@ pub struct CliPerf {
     pub testbeds: Vec<TestbedUuid>,
 
     /// Benchmark UUIDs
-    #[clap(long, required = true, value_name = "BENCHMARK")]
+    #[clap(long, required_unless_present = "tags", value_name = "BENCHMARK")]
     pub benchmarks: Vec<BenchmarkUuid>,
 
+    /// Benchmark tag selectors (ex: `suite=parser,os=linux`).
+    /// Every benchmark that matches all of the tag selectors is included.
+    #[clap(long, value_name = "KEY=VALUE,...")]
+    pub tags: Option<TagSelectors>,
+
     /// Measure UUIDs
     #[clap(long, required = true, value_name = "MEASURE")]
     pub measures: Vec<MeasureUuid>,
@@ -40,14 +46,75 @@ pub struct CliPerf {
     #[clap(long, value_name = "SECONDS")]
     pub end_time: Option<DateTime>,
 
-    /// Output results in a table
+    /// Downsample the metrics into buckets of this size.
+    /// If not set, time windows longer than 90 days are downsampled by day
+    /// and time windows longer than two years are downsampled by week.
+    #[clap(long, conflicts_with = "no_bucket")]
+    pub bucket: Option<CliPerfBucket>,
+
+    /// Do not downsample the metrics, even for long time windows
+    #[clap(long)]
+    pub no_bucket: bool,
+
+    /// The aggregation used for each bucket (default: mean)
     #[clap(long)]
+    pub aggregate: Option<CliPerfAggregate>,
+
+    /// Output results in a table
+    #[clap(long, conflicts_with = "format")]
     pub table: Option<Option<CliPerfTableStyle>>,
 
+    /// Output format (default: json).
+    /// CSV and Parquet output one row per metric and are streamed to stdout.
+    #[clap(long)]
+    pub format: Option<CliPerfFormat>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+/// Perf bucket sizes
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliPerfBucket {
+    /// One bucket per hour
+    Hour,
+    /// One bucket per day
+    Day,
+    /// One bucket per week
+    Week,
+    /// One bucket per branch version
+    Version,
+}
+
+/// Perf bucket aggregations
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliPerfAggregate {
+    /// Mean
+    Mean,
+    /// Median
+    Median,
+    /// Minimum
+    Min,
+    /// Maximum
+    Max,
+    /// 90th percentile
+    P90,
+}
+
+/// Perf output formats
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliPerfFormat {
+    /// JSON
+    Json,
+    /// Comma separated values
+    Csv,
+    /// Apache Parquet
+    Parquet,
+}
+
 /// Supported Table Formats
 #[derive(ValueEnum, Debug, Clone)]
 #[clap(rename_all = "snake_case")]
@@ -1,6 +1,6 @@
 use bencher_json::{
     BenchmarkUuid, BranchUuid, Index, MeasureUuid, PlotUuid, ProjectResourceId, ResourceName,
-    TestbedUuid, Window,
+    TagSelectors, TestbedUuid, Window,
 };
 use clap::{Parser, Subcommand, ValueEnum};
 
@@ -106,10 +106,19 @@ pub struct CliPlotCreate {
     pub testbeds: Vec<TestbedUuid>,
 
     /// The benchmarks to include in the plot.
-    /// At least one benchmark must be specified.
-    #[clap(long, required = true, value_name = "BENCHMARK")]
+    /// At least one benchmark must be specified, unless `--benchmark-tags` is provided.
+    #[clap(
+        long,
+        required_unless_present = "benchmark_tags",
+        value_name = "BENCHMARK"
+    )]
     pub benchmarks: Vec<BenchmarkUuid>,
 
+    /// The benchmark tag selectors for the plot (ex: `suite=parser,os=linux`).
+    /// All benchmarks that match every selector are included in the plot.
+    #[clap(long, value_name = "KEY=VALUE,...")]
+    pub benchmark_tags: Option<TagSelectors>,
+
     /// The measures to include in the plot.
     /// At least one measure must be specified.
     #[clap(long, required = true, value_name = "MEASURE")]
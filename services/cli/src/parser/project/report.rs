@@ -1,6 +1,6 @@
 use bencher_json::{
-    Boundary, BranchNameId, DateTime, GitHash, MeasureNameId, ProjectResourceId, ReportUuid,
-    SampleSize, TestbedNameId, Window,
+    Boundary, BranchNameId, DateTime, GitHash, MeasureNameId, ProjectResourceId, ReportGroupUuid,
+    ReportUuid, SampleSize, TestbedNameId, Window,
 };
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
@@ -44,6 +44,10 @@ pub struct CliReportList {
     #[clap(long, value_name = "SECONDS")]
     pub end_time: Option<DateTime>,
 
+    /// Report group UUID
+    #[clap(long)]
+    pub group: Option<ReportGroupUuid>,
+
     #[clap(flatten)]
     pub pagination: CliPagination<CliReportsSort>,
 
@@ -109,6 +113,10 @@ pub struct CliReportCreate {
     #[clap(value_enum, long)]
     pub fold: Option<CliReportFold>,
 
+    /// Report group UUID, to link reports that were generated together
+    #[clap(long)]
+    pub group: Option<ReportGroupUuid>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -164,6 +172,8 @@ pub enum CliReportAdapter {
     Magic,
     /// {...} JSON
     Json,
+    /// 🔧 Custom (requires an adapter spec)
+    Custom,
     // TODO remove in due time
     #[clap(hide = true)]
     CSharp,
@@ -224,6 +234,8 @@ pub enum CliReportAdapter {
     Shell,
     /// ❯_ Shell Hyperfine
     ShellHyperfine,
+    /// ❯_ Shell perf stat
+    ShellPerfStat,
 }
 
 /// Suggested Central Tendency (Average)
@@ -0,0 +1,56 @@
+use std::num::NonZeroU32;
+
+use bencher_json::ProjectResourceId;
+use clap::{Parser, Subcommand};
+
+use crate::parser::CliBackend;
+
+#[derive(Subcommand, Debug)]
+pub enum CliRetention {
+    /// View the retention policy for a project
+    View(CliRetentionView),
+    /// Update the retention policy for a project
+    #[clap(alias = "edit")]
+    Update(CliRetentionUpdate),
+    /// Count the data that would be deleted by the retention policy
+    DryRun(CliRetentionDryRun),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRetentionView {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRetentionUpdate {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Number of days to keep reports.
+    /// Reports for a version used as a start point or with an active alert are always kept.
+    /// If not set, reports are kept forever.
+    #[clap(long, value_name = "DAYS")]
+    pub reports: Option<NonZeroU32>,
+
+    /// Number of days to keep archived branches.
+    /// Archived branches used as a start point or with an active alert are always kept.
+    /// If not set, archived branches are kept forever.
+    #[clap(long, value_name = "DAYS")]
+    pub archived_branches: Option<NonZeroU32>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliRetentionDryRun {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
@@ -1,6 +1,6 @@
 use bencher_json::{
-    Boundary, BranchNameId, MeasureNameId, ModelUuid, ProjectResourceId, SampleSize, TestbedNameId,
-    ThresholdUuid, Window,
+    Boundary, BranchNameId, MeasureNameId, ModelUuid, ProjectResourceId, SampleSize, TagSelectors,
+    TestbedNameId, ThresholdUuid, Window,
 };
 use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
@@ -79,6 +79,10 @@ pub struct CliThresholdCreate {
     #[clap(long)]
     pub measure: MeasureNameId,
 
+    /// Only apply the threshold to benchmarks that match every tag selector (ex: `suite=parser`)
+    #[clap(long, value_name = "KEY=VALUE,...")]
+    pub benchmark_tags: Option<TagSelectors>,
+
     #[clap(flatten)]
     pub model: CliModel,
 
@@ -144,12 +148,16 @@ pub enum CliModelTest {
     /// t-test (normal distribution)
     #[clap(alias = "t")]
     TTest,
+    /// Welch's t-test (all iterations in a report as one sample)
+    WelchTTest,
     /// Log normal distribution
     LogNormal,
     /// Interquartile range (IQR)
     Iqr,
     /// Delta interquartile range (ΔIQR)
     DeltaIqr,
+    /// Mann-Whitney U (rank-based nonparametric)
+    MannWhitney,
 }
 
 #[derive(Parser, Debug)]
@@ -0,0 +1,88 @@
+use bencher_json::{ProjectResourceId, Secret, Url, WebhookUuid};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::parser::{CliBackend, CliPagination};
+
+#[derive(Subcommand, Debug)]
+pub enum CliWebhook {
+    /// List webhooks
+    #[clap(alias = "ls")]
+    List(CliWebhookList),
+    /// Create a webhook
+    #[clap(alias = "add")]
+    Create(CliWebhookCreate),
+    /// Update a webhook
+    #[clap(alias = "edit")]
+    Update(CliWebhookUpdate),
+    /// Delete a webhook
+    #[clap(alias = "rm")]
+    Delete(CliWebhookDelete),
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookList {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    #[clap(flatten)]
+    pub pagination: CliPagination<CliWebhooksSort>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+#[clap(rename_all = "snake_case")]
+pub enum CliWebhooksSort {
+    /// Creation date time of the webhook
+    Created,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookCreate {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// The URL to send webhook events to
+    #[clap(long)]
+    pub url: Url,
+
+    /// The secret used to sign webhook events with HMAC-SHA256
+    #[clap(long)]
+    pub secret: Secret,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookUpdate {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    /// The new URL to send webhook events to
+    #[clap(long)]
+    pub url: Option<Url>,
+
+    /// The new secret used to sign webhook events with HMAC-SHA256
+    #[clap(long)]
+    pub secret: Option<Secret>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
+
+#[derive(Parser, Debug)]
+pub struct CliWebhookDelete {
+    /// Project slug or UUID
+    pub project: ProjectResourceId,
+
+    /// Webhook UUID
+    pub webhook: WebhookUuid,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
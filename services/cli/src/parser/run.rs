@@ -25,6 +25,10 @@ pub struct CliRun {
     #[clap(value_enum, long, env = "BENCHER_ADAPTER", default_value = "magic")]
     pub adapter: CliReportAdapter,
 
+    /// Custom adapter spec, as inline JSON or a path to a JSON file (requires: `--adapter custom`)
+    #[clap(long, value_name = "SPEC")]
+    pub adapter_spec: Option<String>,
+
     /// Benchmark harness suggested central tendency (ie average)
     #[clap(value_enum, long)]
     pub average: Option<CliReportAverage>,
@@ -63,10 +67,28 @@ pub struct CliRun {
     #[clap(flatten)]
     pub cmd: CliRunCommand,
 
+    /// Run a matrix of benchmark variants, as inline JSON or a path to a JSON file.
+    /// Each variant has its own `testbed`, shell `command`, and optional `env` variables.
+    /// The variants are run in either `sequential` (default) or `interleaved` `order`.
+    /// One Report is created for each variant, and the Reports are linked together as a group.
+    #[clap(
+        long,
+        value_name = "MATRIX",
+        conflicts_with_all = ["testbed", "command", "exec", "file", "file_size", "build_time"]
+    )]
+    pub matrix: Option<String>,
+
     /// Do a dry run (no data is saved)
     #[clap(long)]
     pub dry_run: bool,
 
+    /// Save all data to a local database file instead of the backend host.
+    /// The file is created if it does not exist.
+    /// Thresholds are evaluated against the local history, the same as with the backend host.
+    /// Use `bencher upload` to later send the local reports to the backend host.
+    #[clap(long, value_name = "PATH", conflicts_with = "dry_run")]
+    pub local: Option<Utf8PathBuf>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
@@ -83,6 +105,7 @@ pub struct CliRunProject {
 }
 
 #[derive(Args, Debug)]
+#[expect(clippy::struct_excessive_bools)]
 pub struct CliRunBranch {
     /// Branch name, slug, or UUID.
     /// If a name or slug is provided, the branch will be created if it does not exist.
@@ -106,6 +129,12 @@ pub struct CliRunBranch {
     #[clap(long, alias = "branch-start-point-hash", requires = "start_point")]
     pub start_point_hash: Option<GitHash>,
 
+    /// Use the `git` merge-base between `HEAD` and the start point branch as the start point hash (requires: `--branch-start-point`).
+    /// If the start point branch does not have data for the merge-base,
+    /// then the nearest first-parent ancestor with data is used instead.
+    #[clap(long, requires = "start_point", conflicts_with = "start_point_hash")]
+    pub start_point_merge_base: bool,
+
     /// The maximum number of historical branch versions to include (requires: `--branch-start-point`).
     /// Versions beyond this number will be omitted.
     #[clap(long, requires = "start_point", default_value = "255")]
@@ -167,7 +196,7 @@ pub struct CliRunCommand {
     pub command: Option<Vec<String>>,
 }
 
-#[derive(Args, Debug)]
+#[derive(Args, Debug, Clone)]
 pub struct CliRunShell {
     /// Shell command path
     #[clap(long)]
@@ -205,25 +234,28 @@ pub enum CliRunFormat {
 #[clap(group(
     ArgGroup::new("ci_cd")
         .multiple(false)
-        .args(&["github_actions"]),
+        .args(&["github_actions", "gitlab_ci"]),
 ))]
 pub struct CliRunCi {
     /// GitHub API authentication token for GitHub Actions to comment on PRs (ie `--github-actions ${{ secrets.GITHUB_TOKEN }}`)
     #[clap(long)]
     pub github_actions: Option<String>,
-    /// Only post results to CI if a Threshold exists for the Branch, Testbed, and Measure (requires: `--github-actions`)
+    /// GitLab API access token for GitLab CI/CD to comment on merge requests (ie `--gitlab-ci $GITLAB_TOKEN`)
+    #[clap(long)]
+    pub gitlab_ci: Option<String>,
+    /// Only post results to CI if a Threshold exists for the Branch, Testbed, and Measure (requires: `--github-actions` or `--gitlab-ci`)
     #[clap(long, requires = "ci_cd")]
     pub ci_only_thresholds: bool,
-    /// Only start posting results to CI if an Alert is generated (requires: `--github-actions`)
+    /// Only start posting results to CI if an Alert is generated (requires: `--github-actions` or `--gitlab-ci`)
     #[clap(long, requires = "ci_cd")]
     pub ci_only_on_alert: bool,
-    /// All links should be to public URLs that do not require a login (requires: `--github-actions`)
+    /// All links should be to public URLs that do not require a login (requires: `--github-actions` or `--gitlab-ci`)
     #[clap(long, requires = "ci_cd")]
     pub ci_public_links: bool,
-    /// Custom ID for posting results to CI (requires: `--github-actions`)
+    /// Custom ID for posting results to CI (requires: `--github-actions` or `--gitlab-ci`)
     #[clap(long, requires = "ci_cd")]
     pub ci_id: Option<String>,
-    /// Issue number for posting results to CI (requires: `--github-actions`)
+    /// Issue number for posting results to CI (requires: `--github-actions` or `--gitlab-ci`)
     #[clap(long, requires = "ci_cd")]
     pub ci_number: Option<u64>,
     /// CAUTION: Override safety checks and accept that you are vulnerable to pwn requests (requires: `--github-actions`)
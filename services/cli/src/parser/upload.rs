@@ -0,0 +1,36 @@
+use bencher_json::{BranchNameId, DateTime, ProjectResourceId, TestbedNameId};
+use camino::Utf8PathBuf;
+use clap::Parser;
+
+use crate::parser::CliBackend;
+
+#[derive(Parser, Debug)]
+pub struct CliUpload {
+    /// Local database file created by `bencher run --local`
+    #[clap(long, value_name = "PATH")]
+    pub local: Utf8PathBuf,
+
+    /// Project slug or UUID.
+    /// The reports are uploaded to the project with the same slug on the backend host.
+    #[clap(long, env = "BENCHER_PROJECT")]
+    pub project: ProjectResourceId,
+
+    /// Only upload reports for this local branch name, slug, or UUID
+    #[clap(long)]
+    pub branch: Option<BranchNameId>,
+
+    /// Only upload reports for this local testbed name, slug, or UUID
+    #[clap(long)]
+    pub testbed: Option<TestbedNameId>,
+
+    /// Only upload reports started at or after this time (seconds since epoch)
+    #[clap(long, value_name = "SECONDS")]
+    pub start_time: Option<DateTime>,
+
+    /// Only upload reports ended at or before this time (seconds since epoch)
+    #[clap(long, value_name = "SECONDS")]
+    pub end_time: Option<DateTime>,
+
+    #[clap(flatten)]
+    pub backend: CliBackend,
+}
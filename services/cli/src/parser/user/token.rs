@@ -1,4 +1,4 @@
-use bencher_json::{ResourceName, TokenUuid, UserResourceId};
+use bencher_json::{OrganizationUuid, ProjectUuid, ResourceName, TokenUuid, UserResourceId};
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::parser::{CliBackend, CliPagination};
@@ -59,10 +59,31 @@ pub struct CliTokenCreate {
     #[clap(long)]
     pub ttl: Option<u32>,
 
+    /// Only allow the token to access these organizations and their projects.
+    #[clap(long, value_name = "ORGANIZATION")]
+    pub organizations: Vec<OrganizationUuid>,
+
+    /// Only allow the token to access these projects.
+    #[clap(long, value_name = "PROJECT")]
+    pub projects: Vec<ProjectUuid>,
+
+    /// The most permissive action the token is allowed to take.
+    #[clap(value_enum, long)]
+    pub permission: Option<CliTokenPermission>,
+
     #[clap(flatten)]
     pub backend: CliBackend,
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy)]
+#[clap(rename_all = "snake_case")]
+pub enum CliTokenPermission {
+    /// Read-only access
+    Read,
+    /// Read access and the ability to create reports
+    Report,
+}
+
 #[derive(Parser, Debug)]
 pub struct CliTokenView {
     /// User slug or UUID
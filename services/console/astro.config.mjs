@@ -85,6 +85,12 @@ export default defineConfig({
 				access: SECRET,
 				optional: true,
 			}),
+			// https://openid.net/specs/openid-connect-core-1_0.html
+			OAUTH_OIDC: envField.string({
+				context: SERVER,
+				access: SECRET,
+				optional: true,
+			}),
 		},
 	},
 	// Do not use any trailing slashes in the paths below
@@ -43,6 +43,7 @@ export const adapterIcon = (adapter: Adapter) => {
 		case Adapter.RubyBenchmark:
 			return RUBY_ICON;
 		case Adapter.ShellHyperfine:
+		case Adapter.ShellPerfStat:
 			return SHELL_ICON;
 		default:
 			console.log(`Unsupported adapter: ${adapter}`);
@@ -81,6 +82,8 @@ export const adapterCommand = (isConsole: boolean, adapter: null | Adapter) => {
 			return `bencher run${host} "ruby benchmarks.rb"`;
 		case Adapter.ShellHyperfine:
 			return `bencher run${host} --file results.json "hyperfine --export-json results.json 'sleep 0.1'"`;
+		case Adapter.ShellPerfStat:
+			return `bencher run${host} --file perf.txt "perf stat -o perf.txt ./benchmark"`;
 		// biome-ignore lint/complexity/noUselessSwitchCase: code as docs
 		case Adapter.Json:
 		default:
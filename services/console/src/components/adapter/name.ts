@@ -6,6 +6,8 @@ export const adapterName = (adapter: Adapter): string => {
 			return "Magic";
 		case Adapter.Json:
 			return "JSON";
+		case Adapter.Custom:
+			return "Custom";
 		case Adapter.Rust:
 			return "Rust";
 		case Adapter.RustBench:
@@ -54,6 +56,8 @@ export const adapterName = (adapter: Adapter): string => {
 			return "Shell";
 		case Adapter.ShellHyperfine:
 			return "Hyperfine";
+		case Adapter.ShellPerfStat:
+			return "perf stat";
 	}
 };
 
@@ -88,6 +92,8 @@ export const adapterCommand = (isConsole: boolean, adapter: null | Adapter) => {
 			return `bencher run${host} "ruby benchmarks.rb"`;
 		case Adapter.ShellHyperfine:
 			return `bencher run${host} --file results.json "hyperfine --export-json results.json 'sleep 0.1'"`;
+		case Adapter.ShellPerfStat:
+			return `bencher run${host} --file perf.txt "perf stat -o perf.txt ./benchmark"`;
 		// biome-ignore lint/complexity/noUselessSwitchCase: code as docs
 		case Adapter.Json:
 		default:
@@ -8,6 +8,7 @@ interface Props {
 	newUser: boolean;
 	github: undefined | string;
 	google: undefined | string;
+	oidc: undefined | string;
 }
 
 const OAuthForm = (props: Props) => {
@@ -45,6 +46,21 @@ const OAuthForm = (props: Props) => {
 					<span>{props.newUser ? "Sign up" : "Log in"} with Google</span>
 				</button>
 			</Show>
+			<Show when={props.oidc === "true"}>
+				<button
+					type="button"
+					class="button is-fullwidth"
+					style="margin-top: 1rem;"
+					onMouseDown={() =>
+						authPath(props.apiUrl, "/v0/auth/oidc", searchParams)
+					}
+				>
+					<span class="icon">
+						<i class="fas fa-key" />
+					</span>
+					<span>{props.newUser ? "Sign up" : "Log in"} with SSO</span>
+				</button>
+			</Show>
 		</>
 	);
 };
@@ -380,6 +380,8 @@ const AdapterCard = (props: Props) => {
 						return "-rust-iai-callgrind";
 					case Adapter.ShellHyperfine:
 						return "_%EF%B8%8F-shell-hyperfine";
+					case Adapter.ShellPerfStat:
+						return "_%EF%B8%8F-shell-perf-stat";
 					default:
 						return "";
 				}
@@ -425,6 +427,8 @@ const AdapterCard = (props: Props) => {
 							return "Rust Iai-Callgrind";
 						case Adapter.ShellHyperfine:
 							return "Shell Hyperfine";
+						case Adapter.ShellPerfStat:
+							return "Shell perf stat";
 						default:
 							return `${props.value}`;
 					}
@@ -133,6 +133,10 @@ export const fmtModelTest = (test: ModelTest) => {
 			return "Interquartile Range (IQR)";
 		case ModelTest.DeltaIqr:
 			return "Delta Interquartile Range (ΔIQR)";
+		case ModelTest.MannWhitney:
+			return "Mann-Whitney U";
+		case ModelTest.WelchTTest:
+			return "Welch's t-test";
 		default:
 			return "No Model";
 	}
@@ -166,6 +170,14 @@ const testValue = (selected: ModelTest) => {
 				value: ModelTest.DeltaIqr,
 				option: fmtModelTest(ModelTest.DeltaIqr),
 			},
+			{
+				value: ModelTest.MannWhitney,
+				option: fmtModelTest(ModelTest.MannWhitney),
+			},
+			{
+				value: ModelTest.WelchTTest,
+				option: fmtModelTest(ModelTest.WelchTTest),
+			},
 			{
 				value: ModelTest.Static,
 				option: fmtModelTest(ModelTest.Static),
@@ -220,6 +232,10 @@ export const testFragment = (modelTest: ModelTest) => {
 			return "interquartile-range";
 		case ModelTest.DeltaIqr:
 			return "delta-interquartile-range";
+		case ModelTest.MannWhitney:
+			return "mann-whitney-u";
+		case ModelTest.WelchTTest:
+			return "welchs-t-test";
 	}
 };
 
@@ -363,6 +379,8 @@ const FIELDS = {
 	[ModelTest.LogNormal]: cdfConfig(ModelTest.LogNormal),
 	[ModelTest.Iqr]: iqrConfig(ModelTest.Iqr),
 	[ModelTest.DeltaIqr]: iqrConfig(ModelTest.DeltaIqr),
+	[ModelTest.MannWhitney]: cdfConfig(ModelTest.MannWhitney),
+	[ModelTest.WelchTTest]: cdfConfig(ModelTest.WelchTTest),
 };
 
 const initForm = (fields: object[]) => {
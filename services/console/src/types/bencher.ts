@@ -53,6 +53,34 @@ export interface JsonNewMetric {
 
 export type JsonMetricsMap = Record<MeasureNameId, JsonNewMetric>;
 
+export interface JsonAdapterSpecUnit {
+	/** The measure for metrics with this unit. */
+	measure: MeasureNameId;
+	/**
+	 * The factor to multiply the metric value, lower value, and upper value by.
+	 * For example, use `1000000` to convert from milliseconds to nanoseconds.
+	 */
+	scale?: number;
+}
+
+export interface JsonAdapterSpec {
+	/**
+	 * A regular expression that is applied to each line of the benchmark results.
+	 * It must have a `name` named capture group for the benchmark name
+	 * and a `value` named capture group for the metric value.
+	 * It may also have `lower_value`, `upper_value`, and `unit` named capture groups.
+	 * Lines that do not match are ignored.
+	 */
+	regex: string;
+	/**
+	 * The measure for metrics without a `unit` that is in `units`.
+	 * If no measure is specified, then `latency` will be used.
+	 */
+	measure?: MeasureNameId;
+	/** A map from the captured `unit` to its measure and scale. */
+	units?: Record<string, JsonAdapterSpecUnit>;
+}
+
 export interface JsonBenchmark {
 	uuid: Uuid;
 	project: Uuid;
@@ -61,6 +89,11 @@ export interface JsonBenchmark {
 	created: string;
 	modified: string;
 	archived?: string;
+	/**
+	 * The key/value tags for the benchmark.
+	 * Only included when the benchmark is returned by the benchmark endpoints.
+	 */
+	tags?: Record<TagKey, TagValue>;
 }
 
 export interface JsonMetric {
@@ -133,6 +166,8 @@ export enum ModelTest {
 	LogNormal = "log_normal",
 	Iqr = "iqr",
 	DeltaIqr = "delta_iqr",
+	MannWhitney = "mann_whitney",
+	WelchTTest = "welch_t_test",
 }
 
 export type SampleSize = number;
@@ -157,6 +192,7 @@ export interface JsonThreshold {
 	branch: JsonBranch;
 	testbed: JsonTestbed;
 	measure: JsonMeasure;
+	benchmark_tags?: string;
 	model?: JsonModel;
 	created: string;
 	modified: string;
@@ -198,6 +234,23 @@ export interface JsonAlert {
 
 export type JsonReportAlerts = JsonAlert[];
 
+export interface JsonChangePoint {
+	uuid: Uuid;
+	/** The report where the change in performance was first detected. */
+	report: Uuid;
+	/** The report iteration where the change in performance was first detected. */
+	iteration: Iteration;
+	/** The version where the change in performance was first detected. */
+	version: JsonVersion;
+	benchmark: JsonBenchmark;
+	threshold: JsonThreshold;
+	/** The mean of the metrics before the change point. */
+	baseline: number;
+	/** The mean of the metrics after the change point. */
+	value: number;
+	created: string;
+}
+
 export interface JsonThresholdModel {
 	uuid: Uuid;
 	project: Uuid;
@@ -249,6 +302,10 @@ export type Secret = string;
 
 export type Slug = string;
 
+export type TagKey = string;
+
+export type TagValue = string;
+
 /** A testbed UUID, slug, or name. */
 export type TestbedNameId = Uuid | Slug | string;
 
@@ -286,6 +343,7 @@ export interface JsonAuthUser {
 	token: Jwt;
 	creation: string;
 	expiration: string;
+	scope?: JsonTokenScope;
 }
 
 export enum CardBrand {
@@ -424,9 +482,15 @@ export interface JsonNewPlot {
 	testbeds: Uuid[];
 	/**
 	 * The benchmarks to include in the plot.
-	 * At least one benchmark must be specified.
+	 * At least one benchmark must be specified, unless `benchmark_tags` is provided.
 	 */
 	benchmarks: Uuid[];
+	/**
+	 * The benchmark tag selectors for the plot.
+	 * All benchmarks that match every selector are included in the plot,
+	 * including benchmarks that are created after the plot.
+	 */
+	benchmark_tags?: string;
 	/**
 	 * The measures to include in the plot.
 	 * At least one measure must be specified.
@@ -470,6 +534,37 @@ export interface JsonNewSso {
 	 * For example: example.com
 	 */
 	domain: NonEmpty;
+	/**
+	 * Allow users with an email at the SSO domain
+	 * to log in with the OIDC provider configured for the server.
+	 * Defaults to `false`.
+	 */
+	oidc?: boolean;
+}
+
+export enum TokenPermission {
+	/** Read-only access */
+	Read = "read",
+	/** Read access and the ability to create reports */
+	Report = "report",
+}
+
+export interface JsonTokenScope {
+	/**
+	 * Only allow access to these organizations and their projects.
+	 * If not provided, the token is not restricted to any organizations.
+	 */
+	organizations?: Uuid[];
+	/**
+	 * Only allow access to these projects.
+	 * If not provided, the token is not restricted to any projects.
+	 */
+	projects?: Uuid[];
+	/**
+	 * The most permissive action that the token is allowed to take.
+	 * If not provided, the token is not restricted to any permission.
+	 */
+	permission?: TokenPermission;
 }
 
 export interface JsonNewToken {
@@ -483,6 +578,22 @@ export interface JsonNewToken {
 	 * If not provided, the token will not expire for over 128 years.
 	 */
 	ttl?: number;
+	/**
+	 * Restrict what the token is allowed to access.
+	 * If not provided, the token has all of the permissions of its user.
+	 */
+	scope?: JsonTokenScope;
+}
+
+export interface JsonNewWebhook {
+	/** The URL to send webhook events to. */
+	url: Url;
+	/**
+	 * The secret used to sign webhook events.
+	 * The HMAC-SHA256 signature of the request body is sent in the `X-Bencher-Signature` header,
+	 * formatted as `sha256=<hex digest>`.
+	 */
+	secret: Secret;
 }
 
 export interface JsonOAuth {
@@ -525,6 +636,7 @@ export interface JsonOneMetric {
 export interface JsonSso {
 	uuid: Uuid;
 	domain: NonEmpty;
+	oidc: boolean;
 	created: string;
 }
 
@@ -546,11 +658,44 @@ export interface JsonProject {
 	slug: Slug;
 	url?: Url;
 	visibility: Visibility;
+	openmetrics: boolean;
 	created: string;
 	modified: string;
 	claimed?: string;
 }
 
+export interface JsonProjectMember {
+	/** The member UUID. */
+	uuid: Uuid;
+	/** The member user name. */
+	name: UserName;
+	/** The member slug. */
+	slug: Slug;
+	/** The member email. */
+	email: Email;
+	/** The custom organization role assigned to the member for the project. */
+	role: Uuid;
+	/** The date time the role was assigned. */
+	created: string;
+	/** The date time the role assignment was last modified. */
+	modified: string;
+}
+
+/**
+ * The envelope of the metrics that were aggregated into a single bucket.
+ * The other fields of the perf metric are from the latest metric in the bucket.
+ */
+export interface JsonPerfBucket {
+	/** The number of metrics in the bucket. */
+	count: number;
+	/** The start time of the earliest report in the bucket. */
+	start_time: string;
+	/** The minimum metric value in the bucket. */
+	min: number;
+	/** The maximum metric value in the bucket. */
+	max: number;
+}
+
 export interface JsonPerfMetric {
 	report: Uuid;
 	iteration: Iteration;
@@ -561,6 +706,7 @@ export interface JsonPerfMetric {
 	threshold?: JsonThresholdModel;
 	boundary?: JsonBoundary;
 	alert?: JsonPerfAlert;
+	bucket?: JsonPerfBucket;
 }
 
 export interface JsonPerfMetrics {
@@ -575,9 +721,66 @@ export interface JsonPerf {
 	project: JsonProject;
 	start_time?: string;
 	end_time?: string;
+	bucket?: PerfBucket;
+	aggregate?: PerfAggregate;
 	results: JsonPerfMetrics[];
 }
 
+export interface JsonCompareReport {
+	uuid: Uuid;
+	branch: JsonBranch;
+	testbed: JsonTestbed;
+	start_time: string;
+	end_time: string;
+}
+
+export enum CompareVerdict {
+	/** The candidate is significantly better than the baseline. */
+	Improved = "improved",
+	/** The candidate is significantly worse than the baseline. */
+	Regressed = "regressed",
+	/** There is no significant difference between the baseline and the candidate. */
+	Unchanged = "unchanged",
+	/** The benchmark measure is only in the candidate. */
+	Added = "added",
+	/** The benchmark measure is only in the baseline. */
+	Removed = "removed",
+}
+
+export interface JsonCompareResult {
+	benchmark: JsonBenchmark;
+	measure: JsonMeasure;
+	/** The mean of the baseline metrics across all report iterations. */
+	baseline?: number;
+	/** The mean of the candidate metrics across all report iterations. */
+	candidate?: number;
+	/** The absolute change from the baseline to the candidate. */
+	delta?: number;
+	/** The relative change from the baseline to the candidate, as a percentage. */
+	percent?: number;
+	verdict: CompareVerdict;
+}
+
+export interface JsonCompareSummary {
+	improved: number;
+	regressed: number;
+	unchanged: number;
+	added: number;
+	removed: number;
+}
+
+export interface JsonCompare {
+	baseline: JsonCompareReport;
+	candidate: JsonCompareReport;
+	/** The test used to determine if a change is significant. */
+	test: ModelTest;
+	lower_boundary?: Boundary;
+	upper_boundary?: Boundary;
+	/** The comparison for every benchmark and measure in either report. */
+	results: JsonCompareResult[];
+	summary: JsonCompareSummary;
+}
+
 /**
  * `JsonPerfQuery` is the full, strongly typed version of `JsonPerfQueryParams`.
  * It should always be used to validate `JsonPerfQueryParams`.
@@ -590,6 +793,8 @@ export interface JsonPerfQuery {
 	measures: Uuid[];
 	start_time?: string;
 	end_time?: string;
+	bucket?: PerfBucket;
+	aggregate?: PerfAggregate;
 }
 
 export enum PlanStatus {
@@ -628,6 +833,7 @@ export interface JsonPlot {
 	branches: Uuid[];
 	testbeds: Uuid[];
 	benchmarks: Uuid[];
+	benchmark_tags?: string;
 	measures: Uuid[];
 	created: string;
 	modified: string;
@@ -642,6 +848,7 @@ export interface JsonPubUser {
 export enum Adapter {
 	Magic = "magic",
 	Json = "json",
+	Custom = "custom",
 	Rust = "rust",
 	RustBench = "rust_bench",
 	RustCriterion = "rust_criterion",
@@ -666,6 +873,7 @@ export enum Adapter {
 	RubyBenchmark = "ruby_benchmark",
 	Shell = "shell",
 	ShellHyperfine = "shell_hyperfine",
+	ShellPerfStat = "shell_perf_stat",
 }
 
 export interface JsonReport {
@@ -679,9 +887,30 @@ export interface JsonReport {
 	adapter: Adapter;
 	results: JsonReportResults;
 	alerts: JsonReportAlerts;
+	group?: Uuid;
 	created: string;
 }
 
+export interface JsonRetention {
+	project: Uuid;
+	/** The number of days to keep reports. */
+	reports?: number;
+	/** The number of days to keep archived branches. */
+	archived_branches?: number;
+}
+
+/** The data that was or would be deleted by a retention policy. */
+export interface JsonRetentionPrune {
+	/** The number of archived branches. */
+	branches: number;
+	/** The number of reports, including those for archived branches. */
+	reports: number;
+	/** The number of metrics for those reports. */
+	metrics: number;
+	/** The number of versions that no longer have any reports. */
+	versions: number;
+}
+
 export interface JsonSignup {
 	name: UserName;
 	slug?: Slug;
@@ -715,6 +944,32 @@ export interface JsonUpdateAlert {
 	status?: UpdateAlertStatus;
 }
 
+export interface JsonUpdateProjectMember {
+	/**
+	 * The custom role to assign to the member for the project.
+	 * The role must belong to the organization that owns the project.
+	 */
+	role: Uuid;
+}
+
+export interface JsonUpdateRetention {
+	/**
+	 * The number of days to keep reports, by report start time.
+	 * Reports for a version that is used as a branch start point
+	 * or that has an active alert are always kept.
+	 * If not provided, reports are kept forever.
+	 */
+	reports?: number;
+	/**
+	 * The number of days to keep archived branches, by archive time.
+	 * An archived branch is deleted along with all of its reports and thresholds.
+	 * Archived branches that are used as a start point by another branch
+	 * or that have an active alert are always kept.
+	 * If not provided, archived branches are kept forever.
+	 */
+	archived_branches?: number;
+}
+
 export interface JsonUpdateUser {
 	/**
 	 * The new name of the user.
@@ -773,26 +1028,169 @@ export interface JsonUsage {
 	usage?: number;
 }
 
+export interface JsonWebhook {
+	uuid: Uuid;
+	project: Uuid;
+	url: Url;
+	created: string;
+	modified: string;
+}
+
+export enum WebhookEvent {
+	/** An alert was raised by a threshold. */
+	AlertCreated = "alert_created",
+	/** The status of an alert was changed. */
+	AlertUpdated = "alert_updated",
+}
+
+/** The payload sent to a webhook URL. */
+export interface JsonWebhookEvent {
+	event: WebhookEvent;
+	alert: JsonAlert;
+}
+
+export enum AuditCredential {
+	/** A console login session. */
+	Session = "session",
+	/** An API token. */
+	ApiKey = "api_key",
+}
+
+export enum AuditAction {
+	Create = "create",
+	Update = "update",
+	Delete = "delete",
+	/** A user was invited to an organization. */
+	Invite = "invite",
+	/** A user was provisioned with SCIM. */
+	Provision = "provision",
+	/** A user was deprovisioned with SCIM. */
+	Deprovision = "deprovision",
+	/** A server backup was started. */
+	Backup = "backup",
+	/** A server restart was requested. */
+	Restart = "restart",
+}
+
+export enum AuditResource {
+	Server = "server",
+	Organization = "organization",
+	Member = "member",
+	Sso = "sso",
+	Plan = "plan",
+	/** A custom organization role. */
+	Role = "role",
+	Project = "project",
+	Branch = "branch",
+	Testbed = "testbed",
+	Benchmark = "benchmark",
+	Measure = "measure",
+	Threshold = "threshold",
+	Alert = "alert",
+	Report = "report",
+	Plot = "plot",
+	Webhook = "webhook",
+	Retention = "retention",
+	/** A custom role assignment for a project member. */
+	ProjectMember = "project_member",
+	User = "user",
+	Token = "token",
+}
+
+export interface JsonAuditEvent {
+	uuid: Uuid;
+	/** The user that made the change. */
+	user: Uuid;
+	/** How the user was authenticated. */
+	credential: AuditCredential;
+	/** The API token used to make the change, if known. */
+	token?: Uuid;
+	action: AuditAction;
+	resource: AuditResource;
+	/** The UUID of the resource that was changed, if any. */
+	resource_uuid?: string;
+	/** The organization for the resource, if any. */
+	organization?: Uuid;
+	/** The project for the resource, if any. */
+	project?: Uuid;
+	/** The resource before the change, if any. */
+	before?: unknown;
+	/** The resource after the change, if any. */
+	after?: unknown;
+	created: string;
+}
+
 export enum OrganizationPermission {
 	View = "view",
 	Create = "create",
 	Edit = "edit",
 	Delete = "delete",
 	Manage = "manage",
+	CreateReport = "create_report",
 	ViewRole = "view_role",
 	CreateRole = "create_role",
 	EditRole = "edit_role",
 	DeleteRole = "delete_role",
 }
 
+export enum PerfAggregate {
+	/** The mean of the metric values in each bucket. */
+	Mean = "mean",
+	/** The median of the metric values in each bucket. */
+	Median = "median",
+	/** The minimum metric value in each bucket. */
+	Min = "min",
+	/** The maximum metric value in each bucket. */
+	Max = "max",
+	/** The 90th percentile of the metric values in each bucket. */
+	P90 = "p90",
+}
+
+export enum PerfBucket {
+	/** One bucket per hour, by report start time. */
+	Hour = "hour",
+	/** One bucket per day, by report start time. */
+	Day = "day",
+	/** One bucket per week starting on Monday, by report start time. */
+	Week = "week",
+	/** One bucket per branch version. */
+	Version = "version",
+}
+
+export enum PerfExportFormat {
+	/** Comma separated values. */
+	Csv = "csv",
+	/** Apache Parquet. */
+	Parquet = "parquet",
+}
+
+export enum PerfImgFormat {
+	/** A JPEG raster image. */
+	Jpeg = "jpeg",
+	/** A PNG raster image. */
+	Png = "png",
+	/** An SVG vector image. */
+	Svg = "svg",
+}
+
+export enum PerfImgTheme {
+	/** Dark text on a light background. */
+	Light = "light",
+	/** Light text on a dark background. */
+	Dark = "dark",
+}
+
 export enum PerfQueryKey {
 	Branches = "branches",
 	Heads = "heads",
 	Testbeds = "testbeds",
 	Benchmarks = "benchmarks",
+	Tags = "tags",
 	Measures = "measures",
 	StartTime = "start_time",
 	EndTime = "end_time",
+	Bucket = "bucket",
+	Aggregate = "aggregate",
 }
 
 export enum PlotKey {
@@ -815,6 +1213,38 @@ export enum ProjectPermission {
 	DeleteRole = "delete_role",
 }
 
+export interface JsonNewRole {
+	/**
+	 * The name of the role.
+	 * Maximum length is 64 characters.
+	 */
+	name: ResourceName;
+	/** The project permissions granted by the role. */
+	permissions: ProjectPermission[];
+}
+
+export interface JsonRole {
+	uuid: Uuid;
+	organization: Uuid;
+	name: ResourceName;
+	permissions: ProjectPermission[];
+	created: string;
+	modified: string;
+}
+
+export interface JsonUpdateRole {
+	/**
+	 * The new name of the role.
+	 * Maximum length is 64 characters.
+	 */
+	name?: ResourceName;
+	/**
+	 * The new project permissions granted by the role.
+	 * This replaces all of the existing permissions.
+	 */
+	permissions?: ProjectPermission[];
+}
+
 /** <https://docs.cloud.google.com/recaptcha/docs/actions-website> */
 export enum RecaptchaAction {
 	Signup = "signup",
@@ -46,6 +46,8 @@ impl Spec {
                 "run" => TagDetails { description: Some("Run".into()), external_docs: None},
                 "auth" => TagDetails { description: Some("Auth".into()), external_docs: None},
                 "sso" => TagDetails { description: Some("Single Sign-On (SSO)".into()), external_docs: None},
+                "scim" => TagDetails { description: Some("SCIM Provisioning".into()), external_docs: None},
+                "audit" => TagDetails { description: Some("Audit Log".into()), external_docs: None},
                 "organizations" => TagDetails { description: Some("Organizations".into()), external_docs: None},
                 "projects" => TagDetails { description: Some("Projects".into()), external_docs: None},
                 "reports" => TagDetails { description: Some("Reports".into()), external_docs: None},
@@ -59,6 +61,8 @@ impl Spec {
                 "thresholds" => TagDetails { description: Some("Thresholds".into()), external_docs: None},
                 "models" => TagDetails { description: Some("Models".into()), external_docs: None},
                 "alerts" => TagDetails { description: Some("Alerts".into()), external_docs: None},
+                "change_points" => TagDetails { description: Some("Change Points".into()), external_docs: None},
+                "webhooks" => TagDetails { description: Some("Webhooks".into()), external_docs: None},
                 "users" => TagDetails { description: Some("Users".into()), external_docs: None},
                 "tokens" => TagDetails { description: Some("API Tokens".into()), external_docs: None},
                 "server" => TagDetails { description: Some("Server".into()), external_docs: None},
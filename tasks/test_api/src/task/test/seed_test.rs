@@ -1,7 +1,7 @@
 use std::process::Command;
 
 use assert_cmd::{assert::OutputAssertExt as _, cargo::CommandCargoExt as _};
-use bencher_json::{Jwt, LOCALHOST_BENCHER_API_URL, Url};
+use bencher_json::{Jwt, LOCALHOST_BENCHER_API_URL, Url, project::compare::JsonCompareSummary};
 use pretty_assertions::assert_eq;
 
 use crate::parser::TaskSeedTest;
@@ -1443,6 +1443,80 @@ impl SeedTest {
                 .as_ref(),
             "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
         );
+        let report_uuid = json.uuid.to_string();
+
+        // A report compared to itself is unchanged
+        // cargo run -- compare --host http://localhost:61016 the-computer --baseline-report [report.uuid] --candidate-report [report.uuid] --format json
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "compare",
+            HOST_ARG,
+            host,
+            PROJECT_SLUG,
+            "--baseline-report",
+            &report_uuid,
+            "--candidate-report",
+            &report_uuid,
+            "--format",
+            "json",
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let compare: bencher_json::JsonCompare =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        assert_eq!(compare.baseline.uuid, json.uuid);
+        assert_eq!(compare.candidate.uuid, json.uuid);
+        assert!(!compare.results.is_empty(), "{compare:?}");
+        assert_eq!(
+            usize::try_from(compare.summary.unchanged).unwrap(),
+            compare.results.len()
+        );
+
+        // The most recent report for each branch is compared
+        // cargo run -- compare --host http://localhost:61016 the-computer --baseline-branch master --candidate-branch feature-hash --testbed base --format json
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "compare",
+            HOST_ARG,
+            host,
+            PROJECT_SLUG,
+            "--baseline-branch",
+            BRANCH_SLUG,
+            "--candidate-branch",
+            "feature-hash",
+            "--testbed",
+            TESTBED_SLUG,
+            "--format",
+            "json",
+        ])
+        .current_dir(CLI_DIR);
+        let assert = cmd.assert().success();
+        let compare: bencher_json::JsonCompare =
+            serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        assert_eq!(compare.baseline.branch.slug.to_string(), BRANCH_SLUG);
+        assert_eq!(compare.candidate.uuid, json.uuid);
+        let verdicts = compare
+            .results
+            .iter()
+            .map(|result| result.verdict)
+            .collect::<Vec<_>>();
+        assert_eq!(compare.summary, JsonCompareSummary::new(verdicts));
+
+        // A missing report is not found
+        // cargo run -- compare --host http://localhost:61016 the-computer --baseline-report [uuid] --candidate-report [report.uuid]
+        let mut cmd = Command::cargo_bin(BENCHER_CMD)?;
+        cmd.args([
+            "compare",
+            HOST_ARG,
+            host,
+            PROJECT_SLUG,
+            "--baseline-report",
+            "00000000-0000-4000-8000-000000000000",
+            "--candidate-report",
+            &report_uuid,
+        ])
+        .current_dir(CLI_DIR);
+        cmd.assert().failure();
 
         std::thread::sleep(std::time::Duration::from_secs(1));
 